@@ -43,20 +43,32 @@ struct PreparePhase2Opts {
     pub power: usize,
     #[options(help = "the size (in powers) of the phase 2 circuit", default = "21")]
     pub phase2_size: u32,
+    #[options(
+        help = "a chunk file of a chunked phase 1 transcript to read instead of the response file, repeated in chunk order"
+    )]
+    pub chunk_fname: Vec<String>,
+    #[options(
+        help = "the number of powers in each chunk of the chunked phase 1 transcript",
+        default = "0"
+    )]
+    pub chunk_size: usize,
 }
 
-fn prepare_phase2<E: PairingEngine + Sync>(opts: &PreparePhase2Opts) -> Result<()> {
-    let parameters = Phase1Parameters::<E>::new_full(opts.proving_system, opts.power, opts.batch_size);
-    // Try to load response file from disk.
+/// Memory maps the given file for reading.
+fn map_file(fname: &str) -> Mmap {
     let reader = OpenOptions::new()
         .read(true)
-        .open(&opts.response_fname)
+        .open(fname)
         .expect("unable open response file in this directory");
-    let response_readable_map = unsafe {
+    unsafe {
         MmapOptions::new()
             .map(&reader)
             .expect("unable to create a memory map for input")
-    };
+    }
+}
+
+fn prepare_phase2<E: PairingEngine + Sync>(opts: &PreparePhase2Opts) -> Result<()> {
+    let parameters = Phase1Parameters::<E>::new_full(opts.proving_system, opts.power, opts.batch_size);
 
     // Create the parameter file
     let mut writer = OpenOptions::new()
@@ -66,18 +78,43 @@ fn prepare_phase2<E: PairingEngine + Sync>(opts: &PreparePhase2Opts) -> Result<(
         .open(&opts.phase2_fname)
         .expect("unable to create parameter file in this directory");
 
-    // Deserialize the accumulator
-    let current_accumulator = Phase1::deserialize(
-        &response_readable_map,
-        UseCompression::Yes,
-        CheckForCorrectness::Full,
-        &parameters,
-    )
-    .expect("unable to read uncompressed accumulator");
+    // Only the powers used by the phase 2 circuit are deserialized. The h query
+    // needs twice as many powers of tau in G1 as the other vectors.
+    let phase2_size = 2usize.pow(opts.phase2_size);
+    let (g1_length, other_length) = (2 * phase2_size - 1, phase2_size);
+
+    // Deserialize the prefix of the accumulator
+    let current_accumulator = if opts.chunk_fname.is_empty() {
+        let response_readable_map = map_file(&opts.response_fname);
+        Phase1::deserialize_prefix(
+            &response_readable_map,
+            UseCompression::Yes,
+            CheckForCorrectness::Full,
+            g1_length,
+            other_length,
+            &parameters,
+        )
+        .expect("unable to read uncompressed accumulator")
+    } else {
+        assert!(
+            opts.chunk_size > 0,
+            "the chunk size of a chunked transcript must be set"
+        );
+        Phase1::deserialize_chunked_prefix(
+            opts.chunk_fname.iter().map(|fname| map_file(fname)),
+            UseCompression::Yes,
+            CheckForCorrectness::Full,
+            g1_length,
+            other_length,
+            opts.chunk_size,
+            &parameters,
+        )
+        .expect("unable to read chunked accumulator")
+    };
 
     // Load the elements to the Groth16 utility
     let groth16_params = Groth16Params::<E>::new(
-        2usize.pow(opts.phase2_size),
+        phase2_size,
         current_accumulator.tau_powers_g1,
         current_accumulator.tau_powers_g2,
         current_accumulator.alpha_tau_powers_g1,
@@ -110,31 +110,24 @@ pub fn contribute<T: Engine + Sync>(
     // Perform the transformation
     println!("Computing and writing your contribution, this could take a while...");
 
-    // this computes a transformation and writes it
-    Phase1::computation(
+    // this computes a transformation and writes it with the public key,
+    // hashing the contribution as it is written, so the user can compare later
+    let contribution_hash = Phase1::computation_with_hash(
         &readable_map,
         &mut writable_map,
         compressed_input,
         compressed_output,
         check_input_correctness,
         &private_key,
+        &public_key,
         &parameters,
     )
     .expect("must contribute with the key");
 
     println!("Finishing writing your contribution to response file...");
 
-    // Write the public key
-    public_key
-        .write(&mut writable_map, compressed_output, &parameters)
-        .expect("unable to write public key");
-
     writable_map.flush().expect("must flush a memory map");
 
-    // Get the hash of the contribution, so the user can compare later
-    let output_readonly = writable_map.make_read_only().expect("must make a map readonly");
-    let contribution_hash = calculate_hash(&output_readonly);
-
     print!(
         "Done!\n\n\
               Your contribution has been written to response file\n\n\
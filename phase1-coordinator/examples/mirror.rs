@@ -0,0 +1,78 @@
+//! Mirrors the ceremony transcript incrementally, using the transcript manifest.
+//!
+//! Usage: `mirror <manifest> <source> <mirror>`, where `<manifest>` is a JSON file of the
+//! manifest pages, `<source>` is the directory the artifact paths are relative to, and
+//! `<mirror>` is the directory to copy the artifacts into.
+//!
+//! An artifact is only copied if the mirror has no copy of it, or a copy with a different
+//! size or hash, so running the mirror again only copies the artifacts that are new.
+
+use phase1_coordinator::objects::{TranscriptArtifact, TranscriptManifest};
+use setup_utils::calculate_hash;
+
+use std::{
+    fs,
+    path::{Component, Path, PathBuf},
+};
+
+/// Returns `true` if the mirror has a copy of the given artifact with the same size and hash.
+fn is_mirrored(artifact: &TranscriptArtifact, path: &Path) -> anyhow::Result<bool> {
+    match fs::metadata(path) {
+        Ok(metadata) if metadata.len() == artifact.size => {
+            Ok(hex::encode(calculate_hash(&fs::read(path)?)) == artifact.hash)
+        }
+        _ => Ok(false),
+    }
+}
+
+/// Returns the path of the given artifact relative to the given directory.
+///
+/// The manifest is fetched from the coordinator, so an artifact path which is absolute
+/// or has a `..` component is rejected, to keep the copies inside the given directory.
+fn resolve(directory: &Path, artifact: &TranscriptArtifact) -> anyhow::Result<PathBuf> {
+    let path = Path::new(&artifact.path);
+    let mut resolved = directory.to_path_buf();
+    for component in path.components() {
+        match component {
+            Component::Normal(name) => resolved.push(name),
+            Component::CurDir => continue,
+            _ => anyhow::bail!("{} is not a relative path inside the transcript", artifact.path),
+        }
+    }
+    Ok(resolved)
+}
+
+fn main() -> anyhow::Result<()> {
+    let args: Vec<String> = std::env::args().collect();
+    if args.len() != 4 {
+        anyhow::bail!("Usage: {} <manifest> <source> <mirror>", args[0]);
+    }
+    let (source, mirror) = (Path::new(&args[2]), Path::new(&args[3]));
+
+    // Load the manifest, as a list of the pages fetched from the coordinator.
+    let pages: Vec<TranscriptManifest> = serde_json::from_slice(&fs::read(&args[1])?)?;
+
+    let (mut copied, mut skipped) = (0, 0);
+    for artifact in pages.iter().flat_map(|page| &page.artifacts) {
+        let destination = resolve(mirror, artifact)?;
+        if is_mirrored(artifact, &destination)? {
+            skipped += 1;
+            continue;
+        }
+
+        // Copy the artifact, and check that the copy matches the manifest.
+        let bytes = fs::read(resolve(source, artifact)?)?;
+        if hex::encode(calculate_hash(&bytes)) != artifact.hash {
+            anyhow::bail!("{} does not match the hash in the manifest", artifact.path);
+        }
+        if let Some(parent) = destination.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&destination, &bytes)?;
+        println!("Copied {} ({} bytes)", artifact.path, artifact.size);
+        copied += 1;
+    }
+
+    println!("Copied {} artifacts, {} were already mirrored", copied, skipped);
+    Ok(())
+}
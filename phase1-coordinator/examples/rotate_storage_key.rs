@@ -0,0 +1,50 @@
+//! Rotates the key that encrypts the coordinator metadata in storage.
+//!
+//! Usage: `rotate_storage_key <storage> <current key> <new key>`, where `<storage>` is the
+//! base directory of the coordinator storage, and `<current key>` and `<new key>` are files
+//! holding a key generated by `age-keygen`, or `-` if storage is, or is to be, unencrypted.
+//!
+//! The coordinator must be stopped while the key is rotated, and restarted with the new key.
+
+use phase1_coordinator::{
+    environment::{Development, Environment, Parameters},
+    storage::{Disk, Storage, StorageKey, StorageKeySource},
+};
+
+use std::path::Path;
+
+/// Returns the key source for the given argument, or `None` if it is `-`.
+fn key_source(argument: &str) -> Option<StorageKeySource> {
+    match argument {
+        "-" => None,
+        path => Some(StorageKeySource::File(path.to_string())),
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    let args: Vec<String> = std::env::args().collect();
+    if args.len() != 4 {
+        anyhow::bail!("Usage: {} <storage> <current key> <new key>", args[0]);
+    }
+    if !Path::new(&args[1]).join("manifest.json").is_file() {
+        anyhow::bail!("{} holds no coordinator storage", args[1]);
+    }
+
+    // Load storage with the current key.
+    let environment: Environment = Development::from(Parameters::TestCustom {
+        number_of_chunks: 8,
+        power: 12,
+        batch_size: 256,
+    })
+    .local_base_directory(&args[1])
+    .storage_encryption_key(key_source(&args[2]))
+    .into();
+    let mut storage = Disk::load(&environment)?;
+
+    // Rewrite the coordinator metadata with the new key.
+    let key = key_source(&args[3]).as_ref().map(StorageKey::load).transpose()?;
+    storage.rotate_encryption_key(key)?;
+
+    println!("Rotated the storage key of {}", args[1]);
+    Ok(())
+}
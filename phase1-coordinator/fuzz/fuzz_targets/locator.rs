@@ -0,0 +1,23 @@
+#![no_main]
+
+use phase1_coordinator::storage::{DiskResolver, LocatorPath, StorageLocator};
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let path = match std::str::from_utf8(data) {
+        Ok(path) => path,
+        Err(_) => return,
+    };
+
+    // Parse the path as it would be read from the manifest.
+    let resolver = DiskResolver::new("./transcript/fuzz");
+    let locator = match resolver.to_locator(&LocatorPath::from(path)) {
+        Ok(locator) => locator,
+        Err(_) => return,
+    };
+
+    // Check that the parsed locator resolves to a path that parses back to the same locator.
+    let path = resolver.to_path(&locator).expect("a parsed locator must have a path");
+    assert_eq!(locator, resolver.to_locator(&path).expect("a locator path must parse"));
+});
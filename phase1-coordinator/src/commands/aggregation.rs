@@ -4,7 +4,7 @@ use crate::{
     storage::{ContributionLocator, Locator, Object, ObjectReader, StorageLock},
     CoordinatorError,
 };
-use phase1::{helpers::CurveKind, Phase1};
+use phase1::{helpers::CurveKind, ContributionMode, Phase1};
 
 use std::time::Instant;
 use tracing::{debug, error, trace};
@@ -13,15 +13,26 @@ use zexe_algebra::{Bls12_377, BW6_761};
 pub(crate) struct Aggregation;
 
 impl Aggregation {
-    /// Runs aggregation for a given environment, storage, and round.
+    /// Runs aggregation for a given environment, storage, and round,
+    /// and returns the hash of the aggregated round file.
     #[inline]
-    pub(crate) fn run(environment: &Environment, storage: &mut StorageLock, round: &Round) -> anyhow::Result<()> {
+    pub(crate) fn run(environment: &Environment, storage: &mut StorageLock, round: &Round) -> anyhow::Result<Vec<u8>> {
         let start = Instant::now();
 
         // Fetch the round height.
         let round_height = round.round_height();
         debug!("Starting aggregation on round {}", round_height);
 
+        // Check that the contributions are chunked, as aggregation combines one contribution per chunk.
+        let contribution_mode = environment.parameters().contribution_mode();
+        if contribution_mode != ContributionMode::Chunked {
+            error!(
+                "Aggregation requires chunked contributions, found {:?} mode",
+                contribution_mode
+            );
+            return Err(CoordinatorError::RoundAggregationModeUnsupported.into());
+        }
+
         // Fetch the compressed input setting for the final round file.
         let compressed_input = environment.compressed_inputs();
         // Fetch the compressed output setting based on the round height.
@@ -58,10 +69,13 @@ impl Aggregation {
                 &phase1_chunked_parameters!(BW6_761, settings, chunk_id),
             ),
         };
-        if let Err(error) = result {
-            error!("Aggregation failed with {}", error);
-            return Err(CoordinatorError::RoundAggregationFailed.into());
-        }
+        let round_file_hash = match result {
+            Ok(round_file_hash) => round_file_hash.to_vec(),
+            Err(error) => {
+                error!("Aggregation failed with {}", error);
+                return Err(CoordinatorError::RoundAggregationFailed.into());
+            }
+        };
 
         // Run aggregate verification on the given round.
         let settings = environment.parameters();
@@ -87,7 +101,7 @@ impl Aggregation {
 
         let elapsed = Instant::now().duration_since(start);
         debug!("Completed aggregation on round {} in {:?}", round_height, elapsed);
-        Ok(())
+        Ok(round_file_hash)
     }
 
     /// Attempts to open every contribution for the given round and
@@ -97,7 +111,7 @@ impl Aggregation {
         environment: &Environment,
         storage: &'a StorageLock<'a>,
         round: &Round,
-    ) -> anyhow::Result<Vec<ObjectReader<'a>>> {
+    ) -> anyhow::Result<Vec<ObjectReader>> {
         let mut readers = vec![];
 
         // Fetch the round height.
@@ -146,10 +160,14 @@ mod tests {
     use crate::{
         authentication::Dummy,
         commands::{Aggregation, Seed, SigningKey, SEED_LENGTH},
+        environment::{Environment, Parameters, Settings, Testing},
         storage::{Locator, StorageLock},
         testing::prelude::*,
         Coordinator,
+        CoordinatorError,
     };
+    use phase1::{helpers::CurveKind, ContributionMode, ProvingSystem};
+    use setup_utils::calculate_hash;
 
     use chrono::Utc;
     use once_cell::sync::Lazy;
@@ -300,12 +318,69 @@ mod tests {
             let mut storage = StorageLock::Write(test_storage.write().unwrap());
 
             // Run aggregation on the round.
-            Aggregation::run(&TEST_ENVIRONMENT_3, &mut storage, &round).unwrap();
+            let round_file_hash = Aggregation::run(&TEST_ENVIRONMENT_3, &mut storage, &round).unwrap();
 
             // Fetch the round locator for the given round.
             let round_locator = Locator::RoundFile { round_height };
 
             assert!(storage.exists(&round_locator));
+
+            // Check that the returned hash is the hash of the round file.
+            let round_file = storage.reader(&round_locator).unwrap();
+            assert_eq!(calculate_hash(round_file.as_ref()).to_vec(), round_file_hash);
+        }
+    }
+
+    #[test]
+    #[serial]
+    fn test_aggregation_run_full_mode() {
+        initialize_test_environment(&TEST_ENVIRONMENT_3);
+
+        let coordinator = Coordinator::new(TEST_ENVIRONMENT_3.clone(), Box::new(Dummy)).unwrap();
+        let test_storage = coordinator.storage();
+
+        let contributor = Lazy::force(&TEST_CONTRIBUTOR_ID).clone();
+        let verifier = Lazy::force(&TEST_VERIFIER_ID).clone();
+
+        {
+            // Acquire the storage write lock.
+            let mut storage = StorageLock::Write(test_storage.write().unwrap());
+
+            // Run initialization and start round 1.
+            coordinator.run_initialization(&mut storage, Utc::now()).unwrap();
+            coordinator
+                .next_round(&mut storage, *TEST_STARTED_AT, vec![contributor], vec![verifier])
+                .unwrap();
+        }
+
+        // Define an environment with the same parameters, in full contribution mode.
+        let full_environment: Environment = Testing::from(Parameters::Custom(Settings::new(
+            ContributionMode::Full,
+            ProvingSystem::Groth16,
+            CurveKind::Bls12_377,
+            TEST_ENVIRONMENT_3.parameters().power(),
+            TEST_ENVIRONMENT_3.parameters().batch_size(),
+            TEST_ENVIRONMENT_3.parameters().chunk_size(),
+        )))
+        .into();
+
+        // Fetch the current round state.
+        let round_height = coordinator.current_round_height().unwrap();
+        let round = coordinator.get_round(round_height).unwrap();
+
+        // Check that aggregation is rejected in full contribution mode.
+        {
+            // Obtain the storage lock.
+            let mut storage = StorageLock::Write(test_storage.write().unwrap());
+
+            let result = Aggregation::run(&full_environment, &mut storage, &round);
+            assert!(matches!(
+                result.unwrap_err().downcast::<CoordinatorError>(),
+                Ok(CoordinatorError::RoundAggregationModeUnsupported)
+            ));
+
+            // Check that the round file was not initialized.
+            assert!(!storage.exists(&Locator::RoundFile { round_height }));
         }
     }
 }
@@ -5,8 +5,12 @@ use crate::{
     storage::{Locator, StorageLock},
     CoordinatorError,
 };
-use phase1::{helpers::CurveKind, Phase1, Phase1Parameters};
-use setup_utils::{calculate_hash, derive_rng_from_seed, UseCompression};
+use phase1::{
+    helpers::{buffers::Cancellation, CurveKind},
+    Phase1,
+    Phase1Parameters,
+};
+use setup_utils::{calculate_hash, derive_rng_from_seed, GenericArray, UseCompression, U64};
 
 use rand::Rng;
 use std::{io::Write, sync::Arc, time::Instant};
@@ -27,6 +31,10 @@ impl Computation {
     /// and response file have been initialized, typically as part of a call to
     /// `Coordinator::try_lock` to lock the contribution chunk.
     ///
+    /// If the given cancellation is cancelled, the computation stops at the next batch
+    /// with `CoordinatorError::Cancelled`, and the partially written response is left
+    /// to be overwritten by the next computation of the chunk.
+    ///
     #[inline]
     pub(crate) fn run(
         environment: &Environment,
@@ -37,6 +45,7 @@ impl Computation {
         response_locator: &Locator,
         contribution_file_signature_locator: &Locator,
         seed: &Seed,
+        cancellation: &Cancellation,
     ) -> anyhow::Result<()> {
         let start = Instant::now();
         info!(
@@ -58,13 +67,14 @@ impl Computation {
         // Run computation on chunk.
         let settings = environment.parameters();
         let curve = settings.curve();
-        if let Err(error) = match curve {
+        let contribution_hash = match match curve {
             CurveKind::Bls12_377 => Self::contribute(
                 environment,
                 storage.reader(challenge_locator)?.as_ref(),
                 storage.writer(response_locator)?.as_mut(),
                 &phase1_chunked_parameters!(Bls12_377, settings, chunk_id),
                 derive_rng_from_seed(&seed[..]),
+                cancellation,
             ),
             CurveKind::BW6 => Self::contribute(
                 environment,
@@ -72,15 +82,19 @@ impl Computation {
                 storage.writer(response_locator)?.as_mut(),
                 &phase1_chunked_parameters!(BW6_761, settings, chunk_id),
                 derive_rng_from_seed(&seed[..]),
+                cancellation,
             ),
         } {
-            error!("Computation failed with {}", error);
-            return Err(CoordinatorError::ComputationFailed.into());
-        }
-
-        // Load a contribution response reader.
-        let reader = storage.reader(response_locator)?;
-        let contribution_hash = calculate_hash(reader.as_ref());
+            Ok(contribution_hash) => contribution_hash,
+            Err(CoordinatorError::Phase1Setup(setup_utils::Error::Cancelled)) => {
+                info!("Computation of chunk {} was cancelled", chunk_id);
+                return Err(CoordinatorError::Cancelled.into());
+            }
+            Err(error) => {
+                error!("Computation failed with {}", error);
+                return Err(CoordinatorError::ComputationFailed.into());
+            }
+        };
         debug!("Response hash is {}", pretty_hash!(&contribution_hash));
 
         debug!(
@@ -96,6 +110,7 @@ impl Computation {
             contributor_signing_key,
             challenge_locator,
             response_locator,
+            Some(contribution_hash.as_slice()),
             None,
             contribution_file_signature_locator,
         )?;
@@ -120,7 +135,8 @@ impl Computation {
         mut response_writer: &mut [u8],
         parameters: &Phase1Parameters<T>,
         mut rng: impl Rng,
-    ) -> Result<(), CoordinatorError> {
+        cancellation: &Cancellation,
+    ) -> Result<GenericArray<u8, U64>, CoordinatorError> {
         // Fetch the environment settings.
         let compressed_inputs = environment.compressed_inputs();
         let compressed_outputs = environment.compressed_outputs();
@@ -148,24 +164,23 @@ impl Computation {
         let (public_key, private_key) =
             Phase1::key_generation(&mut rng, challenge_hash.as_ref()).expect("could not generate keypair");
 
-        // Perform the transformation
+        // Perform the transformation, and write the public key after it.
         trace!("Computing and writing your contribution, this could take a while");
-        Phase1::computation(
+        let contribution_hash = Phase1::computation_with_hash_and_cancellation(
             challenge_reader,
             response_writer,
             compressed_inputs,
             compressed_outputs,
             check_input_for_correctness,
             &private_key,
+            &public_key,
             &parameters,
+            cancellation,
         )?;
         response_writer.flush()?;
         trace!("Finishing writing your contribution to response file");
 
-        // Write the public key.
-        public_key.write(response_writer, compressed_outputs, &parameters)?;
-
-        Ok(())
+        Ok(contribution_hash)
     }
 }
 
@@ -177,6 +192,7 @@ mod tests {
         storage::{ContributionLocator, ContributionSignatureLocator, Locator, Object, StorageLock},
         testing::prelude::*,
     };
+    use phase1::helpers::buffers::Cancellation;
     use setup_utils::calculate_hash;
 
     use rand::RngCore;
@@ -247,18 +263,13 @@ mod tests {
                 response_locator,
                 contribution_file_signature_locator,
                 &seed,
+                &Cancellation::new(),
             )
             .unwrap();
 
             // Check that the current contribution was generated based on the previous contribution hash.
             let challenge_hash = calculate_hash(&storage.reader(&challenge_locator).unwrap());
-            let saved_challenge_hash = storage
-                .reader(&response_locator)
-                .unwrap()
-                .chunks(64)
-                .next()
-                .unwrap()
-                .to_vec();
+            let saved_challenge_hash = storage.reader(&response_locator).unwrap().get(0..64).unwrap().to_vec();
             for (i, (expected, candidate)) in (challenge_hash.iter().zip(&saved_challenge_hash)).enumerate() {
                 trace!("Checking byte {} of expected hash", i);
                 assert_eq!(expected, candidate);
@@ -158,7 +158,7 @@ mod tests {
 
             // Check that the contribution chunk was generated based on the blank hash.
             let hash = blank_hash();
-            for (i, (expected, candidate)) in hash.iter().zip(reader.as_ref().chunks(64).next().unwrap()).enumerate() {
+            for (i, (expected, candidate)) in hash.iter().zip(reader.get(0..64).unwrap()).enumerate() {
                 trace!("Checking byte {} of expected hash", i);
                 assert_eq!(expected, candidate);
             }
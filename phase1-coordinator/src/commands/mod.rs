@@ -9,6 +9,9 @@ pub(crate) use computation::*;
 pub(crate) mod initialization;
 pub(crate) use initialization::*;
 
+pub(crate) mod practice;
+pub(crate) use practice::*;
+
 #[cfg(any(test, feature = "operator"))]
 pub(crate) mod verification;
 #[cfg(any(test, feature = "operator"))]
@@ -40,6 +43,9 @@ pub type SigningKey = String;
 /// 2. Response file
 /// 3. Next challenge file (for verifiers)
 ///
+/// If the hash of the response file is given, for example as it was computed while
+/// the response was written, the response file is not read again to hash it.
+///
 /// On success, this function writes a contribution file signature to disk.
 ///
 /// On failure, this function returns a `CoordinatorError`.
@@ -52,6 +58,7 @@ pub(crate) fn write_contribution_file_signature(
     signing_key: &SigningKey,
     challenge_locator: &Locator,
     response_locator: &Locator,
+    response_hash: Option<&[u8]>,
     next_challenge_locator: Option<&Locator>,
     contribution_file_signature_locator: &Locator,
 ) -> Result<(), CoordinatorError> {
@@ -59,9 +66,14 @@ pub(crate) fn write_contribution_file_signature(
     let challenge_reader = storage.reader(challenge_locator)?;
     let challenge_hash = calculate_hash(challenge_reader.as_ref()).to_vec();
 
-    // Calculate the response hash.
-    let response_reader = storage.reader(response_locator)?;
-    let response_hash = calculate_hash(response_reader.as_ref()).to_vec();
+    // Calculate the response hash, if it was not given.
+    let response_hash = match response_hash {
+        Some(response_hash) => response_hash.to_vec(),
+        None => {
+            let response_reader = storage.reader(response_locator)?;
+            calculate_hash(response_reader.as_ref()).to_vec()
+        }
+    };
 
     // Calculate the next challenge hash.
     let next_challenge_hash = match next_challenge_locator {
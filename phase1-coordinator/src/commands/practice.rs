@@ -0,0 +1,337 @@
+use crate::{
+    environment::Environment,
+    storage::{Locator, StorageLock},
+    CoordinatorError,
+};
+use phase1::{helpers::CurveKind, Phase1, Phase1Parameters, PublicKey};
+use setup_utils::{blank_hash, calculate_hash, CheckForCorrectness, UseCompression};
+
+use std::{io::Write, time::Instant};
+use tracing::{debug, error, info, trace};
+use zexe_algebra::{Bls12_377, PairingEngine as Engine, BW6_761};
+
+/// Returns an instance of full `Phase1Parameters` for the practice challenge, given an
+/// instantiation of `PairingEngine`, an instance of `Settings`, and the practice power.
+macro_rules! phase1_practice_parameters {
+    ($curve:ident, $settings:ident, $power:ident) => {{
+        Phase1Parameters::<$curve>::new_full($settings.proving_system(), $power, $settings.batch_size())
+    }};
+}
+
+pub(crate) struct Practice;
+
+impl Practice {
+    ///
+    /// Generates the practice challenge for a given environment.
+    ///
+    /// The practice challenge is a freshly initialized accumulator over the practice
+    /// power of the environment. Any previous practice challenge is replaced.
+    ///
+    #[inline]
+    pub(crate) fn initialize(environment: &Environment, storage: &mut StorageLock) -> Result<(), CoordinatorError> {
+        let power = environment
+            .practice_power()
+            .ok_or(CoordinatorError::PracticeModeDisabled)?;
+        info!(
+            "Starting initialization of the practice challenge on 2^{} powers",
+            power
+        );
+        let start = Instant::now();
+
+        // Remove the previous practice challenge, if it exists.
+        let locator = Locator::PracticeChallenge;
+        if storage.exists(&locator) {
+            storage.remove(&locator)?;
+        }
+
+        // Initialize and fetch a writer for the practice challenge locator so the output is saved.
+        storage.initialize(locator.clone(), Self::challenge_size(environment)?)?;
+
+        let settings = environment.parameters();
+        if let Err(error) = match settings.curve() {
+            CurveKind::Bls12_377 => Self::initialization(
+                storage.writer(&locator)?.as_mut(),
+                environment.compressed_inputs(),
+                &phase1_practice_parameters!(Bls12_377, settings, power),
+            ),
+            CurveKind::BW6 => Self::initialization(
+                storage.writer(&locator)?.as_mut(),
+                environment.compressed_inputs(),
+                &phase1_practice_parameters!(BW6_761, settings, power),
+            ),
+        } {
+            error!("Practice initialization failed with {}", error);
+            return Err(CoordinatorError::InitializationFailed);
+        }
+
+        let elapsed = Instant::now().duration_since(start);
+        info!("Completed initialization of the practice challenge in {:?}", elapsed);
+        Ok(())
+    }
+
+    ///
+    /// Verifies the given practice response against the practice challenge.
+    ///
+    /// The response is verified in memory against a copy of the practice challenge,
+    /// and is never written to storage.
+    ///
+    #[inline]
+    pub(crate) fn verify(environment: &Environment, challenge: &[u8], response: &[u8]) -> Result<(), CoordinatorError> {
+        let power = environment
+            .practice_power()
+            .ok_or(CoordinatorError::PracticeModeDisabled)?;
+
+        // Check that the practice response size is correct.
+        let expected = Self::response_size(environment)?;
+        if response.len() as u64 != expected {
+            error!(
+                "Practice response size should be {} but found {}",
+                expected,
+                response.len()
+            );
+            return Err(CoordinatorError::PracticeResponseSizeMismatch);
+        }
+
+        let settings = environment.parameters();
+        if let Err(error) = match settings.curve() {
+            CurveKind::Bls12_377 => Self::verification(
+                environment,
+                challenge,
+                response,
+                &phase1_practice_parameters!(Bls12_377, settings, power),
+            ),
+            CurveKind::BW6 => Self::verification(
+                environment,
+                challenge,
+                response,
+                &phase1_practice_parameters!(BW6_761, settings, power),
+            ),
+        } {
+            error!("Practice verification failed with {}", error);
+            return Err(CoordinatorError::VerificationFailed);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the expected file size of the practice challenge.
+    #[inline]
+    pub(crate) fn challenge_size(environment: &Environment) -> Result<u64, CoordinatorError> {
+        let power = environment
+            .practice_power()
+            .ok_or(CoordinatorError::PracticeModeDisabled)?;
+        let settings = environment.parameters();
+
+        let (accumulator_size, contribution_size, public_key_size) = match settings.curve() {
+            CurveKind::Bls12_377 => Self::sizes(&phase1_practice_parameters!(Bls12_377, settings, power)),
+            CurveKind::BW6 => Self::sizes(&phase1_practice_parameters!(BW6_761, settings, power)),
+        };
+
+        Ok(match environment.compressed_inputs() {
+            UseCompression::Yes => (contribution_size - public_key_size) as u64,
+            UseCompression::No => accumulator_size as u64,
+        })
+    }
+
+    /// Returns the expected file size of a practice response.
+    #[inline]
+    pub(crate) fn response_size(environment: &Environment) -> Result<u64, CoordinatorError> {
+        let power = environment
+            .practice_power()
+            .ok_or(CoordinatorError::PracticeModeDisabled)?;
+        let settings = environment.parameters();
+
+        let (accumulator_size, contribution_size, public_key_size) = match settings.curve() {
+            CurveKind::Bls12_377 => Self::sizes(&phase1_practice_parameters!(Bls12_377, settings, power)),
+            CurveKind::BW6 => Self::sizes(&phase1_practice_parameters!(BW6_761, settings, power)),
+        };
+
+        Ok(match environment.compressed_outputs() {
+            UseCompression::Yes => contribution_size as u64,
+            UseCompression::No => (accumulator_size + public_key_size) as u64,
+        })
+    }
+
+    #[inline]
+    fn sizes<T: Engine>(parameters: &Phase1Parameters<T>) -> (usize, usize, usize) {
+        (
+            parameters.accumulator_size,
+            parameters.contribution_size,
+            parameters.public_key_size,
+        )
+    }
+
+    /// Runs Phase 1 initialization on the given parameters.
+    #[inline]
+    fn initialization<T: Engine + Sync>(
+        mut writer: &mut [u8],
+        compressed: UseCompression,
+        parameters: &Phase1Parameters<T>,
+    ) -> Result<(), CoordinatorError> {
+        let hash = blank_hash();
+        (&mut writer[0..]).write_all(hash.as_slice())?;
+        writer.flush()?;
+
+        trace!("Starting Phase 1 initialization operation for the practice challenge");
+        Phase1::initialization(&mut writer, compressed, &parameters)?;
+        writer.flush()?;
+        trace!("Completed Phase 1 initialization operation for the practice challenge");
+
+        Ok(())
+    }
+
+    /// Runs Phase 1 verification of the practice response on the given parameters.
+    #[inline]
+    fn verification<T: Engine + Sync>(
+        environment: &Environment,
+        challenge_reader: &[u8],
+        response_reader: &[u8],
+        parameters: &Phase1Parameters<T>,
+    ) -> Result<(), CoordinatorError> {
+        // Check that the response was computed on the practice challenge.
+        let challenge_hash = calculate_hash(challenge_reader);
+        let saved_challenge_hash = response_reader
+            .get(0..64)
+            .ok_or(CoordinatorError::StorageReaderFailed)?;
+        if challenge_hash.as_slice() != saved_challenge_hash {
+            error!("Practice response does not match the practice challenge hash.");
+            return Err(CoordinatorError::ContributionHashMismatch);
+        }
+        debug!("The practice challenge hash is {}", pretty_hash!(&challenge_hash));
+
+        // Fetch the compression settings.
+        let compressed_challenge = environment.compressed_inputs();
+        let compressed_response = environment.compressed_outputs();
+
+        // Fetch the public key of the contributor.
+        let public_key = PublicKey::read(response_reader, compressed_response, &parameters)?;
+
+        trace!("Starting verification of the practice response");
+        Phase1::verification(
+            challenge_reader,
+            response_reader,
+            &public_key,
+            &challenge_hash,
+            compressed_challenge,
+            compressed_response,
+            CheckForCorrectness::No,
+            CheckForCorrectness::Full,
+            &parameters,
+        )?;
+        trace!("Completed verification of the practice response");
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use crate::{
+        commands::Practice,
+        environment::{Environment, Parameters, Testing},
+        storage::{Locator, StorageLock},
+        testing::prelude::*,
+        CoordinatorError,
+    };
+    use phase1::{helpers::CurveKind, Phase1, Phase1Parameters};
+    use setup_utils::calculate_hash;
+
+    use std::io::Write;
+    use zexe_algebra::{Bls12_377, PairingEngine as Engine, BW6_761};
+
+    /// Computes a contribution on the given practice challenge, as a contributor would.
+    pub(crate) fn contribute_practice(environment: &Environment, challenge: &[u8]) -> Vec<u8> {
+        let power = environment.practice_power().unwrap();
+        let settings = environment.parameters();
+        let mut response = vec![0u8; Practice::response_size(environment).unwrap() as usize];
+        match settings.curve() {
+            CurveKind::Bls12_377 => contribute::<Bls12_377>(
+                environment,
+                challenge,
+                &mut response,
+                &phase1_practice_parameters!(Bls12_377, settings, power),
+            ),
+            CurveKind::BW6 => contribute::<BW6_761>(
+                environment,
+                challenge,
+                &mut response,
+                &phase1_practice_parameters!(BW6_761, settings, power),
+            ),
+        }
+        response
+    }
+
+    fn contribute<T: Engine + Sync>(
+        environment: &Environment,
+        challenge: &[u8],
+        mut response: &mut [u8],
+        parameters: &Phase1Parameters<T>,
+    ) {
+        let challenge_hash = calculate_hash(challenge);
+        (&mut response[0..]).write_all(challenge_hash.as_slice()).unwrap();
+
+        let (public_key, private_key) =
+            Phase1::key_generation(&mut rand::thread_rng(), challenge_hash.as_ref()).unwrap();
+        Phase1::computation(
+            challenge,
+            response,
+            environment.compressed_inputs(),
+            environment.compressed_outputs(),
+            environment.check_input_for_correctness(),
+            &private_key,
+            &parameters,
+        )
+        .unwrap();
+        public_key
+            .write(response, environment.compressed_outputs(), &parameters)
+            .unwrap();
+    }
+
+    #[test]
+    #[serial]
+    fn test_practice_round_trip() {
+        let environment: Environment = Testing::from(Parameters::Test8Chunks).practice_power(Some(6)).into();
+        initialize_test_environment(&environment);
+
+        // Define test storage.
+        let test_storage = test_storage(&environment);
+        let mut storage = StorageLock::Write(test_storage.write().unwrap());
+
+        // Generate the practice challenge, twice, to check it is replaced.
+        Practice::initialize(&environment, &mut storage).unwrap();
+        Practice::initialize(&environment, &mut storage).unwrap();
+        assert_eq!(
+            Practice::challenge_size(&environment).unwrap(),
+            storage.size(&Locator::PracticeChallenge).unwrap()
+        );
+
+        // Compute and verify a practice response.
+        let challenge = storage.reader(&Locator::PracticeChallenge).unwrap().to_vec();
+        let response = contribute_practice(&environment, &challenge);
+        Practice::verify(&environment, &challenge, &response).unwrap();
+
+        // Check that a tampered response is rejected.
+        let mut tampered = response.clone();
+        tampered[0] ^= 1;
+        assert!(Practice::verify(&environment, &challenge, &tampered).is_err());
+
+        // Check that a truncated response is rejected.
+        assert!(Practice::verify(&environment, &challenge, &response[1..]).is_err());
+    }
+
+    #[test]
+    #[serial]
+    fn test_practice_disabled() {
+        initialize_test_environment(&TEST_ENVIRONMENT);
+
+        // Define test storage.
+        let test_storage = test_storage(&TEST_ENVIRONMENT);
+        let mut storage = StorageLock::Write(test_storage.write().unwrap());
+
+        match Practice::initialize(&TEST_ENVIRONMENT, &mut storage) {
+            Err(CoordinatorError::PracticeModeDisabled) => {}
+            result => panic!("Expected practice mode to be disabled, found {:?}", result),
+        }
+        assert!(!storage.exists(&Locator::PracticeChallenge));
+    }
+}
@@ -2,10 +2,15 @@ use crate::{
     authentication::Signature,
     commands::SigningKey,
     environment::Environment,
-    storage::{ContributionLocator, ContributionSignatureLocator, Locator, Object, StorageLock},
+    storage::{ContributionLocator, ContributionSignatureLocator, Locator, Object, ObjectReader, StorageLock},
     CoordinatorError,
 };
-use phase1::{helpers::CurveKind, Phase1, Phase1Parameters, PublicKey};
+use phase1::{
+    helpers::{buffers::Cancellation, CurveKind},
+    Phase1,
+    Phase1Parameters,
+    PublicKey,
+};
 use setup_utils::{calculate_hash, CheckForCorrectness, GenericArray, U64};
 
 use std::{io::Write, sync::Arc, time::Instant};
@@ -20,6 +25,9 @@ impl Verification {
     /// round height, chunk ID, and contribution ID of the
     /// unverified response file.
     ///
+    /// If the given cancellation is cancelled, the verification stops
+    /// at the next batch with `CoordinatorError::Cancelled`.
+    ///
     #[inline]
     pub(crate) fn run(
         environment: &Environment,
@@ -30,6 +38,7 @@ impl Verification {
         chunk_id: u64,
         current_contribution_id: u64,
         is_final_contribution: bool,
+        cancellation: &Cancellation,
     ) -> Result<(), CoordinatorError> {
         info!(
             "Starting verification of round {} chunk {} contribution {}",
@@ -37,16 +46,165 @@ impl Verification {
         );
         let start = Instant::now();
 
+        let response_hash = Self::check(
+            environment,
+            storage,
+            round_height,
+            chunk_id,
+            current_contribution_id,
+            cancellation,
+        )?;
+        Self::complete(
+            environment,
+            storage,
+            signature,
+            signing_key,
+            round_height,
+            chunk_id,
+            current_contribution_id,
+            is_final_contribution,
+            &response_hash,
+        )?;
+
+        let elapsed = Instant::now().duration_since(start);
+        info!(
+            "Completed verification of round {} chunk {} contribution {} in {:?}",
+            round_height, chunk_id, current_contribution_id, elapsed
+        );
+        Ok(())
+    }
+
+    ///
+    /// Checks the unverified response file for a given round height, chunk ID,
+    /// and contribution ID against its challenge file, and returns the response hash.
+    ///
+    #[inline]
+    pub(crate) fn check(
+        environment: &Environment,
+        storage: &StorageLock,
+        round_height: u64,
+        chunk_id: u64,
+        current_contribution_id: u64,
+        cancellation: &Cancellation,
+    ) -> Result<GenericArray<u8, U64>, CoordinatorError> {
+        let (challenge_reader, response_reader) =
+            Self::readers(environment, storage, round_height, chunk_id, current_contribution_id)?;
+        Self::check_readers(
+            environment,
+            chunk_id,
+            challenge_reader.as_ref(),
+            response_reader.as_ref(),
+            cancellation,
+        )
+    }
+
+    ///
+    /// Returns readers of the challenge file and the unverified response file
+    /// for a given round height, chunk ID, and contribution ID.
+    ///
+    #[inline]
+    pub(crate) fn readers(
+        environment: &Environment,
+        storage: &StorageLock,
+        round_height: u64,
+        chunk_id: u64,
+        current_contribution_id: u64,
+    ) -> Result<(ObjectReader, ObjectReader), CoordinatorError> {
         // Check that this is not the initial contribution.
         if (round_height == 0 || round_height == 1) && current_contribution_id == 0 {
             return Err(CoordinatorError::VerificationOnContributionIdZero);
         }
 
         // Check that the chunk ID is valid.
-        if chunk_id > environment.number_of_chunks() {
-            return Err(CoordinatorError::ChunkIdInvalid);
+        environment.validate_chunk_id(chunk_id)?;
+
+        // Fetch the locators for `Verification`.
+        let challenge_locator = Locator::ContributionFile(ContributionLocator::new(
+            round_height,
+            chunk_id,
+            current_contribution_id - 1,
+            true,
+        ));
+        let response_locator = Locator::ContributionFile(ContributionLocator::new(
+            round_height,
+            chunk_id,
+            current_contribution_id,
+            false,
+        ));
+
+        trace!("Challenge locator is {}", storage.to_path(&challenge_locator)?);
+        trace!("Response locator is {}", storage.to_path(&response_locator)?);
+
+        // Check that the previous and current locators exist in storage.
+        if !storage.exists(&challenge_locator) || !storage.exists(&response_locator) {
+            return Err(CoordinatorError::ContributionLocatorMissing);
+        }
+
+        Ok((storage.reader(&challenge_locator)?, storage.reader(&response_locator)?))
+    }
+
+    ///
+    /// Checks the given unverified response file for a given chunk ID against
+    /// the given challenge file, and returns the response hash.
+    ///
+    /// This does not access storage, so checks may run without holding the storage
+    /// lock, and checks of different chunks may run concurrently.
+    ///
+    #[inline]
+    pub(crate) fn check_readers(
+        environment: &Environment,
+        chunk_id: u64,
+        challenge_reader: &[u8],
+        response_reader: &[u8],
+        cancellation: &Cancellation,
+    ) -> Result<GenericArray<u8, U64>, CoordinatorError> {
+        // Execute ceremony verification on chunk.
+        let settings = environment.parameters();
+        let result = match settings.curve() {
+            CurveKind::Bls12_377 => Self::transform_pok_and_correctness(
+                environment,
+                challenge_reader,
+                response_reader,
+                &phase1_chunked_parameters!(Bls12_377, settings, chunk_id),
+                cancellation,
+            ),
+            CurveKind::BW6 => Self::transform_pok_and_correctness(
+                environment,
+                challenge_reader,
+                response_reader,
+                &phase1_chunked_parameters!(BW6_761, settings, chunk_id),
+                cancellation,
+            ),
+        };
+        match result {
+            Ok(response_hash) => Ok(response_hash),
+            Err(CoordinatorError::Phase1Setup(setup_utils::Error::Cancelled)) => {
+                info!("Verification of chunk {} was cancelled", chunk_id);
+                Err(CoordinatorError::Cancelled)
+            }
+            Err(error) => {
+                error!("Verification failed with {}", error);
+                Err(CoordinatorError::VerificationFailed)
+            }
         }
+    }
 
+    ///
+    /// Writes the next challenge file and the contribution file signature for
+    /// a response file which passed `Verification::check` with the given response hash.
+    ///
+    #[inline]
+    pub(crate) fn complete(
+        environment: &Environment,
+        storage: &mut StorageLock,
+        signature: Arc<Box<dyn Signature>>,
+        signing_key: &SigningKey,
+        round_height: u64,
+        chunk_id: u64,
+        current_contribution_id: u64,
+        is_final_contribution: bool,
+        response_hash: &[u8],
+    ) -> Result<(), CoordinatorError> {
         // Fetch the locators for `Verification`.
         let challenge_locator = Locator::ContributionFile(ContributionLocator::new(
             round_height,
@@ -86,8 +244,6 @@ impl Verification {
             ),
         };
 
-        trace!("Challenge locator is {}", storage.to_path(&challenge_locator)?);
-        trace!("Response locator is {}", storage.to_path(&response_locator)?);
         trace!(
             "Next challenge locator is {}",
             storage.to_path(&next_challenge_locator)?
@@ -97,13 +253,13 @@ impl Verification {
             storage.to_path(&contribution_file_signature_locator)?
         );
 
-        if let Err(error) = Self::verification(
+        if let Err(error) = Self::next_challenge(
             environment,
             storage,
             chunk_id,
-            challenge_locator.clone(),
-            response_locator.clone(),
-            next_challenge_locator.clone(),
+            &response_locator,
+            &next_challenge_locator,
+            response_hash,
         ) {
             error!("Verification failed with {}", error);
             return Err(error);
@@ -128,6 +284,7 @@ impl Verification {
             signing_key,
             &challenge_locator,
             &response_locator,
+            None,
             Some(&next_challenge_locator),
             &contribution_file_signature_locator,
         )?;
@@ -136,56 +293,22 @@ impl Verification {
             "Successfully wrote contribution file signature for round {} chunk {} verified contribution {}",
             round_height, chunk_id, current_contribution_id
         );
-
-        let elapsed = Instant::now().duration_since(start);
-        info!(
-            "Completed verification of round {} chunk {} contribution {} in {:?}",
-            round_height, chunk_id, current_contribution_id, elapsed
-        );
         Ok(())
     }
 
     #[inline]
-    fn verification(
+    fn next_challenge(
         environment: &Environment,
         storage: &mut StorageLock,
         chunk_id: u64,
-        challenge_locator: Locator,
-        response_locator: Locator,
-        next_challenge_locator: Locator,
+        response_locator: &Locator,
+        next_challenge_locator: &Locator,
+        response_hash: &[u8],
     ) -> Result<(), CoordinatorError> {
-        // Check that the previous and current locators exist in storage.
-        if !storage.exists(&challenge_locator) || !storage.exists(&response_locator) {
-            return Err(CoordinatorError::ContributionLocatorMissing);
-        }
-
-        // Execute ceremony verification on chunk.
-        let settings = environment.parameters();
-        let result = match settings.curve() {
-            CurveKind::Bls12_377 => Self::transform_pok_and_correctness(
-                environment,
-                storage.reader(&challenge_locator)?.as_ref(),
-                storage.reader(&response_locator)?.as_ref(),
-                &phase1_chunked_parameters!(Bls12_377, settings, chunk_id),
-            ),
-            CurveKind::BW6 => Self::transform_pok_and_correctness(
-                environment,
-                storage.reader(&challenge_locator)?.as_ref(),
-                storage.reader(&response_locator)?.as_ref(),
-                &phase1_chunked_parameters!(BW6_761, settings, chunk_id),
-            ),
-        };
-        let response_hash = match result {
-            Ok(response_hash) => response_hash,
-            Err(error) => {
-                error!("Verification failed with {}", error);
-                return Err(CoordinatorError::VerificationFailed.into());
-            }
-        };
-
         trace!("Verification succeeded! Writing the next challenge file");
 
         // Fetch the compression settings.
+        let settings = environment.parameters();
         let response_is_compressed = environment.compressed_outputs();
         let next_challenge_is_compressed = environment.compressed_inputs();
 
@@ -193,14 +316,14 @@ impl Verification {
         let next_challenge_hash = if response_is_compressed == next_challenge_is_compressed {
             // TODO (howardwu): Update this.
             trace!("Copying decompressed response file without the public key");
-            storage.copy(&response_locator, &next_challenge_locator)?;
+            storage.copy(response_locator, next_challenge_locator)?;
 
-            calculate_hash(&storage.reader(&next_challenge_locator)?)
+            calculate_hash(&storage.reader(next_challenge_locator)?)
         } else {
             trace!("Starting decompression of the response file for the next challenge file");
 
             // Initialize the next contribution locator, if it does not exist.
-            if !storage.exists(&next_challenge_locator) {
+            if !storage.exists(next_challenge_locator) {
                 storage.initialize(
                     next_challenge_locator.clone(),
                     Object::contribution_file_size(environment, chunk_id, true),
@@ -209,20 +332,20 @@ impl Verification {
 
             match settings.curve() {
                 CurveKind::Bls12_377 => Self::decompress(
-                    storage.reader(&response_locator)?.as_ref(),
-                    storage.writer(&next_challenge_locator)?.as_mut(),
-                    response_hash.as_ref(),
+                    storage.reader(response_locator)?.as_ref(),
+                    storage.writer(next_challenge_locator)?.as_mut(),
+                    response_hash,
                     &phase1_chunked_parameters!(Bls12_377, settings, chunk_id),
                 )?,
                 CurveKind::BW6 => Self::decompress(
-                    storage.reader(&response_locator)?.as_ref(),
-                    storage.writer(&next_challenge_locator)?.as_mut(),
-                    response_hash.as_ref(),
+                    storage.reader(response_locator)?.as_ref(),
+                    storage.writer(next_challenge_locator)?.as_mut(),
+                    response_hash,
                     &phase1_chunked_parameters!(BW6_761, settings, chunk_id),
                 )?,
             };
 
-            calculate_hash(storage.reader(&next_challenge_locator)?.as_ref())
+            calculate_hash(storage.reader(next_challenge_locator)?.as_ref())
         };
 
         debug!("The next challenge hash is {}", pretty_hash!(&next_challenge_hash));
@@ -230,17 +353,16 @@ impl Verification {
         {
             // Fetch the saved response hash in the next challenge file.
             let saved_response_hash = storage
-                .reader(&next_challenge_locator)?
+                .reader(next_challenge_locator)?
                 .as_ref()
-                .chunks(64)
-                .next()
-                .unwrap()
+                .get(0..64)
+                .ok_or(CoordinatorError::StorageReaderFailed)?
                 .to_vec();
 
             // Check that the response hash matches the next challenge hash.
             debug!("The response hash is {}", pretty_hash!(&response_hash));
             debug!("The saved response hash is {}", pretty_hash!(&saved_response_hash));
-            if response_hash.as_slice() != saved_response_hash {
+            if response_hash != saved_response_hash.as_slice() {
                 error!("Response hash does not match the saved response hash.");
                 return Err(CoordinatorError::ContributionHashMismatch);
             }
@@ -255,6 +377,7 @@ impl Verification {
         challenge_reader: &[u8],
         response_reader: &[u8],
         parameters: &Phase1Parameters<T>,
+        cancellation: &Cancellation,
     ) -> Result<GenericArray<u8, U64>, CoordinatorError> {
         debug!("Verifying 2^{} powers of tau", parameters.total_size_in_log2);
 
@@ -292,7 +415,7 @@ impl Verification {
         // trace!("Public key of the contributor is {:#?}", public_key);
 
         trace!("Starting verification");
-        Phase1::verification(
+        Phase1::verification_with_cancellation(
             challenge_reader,
             response_reader,
             &public_key,
@@ -302,6 +425,7 @@ impl Verification {
             CheckForCorrectness::No,
             CheckForCorrectness::Full,
             &parameters,
+            cancellation,
         )?;
         trace!("Completed verification");
 
@@ -342,7 +466,9 @@ mod tests {
         storage::{ContributionLocator, ContributionSignatureLocator, Locator, Object, StorageLock},
         testing::prelude::*,
         Coordinator,
+        CoordinatorError,
     };
+    use phase1::helpers::buffers::Cancellation;
 
     use chrono::Utc;
     use once_cell::sync::Lazy;
@@ -427,6 +553,7 @@ mod tests {
                 response_locator,
                 contribution_file_signature_locator,
                 &seed,
+                &Cancellation::new(),
             )
             .unwrap();
 
@@ -440,6 +567,7 @@ mod tests {
                 chunk_id,
                 1,
                 is_final,
+                &Cancellation::new(),
             )
             .unwrap();
 
@@ -453,4 +581,209 @@ mod tests {
             assert!(storage.exists(&next));
         }
     }
+
+    #[test]
+    #[serial]
+    fn test_verification_run_truncated_response() {
+        initialize_test_environment(&TEST_ENVIRONMENT_3);
+
+        let coordinator = Coordinator::new(TEST_ENVIRONMENT_3.clone(), Box::new(Dummy)).unwrap();
+        let test_storage = coordinator.storage();
+
+        let contributor = Lazy::force(&TEST_CONTRIBUTOR_ID).clone();
+        let contributor_signing_key = "secret_key".to_string();
+
+        let verifier = Lazy::force(&TEST_VERIFIER_ID).clone();
+        let verifier_signing_key = "secret_key".to_string();
+
+        {
+            // Acquire the storage write lock.
+            let mut storage = StorageLock::Write(test_storage.write().unwrap());
+
+            // Run initialization.
+            coordinator.run_initialization(&mut storage, Utc::now()).unwrap();
+
+            let contributors = vec![contributor.clone()];
+            let verifiers = vec![verifier.clone()];
+            coordinator
+                .next_round(&mut storage, *TEST_STARTED_AT, contributors, verifiers)
+                .unwrap();
+        }
+
+        // Define test parameters.
+        let round_height = coordinator.current_round_height().unwrap();
+        let chunk_id = 0;
+
+        // Obtain the storage lock.
+        let mut storage = StorageLock::Write(test_storage.write().unwrap());
+
+        // Fetch the challenge locator.
+        let challenge_locator = &Locator::ContributionFile(ContributionLocator::new(round_height, chunk_id, 0, true));
+        // Fetch the response locator.
+        let response_locator = &Locator::ContributionFile(ContributionLocator::new(round_height, chunk_id, 1, false));
+        // Fetch the contribution file signature locator.
+        let contribution_file_signature_locator =
+            &Locator::ContributionFileSignature(ContributionSignatureLocator::new(round_height, chunk_id, 1, false));
+
+        let expected_filesize = Object::contribution_file_size(&TEST_ENVIRONMENT_3, chunk_id, false);
+        storage.initialize(response_locator.clone(), expected_filesize).unwrap();
+        storage
+            .initialize(
+                contribution_file_signature_locator.clone(),
+                Object::contribution_file_signature_size(false),
+            )
+            .unwrap();
+
+        // Run computation on chunk.
+        let mut seed: Seed = [0; SEED_LENGTH];
+        rand::thread_rng().fill_bytes(&mut seed[..]);
+        Computation::run(
+            &TEST_ENVIRONMENT_3,
+            &mut storage,
+            coordinator.signature(),
+            &contributor_signing_key,
+            challenge_locator,
+            response_locator,
+            contribution_file_signature_locator,
+            &seed,
+            &Cancellation::new(),
+        )
+        .unwrap();
+
+        // Truncate the response file out from under the coordinator, while its verification is pending.
+        std::fs::OpenOptions::new()
+            .write(true)
+            .open(storage.to_path(response_locator).unwrap().as_path())
+            .unwrap()
+            .set_len(expected_filesize / 2)
+            .unwrap();
+
+        // Run verification on chunk, which should fail instead of crashing.
+        let result = Verification::run(
+            &TEST_ENVIRONMENT_3,
+            &mut storage,
+            coordinator.signature(),
+            &verifier_signing_key,
+            round_height,
+            chunk_id,
+            1,
+            true,
+            &Cancellation::new(),
+        );
+        assert!(matches!(result, Err(CoordinatorError::StorageMappedSizeMismatch)));
+
+        // Check the next challenge file was not created.
+        let next = Locator::ContributionFile(ContributionLocator::new(round_height + 1, chunk_id, 0, true));
+        assert!(!storage.exists(&next));
+
+        // Check the coordinator is still responsive.
+        drop(storage);
+        assert_eq!(round_height, coordinator.current_round_height().unwrap());
+    }
+
+    #[test]
+    #[serial]
+    fn test_verification_run_cancelled() {
+        initialize_test_environment(&TEST_ENVIRONMENT_3);
+
+        let coordinator = Coordinator::new(TEST_ENVIRONMENT_3.clone(), Box::new(Dummy)).unwrap();
+        let test_storage = coordinator.storage();
+
+        let contributor = Lazy::force(&TEST_CONTRIBUTOR_ID).clone();
+        let contributor_signing_key = "secret_key".to_string();
+
+        let verifier = Lazy::force(&TEST_VERIFIER_ID).clone();
+        let verifier_signing_key = "secret_key".to_string();
+
+        {
+            // Acquire the storage write lock.
+            let mut storage = StorageLock::Write(test_storage.write().unwrap());
+
+            // Run initialization.
+            coordinator.run_initialization(&mut storage, Utc::now()).unwrap();
+
+            let contributors = vec![contributor.clone()];
+            let verifiers = vec![verifier.clone()];
+            coordinator
+                .next_round(&mut storage, *TEST_STARTED_AT, contributors, verifiers)
+                .unwrap();
+        }
+
+        // Define test parameters.
+        let round_height = coordinator.current_round_height().unwrap();
+        let chunk_id = 0;
+
+        // Obtain the storage lock.
+        let mut storage = StorageLock::Write(test_storage.write().unwrap());
+
+        // Fetch the challenge locator.
+        let challenge_locator = &Locator::ContributionFile(ContributionLocator::new(round_height, chunk_id, 0, true));
+        // Fetch the response locator.
+        let response_locator = &Locator::ContributionFile(ContributionLocator::new(round_height, chunk_id, 1, false));
+        // Fetch the contribution file signature locator.
+        let contribution_file_signature_locator =
+            &Locator::ContributionFileSignature(ContributionSignatureLocator::new(round_height, chunk_id, 1, false));
+
+        let expected_filesize = Object::contribution_file_size(&TEST_ENVIRONMENT_3, chunk_id, false);
+        storage.initialize(response_locator.clone(), expected_filesize).unwrap();
+        storage
+            .initialize(
+                contribution_file_signature_locator.clone(),
+                Object::contribution_file_signature_size(false),
+            )
+            .unwrap();
+
+        // Run computation on chunk.
+        let mut seed: Seed = [0; SEED_LENGTH];
+        rand::thread_rng().fill_bytes(&mut seed[..]);
+        Computation::run(
+            &TEST_ENVIRONMENT_3,
+            &mut storage,
+            coordinator.signature(),
+            &contributor_signing_key,
+            challenge_locator,
+            response_locator,
+            contribution_file_signature_locator,
+            &seed,
+            &Cancellation::new(),
+        )
+        .unwrap();
+        let response = storage.reader(response_locator).unwrap().as_ref().to_vec();
+
+        // Run verification on chunk with a cancelled flag, which should stop with the cancelled error.
+        let cancellation = Cancellation::new();
+        cancellation.cancel();
+        let result = Verification::run(
+            &TEST_ENVIRONMENT_3,
+            &mut storage,
+            coordinator.signature(),
+            &verifier_signing_key,
+            round_height,
+            chunk_id,
+            1,
+            true,
+            &cancellation,
+        );
+        assert!(matches!(result, Err(CoordinatorError::Cancelled)));
+
+        // Check the next challenge file was not created, and the response is unchanged.
+        let next = Locator::ContributionFile(ContributionLocator::new(round_height + 1, chunk_id, 0, true));
+        assert!(!storage.exists(&next));
+        assert_eq!(response, storage.reader(response_locator).unwrap().as_ref().to_vec());
+
+        // Check the verification completes once it is not cancelled.
+        Verification::run(
+            &TEST_ENVIRONMENT_3,
+            &mut storage,
+            coordinator.signature(),
+            &verifier_signing_key,
+            round_height,
+            chunk_id,
+            1,
+            true,
+            &Cancellation::new(),
+        )
+        .unwrap();
+        assert!(storage.exists(&next));
+    }
 }
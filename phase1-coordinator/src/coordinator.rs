@@ -4,44 +4,94 @@
 
 use crate::{
     authentication::Signature,
-    commands::{Aggregation, Initialization},
+    commands::{Aggregation, Initialization, Practice},
     coordinator_state::{
         CeremonyStorageAction,
+        ContributionReservation,
         CoordinatorState,
+        DropCurrentParticpantData,
         DropParticipant,
         ParticipantInfo,
+        ParticipantProgress,
         ResetCurrentRoundStorageAction,
         RoundMetrics,
     },
-    environment::{Deployment, Environment},
-    objects::{participant::*, task::TaskInitializationError, ContributionFileSignature, LockedLocators, Round, Task},
+    environment::{CoordinatorMode, Deployment, Environment},
+    objects::{
+        participant::*,
+        task::TaskInitializationError,
+        AdmissionCredentials,
+        Chunk,
+        ChunkLock,
+        ChunkingReport,
+        ClientVersion,
+        ContributionFileSignature,
+        CoordinatorEvent,
+        HardwareDeclaration,
+        LockedLocators,
+        PracticeFeedback,
+        ProofOfWorkChallenge,
+        ChunkVerificationMetadata,
+        Round,
+        RoundHistory,
+        RoundStatistics,
+        RoundVerificationMetadata,
+        Task,
+        TranscriptArtifact,
+        TranscriptManifest,
+        VerificationTiming,
+        Version,
+    },
     storage::{ContributionLocator, ContributionSignatureLocator, Locator, LocatorPath, Object, Storage, StorageLock},
 };
+use phase1::helpers::buffers::Cancellation;
 use setup_utils::calculate_hash;
 
 use chrono::{DateTime, Utc};
 use std::{
+    collections::{BTreeMap, HashMap},
     fmt,
-    sync::{Arc, RwLock},
+    io::{Cursor, Read, Write},
+    ops::Bound,
+    sync::{Arc, Mutex, RwLock, RwLockWriteGuard},
+    time::Instant,
 };
+use tokio::sync::broadcast;
 use tracing::*;
 
+/// The number of events buffered for each subscriber. A subscriber which falls
+/// further behind misses the oldest events, rather than holding up the coordinator.
+const EVENT_CHANNEL_CAPACITY: usize = 1024;
+
 #[derive(Debug)]
 pub enum CoordinatorError {
+    AdmissionPolicyMissing,
     AggregateContributionFileSizeMismatch,
+    Cancelled,
+    CeremonyPaused,
     ChallengeHashSizeInvalid,
     ChunkAlreadyComplete,
+    ChunkAlreadyContributed,
     ChunkAlreadyVerified,
     ChunkIdAlreadyAdded,
     ChunkIdInvalid,
     ChunkIdMismatch,
     ChunkIdMissing,
+    ChunkIdOutOfRange { chunk_id: u64, num_chunks: u64 },
     ChunkLockAlreadyAcquired,
     ChunkLockLimitReached,
+    ChunkLockTokenMismatch,
     ChunkMissing,
     ChunkMissingVerification,
     ChunkCannotLockZeroContributions { chunk_id: u64 },
     ChunkNotLockedOrByWrongParticipant,
+    ChunkReinitializationUnsupported,
+    ClientVersionInvalid(String),
+    ClientVersionUnsupported {
+        client: ClientVersion,
+        min_version: Version,
+        max_version: Option<Version>,
+    },
     ComputationFailed,
     CompressedContributionHashingUnsupported,
     ContributorPendingTasksCannotBeEmpty(Participant),
@@ -55,6 +105,11 @@ pub enum CoordinatorError {
     ContributionIdIsNonzero,
     ContributionIdMismatch,
     ContributionIdMustBeNonzero,
+    ContributionIdOutOfRange {
+        chunk_id: u64,
+        contribution_id: u64,
+        num_contributions: u64,
+    },
     ContributionLocatorAlreadyExists,
     ContributionLocatorIncorrect,
     ContributionLocatorMissing,
@@ -70,6 +125,7 @@ pub enum CoordinatorError {
     ContributorSignatureInvalid,
     ContributorsMissing,
     CoordinatorContributorMissing,
+    CoordinatorReadOnly { primary_url: String },
     CoordinatorStateNotInitialized,
     CurrentRoundAggregating,
     CurrentRoundAggregated,
@@ -83,6 +139,10 @@ pub enum CoordinatorError {
     InitializationFailed,
     InitializationTranscriptsDiffer,
     Integer(std::num::ParseIntError),
+    InvitationConsumed,
+    InvitationInvalid,
+    InvitationKeyMissing,
+    InvitationMissing,
     IOError(std::io::Error),
     Hex(hex::FromHexError),
     JsonError(serde_json::Error),
@@ -117,6 +177,12 @@ pub enum CoordinatorError {
     ParticipantBanned,
     ParticipantDidNotDoWork,
     ParticipantDidntLockChunkId,
+    ParticipantHardwareBelowMinimum {
+        requirement: &'static str,
+        declared: u64,
+        minimum: u64,
+    },
+    ParticipantHardwareUndeclared,
     ParticipantHasAssignedTasks,
     ParticipantHasLockedMaximumChunks,
     ParticipantHasNotStarted,
@@ -142,10 +208,26 @@ pub enum CoordinatorError {
     ParticipantWasDropped,
     PendingTasksMustContainResponseTask { response_task: Task },
     Phase1Setup(setup_utils::Error),
+    PracticeChallengeExpired,
+    PracticeChallengeNotRequested,
+    PracticeModeDisabled,
+    PracticeResponseSizeMismatch,
+    ProofOfWorkChallengeConsumed,
+    ProofOfWorkChallengeExpired,
+    ProofOfWorkChallengeInvalid,
+    ProofOfWorkChallengeMissing,
+    ProofOfWorkInsufficient,
+    ProofOfWorkKeyMissing,
+    ProofOfWorkMissing,
     QueueIsEmpty,
     QueueWaitTimeIncomplete,
+    RequiredVerificationsInvalid {
+        required_verifications: u64,
+        maximum_verifiers: usize,
+    },
     ResponseHashSizeInvalid,
     RoundAggregationFailed,
+    RoundAggregationModeUnsupported,
     RoundAlreadyInitialized,
     RoundAlreadyAggregated,
     RoundCommitFailedOrCorrupted,
@@ -156,6 +238,10 @@ pub enum CoordinatorError {
     RoundDoesNotExist,
     RoundFileMissing,
     RoundFileSizeMismatch,
+    RoundFormatVersionUnsupported {
+        format_version: u64,
+    },
+    RoundHeightInconsistent,
     RoundHeightIsZero,
     RoundHeightMismatch,
     RoundHeightNotSet,
@@ -172,9 +258,16 @@ pub enum CoordinatorError {
     RoundUpdateCorruptedStateOfVerifiers,
     RoundVerifiersMissing,
     RoundVerifiersNotUnique,
+    SetupAlreadyAdded,
+    SetupInvalid,
+    SetupStorageShared,
     SignatureSchemeIsInsecure,
     StateLockFailed,
     StorageCopyFailed,
+    StorageDecryptionFailed,
+    StorageEncryptionFailed,
+    StorageEncryptionKeyInvalid,
+    StorageEncryptionKeyMissing,
     StorageFailed,
     StorageInitializationFailed,
     StorageLocatorAlreadyExists,
@@ -183,16 +276,21 @@ pub enum CoordinatorError {
     StorageLocatorMissing,
     StorageLocatorNotOpen,
     StorageLockFailed,
+    StorageMappedSizeMismatch,
+    StorageObjectStreamConsumed,
+    StorageObjectStreamTooShort,
     StorageReaderFailed,
     StorageSizeLookupFailed,
     StorageUpdateFailed,
     TaskInitializationFailed(TaskInitializationError),
     PreviousContributionMissing { current_task: Task },
+    TranscriptForkDetected,
     TryFromSliceError(std::array::TryFromSliceError),
     UnauthorizedChunkContributor,
     UnauthorizedChunkVerifier,
     VerificationFailed,
     VerificationOnContributionIdZero,
+    VerifierIsSoleVerifier,
     VerifierMissing,
     VerifierSignatureInvalid,
     VerifiersMissing,
@@ -319,6 +417,71 @@ impl TimeSource for MockTimeSource {
     }
 }
 
+/// The outcome of planning the transition to the next round, as computed by
+/// [Coordinator::plan_next_round] without modifying the coordinator.
+#[derive(Debug)]
+pub struct RoundPlan {
+    /// The height of the round the coordinator would advance to.
+    next_round_height: u64,
+    /// The contributors that would be selected from the queue.
+    contributors: Vec<Participant>,
+    /// The verifiers that would be selected from the queue.
+    verifiers: Vec<Participant>,
+    /// The expected number of bytes the next round writes to storage.
+    estimated_storage_bytes: u64,
+    /// The expected number of seconds to aggregate the next round, if it can be estimated.
+    estimated_aggregation_seconds: Option<u64>,
+    /// The errors preventing the transition, in the order it would encounter them.
+    blockers: Vec<CoordinatorError>,
+}
+
+impl RoundPlan {
+    /// Returns the height of the round the coordinator would advance to.
+    #[inline]
+    pub fn next_round_height(&self) -> u64 {
+        self.next_round_height
+    }
+
+    /// Returns the contributors that would be selected from the queue.
+    #[inline]
+    pub fn contributors(&self) -> &[Participant] {
+        &self.contributors
+    }
+
+    /// Returns the verifiers that would be selected from the queue.
+    #[inline]
+    pub fn verifiers(&self) -> &[Participant] {
+        &self.verifiers
+    }
+
+    /// Returns the expected number of bytes the next round writes to storage,
+    /// including the contributions of every chunk and the aggregated round file.
+    #[inline]
+    pub fn estimated_storage_bytes(&self) -> u64 {
+        self.estimated_storage_bytes
+    }
+
+    /// Returns the expected number of seconds to aggregate the next round, which is
+    /// the time the coordinator took to aggregate the current round, if it is known.
+    #[inline]
+    pub fn estimated_aggregation_seconds(&self) -> Option<u64> {
+        self.estimated_aggregation_seconds
+    }
+
+    /// Returns the errors preventing the transition. The first blocker is
+    /// the error an attempt to advance the round would return.
+    #[inline]
+    pub fn blockers(&self) -> &[CoordinatorError] {
+        &self.blockers
+    }
+
+    /// Returns `true` if nothing prevents the transition to the next round.
+    #[inline]
+    pub fn is_ready(&self) -> bool {
+        self.blockers.is_empty()
+    }
+}
+
 /// A core structure for operating the Phase 1 ceremony. This struct
 /// is designed to be [Send] + [Sync]. The state of the ceremony is
 /// stored in a [CoordinatorState] object.
@@ -334,6 +497,16 @@ pub struct Coordinator {
     state: Arc<RwLock<CoordinatorState>>,
     /// The source of time, allows mocking system time for testing.
     time: Arc<dyn TimeSource>,
+    /// The time each participant was last served the practice challenge,
+    /// with at most one session per participant.
+    practice_sessions: Arc<RwLock<HashMap<Participant, DateTime<Utc>>>>,
+    /// The locks held while a contribution of each chunk is verified, so that
+    /// verifications of different chunks may run concurrently.
+    chunk_locks: Arc<Mutex<HashMap<u64, Arc<Mutex<()>>>>>,
+    /// The channel of events published as the ceremony changes.
+    events: broadcast::Sender<CoordinatorEvent>,
+    /// The flag to cancel the computations and verifications in progress on shutdown.
+    cancellation: Cancellation,
 }
 
 impl Coordinator {
@@ -359,10 +532,17 @@ impl Coordinator {
         // Load an instance of storage.
         let storage = environment.storage()?;
         // Load an instance of coordinator state.
-        let state = match storage.get(&Locator::CoordinatorState)? {
+        let mut state = match storage.get(&Locator::CoordinatorState)? {
             Object::CoordinatorState(state) => state,
             _ => return Err(CoordinatorError::StorageFailed),
         };
+        state.restore_secrets(&environment);
+
+        // Check that every contribution can reach the required number of verifications.
+        environment.validate_required_verifications()?;
+
+        // Check that storage holds a single transcript of this ceremony.
+        Self::check_transcript_integrity(&environment, &*storage, &state)?;
 
         Ok(Self {
             environment: environment.clone(),
@@ -370,9 +550,122 @@ impl Coordinator {
             storage: Arc::new(RwLock::new(storage)),
             state: Arc::new(RwLock::new(state)),
             time,
+            practice_sessions: Arc::new(RwLock::new(HashMap::new())),
+            chunk_locks: Arc::new(Mutex::new(HashMap::new())),
+            events: broadcast::channel(EVENT_CHANNEL_CAPACITY).0,
+            cancellation: Cancellation::new(),
         })
     }
 
+    ///
+    /// Checks that the transcript in storage belongs to this ceremony and has
+    /// not been forked, for example by restoring a backup from a different run.
+    ///
+    /// Returns `CoordinatorError::TranscriptForkDetected` if the ceremony ID in storage
+    /// does not match the environment, if a round state exists beyond the stored round
+    /// height, or if the initial contribution of a chunk in any round after the first was
+    /// not computed on the final response signed for that chunk in the round before it,
+    /// including when that signature is missing.
+    ///
+    fn check_transcript_integrity(
+        environment: &Environment,
+        storage: &dyn Storage,
+        state: &CoordinatorState,
+    ) -> Result<(), CoordinatorError> {
+        // Check that the ceremony ID in storage matches the environment.
+        if let (Some(expected), Some(ceremony_id)) = (environment.ceremony_id(), state.ceremony_id()) {
+            if expected != ceremony_id {
+                error!(
+                    "Storage in {} belongs to ceremony {}, but ceremony {} was expected. \
+                    Check that the base directory points to the transcript of this ceremony.",
+                    environment.local_base_directory(),
+                    ceremony_id,
+                    expected
+                );
+                return Err(CoordinatorError::TranscriptForkDetected);
+            }
+        }
+
+        // Fetch the current round height, if the ceremony has been initialized.
+        let round_height = match storage.get(&Locator::RoundHeight) {
+            Ok(Object::RoundHeight(round_height)) => round_height,
+            _ => return Ok(()),
+        };
+
+        // Check that no round state exists beyond the stored round height.
+        let next_round_height = round_height + 1;
+        if storage.exists(&Locator::RoundState {
+            round_height: next_round_height,
+        }) {
+            error!(
+                "Round {} exists in storage, but the stored round height is {}. \
+                Restore the round height and the round directories from the same backup.",
+                next_round_height, round_height
+            );
+            return Err(CoordinatorError::TranscriptForkDetected);
+        }
+
+        // Round 1 starts from the initialization in round 0, which has no contributions to chain from,
+        // so each round from round 2 is checked against the round before it.
+        for round_height in 2..=round_height {
+            // Fetch the previous round, to find the final contribution of each chunk.
+            let previous_round = match storage.get(&Locator::RoundState {
+                round_height: round_height - 1,
+            })? {
+                Object::RoundState(round) => round,
+                _ => return Err(CoordinatorError::StorageFailed),
+            };
+            let final_contribution_id = previous_round.expected_number_of_contributions() - 1;
+
+            // Check that the initial contribution of each chunk starts with the hash of
+            // the final response to that chunk, as signed in the previous round.
+            for chunk_id in 0..environment.number_of_chunks() {
+                let signature_locator = Locator::ContributionFileSignature(ContributionSignatureLocator::new(
+                    round_height - 1,
+                    chunk_id,
+                    final_contribution_id,
+                    false,
+                ));
+                if !storage.exists(&signature_locator) {
+                    error!(
+                        "Chunk {} of round {} has no signature of its final response. \
+                        Restore the round {} directory from the same backup as the other rounds.",
+                        chunk_id,
+                        round_height - 1,
+                        round_height - 1
+                    );
+                    return Err(CoordinatorError::TranscriptForkDetected);
+                }
+                let response_hash = match storage.get(&signature_locator)? {
+                    Object::ContributionFileSignature(signature) => hex::decode(signature.get_response_hash())?,
+                    _ => return Err(CoordinatorError::StorageFailed),
+                };
+
+                let contribution_locator =
+                    Locator::ContributionFile(ContributionLocator::new(round_height, chunk_id, 0, true));
+                let saved_response_hash = storage
+                    .reader(&contribution_locator)?
+                    .get(0..64)
+                    .ok_or(CoordinatorError::StorageReaderFailed)?
+                    .to_vec();
+
+                if response_hash != saved_response_hash {
+                    error!(
+                        "Chunk {} of round {} was not computed on the final response of round {}. \
+                        Restore the round {} directory from the same backup as the previous rounds.",
+                        chunk_id,
+                        round_height,
+                        round_height - 1,
+                        round_height
+                    );
+                    return Err(CoordinatorError::TranscriptForkDetected);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     ///
     /// Runs a set of operations to initialize state and start the coordinator.
     ///
@@ -387,9 +680,10 @@ impl Coordinator {
         info!("{:#?}", self.environment.parameters());
 
         // Ensure the ceremony is initialized, if it has not started yet.
-        {
+        // A read-only coordinator leaves this to the primary coordinator.
+        if !self.environment.is_read_only() {
             // Acquire the storage write lock.
-            let mut storage = StorageLock::Write(self.storage.write().unwrap());
+            let mut storage = self.write_storage()?;
 
             // Check if the ceremony has been initialized yet.
             if Self::load_current_round_height(&storage).is_err() {
@@ -398,7 +692,7 @@ impl Coordinator {
                 info!("Initialized ceremony");
 
                 // Acquire the state write lock.
-                let mut state = self.state.write().unwrap();
+                let mut state = self.write_state()?;
 
                 // Initialize the coordinator state to round 0.
                 state.initialize(round_height);
@@ -412,24 +706,80 @@ impl Coordinator {
 
         info!("Current round height is {}", current_round_height);
         info!("{}", serde_json::to_string_pretty(&self.current_round()?)?);
+
+        // Regenerate the practice challenge, if practice mode is enabled.
+        if self.environment.practice_power().is_some() && !self.environment.is_read_only() {
+            // Acquire the storage write lock.
+            let mut storage = self.write_storage()?;
+
+            info!("Initializing the practice challenge");
+            Practice::initialize(&self.environment, &mut storage)?;
+            info!("Initialized the practice challenge");
+        }
+
         info!("Coordinator has booted up");
 
         Ok(())
     }
 
+    ///
+    /// Returns `CoordinatorReadOnly` if the coordinator is read-only,
+    /// as mutations are handled by the primary coordinator.
+    ///
+    #[inline]
+    fn check_writable(&self) -> Result<(), CoordinatorError> {
+        match self.environment.coordinator_mode() {
+            CoordinatorMode::Primary => Ok(()),
+            CoordinatorMode::ReadOnly { primary_url } => Err(CoordinatorError::CoordinatorReadOnly {
+                primary_url: primary_url.clone(),
+            }),
+        }
+    }
+
+    ///
+    /// Acquires the storage write lock, for a mutation of the ceremony.
+    ///
+    /// The mutations of the coordinator acquire the storage and state write locks only through
+    /// this function and `write_state`, so a read-only coordinator rejects every mutation with
+    /// `CoordinatorReadOnly` before it changes anything. Storage rejects the writes of a
+    /// read-only coordinator as well.
+    ///
+    #[inline]
+    fn write_storage(&self) -> Result<StorageLock, CoordinatorError> {
+        self.check_writable()?;
+        Ok(StorageLock::Write(self.storage.write().unwrap()))
+    }
+
+    ///
+    /// Acquires the state write lock, for a mutation of the coordinator state.
+    ///
+    /// A read-only coordinator rejects the mutation with `CoordinatorReadOnly`, so its
+    /// state does not diverge from the primary coordinator, see `write_storage`.
+    ///
+    #[inline]
+    fn write_state(&self) -> Result<RwLockWriteGuard<CoordinatorState>, CoordinatorError> {
+        self.check_writable()?;
+        Ok(self.state.write().unwrap())
+    }
+
     ///
     /// Runs a set of operations to update the coordinator state to reflect
     /// newly finished, dropped, or banned participants.
     ///
     #[inline]
     pub fn update(&self) -> Result<(), CoordinatorError> {
+        // A read-only coordinator only refreshes its view of storage.
+        if self.environment.is_read_only() {
+            return self.refresh();
+        }
+
         // Process ceremony updates for the current round and queue.
         let (is_current_round_finished, is_current_round_aggregated) = {
             // Acquire the storage write lock.
-            let mut storage = StorageLock::Write(self.storage.write().unwrap());
+            let mut storage = self.write_storage()?;
 
             // Acquire the state write lock.
-            let mut state = self.state.write().unwrap();
+            let mut state = self.write_state()?;
 
             info!("\n{}", state.status_report(self.time.as_ref()));
 
@@ -460,6 +810,13 @@ impl Coordinator {
             state.update_banned_participants()?;
             state.save(&mut storage)?;
 
+            // Record the time of this update, so monitors can check that storage is writable.
+            let health = Object::Health(self.time.utc_now());
+            match storage.exists(&Locator::Health) {
+                true => storage.update(&Locator::Health, health)?,
+                false => storage.insert(Locator::Health, health)?,
+            }
+
             // Check if the current round is finished and if the current round is aggregated.
             (state.is_current_round_finished(), state.is_current_round_aggregated())
         };
@@ -473,10 +830,10 @@ impl Coordinator {
                 self.try_aggregate()?;
 
                 // Acquire the storage write lock.
-                let mut storage = StorageLock::Write(self.storage.write().unwrap());
+                let mut storage = self.write_storage()?;
 
                 // Acquire the state write lock.
-                let mut state = self.state.write().unwrap();
+                let mut state = self.write_state()?;
 
                 // Update the metrics for the current round and participants.
                 state.update_round_metrics();
@@ -524,6 +881,169 @@ impl Coordinator {
         Ok(())
     }
 
+    ///
+    /// Reloads storage and the coordinator state from disk, to observe
+    /// the changes made by the primary coordinator of a read-only coordinator.
+    ///
+    #[inline]
+    pub fn refresh(&self) -> Result<(), CoordinatorError> {
+        // Acquire the storage write lock.
+        let mut storage = self.storage.write().unwrap();
+
+        // Reload the manifest and the opened locators.
+        storage.refresh()?;
+
+        // Reload the coordinator state.
+        let mut refreshed = match storage.get(&Locator::CoordinatorState)? {
+            Object::CoordinatorState(state) => state,
+            _ => return Err(CoordinatorError::StorageFailed),
+        };
+        refreshed.restore_secrets(&self.environment);
+        *self.state.write().unwrap() = refreshed;
+
+        debug!("Refreshed the coordinator view of storage");
+        Ok(())
+    }
+
+    ///
+    /// Returns the time of the last update of the coordinator which was written to storage,
+    /// or `None` if the coordinator has not been updated yet.
+    ///
+    /// The time is stored in the health file, whose modification time external monitors
+    /// can also check to confirm that storage is still writable.
+    ///
+    #[inline]
+    pub fn storage_health(&self) -> Result<Option<DateTime<Utc>>, CoordinatorError> {
+        // Acquire the storage read lock.
+        let storage = StorageLock::Read(self.storage.read().unwrap());
+
+        if !storage.exists(&Locator::Health) {
+            return Ok(None);
+        }
+        match storage.get(&Locator::Health)? {
+            Object::Health(updated_at) => Ok(Some(updated_at)),
+            _ => Err(CoordinatorError::StorageFailed),
+        }
+    }
+
+    ///
+    /// Returns the practice challenge, and records the time it was served to the given participant.
+    ///
+    /// The practice challenge is independent of the round state and the queue,
+    /// so any participant may fetch it at any time to check their setup.
+    /// Fetching the practice challenge again restarts the session of the participant.
+    ///
+    #[inline]
+    pub fn practice_challenge(&self, participant: &Participant) -> Result<Vec<u8>, CoordinatorError> {
+        // Check that practice mode is enabled.
+        if self.environment.practice_power().is_none() {
+            return Err(CoordinatorError::PracticeModeDisabled);
+        }
+
+        // Fetch the practice challenge from storage.
+        let challenge = {
+            // Acquire the storage read lock.
+            let storage = StorageLock::Read(self.storage.read().unwrap());
+            match storage.get(&Locator::PracticeChallenge)? {
+                Object::PracticeChallenge(challenge) => challenge,
+                _ => return Err(CoordinatorError::StorageFailed),
+            }
+        };
+
+        // Acquire the practice sessions lock.
+        let now = self.time.utc_now();
+        let timeout = self.practice_session_timeout();
+        let mut practice_sessions = self.practice_sessions.write().unwrap();
+
+        // Expire the sessions of participants who never sent a practice response.
+        practice_sessions.retain(|_, served_at| now - *served_at <= timeout);
+
+        // Record the time the practice challenge was served.
+        practice_sessions.insert(participant.clone(), now);
+
+        Ok(challenge)
+    }
+
+    ///
+    /// Verifies the given practice response from the given participant,
+    /// and returns feedback on whether their machine is fast enough
+    /// to contribute to the real parameters.
+    ///
+    /// The participant must have fetched the practice challenge beforehand,
+    /// and must fetch it again before each subsequent practice response.
+    ///
+    #[inline]
+    pub fn practice_response(
+        &self,
+        participant: &Participant,
+        response: &[u8],
+    ) -> Result<PracticeFeedback, CoordinatorError> {
+        // Check that practice mode is enabled.
+        let practice_power = self
+            .environment
+            .practice_power()
+            .ok_or(CoordinatorError::PracticeModeDisabled)?;
+
+        // Fetch the time the practice challenge was served to the participant.
+        let served_at = self
+            .practice_sessions
+            .write()
+            .unwrap()
+            .remove(participant)
+            .ok_or(CoordinatorError::PracticeChallengeNotRequested)?;
+        let elapsed = self.time.utc_now() - served_at;
+
+        // Check that the practice session has not expired.
+        if elapsed > self.practice_session_timeout() {
+            return Err(CoordinatorError::PracticeChallengeExpired);
+        }
+
+        // Fetch a copy of the practice challenge, and release the storage lock before verifying.
+        let challenge = {
+            // Acquire the storage read lock.
+            let storage = StorageLock::Read(self.storage.read().unwrap());
+            match storage.get(&Locator::PracticeChallenge)? {
+                Object::PracticeChallenge(challenge) => challenge,
+                _ => return Err(CoordinatorError::StorageFailed),
+            }
+        };
+
+        // Verify the practice response.
+        let start = Instant::now();
+        Practice::verify(&self.environment, &challenge, response)?;
+        let verification_time =
+            chrono::Duration::from_std(start.elapsed()).unwrap_or_else(|_| chrono::Duration::zero());
+
+        // Extrapolate the elapsed time from the practice powers to a chunk of the real parameters.
+        let settings = self.environment.parameters();
+        let proving_system = settings.proving_system();
+        let practice_size: u64 = total_size_in_g1!(proving_system, practice_power);
+        let scale = settings.chunk_size() as f64 / practice_size as f64;
+        let estimated_contribution_time =
+            chrono::Duration::milliseconds((elapsed.num_milliseconds() as f64 * scale) as i64);
+
+        let feedback = PracticeFeedback::new(
+            elapsed,
+            verification_time,
+            estimated_contribution_time,
+            self.environment.participant_lock_timeout(),
+        );
+        info!("{} completed a practice contribution: {:?}", participant, feedback);
+
+        Ok(feedback)
+    }
+
+    ///
+    /// Returns the duration after which a practice session expires.
+    ///
+    /// A practice response that takes twice the participant lock timeout
+    /// is too slow to contribute regardless, so the session is discarded.
+    ///
+    #[inline]
+    fn practice_session_timeout(&self) -> chrono::Duration {
+        self.environment.participant_lock_timeout() * 2
+    }
+
     ///
     /// Initializes a listener to handle the shutdown signal.
     ///
@@ -532,6 +1052,9 @@ impl Coordinator {
         ctrlc::set_handler(move || {
             warn!("\n\nATTENTION - Coordinator is shutting down...\n");
 
+            // Cancel the computations and verifications in progress, which hold the storage lock.
+            self.cancellation.cancel();
+
             // Acquire the storage lock.
             let mut storage = StorageLock::Write(self.storage.write().unwrap());
             trace!("Coordinator has acquired the storage lock");
@@ -540,9 +1063,11 @@ impl Coordinator {
             let state = self.state.write().unwrap();
             trace!("Coordinator has acquired the state lock");
 
-            // Save the coordinator state to storage.
-            state.save(&mut storage).unwrap();
-            debug!("Coordinator has safely shutdown storage");
+            // Save the coordinator state to storage, unless it belongs to the primary coordinator.
+            if !self.environment.is_read_only() {
+                state.save(&mut storage).unwrap();
+                debug!("Coordinator has safely shutdown storage");
+            }
 
             // Print the final coordinator state.
             let final_state = serde_json::to_string_pretty(&*state).unwrap();
@@ -577,6 +1102,30 @@ impl Coordinator {
         state.is_queue_verifier(&participant)
     }
 
+    ///
+    /// Returns `true` if the given participant is in the queue.
+    ///
+    #[inline]
+    pub fn participant_is_queued(&self, participant: &Participant) -> bool {
+        // Acquire a state read lock.
+        let state = self.state.read().unwrap();
+        // Fetch the state of the queue participant.
+        state.participant_is_queued(&participant)
+    }
+
+    ///
+    /// Returns the zero-based position of the given participant in the queue,
+    /// in the order participants were admitted to the queue, or `None` if the
+    /// participant is not in the queue.
+    ///
+    #[inline]
+    pub fn queue_position(&self, participant: &Participant) -> Result<Option<usize>, CoordinatorError> {
+        // Acquire a state read lock.
+        let state = self.state.read().unwrap();
+        // Fetch the queue position of the participant.
+        state.queue_position(&participant)
+    }
+
     ///
     /// Returns the total number of contributors currently in the queue.
     ///  
@@ -632,6 +1181,21 @@ impl Coordinator {
         state.current_contributors()
     }
 
+    ///
+    /// Returns the reservation of the response file of the given chunk ID,
+    /// if it is held by a current contributor and has not expired.
+    ///
+    #[inline]
+    pub fn contribution_reservation(&self, chunk_id: u64) -> Option<ContributionReservation> {
+        // Acquire a state read lock.
+        let state = self.state.read().unwrap();
+        // Fetch the reservation, if it is live.
+        state
+            .contribution_reservation(chunk_id)
+            .filter(|reservation| state.is_contribution_reservation_live(reservation, self.time.utc_now()))
+            .cloned()
+    }
+
     ///
     /// Returns a list of the verifiers currently in the round.
     ///
@@ -643,6 +1207,18 @@ impl Coordinator {
         state.current_verifiers()
     }
 
+    ///
+    /// Returns the chunks the given participant holds and has completed in
+    /// the current round, and the chunk it is assigned to process next.
+    ///
+    #[inline]
+    pub fn participant_progress(&self, participant: &Participant) -> Result<ParticipantProgress, CoordinatorError> {
+        // Acquire a state read lock.
+        let state = self.state.read().unwrap();
+        // Fetch the progress of the participant.
+        state.participant_progress(participant)
+    }
+
     ///
     /// Returns a list of participants that were dropped from the current round.
     ///
@@ -665,38 +1241,115 @@ impl Coordinator {
     }
 
     ///
-    /// Adds the given participant to the queue if they are permitted to participate.
+    /// Returns the statistics of the round with the given height, such as the number
+    /// of contributions, verifications and participants, and the elapsed time.
+    ///
+    /// Unlike the current round metrics, these are derived from the round in storage,
+    /// and are available for any round which has started.
     ///
     #[inline]
-    pub fn add_to_queue(&self, participant: Participant, reliability_score: u8) -> Result<(), CoordinatorError> {
-        // Acquire the storage write lock.
-        let mut storage = StorageLock::Write(self.storage.write().unwrap());
+    pub fn get_round_metrics(&self, round_height: u64) -> Result<RoundStatistics, CoordinatorError> {
+        // Fetch the round from storage.
+        let round = self.get_round(round_height)?;
+        // Derive the statistics of the round.
+        Ok(round.statistics(self.time.utc_now()))
+    }
 
-        // Acquire the state write lock.
-        let mut state = self.state.write().unwrap();
+    ///
+    /// Returns the history of the rounds of the ceremony.
+    ///
+    #[inline]
+    pub fn ceremony_history(&self) -> CeremonyHistory {
+        // Acquire a state read lock.
+        let state = self.state.read().unwrap();
+        // Fetch the ceremony history.
+        state.history().clone()
+    }
 
-        // Attempt to add the participant to the next round.
-        state.add_to_queue(participant, reliability_score)?;
+    ///
+    /// Returns the aggregates over the rounds of the ceremony, such as
+    /// the average time per round and the contributions per hour.
+    ///
+    #[inline]
+    pub fn history_aggregates(&self) -> HistoryAggregates {
+        // Acquire a state read lock.
+        let state = self.state.read().unwrap();
+        // Compute the aggregates of the ceremony history.
+        state.history().aggregates()
+    }
 
-        // Save the coordinator state in storage.
-        state.save(&mut storage)?;
+    ///
+    /// Subscribes to the events of the ceremony, and returns a snapshot of the current
+    /// round along with a receiver of every event published after the snapshot.
+    ///
+    /// A receiver which falls more than `EVENT_CHANNEL_CAPACITY` events behind misses
+    /// the oldest events, and is told how many it missed on its next receive.
+    ///
+    #[inline]
+    pub fn subscribe(&self) -> Result<(CoordinatorEvent, broadcast::Receiver<CoordinatorEvent>), CoordinatorError> {
+        // Acquire a storage read lock.
+        let storage = StorageLock::Read(self.storage.read().unwrap());
 
-        Ok(())
+        // Acquire a state read lock, as events are published while the state write lock is held.
+        let state = self.state.read().unwrap();
+
+        // Fetch the locks held in the current round.
+        let round = Self::load_current_round(&storage)?;
+        let locked_chunks = round
+            .chunks()
+            .iter()
+            .filter_map(|chunk| {
+                chunk.lock_holder().as_ref().map(|participant| ChunkLock {
+                    chunk_id: chunk.chunk_id(),
+                    participant: participant.clone(),
+                })
+            })
+            .collect();
+        let snapshot = CoordinatorEvent::Snapshot {
+            round_height: round.round_height(),
+            locked_chunks,
+        };
+
+        // Subscribe before releasing the state lock, so no event is missed after the snapshot.
+        let receiver = self.events.subscribe();
+        drop(state);
+
+        Ok((snapshot, receiver))
     }
 
     ///
-    /// Removes the given participant from the queue if they are in the queue.
+    /// Returns the estimated time remaining for contributions in the current round,
+    /// from the average time per contribution in the current round metrics and the
+    /// number of contributions remaining in the current round.
+    ///
+    /// If no contribution has completed in the current round, returns `None`.
+    ///
+    /// On failure to load the current round from storage, returns a `CoordinatorError`.
     ///
     #[inline]
-    pub fn remove_from_queue(&self, participant: &Participant) -> Result<(), CoordinatorError> {
+    pub fn estimate_time_remaining(&self) -> Result<Option<chrono::Duration>, CoordinatorError> {
+        // Count the contributions remaining in the current round.
+        let contributions_remaining = self.current_round()?.contributions_remaining();
+
+        // Acquire a state read lock.
+        let state = self.state.read().unwrap();
+        // Estimate the time remaining from the current round metrics.
+        Ok(state.estimate_time_remaining(contributions_remaining))
+    }
+
+    ///
+    /// Adds the given participant to the queue if they are permitted to participate.
+    ///
+    #[inline]
+    pub fn add_to_queue(&self, participant: Participant, reliability_score: u8) -> Result<(), CoordinatorError> {
         // Acquire the storage write lock.
-        let mut storage = StorageLock::Write(self.storage.write().unwrap());
+        let mut storage = self.write_storage()?;
 
         // Acquire the state write lock.
-        let mut state = self.state.write().unwrap();
+        let mut state = self.write_state()?;
 
-        // Attempt to remove the participant from the next round.
-        state.remove_from_queue(participant)?;
+        // Attempt to add the participant to the next round.
+        state.add_to_queue_with_admission(participant, reliability_score, None, None, self.time.utc_now())?;
 
         // Save the coordinator state in storage.
         state.save(&mut storage)?;
@@ -705,24 +1358,27 @@ impl Coordinator {
     }
 
     ///
-    /// Drops the given participant from the ceremony.
+    /// Adds the given participant to the queue if they are permitted to participate,
+    /// recording the hardware they self-reported.
     ///
-    #[tracing::instrument(
-        skip(self, participant),
-        fields(participant = %participant)
-    )]
-    pub fn drop_participant(&self, participant: &Participant) -> Result<(), CoordinatorError> {
+    /// If the environment has a hardware policy, contributors must declare hardware
+    /// meeting its minimum, and their participant lock timeout is scaled by the declaration.
+    ///
+    #[inline]
+    pub fn add_to_queue_with_hardware(
+        &self,
+        participant: Participant,
+        reliability_score: u8,
+        hardware: Option<HardwareDeclaration>,
+    ) -> Result<(), CoordinatorError> {
         // Acquire the storage write lock.
-        let mut storage = StorageLock::Write(self.storage.write().unwrap());
+        let mut storage = self.write_storage()?;
 
-        // Acquire a state write lock.
-        let mut state = self.state.write().unwrap();
-
-        // Drop the participant from the ceremony.
-        let drop = state.drop_participant(participant, self.time.as_ref())?;
+        // Acquire the state write lock.
+        let mut state = self.write_state()?;
 
-        // Update the round to reflect the coordinator state change.
-        self.drop_participant_from_storage(&mut storage, &drop)?;
+        // Attempt to add the participant to the next round.
+        state.add_to_queue_with_admission(participant, reliability_score, hardware, None, self.time.utc_now())?;
 
         // Save the coordinator state in storage.
         state.save(&mut storage)?;
@@ -730,16 +1386,248 @@ impl Coordinator {
         Ok(())
     }
 
+    ///
+    /// Adds the given participant to the queue as in `add_to_queue_with_hardware`,
+    /// presenting the given admission credentials.
+    ///
+    /// If the environment has an admission policy, contributors must present the
+    /// credentials it requires, which are consumed when they join the queue.
+    ///
+    #[inline]
+    pub fn add_to_queue_with_admission(
+        &self,
+        participant: Participant,
+        reliability_score: u8,
+        hardware: Option<HardwareDeclaration>,
+        credentials: &AdmissionCredentials,
+    ) -> Result<(), CoordinatorError> {
+        // Acquire the storage write lock.
+        let mut storage = self.write_storage()?;
+
+        // Acquire the state write lock.
+        let mut state = self.write_state()?;
+
+        // Attempt to add the participant to the next round.
+        state.add_to_queue_with_admission(
+            participant,
+            reliability_score,
+            hardware,
+            Some(credentials),
+            self.time.utc_now(),
+        )?;
+
+        // Save the coordinator state in storage.
+        state.save(&mut storage)?;
+
+        Ok(())
+    }
+
+    ///
+    /// Issues a new single-use invitation token, for the operator to hand to a contributor.
+    ///
+    /// If the environment does not require invitation tokens, returns `AdmissionPolicyMissing`.
+    ///
+    #[inline]
+    pub fn issue_invitation(&self) -> Result<String, CoordinatorError> {
+        // Check that the coordinator is not read-only, as only the primary issues invitations.
+        self.check_writable()?;
+
+        let policy = self
+            .environment
+            .admission_policy()
+            .as_ref()
+            .and_then(|policy| policy.invitation())
+            .ok_or(CoordinatorError::AdmissionPolicyMissing)?;
+
+        policy.issue()
+    }
+
+    ///
+    /// Issues a new proof-of-work challenge to the given contributor, which they
+    /// must solve and present to join the queue before the challenge expires.
+    ///
+    /// The challenge is signed rather than stored, so issuing it does not change the
+    /// coordinator state, and any number of challenges may be issued at once.
+    ///
+    /// If the environment does not require a proof-of-work, returns `AdmissionPolicyMissing`.
+    ///
+    #[inline]
+    pub fn issue_admission_challenge(
+        &self,
+        participant: &Participant,
+    ) -> Result<ProofOfWorkChallenge, CoordinatorError> {
+        // Check that the coordinator is not read-only, as only the primary holds the key the challenges are checked with.
+        self.check_writable()?;
+
+        let policy = self
+            .environment
+            .admission_policy()
+            .as_ref()
+            .and_then(|policy| policy.proof_of_work())
+            .ok_or(CoordinatorError::AdmissionPolicyMissing)?;
+
+        policy.issue(participant, self.time.utc_now())
+    }
+
+    ///
+    /// Checks the client the given participant identified themselves with,
+    /// from the value of their `X-Setup-Client` header, and records it for
+    /// the tasks the participant completes.
+    ///
+    /// If the header is missing or malformed, returns `ClientVersionInvalid`.
+    /// If the environment has a client version policy and the client is outside
+    /// of its range, returns `ClientVersionUnsupported` with the supported range.
+    ///
+    #[inline]
+    pub fn check_client(
+        &self,
+        participant: &Participant,
+        header: Option<&str>,
+    ) -> Result<ClientVersion, CoordinatorError> {
+        let client: ClientVersion = header
+            .ok_or_else(|| CoordinatorError::ClientVersionInvalid(String::new()))?
+            .parse()?;
+
+        // Check that the client is within the supported range, if there is a client version policy.
+        if let Some(policy) = self.environment.client_version_policy() {
+            if let Err(error) = policy.evaluate(&client) {
+                info!("Rejected {} using unsupported client {}", participant, client);
+                return Err(error);
+            }
+        }
+
+        // Acquire the storage write lock.
+        let mut storage = self.write_storage()?;
+
+        // Acquire the state write lock.
+        let mut state = self.write_state()?;
+
+        // Record the client, for the next task the participant completes.
+        if state.set_participant_client(participant, client.clone()) {
+            // Save the coordinator state in storage.
+            state.save(&mut storage)?;
+        }
+
+        Ok(client)
+    }
+
+    ///
+    /// Removes the given participant from the queue if they are in the queue.
+    ///
+    #[inline]
+    pub fn remove_from_queue(&self, participant: &Participant) -> Result<(), CoordinatorError> {
+        // Acquire the storage write lock.
+        let mut storage = self.write_storage()?;
+
+        // Acquire the state write lock.
+        let mut state = self.write_state()?;
+
+        // Attempt to remove the participant from the next round.
+        state.remove_from_queue(participant)?;
+
+        // Save the coordinator state in storage.
+        state.save(&mut storage)?;
+
+        Ok(())
+    }
+
+    ///
+    /// Drops the given participant from the ceremony.
+    ///
+    #[tracing::instrument(
+        skip(self, participant),
+        fields(participant = %participant)
+    )]
+    pub fn drop_participant(&self, participant: &Participant) -> Result<(), CoordinatorError> {
+        // Acquire the storage write lock.
+        let mut storage = self.write_storage()?;
+
+        // Acquire a state write lock.
+        let mut state = self.write_state()?;
+
+        // Drop the participant from the ceremony.
+        let drop = state.drop_participant(participant, self.time.as_ref())?;
+
+        // Update the round to reflect the coordinator state change.
+        self.drop_participant_from_storage(&mut storage, &drop)?;
+
+        // Save the coordinator state in storage.
+        state.save(&mut storage)?;
+
+        Ok(())
+    }
+
+    ///
+    /// Removes the given verifier from the current round, and returns the IDs
+    /// of the chunks affected by the removal, in ascending order.
+    ///
+    /// The chunks locked by the verifier are released, and its assigned and pending
+    /// verification tasks are reassigned to the remaining verifiers. As the round
+    /// cannot proceed without a verifier, this refuses to remove the sole verifier.
+    ///
+    #[tracing::instrument(
+        skip(self, verifier),
+        fields(verifier = %verifier)
+    )]
+    pub fn remove_from_round(&self, verifier: &Participant) -> Result<Vec<u64>, CoordinatorError> {
+        // Check that the participant is a verifier.
+        if !verifier.is_verifier() {
+            return Err(CoordinatorError::ExpectedVerifier);
+        }
+
+        // Acquire the storage write lock.
+        let mut storage = self.write_storage()?;
+
+        // Acquire a state write lock.
+        let mut state = self.write_state()?;
+
+        // Check that the verifier is in the current round, and is not the sole verifier.
+        let current_verifiers = state.current_verifiers();
+        if !current_verifiers.iter().any(|(participant, _)| participant == verifier) {
+            return Err(CoordinatorError::ParticipantNotFound(verifier.clone()));
+        }
+        if current_verifiers.len() == 1 {
+            return Err(CoordinatorError::VerifierIsSoleVerifier);
+        }
+
+        // Drop the verifier from the current round.
+        let drop = state.drop_participant(verifier, self.time.as_ref())?;
+
+        // Fetch the chunks affected by the removal of the verifier.
+        let mut affected_chunks = match &drop {
+            DropParticipant::DropCurrent(DropCurrentParticpantData {
+                storage_action: CeremonyStorageAction::RemoveVerifier(action),
+                ..
+            }) => action
+                .locked_chunks
+                .iter()
+                .cloned()
+                .chain(action.tasks.iter().map(|task| task.chunk_id()))
+                .collect(),
+            _ => vec![],
+        };
+        affected_chunks.sort_unstable();
+        affected_chunks.dedup();
+
+        // Update the round to reflect the coordinator state change.
+        self.drop_participant_from_storage(&mut storage, &drop)?;
+
+        // Save the coordinator state in storage.
+        state.save(&mut storage)?;
+
+        Ok(affected_chunks)
+    }
+
     ///
     /// Bans the given participant from the ceremony.
     ///
     #[inline]
     pub fn ban_participant(&self, participant: &Participant) -> Result<(), CoordinatorError> {
         // Acquire the storage write lock.
-        let mut storage = StorageLock::Write(self.storage.write().unwrap());
+        let mut storage = self.write_storage()?;
 
         // Acquire a state write lock.
-        let mut state = self.state.write().unwrap();
+        let mut state = self.write_state()?;
 
         // Ban the participant from the ceremony.
         let drop = state.ban_participant(participant, self.time.as_ref())?;
@@ -759,10 +1647,10 @@ impl Coordinator {
     #[inline]
     pub fn unban_participant(&self, participant: &Participant) -> Result<(), CoordinatorError> {
         // Acquire the storage write lock.
-        let mut storage = StorageLock::Write(self.storage.write().unwrap());
+        let mut storage = self.write_storage()?;
 
         // Acquire a state write lock.
-        let mut state = self.state.write().unwrap();
+        let mut state = self.write_state()?;
 
         // Unban the participant from the ceremony.
         state.unban_participant(participant);
@@ -790,10 +1678,10 @@ impl Coordinator {
     #[inline]
     pub fn enable_manual_lock(&mut self) -> Result<(), CoordinatorError> {
         // Acquire the storage write lock.
-        let mut storage = StorageLock::Write(self.storage.write().unwrap());
+        let mut storage = self.write_storage()?;
 
         // Acquire the state write lock.
-        let mut state = self.state.write().unwrap();
+        let mut state = self.write_state()?;
 
         // Sets the manual lock to `true`.
         state.enable_manual_lock();
@@ -810,10 +1698,10 @@ impl Coordinator {
     #[inline]
     pub fn disable_manual_lock(&mut self) -> Result<(), CoordinatorError> {
         // Acquire the storage write lock.
-        let mut storage = StorageLock::Write(self.storage.write().unwrap());
+        let mut storage = self.write_storage()?;
 
         // Acquire the state write lock.
-        let mut state = self.state.write().unwrap();
+        let mut state = self.write_state()?;
 
         // Sets the manual lock to `false`.
         state.disable_manual_lock();
@@ -824,6 +1712,64 @@ impl Coordinator {
         Ok(())
     }
 
+    ///
+    /// Returns `true` if the ceremony is paused for maintenance.
+    ///
+    #[inline]
+    pub fn is_paused(&self) -> bool {
+        // Acquire the state read lock.
+        let state = self.state.read().unwrap();
+        // Fetch whether the ceremony is paused.
+        state.is_paused()
+    }
+
+    ///
+    /// Pauses the ceremony for maintenance.
+    ///
+    /// While paused, contributors may not lock chunks or add contributions,
+    /// while verifiers may continue to verify the contributions already added.
+    /// Participants are not dropped for timeouts while paused, and the timeouts
+    /// resume from where they were paused once the ceremony is resumed.
+    ///
+    #[inline]
+    pub fn pause(&self) -> Result<(), CoordinatorError> {
+        // Acquire the storage write lock.
+        let mut storage = self.write_storage()?;
+
+        // Acquire the state write lock.
+        let mut state = self.write_state()?;
+
+        // Sets the ceremony as paused.
+        state.pause(self.time.as_ref());
+
+        // Save the coordinator state in storage.
+        state.save(&mut storage)?;
+
+        info!("Paused the ceremony");
+        Ok(())
+    }
+
+    ///
+    /// Resumes the ceremony after it was paused for maintenance.
+    ///
+    #[inline]
+    pub fn resume(&self) -> Result<(), CoordinatorError> {
+        // Acquire the storage write lock.
+        let mut storage = self.write_storage()?;
+
+        // Acquire the state write lock.
+        let mut state = self.write_state()?;
+
+        // Sets the ceremony as resumed.
+        state.resume(self.time.as_ref());
+
+        // Save the coordinator state in storage.
+        state.save(&mut storage)?;
+
+        info!("Resumed the ceremony");
+        Ok(())
+    }
+
     ///
     /// Returns `true` if the given participant is authorized as a
     /// contributor and listed in the contributor IDs for this round.
@@ -972,8 +1918,14 @@ impl Coordinator {
         }) {
             // Case 1 - This is a typical round of the ceremony.
             true => Ok(current_round_height),
-            // Case 2 - Storage failed to locate the current round.
-            false => Err(CoordinatorError::StorageFailed),
+            // Case 2 - The round height was written, but its round was not.
+            false => {
+                error!(
+                    "Round height in storage is {}, but the round is missing",
+                    current_round_height
+                );
+                Err(CoordinatorError::RoundHeightInconsistent)
+            }
         }
     }
 
@@ -1007,24 +1959,108 @@ impl Coordinator {
         // Acquire the storage lock.
         let storage = StorageLock::Read(self.storage.read().unwrap());
 
+        // Fetch the round corresponding to the given round height from storage.
+        Self::load_round(&storage, round_height)
+    }
+
+    ///
+    /// Returns the heights of the rounds in storage in ascending order,
+    /// skipping the first `offset` heights and returning at most `limit` heights.
+    ///
+    /// If the ceremony has not been initialized, returns an empty list.
+    ///
+    #[inline]
+    pub fn list_rounds(&self, offset: u64, limit: u64) -> Result<Vec<u64>, CoordinatorError> {
+        // Acquire the storage lock.
+        let storage = StorageLock::Read(self.storage.read().unwrap());
+
+        // Check that the ceremony has been initialized.
+        if !storage.exists(&Locator::RoundHeight) {
+            return Ok(vec![]);
+        }
+
         // Fetch the current round height from storage.
         let current_round_height = Self::load_current_round_height(&storage)?;
 
-        // Check that the given round height is valid.
-        match round_height <= current_round_height {
-            // Fetch the round corresponding to the given round height from storage.
-            true => Ok(serde_json::from_slice(
-                &*storage.reader(&Locator::RoundState { round_height })?.as_ref(),
-            )?),
-            // The given round height does not exist.
-            false => Err(CoordinatorError::RoundDoesNotExist),
-        }
+        // Probe storage for the round state of each height, as pruned rounds may be missing.
+        Ok((0..=current_round_height)
+            .filter(|round_height| {
+                storage.exists(&Locator::RoundState {
+                    round_height: *round_height,
+                })
+            })
+            .skip(offset as usize)
+            .take(limit as usize)
+            .collect())
+    }
+
+    ///
+    /// Returns a copy of the chunk corresponding to the given chunk ID
+    /// in the current round from storage.
+    ///
+    /// If the chunk ID is not in the current round, returns `ChunkIdOutOfRange`.
+    ///
+    #[inline]
+    pub fn get_chunk(&self, chunk_id: u64) -> Result<Chunk, CoordinatorError> {
+        // Check that the chunk ID is valid.
+        self.environment.validate_chunk_id(chunk_id)?;
+
+        // Fetch the current round from storage.
+        let round = self.current_round()?;
+
+        Ok(round.chunk(chunk_id)?.clone())
+    }
+
+    ///
+    /// Returns a map of each chunk ID in the current round to the contributors
+    /// who have contributed to the chunk, in the order of their contributions.
+    ///
+    /// The initial contribution of each chunk is made by the coordinator,
+    /// and is not included.
+    ///
+    #[inline]
+    pub fn contributors_by_chunk(&self) -> Result<HashMap<u64, Vec<Participant>>, CoordinatorError> {
+        // Fetch the current round from storage.
+        let round = self.current_round()?;
+
+        Ok(round
+            .chunks()
+            .iter()
+            .map(|chunk| {
+                let contributors = chunk
+                    .get_contributions()
+                    .iter()
+                    .filter(|(contribution_id, _)| **contribution_id != 0)
+                    .filter_map(|(_, contribution)| contribution.get_contributor().clone())
+                    .collect();
+                (chunk.chunk_id(), contributors)
+            })
+            .collect())
+    }
+
+    ///
+    /// Writes the round state corresponding to the given height from storage
+    /// as JSON to the given writer.
+    ///
+    /// The JSON is streamed directly to the writer, rather than serialized
+    /// into an intermediate `String`.
+    ///
+    /// If there are no prior rounds, returns a `CoordinatorError`.
+    ///
+    #[inline]
+    pub fn round_state_json(&self, round_height: u64, out: &mut impl Write) -> Result<(), CoordinatorError> {
+        // Fetch the round corresponding to the given round height from storage.
+        let round = self.get_round(round_height)?;
+
+        // Stream the round state to the writer.
+        serde_json::to_writer(out, &round)?;
+        Ok(())
     }
 
     /// Lets the coordinator know that the participant is still alive
     /// and participating (or waiting to participate) in the ceremony.
     pub fn heartbeat(&self, participant: &Participant) -> Result<(), CoordinatorError> {
-        let mut state = self.state.write().expect("unable to obtain write lock on state");
+        let mut state = self.write_state()?;
         state.heartbeat(participant, self.time.as_ref())
     }
 
@@ -1036,7 +2072,7 @@ impl Coordinator {
     #[tracing::instrument(skip(self, participant), err)]
     pub fn try_lock(&self, participant: &Participant) -> Result<(u64, LockedLocators), CoordinatorError> {
         // Acquire the state write lock.
-        let mut state = self.state.write().unwrap();
+        let mut state = self.write_state()?;
 
         // Check that the participant is in the current round, and has not been dropped or finished.
         if !state.is_current_contributor(participant) && !state.is_current_verifier(participant) {
@@ -1058,8 +2094,13 @@ impl Coordinator {
             return Err(CoordinatorError::CurrentRoundAggregated);
         }
 
+        // Check that the ceremony is not paused, if the participant is a contributor.
+        if participant.is_contributor() && state.is_paused() {
+            return Err(CoordinatorError::CeremonyPaused);
+        }
+
         // Acquire the storage write lock.
-        let mut storage = StorageLock::Write(self.storage.write().unwrap());
+        let mut storage = self.write_storage()?;
 
         // Attempt to fetch the next chunk ID and contribution ID for the given participant.
         let current_task = state.fetch_task(participant, self.time.as_ref())?;
@@ -1072,33 +2113,371 @@ impl Coordinator {
             return Err(CoordinatorError::PreviousContributionMissing { current_task });
         }
 
+        // Release the reservation of the response file, so the lock initializes it.
+        if participant.is_contributor() {
+            if let Err(error) =
+                self.release_contribution_reservation(&mut storage, &mut state, current_task.chunk_id(), participant)
+            {
+                state.rollback_pending_task(participant, current_task, &*self.time)?;
+                return Err(error);
+            }
+        }
+
         debug!("Locking chunk {} for {}", current_task.chunk_id(), participant);
         match self.try_lock_chunk(&mut storage, current_task.chunk_id(), participant) {
             // Case 1 - Participant acquired lock, return the locator.
-            Ok(locked_locators) => {
+            Ok(mut locked_locators) => {
                 trace!("Incrementing the number of locks held by {}", participant);
                 state.acquired_lock(participant, current_task.chunk_id(), self.time.as_ref())?;
 
+                // Issue the token of the lock to the participant.
+                if let Some(lock_token) = state.lock_token(participant, current_task.chunk_id()) {
+                    locked_locators.set_lock_token(lock_token.to_string());
+                }
+
                 // Save the coordinator state in storage.
                 state.save(&mut storage)?;
 
                 info!("Acquired lock on chunk {} for {}", current_task.chunk_id(), participant);
+                self.publish(CoordinatorEvent::ChunkLocked {
+                    round_height: state.current_round_height(),
+                    chunk_id: current_task.chunk_id(),
+                    participant: participant.clone(),
+                });
                 Ok((current_task.chunk_id(), locked_locators))
             }
             // Case 2 - Participant failed to acquire the lock, put the chunk ID back.
             Err(error) => {
                 info!("Failed to acquire lock for {}", participant);
 
-                trace!("Adding task {} back to assigned tasks", current_task);
-                state.rollback_pending_task(participant, current_task, self.time.as_ref())?;
+                trace!("Adding task {} back to assigned tasks", current_task);
+                state.rollback_pending_task(participant, current_task, self.time.as_ref())?;
+
+                // Save the coordinator state in storage.
+                state.save(&mut storage)?;
+
+                error!("{}", error);
+                return Err(error);
+            }
+        }
+    }
+
+    ///
+    /// Reserves the response file of the next contribution to the given chunk ID
+    /// for the given contributor, and returns the path of the reserved response file.
+    ///
+    /// The next contribution to the chunk must be assigned to the contributor. The response
+    /// file is initialized as a placeholder under the storage write lock, in the same step as
+    /// checking that it does not exist, and the reservation is recorded with its owner.
+    /// Reserving the same response file again returns it, and the contributor may then lock
+    /// the chunk, which releases the reservation.
+    ///
+    /// A reservation expires after the participant lock timeout. Until then, other
+    /// reservations of the same response file fail with `ContributionLocatorAlreadyExists`.
+    ///
+    #[tracing::instrument(skip(self, participant), err)]
+    pub fn reserve_next_contribution_locator(
+        &self,
+        chunk_id: u64,
+        participant: &Participant,
+    ) -> Result<String, CoordinatorError> {
+        // Check that the participant is a contributor.
+        if !participant.is_contributor() {
+            return Err(CoordinatorError::ExpectedContributor);
+        }
+
+        // Check that the chunk ID is valid.
+        self.environment.validate_chunk_id(chunk_id)?;
+
+        // Acquire the state write lock.
+        let mut state = self.write_state()?;
+
+        // Check that the participant is in the current round, and has not been dropped or finished.
+        if !state.is_current_contributor(participant) {
+            return Err(CoordinatorError::ParticipantUnauthorized);
+        }
+
+        // Check that the current round is not yet finished.
+        if state.is_current_round_finished() {
+            return Err(CoordinatorError::CurrentRoundFinished);
+        }
+
+        // Acquire the storage write lock.
+        let mut storage = self.write_storage()?;
+
+        // Fetch the current round from storage.
+        let round = Self::load_current_round(&storage)?;
+
+        // Check that the chunk is not locked by another participant.
+        let chunk = round.chunk(chunk_id)?;
+        if chunk.is_locked() && !chunk.is_locked_by(participant) {
+            return Err(CoordinatorError::ChunkLockAlreadyAcquired);
+        }
+
+        // Return the response file if the participant already reserved it.
+        let now = self.time.utc_now();
+        if let Some(reservation) = state.contribution_reservation(chunk_id) {
+            if reservation.participant == *participant && state.is_contribution_reservation_live(reservation, now) {
+                let locator =
+                    ContributionLocator::new(reservation.round_height, chunk_id, reservation.contribution_id, false);
+                return Ok(storage.to_path(&Locator::ContributionFile(locator))?.to_string());
+            }
+        }
+
+        // Check that the next contribution to the chunk is assigned to the participant.
+        // The placeholder of an existing reservation is released below.
+        let next_contribution_id = match state.contribution_reservation(chunk_id) {
+            Some(reservation) if reservation.round_height == state.current_round_height() => {
+                reservation.contribution_id
+            }
+            _ => round.next_contribution_locator(&storage, chunk_id)?.contribution_id(),
+        };
+        match state.assigned_task(participant, chunk_id) {
+            Some(task) if task.contribution_id() == next_contribution_id => {}
+            _ => return Err(CoordinatorError::ParticipantUnauthorizedForChunkId { chunk_id }),
+        }
+
+        // Release the reservation of another participant, if it is no longer live.
+        self.release_contribution_reservation(&mut storage, &mut state, chunk_id, participant)?;
+
+        // Reserve the response file of the next contribution.
+        let locator = round.reserve_next_contribution_locator(&self.environment, &mut storage, chunk_id)?;
+        state.reserve_contribution(
+            chunk_id,
+            ContributionReservation {
+                participant: participant.clone(),
+                round_height: locator.round_height(),
+                contribution_id: locator.contribution_id(),
+                reserved_at: now,
+            },
+        );
+        state.save(&mut storage)?;
+        debug!("{} reserved {:?}", participant, locator);
+
+        Ok(storage.to_path(&Locator::ContributionFile(locator))?.to_string())
+    }
+
+    ///
+    /// Releases the reservation of the response file of the given chunk ID, and removes
+    /// its placeholder, if the reservation is held by the given participant or is no
+    /// longer live.
+    ///
+    /// If another contributor holds a live reservation, returns `ContributionLocatorAlreadyExists`.
+    ///
+    #[inline]
+    fn release_contribution_reservation(
+        &self,
+        storage: &mut StorageLock,
+        state: &mut CoordinatorState,
+        chunk_id: u64,
+        participant: &Participant,
+    ) -> Result<(), CoordinatorError> {
+        let reservation = match state.contribution_reservation(chunk_id) {
+            Some(reservation) => reservation.clone(),
+            None => return Ok(()),
+        };
+
+        // Check that the reservation is not held by another contributor.
+        if reservation.participant != *participant
+            && state.is_contribution_reservation_live(&reservation, self.time.utc_now())
+        {
+            return Err(CoordinatorError::ContributionLocatorAlreadyExists);
+        }
+        state.take_contribution_reservation(chunk_id);
+
+        // Remove the placeholder, if it is in the current round.
+        let locator = Locator::ContributionFile(ContributionLocator::new(
+            reservation.round_height,
+            chunk_id,
+            reservation.contribution_id,
+            false,
+        ));
+        if reservation.round_height == state.current_round_height() && storage.exists(&locator) {
+            storage.remove(&locator)?;
+        }
+
+        debug!(
+            "Released the reservation of {:?} by {}",
+            locator, reservation.participant
+        );
+        Ok(())
+    }
+
+    ///
+    /// Releases the lock on the given chunk ID regardless of which participant holds it,
+    /// and returns the participant that held the lock, if any.
+    ///
+    /// This is an admin operation for releasing locks which have not expired, for example
+    /// after confirming that the lock holder is gone. The pending task of the lock holder
+    /// is returned to its assigned tasks, and any response it uploaded for the chunk is removed.
+    ///
+    /// On failure, this function returns a `CoordinatorError`.
+    ///
+    #[inline]
+    pub fn force_unlock_chunk(&self, chunk_id: u64) -> Result<Option<Participant>, CoordinatorError> {
+        // Check that the chunk ID is valid.
+        self.environment.validate_chunk_id(chunk_id)?;
+
+        // Acquire the state write lock.
+        let mut state = self.write_state()?;
+
+        // Acquire the storage write lock.
+        let mut storage = self.write_storage()?;
+
+        // Fetch the current round from storage.
+        let mut round = Self::load_current_round(&storage)?;
+
+        // Fetch the lock holder of the chunk, if the chunk is locked.
+        let lock_holder = match round.chunk(chunk_id)?.lock_holder() {
+            Some(lock_holder) => lock_holder.clone(),
+            None => {
+                debug!("Chunk {} is not locked", chunk_id);
+                return Ok(None);
+            }
+        };
+        warn!("Force unlocking chunk {} held by {}", chunk_id, lock_holder);
+
+        // Release the lock from the lock holder in the coordinator state.
+        state.released_lock(&lock_holder, chunk_id, self.time.as_ref())?;
+
+        // Release the lock from the chunk in the round state.
+        let round_height = round.round_height();
+        round.remove_locks_unsafe(&mut storage, &lock_holder, &[chunk_id])?;
+        storage.update(&Locator::RoundState { round_height }, Object::RoundState(round))?;
+
+        // Save the coordinator state in storage.
+        state.save(&mut storage)?;
+
+        info!("Released the lock on chunk {} from {}", chunk_id, lock_holder);
+        self.publish(CoordinatorEvent::ChunkUnlocked {
+            round_height,
+            chunk_id,
+            participant: lock_holder.clone(),
+        });
+        Ok(Some(lock_holder))
+    }
+
+    ///
+    /// Runs the initialization of the given chunk ID again, and rewrites its initial
+    /// contribution file, for example after the file was lost or corrupted.
+    ///
+    /// The initial contribution of a chunk is only produced by initialization in round 1,
+    /// as later rounds start from the aggregated previous round. So a chunk can only be
+    /// reinitialized in round 1, before it is locked or contributed to.
+    ///
+    /// On failure, this function returns a `CoordinatorError`.
+    ///
+    #[inline]
+    pub fn reinitialize_chunk(&self, chunk_id: u64) -> Result<(), CoordinatorError> {
+        // Check that the chunk ID is valid.
+        self.environment.validate_chunk_id(chunk_id)?;
+
+        // Acquire the storage write lock.
+        let mut storage = self.write_storage()?;
+
+        // Check that the current round is the first round.
+        let round = Self::load_current_round(&storage)?;
+        if round.round_height() != 1 {
+            error!("Chunk {} can only be reinitialized in round 1", chunk_id);
+            return Err(CoordinatorError::ChunkReinitializationUnsupported);
+        }
+
+        // Check that the chunk has no contributions besides the initial contribution.
+        let chunk = round.chunk(chunk_id)?;
+        if chunk.current_contribution_id() != 0 {
+            return Err(CoordinatorError::ChunkAlreadyContributed);
+        }
+
+        // Check that no participant is working on the chunk.
+        if chunk.is_locked() {
+            return Err(CoordinatorError::ChunkLockAlreadyAcquired);
+        }
+
+        warn!("Reinitializing chunk {}", chunk_id);
+
+        // Remove the initial contribution files of the chunk which remain in storage.
+        for round_height in 0..=1 {
+            let locator = Locator::ContributionFile(ContributionLocator::new(round_height, chunk_id, 0, true));
+            if storage.exists(&locator) {
+                storage.remove(&locator)?;
+            }
+        }
+
+        // Run the initialization of round 0 on the chunk, which copies
+        // the initial contribution file to round 1.
+        if let Err(error) = Initialization::run(&self.environment, &mut storage, 0, chunk_id) {
+            error!("Reinitialization of chunk {} failed with {}", chunk_id, error);
+            return Err(CoordinatorError::InitializationFailed);
+        }
+
+        info!("Reinitialized chunk {}", chunk_id);
+        Ok(())
+    }
+
+    ///
+    /// Writes the next challenge file and contribution file signature uploaded by the given
+    /// verifier for the given chunk ID, if the given lock token was issued with the lock
+    /// that the verifier currently holds on the chunk.
+    ///
+    /// A verifier which was dropped while holding the lock, for example after its lock
+    /// expired, no longer holds the lock it was issued the token with. Its late upload
+    /// is rejected, rather than overwriting the files of the verifier taking over the chunk.
+    ///
+    /// On failure, this function returns a `CoordinatorError`.
+    ///
+    #[tracing::instrument(skip(self, participant, lock_token, next_challenge_file, signature), err)]
+    pub fn upload_next_challenge(
+        &self,
+        participant: &Participant,
+        chunk_id: u64,
+        lock_token: &str,
+        next_challenge_file: Vec<u8>,
+        signature: ContributionFileSignature,
+    ) -> Result<(), CoordinatorError> {
+        // Check that the participant is a verifier.
+        if !participant.is_verifier() {
+            return Err(CoordinatorError::ExpectedVerifier);
+        }
+
+        // Check that the chunk ID is valid.
+        self.environment.validate_chunk_id(chunk_id)?;
+
+        // Acquire the state read lock.
+        let state = self.state.read().unwrap();
+
+        // Check that the lock token was issued with the lock currently held by the participant.
+        if state.lock_token(participant, chunk_id) != Some(lock_token) {
+            warn!(
+                "Rejecting the upload of {} to chunk {} with a stale lock token",
+                participant, chunk_id
+            );
+            return Err(CoordinatorError::ChunkLockTokenMismatch);
+        }
+
+        // Acquire the storage write lock.
+        let mut storage = self.write_storage()?;
 
-                // Save the coordinator state in storage.
-                state.save(&mut storage)?;
+        // Fetch the current round from storage.
+        let round = Self::load_current_round(&storage)?;
 
-                error!("{}", error);
-                return Err(error);
-            }
+        // Check that the chunk lock is currently held by this verifier.
+        if !round.is_chunk_locked_by(chunk_id, participant) {
+            return Err(CoordinatorError::ChunkNotLockedOrByWrongParticipant);
         }
+
+        // Write the next challenge file and its contribution file signature.
+        let (next_challenge, next_challenge_signature) = round.next_challenge_locators(chunk_id)?;
+        storage.update(
+            &Locator::ContributionFile(next_challenge),
+            Object::ContributionFile(next_challenge_file),
+        )?;
+        storage.update(
+            &Locator::ContributionFileSignature(next_challenge_signature),
+            Object::ContributionFileSignature(signature),
+        )?;
+
+        debug!("{} uploaded the next challenge of chunk {}", participant, chunk_id);
+        Ok(())
     }
 
     ///
@@ -1130,12 +2509,10 @@ impl Coordinator {
         }
 
         // Check that the chunk ID is valid.
-        if chunk_id > self.environment.number_of_chunks() {
-            return Err(CoordinatorError::ChunkIdInvalid);
-        }
+        self.environment.validate_chunk_id(chunk_id)?;
 
         // Acquire the state write lock.
-        let mut state = self.state.write().unwrap();
+        let mut state = self.write_state()?;
 
         // Check that the participant is in the current round, and has not been dropped or finished.
         if !state.is_current_contributor(participant) {
@@ -1157,8 +2534,13 @@ impl Coordinator {
             return Err(CoordinatorError::CurrentRoundAggregated);
         }
 
+        // Check that the ceremony is not paused.
+        if state.is_paused() {
+            return Err(CoordinatorError::CeremonyPaused);
+        }
+
         // Acquire the storage write lock.
-        let mut storage = StorageLock::Write(self.storage.write().unwrap());
+        let mut storage = self.write_storage()?;
 
         // Fetch the current round height from storage.
         let round_height = Self::load_current_round_height(&storage)?;
@@ -1209,10 +2591,21 @@ impl Coordinator {
                     let completed_task = Task::new(chunk_id, contribution_id);
                     state.completed_task(participant, completed_task, self.time.as_ref())?;
 
+                    // Record the client of the contributor on the contribution.
+                    if let Some(client) = state.participant_client(participant).cloned() {
+                        Self::record_task_client(&mut storage, completed_task, participant, client)?;
+                    }
+
                     // Save the coordinator state in storage.
                     state.save(&mut storage)?;
 
                     info!("Added contribution");
+                    self.publish(CoordinatorEvent::ContributionAdded {
+                        round_height,
+                        chunk_id,
+                        contribution_id,
+                        participant: participant.clone(),
+                    });
                     return Ok(locator);
                 }
                 // Case 2 - Participant failed to add their contribution, remove the contribution file.
@@ -1245,6 +2638,11 @@ impl Coordinator {
     ///
     /// On success, this function releases the lock from the verifier.
     ///
+    /// If the participant already verified the current contribution of the chunk,
+    /// this function is a no-op which returns `Ok`, so that a verifier may safely
+    /// retry a verification it did not receive a response for. If another verifier
+    /// verified the contribution, it returns `ContributionAlreadyVerified`.
+    ///
     /// On failure, it returns a `CoordinatorError`.
     ///
     #[inline]
@@ -1255,18 +2653,36 @@ impl Coordinator {
         }
 
         // Check that the chunk ID is valid.
-        if chunk_id > self.environment.number_of_chunks() {
-            return Err(CoordinatorError::ChunkIdInvalid);
-        }
+        self.environment.validate_chunk_id(chunk_id)?;
 
         // Acquire the state write lock.
-        let mut state = self.state.write().unwrap();
+        let mut state = self.write_state()?;
 
         // Check that the participant is in the current round, and has not been dropped or finished.
         if !state.is_current_verifier(participant) {
             return Err(CoordinatorError::ParticipantUnauthorized);
         }
 
+        // Acquire the storage write lock.
+        let mut storage = self.write_storage()?;
+
+        // Check if the participant already verified the current contribution of the chunk.
+        // A verifier which retries a verification that completed before it received the
+        // response is not at fault, so the retry succeeds without changing any state.
+        let round = Self::load_current_round(&storage)?;
+        let chunk = round.chunk(chunk_id)?;
+        let current_contribution = chunk.current_contribution()?;
+        if current_contribution.is_verified_by(participant) {
+            debug!(
+                "{} already verified chunk {} contribution {}",
+                participant,
+                chunk_id,
+                chunk.current_contribution_id()
+            );
+            return Ok(());
+        }
+        let is_current_contribution_verified = current_contribution.is_verified();
+
         // Check that the current round is not yet finished.
         if state.is_current_round_finished() {
             return Err(CoordinatorError::CurrentRoundFinished);
@@ -1282,9 +2698,6 @@ impl Coordinator {
             return Err(CoordinatorError::CurrentRoundAggregated);
         }
 
-        // Acquire the storage write lock.
-        let mut storage = StorageLock::Write(self.storage.write().unwrap());
-
         // Check if the participant should dispose the response being contributed.
         if let Some(task) = state.lookup_disposing_task(participant, chunk_id)?.cloned() {
             let contribution_id = task.contribution_id();
@@ -1344,6 +2757,18 @@ impl Coordinator {
                 participant, chunk_id, contribution_id
             );
 
+            // Select the next verifier before changing any state, if the contribution needs another verification.
+            let successful_verifications = chunk
+                .get_contribution(contribution_id)?
+                .get_verifications()
+                .iter()
+                .filter(|record| record.is_success())
+                .count() as u64;
+            let next_verifier = match successful_verifications + 1 < self.environment.required_verifications() {
+                true => Some(state.next_pending_verifier(Task::new(chunk_id, contribution_id), Some(participant))?),
+                false => None,
+            };
+
             match self.verify_contribution(&mut storage, chunk_id, participant) {
                 // Case 1 - Participant verified contribution, return the response file locator.
                 Ok(contribution_id) => {
@@ -1351,18 +2776,58 @@ impl Coordinator {
                     let completed_task = Task::new(chunk_id, contribution_id);
                     state.completed_task(participant, completed_task, self.time.as_ref())?;
 
+                    // Record the client of the verifier on the verification record.
+                    if let Some(client) = state.participant_client(participant).cloned() {
+                        Self::record_task_client(&mut storage, completed_task, participant, client)?;
+                    }
+
+                    // Check if the contribution is accepted, or needs the verification of another verifier.
+                    let round = Self::load_current_round(&storage)?;
+                    let is_accepted = round.chunk(chunk_id)?.get_contribution(contribution_id)?.is_verified();
+                    if !is_accepted {
+                        let next_verifier = next_verifier.ok_or(CoordinatorError::VerifierMissing)?;
+
+                        // Remove the next challenge file of this verifier, for the next verifier to compute independently.
+                        let (next_challenge, next_signature) = round.next_challenge_locators(chunk_id)?;
+                        storage.remove(&Locator::ContributionFile(next_challenge))?;
+                        storage.remove(&Locator::ContributionFileSignature(next_signature))?;
+
+                        // Assign the verification to the verifier selected above, who has not verified the contribution yet.
+                        let next_verifier =
+                            state.assign_pending_verification(completed_task, next_verifier, self.time.as_ref())?;
+                        info!(
+                            "Reassigned verification of chunk {} contribution {} to {}",
+                            chunk_id, contribution_id, next_verifier
+                        );
+                    }
+
                     // Save the coordinator state in storage.
                     state.save(&mut storage)?;
 
                     info!("Added verification from {} for chunk {}", participant, chunk_id);
+                    if is_accepted {
+                        self.publish(CoordinatorEvent::ContributionVerified {
+                            round_height: state.current_round_height(),
+                            chunk_id,
+                            contribution_id,
+                            participant: participant.clone(),
+                        });
+                    }
                     return Ok(());
                 }
                 // Case 2 - Participant failed to add their contribution, remove the contribution file.
                 Err(error) => {
                     info!("Failed to add a verification and removing the contribution file");
 
-                    // Fetch the current round from storage.
-                    let round = Self::load_current_round(&storage)?;
+                    // Fetch the current round from storage, and record the failed verification.
+                    let mut round = Self::load_current_round(&storage)?;
+                    round.record_failed_verification(chunk_id, contribution_id, participant, self.time.as_ref())?;
+                    storage.update(
+                        &Locator::RoundState {
+                            round_height: round.round_height(),
+                        },
+                        Object::RoundState(round.clone()),
+                    )?;
 
                     // Fetch the next challenge locator.
                     let is_final_contribution = contribution_id == round.expected_number_of_contributions() - 1;
@@ -1384,25 +2849,130 @@ impl Coordinator {
                     // Remove the invalid next challenge file from storage.
                     storage.remove(&next_challenge)?;
 
+                    // Return the task to the contributor to retry, if retries are enabled.
+                    if self.environment.contribution_retry_policy().is_some() {
+                        self.retry_failed_contribution(
+                            &mut state,
+                            &mut storage,
+                            participant,
+                            chunk_id,
+                            contribution_id,
+                        )?;
+                    }
+
                     error!("{}", error);
                     return Err(error);
                 }
             }
         }
 
+        // Check if the current contribution of the chunk was already verified by another verifier.
+        if is_current_contribution_verified {
+            return Err(CoordinatorError::ContributionAlreadyVerified);
+        }
+
         Err(CoordinatorError::VerificationFailed)
     }
 
+    ///
+    /// Quarantines the given contribution, which failed verification, and returns its task
+    /// to the contributor to lock the chunk again and re-upload the contribution.
+    ///
+    /// If the contributor has exhausted the retries of the retry policy of the environment,
+    /// the contributor is dropped from the round and their tasks are reassigned.
+    ///
+    #[inline]
+    fn retry_failed_contribution(
+        &self,
+        state: &mut CoordinatorState,
+        storage: &mut StorageLock,
+        verifier: &Participant,
+        chunk_id: u64,
+        contribution_id: u64,
+    ) -> Result<(), CoordinatorError> {
+        // Fetch the retry policy.
+        let policy = match self.environment.contribution_retry_policy() {
+            Some(policy) => policy,
+            None => return Ok(()),
+        };
+
+        // Fetch the current round from storage.
+        let mut round = Self::load_current_round(&storage)?;
+        let round_height = round.round_height();
+
+        // Fetch the contributor of the failed contribution.
+        let contributor = round
+            .chunk(chunk_id)?
+            .get_contribution(contribution_id)?
+            .get_contributor()
+            .clone()
+            .ok_or(CoordinatorError::RoundContributorMissing)?;
+
+        // Remove the task from the verifier, and return it to the contributor.
+        let task = Task::new(chunk_id, contribution_id);
+        let failed = state.failed_verification(verifier, &contributor, task, self.time.as_ref())?;
+
+        // Remove the next challenge file signature initialized by the verifier.
+        let is_final_contribution = contribution_id == round.expected_number_of_contributions() - 1;
+        let next_challenge_signature = match is_final_contribution {
+            true => ContributionSignatureLocator::new(round_height + 1, chunk_id, 0, true),
+            false => ContributionSignatureLocator::new(round_height, chunk_id, contribution_id, true),
+        };
+        let next_challenge_signature = Locator::ContributionFileSignature(next_challenge_signature);
+        if storage.exists(&next_challenge_signature) {
+            storage.remove(&next_challenge_signature)?;
+        }
+
+        // Quarantine the failed contribution, and release the lock on the chunk from the verifier.
+        let quarantine = Locator::QuarantinedContributionFile {
+            round_height,
+            chunk_id,
+            contribution_id,
+            attempt: failed.attempt,
+        };
+        round.quarantine_contribution_unsafe(storage, chunk_id, contribution_id, &quarantine)?;
+
+        // Save the updated round to storage.
+        storage.update(&Locator::RoundState { round_height }, Object::RoundState(round))?;
+
+        warn!(
+            "Contribution from {} to chunk {} failed verification (attempt {} of {})",
+            contributor,
+            chunk_id,
+            failed.attempt,
+            policy.retries() + 1
+        );
+        self.publish(CoordinatorEvent::ContributionFailed {
+            round_height,
+            chunk_id,
+            contribution_id,
+            participant: contributor.clone(),
+            attempt: failed.attempt,
+        });
+
+        // Drop the contributor and reassign their tasks, if they have exhausted their retries.
+        if policy.is_exhausted(failed.attempt) {
+            warn!("Dropping {} after exhausting their contribution retries", contributor);
+            let drop = state.drop_participant(&contributor, self.time.as_ref())?;
+            self.drop_participant_from_storage(storage, &drop)?;
+        }
+
+        // Save the coordinator state in storage.
+        state.save(storage)?;
+
+        Ok(())
+    }
+
     ///
     /// Attempts to aggregate the contributions of the current round of the ceremony.
     ///
     #[inline]
     pub fn try_aggregate(&self) -> Result<(), CoordinatorError> {
         // Acquire the storage write lock.
-        let mut storage = StorageLock::Write(self.storage.write().unwrap());
+        let mut storage = self.write_storage()?;
 
         // Acquire the state write lock.
-        let mut state = self.state.write().unwrap();
+        let mut state = self.write_state()?;
 
         // Check that the current round height matches in storage and state.
         let current_round_height = {
@@ -1461,6 +3031,15 @@ impl Coordinator {
                     return Err(CoordinatorError::RoundAggregationFailed);
                 }
 
+                // Record the aggregated round in the ceremony history.
+                match self.round_history(&storage) {
+                    Ok(round_history) => state.record_round_history(round_history),
+                    Err(error) => warn!(
+                        "Unable to record round {} in the history ({})",
+                        current_round_height, error
+                    ),
+                }
+
                 Ok(())
             }
             // Case 1b - Coordinator failed to aggregate the current round.
@@ -1482,44 +3061,13 @@ impl Coordinator {
     pub fn try_advance(&self, started_at: DateTime<Utc>) -> Result<u64, CoordinatorError> {
         tracing::debug!("Trying to advance to the next round.");
         // Acquire the storage write lock.
-        let mut storage = StorageLock::Write(self.storage.write().unwrap());
+        let mut storage = self.write_storage()?;
 
         // Acquire the state write lock.
-        let mut state = self.state.write().unwrap();
+        let mut state = self.write_state()?;
 
-        // Check that the current round height matches in storage and state.
-        let current_round_height = {
-            // Fetch the current round height from storage.
-            let current_round_height_in_storage = Self::load_current_round_height(&storage)?;
-            debug!("Current round height in storage is {}", current_round_height_in_storage);
-
-            // Fetch the current round height from coordinator state.
-            let current_round_height = state.current_round_height();
-            debug!("Current round height in coordinator state is {}", current_round_height);
-
-            // Check that the current round height matches in storage and state.
-            if current_round_height_in_storage == current_round_height {
-                current_round_height
-            } else {
-                tracing::error!(
-                    "Round height in storage ({}) does not match the \
-                    round height in coordinator state ({})",
-                    current_round_height_in_storage,
-                    current_round_height,
-                );
-                return Err(CoordinatorError::RoundHeightMismatch);
-            }
-        };
-
-        // Check that the current round is finished.
-        if !state.is_current_round_finished() {
-            return Err(CoordinatorError::CurrentRoundNotFinished);
-        }
-
-        // Check that the current round is aggregated, if this is not round 0.
-        if current_round_height > 0 && !state.is_current_round_aggregated() {
-            return Err(CoordinatorError::CurrentRoundNotAggregated);
-        }
+        // Check that the current round is ready to be advanced.
+        let current_round_height = Self::check_advance(&storage, &state)?;
 
         // Attempt to advance the round.
         trace!("Running precommit for the next round");
@@ -1527,12 +3075,22 @@ impl Coordinator {
             // Case 1 - Precommit succeed, attempt to advance the round.
             Ok((contributors, verifiers)) => {
                 trace!("Trying to add advance to the next round");
-                match self.next_round(&mut storage, started_at, contributors, verifiers) {
+                let contributor_hardware = state.next_contributor_hardware();
+                match self.next_round_with_hardware(
+                    &mut storage,
+                    started_at,
+                    contributors,
+                    verifiers,
+                    contributor_hardware,
+                ) {
                     // Case 1a - Coordinator advanced the round.
                     Ok(next_round_height) => {
                         // If success, update coordinator state to next round.
                         info!("Coordinator has advanced to round {}", next_round_height);
                         state.commit_next_round();
+                        self.publish(CoordinatorEvent::RoundAdvanced {
+                            round_height: next_round_height,
+                        });
                         Ok(next_round_height)
                     }
                     // Case 1b - Coordinator failed to advance the round.
@@ -1561,6 +3119,111 @@ impl Coordinator {
         result
     }
 
+    ///
+    /// Plans the transition to the next round, as `try_advance` would perform it
+    /// now, without writing to storage or the coordinator state.
+    ///
+    /// The returned plan lists the participants that would be selected from the
+    /// queue, the expected storage usage and aggregation time of the next round,
+    /// and any blockers.
+    ///
+    #[inline]
+    pub fn plan_next_round(&self) -> Result<RoundPlan, CoordinatorError> {
+        // Acquire the storage read lock.
+        let storage = StorageLock::Read(self.storage.read().unwrap());
+
+        // Plan against a copy of the coordinator state, so the precommit is discarded.
+        let mut state = self.state.read().unwrap().clone();
+
+        let mut plan = RoundPlan {
+            next_round_height: state.current_round_height() + 1,
+            contributors: vec![],
+            verifiers: vec![],
+            estimated_storage_bytes: 0,
+            estimated_aggregation_seconds: state
+                .current_round_metrics()
+                .and_then(|metrics| metrics.aggregation_seconds()),
+            blockers: vec![],
+        };
+
+        // Check that the current round is ready to be advanced.
+        if let Err(error) = Self::check_advance(&storage, &state) {
+            plan.blockers.push(error);
+            return Ok(plan);
+        }
+
+        // Select the participants for the next round from the queue.
+        match state.precommit_next_round(plan.next_round_height, self.time.as_ref()) {
+            Ok((contributors, verifiers)) => {
+                if let Err(error) = Self::check_next_round_participants(&contributors, &verifiers) {
+                    plan.blockers.push(error);
+                }
+                plan.contributors = contributors;
+                plan.verifiers = verifiers;
+            }
+            Err(error) => plan.blockers.push(error),
+        }
+
+        // Check that storage is prepared for the next round.
+        if let Err(error) = self.check_next_round_storage(&storage) {
+            plan.blockers.push(error);
+        }
+
+        // Estimate the storage used by the contributions and round file of the next round.
+        let contributions_per_chunk = self
+            .environment
+            .contributions_per_chunk()
+            .unwrap_or(plan.contributors.len() as u64);
+        for chunk_id in 0..self.environment.number_of_chunks() {
+            let contribution_size = Object::contribution_file_size(&self.environment, chunk_id, false)
+                + Object::contribution_file_size(&self.environment, chunk_id, true);
+            plan.estimated_storage_bytes += contributions_per_chunk * contribution_size;
+        }
+        plan.estimated_storage_bytes += Object::round_file_size(&self.environment);
+
+        Ok(plan)
+    }
+
+    ///
+    /// Checks that the current round height matches in storage and state,
+    /// and that the current round is finished and aggregated.
+    ///
+    /// On success, returns the current round height.
+    ///
+    #[inline]
+    fn check_advance(storage: &StorageLock, state: &CoordinatorState) -> Result<u64, CoordinatorError> {
+        // Fetch the current round height from storage.
+        let current_round_height_in_storage = Self::load_current_round_height(&storage)?;
+        debug!("Current round height in storage is {}", current_round_height_in_storage);
+
+        // Fetch the current round height from coordinator state.
+        let current_round_height = state.current_round_height();
+        debug!("Current round height in coordinator state is {}", current_round_height);
+
+        // Check that the current round height matches in storage and state.
+        if current_round_height_in_storage != current_round_height {
+            tracing::error!(
+                "Round height in storage ({}) does not match the \
+                round height in coordinator state ({})",
+                current_round_height_in_storage,
+                current_round_height,
+            );
+            return Err(CoordinatorError::RoundHeightMismatch);
+        }
+
+        // Check that the current round is finished.
+        if !state.is_current_round_finished() {
+            return Err(CoordinatorError::CurrentRoundNotFinished);
+        }
+
+        // Check that the current round is aggregated, if this is not round 0.
+        if current_round_height > 0 && !state.is_current_round_aggregated() {
+            return Err(CoordinatorError::CurrentRoundNotAggregated);
+        }
+
+        Ok(current_round_height)
+    }
+
     ///
     /// Returns the chunk ID from the given contribution file locator path.
     ///
@@ -1571,21 +3234,265 @@ impl Coordinator {
         // Acquire the storage lock.
         let storage = StorageLock::Read(self.storage.read().unwrap());
 
-        // Fetch the chunk ID corresponding to the given locator path.
-        let locator = storage.to_locator(&locator_path)?;
-        match &locator {
-            Locator::ContributionFile(contribution_locator) => match storage.exists(&locator) {
-                true => Ok(contribution_locator.chunk_id()),
-                false => Err(CoordinatorError::ContributionLocatorMissing),
-            },
-            _ => Err(CoordinatorError::ContributionLocatorIncorrect),
+        // Fetch the chunk ID corresponding to the given locator path.
+        let locator = storage.to_locator(&locator_path)?;
+        match &locator {
+            Locator::ContributionFile(contribution_locator) => match storage.exists(&locator) {
+                true => Ok(contribution_locator.chunk_id()),
+                false => Err(CoordinatorError::ContributionLocatorMissing),
+            },
+            _ => Err(CoordinatorError::ContributionLocatorIncorrect),
+        }
+    }
+
+    /// Convert a locator to a path string using the coordinator's
+    /// storage layer.
+    pub fn locator_to_path(&self, locator: Locator) -> Result<LocatorPath, CoordinatorError> {
+        self.storage.read().unwrap().to_path(&locator)
+    }
+
+    ///
+    /// Returns the locator path of the aggregated round file for the given round height.
+    ///
+    /// If the round has not been aggregated, returns `RoundFileMissing`.
+    ///
+    #[inline]
+    pub fn round_file_locator(&self, round_height: u64) -> Result<String, CoordinatorError> {
+        // Acquire the storage lock.
+        let storage = StorageLock::Read(self.storage.read().unwrap());
+
+        // Check that the round file exists.
+        let round_file = Locator::RoundFile { round_height };
+        if !storage.exists(&round_file) {
+            return Err(CoordinatorError::RoundFileMissing);
+        }
+
+        Ok(storage.to_path(&round_file)?.to_string())
+    }
+
+    ///
+    /// Returns the size in bytes of the aggregated round file for the given round height.
+    ///
+    /// If the round has not been aggregated, returns `RoundFileMissing`.
+    ///
+    #[inline]
+    pub fn round_file_size(&self, round_height: u64) -> Result<u64, CoordinatorError> {
+        // Acquire the storage lock.
+        let storage = StorageLock::Read(self.storage.read().unwrap());
+
+        // Check that the round file exists.
+        let round_file = Locator::RoundFile { round_height };
+        if !storage.exists(&round_file) {
+            return Err(CoordinatorError::RoundFileMissing);
+        }
+
+        storage.size(&round_file)
+    }
+
+    ///
+    /// Returns a reader of the file of the given contribution, for serving downloads.
+    ///
+    /// If the contribution has been verified, the verified file is read,
+    /// otherwise the unverified file uploaded by the contributor is read.
+    /// The size of the file is checked against the expected size for the chunk
+    /// by the storage layer before any bytes are returned. The reader reads from a
+    /// memory map of the file, and never reads more than the expected size.
+    ///
+    /// If the contribution does not exist, returns `ContributionMissing`, and if
+    /// its file does not exist in storage, returns `ContributionLocatorMissing`.
+    ///
+    #[inline]
+    pub fn contribution_file_reader(
+        &self,
+        round_height: u64,
+        chunk_id: u64,
+        contribution_id: u64,
+    ) -> Result<impl Read, CoordinatorError> {
+        // Check that the chunk ID is valid.
+        self.environment.validate_chunk_id(chunk_id)?;
+
+        // Acquire the storage lock.
+        let storage = StorageLock::Read(self.storage.read().unwrap());
+
+        // Fetch the contribution from the round.
+        let round = Self::load_round(&storage, round_height)?;
+        let contribution = round.chunk(chunk_id)?.get_contribution(contribution_id)?;
+
+        // Fetch the locator of the contribution file to read.
+        let path = match contribution.is_verified() {
+            true => contribution.get_verified_location(),
+            false => contribution.get_contributed_location(),
+        };
+        let locator = storage.to_locator(path.as_ref().ok_or(CoordinatorError::ContributionLocatorMissing)?)?;
+
+        // Check that the contribution file exists.
+        if !storage.exists(&locator) {
+            return Err(CoordinatorError::ContributionLocatorMissing);
+        }
+
+        // Map the contribution file, which checks its size, and bound the reader by the expected size.
+        let expected_size = Object::contribution_file_size(&self.environment, chunk_id, contribution.is_verified());
+        Ok(Cursor::new(storage.reader(&locator)?).take(expected_size))
+    }
+
+    ///
+    /// Returns the public data needed to verify the given round independently,
+    /// for publishing on the ceremony website.
+    ///
+    /// This comprises the hash of the initial challenge and the IDs of the verified
+    /// contributions of each chunk, the hash of the aggregated round file, and the
+    /// fingerprint of the ceremony settings.
+    ///
+    /// If the round has not been aggregated, returns `RoundFileMissing`.
+    ///
+    #[inline]
+    pub fn export_verifying_metadata(&self, round_height: u64) -> Result<RoundVerificationMetadata, CoordinatorError> {
+        // Acquire the storage lock.
+        let storage = StorageLock::Read(self.storage.read().unwrap());
+
+        // Fetch the round corresponding to the given round height from storage.
+        let round = Self::load_round(&storage, round_height)?;
+
+        // Check that the round file exists, as the round is only complete once aggregated.
+        let round_file = Locator::RoundFile { round_height };
+        if !storage.exists(&round_file) {
+            return Err(CoordinatorError::RoundFileMissing);
+        }
+        let output_hash = hex::encode(calculate_hash(storage.reader(&round_file)?.as_ref()));
+
+        let chunks = round
+            .chunks()
+            .iter()
+            .map(|chunk| {
+                let chunk_id = chunk.chunk_id();
+
+                // Compute the hash of the initial challenge of the chunk.
+                let initial_challenge =
+                    Locator::ContributionFile(ContributionLocator::new(round_height, chunk_id, 0, true));
+                let initial_hash = hex::encode(calculate_hash(storage.reader(&initial_challenge)?.as_ref()));
+
+                // Collect the IDs and verifiers of the verified contributions, in order.
+                let (contribution_ids, verifiers) = chunk
+                    .get_contributions()
+                    .iter()
+                    .filter(|(contribution_id, contribution)| **contribution_id > 0 && contribution.is_verified())
+                    .map(|(contribution_id, contribution)| {
+                        let verifiers = contribution
+                            .get_verifications()
+                            .iter()
+                            .filter(|record| record.is_success())
+                            .map(|record| record.verifier().clone())
+                            .collect();
+                        (*contribution_id, verifiers)
+                    })
+                    .unzip();
+
+                Ok(ChunkVerificationMetadata {
+                    chunk_id,
+                    initial_hash,
+                    contribution_ids,
+                    verifiers,
+                })
+            })
+            .collect::<Result<_, CoordinatorError>>()?;
+
+        Ok(RoundVerificationMetadata {
+            round_height,
+            parameters_fingerprint: self.environment.parameters().fingerprint(),
+            chunks,
+            output_hash,
+        })
+    }
+
+    ///
+    /// Returns a page of the manifest of the ceremony transcript, with the path, size,
+    /// hash and modification time of each publicly downloadable file.
+    ///
+    /// The published files are the verified contribution files and their signatures,
+    /// and the aggregated round files. The page holds up to `limit` files, sorted by path,
+    /// starting after the given path. A `limit` of zero is treated as one, so that a page
+    /// always advances while files remain. Only the files of the page are hashed.
+    ///
+    #[inline]
+    pub fn transcript_manifest(
+        &self,
+        after: Option<&str>,
+        limit: usize,
+    ) -> Result<TranscriptManifest, CoordinatorError> {
+        // Acquire the storage lock.
+        let storage = StorageLock::Read(self.storage.read().unwrap());
+
+        // Fetch the finish time of each aggregated round from the ceremony history.
+        let aggregated_at: HashMap<u64, DateTime<Utc>> = {
+            let state = self.state.read().unwrap();
+            state
+                .history()
+                .rounds()
+                .map(|round| (round.round_height(), round.finished_at()))
+                .collect()
+        };
+
+        // Collect the published files of each round, with the time they were last modified.
+        let mut published = BTreeMap::new();
+        for round_height in 0..=Self::load_current_round_height(&storage)? {
+            let round = Self::load_round(&storage, round_height)?;
+            for chunk in round.chunks() {
+                for contribution in chunk.get_contributions().values().filter(|c| c.is_verified()) {
+                    let verified_at = contribution
+                        .get_verifications()
+                        .iter()
+                        .filter(|record| record.is_success())
+                        .map(|record| *record.verified_at())
+                        .max();
+                    for path in contribution
+                        .get_verified_location()
+                        .iter()
+                        .chain(contribution.get_verified_signature_location())
+                    {
+                        let locator = storage.to_locator(path)?;
+                        if storage.exists(&locator) {
+                            published.insert(path.to_string(), (locator, verified_at));
+                        }
+                    }
+                }
+            }
+
+            let round_file = Locator::RoundFile { round_height };
+            if storage.exists(&round_file) {
+                let modified_at = aggregated_at
+                    .get(&round_height)
+                    .copied()
+                    .or_else(|| round.finished_at());
+                published.insert(storage.to_path(&round_file)?.to_string(), (round_file, modified_at));
+            }
         }
-    }
 
-    /// Convert a locator to a path string using the coordinator's
-    /// storage layer.
-    pub fn locator_to_path(&self, locator: Locator) -> Result<LocatorPath, CoordinatorError> {
-        self.storage.read().unwrap().to_path(&locator)
+        // Select the files of the page, and compute their sizes and hashes.
+        let lower_bound = match after {
+            Some(after) => Bound::Excluded(after),
+            None => Bound::Unbounded,
+        };
+        let mut remaining = published.range::<str, _>((lower_bound, Bound::Unbounded)).peekable();
+        let mut artifacts = Vec::new();
+        while artifacts.len() < limit.max(1) {
+            let (path, (locator, modified_at)) = match remaining.next() {
+                Some(entry) => entry,
+                None => break,
+            };
+            artifacts.push(TranscriptArtifact {
+                path: path.clone(),
+                size: storage.size(locator)?,
+                hash: hex::encode(calculate_hash(storage.reader(locator)?.as_ref())),
+                modified_at: *modified_at,
+            });
+        }
+
+        let next_after = match remaining.peek() {
+            Some(_) => artifacts.last().map(|artifact| artifact.path.clone()),
+            None => None,
+        };
+
+        Ok(TranscriptManifest { artifacts, next_after })
     }
 
     ///
@@ -1608,9 +3515,7 @@ impl Coordinator {
         participant: &Participant,
     ) -> Result<LockedLocators, CoordinatorError> {
         // Check that the chunk ID is valid.
-        if chunk_id > self.environment.number_of_chunks() {
-            return Err(CoordinatorError::ChunkIdInvalid);
-        }
+        self.environment.validate_chunk_id(chunk_id)?;
 
         // Fetch the current round height from storage.
         let current_round_height = Self::load_current_round_height(&storage)?;
@@ -1797,6 +3702,28 @@ impl Coordinator {
         }
     }
 
+    ///
+    /// Records the client the given participant identified themselves with
+    /// on the given task in the current round.
+    ///
+    #[inline]
+    fn record_task_client(
+        storage: &mut StorageLock,
+        task: Task,
+        participant: &Participant,
+        client: ClientVersion,
+    ) -> Result<(), CoordinatorError> {
+        let mut round = Self::load_current_round(storage)?;
+        round.set_task_client(task.chunk_id(), task.contribution_id(), participant, client)?;
+        storage.update(
+            &Locator::RoundState {
+                round_height: round.round_height(),
+            },
+            Object::RoundState(round),
+        )?;
+        Ok(())
+    }
+
     ///
     /// Attempts to run verification in the current round for a given
     /// chunk ID and participant.
@@ -1942,7 +3869,10 @@ impl Coordinator {
             );
 
             // Fetch the saved response hash in the next challenge file.
-            let saved_response_hash = next_challenge_reader.as_ref().chunks(64).next().unwrap().to_vec();
+            let saved_response_hash = next_challenge_reader
+                .get(0..64)
+                .ok_or(CoordinatorError::StorageReaderFailed)?
+                .to_vec();
             let pretty_hash = pretty_hash!(&saved_response_hash);
 
             // Check that the response hash matches the next challenge hash.
@@ -2008,6 +3938,8 @@ impl Coordinator {
             participant.clone(),
             storage.to_path(&next_challenge_locator)?,
             storage.to_path(&contribution_file_signature_locator)?,
+            self.environment.required_verifications(),
+            self.time.as_ref(),
         )?;
 
         // Add the updated round to storage.
@@ -2078,7 +4010,7 @@ impl Coordinator {
         }
 
         // Fetch the current round from storage.
-        let round = Self::load_current_round(&storage)?;
+        let mut round = Self::load_current_round(&storage)?;
 
         // Check that the final unverified and verified contribution locators exist.
         let contribution_id = round.expected_number_of_contributions() - 1;
@@ -2113,8 +4045,17 @@ impl Coordinator {
         // Execute round aggregation and aggregate verification for the current round.
         {
             debug!("Coordinator is starting aggregation and aggregate verification");
-            Aggregation::run(&self.environment, &mut storage, &round)?;
+            let round_file_hash = Aggregation::run(&self.environment, &mut storage, &round)?;
             debug!("Coordinator completed aggregation and aggregate verification");
+
+            // Record the hash of the round file, which aggregation computed as it wrote the file.
+            round.set_round_file_hash(&round_file_hash);
+            storage.update(
+                &Locator::RoundState {
+                    round_height: current_round_height,
+                },
+                Object::RoundState(round),
+            )?;
         }
 
         // Check that the round file for the current round now exists.
@@ -2147,6 +4088,66 @@ impl Coordinator {
         contributors: Vec<Participant>,
         verifiers: Vec<Participant>,
     ) -> Result<u64, CoordinatorError> {
+        self.next_round_with_hardware(storage, started_at, contributors, verifiers, BTreeMap::new())
+    }
+
+    ///
+    /// Starts the next round with the given contributors and verifiers,
+    /// recording the hardware the contributors declared in the round state.
+    ///
+    #[inline]
+    pub(crate) fn next_round_with_hardware(
+        &self,
+        storage: &mut StorageLock,
+        started_at: DateTime<Utc>,
+        contributors: Vec<Participant>,
+        verifiers: Vec<Participant>,
+        contributor_hardware: BTreeMap<Participant, HardwareDeclaration>,
+    ) -> Result<u64, CoordinatorError> {
+        // Check that the next round has participants and storage is prepared for it.
+        Self::check_next_round_participants(&contributors, &verifiers)?;
+        let new_height = self.check_next_round_storage(storage)?;
+        let current_round_height = new_height - 1;
+        info!("Transitioning from round {} to {}", current_round_height, new_height);
+
+        // Instantiate the new round and height.
+        let mut new_round = Round::new(
+            &self.environment,
+            &storage,
+            new_height,
+            started_at,
+            contributors,
+            verifiers,
+        )?;
+        new_round.set_contributor_hardware(contributor_hardware);
+
+        #[cfg(test)]
+        trace!("{:#?}", &new_round);
+
+        // Insert the new round into storage.
+        storage.insert(
+            Locator::RoundState {
+                round_height: new_height,
+            },
+            Object::RoundState(new_round),
+        )?;
+
+        // Next, update the round height to reflect the new round.
+        storage.update(&Locator::RoundHeight, Object::RoundHeight(new_height))?;
+
+        debug!("Added round {} to storage", current_round_height);
+        info!("Transitioned from round {} to {}", current_round_height, new_height);
+        Ok(new_height)
+    }
+
+    ///
+    /// Checks that the next round has at least one contributor and one verifier.
+    ///
+    #[inline]
+    fn check_next_round_participants(
+        contributors: &[Participant],
+        verifiers: &[Participant],
+    ) -> Result<(), CoordinatorError> {
         // Check that the next round has at least one authorized contributor.
         if contributors.is_empty() {
             return Err(CoordinatorError::ContributorsMissing);
@@ -2155,7 +4156,17 @@ impl Coordinator {
         if verifiers.is_empty() {
             return Err(CoordinatorError::VerifierMissing);
         }
+        Ok(())
+    }
 
+    ///
+    /// Checks that the current round is aggregated in storage, the next round
+    /// is not initialized yet, and the initial contributions of the next round exist.
+    ///
+    /// On success, returns the height of the next round.
+    ///
+    #[inline]
+    fn check_next_round_storage(&self, storage: &StorageLock) -> Result<u64, CoordinatorError> {
         // Fetch the current round height from storage.
         let current_round_height = Self::load_current_round_height(&storage)?;
 
@@ -2177,7 +4188,6 @@ impl Coordinator {
 
         // Create the new round height.
         let new_height = current_round_height + 1;
-        info!("Transitioning from round {} to {}", current_round_height, new_height);
 
         // Check that the new round does not exist in storage.
         // If it exists, this means the round was already initialized.
@@ -2199,32 +4209,6 @@ impl Coordinator {
             }
         }
 
-        // Instantiate the new round and height.
-        let new_round = Round::new(
-            &self.environment,
-            &storage,
-            new_height,
-            started_at,
-            contributors,
-            verifiers,
-        )?;
-
-        #[cfg(test)]
-        trace!("{:#?}", &new_round);
-
-        // Insert the new round into storage.
-        storage.insert(
-            Locator::RoundState {
-                round_height: new_height,
-            },
-            Object::RoundState(new_round),
-        )?;
-
-        // Next, update the round height to reflect the new round.
-        storage.update(&Locator::RoundHeight, Object::RoundHeight(new_height))?;
-
-        debug!("Added round {} to storage", current_round_height);
-        info!("Transitioned from round {} to {}", current_round_height, new_height);
         Ok(new_height)
     }
 
@@ -2340,6 +4324,13 @@ impl Coordinator {
         Ok(round_height)
     }
 
+    /// Publishes the given event to the subscribers, if there are any.
+    #[inline]
+    fn publish(&self, event: CoordinatorEvent) {
+        // Sending only fails when there are no subscribers, so the event can be dropped.
+        let _ = self.events.send(event);
+    }
+
     /// Update the round on disk after a drop has occured.
     #[inline]
     fn drop_participant_from_storage(
@@ -2362,6 +4353,9 @@ impl Coordinator {
             }
         };
 
+        // Fetch the height of the round the participant is dropped from.
+        let round_height = Self::load_current_round_height(storage)?;
+
         match &drop_data.storage_action {
             CeremonyStorageAction::ResetCurrentRound(reset_action) => {
                 self.reset_round_storage(storage, reset_action)?;
@@ -2397,6 +4391,31 @@ impl Coordinator {
                     },
                     Object::RoundState(round),
                 )?;
+
+                for chunk_id in &replace_action.locked_chunks {
+                    self.publish(CoordinatorEvent::ChunkUnlocked {
+                        round_height,
+                        chunk_id: *chunk_id,
+                        participant: replace_action.dropped_contributor.clone(),
+                    });
+                }
+            }
+            CeremonyStorageAction::RemoveSpare(remove_action) => {
+                warn!("Removing spare contributor {}", remove_action.dropped_contributor);
+
+                // Fetch the current round from storage.
+                let mut round = Self::load_current_round(&storage)?;
+
+                // Remove the spare contributor from the round state, which holds no locks or tasks.
+                round.remove_contributor_unsafe(storage, &remove_action.dropped_contributor, &[], &[])?;
+
+                // Save the updated round to storage.
+                storage.update(
+                    &Locator::RoundState {
+                        round_height: round.round_height(),
+                    },
+                    Object::RoundState(round),
+                )?;
             }
             CeremonyStorageAction::RemoveVerifier(remove_action) => {
                 warn!("Removing verifier {}", remove_action.dropped_verifier);
@@ -2406,10 +4425,18 @@ impl Coordinator {
 
                 warn!("Removing locked chunks and all impacted contributions");
 
-                // Remove the lock from the specified chunks.
+                // Remove the lock from the specified chunks, along with the partial verifications.
                 round.remove_locks_unsafe(storage, &remove_action.dropped_verifier, &remove_action.locked_chunks)?;
                 warn!("Removed locked chunks");
 
+                for chunk_id in &remove_action.locked_chunks {
+                    self.publish(CoordinatorEvent::VerifierLockRevoked {
+                        round_height: round.round_height(),
+                        chunk_id: *chunk_id,
+                        participant: remove_action.dropped_verifier.clone(),
+                    });
+                }
+
                 // Remove the contributions from the specified chunks.
                 round.remove_chunk_contributions_unsafe(
                     storage,
@@ -2428,6 +4455,11 @@ impl Coordinator {
             }
         }
 
+        self.publish(CoordinatorEvent::ParticipantDropped {
+            round_height,
+            participant: drop_data.participant.clone(),
+        });
+
         Ok(())
     }
 
@@ -2442,6 +4474,47 @@ impl Coordinator {
         }
     }
 
+    ///
+    /// Returns the record of the current round for the ceremony history.
+    ///
+    /// The bytes processed are the sizes of the contribution and verification files
+    /// of the round, excluding the initial contributions.
+    ///
+    #[inline]
+    fn round_history(&self, storage: &StorageLock) -> Result<RoundHistory, CoordinatorError> {
+        // Fetch the current round from storage.
+        let round = Self::load_current_round(storage)?;
+
+        let mut number_of_contributions = 0;
+        let mut bytes_processed = 0;
+        for chunk in round.chunks() {
+            for (_, contribution) in chunk.get_contributions().iter().filter(|(id, _)| **id != 0) {
+                number_of_contributions += 1;
+                for path in contribution
+                    .get_contributed_location()
+                    .iter()
+                    .chain(contribution.get_verified_location())
+                {
+                    let locator = storage.to_locator(path)?;
+                    if storage.exists(&locator) {
+                        bytes_processed += storage.size(&locator)?;
+                    }
+                }
+            }
+        }
+
+        let finished_at = self.time.utc_now();
+        Ok(RoundHistory::new(
+            round.round_height(),
+            round.started_at().unwrap_or(finished_at),
+            finished_at,
+            round.number_of_contributors(),
+            round.number_of_verifiers(),
+            number_of_contributions,
+            bytes_processed,
+        ))
+    }
+
     #[inline]
     fn load_current_round(storage: &StorageLock) -> Result<Round, CoordinatorError> {
         // Fetch the current round height from storage.
@@ -2495,11 +4568,11 @@ impl Coordinator {
     /// previous round to invite new participants into the round.
     ///
     pub fn reset_round(&self) -> Result<(), CoordinatorError> {
-        let mut state = self.state.write().map_err(|_| CoordinatorError::StateLockFailed)?;
+        let mut state = self.write_state()?;
         let reset_action = state.reset_current_round(true, &*self.time)?;
 
         // Acquire the storage write lock.
-        let mut storage = StorageLock::Write(self.storage.write().map_err(|_| CoordinatorError::StorageLockFailed)?);
+        let mut storage = self.write_storage()?;
 
         storage.update(&Locator::CoordinatorState, Object::CoordinatorState(state.clone()))?;
         self.reset_round_storage(&mut storage, &reset_action)?;
@@ -2664,9 +4737,7 @@ impl Coordinator {
         );
 
         // Check that the chunk ID is valid.
-        if chunk_id > self.environment.number_of_chunks() {
-            return Err(CoordinatorError::ChunkIdInvalid);
-        }
+        self.environment.validate_chunk_id(chunk_id)?;
 
         // Check that the contribution ID is valid.
         if contribution_id == 0 {
@@ -2679,11 +4750,14 @@ impl Coordinator {
         }
 
         // Acquire the storage write lock.
-        let mut storage = StorageLock::Write(self.storage.write().unwrap());
+        let mut storage = self.write_storage()?;
 
         // Fetch the specified round from storage.
         let round = Self::load_round(&storage, round_height)?;
 
+        // Check that the contribution ID is in range for the round.
+        round.validate_contribution_id(chunk_id, contribution_id)?;
+
         // Check that the chunk lock is currently held by this contributor.
         if !round.is_chunk_locked_by(chunk_id, &participant) {
             error!("{} should have lock on chunk {} but does not", &participant, chunk_id);
@@ -2723,6 +4797,7 @@ impl Coordinator {
             response_locator,
             contribution_file_signature_locator,
             participant_seed,
+            &self.cancellation,
         )?;
         info!(
             "Completed computation on round {} chunk {} contribution {} as {}",
@@ -2732,6 +4807,19 @@ impl Coordinator {
         Ok(())
     }
 
+    ///
+    /// Returns the verification lock of the given chunk ID.
+    ///
+    #[inline]
+    fn chunk_lock(&self, chunk_id: u64) -> Arc<Mutex<()>> {
+        self.chunk_locks
+            .lock()
+            .unwrap()
+            .entry(chunk_id)
+            .or_insert_with(|| Arc::new(Mutex::new(())))
+            .clone()
+    }
+
     ///
     /// Attempts to run verification for a given round height, given chunk ID, and contribution ID.
     ///
@@ -2756,9 +4844,7 @@ impl Coordinator {
         );
 
         // Check that the chunk ID is valid.
-        if chunk_id > self.environment.number_of_chunks() {
-            return Err(CoordinatorError::ChunkIdInvalid);
-        }
+        self.environment.validate_chunk_id(chunk_id)?;
 
         // Check that the contribution ID is valid.
         if contribution_id == 0 {
@@ -2767,15 +4853,22 @@ impl Coordinator {
 
         // Check that the participant is a verifier.
         if !participant.is_verifier() {
-            return Err(CoordinatorError::ExpectedContributor);
+            return Err(CoordinatorError::ExpectedVerifier);
         }
 
-        // Acquire the storage write lock.
-        let mut storage = StorageLock::Write(self.storage.write().unwrap());
+        // Acquire the verification lock of this chunk.
+        let chunk_lock = self.chunk_lock(chunk_id);
+        let _chunk_guard = chunk_lock.lock().unwrap();
+
+        // Acquire the storage read lock.
+        let storage = StorageLock::Read(self.storage.read().unwrap());
 
         // Fetch the specified round from storage.
         let round = Self::load_round(&storage, round_height)?;
 
+        // Check that the contribution ID is in range for the round.
+        round.validate_contribution_id(chunk_id, contribution_id)?;
+
         // Check that the chunk lock is currently held by this contributor.
         if !round.is_chunk_locked_by(chunk_id, &participant) {
             error!("{} should have lock on chunk {} but does not", &participant, chunk_id);
@@ -2807,11 +4900,41 @@ impl Coordinator {
             false => Locator::ContributionFile(ContributionLocator::new(round_height, chunk_id, contribution_id, true)),
         };
 
+        // Fetch readers of the challenge and response files, and release the storage lock.
+        let (challenge_reader, response_reader) =
+            Verification::readers(&self.environment, &storage, round_height, chunk_id, contribution_id)?;
+        drop(storage);
+
         info!(
             "Starting verification on round {} chunk {} contribution {} as {}",
             round_height, chunk_id, contribution_id, participant
         );
-        Verification::run(
+        // Check the response file under the verification lock of this chunk only,
+        // as this is the most expensive step of the verification.
+        let started_at = Instant::now();
+        let response_hash = Verification::check_readers(
+            &self.environment,
+            chunk_id,
+            challenge_reader.as_ref(),
+            response_reader.as_ref(),
+            &self.cancellation,
+        )?;
+        let verification_duration =
+            chrono::Duration::from_std(started_at.elapsed()).unwrap_or_else(|_| chrono::Duration::max_value());
+        drop(challenge_reader);
+        drop(response_reader);
+
+        // Acquire the storage write lock.
+        let mut storage = self.write_storage()?;
+
+        // Check that the chunk lock is still held by this verifier, as it may have
+        // been released while the storage lock was not held.
+        if !Self::load_round(&storage, round_height)?.is_chunk_locked_by(chunk_id, &participant) {
+            error!("{} should have lock on chunk {} but does not", &participant, chunk_id);
+            return Err(CoordinatorError::ChunkNotLockedOrByWrongParticipant);
+        }
+
+        Verification::complete(
             &self.environment,
             &mut storage,
             self.signature.clone(),
@@ -2820,6 +4943,7 @@ impl Coordinator {
             chunk_id,
             contribution_id,
             is_final_contribution,
+            &response_hash,
         )?;
         info!(
             "Completed verification on round {} chunk {} contribution {} as {}",
@@ -2833,7 +4957,107 @@ impl Coordinator {
             return Err(CoordinatorError::ContributionLocatorMissing);
         }
 
-        Ok(storage.to_path(&verified_locator)?)
+        let verified_path = storage.to_path(&verified_locator)?;
+        drop(storage);
+
+        // Record the timing of the verification, which reads the contribution
+        // from the storage of the coordinator, so spends no time on transfers.
+        // The timing is saved with the next update of the coordinator state,
+        // as the state lock is acquired after the storage lock is released.
+        let timing = VerificationTiming::new(
+            &self.environment,
+            round_height,
+            chunk_id,
+            verification_duration,
+            chrono::Duration::zero(),
+            None,
+            self.time.utc_now(),
+        );
+        self.write_state()?.record_verification_timing(timing);
+
+        Ok(verified_path)
+    }
+
+    ///
+    /// Records the timing of a verification the given verifier performed
+    /// on the given chunk ID in the current round, as reported by the verifier.
+    ///
+    /// Returns `CoordinatorError::UnauthorizedChunkVerifier` if the verifier
+    /// has not verified a contribution to the chunk in the current round.
+    ///
+    /// The timings are used to recommend the chunk size of future ceremonies
+    /// in `Coordinator::chunking_report`.
+    ///
+    #[inline]
+    pub fn record_verification_timing(
+        &self,
+        verifier: &Participant,
+        chunk_id: u64,
+        verification_duration: chrono::Duration,
+        transfer_duration: chrono::Duration,
+        hardware: Option<String>,
+    ) -> Result<(), CoordinatorError> {
+        // Check that the participant is a verifier.
+        if !verifier.is_verifier() {
+            return Err(CoordinatorError::ExpectedVerifier);
+        }
+
+        // Check that the chunk ID is valid.
+        self.environment.validate_chunk_id(chunk_id)?;
+
+        // Acquire the storage write lock.
+        let mut storage = self.write_storage()?;
+
+        // Acquire the state write lock.
+        let mut state = self.write_state()?;
+
+        // Check that the verifier verified a contribution to the chunk in the current round.
+        let round = Self::load_current_round(&storage)?;
+        let is_verified_by = round
+            .chunk(chunk_id)?
+            .get_contributions()
+            .values()
+            .any(|contribution| contribution.is_verified_by(verifier));
+        if !is_verified_by {
+            error!(
+                "{} reported a verification of chunk {} it did not verify in round {}",
+                verifier,
+                chunk_id,
+                round.round_height()
+            );
+            return Err(CoordinatorError::UnauthorizedChunkVerifier);
+        }
+
+        let timing = VerificationTiming::new(
+            &self.environment,
+            round.round_height(),
+            chunk_id,
+            verification_duration,
+            transfer_duration,
+            hardware,
+            self.time.utc_now(),
+        );
+        state.record_verification_timing(timing);
+
+        // Save the coordinator state in storage.
+        state.save(&mut storage)?;
+
+        Ok(())
+    }
+
+    ///
+    /// Returns the estimated cost of verification from the timings recorded in the ceremony,
+    /// and the chunk size recommended for each verification task to take the target duration.
+    ///
+    /// Returns `None` if no verifications have been recorded yet.
+    ///
+    #[inline]
+    pub fn chunking_report(&self, target_task_duration: chrono::Duration) -> Option<ChunkingReport> {
+        // Acquire a state read lock.
+        let state = self.state.read().unwrap();
+
+        let current_chunk_size = self.environment.parameters().chunk_size() as u64;
+        ChunkingReport::new(state.verification_timings(), current_chunk_size, target_task_duration)
     }
 
     ///
@@ -2864,7 +5088,7 @@ mod tests {
         authentication::Dummy,
         commands::{Seed, SigningKey, SEED_LENGTH},
         environment::*,
-        objects::Participant,
+        objects::{Participant, Round},
         storage::StorageLock,
         testing::prelude::*,
         Coordinator,
@@ -2983,6 +5207,30 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    #[serial]
+    fn coordinator_round_state_json() -> anyhow::Result<()> {
+        initialize_test_environment(&TEST_ENVIRONMENT);
+
+        let coordinator = Coordinator::new(TEST_ENVIRONMENT.clone(), Box::new(Dummy))?;
+        initialize_coordinator(&coordinator)?;
+
+        // Check that the streamed round state parses back to the round in storage.
+        for round_height in 0..=1 {
+            let mut json = vec![];
+            coordinator.round_state_json(round_height, &mut json)?;
+            let candidate: Round = serde_json::from_slice(&json)?;
+            assert_eq!(coordinator.get_round(round_height)?, candidate);
+        }
+
+        // Check that a round which does not exist is rejected, and nothing is written.
+        let mut json = vec![];
+        assert!(coordinator.round_state_json(2, &mut json).is_err());
+        assert!(json.is_empty());
+
+        Ok(())
+    }
+
     #[test]
     #[serial]
     fn coordinator_initialization() -> anyhow::Result<()> {
@@ -3,6 +3,15 @@ use crate::{
     objects::{
         participant::*,
         task::{initialize_tasks, Task},
+        AdmissionCredentials,
+        CeremonyHistory,
+        ClientVersion,
+        FailedContribution,
+        HardwareDeclaration,
+        ProofOfWorkChallenge,
+        RoundHistory,
+        VerificationTiming,
+        MAXIMUM_VERIFICATION_TIMINGS,
     },
     storage::{Locator, Object, StorageLock},
     CoordinatorError,
@@ -14,11 +23,59 @@ use chrono::{DateTime, Duration, Utc};
 use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::{
-    collections::{HashMap, HashSet, LinkedList},
+    collections::{BTreeMap, HashMap, HashSet, LinkedList},
     iter::FromIterator,
 };
 use tracing::*;
 
+///
+/// Returns the tasks for the contributor starting from the given bucket ID.
+///
+/// If the environment fixes the number of contributions per chunk, only that many
+/// contributors are assigned tasks, and the contributors in the remaining buckets
+/// are left as spares with no tasks.
+///
+fn initialize_contributor_tasks(
+    environment: &Environment,
+    bucket_id: u64,
+    number_of_contributors: u64,
+) -> Result<LinkedList<Task>, CoordinatorError> {
+    let number_of_buckets = match environment.contributions_per_chunk() {
+        Some(contributions_per_chunk) => std::cmp::min(contributions_per_chunk, number_of_contributors),
+        None => number_of_contributors,
+    };
+
+    // Check if the contributor is a spare.
+    if bucket_id >= number_of_buckets {
+        return Ok(LinkedList::new());
+    }
+
+    Ok(initialize_tasks(
+        bucket_id,
+        environment.number_of_chunks(),
+        number_of_buckets,
+    )?)
+}
+
+///
+/// Returns a new random ceremony ID, formatted as a version 4 UUID.
+///
+fn new_ceremony_id() -> String {
+    let mut bytes: [u8; 16] = rand::random();
+    // Set the version to 4 and the variant to RFC 4122.
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+    let hex = hex::encode(bytes);
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[0..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..32]
+    )
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub(super) enum CoordinatorStatus {
     Initializing,
@@ -37,6 +94,9 @@ pub struct ChunkLock {
     chunk_id: u64,
     /// The time that the chunk was locked.
     lock_time: DateTime<Utc>,
+    /// The random token issued to the participant with this lock.
+    #[serde(default)]
+    token: String,
 }
 
 impl ChunkLock {
@@ -46,6 +106,7 @@ impl ChunkLock {
         Self {
             chunk_id,
             lock_time: time.utc_now(),
+            token: hex::encode(rand::random::<[u8; 16]>()),
         }
     }
 
@@ -58,6 +119,13 @@ impl ChunkLock {
     pub fn lock_time(&self) -> &DateTime<Utc> {
         &self.lock_time
     }
+
+    /// The random token issued to the participant with this lock.
+    ///
+    /// Locks acquired before tokens were issued have an empty token.
+    pub fn token(&self) -> &str {
+        &self.token
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -92,6 +160,9 @@ pub struct ParticipantInfo {
     disposing_tasks: LinkedList<Task>,
     /// The list of (chunk ID, contribution ID) tasks that are disposed of while computing.
     disposed_tasks: LinkedList<Task>,
+    /// The hardware this participant declared when joining the queue, if any.
+    #[serde(default)]
+    hardware: Option<HardwareDeclaration>,
 }
 
 impl ParticipantInfo {
@@ -121,6 +192,7 @@ impl ParticipantInfo {
             completed_tasks: LinkedList::new(),
             disposing_tasks: LinkedList::new(),
             disposed_tasks: LinkedList::new(),
+            hardware: None,
         }
     }
 
@@ -173,6 +245,13 @@ impl ParticipantInfo {
         &self.disposed_tasks
     }
 
+    ///
+    /// Returns the hardware this participant declared when joining the queue, if any.
+    ///
+    pub fn hardware(&self) -> Option<&HardwareDeclaration> {
+        self.hardware.as_ref()
+    }
+
     ///
     /// Returns `true` if the participant is dropped from the current round.
     ///
@@ -187,6 +266,22 @@ impl ParticipantInfo {
         self.dropped_at.is_some()
     }
 
+    ///
+    /// Returns `true` if the participant is a contributor who was
+    /// started in the current round without any tasks to compute.
+    ///
+    #[inline]
+    fn is_spare(&self) -> bool {
+        self.id.is_contributor()
+            && self.started_at.is_some()
+            && self.locked_chunks.is_empty()
+            && self.assigned_tasks.is_empty()
+            && self.pending_tasks.is_empty()
+            && self.completed_tasks.is_empty()
+            && self.disposing_tasks.is_empty()
+            && self.disposed_tasks.is_empty()
+    }
+
     ///
     /// Returns `true` if the participant is finished with the current round.
     ///
@@ -223,7 +318,7 @@ impl ParticipantInfo {
         }
 
         // Check that if the participant is a contributor, that they completed tasks.
-        if self.id.is_contributor() && self.completed_tasks.is_empty() {
+        if self.id.is_contributor() && self.completed_tasks.is_empty() && !self.is_spare() {
             return false;
         }
 
@@ -618,6 +713,36 @@ impl ParticipantInfo {
         Ok(())
     }
 
+    ///
+    /// Releases the lock held by this participant on the given chunk ID, and reverts
+    /// any pending task for the chunk ID to the front of the assigned tasks.
+    ///
+    /// This function is used when a lock is released by the coordinator,
+    /// regardless of the state of the participant's work on the chunk.
+    ///
+    #[inline]
+    fn released_lock(&mut self, chunk_id: u64, time: &dyn TimeSource) -> Result<(), CoordinatorError> {
+        trace!("Releasing the lock on chunk {} for {}", chunk_id, self.id);
+
+        // Check that the participant had locked this chunk.
+        if self.locked_chunks.remove(&chunk_id).is_none() {
+            return Err(CoordinatorError::ParticipantDidntLockChunkId);
+        }
+
+        // Revert the pending tasks for the given chunk ID to assigned tasks.
+        let pending_tasks: Vec<Task> = self
+            .pending_tasks
+            .iter()
+            .filter(|t| t.contains(chunk_id))
+            .cloned()
+            .collect();
+        for task in pending_tasks {
+            self.rollback_pending_task(task, time)?;
+        }
+
+        Ok(())
+    }
+
     ///
     /// Adds the given [Task] to the list of completed tasks and
     /// removes the given chunk ID from the locked chunks held by this
@@ -695,6 +820,100 @@ impl ParticipantInfo {
         Ok(())
     }
 
+    ///
+    /// Removes the given (chunk ID, contribution ID) task, whose contribution failed verification,
+    /// from the pending tasks of this verifier, and releases the lock on its chunk.
+    ///
+    #[inline]
+    fn failed_task(&mut self, task: Task, time: &dyn TimeSource) -> Result<(), CoordinatorError> {
+        trace!("Failing task for {}", self.id);
+
+        // Check that the participant has started in the round.
+        if self.started_at.is_none() {
+            return Err(CoordinatorError::ParticipantHasNotStarted);
+        }
+
+        // Check that the participant was not dropped from the round.
+        if self.dropped_at.is_some() {
+            return Err(CoordinatorError::ParticipantWasDropped);
+        }
+
+        // Check that the participant has not finished the round.
+        if self.finished_at.is_some() {
+            return Err(CoordinatorError::ParticipantAlreadyFinished);
+        }
+
+        // Check that the participant had locked this chunk.
+        if !self.locked_chunks.contains_key(&task.chunk_id()) {
+            return Err(CoordinatorError::ParticipantDidntLockChunkId);
+        }
+
+        // Check that the participant has a pending task for this.
+        if !self.pending_tasks.contains(&task) {
+            return Err(CoordinatorError::ParticipantMissingPendingTask { pending_task: task });
+        }
+
+        // Update the last seen time.
+        self.last_seen = time.utc_now();
+
+        // Remove the given chunk ID from the locked chunks.
+        self.locked_chunks.remove(&task.chunk_id());
+
+        // Remove the task from the pending tasks.
+        self.pending_tasks = self
+            .pending_tasks
+            .clone()
+            .into_par_iter()
+            .filter(|t| *t != task)
+            .collect();
+
+        Ok(())
+    }
+
+    ///
+    /// Reverts the given (chunk ID, contribution ID) task, whose contribution failed verification,
+    /// from the completed tasks of this contributor to the front of its assigned tasks.
+    ///
+    /// If the contributor had already finished the round, it is resumed to compute the task again.
+    ///
+    #[inline]
+    fn retry_task(&mut self, task: Task) -> Result<(), CoordinatorError> {
+        trace!("Retrying task for {}", self.id);
+
+        // Check that the participant has started in the round.
+        if self.started_at.is_none() {
+            return Err(CoordinatorError::ParticipantHasNotStarted);
+        }
+
+        // Check that the participant was not dropped from the round.
+        if self.dropped_at.is_some() {
+            return Err(CoordinatorError::ParticipantWasDropped);
+        }
+
+        // Check that the participant has completed the task.
+        if !self.completed_tasks.contains(&task) {
+            return Err(CoordinatorError::ParticipantUnauthorizedForChunkId {
+                chunk_id: task.chunk_id(),
+            });
+        }
+
+        // Remove the task from the completed tasks.
+        self.completed_tasks = self
+            .completed_tasks
+            .clone()
+            .into_par_iter()
+            .filter(|t| *t != task)
+            .collect();
+
+        // Add the task to the front of the assigned tasks.
+        self.assigned_tasks.push_front(task);
+
+        // Resume the participant, if it had finished the round.
+        self.finished_at = None;
+
+        Ok(())
+    }
+
     ///
     /// Completes the disposal of a given chunk (chunk ID, contribution ID) task present in the `disposing_tasks` list to the list of disposed tasks
     /// and removes the given chunk ID from the locked chunks held by this participant.
@@ -827,7 +1046,7 @@ impl ParticipantInfo {
         }
 
         // Check that if the participant is a contributor, that they completed tasks.
-        if self.id.is_contributor() && self.completed_tasks.is_empty() {
+        if self.id.is_contributor() && self.completed_tasks.is_empty() && !self.is_spare() {
             return Err(CoordinatorError::ParticipantDidNotDoWork);
         }
 
@@ -860,6 +1079,34 @@ impl ParticipantInfo {
     }
 }
 
+/// A summary of the progress of a participant in the current round.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ParticipantProgress {
+    /// The IDs of the chunks that this participant currently holds a lock on.
+    locked_chunks: Vec<u64>,
+    /// The IDs of the chunks that this participant finished in the current round.
+    completed_chunks: Vec<u64>,
+    /// The ID of the chunk that this participant is assigned to process next, if any.
+    next_chunk: Option<u64>,
+}
+
+impl ParticipantProgress {
+    /// The IDs of the chunks that the participant currently holds a lock on.
+    pub fn locked_chunks(&self) -> &[u64] {
+        &self.locked_chunks
+    }
+
+    /// The IDs of the chunks that the participant finished in the current round.
+    pub fn completed_chunks(&self) -> &[u64] {
+        &self.completed_chunks
+    }
+
+    /// The ID of the chunk that the participant is assigned to process next, if any.
+    pub fn next_chunk(&self) -> Option<u64> {
+        self.next_chunk
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RoundMetrics {
     /// The number of contributors participating in the current round.
@@ -910,6 +1157,33 @@ impl Default for RoundMetrics {
     }
 }
 
+impl RoundMetrics {
+    ///
+    /// Returns the number of seconds the coordinator took to aggregate the current round,
+    /// if the aggregation finished.
+    ///
+    #[inline]
+    pub(super) fn aggregation_seconds(&self) -> Option<u64> {
+        match (self.started_aggregation_at, self.finished_aggregation_at) {
+            (Some(started_at), Some(finished_at)) => Some((finished_at - started_at).num_seconds().max(0) as u64),
+            _ => None,
+        }
+    }
+}
+
+/// A response file reserved by a contributor ahead of locking its chunk.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContributionReservation {
+    /// The contributor who reserved the response file.
+    pub participant: Participant,
+    /// The round height of the reserved response file.
+    pub round_height: u64,
+    /// The contribution ID of the reserved response file.
+    pub contribution_id: u64,
+    /// The time the response file was reserved.
+    pub reserved_at: DateTime<Utc>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CoordinatorState {
     /// The parameters and settings of this coordinator.
@@ -918,6 +1192,25 @@ pub struct CoordinatorState {
     status: CoordinatorStatus,
     /// The map of queue participants with a reliability score and an assigned future round.
     queue: HashMap<Participant, (u8, Option<u64>)>,
+    /// The map of queue contributors to the hardware they declared when joining the queue.
+    #[serde(default)]
+    queue_hardware: HashMap<Participant, HardwareDeclaration>,
+    /// The map of queue participants to the sequence number they were admitted to the queue with.
+    #[serde(default)]
+    queue_admissions: HashMap<Participant, u64>,
+    /// The sequence number of the next participant admitted to the queue.
+    #[serde(default)]
+    queue_admission_sequence: u64,
+    /// The map of participants to the client they last identified themselves with.
+    #[serde(default)]
+    participant_clients: HashMap<Participant, ClientVersion>,
+    /// The map of the proof-of-work challenges which have been used to join the queue, to the time
+    /// they expire at. Challenges are signed rather than stored, so only the consumed ones are kept.
+    #[serde(default)]
+    consumed_admission_challenges: HashMap<String, DateTime<Utc>>,
+    /// The set of IDs of the invitation tokens which have been used to join the queue.
+    #[serde(default)]
+    consumed_invitations: HashSet<String>,
     /// The map of unique participants for the next round.
     next: HashMap<Participant, ParticipantInfo>,
     /// The metrics for the current round of the ceremony.
@@ -940,6 +1233,27 @@ pub struct CoordinatorState {
     banned: HashSet<Participant>,
     /// The manual lock to hold the coordinator from transitioning to the next round.
     manual_lock: bool,
+    /// The flag to pause the ceremony for maintenance, which rejects new locks and contributions.
+    #[serde(default)]
+    paused: bool,
+    /// The time the ceremony was paused, to suspend the participant timeouts until it is resumed.
+    #[serde(default)]
+    paused_at: Option<DateTime<Utc>>,
+    /// The ID of the ceremony, recorded when the ceremony is initialized.
+    #[serde(default)]
+    ceremony_id: Option<String>,
+    /// The history of the rounds of the ceremony.
+    #[serde(default)]
+    history: CeremonyHistory,
+    /// The list of contributions that failed verification in the current round.
+    #[serde(default)]
+    failed_contributions: Vec<FailedContribution>,
+    /// The timings of the verifications performed in the ceremony, in the order they completed.
+    #[serde(default)]
+    verification_timings: Vec<VerificationTiming>,
+    /// The response files reserved by contributors ahead of locking their chunk, by chunk ID.
+    #[serde(default)]
+    contribution_reservations: HashMap<u64, ContributionReservation>,
 }
 
 impl CoordinatorState {
@@ -952,6 +1266,12 @@ impl CoordinatorState {
             environment,
             status: CoordinatorStatus::Initializing,
             queue: HashMap::default(),
+            queue_hardware: HashMap::default(),
+            queue_admissions: HashMap::default(),
+            queue_admission_sequence: 0,
+            participant_clients: HashMap::default(),
+            consumed_admission_challenges: HashMap::default(),
+            consumed_invitations: HashSet::default(),
             next: HashMap::default(),
             current_metrics: None,
             current_round_height: None,
@@ -963,6 +1283,13 @@ impl CoordinatorState {
             dropped: Vec::new(),
             banned: HashSet::new(),
             manual_lock: false,
+            paused: false,
+            paused_at: None,
+            ceremony_id: None,
+            history: CeremonyHistory::default(),
+            failed_contributions: Vec::new(),
+            verification_timings: Vec::new(),
+            contribution_reservations: HashMap::default(),
         }
     }
 
@@ -1011,7 +1338,6 @@ impl CoordinatorState {
 
         let number_of_contributors = self.current_contributors.len() + finished_contributors.len();
         let number_of_verifiers = self.current_verifiers.len() + finished_verifiers.len();
-        let number_of_chunks = self.environment.number_of_chunks() as u64;
 
         let current_contributors = self
             .current_contributors
@@ -1021,7 +1347,7 @@ impl CoordinatorState {
             .enumerate()
             .map(|(bucket_index, (participant, mut participant_info))| {
                 let bucket_id = bucket_index as u64;
-                let tasks = initialize_tasks(bucket_id, number_of_chunks, number_of_contributors as u64)?;
+                let tasks = initialize_contributor_tasks(&self.environment, bucket_id, number_of_contributors as u64)?;
                 participant_info.restart_tasks(tasks, time)?;
                 Ok((participant, participant_info))
             })
@@ -1077,6 +1403,7 @@ impl CoordinatorState {
             });
 
             let mut queue = self.queue.clone();
+            let mut queue_hardware = self.queue_hardware.clone();
 
             // Add each participant back into the queue.
             for (participant, participant_info) in current_contributors
@@ -1088,13 +1415,19 @@ impl CoordinatorState {
                     participant.clone(),
                     (participant_info.reliability, Some(participant_info.round_height)),
                 );
+                if let Some(hardware) = participant_info.hardware {
+                    queue_hardware.insert(participant.clone(), hardware);
+                }
             }
 
             *self = Self {
                 current_metrics,
                 current_round_height: Some(new_round_height),
                 queue,
+                queue_hardware,
                 banned: self.banned.clone(),
+                history: self.history.clone(),
+                verification_timings: self.verification_timings.clone(),
                 ..Self::new(self.environment.clone())
             };
 
@@ -1137,6 +1470,8 @@ impl CoordinatorState {
                 queue: self.queue.clone(),
                 banned: self.banned.clone(),
                 dropped: self.dropped.clone(),
+                history: self.history.clone(),
+                verification_timings: self.verification_timings.clone(),
                 ..Self::new(self.environment.clone())
             };
 
@@ -1156,6 +1491,14 @@ impl CoordinatorState {
     ///
     #[inline]
     pub(super) fn initialize(&mut self, current_round_height: u64) {
+        // Record the ID of the ceremony, if it has not been recorded yet.
+        if self.ceremony_id.is_none() {
+            self.ceremony_id = Some(match self.environment.ceremony_id() {
+                Some(ceremony_id) => ceremony_id.to_string(),
+                None => new_ceremony_id(),
+            });
+        }
+
         // Set the current round height to the given round height.
         if self.current_round_height.is_none() {
             self.current_round_height = Some(current_round_height);
@@ -1196,6 +1539,41 @@ impl CoordinatorState {
         participant.is_verifier() && self.queue.contains_key(participant)
     }
 
+    ///
+    /// Returns `true` if the given participant is in the queue.
+    ///
+    #[inline]
+    pub fn participant_is_queued(&self, participant: &Participant) -> bool {
+        self.queue.contains_key(participant)
+    }
+
+    ///
+    /// Returns the zero-based position of the given participant in the queue,
+    /// in the order participants were admitted to the queue, or `None` if the
+    /// participant is not in the queue.
+    ///
+    /// Participants without a recorded admission, such as those restored from
+    /// an older coordinator state, are ordered after all other participants.
+    ///
+    #[inline]
+    pub fn queue_position(&self, participant: &Participant) -> Result<Option<usize>, CoordinatorError> {
+        // Check that the coordinator state is initialized.
+        if self.status == CoordinatorStatus::Initializing {
+            return Err(CoordinatorError::CoordinatorStateNotInitialized);
+        }
+
+        if !self.queue.contains_key(participant) {
+            return Ok(None);
+        }
+
+        // Sort the queue participants by their admission, breaking ties by participant ID.
+        let admission = |p: &Participant| self.queue_admissions.get(p).copied().unwrap_or(u64::MAX);
+        let mut queue: Vec<_> = self.queue.keys().collect();
+        queue.sort_by(|a, b| admission(a).cmp(&admission(b)).then_with(|| a.cmp(b)));
+
+        Ok(queue.into_iter().position(|p| p == participant))
+    }
+
     ///
     /// Returns `true` if the given participant is an authorized contributor in the ceremony.
     ///
@@ -1344,6 +1722,35 @@ impl CoordinatorState {
         }
     }
 
+    ///
+    /// Returns the progress of the given participant in the current round.
+    ///
+    /// The next chunk is the chunk of the task that the participant will be
+    /// given on its next call to fetch a task, in the order they were assigned.
+    ///
+    #[inline]
+    pub fn participant_progress(&self, participant: &Participant) -> Result<ParticipantProgress, CoordinatorError> {
+        let participant_info = self
+            .current_participant_info(participant)
+            .ok_or_else(|| CoordinatorError::ParticipantNotFound(participant.clone()))?;
+
+        let mut locked_chunks: Vec<u64> = participant_info.locked_chunks.keys().cloned().collect();
+        locked_chunks.sort_unstable();
+
+        let mut completed_chunks: Vec<u64> = participant_info
+            .completed_tasks
+            .iter()
+            .map(|task| task.chunk_id())
+            .collect();
+        completed_chunks.sort_unstable();
+
+        Ok(ParticipantProgress {
+            locked_chunks,
+            completed_chunks,
+            next_chunk: participant_info.assigned_tasks.front().map(|task| task.chunk_id()),
+        })
+    }
+
     ///
     /// Returns a list of the verifiers currently in the round.
     ///
@@ -1360,6 +1767,195 @@ impl CoordinatorState {
         self.dropped.clone()
     }
 
+    ///
+    /// Returns the ID of the ceremony, if the ceremony has been initialized.
+    ///
+    #[inline]
+    pub fn ceremony_id(&self) -> Option<&str> {
+        self.ceremony_id.as_deref()
+    }
+
+    ///
+    /// Returns the history of the rounds of the ceremony.
+    ///
+    #[inline]
+    pub fn history(&self) -> &CeremonyHistory {
+        &self.history
+    }
+
+    ///
+    /// Returns the contributions that failed verification in the current round,
+    /// in the order they failed.
+    ///
+    #[inline]
+    pub fn failed_contributions(&self) -> &[FailedContribution] {
+        &self.failed_contributions
+    }
+
+    ///
+    /// Returns the timings of the verifications performed in the ceremony,
+    /// in the order they completed.
+    ///
+    #[inline]
+    pub fn verification_timings(&self) -> &[VerificationTiming] {
+        &self.verification_timings
+    }
+
+    ///
+    /// Records the timing of a verification performed in the ceremony,
+    /// dropping the oldest timing once `MAXIMUM_VERIFICATION_TIMINGS` are held.
+    ///
+    #[inline]
+    pub(super) fn record_verification_timing(&mut self, timing: VerificationTiming) {
+        trace!(
+            "Recording verification of round {} chunk {} in {} seconds",
+            timing.round_height,
+            timing.chunk_id,
+            timing.verification_duration.num_seconds()
+        );
+        self.verification_timings.push(timing);
+
+        if self.verification_timings.len() > MAXIMUM_VERIFICATION_TIMINGS {
+            let excess = self.verification_timings.len() - MAXIMUM_VERIFICATION_TIMINGS;
+            self.verification_timings.drain(..excess);
+        }
+    }
+
+    ///
+    /// Returns the task of the given chunk ID which is assigned to the given contributor, if any.
+    ///
+    #[inline]
+    pub(super) fn assigned_task(&self, participant: &Participant, chunk_id: u64) -> Option<Task> {
+        self.current_contributors
+            .get(participant)?
+            .assigned_tasks
+            .iter()
+            .find(|task| task.chunk_id() == chunk_id)
+            .copied()
+    }
+
+    ///
+    /// Returns the reservation of the response file of the given chunk ID, if any.
+    ///
+    #[inline]
+    pub fn contribution_reservation(&self, chunk_id: u64) -> Option<&ContributionReservation> {
+        self.contribution_reservations.get(&chunk_id)
+    }
+
+    ///
+    /// Returns `true` if the given reservation is for the current round, has not expired
+    /// at the given time, and is held by a current contributor. Otherwise, returns `false`.
+    ///
+    /// A reservation expires after the participant lock timeout, as it stands in for a lock
+    /// until the contributor locks the chunk.
+    ///
+    #[inline]
+    pub(super) fn is_contribution_reservation_live(
+        &self,
+        reservation: &ContributionReservation,
+        now: DateTime<Utc>,
+    ) -> bool {
+        reservation.round_height == self.current_round_height()
+            && now - reservation.reserved_at < self.environment.participant_lock_timeout()
+            && self.is_current_contributor(&reservation.participant)
+    }
+
+    ///
+    /// Records the reservation of the response file of the given chunk ID.
+    ///
+    #[inline]
+    pub(super) fn reserve_contribution(&mut self, chunk_id: u64, reservation: ContributionReservation) {
+        trace!(
+            "{} reserved round {} chunk {} contribution {}",
+            reservation.participant,
+            reservation.round_height,
+            chunk_id,
+            reservation.contribution_id
+        );
+        self.contribution_reservations.insert(chunk_id, reservation);
+    }
+
+    ///
+    /// Removes and returns the reservation of the response file of the given chunk ID, if any.
+    ///
+    #[inline]
+    pub(super) fn take_contribution_reservation(&mut self, chunk_id: u64) -> Option<ContributionReservation> {
+        self.contribution_reservations.remove(&chunk_id)
+    }
+
+    ///
+    /// Returns the client the given participant last identified themselves with, if any.
+    ///
+    #[inline]
+    pub fn participant_client(&self, participant: &Participant) -> Option<&ClientVersion> {
+        self.participant_clients.get(participant)
+    }
+
+    ///
+    /// Records the client the given participant identified themselves with.
+    ///
+    /// Returns `true` if the recorded client changed. Otherwise, returns `false`.
+    ///
+    #[inline]
+    pub(super) fn set_participant_client(&mut self, participant: &Participant, client: ClientVersion) -> bool {
+        self.participant_clients.insert(participant.clone(), client.clone()) != Some(client)
+    }
+
+    ///
+    /// Checks that the given contributor passes the admission gates of the environment
+    /// with the given credentials, without consuming them.
+    ///
+    /// On success, returns the ID of the invitation token and the proof-of-work
+    /// challenge to consume, if they are required.
+    ///
+    #[inline]
+    fn check_admission(
+        &self,
+        participant: &Participant,
+        credentials: Option<&AdmissionCredentials>,
+        now: DateTime<Utc>,
+    ) -> Result<(Option<String>, Option<ProofOfWorkChallenge>), CoordinatorError> {
+        let policy = match self.environment.admission_policy() {
+            Some(policy) => policy,
+            None => return Ok((None, None)),
+        };
+        let credentials = credentials.cloned().unwrap_or_default();
+
+        // Check that the invitation token is signed by the operator and has not been used before.
+        let invitation_id = match policy.invitation() {
+            Some(invitation) => {
+                let token = credentials.invitation.ok_or(CoordinatorError::InvitationMissing)?;
+                let id = invitation.verify(&token)?;
+                if self.consumed_invitations.contains(&id) {
+                    return Err(CoordinatorError::InvitationConsumed);
+                }
+                Some(id)
+            }
+            None => None,
+        };
+
+        // Check that the nonce solves an unexpired challenge issued to the contributor, which has not been used before.
+        let challenge = match policy.proof_of_work() {
+            Some(proof_of_work) => {
+                let nonce = credentials.nonce.ok_or(CoordinatorError::ProofOfWorkMissing)?;
+                let challenge = credentials
+                    .challenge
+                    .ok_or(CoordinatorError::ProofOfWorkChallengeMissing)?;
+                proof_of_work.verify(participant, &challenge, now)?;
+                if self.consumed_admission_challenges.contains_key(&challenge.challenge) {
+                    return Err(CoordinatorError::ProofOfWorkChallengeConsumed);
+                }
+                if !challenge.is_solved_by(participant, nonce) {
+                    return Err(CoordinatorError::ProofOfWorkInsufficient);
+                }
+                Some(challenge)
+            }
+            None => None,
+        };
+
+        Ok((invitation_id, challenge))
+    }
+
     ///
     /// Returns the current round height stored in the coordinator state.
     ///
@@ -1378,6 +1974,35 @@ impl CoordinatorState {
         self.current_metrics.clone()
     }
 
+    ///
+    /// Returns the estimated time remaining for the current round to finish the given
+    /// number of remaining contributions.
+    ///
+    /// The estimate is the average time per contribution in the current round metrics,
+    /// as of the last update of the round metrics, for each remaining contribution, shared
+    /// among the current contributors who contribute in parallel. Task timers record whole
+    /// seconds, so the estimate is rounded up to whole seconds.
+    ///
+    /// If no contribution has been timed to completion in the current round,
+    /// returns `None`.
+    ///
+    #[inline]
+    pub fn estimate_time_remaining(&self, contributions_remaining: u64) -> Option<Duration> {
+        let metrics = self.current_metrics.as_ref()?;
+
+        // Check that a contribution has been timed to completion in the current round.
+        let average_per_task = metrics.contributor_average_per_task?;
+
+        if contributions_remaining == 0 {
+            return Some(Duration::zero());
+        }
+
+        let number_of_contributors = std::cmp::max(self.current_contributors.len() as u64, 1);
+        let cumulative_seconds = average_per_task * contributions_remaining;
+        let seconds = (cumulative_seconds + number_of_contributors - 1) / number_of_contributors;
+        Some(Duration::seconds(seconds as i64))
+    }
+
     ///
     /// Returns `true` if all participants in the current round have no more pending chunks.
     ///
@@ -1518,15 +2143,66 @@ impl CoordinatorState {
         true
     }
 
+    ///
+    /// Restores the secrets of the environment which are not serialized, such as the key
+    /// of the invitation tokens, from the environment the coordinator was started with.
+    ///
+    #[inline]
+    pub(super) fn restore_secrets(&mut self, environment: &Environment) {
+        self.environment.restore_secrets(environment);
+    }
+
     ///
     /// Adds the given participant to the queue if they are permitted to participate.
     ///
+    #[cfg(test)]
     #[inline]
     pub(super) fn add_to_queue(
         &mut self,
         participant: Participant,
         reliability_score: u8,
     ) -> Result<(), CoordinatorError> {
+        self.add_to_queue_with_hardware(participant, reliability_score, None)
+    }
+
+    ///
+    /// Adds the given participant to the queue if they are permitted to participate,
+    /// recording the hardware they declared.
+    ///
+    /// If the environment has a hardware policy, contributors other than
+    /// the coordinator contributors must declare hardware meeting its minimum.
+    ///
+    #[cfg(test)]
+    #[inline]
+    pub(super) fn add_to_queue_with_hardware(
+        &mut self,
+        participant: Participant,
+        reliability_score: u8,
+        hardware: Option<HardwareDeclaration>,
+    ) -> Result<(), CoordinatorError> {
+        // The time is only read to check the solution of a proof-of-work, which requires credentials.
+        self.add_to_queue_with_admission(participant, reliability_score, hardware, None, Utc::now())
+    }
+
+    ///
+    /// Adds the given participant to the queue if they are permitted to participate,
+    /// recording the hardware they declared.
+    ///
+    /// If the environment has an admission policy, contributors other than the coordinator
+    /// contributors must pass its gates with the given credentials at the given time.
+    /// The invitation token and the proof-of-work challenge are consumed on success.
+    ///
+    #[inline]
+    pub(super) fn add_to_queue_with_admission(
+        &mut self,
+        participant: Participant,
+        reliability_score: u8,
+        hardware: Option<HardwareDeclaration>,
+        credentials: Option<&AdmissionCredentials>,
+        now: DateTime<Utc>,
+    ) -> Result<(), CoordinatorError> {
+        let (mut invitation_id, mut challenge) = (None, None);
+
         // Check that the participant is not banned from participating.
         if self.banned.contains(&participant) {
             return Err(CoordinatorError::ParticipantBanned);
@@ -1555,6 +2231,23 @@ impl CoordinatorState {
                 {
                     return Err(CoordinatorError::ParticipantInCurrentRoundCannotJoinQueue);
                 }
+
+                // Check that the declared hardware meets the minimum, if there is a hardware policy.
+                if let Some(policy) = self.environment.hardware_policy() {
+                    if !self.is_coordinator_contributor(&participant) {
+                        match &hardware {
+                            Some(declaration) => policy.evaluate(declaration)?,
+                            None => return Err(CoordinatorError::ParticipantHardwareUndeclared),
+                        }
+                    }
+                }
+
+                // Check that the contributor passes the admission gates, if there is an admission policy.
+                if !self.is_coordinator_contributor(&participant) {
+                    let admission = self.check_admission(&participant, credentials, now)?;
+                    invitation_id = admission.0;
+                    challenge = admission.1;
+                }
             }
             Participant::Verifier(_) => {
                 // Check if the verifier is authorized.
@@ -1571,6 +2264,28 @@ impl CoordinatorState {
             }
         }
 
+        // Record the declared hardware of the participant.
+        if let Some(hardware) = hardware {
+            self.queue_hardware.insert(participant.clone(), hardware);
+        }
+
+        // Consume the invitation token and the proof-of-work challenge of the participant,
+        // and forget the consumed challenges which have expired, as they are rejected regardless.
+        if let Some(invitation_id) = invitation_id {
+            self.consumed_invitations.insert(invitation_id);
+        }
+        if let Some(challenge) = challenge {
+            self.consumed_admission_challenges
+                .retain(|_, expires_at| now < *expires_at);
+            self.consumed_admission_challenges
+                .insert(challenge.challenge, challenge.expires_at);
+        }
+
+        // Record the admission order of the participant.
+        self.queue_admissions
+            .insert(participant.clone(), self.queue_admission_sequence);
+        self.queue_admission_sequence += 1;
+
         // Add the participant to the queue.
         self.queue.insert(participant, (reliability_score, None));
 
@@ -1594,6 +2309,8 @@ impl CoordinatorState {
 
         // Remove the participant from the queue.
         self.queue.remove(participant);
+        self.queue_hardware.remove(participant);
+        self.queue_admissions.remove(participant);
 
         Ok(())
     }
@@ -1650,9 +2367,7 @@ impl CoordinatorState {
         time: &dyn TimeSource,
     ) -> Result<(), CoordinatorError> {
         // Check that the chunk ID is valid.
-        if chunk_id > self.environment.number_of_chunks() {
-            return Err(CoordinatorError::ChunkIdInvalid);
-        }
+        self.environment.validate_chunk_id(chunk_id)?;
 
         match participant {
             Participant::Contributor(_) => match self.current_contributors.get_mut(participant) {
@@ -1668,6 +2383,23 @@ impl CoordinatorState {
         }
     }
 
+    ///
+    /// Returns the token of the lock held by the given current participant
+    /// on the given chunk ID, if the participant holds a lock with a token on it.
+    ///
+    #[inline]
+    pub(super) fn lock_token(&self, participant: &Participant, chunk_id: u64) -> Option<&str> {
+        let participant_info = match participant {
+            Participant::Contributor(_) => self.current_contributors.get(participant)?,
+            Participant::Verifier(_) => self.current_verifiers.get(participant)?,
+        };
+        participant_info
+            .locked_chunks
+            .get(&chunk_id)
+            .map(|lock| lock.token())
+            .filter(|token| !token.is_empty())
+    }
+
     ///
     /// Reverts the given (chunk ID, contribution ID) task to the list of assigned tasks
     /// from the list of pending tasks.
@@ -1680,9 +2412,7 @@ impl CoordinatorState {
         time: &dyn TimeSource,
     ) -> Result<(), CoordinatorError> {
         // Check that the chunk ID is valid.
-        if task.chunk_id() > self.environment.number_of_chunks() {
-            return Err(CoordinatorError::ChunkIdInvalid);
-        }
+        self.environment.validate_chunk_id(task.chunk_id())?;
 
         match self.current_participant_info_mut(participant) {
             Some(participant) => Ok(participant.rollback_pending_task(task, time)?),
@@ -1690,6 +2420,26 @@ impl CoordinatorState {
         }
     }
 
+    ///
+    /// Releases the lock on the given chunk ID held by the given participant,
+    /// and reverts the pending task for the chunk ID to the assigned tasks.
+    ///
+    #[inline]
+    pub(super) fn released_lock(
+        &mut self,
+        participant: &Participant,
+        chunk_id: u64,
+        time: &dyn TimeSource,
+    ) -> Result<(), CoordinatorError> {
+        // Check that the chunk ID is valid.
+        self.environment.validate_chunk_id(chunk_id)?;
+
+        match self.current_participant_info_mut(participant) {
+            Some(participant) => Ok(participant.released_lock(chunk_id, time)?),
+            None => Err(CoordinatorError::ParticipantNotFound(participant.clone())),
+        }
+    }
+
     ///
     /// Returns the (chunk ID, contribution ID) task if the given participant has the
     /// given chunk ID in a pending task.
@@ -1700,9 +2450,7 @@ impl CoordinatorState {
         chunk_id: u64,
     ) -> Result<Option<&Task>, CoordinatorError> {
         // Check that the chunk ID is valid.
-        if chunk_id > self.environment.number_of_chunks() {
-            return Err(CoordinatorError::ChunkIdInvalid);
-        }
+        self.environment.validate_chunk_id(chunk_id)?;
 
         // Fetch the participant info for the given participant.
         let participant_info = match participant {
@@ -1744,9 +2492,7 @@ impl CoordinatorState {
         chunk_id: u64,
     ) -> Result<Option<&Task>, CoordinatorError> {
         // Check that the chunk ID is valid.
-        if chunk_id > self.environment.number_of_chunks() {
-            return Err(CoordinatorError::ChunkIdInvalid);
-        }
+        self.environment.validate_chunk_id(chunk_id)?;
 
         // Fetch the participant info for the given participant.
         let participant_info = match participant {
@@ -1794,9 +2540,7 @@ impl CoordinatorState {
         let contribution_id = task.contribution_id();
 
         // Check that the chunk ID is valid.
-        if chunk_id > self.environment.number_of_chunks() {
-            return Err(CoordinatorError::ChunkIdInvalid);
-        }
+        self.environment.validate_chunk_id(chunk_id)?;
 
         warn!(
             "Disposing chunk {} contribution {} from {}",
@@ -1830,23 +2574,56 @@ impl CoordinatorState {
         time: &dyn TimeSource,
     ) -> Result<Participant, CoordinatorError> {
         // Check that the chunk ID is valid.
-        if task.chunk_id() > self.environment.number_of_chunks() {
-            return Err(CoordinatorError::ChunkIdInvalid);
-        }
+        self.environment.validate_chunk_id(task.chunk_id())?;
 
-        // Check that the pending verification set does not already contain the chunk ID.
-        if self.pending_verification.contains_key(&task) {
-            return Err(CoordinatorError::ChunkIdAlreadyAdded);
-        }
+        // Assign the task to the least busy verifier, who has not verified it already.
+        let verifier = self.next_pending_verifier(task, None)?;
+        self.assign_pending_verification(task, verifier, time)
+    }
 
-        let verifier = match self
+    ///
+    /// Returns the verifier to assign the given (chunk ID, contribution ID) task to for verification,
+    /// which is the least busy current verifier who has not verified it already, other than the given
+    /// excluded verifier.
+    ///
+    /// This function does not change the state, so callers may check a verifier is available before
+    /// recording a verification which needs another one.
+    ///
+    #[inline]
+    pub(super) fn next_pending_verifier(
+        &self,
+        task: Task,
+        excluded: Option<&Participant>,
+    ) -> Result<Participant, CoordinatorError> {
+        match self
             .current_verifiers
             .par_iter()
+            .filter(|(verifier, _)| Some(*verifier) != excluded)
+            .filter(|(_, v)| !v.completed_tasks.contains(&task))
             .min_by_key(|(_, v)| v.assigned_tasks.len() + v.pending_tasks.len() + v.locked_chunks.len())
         {
-            Some((verifier, _verifier_info)) => verifier.clone(),
-            None => return Err(CoordinatorError::VerifierMissing),
-        };
+            Some((verifier, _verifier_info)) => Ok(verifier.clone()),
+            None => Err(CoordinatorError::VerifierMissing),
+        }
+    }
+
+    ///
+    /// Adds the given (chunk ID, contribution ID) task to the pending verification set,
+    /// and assigns it to the given verifier, as selected by `next_pending_verifier`.
+    ///
+    /// On success, this function returns the verifier that was assigned to the verification task.
+    ///
+    #[inline]
+    pub(super) fn assign_pending_verification(
+        &mut self,
+        task: Task,
+        verifier: Participant,
+        time: &dyn TimeSource,
+    ) -> Result<Participant, CoordinatorError> {
+        // Check that the pending verification set does not already contain the chunk ID.
+        if self.pending_verification.contains_key(&task) {
+            return Err(CoordinatorError::ChunkIdAlreadyAdded);
+        }
 
         info!(
             "Assigning (chunk {}, contribution {}) to {} for verification",
@@ -1873,9 +2650,7 @@ impl CoordinatorState {
     #[inline]
     pub(super) fn remove_pending_verification(&mut self, task: Task) -> Result<Participant, CoordinatorError> {
         // Check that the chunk ID is valid.
-        if task.chunk_id() > self.environment.number_of_chunks() {
-            return Err(CoordinatorError::ChunkIdInvalid);
-        }
+        self.environment.validate_chunk_id(task.chunk_id())?;
 
         // Check that the set pending verification does not already contain the chunk ID.
         if !self.pending_verification.contains_key(&task) {
@@ -1916,32 +2691,115 @@ impl CoordinatorState {
         time: &dyn TimeSource,
     ) -> Result<Participant, CoordinatorError> {
         // Check that the chunk ID is valid.
-        if task.chunk_id() > self.environment.number_of_chunks() {
-            return Err(CoordinatorError::ChunkIdInvalid);
+        self.environment.validate_chunk_id(task.chunk_id())?;
+
+        match participant {
+            Participant::Contributor(_) => match self.current_contributors.get_mut(participant) {
+                // Adds the task to the list of completed tasks for the contributor,
+                // and add the task to the pending verification set.
+                Some(participant_info) => {
+                    participant_info.completed_task(task, time)?;
+                    self.stop_task_timer(participant, &task, time);
+                    Ok(self.add_pending_verification(task, time)?)
+                }
+                None => Err(CoordinatorError::ParticipantNotFound(participant.clone())),
+            },
+            Participant::Verifier(_) => match self.current_verifiers.get_mut(participant) {
+                // Adds the task to the list of completed tasks for the verifier,
+                // and remove the task from the pending verification set.
+                Some(participant_info) => {
+                    participant_info.completed_task(task, time)?;
+                    self.stop_task_timer(participant, &task, time);
+                    Ok(self.remove_pending_verification(task)?)
+                }
+                None => Err(CoordinatorError::ParticipantNotFound(participant.clone())),
+            },
+        }
+    }
+
+    ///
+    /// Removes the given (chunk ID, contribution ID) task, whose contribution failed verification,
+    /// from the given verifier, and reverts it to the assigned tasks of the given contributor.
+    ///
+    /// On success, this function returns the record of the failed contribution,
+    /// which counts the times the contributor has failed verification on the chunk.
+    ///
+    #[tracing::instrument(
+        level = "error",
+        skip(self, time, verifier, contributor),
+        fields(task = %task),
+        err
+    )]
+    pub(super) fn failed_verification(
+        &mut self,
+        verifier: &Participant,
+        contributor: &Participant,
+        task: Task,
+        time: &dyn TimeSource,
+    ) -> Result<FailedContribution, CoordinatorError> {
+        // Check that the chunk ID is valid.
+        self.environment.validate_chunk_id(task.chunk_id())?;
+
+        // Fetch the current round height.
+        let current_round_height = self.current_round_height.ok_or(CoordinatorError::RoundHeightNotSet)?;
+
+        // Remove the task from the verifier, and from the pending verification set.
+        match self.current_verifiers.get_mut(verifier) {
+            Some(verifier_info) => verifier_info.failed_task(task, time)?,
+            None => return Err(CoordinatorError::ParticipantNotFound(verifier.clone())),
+        };
+        self.stop_task_timer(verifier, &task, time);
+        self.remove_pending_verification(task)?;
+
+        // Remove the task from the completed tasks of the verifiers which verified the failed
+        // contribution, as the re-uploaded contribution must be verified again.
+        for verifier_info in self.current_verifiers.values_mut() {
+            if verifier_info.completed_tasks.contains(&task) {
+                verifier_info.completed_tasks = verifier_info
+                    .completed_tasks
+                    .clone()
+                    .into_par_iter()
+                    .filter(|t| *t != task)
+                    .collect();
+            }
         }
 
-        match participant {
-            Participant::Contributor(_) => match self.current_contributors.get_mut(participant) {
-                // Adds the task to the list of completed tasks for the contributor,
-                // and add the task to the pending verification set.
-                Some(participant_info) => {
-                    participant_info.completed_task(task, time)?;
-                    self.stop_task_timer(participant, &task, time);
-                    Ok(self.add_pending_verification(task, time)?)
-                }
-                None => Err(CoordinatorError::ParticipantNotFound(participant.clone())),
-            },
-            Participant::Verifier(_) => match self.current_verifiers.get_mut(participant) {
-                // Adds the task to the list of completed tasks for the verifier,
-                // and remove the task from the pending verification set.
-                Some(participant_info) => {
-                    participant_info.completed_task(task, time)?;
-                    self.stop_task_timer(participant, &task, time);
-                    Ok(self.remove_pending_verification(task)?)
-                }
-                None => Err(CoordinatorError::ParticipantNotFound(participant.clone())),
-            },
+        // Resume the contributor in the current round, if it had already finished.
+        let finished_contributor = self
+            .finished_contributors
+            .get_mut(&current_round_height)
+            .and_then(|contributors| contributors.remove(contributor));
+        if let Some(contributor_info) = finished_contributor {
+            debug!(
+                "Resuming finished contributor {} to retry a failed contribution",
+                contributor
+            );
+            self.current_contributors.insert(contributor.clone(), contributor_info);
         }
+
+        // Revert the task to the assigned tasks of the contributor.
+        match self.current_contributors.get_mut(contributor) {
+            Some(contributor_info) => contributor_info.retry_task(task)?,
+            None => return Err(CoordinatorError::ParticipantNotFound(contributor.clone())),
+        };
+
+        // Record the failed contribution.
+        let attempt = self
+            .failed_contributions
+            .iter()
+            .filter(|failed| failed.contributor == *contributor && failed.chunk_id == task.chunk_id())
+            .count() as u64
+            + 1;
+        let failed_contribution = FailedContribution {
+            contributor: contributor.clone(),
+            chunk_id: task.chunk_id(),
+            contribution_id: task.contribution_id(),
+            attempt,
+            failed_at: time.utc_now(),
+        };
+        self.failed_contributions.push(failed_contribution.clone());
+
+        Ok(failed_contribution)
     }
 
     ///
@@ -2084,6 +2942,16 @@ impl CoordinatorState {
         }
     }
 
+    ///
+    /// Records the given aggregated round in the ceremony history,
+    /// rolling up the oldest rounds past the cap set in the environment.
+    ///
+    #[inline]
+    pub(super) fn record_round_history(&mut self, round: RoundHistory) {
+        let cap = self.environment.history_rounds();
+        self.history.record(round, cap);
+    }
+
     ///
     /// Rolls back the current round from aggregating in round metrics.
     ///
@@ -2116,10 +2984,14 @@ impl CoordinatorState {
     /// response to the participant being dropped in the form of
     /// [DropParticipant].
     ///
-    /// If the participant is a [Participant::Contributor], this will
-    /// attempt to replace the contributor with an available
-    /// replacement contributor. If there are no replacement
-    /// contributors available the round will be reset via
+    /// If the participant is a spare [Participant::Contributor], it holds
+    /// no tasks and is simply removed from the current round.
+    ///
+    /// If the participant is any other [Participant::Contributor], this will
+    /// attempt to promote a spare contributor of the current round into the
+    /// bucket of the dropped contributor, or otherwise replace the contributor
+    /// with an available replacement contributor. If there are no spares or
+    /// replacement contributors available the round will be reset via
     /// [CoordinatorState::reset_current_round].
     ///
     /// If the participant being dropped is the only remaining regular
@@ -2149,6 +3021,7 @@ impl CoordinatorState {
             if self.queue.contains_key(participant) {
                 trace!("Removing {} from the queue", participant);
                 self.queue.remove(participant);
+                self.queue_admissions.remove(participant);
             }
 
             // Remove the participant from the precommit for the next round.
@@ -2203,6 +3076,23 @@ impl CoordinatorState {
 
         // Drop the contributor from the current round, and update participant info and coordinator state.
         let storage_action: CeremonyStorageAction = match participant {
+            Participant::Contributor(_id) if participant_info.is_spare() => {
+                // Set the participant as dropped.
+                let mut dropped_info = participant_info.clone();
+                dropped_info.drop(time)?;
+
+                // A spare holds no tasks, so no other participant is affected.
+                self.current_contributors.remove(&participant);
+
+                // Add the participant info to the dropped participants.
+                self.dropped.push(dropped_info);
+
+                warn!("Dropped spare {} from the ceremony", participant);
+
+                CeremonyStorageAction::RemoveSpare(RemoveSpareStorageAction {
+                    dropped_contributor: participant.clone(),
+                })
+            }
             Participant::Contributor(_id) => {
                 // TODO (howardwu): Optimization only.
                 //  -----------------------------------------------------------------------------------
@@ -2218,8 +3108,7 @@ impl CoordinatorState {
                 let mut dropped_info = participant_info.clone();
                 dropped_info.drop(time)?;
 
-                // Fetch the number of chunks and number of contributors.
-                let number_of_chunks = self.environment.number_of_chunks() as u64;
+                // Fetch the number of contributors.
                 let number_of_contributors = self
                     .current_metrics
                     .clone()
@@ -2272,11 +3161,14 @@ impl CoordinatorState {
                     excluded_tasks.extend(contributor_info.pending_tasks.iter().map(|task| task.chunk_id()));
 
                     // Reassign tasks for the affected contributor.
-                    contributor_info.assigned_tasks =
-                        initialize_tasks(contributor_info.bucket_id, number_of_chunks, number_of_contributors)?
-                            .into_iter()
-                            .filter(|task| !excluded_tasks.contains(&task.chunk_id()))
-                            .collect();
+                    contributor_info.assigned_tasks = initialize_contributor_tasks(
+                        &self.environment,
+                        contributor_info.bucket_id,
+                        number_of_contributors,
+                    )?
+                    .into_iter()
+                    .filter(|task| !excluded_tasks.contains(&task.chunk_id()))
+                    .collect();
                 }
 
                 // All verifiers assigned to affected tasks must dispose their affected
@@ -2313,7 +3205,31 @@ impl CoordinatorState {
                 // Add the participant info to the dropped participants.
                 self.dropped.push(dropped_info);
 
-                let action = if self.environment.coordinator_contributors().is_empty() {
+                // Fetch the spare contributor with the lowest bucket ID, if one is available.
+                let spare_contributor = self
+                    .current_contributors
+                    .values()
+                    .filter(|contributor_info| contributor_info.is_spare())
+                    .min_by_key(|contributor_info| contributor_info.bucket_id)
+                    .map(|contributor_info| contributor_info.id.clone());
+
+                let action = if let Some(spare_contributor) = spare_contributor {
+                    tracing::info!(
+                        "Found a spare contributor for the dropped contributor. \
+                        Assigning spare contributor to the dropped contributor's tasks."
+                    );
+                    // Promote the spare contributor into the bucket of the dropped contributor.
+                    let replacement_contributor =
+                        self.promote_spare_contributor_unsafe(&spare_contributor, bucket_id)?;
+
+                    CeremonyStorageAction::ReplaceContributor(ReplaceContributorStorageAction {
+                        dropped_contributor: participant.clone(),
+                        bucket_id,
+                        locked_chunks,
+                        tasks,
+                        replacement_contributor,
+                    })
+                } else if self.environment.coordinator_contributors().is_empty() {
                     tracing::info!("No replacement contributors available, the round will be restarted.");
                     // There are no replacement contributors so the only option is to restart the round.
                     CeremonyStorageAction::ResetCurrentRound(ResetCurrentRoundStorageAction {
@@ -2490,7 +3406,7 @@ impl CoordinatorState {
         // TODO (raychu86): Update the participant info (interleave the tasks by contribution id).
         // TODO (raychu86): Add tasks to the replacement contributor if it already has pending tasks.
 
-        let tasks = initialize_tasks(bucket_id, self.environment.number_of_chunks(), number_of_contributors)?;
+        let tasks = initialize_contributor_tasks(&self.environment, bucket_id, number_of_contributors)?;
         let mut participant_info =
             ParticipantInfo::new(contributor.clone(), self.current_round_height(), 10, bucket_id, time);
         participant_info.start(tasks, time)?;
@@ -2500,6 +3416,34 @@ impl CoordinatorState {
         Ok(contributor.clone())
     }
 
+    ///
+    /// Promotes the given spare contributor of the current round into
+    /// the given bucket ID, and assigns them the tasks of that bucket.
+    ///
+    #[inline]
+    fn promote_spare_contributor_unsafe(
+        &mut self,
+        spare_contributor: &Participant,
+        bucket_id: u64,
+    ) -> Result<Participant, CoordinatorError> {
+        let number_of_contributors = self
+            .current_metrics
+            .clone()
+            .ok_or(CoordinatorError::CoordinatorStateNotInitialized)?
+            .number_of_contributors;
+        let tasks = initialize_contributor_tasks(&self.environment, bucket_id, number_of_contributors)?;
+
+        let participant_info = self
+            .current_contributors
+            .get_mut(spare_contributor)
+            .ok_or_else(|| CoordinatorError::ParticipantNotFound(spare_contributor.clone()))?;
+        participant_info.bucket_id = bucket_id;
+        participant_info.assigned_tasks = tasks;
+        trace!("{:?}", participant_info);
+
+        Ok(spare_contributor.clone())
+    }
+
     ///
     /// Returns `true` if the manual lock for transitioning to the next round is enabled.
     ///
@@ -2524,6 +3468,58 @@ impl CoordinatorState {
         self.manual_lock = false;
     }
 
+    ///
+    /// Returns `true` if the ceremony is paused for maintenance.
+    ///
+    #[inline]
+    pub(super) fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    ///
+    /// Sets the ceremony as paused for maintenance, and records the time it was paused.
+    ///
+    #[inline]
+    pub(super) fn pause(&mut self, time: &dyn TimeSource) {
+        if !self.paused {
+            self.paused_at = Some(time.utc_now());
+        }
+        self.paused = true;
+    }
+
+    ///
+    /// Sets the ceremony as resumed after maintenance.
+    ///
+    /// The participant timeouts are suspended while the ceremony is paused, so the
+    /// time the participants were last seen, the times they locked chunks, and the
+    /// times of reservations and failed contributions are moved forward by the pause.
+    ///
+    #[inline]
+    pub(super) fn resume(&mut self, time: &dyn TimeSource) {
+        if let Some(paused_at) = self.paused_at.take() {
+            let pause = time.utc_now() - paused_at;
+            if pause > Duration::zero() {
+                for participant_info in self
+                    .current_contributors
+                    .values_mut()
+                    .chain(self.current_verifiers.values_mut())
+                {
+                    participant_info.last_seen = participant_info.last_seen + pause;
+                    for lock in participant_info.locked_chunks.values_mut() {
+                        lock.lock_time = lock.lock_time + pause;
+                    }
+                }
+                for reservation in self.contribution_reservations.values_mut() {
+                    reservation.reserved_at = reservation.reserved_at + pause;
+                }
+                for failed in &mut self.failed_contributions {
+                    failed.failed_at = failed.failed_at + pause;
+                }
+            }
+        }
+        self.paused = false;
+    }
+
     ///
     /// Updates the state of the queue for all waiting participants.
     ///
@@ -2603,6 +3599,13 @@ impl CoordinatorState {
         // Fetch the current number of contributors.
         let number_of_current_contributors = self.current_contributors.len();
 
+        // Check if any contributor with tasks is not yet able to finish.
+        let has_working_contributors = self.current_contributors.values().any(|contributor_info| {
+            !contributor_info.is_spare()
+                && !contributor_info.is_finished()
+                && contributor_info.clone().finish(time).is_err()
+        });
+
         // Initialize a map for newly finished contributors.
         let mut newly_finished: HashMap<Participant, ParticipantInfo> = HashMap::new();
 
@@ -2617,6 +3620,11 @@ impl CoordinatorState {
                     return false;
                 }
 
+                // Keep the spare contributors in the round while they may replace a dropped contributor.
+                if contributor_info.is_spare() && has_working_contributors {
+                    return true;
+                }
+
                 // Attempt to set the contributor as finished.
                 let mut finished_info = contributor_info.clone();
                 if let Err(_) = finished_info.finish(time) {
@@ -2721,13 +3729,31 @@ impl CoordinatorState {
         &mut self,
         time: &dyn TimeSource,
     ) -> Result<Vec<DropParticipant>, CoordinatorError> {
+        // Suspend the participant timeouts while the ceremony is paused.
+        if self.paused {
+            return Ok(vec![]);
+        }
+
         Ok(self
             .update_contributor_seen_drops(time)?
             .into_iter()
             .chain(self.update_participant_lock_drops(time)?.into_iter())
+            .chain(self.update_contribution_retry_drops(time)?.into_iter())
             .collect())
     }
 
+    ///
+    /// Returns the maximum duration the given participant may hold a lock,
+    /// scaled by their declared hardware if there is a hardware policy.
+    ///
+    fn participant_lock_timeout(&self, participant_info: &ParticipantInfo) -> Duration {
+        let participant_lock_timeout = self.environment.participant_lock_timeout();
+        match (self.environment.hardware_policy(), &participant_info.hardware) {
+            (Some(policy), Some(declaration)) => policy.scaled_deadline(participant_lock_timeout, declaration),
+            _ => participant_lock_timeout,
+        }
+    }
+
     /// This will drop a participant (verifier or contributor) if it
     /// has been holding a lock for longer than
     /// [crate::environment::Environment]'s
@@ -2736,9 +3762,6 @@ impl CoordinatorState {
         &mut self,
         time: &dyn TimeSource,
     ) -> Result<Vec<DropParticipant>, CoordinatorError> {
-        // Fetch the timeout threshold for contributors.
-        let participant_lock_timeout = self.environment.participant_lock_timeout();
-
         // Fetch the current time.
         let now = time.utc_now();
 
@@ -2747,6 +3770,9 @@ impl CoordinatorState {
             .iter()
             .chain(self.current_verifiers.clone().iter())
             .filter_map(|(participant, participant_info)| {
+                // Fetch the timeout threshold for this participant.
+                let participant_lock_timeout = self.participant_lock_timeout(participant_info);
+
                 if !self.is_coordinator_contributor(&participant)
                     && participant_info
                         .locked_chunks
@@ -2765,6 +3791,53 @@ impl CoordinatorState {
             .collect()
     }
 
+    /// This will drop a contributor if it has not locked the chunk of a
+    /// contribution that failed verification again within the window of
+    /// [crate::environment::Environment]'s `contribution_retry_policy`.
+    fn update_contribution_retry_drops(
+        &mut self,
+        time: &dyn TimeSource,
+    ) -> Result<Vec<DropParticipant>, CoordinatorError> {
+        // Fetch the retry window, if retries are enabled.
+        let window = match self.environment.contribution_retry_policy() {
+            Some(policy) => policy.window(),
+            None => return Ok(vec![]),
+        };
+
+        // Fetch the current time.
+        let now = time.utc_now();
+
+        // Fetch the contributors who are still assigned a failed task after the window lapsed.
+        let lapsed: Vec<Participant> = self
+            .failed_contributions
+            .iter()
+            .filter(|failed| now - failed.failed_at > window)
+            .filter(|failed| !self.is_coordinator_contributor(&failed.contributor))
+            .filter(|failed| {
+                let task = Task::new(failed.chunk_id, failed.contribution_id);
+                self.current_contributors
+                    .get(&failed.contributor)
+                    .map_or(false, |info| info.assigned_tasks.contains(&task))
+            })
+            .map(|failed| failed.contributor.clone())
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+
+        lapsed
+            .iter()
+            .map(|participant| {
+                tracing::info!(
+                    "Dropping participant {} because it has exceeded the maximum ({} minutes) allowed time \
+                    to retry a contribution that failed verification.",
+                    participant,
+                    window.num_minutes()
+                );
+                self.drop_participant(participant, time)
+            })
+            .collect()
+    }
+
     /// This will drop a contributor if it hasn't been seen for more
     /// than [crate::environment::Environment]'s
     /// `contributor_seen_timeout`.
@@ -3055,6 +4128,17 @@ impl CoordinatorState {
             return Err(CoordinatorError::RoundNumberOfContributorsUnauthorized);
         }
 
+        // Check that the next round contains enough contributors for each chunk.
+        if let Some(contributions_per_chunk) = self.environment.contributions_per_chunk() {
+            if (number_of_contributors as u64) < contributions_per_chunk {
+                warn!(
+                    "Precommit found {} contributors, but each chunk needs {} contributions",
+                    number_of_contributors, contributions_per_chunk
+                );
+                return Err(CoordinatorError::RoundNumberOfContributorsUnauthorized);
+            }
+        }
+
         // Check that the next round contains a permitted number of verifiers.
         let minimum_verifiers = self.environment.minimum_verifiers_per_round();
         let maximum_verifiers = self.environment.maximum_verifiers_per_round();
@@ -3114,13 +4198,10 @@ impl CoordinatorState {
             // Sort the contributors by their reliability (in order of highest to lowest number).
             contributors.par_sort_by(|a, b| ((b.1).0).cmp(&(&a.1).0));
 
-            // Fetch the number of chunks and bucket size.
-            let number_of_chunks = self.environment.number_of_chunks() as u64;
-
             // Set the chunk ID ordering for each contributor.
             for (bucket_index, (participant, (reliability, next_round))) in contributors.into_iter().enumerate() {
                 let bucket_id = bucket_index as u64;
-                let tasks = initialize_tasks(bucket_id, number_of_chunks, number_of_contributors as u64)?;
+                let tasks = initialize_contributor_tasks(&self.environment, bucket_id, number_of_contributors as u64)?;
 
                 // Check that each participant is storing the correct round height.
                 if next_round != next_round_height && next_round != current_round_height + 1 {
@@ -3131,10 +4212,11 @@ impl CoordinatorState {
                 // Initialize the participant info for the contributor.
                 let mut participant_info =
                     ParticipantInfo::new(participant.clone(), next_round_height, reliability, bucket_id, time);
+                participant_info.hardware = self.queue_hardware.get(&participant).copied();
                 participant_info.start(tasks, time)?;
 
-                // Check that the chunk IDs are set in the participant information.
-                if participant_info.assigned_tasks.is_empty() {
+                // Check that the chunk IDs are set in the participant information, unless it is a spare.
+                if participant_info.assigned_tasks.is_empty() && !participant_info.is_spare() {
                     return Err(CoordinatorError::ParticipantNotReady);
                 }
 
@@ -3182,6 +4264,18 @@ impl CoordinatorState {
         Ok((next_contributors, next_verifiers))
     }
 
+    ///
+    /// Returns the hardware declared by each contributor staged for the next round.
+    ///
+    #[inline]
+    pub(super) fn next_contributor_hardware(&self) -> BTreeMap<Participant, HardwareDeclaration> {
+        self.next
+            .iter()
+            .filter(|(participant, _)| participant.is_contributor())
+            .filter_map(|(participant, participant_info)| Some((participant.clone(), participant_info.hardware?)))
+            .collect()
+    }
+
     ///
     /// Executes transition of the coordinator state from the current round to the next round.
     ///
@@ -3216,6 +4310,10 @@ impl CoordinatorState {
         let mut number_of_contributors = 0;
         let mut number_of_verifiers = 0;
         for (participant, participant_info) in self.next.iter() {
+            // The declared hardware is now recorded in the participant info.
+            self.queue_hardware.remove(participant);
+            self.queue_admissions.remove(participant);
+
             match participant {
                 Participant::Contributor(_) => {
                     self.current_contributors
@@ -3255,6 +4353,9 @@ impl CoordinatorState {
 
         // Reset the next round map.
         self.next = HashMap::new();
+
+        // Reset the failed contributions of the previous round.
+        self.failed_contributions = Vec::new();
     }
 
     ///
@@ -3478,6 +4579,14 @@ pub struct ReplaceContributorStorageAction {
     pub replacement_contributor: Participant,
 }
 
+/// Action to update the storage to reflect a spare contributor being
+/// removed in [CoordinatorState].
+#[derive(Debug)]
+pub struct RemoveSpareStorageAction {
+    /// The spare contributor being dropped.
+    pub dropped_contributor: Participant,
+}
+
 /// Actions taken to update the round/storage to reflect a change in
 /// [CoordinatorState].
 #[derive(Debug)]
@@ -3488,6 +4597,8 @@ pub enum CeremonyStorageAction {
     ReplaceContributor(ReplaceContributorStorageAction),
     /// See [RemoveVerifierStorageAction].
     RemoveVerifier(RemoveVerifierStorageAction),
+    /// See [RemoveSpareStorageAction].
+    RemoveSpare(RemoveSpareStorageAction),
 }
 
 /// Data required by the coordinator to drop a participant from the
@@ -3524,6 +4635,7 @@ mod tests {
     use crate::{
         coordinator_state::*,
         environment::{Parameters, Testing},
+        objects::{AdmissionPolicy, HardwarePolicy, ProofOfWorkPolicy},
         testing::prelude::*,
         CoordinatorState,
         MockTimeSource,
@@ -3632,6 +4744,142 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_add_to_queue_with_hardware() {
+        let policy = HardwarePolicy::aleo_inner();
+        let environment: Environment = Testing::from(Parameters::Test3Chunks)
+            .hardware_policy(Some(policy.clone()))
+            .into();
+
+        let coordinator_contributor = TEST_CONTRIBUTOR_ID.clone();
+        let contributor = TEST_CONTRIBUTOR_ID_2.clone();
+        let contributor_2 = TEST_CONTRIBUTOR_ID_3.clone();
+        let verifier = TEST_VERIFIER_ID_2.clone();
+
+        // Initialize a new coordinator state.
+        let mut state = CoordinatorState::new(environment.clone());
+
+        // Contributors must declare their hardware.
+        let result = state.add_to_queue(contributor.clone(), 10);
+        assert!(matches!(result, Err(CoordinatorError::ParticipantHardwareUndeclared)));
+        assert_eq!(0, state.queue.len());
+
+        // Contributors must declare hardware meeting the minimum.
+        let result =
+            state.add_to_queue_with_hardware(contributor.clone(), 10, Some(HardwareDeclaration::new(1, 4, 10)));
+        assert!(matches!(
+            result,
+            Err(CoordinatorError::ParticipantHardwareBelowMinimum {
+                requirement: "cores",
+                ..
+            })
+        ));
+        assert_eq!(0, state.queue.len());
+        assert!(state.queue_hardware.is_empty());
+
+        // Contributors with sufficient hardware are added, and their declaration is recorded.
+        let declaration = *policy.minimum();
+        state
+            .add_to_queue_with_hardware(contributor.clone(), 10, Some(declaration))
+            .unwrap();
+        assert_eq!(1, state.queue.len());
+        assert_eq!(Some(&declaration), state.queue_hardware.get(&contributor));
+
+        // Verifiers and coordinator contributors do not need to declare their hardware.
+        state.add_to_queue(verifier.clone(), 10).unwrap();
+        state.add_to_queue(coordinator_contributor.clone(), 10).unwrap();
+        assert_eq!(3, state.queue.len());
+
+        // Removing a contributor from the queue removes their declaration.
+        state
+            .add_to_queue_with_hardware(contributor_2.clone(), 10, Some(declaration))
+            .unwrap();
+        state.remove_from_queue(&contributor_2).unwrap();
+        assert_eq!(None, state.queue_hardware.get(&contributor_2));
+    }
+
+    #[test]
+    fn test_consumed_admission_challenges() {
+        let policy = AdmissionPolicy::new(None, Some(ProofOfWorkPolicy::new(8, Duration::minutes(5))));
+        let environment: Environment = Testing::from(Parameters::Test3Chunks)
+            .admission_policy(Some(policy.clone()))
+            .into();
+        let proof_of_work = policy.proof_of_work().unwrap();
+
+        let contributor = TEST_CONTRIBUTOR_ID_2.clone();
+        let contributor_2 = TEST_CONTRIBUTOR_ID_3.clone();
+        let credentials = |participant: &Participant, now: DateTime<Utc>| {
+            let challenge = proof_of_work.issue(participant, now).unwrap();
+            AdmissionCredentials {
+                invitation: None,
+                nonce: Some(challenge.solve(participant)),
+                challenge: Some(challenge),
+            }
+        };
+
+        // Initialize a new coordinator state.
+        let mut state = CoordinatorState::new(environment);
+        let now = Utc::now();
+
+        // The challenge a contributor joins the queue with is consumed.
+        let first = credentials(&contributor, now);
+        state
+            .add_to_queue_with_admission(contributor.clone(), 10, None, Some(&first), now)
+            .unwrap();
+        assert_eq!(1, state.consumed_admission_challenges.len());
+        state.remove_from_queue(&contributor).unwrap();
+        assert!(matches!(
+            state.add_to_queue_with_admission(contributor.clone(), 10, None, Some(&first), now),
+            Err(CoordinatorError::ProofOfWorkChallengeConsumed)
+        ));
+
+        // Consumed challenges are forgotten once they expire.
+        let later = now + Duration::minutes(6);
+        state
+            .add_to_queue_with_admission(
+                contributor_2.clone(),
+                10,
+                None,
+                Some(&credentials(&contributor_2, later)),
+                later,
+            )
+            .unwrap();
+        assert_eq!(1, state.consumed_admission_challenges.len());
+        assert!(matches!(
+            state.add_to_queue_with_admission(contributor.clone(), 10, None, Some(&first), later),
+            Err(CoordinatorError::ProofOfWorkChallengeExpired)
+        ));
+    }
+
+    #[test]
+    fn test_verification_timings_are_bounded() {
+        let environment = TEST_ENVIRONMENT.clone();
+
+        // Initialize a new coordinator state.
+        let mut state = CoordinatorState::new(environment.clone());
+
+        // Record one more timing than the state holds, with the round height counting the timings.
+        for round_height in 0..=MAXIMUM_VERIFICATION_TIMINGS as u64 {
+            state.record_verification_timing(VerificationTiming::new(
+                &environment,
+                round_height,
+                0,
+                Duration::seconds(1),
+                Duration::zero(),
+                None,
+                Utc::now(),
+            ));
+        }
+
+        // The oldest timing was dropped.
+        assert_eq!(MAXIMUM_VERIFICATION_TIMINGS, state.verification_timings().len());
+        assert_eq!(1, state.verification_timings()[0].round_height);
+        assert_eq!(
+            MAXIMUM_VERIFICATION_TIMINGS as u64,
+            state.verification_timings().last().unwrap().round_height
+        );
+    }
+
     #[test]
     fn test_update_queue() {
         let environment = TEST_ENVIRONMENT.clone();
@@ -4310,6 +5558,126 @@ mod tests {
         assert_eq!(0, state.banned.len());
     }
 
+    #[test]
+    fn test_estimate_time_remaining() {
+        test_logger();
+
+        let time = MockTimeSource::new(Utc::now());
+        let environment = TEST_ENVIRONMENT.clone();
+
+        // Fetch two contributors and a verifier.
+        let contributor_1 = TEST_CONTRIBUTOR_ID.clone();
+        let contributor_2 = TEST_CONTRIBUTOR_ID_2.clone();
+        let verifier = TEST_VERIFIER_ID.clone();
+
+        // Initialize a new coordinator state.
+        let current_round_height = 5;
+        let mut state = CoordinatorState::new(environment.clone());
+        state.initialize(current_round_height);
+        state.add_to_queue(contributor_1.clone(), 10).unwrap();
+        state.add_to_queue(contributor_2.clone(), 9).unwrap();
+        state.add_to_queue(verifier.clone(), 10).unwrap();
+        state.update_queue().unwrap();
+        state.aggregating_current_round(&time).unwrap();
+        state.aggregated_current_round(&time).unwrap();
+
+        // Advance the coordinator to the next round.
+        let next_round_height = current_round_height + 1;
+        state.precommit_next_round(next_round_height, &time).unwrap();
+        state.commit_next_round();
+
+        // No contributions have been timed yet.
+        let number_of_chunks = environment.number_of_chunks();
+        assert_eq!(None, state.estimate_time_remaining(2 * number_of_chunks));
+
+        // Contributor 1 takes 60 seconds to contribute to a chunk.
+        let task = state.fetch_task(&contributor_1, &time).unwrap();
+        state.acquired_lock(&contributor_1, task.chunk_id(), &time).unwrap();
+        time.update(|prev| prev + Duration::seconds(60));
+        state.completed_task(&contributor_1, task, &time).unwrap();
+
+        // An unfinished contribution does not count towards the average.
+        let task = state.fetch_task(&contributor_2, &time).unwrap();
+        state.acquired_lock(&contributor_2, task.chunk_id(), &time).unwrap();
+        time.update(|prev| prev + Duration::seconds(120));
+        state.update_round_metrics();
+
+        // Each contributor must contribute to every chunk, and contributor 1 has finished one.
+        // The remaining contributions take the average of 60 seconds each, shared by both contributors.
+        let contributions_remaining = 2 * number_of_chunks - 1;
+        let seconds = (60 * contributions_remaining + 1) / 2;
+        assert_eq!(
+            Some(Duration::seconds(seconds as i64)),
+            state.estimate_time_remaining(contributions_remaining)
+        );
+
+        // Contributor 2 takes 120 seconds to contribute, so the average is 90 seconds.
+        state.completed_task(&contributor_2, task, &time).unwrap();
+        state.update_round_metrics();
+        let contributions_remaining = 2 * number_of_chunks - 2;
+        assert_eq!(
+            Some(Duration::seconds((90 * contributions_remaining / 2) as i64)),
+            state.estimate_time_remaining(contributions_remaining)
+        );
+
+        // The round is estimated to be finished once no contributions remain.
+        assert_eq!(Some(Duration::zero()), state.estimate_time_remaining(0));
+    }
+
+    #[test]
+    fn test_participant_progress() {
+        test_logger();
+
+        let time = MockTimeSource::new(Utc::now());
+        let environment = TEST_ENVIRONMENT.clone();
+
+        // Fetch two contributors and a verifier.
+        let contributor_1 = TEST_CONTRIBUTOR_ID.clone();
+        let contributor_2 = TEST_CONTRIBUTOR_ID_2.clone();
+        let verifier = TEST_VERIFIER_ID.clone();
+
+        // Initialize a new coordinator state.
+        let current_round_height = 5;
+        let mut state = CoordinatorState::new(environment.clone());
+        state.initialize(current_round_height);
+        state.add_to_queue(contributor_1.clone(), 10).unwrap();
+        state.add_to_queue(contributor_2.clone(), 9).unwrap();
+        state.add_to_queue(verifier.clone(), 10).unwrap();
+        state.update_queue().unwrap();
+        state.aggregating_current_round(&time).unwrap();
+        state.aggregated_current_round(&time).unwrap();
+
+        // Advance the coordinator to the next round.
+        let next_round_height = current_round_height + 1;
+        state.precommit_next_round(next_round_height, &time).unwrap();
+        state.commit_next_round();
+
+        // Contributor 1 is assigned tasks starting from the first bucket.
+        let tasks = initialize_tasks(0, environment.number_of_chunks(), 2).unwrap();
+
+        // Nothing has been locked or completed yet.
+        let progress = state.participant_progress(&contributor_1).unwrap();
+        assert!(progress.locked_chunks().is_empty());
+        assert!(progress.completed_chunks().is_empty());
+        assert_eq!(Some(tasks[0].chunk_id()), progress.next_chunk());
+
+        // Contributor 1 contributes to one chunk, and locks another.
+        let task = state.fetch_task(&contributor_1, &time).unwrap();
+        state.acquired_lock(&contributor_1, task.chunk_id(), &time).unwrap();
+        state.completed_task(&contributor_1, task, &time).unwrap();
+        let task = state.fetch_task(&contributor_1, &time).unwrap();
+        state.acquired_lock(&contributor_1, task.chunk_id(), &time).unwrap();
+
+        let progress = state.participant_progress(&contributor_1).unwrap();
+        assert_eq!(&[tasks[1].chunk_id()], progress.locked_chunks());
+        assert_eq!(&[tasks[0].chunk_id()], progress.completed_chunks());
+        assert_eq!(Some(tasks[2].chunk_id()), progress.next_chunk());
+
+        // Participants outside of the current round have no progress.
+        let contributor_3 = TEST_CONTRIBUTOR_ID_3.clone();
+        assert!(state.participant_progress(&contributor_3).is_err());
+    }
+
     #[test]
     fn test_round_2x2() {
         test_logger();
@@ -1,13 +1,15 @@
 use crate::{
-    objects::Participant,
-    storage::{Disk, Storage},
+    objects::{AdmissionPolicy, ClientVersionPolicy, ContributionRetryPolicy, HardwarePolicy, Participant},
+    storage::{Disk, Storage, StorageKeySource},
+    CoordinatorError,
 };
 use phase1::{helpers::CurveKind, ContributionMode, ProvingSystem};
-use setup_utils::{CheckForCorrectness, UseCompression};
+use setup_utils::{calculate_hash, CheckForCorrectness, UseCompression};
 
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
 use serde::{Deserialize, Serialize};
 use serde_with::DurationSecondsWithFrac;
+use std::fmt;
 
 type BatchSize = usize;
 type ChunkSize = usize;
@@ -28,6 +30,8 @@ impl Settings {
     /// Creates a new `Settings`
     ///
     /// + `batch_size` - will panic if this is set to `0`.
+    /// + `batch_size` - will panic if the proving system is Marlin and this is
+    ///   smaller than the `3 + 3 * power` alpha powers of tau, which must fit in a single batch.
     pub fn new(
         contribution_mode: ContributionMode,
         proving_system: ProvingSystem,
@@ -39,6 +43,9 @@ impl Settings {
         if batch_size == 0 {
             panic!("batch_size cannot be equal to zero");
         }
+        if proving_system == ProvingSystem::Marlin && batch_size < 3 + 3 * power {
+            panic!("batch_size must be at least 3 + 3 * power for Marlin");
+        }
 
         Self {
             contribution_mode,
@@ -73,6 +80,36 @@ impl Settings {
     pub fn chunk_size(&self) -> ChunkSize {
         self.chunk_size
     }
+
+    /// Returns the number of chunks the powers of tau are split into.
+    pub fn number_of_chunks(&self) -> u64 {
+        let proving_system = &self.proving_system;
+        let power = self.power;
+        let chunk_size = self.chunk_size;
+        (total_size_in_g1!(proving_system, power) + chunk_size as u64 - 1) / chunk_size as u64
+    }
+
+    /// Returns the hex-encoded BLAKE2b hash of the displayed settings,
+    /// which identifies the parameters of the ceremony.
+    pub fn fingerprint(&self) -> String {
+        hex::encode(calculate_hash(self.to_string().as_bytes()))
+    }
+}
+
+impl fmt::Display for Settings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "power: {}, batch size: {}, chunk size: {}, number of chunks: {}, curve: {:?}, proving system: {:?}, contribution mode: {:?}",
+            self.power,
+            self.batch_size,
+            self.chunk_size,
+            self.number_of_chunks(),
+            self.curve,
+            self.proving_system,
+            self.contribution_mode
+        )
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -82,11 +119,32 @@ pub enum Deployment {
     Production,
 }
 
+/// The mode in which the coordinator runs against its storage.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CoordinatorMode {
+    /// The coordinator runs the ceremony and owns its storage.
+    Primary,
+    /// The coordinator serves a view of the storage of the primary coordinator
+    /// at the given URL, without mutating it.
+    ReadOnly { primary_url: String },
+}
+
+impl Default for CoordinatorMode {
+    fn default() -> Self {
+        CoordinatorMode::Primary
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum Parameters {
     AleoInner,
     AleoOuter,
     AleoUniversal,
+    AleoTestUniversal {
+        power: usize,
+        chunk_size: usize,
+        batch_size: usize,
+    },
     Custom(Settings),
     Test3Chunks,
     Test8Chunks,
@@ -98,6 +156,10 @@ pub enum Parameters {
         power: usize,
         batch_size: usize,
     },
+    TestMarlin3Chunks,
+    TestMarlinChunks {
+        number_of_chunks: usize,
+    },
 }
 
 impl Parameters {
@@ -107,6 +169,11 @@ impl Parameters {
             Parameters::AleoInner => Self::aleo_inner(),
             Parameters::AleoOuter => Self::aleo_outer(),
             Parameters::AleoUniversal => Self::aleo_universal(),
+            Parameters::AleoTestUniversal {
+                power,
+                chunk_size,
+                batch_size,
+            } => Self::aleo_test_universal(power, chunk_size, batch_size),
             Parameters::Custom(settings) => settings.clone(),
             Parameters::Test3Chunks => Self::test_3_chunks(),
             Parameters::Test8Chunks => Self::test_8_chunks(),
@@ -116,6 +183,8 @@ impl Parameters {
                 power,
                 batch_size,
             } => Self::test_custom(number_of_chunks, power, batch_size),
+            Parameters::TestMarlin3Chunks => Self::test_marlin_3_chunks(),
+            Parameters::TestMarlinChunks { number_of_chunks } => Self::test_marlin_chunks(number_of_chunks),
         }
     }
 
@@ -152,6 +221,17 @@ impl Parameters {
         )
     }
 
+    fn aleo_test_universal(power: &Power, chunk_size: &ChunkSize, batch_size: &BatchSize) -> Settings {
+        Settings::new(
+            ContributionMode::Chunked,
+            ProvingSystem::Marlin,
+            CurveKind::Bls12_377,
+            *power,
+            *batch_size,
+            *chunk_size,
+        )
+    }
+
     fn test_3_chunks() -> Settings {
         Settings::new(
             ContributionMode::Chunked,
@@ -199,6 +279,31 @@ impl Parameters {
             chunk_size!(number_of_chunks, proving_system, power),
         )
     }
+
+    fn test_marlin_3_chunks() -> Settings {
+        Settings::new(
+            ContributionMode::Chunked,
+            ProvingSystem::Marlin,
+            CurveKind::Bls12_377,
+            Power::from(8_usize),
+            BatchSize::from(64_usize),
+            ChunkSize::from(86_usize),
+        )
+    }
+
+    fn test_marlin_chunks(number_of_chunks: &NumberOfChunks) -> Settings {
+        let proving_system = ProvingSystem::Marlin;
+        let power = 14_usize;
+        let batch_size = 128_usize;
+        Settings::new(
+            ContributionMode::Chunked,
+            proving_system,
+            CurveKind::Bls12_377,
+            Power::from(power),
+            BatchSize::from(batch_size),
+            chunk_size!(number_of_chunks, proving_system, power),
+        )
+    }
 }
 
 #[serde_with::serde_as]
@@ -248,6 +353,39 @@ pub struct Environment {
     allow_current_verifiers_in_queue: bool,
     /// The minimum number of seconds to wait after aggregation before starting the next round.
     queue_wait_time: u64,
+    /// The hardware contributors must declare to join the queue, if any.
+    #[serde(default)]
+    hardware_policy: Option<HardwarePolicy>,
+    /// The power of the practice challenge served to contributors, if practice mode is enabled.
+    #[serde(default)]
+    practice_power: Option<Power>,
+    /// The number of contributions each chunk needs in a round, if fixed.
+    #[serde(default)]
+    contributions_per_chunk: Option<u64>,
+    /// The ID of the ceremony this coordinator expects to find in storage, if set.
+    #[serde(default)]
+    ceremony_id: Option<String>,
+    /// The source of the key used to encrypt the coordinator metadata in storage, if set.
+    #[serde(default)]
+    storage_encryption_key: Option<StorageKeySource>,
+    /// The number of most recent rounds kept individually in the ceremony history, if capped.
+    #[serde(default)]
+    history_rounds: Option<usize>,
+    /// The budget contributors have to re-upload a contribution that failed verification, if any.
+    #[serde(default)]
+    contribution_retry_policy: Option<ContributionRetryPolicy>,
+    /// The number of distinct verifiers which must verify each contribution before it is accepted.
+    #[serde(default = "Environment::default_required_verifications")]
+    required_verifications: u64,
+    /// The mode in which the coordinator runs against its storage.
+    #[serde(default)]
+    coordinator_mode: CoordinatorMode,
+    /// The range of client versions participants must identify themselves with, if any.
+    #[serde(default)]
+    client_version_policy: Option<ClientVersionPolicy>,
+    /// The gates contributors must pass to join the queue, if any.
+    #[serde(default)]
+    admission_policy: Option<AdmissionPolicy>,
 
     /// The contributors managed by the coordinator.
     coordinator_contributors: Vec<Participant>,
@@ -407,6 +545,144 @@ impl Environment {
         self.queue_wait_time
     }
 
+    ///
+    /// Returns the hardware contributors must declare to join the queue, if any.
+    ///
+    /// If set, contributors must declare hardware meeting the minimum of the policy,
+    /// and the participant lock timeout of each contributor is scaled by their declaration.
+    ///
+    pub const fn hardware_policy(&self) -> &Option<HardwarePolicy> {
+        &self.hardware_policy
+    }
+
+    ///
+    /// Returns the power of the practice challenge, if practice mode is enabled.
+    ///
+    /// If set, the coordinator regenerates a small practice challenge on startup,
+    /// which contributors may compute on to check their setup before joining a round.
+    /// Practice contributions never touch the round state or the queue.
+    ///
+    pub const fn practice_power(&self) -> Option<Power> {
+        self.practice_power
+    }
+
+    ///
+    /// Returns the number of contributions each chunk needs in a round, if fixed.
+    ///
+    /// If set, a round is complete once each chunk has this many verified contributions,
+    /// and only this many contributors are assigned tasks. The remaining contributors
+    /// in the round are left as spares. Otherwise, each chunk needs one contribution
+    /// from every contributor in the round.
+    ///
+    pub const fn contributions_per_chunk(&self) -> Option<u64> {
+        self.contributions_per_chunk
+    }
+
+    ///
+    /// Returns the ID of the ceremony this coordinator expects to find in storage, if set.
+    ///
+    /// If set, the coordinator records this ID when initializing the ceremony, and refuses
+    /// to start on storage that records a different ID. Otherwise, a random ID is recorded.
+    ///
+    pub fn ceremony_id(&self) -> Option<&str> {
+        self.ceremony_id.as_deref()
+    }
+
+    ///
+    /// Returns the source of the key used to encrypt the coordinator metadata in storage, if set.
+    ///
+    /// If set, the coordinator state, round headers and chunk states are encrypted when they
+    /// are written, and metadata written before encryption was enabled remains readable.
+    ///
+    pub fn storage_encryption_key(&self) -> Option<&StorageKeySource> {
+        self.storage_encryption_key.as_ref()
+    }
+
+    ///
+    /// Returns the number of most recent rounds kept individually in the ceremony history, if capped.
+    ///
+    /// If set, older rounds are rolled up into the totals of the history.
+    /// Otherwise, every round is kept.
+    ///
+    pub const fn history_rounds(&self) -> Option<usize> {
+        self.history_rounds
+    }
+
+    ///
+    /// Returns the budget contributors have to re-upload a contribution that failed verification, if any.
+    ///
+    /// If set, a contribution that fails verification is quarantined, and its contributor keeps
+    /// the task to lock the chunk again and re-upload within the window of the policy.
+    /// A contributor who exhausts the retries of the policy, or lets the window lapse,
+    /// is dropped and their tasks are reassigned. Otherwise, a failed contribution is discarded.
+    ///
+    pub fn contribution_retry_policy(&self) -> Option<&ContributionRetryPolicy> {
+        self.contribution_retry_policy.as_ref()
+    }
+
+    ///
+    /// Returns the number of distinct verifiers which must verify each contribution
+    /// before it is accepted, which defaults to `1`.
+    ///
+    /// Until a contribution is accepted, each successful verification is recorded in the
+    /// contribution, and the verification task is assigned to another verifier.
+    ///
+    pub const fn required_verifications(&self) -> u64 {
+        self.required_verifications
+    }
+
+    /// Returns the number of required verifications of environments which do not set it.
+    const fn default_required_verifications() -> u64 {
+        1
+    }
+
+    ///
+    /// Returns the mode in which the coordinator runs against its storage.
+    ///
+    /// A read-only coordinator does not initialize rounds or run background tasks,
+    /// rejects every mutation of its storage, and refreshes its view of the storage
+    /// of the primary coordinator on each update.
+    ///
+    pub const fn coordinator_mode(&self) -> &CoordinatorMode {
+        &self.coordinator_mode
+    }
+
+    ///
+    /// Returns `true` if the coordinator runs in read-only mode. Otherwise, returns `false`.
+    ///
+    pub fn is_read_only(&self) -> bool {
+        matches!(self.coordinator_mode, CoordinatorMode::ReadOnly { .. })
+    }
+
+    ///
+    /// Returns the range of client versions participants must identify themselves with, if any.
+    ///
+    /// If set, participants whose client is outside of the range are asked to upgrade
+    /// before they may join the queue or work on tasks.
+    ///
+    pub const fn client_version_policy(&self) -> &Option<ClientVersionPolicy> {
+        &self.client_version_policy
+    }
+
+    ///
+    /// Returns the gates contributors must pass to join the queue, if any.
+    ///
+    /// If set, contributors other than the coordinator contributors must present
+    /// an invitation token, the solution to a proof-of-work challenge, or both,
+    /// as required by the policy, to join the queue.
+    ///
+    pub const fn admission_policy(&self) -> &Option<AdmissionPolicy> {
+        &self.admission_policy
+    }
+
+    ///
+    /// Restores the secrets which are not serialized, such as the key of the invitation
+    /// tokens, from the given environment the coordinator was started with.
+    ///
+    pub(crate) fn restore_secrets(&mut self, environment: &Environment) {
+        self.admission_policy = environment.admission_policy.clone();
+    }
+
     ///
     /// Returns the contributors managed by the coordinator.
     ///
@@ -451,10 +727,39 @@ impl Environment {
     /// to run given a proof system, power and chunk size.
     ///
     pub fn number_of_chunks(&self) -> u64 {
-        let proving_system = &self.parameters.proving_system;
-        let power = self.parameters.power;
-        let chunk_size = self.parameters.chunk_size;
-        (total_size_in_g1!(proving_system, power) + chunk_size as u64 - 1) / chunk_size as u64
+        self.parameters.number_of_chunks()
+    }
+
+    ///
+    /// Returns `Ok` if the given chunk ID is one of the chunks of the ceremony.
+    ///
+    /// Otherwise, returns `ChunkIdOutOfRange` with the number of chunks, so chunk IDs
+    /// supplied by participants are rejected before they reach the round or storage.
+    ///
+    pub fn validate_chunk_id(&self, chunk_id: u64) -> Result<(), CoordinatorError> {
+        let num_chunks = self.number_of_chunks();
+        match chunk_id < num_chunks {
+            true => Ok(()),
+            false => Err(CoordinatorError::ChunkIdOutOfRange { chunk_id, num_chunks }),
+        }
+    }
+
+    ///
+    /// Returns `Ok` if the required number of verifications is at least one, and at most
+    /// the maximum number of verifiers in a round.
+    ///
+    /// Otherwise, returns `RequiredVerificationsInvalid`, as no contribution could be accepted.
+    ///
+    pub fn validate_required_verifications(&self) -> Result<(), CoordinatorError> {
+        let required_verifications = self.required_verifications;
+        let maximum_verifiers = self.maximum_verifiers_per_round;
+        match required_verifications > 0 && required_verifications <= maximum_verifiers as u64 {
+            true => Ok(()),
+            false => Err(CoordinatorError::RequiredVerificationsInvalid {
+                required_verifications,
+                maximum_verifiers,
+            }),
+        }
     }
 
     /// Returns the storage system of the coordinator.
@@ -537,6 +842,78 @@ impl Testing {
         deployment.environment.participant_lock_timeout = participant_lock_timeout;
         deployment
     }
+
+    pub fn hardware_policy(&self, hardware_policy: Option<HardwarePolicy>) -> Self {
+        let mut deployment = self.clone();
+        deployment.environment.hardware_policy = hardware_policy;
+        deployment
+    }
+
+    pub fn practice_power(&self, practice_power: Option<Power>) -> Self {
+        let mut deployment = self.clone();
+        deployment.environment.practice_power = practice_power;
+        deployment
+    }
+
+    pub fn contributions_per_chunk(&self, contributions_per_chunk: Option<u64>) -> Self {
+        let mut deployment = self.clone();
+        deployment.environment.contributions_per_chunk = contributions_per_chunk;
+        deployment
+    }
+
+    pub fn ceremony_id(&self, ceremony_id: Option<String>) -> Self {
+        let mut deployment = self.clone();
+        deployment.environment.ceremony_id = ceremony_id;
+        deployment
+    }
+
+    pub fn storage_encryption_key(&self, storage_encryption_key: Option<StorageKeySource>) -> Self {
+        let mut deployment = self.clone();
+        deployment.environment.storage_encryption_key = storage_encryption_key;
+        deployment
+    }
+
+    pub fn history_rounds(&self, history_rounds: Option<usize>) -> Self {
+        let mut deployment = self.clone();
+        deployment.environment.history_rounds = history_rounds;
+        deployment
+    }
+
+    pub fn contribution_retry_policy(&self, contribution_retry_policy: Option<ContributionRetryPolicy>) -> Self {
+        let mut deployment = self.clone();
+        deployment.environment.contribution_retry_policy = contribution_retry_policy;
+        deployment
+    }
+
+    pub fn required_verifications(&self, required_verifications: u64) -> Self {
+        let mut deployment = self.clone();
+        deployment.environment.required_verifications = required_verifications;
+        deployment
+    }
+
+    pub fn coordinator_mode(&self, coordinator_mode: CoordinatorMode) -> Self {
+        let mut deployment = self.clone();
+        deployment.environment.coordinator_mode = coordinator_mode;
+        deployment
+    }
+
+    pub fn client_version_policy(&self, client_version_policy: Option<ClientVersionPolicy>) -> Self {
+        let mut deployment = self.clone();
+        deployment.environment.client_version_policy = client_version_policy;
+        deployment
+    }
+
+    pub fn admission_policy(&self, admission_policy: Option<AdmissionPolicy>) -> Self {
+        let mut deployment = self.clone();
+        deployment.environment.admission_policy = admission_policy;
+        deployment
+    }
+
+    pub fn local_base_directory(&self, local_base_directory: &str) -> Self {
+        let mut deployment = self.clone();
+        deployment.environment.local_base_directory = local_base_directory.to_string();
+        deployment
+    }
 }
 
 impl From<Parameters> for Testing {
@@ -577,6 +954,17 @@ impl std::default::Default for Testing {
                 allow_current_contributors_in_queue: true,
                 allow_current_verifiers_in_queue: true,
                 queue_wait_time: 0,
+                hardware_policy: None,
+                practice_power: None,
+                contributions_per_chunk: None,
+                ceremony_id: None,
+                storage_encryption_key: None,
+                history_rounds: None,
+                contribution_retry_policy: None,
+                required_verifications: 1,
+                coordinator_mode: CoordinatorMode::Primary,
+                client_version_policy: None,
+                admission_policy: None,
 
                 coordinator_contributors: vec![Participant::new_contributor("testing-coordinator-contributor")],
                 coordinator_verifiers: vec![Participant::new_verifier("testing-coordinator-verifier")],
@@ -633,6 +1021,83 @@ impl Development {
         deployment.environment.coordinator_verifiers = verifiers.to_vec();
         deployment
     }
+
+    #[inline]
+    pub fn practice_power(&self, practice_power: Option<Power>) -> Self {
+        let mut deployment = self.clone();
+        deployment.environment.practice_power = practice_power;
+        deployment
+    }
+
+    #[inline]
+    pub fn contributions_per_chunk(&self, contributions_per_chunk: Option<u64>) -> Self {
+        let mut deployment = self.clone();
+        deployment.environment.contributions_per_chunk = contributions_per_chunk;
+        deployment
+    }
+
+    #[inline]
+    pub fn ceremony_id(&self, ceremony_id: Option<String>) -> Self {
+        let mut deployment = self.clone();
+        deployment.environment.ceremony_id = ceremony_id;
+        deployment
+    }
+
+    #[inline]
+    pub fn storage_encryption_key(&self, storage_encryption_key: Option<StorageKeySource>) -> Self {
+        let mut deployment = self.clone();
+        deployment.environment.storage_encryption_key = storage_encryption_key;
+        deployment
+    }
+
+    #[inline]
+    pub fn history_rounds(&self, history_rounds: Option<usize>) -> Self {
+        let mut deployment = self.clone();
+        deployment.environment.history_rounds = history_rounds;
+        deployment
+    }
+
+    #[inline]
+    pub fn contribution_retry_policy(&self, contribution_retry_policy: Option<ContributionRetryPolicy>) -> Self {
+        let mut deployment = self.clone();
+        deployment.environment.contribution_retry_policy = contribution_retry_policy;
+        deployment
+    }
+
+    #[inline]
+    pub fn required_verifications(&self, required_verifications: u64) -> Self {
+        let mut deployment = self.clone();
+        deployment.environment.required_verifications = required_verifications;
+        deployment
+    }
+
+    #[inline]
+    pub fn coordinator_mode(&self, coordinator_mode: CoordinatorMode) -> Self {
+        let mut deployment = self.clone();
+        deployment.environment.coordinator_mode = coordinator_mode;
+        deployment
+    }
+
+    #[inline]
+    pub fn client_version_policy(&self, client_version_policy: Option<ClientVersionPolicy>) -> Self {
+        let mut deployment = self.clone();
+        deployment.environment.client_version_policy = client_version_policy;
+        deployment
+    }
+
+    #[inline]
+    pub fn admission_policy(&self, admission_policy: Option<AdmissionPolicy>) -> Self {
+        let mut deployment = self.clone();
+        deployment.environment.admission_policy = admission_policy;
+        deployment
+    }
+
+    #[inline]
+    pub fn local_base_directory(&self, local_base_directory: &str) -> Self {
+        let mut deployment = self.clone();
+        deployment.environment.local_base_directory = local_base_directory.to_string();
+        deployment
+    }
 }
 
 impl From<Parameters> for Development {
@@ -679,6 +1144,17 @@ impl std::default::Default for Development {
                 allow_current_contributors_in_queue: true,
                 allow_current_verifiers_in_queue: true,
                 queue_wait_time: 60,
+                hardware_policy: None,
+                practice_power: None,
+                contributions_per_chunk: None,
+                ceremony_id: None,
+                storage_encryption_key: None,
+                history_rounds: None,
+                contribution_retry_policy: None,
+                required_verifications: 1,
+                coordinator_mode: CoordinatorMode::Primary,
+                client_version_policy: None,
+                admission_policy: None,
 
                 coordinator_contributors: vec![Participant::new_contributor("development-coordinator-contributor")],
                 coordinator_verifiers: vec![Participant::new_verifier("development-coordinator-verifier")],
@@ -735,6 +1211,83 @@ impl Production {
         deployment.environment.coordinator_verifiers = verifiers.to_vec();
         deployment
     }
+
+    #[inline]
+    pub fn practice_power(&self, practice_power: Option<Power>) -> Self {
+        let mut deployment = self.clone();
+        deployment.environment.practice_power = practice_power;
+        deployment
+    }
+
+    #[inline]
+    pub fn contributions_per_chunk(&self, contributions_per_chunk: Option<u64>) -> Self {
+        let mut deployment = self.clone();
+        deployment.environment.contributions_per_chunk = contributions_per_chunk;
+        deployment
+    }
+
+    #[inline]
+    pub fn ceremony_id(&self, ceremony_id: Option<String>) -> Self {
+        let mut deployment = self.clone();
+        deployment.environment.ceremony_id = ceremony_id;
+        deployment
+    }
+
+    #[inline]
+    pub fn storage_encryption_key(&self, storage_encryption_key: Option<StorageKeySource>) -> Self {
+        let mut deployment = self.clone();
+        deployment.environment.storage_encryption_key = storage_encryption_key;
+        deployment
+    }
+
+    #[inline]
+    pub fn history_rounds(&self, history_rounds: Option<usize>) -> Self {
+        let mut deployment = self.clone();
+        deployment.environment.history_rounds = history_rounds;
+        deployment
+    }
+
+    #[inline]
+    pub fn contribution_retry_policy(&self, contribution_retry_policy: Option<ContributionRetryPolicy>) -> Self {
+        let mut deployment = self.clone();
+        deployment.environment.contribution_retry_policy = contribution_retry_policy;
+        deployment
+    }
+
+    #[inline]
+    pub fn required_verifications(&self, required_verifications: u64) -> Self {
+        let mut deployment = self.clone();
+        deployment.environment.required_verifications = required_verifications;
+        deployment
+    }
+
+    #[inline]
+    pub fn coordinator_mode(&self, coordinator_mode: CoordinatorMode) -> Self {
+        let mut deployment = self.clone();
+        deployment.environment.coordinator_mode = coordinator_mode;
+        deployment
+    }
+
+    #[inline]
+    pub fn client_version_policy(&self, client_version_policy: Option<ClientVersionPolicy>) -> Self {
+        let mut deployment = self.clone();
+        deployment.environment.client_version_policy = client_version_policy;
+        deployment
+    }
+
+    #[inline]
+    pub fn admission_policy(&self, admission_policy: Option<AdmissionPolicy>) -> Self {
+        let mut deployment = self.clone();
+        deployment.environment.admission_policy = admission_policy;
+        deployment
+    }
+
+    #[inline]
+    pub fn local_base_directory(&self, local_base_directory: &str) -> Self {
+        let mut deployment = self.clone();
+        deployment.environment.local_base_directory = local_base_directory.to_string();
+        deployment
+    }
 }
 
 impl From<Parameters> for Production {
@@ -775,6 +1328,17 @@ impl std::default::Default for Production {
                 allow_current_contributors_in_queue: false,
                 allow_current_verifiers_in_queue: true,
                 queue_wait_time: 120,
+                hardware_policy: None,
+                practice_power: None,
+                contributions_per_chunk: None,
+                ceremony_id: None,
+                storage_encryption_key: None,
+                history_rounds: None,
+                contribution_retry_policy: None,
+                required_verifications: 1,
+                coordinator_mode: CoordinatorMode::Primary,
+                client_version_policy: None,
+                admission_policy: None,
 
                 coordinator_contributors: vec![Participant::new_contributor("coordinator-contributor")],
                 coordinator_verifiers: vec![Participant::new_verifier("coordinator-verifier")],
@@ -790,6 +1354,45 @@ impl std::default::Default for Production {
 #[cfg(test)]
 mod tests {
     use crate::environment::*;
+    use phase1::Phase1Parameters;
+    use zexe_algebra::Bls12_377;
+
+    #[test]
+    fn test_settings_display() {
+        let settings = Parameters::Test8Chunks.to_settings();
+        let display = settings.to_string();
+        assert!(display.contains(&format!("power: {}", settings.power())));
+        assert!(display.contains(&format!("batch size: {}", settings.batch_size())));
+        assert!(display.contains(&format!("chunk size: {}", settings.chunk_size())));
+        assert!(display.contains("number of chunks: 8"));
+        assert!(display.contains(&format!("curve: {:?}", settings.curve())));
+        assert!(display.contains(&format!("proving system: {:?}", settings.proving_system())));
+        assert!(display.contains(&format!("contribution mode: {:?}", settings.contribution_mode())));
+
+        // Parameters constructed from the settings display the same values.
+        let chunk_id = 0;
+        let parameters: Phase1Parameters<Bls12_377> = phase1_chunked_parameters!(Bls12_377, settings, chunk_id);
+        for field in parameters
+            .to_string()
+            .split(", ")
+            .filter(|field| !field.starts_with("chunk index"))
+        {
+            assert!(display.contains(field), "{} is missing from {}", field, display);
+        }
+    }
+
+    #[test]
+    fn test_validate_chunk_id() {
+        let environment: Environment = Testing::from(Parameters::Test3Chunks).into();
+        assert!(environment.validate_chunk_id(0).is_ok());
+        assert!(environment.validate_chunk_id(2).is_ok());
+        for chunk_id in &[3, u64::MAX] {
+            match environment.validate_chunk_id(*chunk_id) {
+                Err(CoordinatorError::ChunkIdOutOfRange { num_chunks: 3, .. }) => {}
+                result => panic!("expected ChunkIdOutOfRange, found {:?}", result),
+            }
+        }
+    }
 
     #[test]
     fn test_aleo_test_3_chunks() {
@@ -848,4 +1451,98 @@ mod tests {
         assert_eq!(ChunkSize::from(1639_usize), chunk_size);
         assert_eq!(number_of_chunks as u64, Testing::from(parameters).number_of_chunks());
     }
+
+    #[test]
+    fn test_aleo_test_marlin_3_chunks() {
+        let parameters = Parameters::TestMarlin3Chunks;
+        let settings = parameters.to_settings();
+        assert_eq!(ProvingSystem::Marlin, settings.proving_system);
+        assert_eq!(Power::from(8_usize), settings.power);
+        assert_eq!(3, Testing::from(parameters).number_of_chunks());
+    }
+
+    #[test]
+    fn test_marlin_custom_chunk_8() {
+        let number_of_chunks = 8;
+
+        let parameters = Parameters::TestMarlinChunks { number_of_chunks };
+        let settings = parameters.to_settings();
+
+        assert_eq!(ProvingSystem::Marlin, settings.proving_system);
+        assert_eq!(Power::from(14_usize), settings.power);
+        assert_eq!(ChunkSize::from(2048_usize), settings.chunk_size);
+        assert_eq!(number_of_chunks as u64, Testing::from(parameters).number_of_chunks());
+    }
+
+    #[test]
+    fn test_aleo_test_universal() {
+        let parameters = Parameters::AleoTestUniversal {
+            power: 10,
+            chunk_size: 256,
+            batch_size: 64,
+        };
+        let settings = parameters.to_settings();
+
+        assert_eq!(ProvingSystem::Marlin, settings.proving_system);
+        assert_eq!(Power::from(10_usize), settings.power);
+        assert_eq!(ChunkSize::from(256_usize), settings.chunk_size);
+        assert_eq!(BatchSize::from(64_usize), settings.batch_size);
+        assert_eq!(4, Production::from(parameters).number_of_chunks());
+    }
+
+    #[test]
+    fn test_marlin_file_sizes() {
+        use crate::storage::Object;
+
+        let environment: Environment = Testing::from(Parameters::TestMarlin3Chunks).into();
+
+        // Bls12_377 points are 96 bytes in G1 and 192 bytes in G2 when uncompressed,
+        // and the hash of the previous contribution is 64 bytes.
+        let (g1, g2, hash) = (96, 192, 64);
+
+        // The first chunk carries the 3 + 3 * power alpha powers and the power + 2 powers in G2.
+        let extras = (3 + 3 * 8) * g1 + (8 + 2) * g2;
+        assert_eq!(
+            86 * g1 + extras + hash,
+            Object::contribution_file_size(&environment, 0, true)
+        );
+        assert_eq!(86 * g1 + hash, Object::contribution_file_size(&environment, 1, true));
+        assert_eq!(84 * g1 + hash, Object::contribution_file_size(&environment, 2, true));
+
+        // The response is compressed and carries the public key of the contributor.
+        let public_key = 3 * 96 + 6 * 48;
+        assert_eq!(
+            86 * 48 + hash + public_key,
+            Object::contribution_file_size(&environment, 1, false)
+        );
+
+        // The round file holds every power of tau in G1 once, with a single hash.
+        assert_eq!(256 * g1 + extras + hash, Object::round_file_size(&environment));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_marlin_batch_size_too_small() {
+        Settings::new(
+            ContributionMode::Chunked,
+            ProvingSystem::Marlin,
+            CurveKind::Bls12_377,
+            Power::from(10_usize),
+            BatchSize::from(32_usize),
+            ChunkSize::from(64_usize),
+        );
+    }
+
+    #[test]
+    fn test_marlin_minimum_batch_size() {
+        let settings = Settings::new(
+            ContributionMode::Chunked,
+            ProvingSystem::Marlin,
+            CurveKind::Bls12_377,
+            Power::from(10_usize),
+            BatchSize::from(33_usize),
+            ChunkSize::from(64_usize),
+        );
+        assert_eq!(BatchSize::from(33_usize), settings.batch_size);
+    }
 }
@@ -47,6 +47,9 @@ pub use coordinator_state::CoordinatorState;
 
 pub mod environment;
 
+pub mod multi_coordinator;
+pub use multi_coordinator::MultiCoordinator;
+
 pub mod objects;
 pub use objects::{ContributionFileSignature, ContributionState, Participant, Round};
 
@@ -57,3 +60,6 @@ pub mod testing;
 
 #[cfg(test)]
 pub mod tests;
+
+#[cfg(feature = "webhook")]
+pub mod webhook;
@@ -81,6 +81,11 @@ macro_rules! chunk_size {
 
 /// Returns the final round filesize given an instantiation of `PairingEngine`,
 /// an instance of `Settings`, and a compressed setting.
+///
+/// For Marlin, the `3 + 3 * power` alpha powers in G1 and the `power + 2` powers
+/// in G2 are counted once by `Phase1Parameters`, which only adds them to chunk 0
+/// and to the full parameters. The contribution and round sizes therefore need
+/// no Marlin-specific adjustment here.
 #[macro_export]
 macro_rules! round_filesize {
     ($curve:ident, $settings:ident, $compressed:ident) => {{
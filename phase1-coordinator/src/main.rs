@@ -25,6 +25,8 @@ pub async fn main() -> anyhow::Result<()> {
     // use phase1_coordinator::environment::Production;
     // let environment: Environment = Production::from(Parameters::AleoInner).into();
 
+    info!("Starting the coordinator with {}", environment.parameters());
+
     // Instantiate the coordinator.
     let coordinator = coordinator(&environment, Box::new(Dummy)).await?;
 
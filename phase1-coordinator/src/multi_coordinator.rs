@@ -0,0 +1,163 @@
+use crate::{authentication::Signature, environment::Environment, Coordinator, CoordinatorError};
+
+use std::{collections::BTreeMap, path::PathBuf, time::Duration};
+use tokio::{task::JoinHandle, time::sleep};
+use tracing::*;
+
+///
+/// The coordinators of multiple setups run concurrently in one process,
+/// such as the inner, outer, and universal setups.
+///
+/// Each setup is named by the path segment of its routes, as in `/v1/<setup>/...`,
+/// and has its own coordinator, with storage and state isolated from the other setups.
+///
+#[derive(Clone, Default)]
+pub struct MultiCoordinator {
+    /// The coordinator of each setup, by the name of the setup.
+    coordinators: BTreeMap<String, Coordinator>,
+}
+
+impl MultiCoordinator {
+    ///
+    /// Creates a new instance of `MultiCoordinator`, without any setups.
+    ///
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    ///
+    /// Adds a coordinator for the given setup, with the given environment and signature scheme.
+    ///
+    /// The setup must be a valid path segment, and the environment must store its transcript
+    /// in a different base directory than the environments of the other setups. The base
+    /// directories are compared in canonical form, so two spellings of one directory match.
+    ///
+    #[inline]
+    pub fn insert(
+        &mut self,
+        setup: &str,
+        environment: Environment,
+        signature: Box<dyn Signature>,
+    ) -> Result<&Coordinator, CoordinatorError> {
+        // Check that the setup is a valid path segment.
+        if setup.is_empty() || !setup.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+            return Err(CoordinatorError::SetupInvalid);
+        }
+
+        // Check that the setup was not added already.
+        if self.coordinators.contains_key(setup) {
+            return Err(CoordinatorError::SetupAlreadyAdded);
+        }
+
+        // Check that the storage of the setup is isolated from the other setups.
+        let base_directory = canonical_directory(environment.local_base_directory())?;
+        for coordinator in self.coordinators.values() {
+            if canonical_directory(coordinator.environment().local_base_directory())? == base_directory {
+                return Err(CoordinatorError::SetupStorageShared);
+            }
+        }
+
+        info!(
+            "Adding the {} setup with storage in {}",
+            setup,
+            environment.local_base_directory()
+        );
+        let coordinator = Coordinator::new(environment, signature)?;
+        Ok(self.coordinators.entry(setup.to_string()).or_insert(coordinator))
+    }
+
+    ///
+    /// Returns the coordinator of the given setup, if it exists.
+    /// Otherwise returns `None`.
+    ///
+    #[inline]
+    pub fn get(&self, setup: &str) -> Option<&Coordinator> {
+        self.coordinators.get(setup)
+    }
+
+    ///
+    /// Returns the names of the setups, in alphabetical order.
+    ///
+    #[inline]
+    pub fn setups(&self) -> Vec<&str> {
+        self.coordinators.keys().map(String::as_str).collect()
+    }
+
+    ///
+    /// Returns the mount point of the routes of the given setup.
+    ///
+    #[inline]
+    pub fn mount_point(setup: &str) -> String {
+        format!("/v1/{}", setup)
+    }
+
+    ///
+    /// Initializes the coordinator of each setup.
+    ///
+    #[inline]
+    pub fn initialize(&self) -> Result<(), CoordinatorError> {
+        for (setup, coordinator) in &self.coordinators {
+            debug!("Initializing the {} setup", setup);
+            coordinator.initialize()?;
+        }
+        Ok(())
+    }
+
+    ///
+    /// Spawns the background service of each setup, which updates
+    /// its coordinator at the given interval.
+    ///
+    /// This function must be called from within a tokio runtime.
+    ///
+    pub fn spawn_services(&self, interval: Duration) -> Vec<JoinHandle<()>> {
+        self.coordinators
+            .iter()
+            .map(|(setup, coordinator)| {
+                let setup = setup.clone();
+                let coordinator = coordinator.clone();
+                tokio::task::spawn(async move {
+                    loop {
+                        // Run the update operation on the blocking thread pool, as it waits on
+                        // the locks of the coordinator and reads and writes its storage.
+                        let updater = coordinator.clone();
+                        match tokio::task::spawn_blocking(move || updater.update()).await {
+                            Ok(Ok(())) => (),
+                            Ok(Err(error)) => error!("Failed to update the {} setup: {}", setup, error),
+                            Err(error) => error!("The update of the {} setup failed to run: {}", setup, error),
+                        }
+
+                        // Sleep for the interval in between iterations.
+                        sleep(interval).await;
+                    }
+                })
+            })
+            .collect()
+    }
+}
+
+///
+/// Returns the canonical form of the given directory, with symbolic links and relative
+/// components resolved. The directory may not exist yet, in which case the deepest
+/// existing ancestor is resolved and the remaining components are appended to it.
+///
+fn canonical_directory(directory: &str) -> Result<PathBuf, CoordinatorError> {
+    let mut existing = std::env::current_dir()?.join(directory);
+    let mut missing = vec![];
+    loop {
+        match existing.canonicalize() {
+            Ok(canonical) => {
+                existing = canonical;
+                break;
+            }
+            Err(_) => match (existing.file_name(), existing.parent()) {
+                (Some(name), Some(parent)) => {
+                    missing.push(name.to_os_string());
+                    existing = parent.to_path_buf();
+                }
+                _ => break,
+            },
+        }
+    }
+    Ok(missing.into_iter().rev().fold(existing, |path, name| path.join(name)))
+}
@@ -0,0 +1,516 @@
+use crate::{objects::Participant, CoordinatorError};
+
+use chrono::{DateTime, Utc};
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use serde_with::DurationSecondsWithFrac;
+use setup_utils::calculate_hash;
+use std::{fmt, fs, path::Path};
+use subtle::ConstantTimeEq;
+
+/// The number of random bytes in the ID of an invitation token, and in a proof-of-work challenge.
+const RANDOM_BYTES: usize = 16;
+
+/// The number of random bytes in the key proof-of-work challenges are signed with.
+const KEY_BYTES: usize = 32;
+
+/// The gates a contributor must pass to join the queue, which keep Sybil
+/// participants from flooding the queue and starving genuine contributors.
+///
+/// Each gate is optional, and a contributor must pass every gate that is set.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AdmissionPolicy {
+    /// The invitation tokens contributors must present, if any.
+    invitation: Option<InvitationPolicy>,
+    /// The proof-of-work contributors must solve, if any.
+    proof_of_work: Option<ProofOfWorkPolicy>,
+}
+
+impl AdmissionPolicy {
+    /// Creates a new instance of `AdmissionPolicy`.
+    #[inline]
+    pub fn new(invitation: Option<InvitationPolicy>, proof_of_work: Option<ProofOfWorkPolicy>) -> Self {
+        Self {
+            invitation,
+            proof_of_work,
+        }
+    }
+
+    /// Returns the invitation tokens contributors must present, if any.
+    #[inline]
+    pub fn invitation(&self) -> Option<&InvitationPolicy> {
+        self.invitation.as_ref()
+    }
+
+    /// Returns the proof-of-work contributors must solve, if any.
+    #[inline]
+    pub fn proof_of_work(&self) -> Option<&ProofOfWorkPolicy> {
+        self.proof_of_work.as_ref()
+    }
+}
+
+///
+/// The invitation tokens issued by the operator, each admitting a single contributor to the queue.
+///
+/// The key the tokens are signed with is not serialized, so it is never written to the
+/// coordinator state or the logs. It is loaded from a separate key source at startup,
+/// and a policy deserialized without its key rejects every token.
+///
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct InvitationPolicy {
+    /// The hex-encoded key the invitation tokens are signed with.
+    #[serde(skip)]
+    secret: String,
+}
+
+impl InvitationPolicy {
+    /// Creates a new instance of `InvitationPolicy`, signing tokens with the given key.
+    #[inline]
+    pub fn new(secret: &[u8]) -> Self {
+        Self {
+            secret: hex::encode(secret),
+        }
+    }
+
+    ///
+    /// Creates a new instance of `InvitationPolicy`, signing tokens with the
+    /// hex-encoded key in the given file.
+    ///
+    /// If the file does not hold a key, returns `InvitationKeyMissing`.
+    ///
+    #[inline]
+    pub fn from_key_file(path: impl AsRef<Path>) -> Result<Self, CoordinatorError> {
+        let secret = hex::decode(fs::read_to_string(path)?.trim())?;
+        if secret.is_empty() {
+            return Err(CoordinatorError::InvitationKeyMissing);
+        }
+        Ok(Self::new(&secret))
+    }
+
+    /// Returns `true` if the policy holds the key the invitation tokens are signed with.
+    #[inline]
+    pub fn has_key(&self) -> bool {
+        !self.secret.is_empty()
+    }
+
+    ///
+    /// Issues a new invitation token, as the random ID of the token
+    /// followed by its signature, separated by a period.
+    ///
+    /// If the policy does not hold its key, returns `InvitationKeyMissing`.
+    ///
+    #[inline]
+    pub fn issue(&self) -> Result<String, CoordinatorError> {
+        if !self.has_key() {
+            return Err(CoordinatorError::InvitationKeyMissing);
+        }
+
+        let mut id = [0u8; RANDOM_BYTES];
+        rand::thread_rng().fill_bytes(&mut id[..]);
+        let id = hex::encode(id);
+        Ok(format!("{}.{}", id, self.sign(&id)))
+    }
+
+    ///
+    /// Checks the signature of the given invitation token, and returns the ID of the token.
+    ///
+    /// If the token is malformed, was not signed with this key, or the policy
+    /// does not hold its key, returns `InvitationInvalid`.
+    ///
+    #[inline]
+    pub fn verify(&self, token: &str) -> Result<String, CoordinatorError> {
+        let mut parts = token.splitn(2, '.');
+        match (parts.next(), parts.next()) {
+            (Some(id), Some(signature)) if self.has_key() && !id.is_empty() => {
+                // Compare the signatures in constant time, so the time taken does
+                // not reveal how much of a forged signature is correct.
+                match bool::from(self.sign(id).as_bytes().ct_eq(signature.as_bytes())) {
+                    true => Ok(id.to_string()),
+                    false => Err(CoordinatorError::InvitationInvalid),
+                }
+            }
+            _ => Err(CoordinatorError::InvitationInvalid),
+        }
+    }
+
+    /// Returns the signature of the given token ID, as a keyed BLAKE2b hash, which is
+    /// a MAC as BLAKE2b is not susceptible to length extension.
+    fn sign(&self, id: &str) -> String {
+        hex::encode(calculate_hash(format!("{}.{}", self.secret, id).as_bytes()))
+    }
+}
+
+impl fmt::Debug for InvitationPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // The key is omitted, so it is not written to the logs.
+        f.debug_struct("InvitationPolicy")
+            .field("secret", &"<redacted>")
+            .finish()
+    }
+}
+
+///
+/// The hashcash-style proof-of-work contributors solve over a challenge issued by the coordinator.
+///
+/// The challenges are signed rather than stored, so issuing them takes up no memory or state,
+/// and no number of challenge requests keeps other contributors from being issued one.
+/// The key the challenges are signed with is generated when the policy is created and is not
+/// serialized, so challenges issued before a restart of the coordinator are rejected after it.
+///
+#[serde_with::serde_as]
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProofOfWorkPolicy {
+    /// The number of leading zero bits the hash of a solution must have.
+    difficulty: u32,
+    /// The duration a challenge may be solved in after it is issued.
+    #[serde_as(as = "DurationSecondsWithFrac<String>")]
+    challenge_lifetime: chrono::Duration,
+    /// The hex-encoded key the challenges are signed with.
+    #[serde(skip)]
+    secret: String,
+}
+
+impl ProofOfWorkPolicy {
+    /// Creates a new instance of `ProofOfWorkPolicy`, signing challenges with a new random key.
+    #[inline]
+    pub fn new(difficulty: u32, challenge_lifetime: chrono::Duration) -> Self {
+        let mut secret = [0u8; KEY_BYTES];
+        rand::thread_rng().fill_bytes(&mut secret[..]);
+        Self {
+            difficulty,
+            challenge_lifetime,
+            secret: hex::encode(secret),
+        }
+    }
+
+    /// Returns the number of leading zero bits the hash of a solution must have.
+    #[inline]
+    pub fn difficulty(&self) -> u32 {
+        self.difficulty
+    }
+
+    /// Returns the duration a challenge may be solved in after it is issued.
+    #[inline]
+    pub fn challenge_lifetime(&self) -> chrono::Duration {
+        self.challenge_lifetime
+    }
+
+    ///
+    /// Issues a new challenge to the given participant at the given time, with the difficulty
+    /// of this policy, signed over the participant, the challenge, the difficulty and the expiry.
+    ///
+    /// If the policy does not hold its key, returns `ProofOfWorkKeyMissing`.
+    ///
+    #[inline]
+    pub fn issue(
+        &self,
+        participant: &Participant,
+        now: DateTime<Utc>,
+    ) -> Result<ProofOfWorkChallenge, CoordinatorError> {
+        if self.secret.is_empty() {
+            return Err(CoordinatorError::ProofOfWorkKeyMissing);
+        }
+
+        let mut challenge = [0u8; RANDOM_BYTES];
+        rand::thread_rng().fill_bytes(&mut challenge[..]);
+        let mut challenge = ProofOfWorkChallenge {
+            challenge: hex::encode(challenge),
+            difficulty: self.difficulty,
+            expires_at: now + self.challenge_lifetime,
+            signature: String::new(),
+        };
+        challenge.signature = self.sign(participant, &challenge);
+        Ok(challenge)
+    }
+
+    ///
+    /// Checks that the given challenge was issued by this policy to the given participant,
+    /// and has not expired at the given time.
+    ///
+    /// If the challenge was not signed with this key for the participant, or the policy
+    /// does not hold its key, returns `ProofOfWorkChallengeInvalid`. If the challenge
+    /// expired, returns `ProofOfWorkChallengeExpired`.
+    ///
+    #[inline]
+    pub fn verify(
+        &self,
+        participant: &Participant,
+        challenge: &ProofOfWorkChallenge,
+        now: DateTime<Utc>,
+    ) -> Result<(), CoordinatorError> {
+        // Compare the signatures in constant time, so the time taken does
+        // not reveal how much of a forged signature is correct.
+        let signature = self.sign(participant, challenge);
+        if self.secret.is_empty() || !bool::from(signature.as_bytes().ct_eq(challenge.signature.as_bytes())) {
+            return Err(CoordinatorError::ProofOfWorkChallengeInvalid);
+        }
+
+        if now >= challenge.expires_at {
+            return Err(CoordinatorError::ProofOfWorkChallengeExpired);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the signature of the given challenge for the given participant,
+    /// as a keyed BLAKE2b hash, in the same way invitation tokens are signed.
+    fn sign(&self, participant: &Participant, challenge: &ProofOfWorkChallenge) -> String {
+        let message = format!(
+            "{}.{}.{}.{}.{}",
+            self.secret,
+            participant,
+            challenge.challenge,
+            challenge.difficulty,
+            challenge.expires_at.to_rfc3339()
+        );
+        hex::encode(calculate_hash(message.as_bytes()))
+    }
+}
+
+impl fmt::Debug for ProofOfWorkPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // The key is omitted, so it is not written to the logs.
+        f.debug_struct("ProofOfWorkPolicy")
+            .field("difficulty", &self.difficulty)
+            .field("challenge_lifetime", &self.challenge_lifetime)
+            .field("secret", &"<redacted>")
+            .finish()
+    }
+}
+
+///
+/// A proof-of-work challenge issued to a contributor.
+///
+/// The contributor solves the challenge by finding a nonce for which the BLAKE2b hash
+/// of the challenge, their participant ID and the little-endian nonce has at least
+/// `difficulty` leading zero bits, and presents the challenge with the nonce to join the queue.
+///
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProofOfWorkChallenge {
+    /// The hex-encoded random challenge.
+    pub challenge: String,
+    /// The number of leading zero bits the hash of a solution must have.
+    pub difficulty: u32,
+    /// The time after which the challenge may no longer be solved.
+    pub expires_at: DateTime<Utc>,
+    /// The signature of the coordinator over the challenge and the participant it was issued to.
+    pub signature: String,
+}
+
+impl ProofOfWorkChallenge {
+    /// Returns the number of leading zero bits of the hash of the given solution.
+    #[inline]
+    pub fn work(&self, participant: &Participant, nonce: u64) -> u32 {
+        let mut input = Vec::with_capacity(self.challenge.len() + 64);
+        input.extend_from_slice(self.challenge.as_bytes());
+        input.extend_from_slice(participant.to_string().as_bytes());
+        input.extend_from_slice(&nonce.to_le_bytes());
+
+        let hash = calculate_hash(&input);
+        let zero_bytes = hash.iter().take_while(|byte| **byte == 0).count();
+        let zero_bits = hash.get(zero_bytes).map_or(0, |byte| byte.leading_zeros());
+        zero_bytes as u32 * 8 + zero_bits
+    }
+
+    /// Returns `true` if the given nonce solves the challenge for the given participant.
+    #[inline]
+    pub fn is_solved_by(&self, participant: &Participant, nonce: u64) -> bool {
+        self.work(participant, nonce) >= self.difficulty
+    }
+
+    /// Returns the first nonce which solves the challenge for the given participant.
+    #[inline]
+    pub fn solve(&self, participant: &Participant) -> u64 {
+        (0..u64::MAX)
+            .find(|nonce| self.is_solved_by(participant, *nonce))
+            .expect("Unable to solve the proof-of-work challenge")
+    }
+}
+
+/// The credentials a contributor presents to pass the admission gates when joining the queue.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AdmissionCredentials {
+    /// The invitation token issued by the operator, if one is required.
+    #[serde(default)]
+    pub invitation: Option<String>,
+    /// The proof-of-work challenge issued to the contributor, if one is required.
+    #[serde(default)]
+    pub challenge: Option<ProofOfWorkChallenge>,
+    /// The nonce solving the proof-of-work challenge issued to the contributor, if one is required.
+    #[serde(default)]
+    pub nonce: Option<u64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_invitation_verify() {
+        let policy = InvitationPolicy::new(b"operator secret");
+        let token = policy.issue().unwrap();
+        let id = policy.verify(&token).unwrap();
+        assert!(token.starts_with(&format!("{}.", id)));
+
+        // Each token has a new ID.
+        assert_ne!(id, policy.verify(&policy.issue().unwrap()).unwrap());
+
+        // Tokens which are malformed, tampered with, or signed with another key are rejected.
+        let other = InvitationPolicy::new(b"another secret");
+        let tampered = format!("00{}", &token[2..]);
+        let truncated = token[..token.len() - 1].to_string();
+        for token in &[
+            String::new(),
+            id.clone(),
+            format!("{}.", id),
+            tampered,
+            truncated,
+            other.issue().unwrap(),
+        ] {
+            assert!(
+                matches!(policy.verify(token), Err(CoordinatorError::InvitationInvalid)),
+                "{:?}",
+                token
+            );
+        }
+
+        // The key is not written to the logs.
+        assert!(!format!("{:?}", policy).contains(&hex::encode(b"operator secret")));
+    }
+
+    #[test]
+    fn test_proof_of_work() {
+        let policy = ProofOfWorkPolicy::new(8, chrono::Duration::minutes(5));
+        let participant = Participant::new_contributor("aleo1");
+        let now = Utc::now();
+        let challenge = policy.issue(&participant, now).unwrap();
+        assert_eq!(8, challenge.difficulty);
+        assert_eq!(now + chrono::Duration::minutes(5), challenge.expires_at);
+        policy.verify(&participant, &challenge, now).unwrap();
+
+        // The solution is bound to the participant it was solved for.
+        let nonce = challenge.solve(&participant);
+        assert!(challenge.is_solved_by(&participant, nonce));
+        let work = challenge.work(&participant, nonce);
+        assert!(work >= 8);
+
+        // The solution does not meet a higher difficulty than its work.
+        let harder = ProofOfWorkChallenge {
+            difficulty: work + 1,
+            ..challenge.clone()
+        };
+        assert!(!harder.is_solved_by(&participant, nonce));
+    }
+
+    #[test]
+    fn test_proof_of_work_challenge_verify() {
+        let policy = ProofOfWorkPolicy::new(8, chrono::Duration::minutes(5));
+        let participant = Participant::new_contributor("aleo1");
+        let now = Utc::now();
+        let challenge = policy.issue(&participant, now).unwrap();
+
+        // Each challenge is new, and survives serialization.
+        assert_ne!(challenge, policy.issue(&participant, now).unwrap());
+        let deserialized = serde_json::from_str(&serde_json::to_string(&challenge).unwrap()).unwrap();
+        policy.verify(&participant, &deserialized, now).unwrap();
+
+        // Challenges which are tampered with, issued to another participant, or signed with another key are rejected.
+        let other = Participant::new_contributor("aleo2");
+        let easier = ProofOfWorkChallenge {
+            difficulty: 0,
+            ..challenge.clone()
+        };
+        let extended = ProofOfWorkChallenge {
+            expires_at: challenge.expires_at + chrono::Duration::minutes(5),
+            ..challenge.clone()
+        };
+        let forged = ProofOfWorkPolicy::new(8, chrono::Duration::minutes(5))
+            .issue(&participant, now)
+            .unwrap();
+        for (participant, challenge) in &[
+            (&other, &challenge),
+            (&participant, &easier),
+            (&participant, &extended),
+            (&participant, &forged),
+        ] {
+            assert!(
+                matches!(
+                    policy.verify(participant, challenge, now),
+                    Err(CoordinatorError::ProofOfWorkChallengeInvalid)
+                ),
+                "{:?}",
+                challenge
+            );
+        }
+
+        // Expired challenges are rejected.
+        assert!(matches!(
+            policy.verify(&participant, &challenge, challenge.expires_at),
+            Err(CoordinatorError::ProofOfWorkChallengeExpired)
+        ));
+
+        // The key is not written to the logs.
+        assert!(!format!("{:?}", policy).contains(&policy.secret));
+    }
+
+    #[test]
+    fn test_invitation_from_key_file() {
+        let path = std::env::temp_dir().join("test_invitation_from_key_file");
+        fs::write(&path, format!("{}\n", hex::encode(b"operator secret"))).unwrap();
+        let policy = InvitationPolicy::from_key_file(&path).unwrap();
+        let token = InvitationPolicy::new(b"operator secret").issue().unwrap();
+        assert!(policy.verify(&token).is_ok());
+
+        // A file without a key is rejected.
+        fs::write(&path, "\n").unwrap();
+        assert!(matches!(
+            InvitationPolicy::from_key_file(&path),
+            Err(CoordinatorError::InvitationKeyMissing)
+        ));
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_admission_policy_serde() {
+        let policy = AdmissionPolicy::new(
+            Some(InvitationPolicy::new(b"operator secret")),
+            Some(ProofOfWorkPolicy::new(20, chrono::Duration::minutes(5))),
+        );
+        let serialized = serde_json::to_string(&policy).unwrap();
+        let deserialized: AdmissionPolicy = serde_json::from_str(&serialized).unwrap();
+        let proof_of_work = deserialized.proof_of_work().unwrap();
+        assert_eq!(20, proof_of_work.difficulty());
+        assert_eq!(chrono::Duration::minutes(5), proof_of_work.challenge_lifetime());
+
+        // The key of the proof-of-work challenges is not serialized, and the policy without it rejects every challenge.
+        let participant = Participant::new_contributor("aleo1");
+        assert!(!serialized.contains(&policy.proof_of_work().unwrap().secret));
+        assert!(matches!(
+            proof_of_work.issue(&participant, Utc::now()),
+            Err(CoordinatorError::ProofOfWorkKeyMissing)
+        ));
+        let challenge = policy.proof_of_work().unwrap().issue(&participant, Utc::now()).unwrap();
+        assert!(matches!(
+            proof_of_work.verify(&participant, &challenge, Utc::now()),
+            Err(CoordinatorError::ProofOfWorkChallengeInvalid)
+        ));
+
+        // The key of the invitation tokens is not serialized, and the policy without it rejects every token.
+        assert!(!serialized.contains(&hex::encode(b"operator secret")));
+        let invitation = deserialized.invitation().unwrap();
+        assert!(!invitation.has_key());
+        assert!(matches!(
+            invitation.issue(),
+            Err(CoordinatorError::InvitationKeyMissing)
+        ));
+        let token = policy.invitation().unwrap().issue().unwrap();
+        assert!(matches!(
+            invitation.verify(&token),
+            Err(CoordinatorError::InvitationInvalid)
+        ));
+
+        // Credentials may omit the fields of the gates which are not required.
+        let credentials: AdmissionCredentials = serde_json::from_str("{}").unwrap();
+        assert_eq!(AdmissionCredentials::default(), credentials);
+    }
+}
@@ -0,0 +1,512 @@
+use crate::CoordinatorError;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+/// The machine-readable codes of the errors returned by the coordinator API.
+///
+/// These codes are stable, so clients may switch on them.
+pub const API_ERROR_CODES: [&str; 29] = [
+    "admission_required",
+    "already_completed",
+    "already_in_round",
+    "ceremony_paused",
+    "chunk_id_invalid",
+    "chunk_lock_held",
+    "chunk_lock_limit",
+    "chunk_not_locked",
+    "client_upgrade_required",
+    "contribution_id_invalid",
+    "hardware_requirement",
+    "internal_error",
+    "malformed_request",
+    "no_remaining_tasks",
+    "not_found",
+    "participant_already_added",
+    "participant_banned",
+    "participant_dropped",
+    "practice_disabled",
+    "practice_expired",
+    "practice_not_requested",
+    "queue_not_ready",
+    "read_only",
+    "round_not_complete",
+    "round_not_ready",
+    "signature_invalid",
+    "unauthorized_contributor",
+    "upload_invalid",
+    "verification_failed",
+];
+
+///
+/// The body of an error response from the coordinator API.
+///
+/// The `code` is one of `API_ERROR_CODES`, and the `details` hold the
+/// fields of the error, if any, such as the chunk ID it refers to.
+///
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ApiError {
+    pub code: String,
+    pub message: String,
+    #[serde(default)]
+    pub details: Value,
+}
+
+impl ApiError {
+    ///
+    /// Returns the API error in the given response body, if it holds one.
+    ///
+    #[inline]
+    pub fn from_response(body: &[u8]) -> Option<Self> {
+        serde_json::from_slice(body).ok()
+    }
+}
+
+impl From<&CoordinatorError> for ApiError {
+    fn from(error: &CoordinatorError) -> Self {
+        Self {
+            code: error.code().to_string(),
+            message: format!("{:?}", error),
+            details: error.details(),
+        }
+    }
+}
+
+impl CoordinatorError {
+    ///
+    /// Returns the HTTP status code of the coordinator API response for this error.
+    ///
+    #[inline]
+    pub fn status(&self) -> u16 {
+        match self.code() {
+            "chunk_id_invalid" | "contribution_id_invalid" | "malformed_request" | "upload_invalid" => 400,
+            "signature_invalid" => 401,
+            "admission_required"
+            | "chunk_not_locked"
+            | "participant_banned"
+            | "participant_dropped"
+            | "unauthorized_contributor" => 403,
+            "not_found" | "practice_disabled" => 404,
+            "read_only" => 405,
+            "already_completed"
+            | "already_in_round"
+            | "chunk_lock_held"
+            | "chunk_lock_limit"
+            | "no_remaining_tasks"
+            | "participant_already_added"
+            | "practice_not_requested"
+            | "queue_not_ready"
+            | "round_not_complete"
+            | "round_not_ready" => 409,
+            "practice_expired" => 410,
+            "hardware_requirement" | "verification_failed" => 422,
+            "client_upgrade_required" => 426,
+            "ceremony_paused" => 503,
+            _ => 500,
+        }
+    }
+
+    ///
+    /// Returns the machine-readable code of the coordinator API response for this error.
+    ///
+    /// Errors caused by the request of a participant have a specific code, and the remaining
+    /// errors are failures of the coordinator, which have the code `internal_error`.
+    ///
+    #[inline]
+    pub fn code(&self) -> &'static str {
+        match self {
+            CoordinatorError::CeremonyPaused => "ceremony_paused",
+
+            CoordinatorError::ChunkIdInvalid
+            | CoordinatorError::ChunkIdMismatch
+            | CoordinatorError::ChunkIdOutOfRange { .. } => "chunk_id_invalid",
+
+            CoordinatorError::ContributionIdOutOfRange { .. } => "contribution_id_invalid",
+
+            CoordinatorError::ClientVersionInvalid(_)
+            | CoordinatorError::ContributionLocatorIncorrect
+            | CoordinatorError::Hex(_)
+            | CoordinatorError::Integer(_)
+            | CoordinatorError::JsonError(_)
+            | CoordinatorError::StorageLocatorFormatIncorrect
+            | CoordinatorError::TryFromSliceError(_) => "malformed_request",
+
+            CoordinatorError::ChallengeHashSizeInvalid
+            | CoordinatorError::ContributionFileSizeMismatch
+            | CoordinatorError::ContributionSignatureFileSizeMismatch
+            | CoordinatorError::ContributionSignatureSizeMismatch
+            | CoordinatorError::NextChallengeHashSizeInvalid
+            | CoordinatorError::PracticeResponseSizeMismatch
+            | CoordinatorError::ResponseHashSizeInvalid => "upload_invalid",
+
+            CoordinatorError::ContributorSignatureInvalid
+            | CoordinatorError::JustificationInvalid
+            | CoordinatorError::VerifierSignatureInvalid => "signature_invalid",
+
+            CoordinatorError::ChunkLockTokenMismatch
+            | CoordinatorError::ChunkNotLockedOrByWrongParticipant
+            | CoordinatorError::ParticipantDidntLockChunkId => "chunk_not_locked",
+
+            CoordinatorError::InvitationConsumed
+            | CoordinatorError::InvitationInvalid
+            | CoordinatorError::InvitationMissing
+            | CoordinatorError::ProofOfWorkChallengeConsumed
+            | CoordinatorError::ProofOfWorkChallengeExpired
+            | CoordinatorError::ProofOfWorkChallengeInvalid
+            | CoordinatorError::ProofOfWorkChallengeMissing
+            | CoordinatorError::ProofOfWorkInsufficient
+            | CoordinatorError::ProofOfWorkMissing => "admission_required",
+
+            CoordinatorError::ParticipantAlreadyBanned | CoordinatorError::ParticipantBanned => "participant_banned",
+
+            CoordinatorError::ParticipantAlreadyDropped | CoordinatorError::ParticipantWasDropped => {
+                "participant_dropped"
+            }
+
+            CoordinatorError::ExpectedContributor
+            | CoordinatorError::ExpectedVerifier
+            | CoordinatorError::ParticipantUnauthorized
+            | CoordinatorError::ParticipantUnauthorizedForChunkId { .. }
+            | CoordinatorError::UnauthorizedChunkContributor
+            | CoordinatorError::UnauthorizedChunkVerifier => "unauthorized_contributor",
+
+            CoordinatorError::ChunkMissing
+            | CoordinatorError::ContributionLocatorMissing
+            | CoordinatorError::ContributionMissing
+            | CoordinatorError::ParticipantMissing
+            | CoordinatorError::ParticipantNotFound(_)
+            | CoordinatorError::RoundDoesNotExist
+            | CoordinatorError::RoundFileMissing => "not_found",
+
+            CoordinatorError::PracticeModeDisabled => "practice_disabled",
+
+            CoordinatorError::ChunkAlreadyComplete
+            | CoordinatorError::ChunkAlreadyVerified
+            | CoordinatorError::ContributionAlreadyVerified
+            | CoordinatorError::ContributionsComplete
+            | CoordinatorError::ContributorAlreadyContributed
+            | CoordinatorError::ParticipantAlreadyFinished
+            | CoordinatorError::ParticipantAlreadyFinishedChunk { .. }
+            | CoordinatorError::ParticipantAlreadyFinishedTask(_) => "already_completed",
+
+            CoordinatorError::ParticipantAlreadyStarted
+            | CoordinatorError::ParticipantInCurrentRoundCannotJoinQueue => "already_in_round",
+
+            CoordinatorError::ChunkLockAlreadyAcquired | CoordinatorError::ParticipantAlreadyWorkingOnChunk { .. } => {
+                "chunk_lock_held"
+            }
+
+            CoordinatorError::ChunkLockLimitReached
+            | CoordinatorError::ParticipantAlreadyHasLockedChunk
+            | CoordinatorError::ParticipantAlreadyHasLockedChunks
+            | CoordinatorError::ParticipantHasLockedMaximumChunks => "chunk_lock_limit",
+
+            CoordinatorError::ParticipantHasNoRemainingTasks => "no_remaining_tasks",
+
+            CoordinatorError::ParticipantAlreadyAdded | CoordinatorError::ParticipantAlreadyPrecommitted => {
+                "participant_already_added"
+            }
+
+            CoordinatorError::PracticeChallengeNotRequested => "practice_not_requested",
+
+            CoordinatorError::QueueIsEmpty | CoordinatorError::QueueWaitTimeIncomplete => "queue_not_ready",
+
+            CoordinatorError::CoordinatorReadOnly { .. } => "read_only",
+
+            CoordinatorError::CurrentRoundNotFinished
+            | CoordinatorError::RoundNotAggregated
+            | CoordinatorError::RoundNotComplete => "round_not_complete",
+
+            CoordinatorError::CurrentRoundAggregated
+            | CoordinatorError::CurrentRoundAggregating
+            | CoordinatorError::CurrentRoundFinished
+            | CoordinatorError::ParticipantHasNotStarted
+            | CoordinatorError::ParticipantNotReady
+            | CoordinatorError::RoundNotReady => "round_not_ready",
+
+            CoordinatorError::PracticeChallengeExpired => "practice_expired",
+
+            CoordinatorError::ParticipantHardwareBelowMinimum { .. }
+            | CoordinatorError::ParticipantHardwareUndeclared => "hardware_requirement",
+
+            CoordinatorError::ClientVersionUnsupported { .. } => "client_upgrade_required",
+
+            CoordinatorError::ContributionHashMismatch
+            | CoordinatorError::TranscriptForkDetected
+            | CoordinatorError::VerificationFailed => "verification_failed",
+
+            CoordinatorError::AdmissionPolicyMissing
+            | CoordinatorError::AggregateContributionFileSizeMismatch
+            | CoordinatorError::Cancelled
+            | CoordinatorError::ChunkAlreadyContributed
+            | CoordinatorError::ChunkCannotLockZeroContributions { .. }
+            | CoordinatorError::ChunkIdAlreadyAdded
+            | CoordinatorError::ChunkIdMissing
+            | CoordinatorError::ChunkMissingVerification
+            | CoordinatorError::ChunkReinitializationUnsupported
+            | CoordinatorError::CompressedContributionHashingUnsupported
+            | CoordinatorError::ComputationFailed
+            | CoordinatorError::ContributionAlreadyAssignedVerifiedLocator
+            | CoordinatorError::ContributionAlreadyAssignedVerifier
+            | CoordinatorError::ContributionFailed
+            | CoordinatorError::ContributionFileSignatureLocatorAlreadyExists
+            | CoordinatorError::ContributionIdIsNonzero
+            | CoordinatorError::ContributionIdMismatch
+            | CoordinatorError::ContributionIdMustBeNonzero
+            | CoordinatorError::ContributionLocatorAlreadyExists
+            | CoordinatorError::ContributionMissingVerification
+            | CoordinatorError::ContributionMissingVerifiedLocator
+            | CoordinatorError::ContributionMissingVerifier
+            | CoordinatorError::ContributionShouldNotExist
+            | CoordinatorError::ContributorPendingTasksCannotBeEmpty(_)
+            | CoordinatorError::ContributorsMissing
+            | CoordinatorError::CoordinatorContributorMissing
+            | CoordinatorError::CoordinatorStateNotInitialized
+            | CoordinatorError::CurrentRoundNotAggregated
+            | CoordinatorError::DropParticipantFailed
+            | CoordinatorError::Error(_)
+            | CoordinatorError::InitializationFailed
+            | CoordinatorError::InitializationTranscriptsDiffer
+            | CoordinatorError::InvitationKeyMissing
+            | CoordinatorError::IOError(_)
+            | CoordinatorError::LocatorDeserializationFailed
+            | CoordinatorError::LocatorFileAlreadyExists
+            | CoordinatorError::LocatorFileAlreadyExistsAndOpen
+            | CoordinatorError::LocatorFileAlreadyOpen
+            | CoordinatorError::LocatorFileMissing
+            | CoordinatorError::LocatorFileNotOpen
+            | CoordinatorError::LocatorFileShouldBeOpen
+            | CoordinatorError::LocatorSerializationFailed
+            | CoordinatorError::NextChallengeHashAlreadyExists
+            | CoordinatorError::NextChallengeHashMissing
+            | CoordinatorError::NextRoundAlreadyInPrecommit
+            | CoordinatorError::NextRoundShouldBeEmpty
+            | CoordinatorError::NumberOfChunksInvalid
+            | CoordinatorError::NumberOfContributionsDiffer
+            | CoordinatorError::ParticipantAlreadyAddedChunk
+            | CoordinatorError::ParticipantDidNotDoWork
+            | CoordinatorError::ParticipantHasAssignedTasks
+            | CoordinatorError::ParticipantHasRemainingTasks
+            | CoordinatorError::ParticipantLockedChunkWithManyContributions
+            | CoordinatorError::ParticipantMissingDisposingTask
+            | CoordinatorError::ParticipantMissingPendingTask { .. }
+            | CoordinatorError::ParticipantRoundHeightInvalid
+            | CoordinatorError::ParticipantRoundHeightMissing
+            | CoordinatorError::ParticipantShouldHavePendingTasks
+            | CoordinatorError::ParticipantShouldNotBeFinished
+            | CoordinatorError::ParticipantStillHasLock
+            | CoordinatorError::ParticipantStillHasLocks
+            | CoordinatorError::ParticipantStillHasTaskAsAssigned
+            | CoordinatorError::ParticipantStillHasTaskAsPending
+            | CoordinatorError::PendingTasksMustContainResponseTask { .. }
+            | CoordinatorError::Phase1Setup(_)
+            | CoordinatorError::PreviousContributionMissing { .. }
+            | CoordinatorError::ProofOfWorkKeyMissing
+            | CoordinatorError::RequiredVerificationsInvalid { .. }
+            | CoordinatorError::RoundAggregationFailed
+            | CoordinatorError::RoundAggregationModeUnsupported
+            | CoordinatorError::RoundAlreadyAggregated
+            | CoordinatorError::RoundAlreadyInitialized
+            | CoordinatorError::RoundCommitFailedOrCorrupted
+            | CoordinatorError::RoundContributorMissing
+            | CoordinatorError::RoundContributorsMissing
+            | CoordinatorError::RoundContributorsNotUnique
+            | CoordinatorError::RoundDirectoryMissing
+            | CoordinatorError::RoundFileSizeMismatch
+            | CoordinatorError::RoundFormatVersionUnsupported { .. }
+            | CoordinatorError::RoundHeightInconsistent
+            | CoordinatorError::RoundHeightIsZero
+            | CoordinatorError::RoundHeightMismatch
+            | CoordinatorError::RoundHeightNotSet
+            | CoordinatorError::RoundLocatorAlreadyExists
+            | CoordinatorError::RoundLocatorMissing
+            | CoordinatorError::RoundNumberOfContributorsUnauthorized
+            | CoordinatorError::RoundNumberOfVerifiersUnauthorized
+            | CoordinatorError::RoundShouldNotExist
+            | CoordinatorError::RoundStateMissing
+            | CoordinatorError::RoundUpdateCorruptedStateOfContributors
+            | CoordinatorError::RoundUpdateCorruptedStateOfVerifiers
+            | CoordinatorError::RoundVerifiersMissing
+            | CoordinatorError::RoundVerifiersNotUnique
+            | CoordinatorError::SetupAlreadyAdded
+            | CoordinatorError::SetupInvalid
+            | CoordinatorError::SetupStorageShared
+            | CoordinatorError::SignatureSchemeIsInsecure
+            | CoordinatorError::StateLockFailed
+            | CoordinatorError::StorageCopyFailed
+            | CoordinatorError::StorageDecryptionFailed
+            | CoordinatorError::StorageEncryptionFailed
+            | CoordinatorError::StorageEncryptionKeyInvalid
+            | CoordinatorError::StorageEncryptionKeyMissing
+            | CoordinatorError::StorageFailed
+            | CoordinatorError::StorageInitializationFailed
+            | CoordinatorError::StorageLocatorAlreadyExists
+            | CoordinatorError::StorageLocatorAlreadyExistsAndOpen
+            | CoordinatorError::StorageLocatorMissing
+            | CoordinatorError::StorageLocatorNotOpen
+            | CoordinatorError::StorageLockFailed
+            | CoordinatorError::StorageMappedSizeMismatch
+            | CoordinatorError::StorageObjectStreamConsumed
+            | CoordinatorError::StorageObjectStreamTooShort
+            | CoordinatorError::StorageReaderFailed
+            | CoordinatorError::StorageSizeLookupFailed
+            | CoordinatorError::StorageUpdateFailed
+            | CoordinatorError::TaskInitializationFailed(_)
+            | CoordinatorError::VerificationOnContributionIdZero
+            | CoordinatorError::VerifierIsSoleVerifier
+            | CoordinatorError::VerifierMissing
+            | CoordinatorError::VerifiersMissing => "internal_error",
+        }
+    }
+
+    ///
+    /// Returns the fields of this error, as the details of the coordinator API response.
+    ///
+    #[inline]
+    fn details(&self) -> Value {
+        match self {
+            CoordinatorError::ChunkIdOutOfRange { chunk_id, num_chunks } => {
+                json!({ "chunkId": chunk_id, "numChunks": num_chunks })
+            }
+            CoordinatorError::ContributionIdOutOfRange {
+                chunk_id,
+                contribution_id,
+                num_contributions,
+            } => json!({
+                "chunkId": chunk_id,
+                "contributionId": contribution_id,
+                "numContributions": num_contributions,
+            }),
+            CoordinatorError::ChunkCannotLockZeroContributions { chunk_id }
+            | CoordinatorError::ParticipantAlreadyFinishedChunk { chunk_id }
+            | CoordinatorError::ParticipantAlreadyWorkingOnChunk { chunk_id }
+            | CoordinatorError::ParticipantUnauthorizedForChunkId { chunk_id } => json!({ "chunkId": chunk_id }),
+            CoordinatorError::ParticipantHardwareBelowMinimum {
+                requirement,
+                declared,
+                minimum,
+            } => json!({ "requirement": requirement, "declared": declared, "minimum": minimum }),
+            CoordinatorError::ParticipantNotFound(participant) => json!({ "participant": participant }),
+            CoordinatorError::CoordinatorReadOnly { primary_url } => json!({ "primaryUrl": primary_url }),
+            CoordinatorError::ClientVersionUnsupported {
+                client,
+                min_version,
+                max_version,
+            } => json!({
+                "client": client,
+                "minVersion": min_version,
+                "maxVersion": max_version,
+                "upgrade": match max_version {
+                    Some(max_version) => format!(
+                        "Install version {} to {} of {} to participate",
+                        min_version,
+                        max_version,
+                        client.name()
+                    ),
+                    None => format!("Install version {} or later of {} to participate", min_version, client.name()),
+                },
+            }),
+            _ => Value::Null,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::objects::{ClientVersion, Participant, Version};
+
+    #[test]
+    fn test_api_error_codes() {
+        // Every code is documented, and maps to a status.
+        let errors = vec![
+            CoordinatorError::ChunkIdOutOfRange {
+                chunk_id: 4,
+                num_chunks: 4,
+            },
+            CoordinatorError::ContributionIdOutOfRange {
+                chunk_id: 0,
+                contribution_id: 3,
+                num_contributions: 3,
+            },
+            CoordinatorError::JsonError(serde_json::from_str::<u64>("").unwrap_err()),
+            CoordinatorError::ContributionFileSizeMismatch,
+            CoordinatorError::ContributorSignatureInvalid,
+            CoordinatorError::ChunkNotLockedOrByWrongParticipant,
+            CoordinatorError::InvitationConsumed,
+            CoordinatorError::ParticipantBanned,
+            CoordinatorError::ParticipantWasDropped,
+            CoordinatorError::UnauthorizedChunkContributor,
+            CoordinatorError::RoundFileMissing,
+            CoordinatorError::PracticeModeDisabled,
+            CoordinatorError::ContributorAlreadyContributed,
+            CoordinatorError::ParticipantInCurrentRoundCannotJoinQueue,
+            CoordinatorError::ChunkLockAlreadyAcquired,
+            CoordinatorError::ChunkLockLimitReached,
+            CoordinatorError::ParticipantHasNoRemainingTasks,
+            CoordinatorError::ParticipantAlreadyAdded,
+            CoordinatorError::PracticeChallengeNotRequested,
+            CoordinatorError::QueueWaitTimeIncomplete,
+            CoordinatorError::CoordinatorReadOnly {
+                primary_url: "http://localhost:9000".to_string(),
+            },
+            CoordinatorError::RoundNotComplete,
+            CoordinatorError::RoundNotReady,
+            CoordinatorError::PracticeChallengeExpired,
+            CoordinatorError::ParticipantHardwareUndeclared,
+            CoordinatorError::ClientVersionUnsupported {
+                client: ClientVersion::new("setup1-verifier", Version::new(0, 1, 0)),
+                min_version: Version::new(0, 2, 0),
+                max_version: None,
+            },
+            CoordinatorError::VerificationFailed,
+            CoordinatorError::StorageFailed,
+            CoordinatorError::CeremonyPaused,
+        ];
+        let mut codes: Vec<&str> = errors.iter().map(CoordinatorError::code).collect();
+        codes.sort_unstable();
+        assert_eq!(API_ERROR_CODES.to_vec(), codes);
+
+        // Only internal errors are returned with a 500 status.
+        for error in &errors {
+            assert_eq!(error.code() == "internal_error", error.status() == 500, "{:?}", error);
+        }
+    }
+
+    #[test]
+    fn test_api_error_response() {
+        let error = CoordinatorError::ParticipantNotFound(Participant::new_contributor("aleo1"));
+        let api_error = ApiError::from(&error);
+        assert_eq!("not_found", api_error.code);
+        assert_eq!(404, error.status());
+        assert_eq!(json!({ "participant": "aleo1.contributor" }), api_error.details);
+
+        // The error round trips through a response body.
+        let body = serde_json::to_vec(&api_error).unwrap();
+        assert_eq!(Some(api_error), ApiError::from_response(&body));
+
+        // Errors without fields have no details, and bodies without an error are not parsed.
+        let api_error = ApiError::from(&CoordinatorError::ChunkLockAlreadyAcquired);
+        assert_eq!(Value::Null, api_error.details);
+        assert_eq!(None, ApiError::from_response(b"Chunk lock failed"));
+    }
+
+    #[test]
+    fn test_api_error_client_upgrade() {
+        let error = CoordinatorError::ClientVersionUnsupported {
+            client: ClientVersion::new("setup1-verifier", Version::new(0, 1, 0)),
+            min_version: Version::new(0, 2, 0),
+            max_version: None,
+        };
+        let api_error = ApiError::from(&error);
+        assert_eq!("client_upgrade_required", api_error.code);
+        assert_eq!(426, error.status());
+        assert_eq!("setup1-verifier/0.1.0", api_error.details["client"]);
+        assert_eq!("0.2.0", api_error.details["minVersion"]);
+        assert_eq!(Value::Null, api_error.details["maxVersion"]);
+        assert_eq!(
+            "Install version 0.2.0 or later of setup1-verifier to participate",
+            api_error.details["upgrade"]
+        );
+    }
+}
@@ -4,6 +4,7 @@ use crate::{
     CoordinatorError,
 };
 
+use chrono::{DateTime, Utc};
 use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 use serde_aux::prelude::*;
@@ -120,6 +121,15 @@ impl Chunk {
         }
     }
 
+    /// Returns a mutable reference to a contribution given a contribution ID.
+    #[inline]
+    pub(crate) fn get_contribution_mut(&mut self, contribution_id: u64) -> Result<&mut Contribution, CoordinatorError> {
+        match self.contributions.get_mut(&contribution_id) {
+            Some(contribution) => Ok(contribution),
+            _ => Err(CoordinatorError::ContributionMissing),
+        }
+    }
+
     /// Returns a reference to a list of contributions in this chunk.
     ///
     /// **Note**: contribution files can be located anywhere on disk,
@@ -335,8 +345,10 @@ impl Chunk {
 
         // If the participant is a verifier, check that they have not already contributed to this chunk before.
         if participant.is_verifier() {
-            // Check that the current contribution in this chunk has NOT been verified.
-            if self.current_contribution()?.is_verified() {
+            // Check that the current contribution in this chunk has NOT been verified,
+            // in total or by this verifier.
+            let contribution = self.current_contribution()?;
+            if contribution.is_verified() || contribution.is_verified_by(&participant) {
                 return Err(CoordinatorError::ChunkAlreadyVerified);
             }
         }
@@ -396,7 +408,8 @@ impl Chunk {
     }
 
     ///
-    /// Updates the contribution corresponding to the given contribution ID as verified.
+    /// Records the verification of the contribution corresponding to the given contribution ID,
+    /// which updates it as verified once the required number of verifications is reached.
     ///
     /// This function is intended to be called by an authorized verifier
     /// holding a lock on the chunk.
@@ -404,7 +417,7 @@ impl Chunk {
     /// The underlying function checks that the contribution has a verifier assigned to it.
     ///
     #[tracing::instrument(
-        skip(self, verifier, contribution_id, verified_locator, verified_signature_locator),
+        skip(self, verifier, contribution_id, verified_locator, verified_signature_locator, verified_at),
         fields(contribution = contribution_id)
     )]
     pub fn verify_contribution(
@@ -413,6 +426,8 @@ impl Chunk {
         verifier: Participant,
         verified_locator: LocatorPath,
         verified_signature_locator: LocatorPath,
+        verified_at: DateTime<Utc>,
+        required_verifications: u64,
     ) -> Result<(), CoordinatorError> {
         // Check that the participant is a verifier.
         if !verifier.is_verifier() {
@@ -437,10 +452,10 @@ impl Chunk {
         match contribution.is_verified() {
             // Case 1 - Check that the contribution is not verified yet.
             true => Err(CoordinatorError::ContributionAlreadyVerified),
-            // Case 2 - If the contribution is not verified, attempt to set it to verified.
+            // Case 2 - If the contribution is not verified, attempt to record the verification.
             false => {
-                // Attempt set the contribution as verified.
-                contribution.set_verified(&verifier)?;
+                // Attempt to record the verification of the contribution.
+                contribution.add_verification(&verifier, verified_at, required_verifications)?;
 
                 // Release the lock on this chunk from the verifier.
                 self.set_lock_holder(None);
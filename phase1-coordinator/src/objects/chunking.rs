@@ -0,0 +1,205 @@
+use crate::environment::Environment;
+
+use chrono::{DateTime, Utc};
+use phase1::helpers::CurveKind;
+use serde::{Deserialize, Serialize};
+use serde_with::DurationSecondsWithFrac;
+use setup_utils::UseCompression;
+use zexe_algebra::{Bls12_377, BW6_761};
+
+/// The maximum number of verification timings held in the coordinator state, which bounds
+/// the state size over a long ceremony. The oldest timings are dropped first.
+pub const MAXIMUM_VERIFICATION_TIMINGS: usize = 10_000;
+
+/// The timing of a verification of a contribution to a chunk,
+/// as collected to recommend the chunk size of future ceremonies.
+#[serde_with::serde_as]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VerificationTiming {
+    /// The height of the round of the verified contribution.
+    pub round_height: u64,
+    /// The chunk ID of the verified contribution.
+    pub chunk_id: u64,
+    /// The number of powers of tau in G1 in the chunk, which the chunk size is measured in.
+    pub number_of_powers: u64,
+    /// The number of group elements in the chunk, across all of its vectors.
+    pub number_of_elements: u64,
+    /// The duration spent checking the contribution.
+    #[serde_as(as = "DurationSecondsWithFrac<String>")]
+    pub verification_duration: chrono::Duration,
+    /// The duration spent downloading the contribution and uploading the next challenge.
+    #[serde_as(as = "DurationSecondsWithFrac<String>")]
+    pub transfer_duration: chrono::Duration,
+    /// The hardware the verifier reported running on, if any.
+    pub hardware: Option<String>,
+    /// The time the verification completed.
+    pub verified_at: DateTime<Utc>,
+}
+
+impl VerificationTiming {
+    ///
+    /// Creates a new instance of `VerificationTiming`, with the number of powers
+    /// and elements of the given chunk ID under the parameters of the environment.
+    ///
+    #[inline]
+    pub fn new(
+        environment: &Environment,
+        round_height: u64,
+        chunk_id: u64,
+        verification_duration: chrono::Duration,
+        transfer_duration: chrono::Duration,
+        hardware: Option<String>,
+        verified_at: DateTime<Utc>,
+    ) -> Self {
+        let settings = environment.parameters();
+        let layout = match settings.curve() {
+            CurveKind::Bls12_377 => {
+                phase1_chunked_parameters!(Bls12_377, settings, chunk_id).layout(UseCompression::No)
+            }
+            CurveKind::BW6 => phase1_chunked_parameters!(BW6_761, settings, chunk_id).layout(UseCompression::No),
+        };
+        let number_of_elements = layout.tau_g1.count
+            + layout.tau_g2.count
+            + layout.alpha_g1.count
+            + layout.beta_g1.count
+            + layout.beta_g2.count;
+
+        Self {
+            round_height,
+            chunk_id,
+            number_of_powers: layout.tau_g1.count as u64,
+            number_of_elements: number_of_elements as u64,
+            verification_duration,
+            transfer_duration,
+            hardware,
+            verified_at,
+        }
+    }
+}
+
+///
+/// The estimated cost of verifying a chunk, and the chunk size recommended
+/// for a target duration of each verification task, from the collected timings.
+///
+/// The recommendation is informational, as the chunk size cannot change
+/// during a ceremony, and is meant for the configuration of the next ceremony.
+///
+#[serde_with::serde_as]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChunkingReport {
+    /// The number of verification timings the estimates are based on.
+    pub number_of_timings: usize,
+    /// The estimated seconds spent checking each element of a chunk.
+    pub verification_seconds_per_element: f64,
+    /// The estimated seconds spent transferring each element of a chunk.
+    pub transfer_seconds_per_element: f64,
+    /// The average number of elements in a chunk for each of its powers of tau in G1.
+    pub elements_per_power: f64,
+    /// The chunk size of the current ceremony.
+    pub current_chunk_size: u64,
+    /// The duration each verification task is targeted to take.
+    #[serde_as(as = "DurationSecondsWithFrac<String>")]
+    pub target_task_duration: chrono::Duration,
+    /// The chunk size for which a verification task is estimated to take the target duration.
+    pub recommended_chunk_size: u64,
+}
+
+impl ChunkingReport {
+    ///
+    /// Estimates the cost of verification from the given timings, and recommends
+    /// the chunk size for which a verification task takes the target duration.
+    ///
+    /// The costs are the total durations divided by the total number of elements,
+    /// which weighs each timing by the size of its chunk. The recommended chunk size
+    /// is at least 1.
+    ///
+    /// Returns `None` if there are no timings with elements to estimate from.
+    ///
+    pub fn new(
+        timings: &[VerificationTiming],
+        current_chunk_size: u64,
+        target_task_duration: chrono::Duration,
+    ) -> Option<Self> {
+        let seconds = |duration: chrono::Duration| duration.num_milliseconds() as f64 / 1000.0;
+
+        let number_of_powers: u64 = timings.iter().map(|timing| timing.number_of_powers).sum();
+        let number_of_elements: u64 = timings.iter().map(|timing| timing.number_of_elements).sum();
+        if number_of_powers == 0 || number_of_elements == 0 {
+            return None;
+        }
+
+        let verification_seconds: f64 = timings.iter().map(|t| seconds(t.verification_duration)).sum();
+        let transfer_seconds: f64 = timings.iter().map(|t| seconds(t.transfer_duration)).sum();
+
+        // Determine the number of powers a task can hold within the target duration.
+        let total_seconds = verification_seconds + transfer_seconds;
+        let recommended_chunk_size = match total_seconds > 0.0 {
+            true => {
+                let powers = seconds(target_task_duration) * number_of_powers as f64 / total_seconds;
+                powers.floor().max(1.0) as u64
+            }
+            false => current_chunk_size,
+        };
+
+        Some(Self {
+            number_of_timings: timings.len(),
+            verification_seconds_per_element: verification_seconds / number_of_elements as f64,
+            transfer_seconds_per_element: transfer_seconds / number_of_elements as f64,
+            elements_per_power: number_of_elements as f64 / number_of_powers as f64,
+            current_chunk_size,
+            target_task_duration,
+            recommended_chunk_size,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timing(number_of_powers: u64, verification_seconds: i64, transfer_seconds: i64) -> VerificationTiming {
+        VerificationTiming {
+            round_height: 1,
+            chunk_id: 0,
+            number_of_powers,
+            number_of_elements: 2 * number_of_powers,
+            verification_duration: chrono::Duration::seconds(verification_seconds),
+            transfer_duration: chrono::Duration::seconds(transfer_seconds),
+            hardware: Some("8 cores".to_string()),
+            verified_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_chunking_report() {
+        // 300 elements took 75 seconds to check and 45 seconds to transfer.
+        let timings = vec![timing(100, 50, 30), timing(50, 25, 15)];
+        let report = ChunkingReport::new(&timings, 256, chrono::Duration::minutes(10)).unwrap();
+
+        assert_eq!(2, report.number_of_timings);
+        assert_eq!(0.25, report.verification_seconds_per_element);
+        assert_eq!(0.15, report.transfer_seconds_per_element);
+        assert_eq!(2.0, report.elements_per_power);
+        assert_eq!(256, report.current_chunk_size);
+        // Each power costs 0.8 seconds, so 750 powers fit in 10 minutes.
+        assert_eq!(750, report.recommended_chunk_size);
+    }
+
+    #[test]
+    fn test_chunking_report_bounds() {
+        // There is nothing to estimate from without timings.
+        assert_eq!(None, ChunkingReport::new(&[], 256, chrono::Duration::minutes(10)));
+        assert_eq!(
+            None,
+            ChunkingReport::new(&[timing(0, 10, 10)], 256, chrono::Duration::minutes(10))
+        );
+
+        // A single power which takes longer than the target is still recommended.
+        let report = ChunkingReport::new(&[timing(1, 600, 600)], 256, chrono::Duration::minutes(10)).unwrap();
+        assert_eq!(1, report.recommended_chunk_size);
+
+        // Instant verifications keep the current chunk size.
+        let report = ChunkingReport::new(&[timing(1, 0, 0)], 256, chrono::Duration::minutes(10)).unwrap();
+        assert_eq!(256, report.recommended_chunk_size);
+    }
+}
@@ -0,0 +1,260 @@
+use crate::CoordinatorError;
+
+use serde::{Deserialize, Serialize};
+use serde_with::{DeserializeFromStr, SerializeDisplay};
+use std::{fmt, str::FromStr};
+
+/// The header in which participants identify their client, as `name/major.minor.patch`.
+pub const CLIENT_VERSION_HEADER: &str = "X-Setup-Client";
+
+/// The version of a participant client, as a `major.minor.patch` semantic version.
+///
+/// Pre-release and build metadata are not supported, as released clients
+/// are always published with a plain version number.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, SerializeDisplay, DeserializeFromStr)]
+pub struct Version {
+    major: u64,
+    minor: u64,
+    patch: u64,
+}
+
+impl Version {
+    /// Creates a new instance of `Version`.
+    #[inline]
+    pub const fn new(major: u64, minor: u64, patch: u64) -> Self {
+        Self { major, minor, patch }
+    }
+}
+
+impl FromStr for Version {
+    type Err = CoordinatorError;
+
+    #[inline]
+    fn from_str(version: &str) -> Result<Self, Self::Err> {
+        let invalid = || CoordinatorError::ClientVersionInvalid(version.to_string());
+
+        let numbers = version
+            .split('.')
+            .map(|number| match number.bytes().all(|byte| byte.is_ascii_digit()) {
+                // Leading signs are rejected, which `parse` would otherwise accept.
+                true => number.parse::<u64>().map_err(|_| invalid()),
+                false => Err(invalid()),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        match numbers[..] {
+            [major, minor, patch] => Ok(Self::new(major, minor, patch)),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+impl fmt::Display for Version {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// The client a participant identifies itself with in the `X-Setup-Client` header.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, SerializeDisplay, DeserializeFromStr)]
+pub struct ClientVersion {
+    name: String,
+    version: Version,
+}
+
+impl ClientVersion {
+    /// Creates a new instance of `ClientVersion`.
+    #[inline]
+    pub fn new(name: &str, version: Version) -> Self {
+        Self {
+            name: name.to_string(),
+            version,
+        }
+    }
+
+    /// Returns the name of the client.
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the version of the client.
+    #[inline]
+    pub fn version(&self) -> &Version {
+        &self.version
+    }
+}
+
+impl FromStr for ClientVersion {
+    type Err = CoordinatorError;
+
+    #[inline]
+    fn from_str(header: &str) -> Result<Self, Self::Err> {
+        let invalid = || CoordinatorError::ClientVersionInvalid(header.to_string());
+
+        let mut parts = header.trim().splitn(2, '/');
+        let name = parts.next().filter(|name| !name.is_empty()).ok_or_else(invalid)?;
+        let version = parts.next().ok_or_else(invalid)?;
+
+        // The client name is restricted to the characters of a crate name.
+        if !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+            return Err(invalid());
+        }
+
+        Ok(Self::new(name, version.parse().map_err(|_| invalid())?))
+    }
+}
+
+impl fmt::Display for ClientVersion {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/{}", self.name, self.version)
+    }
+}
+
+/// The range of client versions a coordinator accepts from participants.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ClientVersionPolicy {
+    /// The oldest client version participants may use.
+    minimum: Version,
+    /// The newest client version participants may use, if any.
+    maximum: Option<Version>,
+}
+
+impl ClientVersionPolicy {
+    /// Creates a new instance of `ClientVersionPolicy`.
+    #[inline]
+    pub fn new(minimum: Version, maximum: Option<Version>) -> Self {
+        Self { minimum, maximum }
+    }
+
+    /// Returns the oldest client version participants may use.
+    #[inline]
+    pub fn minimum(&self) -> &Version {
+        &self.minimum
+    }
+
+    /// Returns the newest client version participants may use, if any.
+    #[inline]
+    pub fn maximum(&self) -> Option<&Version> {
+        self.maximum.as_ref()
+    }
+
+    ///
+    /// Checks that the given client is within the supported range of versions.
+    ///
+    /// On failure, returns a `CoordinatorError` with the supported range,
+    /// for the participant to upgrade their client.
+    ///
+    #[inline]
+    pub fn evaluate(&self, client: &ClientVersion) -> Result<(), CoordinatorError> {
+        let too_old = client.version < self.minimum;
+        let too_new = self.maximum.map_or(false, |maximum| client.version > maximum);
+        if too_old || too_new {
+            return Err(CoordinatorError::ClientVersionUnsupported {
+                client: client.clone(),
+                min_version: self.minimum,
+                max_version: self.maximum,
+            });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_version_parse() {
+        assert_eq!(Version::new(0, 1, 0), "0.1.0".parse().unwrap());
+        assert_eq!(Version::new(12, 0, 345), "12.0.345".parse().unwrap());
+        assert_eq!("1.2.3", Version::new(1, 2, 3).to_string());
+
+        for version in &[
+            "",
+            "1",
+            "1.2",
+            "1.2.3.4",
+            "1..3",
+            "1.2.x",
+            "+1.2.3",
+            "1.2.3-alpha",
+            " 1.2.3",
+        ] {
+            assert!(version.parse::<Version>().is_err(), "{:?}", version);
+        }
+    }
+
+    #[test]
+    fn test_version_ordering() {
+        assert!(Version::new(0, 1, 0) < Version::new(0, 1, 1));
+        assert!(Version::new(0, 1, 9) < Version::new(0, 2, 0));
+        assert!(Version::new(0, 9, 9) < Version::new(1, 0, 0));
+        // Components compare numerically, not lexically.
+        assert!("0.2.0".parse::<Version>().unwrap() < "0.10.0".parse().unwrap());
+    }
+
+    #[test]
+    fn test_client_version_parse() {
+        let client: ClientVersion = "setup1-verifier/0.3.1".parse().unwrap();
+        assert_eq!("setup1-verifier", client.name());
+        assert_eq!(&Version::new(0, 3, 1), client.version());
+        assert_eq!("setup1-verifier/0.3.1", client.to_string());
+
+        // Surrounding whitespace of the header value is ignored.
+        assert_eq!(client, " setup1-verifier/0.3.1 ".parse().unwrap());
+
+        for header in &[
+            "",
+            "setup1-verifier",
+            "setup1-verifier/",
+            "/0.3.1",
+            "setup1 verifier/0.3.1",
+            "a/b/0.3.1",
+        ] {
+            match header.parse::<ClientVersion>() {
+                Err(CoordinatorError::ClientVersionInvalid(value)) => assert_eq!(*header, value),
+                result => panic!("Expected {:?} to be invalid, found {:?}", header, result),
+            }
+        }
+    }
+
+    #[test]
+    fn test_client_version_serde() {
+        let client = ClientVersion::new("setup1-contributor", Version::new(1, 0, 2));
+        let serialized = serde_json::to_string(&client).unwrap();
+        assert_eq!("\"setup1-contributor/1.0.2\"", serialized);
+        assert_eq!(client, serde_json::from_str(&serialized).unwrap());
+    }
+
+    #[test]
+    fn test_policy_evaluate() {
+        let policy = ClientVersionPolicy::new(Version::new(0, 2, 0), Some(Version::new(0, 4, 0)));
+        let client = |version: &str| ClientVersion::new("setup1-verifier", version.parse().unwrap());
+
+        assert!(policy.evaluate(&client("0.2.0")).is_ok());
+        assert!(policy.evaluate(&client("0.3.7")).is_ok());
+        assert!(policy.evaluate(&client("0.4.0")).is_ok());
+
+        match policy.evaluate(&client("0.1.9")) {
+            Err(CoordinatorError::ClientVersionUnsupported {
+                client: rejected,
+                min_version,
+                max_version,
+            }) => {
+                assert_eq!(client("0.1.9"), rejected);
+                assert_eq!(Version::new(0, 2, 0), min_version);
+                assert_eq!(Some(Version::new(0, 4, 0)), max_version);
+            }
+            result => panic!("Expected the client to be too old, found {:?}", result),
+        }
+        assert!(policy.evaluate(&client("0.4.1")).is_err());
+
+        // Without a maximum, any newer client is accepted.
+        let policy = ClientVersionPolicy::new(Version::new(0, 2, 0), None);
+        assert!(policy.evaluate(&client("99.0.0")).is_ok());
+        assert!(policy.evaluate(&client("0.1.0")).is_err());
+    }
+}
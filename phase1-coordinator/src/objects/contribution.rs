@@ -1,8 +1,54 @@
-use crate::{objects::Participant, storage::LocatorPath, CoordinatorError};
+use crate::{
+    objects::{ClientVersion, Participant},
+    storage::LocatorPath,
+    CoordinatorError,
+};
 
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use tracing::trace;
 
+///
+/// The record of a verifier checking a contribution.
+///
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VerificationRecord {
+    verifier: Participant,
+    verified_at: DateTime<Utc>,
+    success: bool,
+    /// The client the verifier identified themselves with, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    client: Option<ClientVersion>,
+}
+
+impl VerificationRecord {
+    /// Returns the verifier of this record.
+    #[inline]
+    pub fn verifier(&self) -> &Participant {
+        &self.verifier
+    }
+
+    /// Returns the time the verifier checked the contribution.
+    #[inline]
+    pub fn verified_at(&self) -> &DateTime<Utc> {
+        &self.verified_at
+    }
+
+    /// Returns `true` if the verifier found the contribution to be valid.
+    /// Otherwise returns `false`.
+    #[inline]
+    pub fn is_success(&self) -> bool {
+        self.success
+    }
+
+    /// Returns the client the verifier identified themselves with, if any.
+    #[inline]
+    pub fn client(&self) -> Option<&ClientVersion> {
+        self.client.as_ref()
+    }
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Contribution {
@@ -17,16 +63,37 @@ pub struct Contribution {
     #[serde(rename = "verifiedSignatureLocation")]
     verified_signature_locator: Option<LocatorPath>,
     verified: bool,
+    /// The verifications of this contribution, in the order they completed.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    verifications: Vec<VerificationRecord>,
+    /// The client the contributor identified themselves with, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    contributor_client: Option<ClientVersion>,
 }
 
 impl Contribution {
-    /// Returns `true` if the contribution has been verified.
-    /// Otherwise returns `false`.
+    /// Returns `true` if the contribution has been verified by
+    /// the required number of verifiers. Otherwise returns `false`.
     #[inline]
     pub fn is_verified(&self) -> bool {
         self.verified
     }
 
+    /// Returns a reference to the verification records of this contribution.
+    #[inline]
+    pub fn get_verifications(&self) -> &Vec<VerificationRecord> {
+        &self.verifications
+    }
+
+    /// Returns `true` if the given verifier has successfully verified
+    /// this contribution. Otherwise returns `false`.
+    #[inline]
+    pub fn is_verified_by(&self, verifier: &Participant) -> bool {
+        self.verifications
+            .iter()
+            .any(|record| record.success && record.verifier == *verifier)
+    }
+
     /// Returns a reference to the contributor, if it exists.
     /// Otherwise returns `None`.
     #[inline]
@@ -34,6 +101,13 @@ impl Contribution {
         &self.contributor_id
     }
 
+    /// Returns a reference to the client of the contributor, if it was recorded.
+    /// Otherwise returns `None`.
+    #[inline]
+    pub fn get_contributor_client(&self) -> &Option<ClientVersion> {
+        &self.contributor_client
+    }
+
     /// Returns a reference to the contributor locator, if it exists.
     /// Otherwise returns `None`.
     #[inline]
@@ -97,6 +171,8 @@ impl Contribution {
             verified_locator: None,
             verified_signature_locator: None,
             verified: false,
+            verifications: vec![],
+            contributor_client: None,
         })
     }
 
@@ -137,6 +213,8 @@ impl Contribution {
             verified_locator: Some(verified_locator),
             verified_signature_locator: Some(verified_signature_locator),
             verified: true,
+            verifications: vec![],
+            contributor_client: None,
         };
 
         Ok(contribution)
@@ -146,7 +224,7 @@ impl Contribution {
     /// Assign a verifier to this instance of `Contribution`.
     ///
     /// If this contribution already has a verifier or verified locator,
-    /// returns a `CoordinatorError`.
+    /// which has not completed its verification, returns a `CoordinatorError`.
     ///
     /// Note that this function does NOT set the state of `verified`.
     /// This approach allows a verifier to assign themselves to verify
@@ -165,16 +243,27 @@ impl Contribution {
             return Err(CoordinatorError::ExpectedVerifier);
         }
 
-        // Check that this contribution does not have a verifier.
-        if self.verifier_id.is_some() {
+        // Check that this contribution does not have a verifier, unless
+        // the previous verifier has completed its verification.
+        let previous_verifier_completed = match &self.verifier_id {
+            Some(verifier_id) => self.is_verified_by(verifier_id),
+            None => false,
+        };
+        if self.verifier_id.is_some() && !previous_verifier_completed {
             return Err(CoordinatorError::ContributionAlreadyAssignedVerifier);
         }
 
-        // Check that this contribution does not have a verified locator.
-        if self.verified_locator.is_some() {
+        // Check that this contribution does not have a verified locator,
+        // unless the previous verifier has completed its verification.
+        if self.verified_locator.is_some() && !previous_verifier_completed {
             return Err(CoordinatorError::ContributionAlreadyAssignedVerifiedLocator);
         }
 
+        // Check that the participant has not verified this contribution already.
+        if self.is_verified_by(&participant) {
+            return Err(CoordinatorError::ContributionAlreadyVerified);
+        }
+
         // Check that this contribution is not verified.
         if self.verified {
             return Err(CoordinatorError::ContributionAlreadyVerified);
@@ -186,19 +275,26 @@ impl Contribution {
         Ok(())
     }
 
-    /// Updates `verified` to `true` in this instance of `Contribution`,
-    /// if the verifier ID and verified location are valid.
+    ///
+    /// Records a successful verification by the given participant in this instance of
+    /// `Contribution`, if the verifier ID and verified location are valid, and updates
+    /// `verified` to `true` once the given number of required verifications is reached.
     ///
     /// If this contribution has already been verified,
     /// returns a `CoordinatorError`.
     ///
     #[tracing::instrument(
         level = "error",
-        skip(self, participant),
+        skip(self, participant, verified_at),
         fields(participant = %participant),
         err
     )]
-    pub(crate) fn set_verified(&mut self, participant: &Participant) -> Result<(), CoordinatorError> {
+    pub(crate) fn add_verification(
+        &mut self,
+        participant: &Participant,
+        verified_at: DateTime<Utc>,
+        required_verifications: u64,
+    ) -> Result<(), CoordinatorError> {
         // Check that the participant is a verifier.
         if !participant.is_verifier() {
             return Err(CoordinatorError::ExpectedVerifier);
@@ -221,15 +317,58 @@ impl Contribution {
         }
 
         // Check that this contribution has not been verified.
-        if self.verified {
+        if self.verified || self.is_verified_by(participant) {
             return Err(CoordinatorError::ContributionAlreadyVerified);
         }
 
-        trace!("Setting contribution to verified");
-        self.verified = true;
+        trace!("Recording the verification of the contribution");
+        self.verifications.push(VerificationRecord {
+            verifier: participant.clone(),
+            verified_at,
+            success: true,
+            client: None,
+        });
+
+        let successful_verifications = self.verifications.iter().filter(|record| record.success).count() as u64;
+        if successful_verifications >= required_verifications {
+            trace!("Setting contribution to verified");
+            self.verified = true;
+        }
         Ok(())
     }
 
+    ///
+    /// Records a failed verification by the given participant in this instance of `Contribution`.
+    ///
+    #[inline]
+    pub(crate) fn add_failed_verification(&mut self, participant: &Participant, verified_at: DateTime<Utc>) {
+        self.verifications.push(VerificationRecord {
+            verifier: participant.clone(),
+            verified_at,
+            success: false,
+            client: None,
+        });
+    }
+
+    ///
+    /// Records the client the given participant identified themselves with,
+    /// on the contribution if they are its contributor, or on their latest
+    /// verification record if they are a verifier.
+    ///
+    #[inline]
+    pub(crate) fn set_client(&mut self, participant: &Participant, client: ClientVersion) {
+        if self.contributor_id.as_ref() == Some(participant) {
+            self.contributor_client = Some(client);
+        } else if let Some(record) = self
+            .verifications
+            .iter_mut()
+            .rev()
+            .find(|record| record.verifier == *participant)
+        {
+            record.client = Some(client);
+        }
+    }
+
     /// Get a list containing all the file locators associated with
     /// this contribution.
     pub(crate) fn get_locators(&self) -> Vec<LocatorPath> {
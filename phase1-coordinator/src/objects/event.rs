@@ -0,0 +1,99 @@
+use crate::objects::Participant;
+
+use serde::{Deserialize, Serialize};
+
+/// The lock held on a chunk of the current round.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChunkLock {
+    pub chunk_id: u64,
+    pub participant: Participant,
+}
+
+///
+/// An update to the ceremony, published by the coordinator as its state changes.
+///
+/// A subscriber first receives a snapshot of the current round, followed by every
+/// update made after it, so the two can be combined into a live view of the ceremony.
+///
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum CoordinatorEvent {
+    /// The current round, and the chunks locked in it, when the subscriber subscribed.
+    Snapshot {
+        round_height: u64,
+        locked_chunks: Vec<ChunkLock>,
+    },
+    /// A participant locked a chunk.
+    ChunkLocked {
+        round_height: u64,
+        chunk_id: u64,
+        participant: Participant,
+    },
+    /// The lock on a chunk was released without a contribution or verification.
+    ChunkUnlocked {
+        round_height: u64,
+        chunk_id: u64,
+        participant: Participant,
+    },
+    /// The lock of a dropped verifier on a chunk was revoked, and its partial
+    /// verification removed, so another verifier can take over the chunk.
+    VerifierLockRevoked {
+        round_height: u64,
+        chunk_id: u64,
+        participant: Participant,
+    },
+    /// A contributor added a contribution, releasing its lock on the chunk.
+    ContributionAdded {
+        round_height: u64,
+        chunk_id: u64,
+        contribution_id: u64,
+        participant: Participant,
+    },
+    /// A verifier verified a contribution, releasing its lock on the chunk.
+    ContributionVerified {
+        round_height: u64,
+        chunk_id: u64,
+        contribution_id: u64,
+        participant: Participant,
+    },
+    /// A contribution failed verification, and was quarantined for its contributor to retry.
+    ContributionFailed {
+        round_height: u64,
+        chunk_id: u64,
+        contribution_id: u64,
+        participant: Participant,
+        attempt: u64,
+    },
+    /// A participant was dropped from the round, as it timed out, disconnected, or was
+    /// dropped by the coordinator, releasing the locks it held.
+    ParticipantDropped {
+        round_height: u64,
+        participant: Participant,
+    },
+    /// The ceremony advanced to a new round.
+    RoundAdvanced { round_height: u64 },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use serde_json::json;
+
+    #[test]
+    fn test_event_json() {
+        let event = CoordinatorEvent::ChunkLocked {
+            round_height: 1,
+            chunk_id: 2,
+            participant: Participant::new_contributor("aleo1"),
+        };
+        let expected = json!({
+            "type": "chunk_locked",
+            "round_height": 1,
+            "chunk_id": 2,
+            "participant": "aleo1.contributor",
+        });
+        assert_eq!(expected, serde_json::to_value(&event).unwrap());
+        assert_eq!(event, serde_json::from_value(expected).unwrap());
+    }
+}
@@ -0,0 +1,290 @@
+use crate::CoordinatorError;
+
+use serde::{Deserialize, Serialize};
+
+/// The hardware specifications a contributor self-reports when joining the queue.
+///
+/// The declaration is advisory, as the coordinator has no way to verify it.
+/// It is used to turn away underpowered machines and to scale the time a
+/// contributor is allowed to hold a lock on a chunk.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HardwareDeclaration {
+    /// The number of CPU cores available for computation.
+    cores: u32,
+    /// The amount of memory available for computation, in gigabytes.
+    memory_in_gb: u32,
+    /// The network bandwidth available for uploads and downloads, in megabits per second.
+    bandwidth_in_mbps: u32,
+}
+
+impl HardwareDeclaration {
+    /// Creates a new instance of `HardwareDeclaration`.
+    #[inline]
+    pub fn new(cores: u32, memory_in_gb: u32, bandwidth_in_mbps: u32) -> Self {
+        Self {
+            cores,
+            memory_in_gb,
+            bandwidth_in_mbps,
+        }
+    }
+
+    /// Returns the number of declared CPU cores.
+    #[inline]
+    pub fn cores(&self) -> u32 {
+        self.cores
+    }
+
+    /// Returns the amount of declared memory, in gigabytes.
+    #[inline]
+    pub fn memory_in_gb(&self) -> u32 {
+        self.memory_in_gb
+    }
+
+    /// Returns the declared network bandwidth, in megabits per second.
+    #[inline]
+    pub fn bandwidth_in_mbps(&self) -> u32 {
+        self.bandwidth_in_mbps
+    }
+}
+
+/// The hardware requirements a coordinator places on contributors.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HardwarePolicy {
+    /// The minimum hardware a contributor must declare to join the queue.
+    minimum: HardwareDeclaration,
+    /// The hardware the base participant lock timeout is calibrated for.
+    reference: HardwareDeclaration,
+    /// The maximum factor by which the participant lock timeout may be extended.
+    maximum_deadline_multiplier: f64,
+}
+
+impl HardwarePolicy {
+    ///
+    /// Creates a new instance of `HardwarePolicy`.
+    ///
+    /// The `maximum_deadline_multiplier` is clamped to be at least `1.0`,
+    /// so that a declaration can never shorten a deadline.
+    ///
+    #[inline]
+    pub fn new(minimum: HardwareDeclaration, reference: HardwareDeclaration, maximum_deadline_multiplier: f64) -> Self {
+        Self {
+            minimum,
+            reference,
+            maximum_deadline_multiplier: maximum_deadline_multiplier.max(1.0),
+        }
+    }
+
+    /// Returns the hardware policy for the Aleo inner ceremony.
+    #[inline]
+    pub fn aleo_inner() -> Self {
+        Self::new(
+            HardwareDeclaration::new(2, 4, 10),
+            HardwareDeclaration::new(8, 16, 100),
+            4.0,
+        )
+    }
+
+    /// Returns the hardware policy for the Aleo outer ceremony.
+    #[inline]
+    pub fn aleo_outer() -> Self {
+        Self::new(
+            HardwareDeclaration::new(4, 8, 10),
+            HardwareDeclaration::new(16, 32, 100),
+            4.0,
+        )
+    }
+
+    /// Returns the hardware policy for the Aleo universal ceremony.
+    #[inline]
+    pub fn aleo_universal() -> Self {
+        Self::new(
+            HardwareDeclaration::new(8, 32, 25),
+            HardwareDeclaration::new(32, 128, 250),
+            4.0,
+        )
+    }
+
+    /// Returns the minimum hardware a contributor must declare.
+    #[inline]
+    pub fn minimum(&self) -> &HardwareDeclaration {
+        &self.minimum
+    }
+
+    /// Returns the hardware the base participant lock timeout is calibrated for.
+    #[inline]
+    pub fn reference(&self) -> &HardwareDeclaration {
+        &self.reference
+    }
+
+    /// Returns the maximum factor by which the participant lock timeout may be extended.
+    #[inline]
+    pub fn maximum_deadline_multiplier(&self) -> f64 {
+        self.maximum_deadline_multiplier
+    }
+
+    ///
+    /// Checks that the given declaration meets the minimum hardware requirements.
+    ///
+    /// On failure, returns a `CoordinatorError` naming the first requirement
+    /// that the declaration falls short of.
+    ///
+    #[inline]
+    pub fn evaluate(&self, declaration: &HardwareDeclaration) -> Result<(), CoordinatorError> {
+        if declaration.cores < self.minimum.cores {
+            return Err(CoordinatorError::ParticipantHardwareBelowMinimum {
+                requirement: "cores",
+                declared: declaration.cores as u64,
+                minimum: self.minimum.cores as u64,
+            });
+        }
+        if declaration.memory_in_gb < self.minimum.memory_in_gb {
+            return Err(CoordinatorError::ParticipantHardwareBelowMinimum {
+                requirement: "memory_in_gb",
+                declared: declaration.memory_in_gb as u64,
+                minimum: self.minimum.memory_in_gb as u64,
+            });
+        }
+        if declaration.bandwidth_in_mbps < self.minimum.bandwidth_in_mbps {
+            return Err(CoordinatorError::ParticipantHardwareBelowMinimum {
+                requirement: "bandwidth_in_mbps",
+                declared: declaration.bandwidth_in_mbps as u64,
+                minimum: self.minimum.bandwidth_in_mbps as u64,
+            });
+        }
+        Ok(())
+    }
+
+    ///
+    /// Returns the factor by which to extend the participant lock timeout
+    /// for a contributor with the given declaration.
+    ///
+    /// The factor is the largest shortfall in cores or bandwidth relative to
+    /// the reference hardware, bounded between `1.0` and the maximum deadline
+    /// multiplier. Memory is only used as a floor, as it does not scale the
+    /// running time of a contribution.
+    ///
+    #[inline]
+    pub fn deadline_multiplier(&self, declaration: &HardwareDeclaration) -> f64 {
+        let ratio = |reference: u32, declared: u32| match declared {
+            0 => self.maximum_deadline_multiplier,
+            declared => reference as f64 / declared as f64,
+        };
+
+        let multiplier = ratio(self.reference.cores, declaration.cores)
+            .max(ratio(self.reference.bandwidth_in_mbps, declaration.bandwidth_in_mbps));
+
+        multiplier.max(1.0).min(self.maximum_deadline_multiplier)
+    }
+
+    ///
+    /// Returns the participant lock timeout for a contributor with the given
+    /// declaration, scaled from the given base timeout.
+    ///
+    #[inline]
+    pub fn scaled_deadline(&self, base: chrono::Duration, declaration: &HardwareDeclaration) -> chrono::Duration {
+        let multiplier = self.deadline_multiplier(declaration);
+        chrono::Duration::milliseconds((base.num_milliseconds() as f64 * multiplier) as i64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_evaluate_accepts_minimum() {
+        let policy = HardwarePolicy::aleo_inner();
+        assert!(policy.evaluate(policy.minimum()).is_ok());
+        assert!(policy.evaluate(policy.reference()).is_ok());
+    }
+
+    #[test]
+    fn test_evaluate_rejects_below_minimum() {
+        let policy = HardwarePolicy::aleo_universal();
+
+        // An inner-sized machine does not meet the memory floor for universal.
+        let declaration = HardwareDeclaration::new(8, 16, 100);
+        match policy.evaluate(&declaration) {
+            Err(CoordinatorError::ParticipantHardwareBelowMinimum {
+                requirement,
+                declared,
+                minimum,
+            }) => {
+                assert_eq!("memory_in_gb", requirement);
+                assert_eq!(16, declared);
+                assert_eq!(32, minimum);
+            }
+            result => panic!("Expected the memory requirement to fail, found {:?}", result),
+        }
+
+        // The same machine is accepted for inner.
+        assert!(HardwarePolicy::aleo_inner().evaluate(&declaration).is_ok());
+
+        let declaration = HardwareDeclaration::new(1, 64, 100);
+        assert!(policy.evaluate(&declaration).is_err());
+
+        let declaration = HardwareDeclaration::new(32, 64, 1);
+        assert!(policy.evaluate(&declaration).is_err());
+    }
+
+    #[test]
+    fn test_deadline_multiplier() {
+        let policy = HardwarePolicy::new(
+            HardwareDeclaration::new(1, 1, 1),
+            HardwareDeclaration::new(8, 16, 100),
+            4.0,
+        );
+
+        // Reference or better hardware keeps the base deadline.
+        assert_eq!(1.0, policy.deadline_multiplier(&HardwareDeclaration::new(8, 16, 100)));
+        assert_eq!(
+            1.0,
+            policy.deadline_multiplier(&HardwareDeclaration::new(64, 256, 1000))
+        );
+
+        // Slower hardware is scaled by the largest shortfall.
+        assert_eq!(2.0, policy.deadline_multiplier(&HardwareDeclaration::new(4, 16, 100)));
+        assert_eq!(2.5, policy.deadline_multiplier(&HardwareDeclaration::new(4, 16, 40)));
+
+        // Memory does not scale the deadline.
+        assert_eq!(1.0, policy.deadline_multiplier(&HardwareDeclaration::new(8, 1, 100)));
+
+        // The multiplier is capped.
+        assert_eq!(4.0, policy.deadline_multiplier(&HardwareDeclaration::new(1, 16, 100)));
+        assert_eq!(4.0, policy.deadline_multiplier(&HardwareDeclaration::new(0, 16, 0)));
+    }
+
+    #[test]
+    fn test_scaled_deadline() {
+        let policy = HardwarePolicy::new(
+            HardwareDeclaration::new(1, 1, 1),
+            HardwareDeclaration::new(8, 16, 100),
+            3.0,
+        );
+        let base = chrono::Duration::minutes(20);
+
+        assert_eq!(
+            base,
+            policy.scaled_deadline(base, &HardwareDeclaration::new(8, 16, 100))
+        );
+        assert_eq!(
+            chrono::Duration::minutes(40),
+            policy.scaled_deadline(base, &HardwareDeclaration::new(4, 16, 100))
+        );
+        assert_eq!(
+            chrono::Duration::minutes(60),
+            policy.scaled_deadline(base, &HardwareDeclaration::new(1, 16, 100))
+        );
+    }
+
+    #[test]
+    fn test_maximum_deadline_multiplier_is_at_least_one() {
+        let policy = HardwarePolicy::new(
+            HardwareDeclaration::new(1, 1, 1),
+            HardwareDeclaration::new(8, 16, 100),
+            0.5,
+        );
+        assert_eq!(1.0, policy.maximum_deadline_multiplier());
+        assert_eq!(1.0, policy.deadline_multiplier(&HardwareDeclaration::new(1, 1, 1)));
+    }
+}
@@ -0,0 +1,260 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// The record of a round of the ceremony, made when the round is aggregated.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RoundHistory {
+    /// The height of the round.
+    round_height: u64,
+    /// The time the round started.
+    started_at: DateTime<Utc>,
+    /// The time the round finished aggregation.
+    finished_at: DateTime<Utc>,
+    /// The number of contributors in the round.
+    number_of_contributors: u64,
+    /// The number of verifiers in the round.
+    number_of_verifiers: u64,
+    /// The number of contributions made in the round, excluding the initial contributions.
+    number_of_contributions: u64,
+    /// The total size of the contribution and verification files of the round.
+    bytes_processed: u64,
+}
+
+impl RoundHistory {
+    /// Creates a new instance of `RoundHistory`.
+    #[inline]
+    pub fn new(
+        round_height: u64,
+        started_at: DateTime<Utc>,
+        finished_at: DateTime<Utc>,
+        number_of_contributors: u64,
+        number_of_verifiers: u64,
+        number_of_contributions: u64,
+        bytes_processed: u64,
+    ) -> Self {
+        Self {
+            round_height,
+            started_at,
+            finished_at,
+            number_of_contributors,
+            number_of_verifiers,
+            number_of_contributions,
+            bytes_processed,
+        }
+    }
+
+    /// Returns the height of the round.
+    #[inline]
+    pub fn round_height(&self) -> u64 {
+        self.round_height
+    }
+
+    /// Returns the time the round started.
+    #[inline]
+    pub fn started_at(&self) -> DateTime<Utc> {
+        self.started_at
+    }
+
+    /// Returns the time the round finished aggregation.
+    #[inline]
+    pub fn finished_at(&self) -> DateTime<Utc> {
+        self.finished_at
+    }
+
+    /// Returns the number of contributors in the round.
+    #[inline]
+    pub fn number_of_contributors(&self) -> u64 {
+        self.number_of_contributors
+    }
+
+    /// Returns the number of verifiers in the round.
+    #[inline]
+    pub fn number_of_verifiers(&self) -> u64 {
+        self.number_of_verifiers
+    }
+
+    /// Returns the number of contributions made in the round.
+    #[inline]
+    pub fn number_of_contributions(&self) -> u64 {
+        self.number_of_contributions
+    }
+
+    /// Returns the total size of the contribution and verification files of the round.
+    #[inline]
+    pub fn bytes_processed(&self) -> u64 {
+        self.bytes_processed
+    }
+
+    /// Returns the number of seconds from the start of the round to the end of its aggregation.
+    #[inline]
+    pub fn seconds(&self) -> u64 {
+        (self.finished_at - self.started_at).num_seconds().max(0) as u64
+    }
+}
+
+/// The totals of the rounds rolled up out of the ceremony history.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+struct RolledUpRounds {
+    number_of_rounds: u64,
+    seconds: u64,
+    number_of_contributions: u64,
+    bytes_processed: u64,
+}
+
+///
+/// The history of the rounds of the ceremony.
+///
+/// The most recent rounds are kept individually, up to the cap set in the environment,
+/// and older rounds are rolled up into totals. The history is part of the coordinator
+/// state, so it is saved to storage and survives restarts.
+///
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CeremonyHistory {
+    /// The most recent rounds, in the order they finished.
+    rounds: VecDeque<RoundHistory>,
+    /// The totals of the rounds that are no longer kept individually.
+    rolled_up: RolledUpRounds,
+}
+
+impl CeremonyHistory {
+    ///
+    /// Records the given round, and rolls up the oldest rounds past the given cap.
+    ///
+    /// If the round was already recorded, as the round was reset and aggregated again,
+    /// the previous record is replaced.
+    ///
+    #[inline]
+    pub(crate) fn record(&mut self, round: RoundHistory, cap: Option<usize>) {
+        self.rounds
+            .retain(|recorded| recorded.round_height != round.round_height);
+        self.rounds.push_back(round);
+
+        if let Some(cap) = cap {
+            while self.rounds.len() > cap {
+                if let Some(oldest) = self.rounds.pop_front() {
+                    self.rolled_up.number_of_rounds += 1;
+                    self.rolled_up.seconds += oldest.seconds();
+                    self.rolled_up.number_of_contributions += oldest.number_of_contributions;
+                    self.rolled_up.bytes_processed += oldest.bytes_processed;
+                }
+            }
+        }
+    }
+
+    /// Returns the rounds kept individually in the history, from oldest to newest.
+    #[inline]
+    pub fn rounds(&self) -> impl Iterator<Item = &RoundHistory> {
+        self.rounds.iter()
+    }
+
+    ///
+    /// Returns the aggregates over every round in the history,
+    /// including the rounds that were rolled up.
+    ///
+    #[inline]
+    pub fn aggregates(&self) -> HistoryAggregates {
+        let rounds_completed = self.rolled_up.number_of_rounds + self.rounds.len() as u64;
+        let seconds = self.rolled_up.seconds + self.rounds.iter().map(RoundHistory::seconds).sum::<u64>();
+        let number_of_contributions = self.rolled_up.number_of_contributions
+            + self
+                .rounds
+                .iter()
+                .map(RoundHistory::number_of_contributions)
+                .sum::<u64>();
+        let bytes_processed =
+            self.rolled_up.bytes_processed + self.rounds.iter().map(RoundHistory::bytes_processed).sum::<u64>();
+
+        HistoryAggregates {
+            rounds_completed,
+            average_seconds_per_round: match rounds_completed {
+                0 => None,
+                _ => Some(seconds / rounds_completed),
+            },
+            contributions_per_hour: match seconds {
+                0 => None,
+                _ => Some(number_of_contributions as f64 * 3600.0 / seconds as f64),
+            },
+            bytes_processed,
+        }
+    }
+}
+
+/// The aggregates over the rounds of the ceremony history.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HistoryAggregates {
+    /// The number of rounds completed.
+    pub rounds_completed: u64,
+    /// The average number of seconds per round, if any round was completed.
+    pub average_seconds_per_round: Option<u64>,
+    /// The number of contributions per hour over the completed rounds, if any time has passed.
+    pub contributions_per_hour: Option<f64>,
+    /// The total size of the contribution and verification files of the completed rounds.
+    pub bytes_processed: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use chrono::{Duration, TimeZone};
+
+    fn round_history(round_height: u64, hours: i64, number_of_contributions: u64) -> RoundHistory {
+        let started_at = Utc.ymd(1970, 1, 1).and_hms(0, 0, 0) + Duration::days(round_height as i64);
+        RoundHistory::new(
+            round_height,
+            started_at,
+            started_at + Duration::hours(hours),
+            2,
+            1,
+            number_of_contributions,
+            1000,
+        )
+    }
+
+    #[test]
+    fn test_aggregates() {
+        let mut history = CeremonyHistory::default();
+        assert_eq!(0, history.aggregates().rounds_completed);
+        assert_eq!(None, history.aggregates().average_seconds_per_round);
+        assert_eq!(None, history.aggregates().contributions_per_hour);
+
+        history.record(round_history(1, 1, 8), None);
+        history.record(round_history(2, 2, 8), None);
+        history.record(round_history(3, 3, 8), None);
+
+        let aggregates = history.aggregates();
+        assert_eq!(3, aggregates.rounds_completed);
+        assert_eq!(Some(7200), aggregates.average_seconds_per_round);
+        assert_eq!(Some(4.0), aggregates.contributions_per_hour);
+        assert_eq!(3000, aggregates.bytes_processed);
+    }
+
+    #[test]
+    fn test_record_rolls_up_past_cap() {
+        let mut capped = CeremonyHistory::default();
+        let mut uncapped = CeremonyHistory::default();
+        for round_height in 1..=3 {
+            capped.record(round_history(round_height, round_height as i64, 8), Some(2));
+            uncapped.record(round_history(round_height, round_height as i64, 8), None);
+        }
+
+        // The oldest round is rolled up, without changing the aggregates.
+        let heights: Vec<u64> = capped.rounds().map(RoundHistory::round_height).collect();
+        assert_eq!(vec![2, 3], heights);
+        assert_eq!(uncapped.aggregates(), capped.aggregates());
+    }
+
+    #[test]
+    fn test_record_replaces_same_round() {
+        let mut history = CeremonyHistory::default();
+        history.record(round_history(1, 1, 8), None);
+        history.record(round_history(1, 2, 4), None);
+
+        assert_eq!(1, history.aggregates().rounds_completed);
+        assert_eq!(
+            Some(4),
+            history.rounds().next().map(RoundHistory::number_of_contributions)
+        );
+    }
+}
@@ -1,17 +1,50 @@
+pub mod admission;
+pub use admission::*;
+
+pub mod api_error;
+pub use api_error::*;
+
 pub mod chunk;
 pub use chunk::*;
 
+pub mod chunking;
+pub use chunking::*;
+
+pub mod client_version;
+pub use client_version::*;
+
 pub mod contribution;
 pub use contribution::*;
 
 pub mod contribution_file_signature;
 pub use contribution_file_signature::*;
 
+pub mod event;
+pub use event::*;
+
+pub mod hardware;
+pub use hardware::*;
+
+pub mod history;
+pub use history::*;
+
 pub mod participant;
 pub use participant::*;
 
+pub mod practice;
+pub use practice::*;
+
+pub mod retry;
+pub use retry::*;
+
 pub mod round;
 pub use round::*;
 
 pub mod task;
 pub use task::Task;
+
+pub mod transcript_manifest;
+pub use transcript_manifest::*;
+
+pub mod verification_metadata;
+pub use verification_metadata::*;
@@ -13,7 +13,7 @@ pub type VerifierId = String;
 
 /// A participant in the setup ceremony. The participant can either be
 /// a [Participant::Contributor] or a [Participant::Verifier].
-#[derive(Clone, Eq, PartialEq, Hash, SerdeDiff)]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, SerdeDiff)]
 pub enum Participant {
     /// A contributor is a ceremony participant that makes active
     /// contributions during the setup ceremony.
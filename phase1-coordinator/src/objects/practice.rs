@@ -0,0 +1,72 @@
+use serde::{Deserialize, Serialize};
+use serde_with::DurationSecondsWithFrac;
+
+/// The feedback returned to a contributor after verifying a practice response.
+///
+/// The estimate is a linear extrapolation of the practice round trip to a chunk
+/// of the real parameters, so it includes the time spent downloading the practice
+/// challenge and uploading the practice response.
+#[serde_with::serde_as]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PracticeFeedback {
+    /// The time from serving the practice challenge to receiving the practice response.
+    #[serde_as(as = "DurationSecondsWithFrac<String>")]
+    elapsed: chrono::Duration,
+    /// The time taken by the coordinator to verify the practice response.
+    #[serde_as(as = "DurationSecondsWithFrac<String>")]
+    verification_time: chrono::Duration,
+    /// The estimated time to contribute to one chunk of the real parameters.
+    #[serde_as(as = "DurationSecondsWithFrac<String>")]
+    estimated_contribution_time: chrono::Duration,
+    /// The maximum duration a contributor may hold a lock on a chunk.
+    #[serde_as(as = "DurationSecondsWithFrac<String>")]
+    participant_lock_timeout: chrono::Duration,
+}
+
+impl PracticeFeedback {
+    /// Creates a new instance of `PracticeFeedback`.
+    #[inline]
+    pub fn new(
+        elapsed: chrono::Duration,
+        verification_time: chrono::Duration,
+        estimated_contribution_time: chrono::Duration,
+        participant_lock_timeout: chrono::Duration,
+    ) -> Self {
+        Self {
+            elapsed,
+            verification_time,
+            estimated_contribution_time,
+            participant_lock_timeout,
+        }
+    }
+
+    /// Returns the time from serving the practice challenge to receiving the practice response.
+    #[inline]
+    pub fn elapsed(&self) -> chrono::Duration {
+        self.elapsed
+    }
+
+    /// Returns the time taken by the coordinator to verify the practice response.
+    #[inline]
+    pub fn verification_time(&self) -> chrono::Duration {
+        self.verification_time
+    }
+
+    /// Returns the estimated time to contribute to one chunk of the real parameters.
+    #[inline]
+    pub fn estimated_contribution_time(&self) -> chrono::Duration {
+        self.estimated_contribution_time
+    }
+
+    /// Returns the maximum duration a contributor may hold a lock on a chunk.
+    #[inline]
+    pub fn participant_lock_timeout(&self) -> chrono::Duration {
+        self.participant_lock_timeout
+    }
+
+    /// Returns `true` if the estimated contribution time is within the participant lock timeout.
+    #[inline]
+    pub fn is_fast_enough(&self) -> bool {
+        self.estimated_contribution_time <= self.participant_lock_timeout
+    }
+}
@@ -0,0 +1,80 @@
+use crate::objects::Participant;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::DurationSecondsWithFrac;
+
+/// The budget a contributor has to re-upload a contribution that failed verification.
+///
+/// Without a retry policy, a contribution that fails verification is discarded along
+/// with the task, and the contributor is left to be dropped by the coordinator.
+#[serde_with::serde_as]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ContributionRetryPolicy {
+    /// The number of times a contributor may re-upload a failed contribution to a chunk.
+    retries: u64,
+    /// The maximum duration a contributor has to lock the chunk again after a failed verification.
+    #[serde_as(as = "DurationSecondsWithFrac<String>")]
+    window: chrono::Duration,
+}
+
+impl ContributionRetryPolicy {
+    /// Creates a new instance of `ContributionRetryPolicy`.
+    #[inline]
+    pub fn new(retries: u64, window: chrono::Duration) -> Self {
+        Self { retries, window }
+    }
+
+    /// Returns the number of times a contributor may re-upload a failed contribution to a chunk.
+    #[inline]
+    pub fn retries(&self) -> u64 {
+        self.retries
+    }
+
+    /// Returns the maximum duration a contributor has to lock the chunk again after a failed verification.
+    #[inline]
+    pub fn window(&self) -> chrono::Duration {
+        self.window
+    }
+
+    ///
+    /// Returns `true` if a contributor whose contribution to a chunk has failed
+    /// verification the given number of times may not retry it again.
+    ///
+    #[inline]
+    pub fn is_exhausted(&self, failed_attempts: u64) -> bool {
+        failed_attempts > self.retries
+    }
+}
+
+/// A contribution that failed verification in the current round.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FailedContribution {
+    /// The contributor of the failed contribution.
+    pub contributor: Participant,
+    /// The chunk ID of the failed contribution.
+    pub chunk_id: u64,
+    /// The contribution ID of the failed contribution.
+    pub contribution_id: u64,
+    /// The number of times the contributor has failed verification on this chunk, starting from 1.
+    pub attempt: u64,
+    /// The time the contribution failed verification.
+    pub failed_at: DateTime<Utc>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_exhausted() {
+        let policy = ContributionRetryPolicy::new(2, chrono::Duration::minutes(10));
+        assert!(!policy.is_exhausted(1));
+        assert!(!policy.is_exhausted(2));
+        assert!(policy.is_exhausted(3));
+
+        // Without retries, the first failure exhausts the budget.
+        let policy = ContributionRetryPolicy::new(0, chrono::Duration::minutes(10));
+        assert!(policy.is_exhausted(1));
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{
     environment::Environment,
-    objects::{participant::*, Chunk},
+    objects::{participant::*, Chunk, ClientVersion, HardwareDeclaration},
     storage::{
         ContributionLocator,
         ContributionSignatureLocator,
@@ -13,6 +13,7 @@ use crate::{
         UpdateAction,
     },
     CoordinatorError,
+    TimeSource,
 };
 
 use chrono::{DateTime, Utc};
@@ -20,7 +21,11 @@ use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 use serde_aux::prelude::*;
 use serde_diff::SerdeDiff;
-use std::{collections::HashSet, hash::Hash};
+use std::{
+    collections::{BTreeMap, HashSet},
+    convert::TryFrom,
+    hash::Hash,
+};
 use tracing::{debug, error, trace, warn};
 
 use super::Task;
@@ -42,6 +47,7 @@ pub struct LockedLocators {
     current_contribution: ContributionLocator,
     next_contribution: ContributionLocator,
     next_contribution_file_signature: ContributionSignatureLocator,
+    lock_token: String,
 }
 
 impl LockedLocators {
@@ -64,15 +70,152 @@ impl LockedLocators {
     pub fn next_contribution_file_signature(&self) -> ContributionSignatureLocator {
         self.next_contribution_file_signature
     }
+
+    /// Get the token of the lock, which the participant presents with its uploads.
+    pub fn lock_token(&self) -> &str {
+        &self.lock_token
+    }
+
+    /// Set the token of the lock, once the lock is recorded in the coordinator state.
+    pub(crate) fn set_lock_token(&mut self, lock_token: String) {
+        self.lock_token = lock_token;
+    }
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize, SerdeDiff)]
+/// The metadata and participants of a [Round], stored apart from its chunks.
+///
+/// Storage keeps a round as this header and one object per chunk, so that
+/// an update to a single chunk does not rewrite the state of the whole round.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct Round {
+pub struct RoundHeader {
+    #[serde(default = "SerializedRound::unversioned")]
+    format_version: u64,
     #[serde(deserialize_with = "deserialize_number_from_string")]
     version: u64,
     #[serde(deserialize_with = "deserialize_number_from_string")]
     height: u64,
+    started_at: Option<DateTime<Utc>>,
+    finished_at: Option<DateTime<Utc>>,
+    contributor_ids: Vec<Participant>,
+    verifier_ids: Vec<Participant>,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    number_of_chunks: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    contributions_per_chunk: Option<u64>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    contributor_hardware: BTreeMap<Participant, HardwareDeclaration>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    round_file_hash: Option<String>,
+}
+
+impl RoundHeader {
+    /// Returns the format version the header was serialized in.
+    #[inline]
+    pub fn format_version(&self) -> u64 {
+        self.format_version
+    }
+
+    /// Returns the height of the round.
+    #[inline]
+    pub fn round_height(&self) -> u64 {
+        self.height
+    }
+
+    /// Returns the number of chunks in the round.
+    #[inline]
+    pub fn number_of_chunks(&self) -> u64 {
+        self.number_of_chunks
+    }
+
+    /// Returns the expected number of contributions in each chunk,
+    /// including the initialization contribution, as in [Round::expected_number_of_contributions].
+    #[inline]
+    pub fn expected_number_of_contributions(&self) -> u64 {
+        match self.contributions_per_chunk {
+            Some(contributions_per_chunk) => contributions_per_chunk + 1,
+            None => self.contributor_ids.len() as u64 + 1,
+        }
+    }
+}
+
+/// The statistics of a [Round], derived from its chunks and timestamps.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RoundStatistics {
+    /// The height of the round.
+    round_height: u64,
+    /// The number of contributions made in the round, excluding the initial contributions.
+    number_of_contributions: u64,
+    /// The number of successful verifications made in the round.
+    number_of_verifications: u64,
+    /// The number of distinct participants who contributed or verified in the round.
+    number_of_participants: u64,
+    /// The time the round started, if it has started.
+    started_at: Option<DateTime<Utc>>,
+    /// The time the round finished, if it has finished.
+    finished_at: Option<DateTime<Utc>>,
+    /// The number of seconds from the start of the round to its finish,
+    /// or to the time of the statistics if the round is not finished.
+    elapsed_seconds: Option<u64>,
+}
+
+impl RoundStatistics {
+    /// Returns the height of the round.
+    #[inline]
+    pub fn round_height(&self) -> u64 {
+        self.round_height
+    }
+
+    /// Returns the number of contributions made in the round.
+    #[inline]
+    pub fn number_of_contributions(&self) -> u64 {
+        self.number_of_contributions
+    }
+
+    /// Returns the number of successful verifications made in the round.
+    #[inline]
+    pub fn number_of_verifications(&self) -> u64 {
+        self.number_of_verifications
+    }
+
+    /// Returns the number of distinct participants who contributed or verified in the round.
+    #[inline]
+    pub fn number_of_participants(&self) -> u64 {
+        self.number_of_participants
+    }
+
+    /// Returns the time the round started, if it has started.
+    #[inline]
+    pub fn started_at(&self) -> Option<DateTime<Utc>> {
+        self.started_at
+    }
+
+    /// Returns the time the round finished, if it has finished.
+    #[inline]
+    pub fn finished_at(&self) -> Option<DateTime<Utc>> {
+        self.finished_at
+    }
+
+    /// Returns the number of seconds the round ran for, if it has started.
+    #[inline]
+    pub fn elapsed_seconds(&self) -> Option<u64> {
+        self.elapsed_seconds
+    }
+}
+
+/// The format version of serialized rounds.
+///
+/// Version 1 is the format of rounds serialized before the format version was recorded,
+/// which may be missing the contributions per chunk and the contributor hardware.
+pub(crate) const ROUND_FORMAT_VERSION: u64 = 2;
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize, SerdeDiff)]
+#[serde(rename_all = "camelCase", try_from = "SerializedRound")]
+pub struct Round {
+    format_version: u64,
+    version: u64,
+    height: u64,
     #[serde_diff(opaque)]
     started_at: Option<DateTime<Utc>>,
     #[serde_diff(opaque)]
@@ -80,6 +223,77 @@ pub struct Round {
     contributor_ids: Vec<Participant>,
     verifier_ids: Vec<Participant>,
     chunks: Vec<Chunk>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde_diff(opaque)]
+    contributions_per_chunk: Option<u64>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    #[serde_diff(opaque)]
+    contributor_hardware: BTreeMap<Participant, HardwareDeclaration>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde_diff(opaque)]
+    round_file_hash: Option<String>,
+}
+
+/// A round as serialized in any format version up to [ROUND_FORMAT_VERSION].
+///
+/// Fields which are missing from older format versions take their default values.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SerializedRound {
+    #[serde(default = "SerializedRound::unversioned")]
+    format_version: u64,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    version: u64,
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    height: u64,
+    #[serde(default)]
+    started_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    finished_at: Option<DateTime<Utc>>,
+    contributor_ids: Vec<Participant>,
+    verifier_ids: Vec<Participant>,
+    chunks: Vec<Chunk>,
+    #[serde(default)]
+    contributions_per_chunk: Option<u64>,
+    #[serde(default)]
+    contributor_hardware: BTreeMap<Participant, HardwareDeclaration>,
+    #[serde(default)]
+    round_file_hash: Option<String>,
+}
+
+impl SerializedRound {
+    /// Returns the format version of rounds serialized without one.
+    fn unversioned() -> u64 {
+        1
+    }
+}
+
+impl TryFrom<SerializedRound> for Round {
+    type Error = String;
+
+    fn try_from(round: SerializedRound) -> Result<Self, Self::Error> {
+        // Rounds in format version 1 take the defaults of the fields added since,
+        // which match the behavior of the coordinator that wrote them.
+        match round.format_version {
+            1 | ROUND_FORMAT_VERSION => Ok(Self {
+                format_version: ROUND_FORMAT_VERSION,
+                version: round.version,
+                height: round.height,
+                started_at: round.started_at,
+                finished_at: round.finished_at,
+                contributor_ids: round.contributor_ids,
+                verifier_ids: round.verifier_ids,
+                chunks: round.chunks,
+                contributions_per_chunk: round.contributions_per_chunk,
+                contributor_hardware: round.contributor_hardware,
+                round_file_hash: round.round_file_hash,
+            }),
+            format_version => Err(format!(
+                "Round format version {} is newer than the supported format version {}",
+                format_version, ROUND_FORMAT_VERSION
+            )),
+        }
+    }
 }
 
 impl Round {
@@ -175,6 +389,7 @@ impl Round {
         debug!("Completed creating round {}", round_height);
 
         Ok(Self {
+            format_version: ROUND_FORMAT_VERSION,
             version: environment.software_version(),
             height: round_height,
             started_at: Some(started_at),
@@ -182,6 +397,13 @@ impl Round {
             contributor_ids,
             verifier_ids,
             chunks,
+            // The initial round only holds the initialization contribution.
+            contributions_per_chunk: match round_height {
+                0 => None,
+                _ => environment.contributions_per_chunk(),
+            },
+            contributor_hardware: BTreeMap::new(),
+            round_file_hash: None,
         })
     }
 
@@ -197,6 +419,18 @@ impl Round {
         self.height
     }
 
+    /// Returns the time the round started, if it has started.
+    #[inline]
+    pub fn started_at(&self) -> Option<DateTime<Utc>> {
+        self.started_at
+    }
+
+    /// Returns the time the round finished, if it has finished.
+    #[inline]
+    pub fn finished_at(&self) -> Option<DateTime<Utc>> {
+        self.finished_at
+    }
+
     /// Returns the number of contributors authorized for this round.
     #[inline]
     pub fn number_of_contributors(&self) -> u64 {
@@ -209,6 +443,12 @@ impl Round {
         self.verifier_ids.len() as u64
     }
 
+    /// Returns the number of chunks in this round.
+    #[inline]
+    pub fn number_of_chunks(&self) -> u64 {
+        self.chunks.len() as u64
+    }
+
     /// Returns a reference to a list of contributors.
     #[inline]
     pub fn contributors(&self) -> &Vec<Participant> {
@@ -261,6 +501,9 @@ impl Round {
     ///
     #[inline]
     pub fn chunk(&self, chunk_id: u64) -> Result<&Chunk, CoordinatorError> {
+        // Check that the chunk ID is in range.
+        self.validate_chunk_id(chunk_id)?;
+
         // Fetch the chunk with the given chunk ID.
         let chunk = match self.chunks.get(chunk_id as usize) {
             Some(chunk) => chunk,
@@ -274,6 +517,41 @@ impl Round {
         }
     }
 
+    ///
+    /// Returns `Ok` if the given chunk ID is one of the chunks of this round.
+    /// Otherwise, returns `ChunkIdOutOfRange` with the number of chunks.
+    ///
+    #[inline]
+    pub fn validate_chunk_id(&self, chunk_id: u64) -> Result<(), CoordinatorError> {
+        let num_chunks = self.number_of_chunks();
+        match chunk_id < num_chunks {
+            true => Ok(()),
+            false => Err(CoordinatorError::ChunkIdOutOfRange { chunk_id, num_chunks }),
+        }
+    }
+
+    ///
+    /// Returns `Ok` if the given contribution ID can be a contribution to the given chunk
+    /// in this round, including the initial contribution.
+    ///
+    /// Otherwise, returns `ChunkIdOutOfRange` if the chunk ID is not in this round,
+    /// or `ContributionIdOutOfRange` with the expected number of contributions.
+    ///
+    #[inline]
+    pub fn validate_contribution_id(&self, chunk_id: u64, contribution_id: u64) -> Result<(), CoordinatorError> {
+        self.validate_chunk_id(chunk_id)?;
+
+        let num_contributions = self.expected_number_of_contributions();
+        match contribution_id < num_contributions {
+            true => Ok(()),
+            false => Err(CoordinatorError::ContributionIdOutOfRange {
+                chunk_id,
+                contribution_id,
+                num_contributions,
+            }),
+        }
+    }
+
     ///
     /// Returns a reference to a list of the chunks.
     ///
@@ -282,15 +560,137 @@ impl Round {
         &self.chunks
     }
 
+    ///
+    /// Returns the header of this round, which holds everything but the chunks.
+    ///
+    #[inline]
+    pub fn header(&self) -> RoundHeader {
+        RoundHeader {
+            format_version: self.format_version,
+            version: self.version,
+            height: self.height,
+            started_at: self.started_at,
+            finished_at: self.finished_at,
+            contributor_ids: self.contributor_ids.clone(),
+            verifier_ids: self.verifier_ids.clone(),
+            number_of_chunks: self.chunks.len() as u64,
+            contributions_per_chunk: self.contributions_per_chunk,
+            contributor_hardware: self.contributor_hardware.clone(),
+            round_file_hash: self.round_file_hash.clone(),
+        }
+    }
+
+    ///
+    /// Assembles a round from the given header and chunks.
+    ///
+    /// Checks that the header is in a supported format version, and that
+    /// there is one chunk for each chunk ID in the header, in order.
+    ///
+    #[inline]
+    pub(crate) fn from_header(header: RoundHeader, chunks: Vec<Chunk>) -> Result<Self, CoordinatorError> {
+        // Headers in format version 1 take the defaults of the fields added since, as in `SerializedRound`.
+        match header.format_version {
+            1 | ROUND_FORMAT_VERSION => {}
+            format_version => return Err(CoordinatorError::RoundFormatVersionUnsupported { format_version }),
+        }
+        if chunks.len() as u64 != header.number_of_chunks {
+            return Err(CoordinatorError::ChunkMissing);
+        }
+        if chunks
+            .iter()
+            .enumerate()
+            .any(|(index, chunk)| chunk.chunk_id() != index as u64)
+        {
+            return Err(CoordinatorError::ChunkIdMismatch);
+        }
+
+        Ok(Self {
+            format_version: ROUND_FORMAT_VERSION,
+            version: header.version,
+            height: header.height,
+            started_at: header.started_at,
+            finished_at: header.finished_at,
+            contributor_ids: header.contributor_ids,
+            verifier_ids: header.verifier_ids,
+            chunks,
+            contributions_per_chunk: header.contributions_per_chunk,
+            contributor_hardware: header.contributor_hardware,
+            round_file_hash: header.round_file_hash,
+        })
+    }
+
+    ///
+    /// Returns the number of contributions each chunk needs in this round, if fixed.
+    ///
+    /// Otherwise, each chunk needs one contribution from every contributor in this round.
+    ///
+    #[inline]
+    pub fn contributions_per_chunk(&self) -> Option<u64> {
+        self.contributions_per_chunk
+    }
+
+    ///
+    /// Returns the hardware each contributor in this round declared when joining the queue.
+    ///
+    /// Contributors who did not declare their hardware are not included.
+    ///
+    #[inline]
+    pub fn contributor_hardware(&self) -> &BTreeMap<Participant, HardwareDeclaration> {
+        &self.contributor_hardware
+    }
+
+    ///
+    /// Records the hardware the contributors in this round declared when joining the queue.
+    ///
+    #[inline]
+    pub(crate) fn set_contributor_hardware(
+        &mut self,
+        contributor_hardware: BTreeMap<Participant, HardwareDeclaration>,
+    ) {
+        self.contributor_hardware = contributor_hardware;
+    }
+
+    ///
+    /// Returns the hash of the round file, once the round is aggregated.
+    ///
+    #[inline]
+    pub fn round_file_hash(&self) -> Option<&str> {
+        self.round_file_hash.as_deref()
+    }
+
+    ///
+    /// Records the hash of the round file, as computed by the aggregation of the round.
+    ///
+    #[inline]
+    pub(crate) fn set_round_file_hash(&mut self, round_file_hash: &[u8]) {
+        self.round_file_hash = Some(hex::encode(round_file_hash));
+    }
+
     ///
     /// Returns the expected number of contributions.
     ///
     #[inline]
     pub fn expected_number_of_contributions(&self) -> u64 {
         // The expected number of contributions is one more than
-        // the total number of authorized contributions to account
+        // the number of contributions each chunk needs to account
         // for the initialization contribution in each round.
-        self.number_of_contributors() + 1
+        match self.contributions_per_chunk {
+            Some(contributions_per_chunk) => contributions_per_chunk + 1,
+            None => self.number_of_contributors() + 1,
+        }
+    }
+
+    ///
+    /// Returns the number of contributions which are not yet uploaded in this round,
+    /// summed over all chunks.
+    ///
+    #[inline]
+    pub fn contributions_remaining(&self) -> u64 {
+        let expected_contributions = self.expected_number_of_contributions();
+        self.chunks
+            .iter()
+            .map(|chunk| expected_contributions.saturating_sub(chunk.get_contributions().len() as u64))
+            .sum()
     }
 
     ///
@@ -344,6 +744,59 @@ impl Round {
         Ok(number_of_locks_held)
     }
 
+    ///
+    /// Returns the statistics of this round, measuring the elapsed time
+    /// up to the given time if the round is not finished.
+    ///
+    /// Contributions verified before verification records were kept
+    /// count as one verification each.
+    ///
+    #[inline]
+    pub fn statistics(&self, now: DateTime<Utc>) -> RoundStatistics {
+        let mut number_of_contributions = 0;
+        let mut number_of_verifications = 0;
+        let mut participants = HashSet::new();
+        for chunk in &self.chunks {
+            for (_, contribution) in chunk.get_contributions().iter().filter(|(id, _)| **id != 0) {
+                if let Some(contributor) = contribution.get_contributor() {
+                    number_of_contributions += 1;
+                    participants.insert(contributor.clone());
+                }
+
+                let verifiers: Vec<_> = contribution
+                    .get_verifications()
+                    .iter()
+                    .filter(|record| record.is_success())
+                    .map(|record| record.verifier())
+                    .collect();
+                match (verifiers.is_empty(), contribution.get_verifier()) {
+                    (true, Some(verifier)) if contribution.is_verified() => {
+                        number_of_verifications += 1;
+                        participants.insert(verifier.clone());
+                    }
+                    _ => {
+                        number_of_verifications += verifiers.len() as u64;
+                        participants.extend(verifiers.into_iter().cloned());
+                    }
+                }
+            }
+        }
+
+        let elapsed_seconds = self
+            .started_at
+            .map(|started_at| (self.finished_at.unwrap_or(now) - started_at).num_seconds().max(0) as u64);
+
+        RoundStatistics {
+            round_height: self.height,
+            number_of_contributions,
+            number_of_verifications,
+            number_of_participants: participants.len() as u64,
+            started_at: self.started_at,
+            finished_at: self.finished_at,
+            elapsed_seconds,
+        }
+    }
+
     ///
     /// Returns `true` if all chunks are unlocked and all contributions in all chunks
     /// have been verified. Otherwise, returns `false`.
@@ -474,6 +927,41 @@ impl Round {
         Ok(next_contribution_locator)
     }
 
+    ///
+    /// Reserves the next contribution locator for a given chunk ID, by initializing
+    /// its response file in storage in the same step as checking that it does not exist.
+    ///
+    /// As the caller holds the storage write lock, concurrent reservations of the same
+    /// locator are serialized, and every reservation after the first one fails with
+    /// `CoordinatorError::ContributionLocatorAlreadyExists`. The caller records the owner
+    /// of the reservation, and removes the placeholder before locking the chunk.
+    ///
+    #[inline]
+    pub(crate) fn reserve_next_contribution_locator(
+        &self,
+        environment: &Environment,
+        storage: &mut StorageLock,
+        chunk_id: u64,
+    ) -> Result<ContributionLocator, CoordinatorError> {
+        // Fetch the next contribution locator, which checks that it does not exist.
+        let next_contribution_locator = self.next_contribution_locator(storage, chunk_id)?;
+
+        // Initialize the response file as the placeholder of the reservation.
+        match storage.initialize(
+            Locator::ContributionFile(next_contribution_locator.clone()),
+            Object::contribution_file_size(environment, chunk_id, false),
+        ) {
+            Ok(()) => Ok(next_contribution_locator),
+            Err(CoordinatorError::StorageLocatorAlreadyExists)
+            | Err(CoordinatorError::LocatorFileAlreadyExists)
+            | Err(CoordinatorError::LocatorFileAlreadyExistsAndOpen) => {
+                tracing::error!("Contribution locator already reserved: {:?}", next_contribution_locator);
+                Err(CoordinatorError::ContributionLocatorAlreadyExists)
+            }
+            Err(error) => Err(error),
+        }
+    }
+
     ///
     /// Returns the next contribution file signature locator for a given chunk ID.
     ///
@@ -531,6 +1019,40 @@ impl Round {
         Ok(contribution_file_signature_locator)
     }
 
+    ///
+    /// Returns the next challenge locator and its contribution file signature locator,
+    /// which the verifier of the current contribution of the given chunk ID writes.
+    ///
+    /// The next challenge of the final contribution of a chunk is the initial
+    /// challenge of the chunk in the next round.
+    ///
+    #[inline]
+    pub(crate) fn next_challenge_locators(
+        &self,
+        chunk_id: u64,
+    ) -> Result<(ContributionLocator, ContributionSignatureLocator), CoordinatorError> {
+        // Fetch the current round height.
+        let current_round_height = self.round_height();
+        // Fetch the chunk corresponding to the given chunk ID.
+        let chunk = self.chunk(chunk_id)?;
+        // Fetch the current contribution ID.
+        let current_contribution_id = chunk.current_contribution_id();
+
+        // Fetch whether this is the final contribution of the specified chunk.
+        match chunk.only_contributions_complete(self.expected_number_of_contributions()) {
+            // This is the final contribution in the chunk.
+            true => Ok((
+                ContributionLocator::new(current_round_height + 1, chunk_id, 0, true),
+                ContributionSignatureLocator::new(current_round_height + 1, chunk_id, 0, true),
+            )),
+            // This is a typical contribution in the chunk.
+            false => Ok((
+                ContributionLocator::new(current_round_height, chunk_id, current_contribution_id, true),
+                ContributionSignatureLocator::new(current_round_height, chunk_id, current_contribution_id, true),
+            )),
+        }
+    }
+
     ///
     /// Attempts to acquire the lock of a given chunk ID from storage
     /// for a given participant.
@@ -628,6 +1150,7 @@ impl Round {
                     current_contribution,
                     next_contribution,
                     next_contribution_file_signature,
+                    lock_token: String::new(),
                 }
             }
             Participant::Verifier(_) => {
@@ -660,32 +1183,15 @@ impl Round {
 
                 tracing::debug!("Obtained response locator {:?}", current_contribution);
 
-                // Fetch whether this is the final contribution of the specified chunk.
-                let is_final_contribution = chunk.only_contributions_complete(self.expected_number_of_contributions());
                 // Fetch the next contribution locator and its contribution file signature locator.
-                let (next_contribution, next_contribution_file_signature) = match is_final_contribution {
-                    // This is the final contribution in the chunk.
-                    true => (
-                        ContributionLocator::new(current_round_height + 1, chunk_id, 0, true),
-                        ContributionSignatureLocator::new(current_round_height + 1, chunk_id, 0, true),
-                    ),
-                    // This is a typical contribution in the chunk.
-                    false => (
-                        ContributionLocator::new(current_round_height, chunk_id, current_contribution_id, true),
-                        ContributionSignatureLocator::new(
-                            current_round_height,
-                            chunk_id,
-                            current_contribution_id,
-                            true,
-                        ),
-                    ),
-                };
+                let (next_contribution, next_contribution_file_signature) = self.next_challenge_locators(chunk_id)?;
 
                 LockedLocators {
                     previous_contribution,
                     current_contribution,
                     next_contribution,
                     next_contribution_file_signature,
+                    lock_token: String::new(),
                 }
             }
         };
@@ -745,12 +1251,14 @@ impl Round {
     }
 
     ///
-    /// Updates the contribution corresponding to a given chunk ID and
-    /// contribution ID as verified.
+    /// Records the verification of the contribution corresponding to a given
+    /// chunk ID and contribution ID, which updates it as verified once the
+    /// given number of required verifications is reached.
     ///
     /// This function assumes the current contribution already has
     /// a verifier assigned to it.
     ///
+    #[allow(clippy::too_many_arguments)]
     #[inline]
     pub(crate) fn verify_contribution(
         &mut self,
@@ -759,19 +1267,59 @@ impl Round {
         participant: Participant,
         verified_locator: LocatorPath,
         verified_signature_locator: LocatorPath,
+        required_verifications: u64,
+        time: &dyn TimeSource,
     ) -> Result<(), CoordinatorError> {
-        // Set the current contribution as verified for the given chunk ID.
+        // Record the verification of the current contribution for the given chunk ID.
         self.chunk_mut(chunk_id)?.verify_contribution(
             contribution_id,
             participant,
             verified_locator,
             verified_signature_locator,
+            time.utc_now(),
+            required_verifications,
         )?;
 
         // If all chunks are complete and the finished at timestamp has not been set yet,
         // then set it with the current UTC timestamp.
-        self.try_finish(Utc::now());
+        self.try_finish(time.utc_now());
+
+        Ok(())
+    }
+
+    ///
+    /// Records a failed verification by the given verifier of the contribution
+    /// corresponding to a given chunk ID and contribution ID.
+    ///
+    #[inline]
+    pub(crate) fn record_failed_verification(
+        &mut self,
+        chunk_id: u64,
+        contribution_id: u64,
+        participant: &Participant,
+        time: &dyn TimeSource,
+    ) -> Result<(), CoordinatorError> {
+        self.chunk_mut(chunk_id)?
+            .get_contribution_mut(contribution_id)?
+            .add_failed_verification(participant, time.utc_now());
+        Ok(())
+    }
 
+    ///
+    /// Records the client the given participant identified themselves with,
+    /// on the task they completed for the given chunk ID and contribution ID.
+    ///
+    #[inline]
+    pub(crate) fn set_task_client(
+        &mut self,
+        chunk_id: u64,
+        contribution_id: u64,
+        participant: &Participant,
+        client: ClientVersion,
+    ) -> Result<(), CoordinatorError> {
+        self.chunk_mut(chunk_id)?
+            .get_contribution_mut(contribution_id)?
+            .set_client(participant, client);
         Ok(())
     }
 
@@ -781,6 +1329,9 @@ impl Round {
     ///
     #[inline]
     pub(crate) fn chunk_mut(&mut self, chunk_id: u64) -> Result<&mut Chunk, CoordinatorError> {
+        // Check that the chunk ID is in range.
+        self.validate_chunk_id(chunk_id)?;
+
         // Fetch the chunk with the given chunk ID.
         let chunk = match self.chunks.get_mut(chunk_id as usize) {
             Some(chunk) => chunk,
@@ -945,6 +1496,23 @@ impl Round {
                 }
             }
 
+            if participant.is_verifier() && !self.chunk(*chunk_id)?.current_contribution()?.is_verified() {
+                // Remove the next challenge locator and its contribution file signature, which were
+                // initialized when the verifier locked the chunk, so another verifier may lock it.
+                let (next_challenge, next_challenge_signature) = self.next_challenge_locators(*chunk_id)?;
+                let next_challenge = Locator::ContributionFile(next_challenge);
+                if storage.exists(&next_challenge) {
+                    storage.remove(&next_challenge)?;
+                }
+                let next_challenge_signature = Locator::ContributionFileSignature(next_challenge_signature);
+                if storage.exists(&next_challenge_signature) {
+                    storage.remove(&next_challenge_signature)?;
+                }
+            }
+
+            // Fetch the chunk corresponding to the given chunk ID.
+            let chunk = self.chunk_mut(*chunk_id)?;
+
             warn!("Removing the lock for chunk {} from {}", chunk_id, participant);
 
             // Remove the lock for each given chunk ID.
@@ -1046,6 +1614,65 @@ impl Round {
         Ok(())
     }
 
+    ///
+    /// Quarantines the given contribution, which failed verification, and removes it from its chunk,
+    /// so that its contributor may lock the chunk again to re-upload the same contribution ID.
+    ///
+    /// The unverified contribution file is moved to the given quarantine locator, and its
+    /// contribution file signature is removed. The lock held by the verifier is released.
+    ///
+    #[tracing::instrument(
+        skip(self, storage, quarantine),
+        fields(round = self.round_height())
+    )]
+    pub(crate) fn quarantine_contribution_unsafe(
+        &mut self,
+        storage: &mut StorageLock,
+        chunk_id: u64,
+        contribution_id: u64,
+        quarantine: &Locator,
+    ) -> Result<(), CoordinatorError> {
+        // Check that the contribution ID is valid.
+        self.validate_contribution_id(chunk_id, contribution_id)?;
+
+        // Check that the contribution is the current contribution of the chunk.
+        let chunk = self.chunk_mut(chunk_id)?;
+        if chunk.current_contribution_id() != contribution_id {
+            return Err(CoordinatorError::ContributionIdMismatch);
+        }
+
+        // Check that the contribution has not been verified.
+        let contribution = chunk.get_contribution(contribution_id)?.clone();
+        if contribution.is_verified() {
+            return Err(CoordinatorError::ContributionAlreadyVerified);
+        }
+
+        // Move the unverified contribution file to the quarantine locator, if it exists.
+        if let Some(locator) = contribution.get_contributed_location() {
+            let locator = storage.to_locator(&locator)?;
+            if storage.exists(&locator) {
+                storage.copy(&locator, quarantine)?;
+                storage.remove(&locator)?;
+            }
+        }
+
+        // Remove the contribution file signature, if it exists.
+        if let Some(locator) = contribution.get_contributed_signature_location() {
+            let locator = storage.to_locator(&locator)?;
+            if storage.exists(&locator) {
+                storage.remove(&locator)?;
+            }
+        }
+
+        warn!("Quarantined chunk {} contribution {}", chunk_id, contribution_id);
+
+        // Remove the contribution, and release the lock on this chunk from the verifier.
+        chunk.remove_contribution_unsafe(contribution_id);
+        chunk.set_lock_holder_unsafe(None);
+
+        Ok(())
+    }
+
     ///
     /// Adds a replacement contributor from the given environment into the round contributor IDs.
     ///
@@ -1060,8 +1687,11 @@ impl Round {
             return Err(CoordinatorError::ExpectedContributor);
         }
 
-        // Add in a replacement contributor to the set of contributor IDs, if one is available.
-        self.contributor_ids.push(participant.clone());
+        // Add in a replacement contributor to the set of contributor IDs, unless
+        // the replacement is a spare contributor who is already in the round.
+        if !self.contributor_ids.contains(&participant) {
+            self.contributor_ids.push(participant.clone());
+        }
         warn!("Added replacement contributor {} to round {}", participant, self.height);
 
         Ok(())
@@ -1188,6 +1818,48 @@ mod tests {
         assert_eq!(candidate, expected);
     }
 
+    #[test]
+    #[serial]
+    fn test_round_format_versions() {
+        initialize_test_environment(&TEST_ENVIRONMENT);
+
+        // Rounds serialized before the format version was recorded are loaded.
+        let expected = test_round_0().unwrap();
+        let round_0 = test_round_0_json().unwrap();
+        assert_eq!(ROUND_FORMAT_VERSION, round_0.format_version);
+        assert_eq!(expected, round_0);
+
+        // Older rounds missing the fields added since load with their defaults.
+        let mut older: serde_json::Value =
+            serde_json::from_str(include_str!("../testing/resources/test_round_0.json")).unwrap();
+        for field in &[
+            "startedAt",
+            "finishedAt",
+            "contributionsPerChunk",
+            "contributorHardware",
+        ] {
+            older.as_object_mut().unwrap().remove(*field);
+        }
+        let round: Round = serde_json::from_value(older).unwrap();
+        assert_eq!(ROUND_FORMAT_VERSION, round.format_version);
+        assert_eq!(None, round.started_at);
+        assert_eq!(None, round.finished_at);
+        assert_eq!(None, round.contributions_per_chunk());
+        assert!(round.contributor_hardware().is_empty());
+        assert_eq!(None, round.round_file_hash());
+        assert_eq!(expected.chunks(), round.chunks());
+
+        // Rounds are serialized with the current format version.
+        let serialized = serde_json::to_value(&expected).unwrap();
+        assert_eq!(serde_json::json!(ROUND_FORMAT_VERSION), serialized["formatVersion"]);
+        assert_eq!(expected, serde_json::from_value::<Round>(serialized.clone()).unwrap());
+
+        // Rounds from a newer format version are rejected.
+        let mut newer = serialized;
+        newer["formatVersion"] = serde_json::json!(ROUND_FORMAT_VERSION + 1);
+        assert!(serde_json::from_value::<Round>(newer).is_err());
+    }
+
     #[test]
     #[serial]
     fn test_round_height() {
@@ -1203,6 +1875,51 @@ mod tests {
         assert_eq!(1, round_1.round_height());
     }
 
+    #[test]
+    #[serial]
+    fn test_round_from_header() {
+        initialize_test_environment(&TEST_ENVIRONMENT);
+
+        let round_1 = test_round_1_partial_json().unwrap();
+        let header = round_1.header();
+        assert_eq!(1, header.round_height());
+        assert_eq!(64, header.number_of_chunks());
+
+        // The round is assembled from its header and chunks.
+        let candidate = Round::from_header(header.clone(), round_1.chunks().clone()).unwrap();
+        assert_eq!(round_1, candidate);
+
+        // Every chunk must be present, in order.
+        let mut chunks = round_1.chunks().clone();
+        chunks.pop();
+        assert!(Round::from_header(header.clone(), chunks).is_err());
+
+        let mut chunks = round_1.chunks().clone();
+        chunks.swap(0, 1);
+        assert!(Round::from_header(header.clone(), chunks).is_err());
+
+        // The header is serialized with the current format version.
+        assert_eq!(ROUND_FORMAT_VERSION, header.format_version());
+        let serialized = serde_json::to_value(&header).unwrap();
+        assert_eq!(serde_json::json!(ROUND_FORMAT_VERSION), serialized["formatVersion"]);
+
+        // Headers serialized before the format version was recorded are loaded.
+        let mut older = serialized.clone();
+        older.as_object_mut().unwrap().remove("formatVersion");
+        let older: RoundHeader = serde_json::from_value(older).unwrap();
+        assert_eq!(1, older.format_version());
+        assert_eq!(round_1, Round::from_header(older, round_1.chunks().clone()).unwrap());
+
+        // Headers from a newer format version are rejected.
+        let mut newer = serialized;
+        newer["formatVersion"] = serde_json::json!(ROUND_FORMAT_VERSION + 1);
+        let newer: RoundHeader = serde_json::from_value(newer).unwrap();
+        assert!(matches!(
+            Round::from_header(newer, round_1.chunks().clone()),
+            Err(CoordinatorError::RoundFormatVersionUnsupported { .. })
+        ));
+    }
+
     #[test]
     #[serial]
     fn test_reset_partial() {
@@ -1269,6 +1986,36 @@ mod tests {
         assert_eq!(expected, candidate);
     }
 
+    #[test]
+    #[serial]
+    fn test_validate_contribution_id() {
+        initialize_test_environment(&TEST_ENVIRONMENT);
+
+        let round_1 = test_round_1_initial_json().unwrap();
+        let num_chunks = round_1.number_of_chunks();
+        let num_contributions = round_1.expected_number_of_contributions();
+
+        // The chunk and contribution IDs are checked at both ends of their ranges.
+        for chunk_id in &[0, num_chunks - 1] {
+            for contribution_id in &[0, num_contributions - 1] {
+                assert!(round_1.validate_contribution_id(*chunk_id, *contribution_id).is_ok());
+            }
+            for contribution_id in &[num_contributions, u64::MAX] {
+                match round_1.validate_contribution_id(*chunk_id, *contribution_id) {
+                    Err(CoordinatorError::ContributionIdOutOfRange { .. }) => {}
+                    result => panic!("expected ContributionIdOutOfRange, found {:?}", result),
+                }
+            }
+        }
+        for chunk_id in &[num_chunks, u64::MAX] {
+            match round_1.validate_contribution_id(*chunk_id, 0) {
+                Err(CoordinatorError::ChunkIdOutOfRange { .. }) => {}
+                result => panic!("expected ChunkIdOutOfRange, found {:?}", result),
+            }
+            assert!(round_1.chunk(*chunk_id).is_err());
+        }
+    }
+
     #[test]
     #[serial]
     fn test_get_chunk_mut_basic() {
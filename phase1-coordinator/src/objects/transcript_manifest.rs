@@ -0,0 +1,32 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A publicly downloadable file of the ceremony transcript.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TranscriptArtifact {
+    /// The path of the file in storage.
+    pub path: String,
+    /// The size of the file in bytes.
+    pub size: u64,
+    /// The hex-encoded BLAKE2b hash of the file.
+    pub hash: String,
+    /// The time the file was last modified, if it was recorded.
+    pub modified_at: Option<DateTime<Utc>>,
+}
+
+///
+/// A page of the manifest of the ceremony transcript, for mirror operators.
+///
+/// The artifacts are sorted by path, so a mirror can sync incrementally by requesting
+/// the pages after the last path it has seen, and only downloading the artifacts
+/// whose hash differs from its copy.
+///
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TranscriptManifest {
+    /// The artifacts of this page, in the order of their paths.
+    pub artifacts: Vec<TranscriptArtifact>,
+    /// The path to request the next page after, if there are more artifacts.
+    pub next_after: Option<String>,
+}
@@ -0,0 +1,38 @@
+use crate::objects::Participant;
+
+use serde::{Deserialize, Serialize};
+
+/// The public data of a chunk needed to verify its contributions in a round.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChunkVerificationMetadata {
+    /// The ID of the chunk.
+    pub chunk_id: u64,
+    /// The hash of the initial challenge of the chunk, which is its contribution 0.
+    pub initial_hash: String,
+    /// The IDs of the verified contributions to the chunk, excluding the initial contribution.
+    pub contribution_ids: Vec<u64>,
+    /// The verifiers which successfully verified each contribution, in the order of the contribution IDs.
+    #[serde(default)]
+    pub verifiers: Vec<Vec<Participant>>,
+}
+
+///
+/// The public data of a completed round needed to verify it independently,
+/// as published by the ceremony website.
+///
+/// The hashes are the hex-encoded BLAKE2b hashes of the files, and the parameters
+/// fingerprint identifies the settings of the ceremony the round was run with.
+///
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RoundVerificationMetadata {
+    /// The height of the round.
+    pub round_height: u64,
+    /// The fingerprint of the ceremony settings.
+    pub parameters_fingerprint: String,
+    /// The initial hash and contribution IDs of each chunk, in the order of the chunk IDs.
+    pub chunks: Vec<ChunkVerificationMetadata>,
+    /// The hash of the aggregated round file.
+    pub output_hash: String,
+}
@@ -1,14 +1,16 @@
 use crate::{
-    environment::Environment,
-    objects::{ContributionFileSignature, Round},
+    environment::{CoordinatorMode, Environment},
+    objects::{Chunk, ContributionFileSignature, Round, RoundHeader},
     storage::{
         ContributionLocator,
         ContributionSignatureLocator,
         Locator,
         Object,
         ObjectReader,
+        ObjectStream,
         ObjectWriter,
         Storage,
+        StorageKey,
         StorageLocator,
         StorageObject,
     },
@@ -16,11 +18,13 @@ use crate::{
     CoordinatorState,
 };
 
+use chrono::{DateTime, Utc};
 use itertools::Itertools;
-use memmap::{MmapMut, MmapOptions};
+use memmap::{Mmap, MmapMut, MmapOptions};
 use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::{
+    borrow::Cow,
     collections::{BTreeSet, HashMap, HashSet},
     convert::TryFrom,
     fs::{self, File, OpenOptions},
@@ -33,12 +37,25 @@ use tracing::{debug, error, trace};
 
 use super::{LocatorPath, StorageAction};
 
+/// The number of bytes of a streamed object to write to its file at a time.
+const STREAM_BATCH_SIZE: usize = 1 << 20;
+
 #[derive(Debug)]
 pub struct Disk {
     environment: Environment,
     manifest: Arc<RwLock<DiskManifest>>,
     open: HashMap<Locator, Arc<RwLock<MmapMut>>>,
+    /// The read-only memory maps of the opened locators, used instead of `open` if the storage is read-only.
+    open_read_only: HashMap<Locator, Arc<Mmap>>,
     resolver: DiskResolver,
+    /// The number of bytes written to storage since it was loaded.
+    bytes_written: u64,
+    /// The last written round headers, to skip rewriting the ones an update leaves unchanged.
+    round_headers: HashMap<Locator, RoundHeader>,
+    /// The last written chunk states, to skip rewriting the ones an update leaves unchanged.
+    chunk_states: HashMap<Locator, Chunk>,
+    /// The key used to encrypt the coordinator metadata, if encryption at rest is enabled.
+    key: Option<StorageKey>,
 }
 
 impl Storage for Disk {
@@ -53,32 +70,42 @@ impl Storage for Disk {
         // Create a new `Storage` instance, and set the `Environment` and `DiskManifest`.
         let mut storage = Self {
             environment: environment.clone(),
-            manifest: Arc::new(RwLock::new(DiskManifest::load(environment.local_base_directory())?)),
+            manifest: Arc::new(RwLock::new(DiskManifest::load(
+                environment.local_base_directory(),
+                environment.is_read_only(),
+            )?)),
             open: HashMap::default(),
+            open_read_only: HashMap::default(),
             resolver: DiskResolver::new(environment.local_base_directory()),
+            bytes_written: 0,
+            round_headers: HashMap::default(),
+            chunk_states: HashMap::default(),
+            key: environment.storage_encryption_key().map(StorageKey::load).transpose()?,
         };
 
         // Open the previously opened locators in the manifest.
-        {
-            // Acquire the manifest file read lock.
-            let manifest = storage.manifest.read().unwrap();
-
-            // Open the previously opened locators in the manifest.
-            for locator in &manifest.open {
-                // Fetch the locator path.
-                let path = storage.to_path(&locator)?;
-
-                trace!("Loading {}", path);
+        storage.reopen()?;
 
-                // Fetch the locator file.
-                let file = manifest.reopen_file(locator)?;
-
-                // Load the file into memory.
-                let memory = unsafe { MmapOptions::new().map_mut(&file)? };
+        // A read-only storage leaves the storage of the primary coordinator as it is.
+        if environment.is_read_only() {
+            trace!("Loaded disk storage in read-only mode");
+            return Ok(storage);
+        }
 
-                // Add the object to the set of opened locators.
-                storage.open.insert(locator.clone(), Arc::new(RwLock::new(memory)));
-            }
+        // Split any round states stored as a single file into a round header and chunk states.
+        let round_states: Vec<Locator> = storage
+            .manifest
+            .read()
+            .unwrap()
+            .locators
+            .iter()
+            .filter(|locator| matches!(locator, Locator::RoundState { .. }))
+            .cloned()
+            .collect();
+        for locator in round_states {
+            debug!("Migrating {} to chunk states", storage.to_path(&locator)?);
+            let round = storage.get(&locator)?;
+            storage.update(&locator, round)?;
         }
 
         // Create the coordinator state locator if it does not exist yet.
@@ -96,6 +123,8 @@ impl Storage for Disk {
     /// Initializes the location corresponding to the given locator.
     #[inline]
     fn initialize(&mut self, locator: Locator, size: u64) -> Result<(), CoordinatorError> {
+        self.check_writable()?;
+
         let locator_path = self.to_path(&locator)?;
         trace!("Initializing {:?}", locator_path);
 
@@ -130,7 +159,8 @@ impl Storage for Disk {
     /// Returns `true` if a given locator exists in storage. Otherwise, returns `false`.
     #[inline]
     fn exists(&self, locator: &Locator) -> bool {
-        let is_in_manifest = self.manifest.read().unwrap().contains(locator);
+        let locator = self.resolve(locator);
+        let is_in_manifest = self.manifest.read().unwrap().contains(&locator);
         #[cfg(test)]
         trace!("Checking if locator exists in storage (manifest = {})", is_in_manifest,);
         is_in_manifest
@@ -139,8 +169,9 @@ impl Storage for Disk {
     /// Returns `true` if a given locator is opened in storage. Otherwise, returns `false`.
     #[inline]
     fn is_open(&self, locator: &Locator) -> bool {
-        let is_in_manifest = self.manifest.read().unwrap().contains(locator);
-        let is_in_locators = self.open.contains_key(locator);
+        let locator = self.resolve(locator);
+        let is_in_manifest = self.manifest.read().unwrap().contains(&locator);
+        let is_in_locators = self.open.contains_key(&locator) || self.open_read_only.contains_key(&locator);
         #[cfg(test)]
         trace!(
             "Checking if locator file is opened in storage (manifest = {}, locators = {})",
@@ -167,13 +198,17 @@ impl Storage for Disk {
             return Err(CoordinatorError::StorageLocatorNotOpen);
         }
 
-        // Acquire the file read lock.
-        let reader = self
-            .open
-            .get(locator)
-            .ok_or(CoordinatorError::StorageLockFailed)?
-            .read()
-            .unwrap();
+        // Assemble the round state from its header and chunk states.
+        if let Locator::RoundState { round_height } = locator {
+            if self.is_round_state_split(*round_height) {
+                let round = self.get_round_state(*round_height)?;
+                trace!("Fetched {}", self.to_path(locator)?);
+                return Ok(Object::RoundState(round));
+            }
+        }
+
+        // Copy the file, decrypting it if it is encrypted.
+        let reader = self.read_plaintext(locator)?;
 
         let object = match locator {
             Locator::CoordinatorState => {
@@ -188,6 +223,14 @@ impl Storage for Disk {
                 let round: Round = serde_json::from_slice(&*reader)?;
                 Ok(Object::RoundState(round))
             }
+            Locator::RoundHeader { round_height: _ } => {
+                let header: RoundHeader = serde_json::from_slice(&*reader)?;
+                Ok(Object::RoundHeader(header))
+            }
+            Locator::ChunkState { .. } => {
+                let chunk: Chunk = serde_json::from_slice(&*reader)?;
+                Ok(Object::ChunkState(chunk))
+            }
             Locator::RoundFile { round_height } => {
                 // Check that the round size is correct.
                 let expected = Object::round_file_size(&self.environment);
@@ -198,9 +241,7 @@ impl Storage for Disk {
                     return Err(CoordinatorError::RoundFileSizeMismatch.into());
                 }
 
-                let mut round_file: Vec<u8> = Vec::with_capacity(expected as usize);
-                round_file.write_all(&*reader)?;
-                Ok(Object::RoundFile(round_file))
+                Ok(Object::RoundFile(reader))
             }
             Locator::ContributionFile(contribution_locator) => {
                 // Check that the contribution size is correct.
@@ -221,9 +262,7 @@ impl Storage for Disk {
                     return Err(CoordinatorError::ContributionFileSizeMismatch.into());
                 }
 
-                let mut contribution_file: Vec<u8> = Vec::with_capacity(expected as usize);
-                contribution_file.write_all(&*reader)?;
-                Ok(Object::ContributionFile(contribution_file))
+                Ok(Object::ContributionFile(reader))
             }
             Locator::ContributionFileSignature(contribution_locator) => {
                 // Check that the contribution file signature size is correct.
@@ -247,6 +286,12 @@ impl Storage for Disk {
                 let contribution_file_signature: ContributionFileSignature = serde_json::from_slice(&*reader)?;
                 Ok(Object::ContributionFileSignature(contribution_file_signature))
             }
+            Locator::PracticeChallenge => Ok(Object::PracticeChallenge(reader)),
+            Locator::Health => {
+                let updated_at: DateTime<Utc> = serde_json::from_slice(&*reader)?;
+                Ok(Object::Health(updated_at))
+            }
+            Locator::QuarantinedContributionFile { .. } => Ok(Object::ContributionFile(reader)),
         };
 
         trace!("Fetched {}", self.to_path(locator)?);
@@ -256,6 +301,8 @@ impl Storage for Disk {
     /// Inserts a new object at the given locator into storage, if it does not exist.
     #[inline]
     fn insert(&mut self, locator: Locator, object: Object) -> Result<(), CoordinatorError> {
+        self.check_writable()?;
+
         trace!("Inserting {}", self.to_path(&locator)?);
 
         // Check that the given locator does not exist in storage.
@@ -270,11 +317,23 @@ impl Storage for Disk {
             return Err(CoordinatorError::StorageLocatorAlreadyExistsAndOpen);
         }
 
+        // Store the round state as a round header and chunk states.
+        if let (Locator::RoundState { .. }, Object::RoundState(round)) = (&locator, &object) {
+            self.update_round_state(round)?;
+            trace!("Inserted {}", self.to_path(&locator)?);
+            return Ok(());
+        }
+
         // Initialize the new file with the object size.
         self.initialize(locator.clone(), object.size())?;
 
-        // Insert the object at the given locator.
-        self.update(&locator, object)?;
+        // Insert the object at the given locator, and remove the new file if the object fails to be written.
+        if let Err(error) = self.update(&locator, object) {
+            if let Err(remove_error) = self.remove_file(&locator) {
+                error!("Failed to remove {:?} after a failed insert: {}", locator, remove_error);
+            }
+            return Err(error);
+        }
 
         trace!("Inserted {}", self.to_path(&locator)?);
         Ok(())
@@ -283,6 +342,8 @@ impl Storage for Disk {
     /// Updates an existing object for the given locator in storage, if it exists.
     #[inline]
     fn update(&mut self, locator: &Locator, object: Object) -> Result<(), CoordinatorError> {
+        self.check_writable()?;
+
         trace!("Updating {}", self.to_path(locator)?);
 
         // Check that the given locator exists in storage.
@@ -297,28 +358,15 @@ impl Storage for Disk {
             return Err(CoordinatorError::StorageLocatorNotOpen);
         }
 
-        // Acquire the file write lock.
-        let mut writer = self
-            .open
-            .get(locator)
-            .ok_or(CoordinatorError::StorageLockFailed)?
-            .write()
-            .unwrap();
-
-        // Acquire the manifest file write lock.
-        let mut manifest = self.manifest.write().unwrap();
-
-        // Resize the file to the given object size.
-        let file = manifest.resize_file(&locator, object.size())?;
-
-        // Update the writer.
-        *writer = unsafe { MmapOptions::new().map_mut(&file)? };
-
-        // Write the new object to the file.
-        (*writer).as_mut().write_all(&object.to_bytes())?;
-
-        // Sync all in-memory data to disk.
-        writer.flush()?;
+        match (locator, &object) {
+            // Only rewrite the parts of the round state that changed.
+            (Locator::RoundState { .. }, Object::RoundState(round)) => self.update_round_state(round)?,
+            // Encrypted files are encrypted as a whole, so only unencrypted files are streamed.
+            (_, Object::Stream(stream)) if !self.encrypts(locator) => self.write_stream(locator, stream)?,
+            // Replace metadata in the same change that records whether it is encrypted.
+            _ if Self::is_metadata(locator) => self.replace(locator, &object.to_bytes()?)?,
+            _ => self.write(locator, &object.to_bytes()?)?,
+        }
 
         trace!("Updated {}", self.to_path(&locator)?);
         Ok(())
@@ -327,6 +375,8 @@ impl Storage for Disk {
     /// Copies an object from the given source locator to the given destination locator.
     #[inline]
     fn copy(&mut self, source_locator: &Locator, destination_locator: &Locator) -> Result<(), CoordinatorError> {
+        self.check_writable()?;
+
         trace!(
             "Copying from A to B\n\n\tA: {}\n\tB: {}\n",
             self.to_path(source_locator)?,
@@ -348,11 +398,8 @@ impl Storage for Disk {
         // Fetch the source object.
         let source_object = self.get(source_locator)?;
 
-        // Initialize the destination file with the source object size.initializeinitialize
-        self.initialize(destination_locator.clone(), source_object.size())?;
-
-        // Update the destination locator with the copied source object.
-        self.update(destination_locator, source_object)?;
+        // Insert the copied source object at the destination locator.
+        self.insert(destination_locator.clone(), source_object)?;
 
         trace!("Copied to {}", self.to_path(destination_locator)?);
         Ok(())
@@ -361,6 +408,8 @@ impl Storage for Disk {
     /// Removes the object corresponding to the given locator from storage.
     #[inline]
     fn remove(&mut self, locator: &Locator) -> Result<(), CoordinatorError> {
+        self.check_writable()?;
+
         trace!("Removing {}", self.to_path(locator)?);
 
         // Check that the locator exists in storage.
@@ -369,25 +418,18 @@ impl Storage for Disk {
             return Err(CoordinatorError::StorageLocatorMissing);
         }
 
-        // Acquire the manifest file write lock.
-        let mut manifest = self.manifest.write().unwrap();
-
-        // Acquire the file write lock.
-        let file = self
-            .open
-            .get(locator)
-            .ok_or(CoordinatorError::StorageLockFailed)?
-            .write()
-            .unwrap();
-
-        // Remove the locator from the manifest.
-        manifest.remove_file(locator)?;
-
-        // Remove the file write lock.
-        drop(file);
-
-        // Remove the locator from the locators.
-        self.open.remove(locator);
+        match locator {
+            // Remove the round header first, so a partially removed round state is no longer found.
+            Locator::RoundState { round_height } if self.is_round_state_split(*round_height) => {
+                self.remove_file(&Locator::RoundHeader {
+                    round_height: *round_height,
+                })?;
+                for chunk_state in self.chunk_states(*round_height) {
+                    self.remove_file(&chunk_state)?;
+                }
+            }
+            _ => self.remove_file(locator)?,
+        }
 
         trace!("Removed {}", self.to_path(locator)?);
         Ok(())
@@ -404,11 +446,26 @@ impl Storage for Disk {
             return Err(CoordinatorError::StorageLocatorMissing);
         }
 
+        // Fetch the files of the locator, as a round state may span a round header and chunk states.
+        let locators = match locator {
+            Locator::RoundState { round_height } if self.is_round_state_split(*round_height) => {
+                let mut locators = vec![Locator::RoundHeader {
+                    round_height: *round_height,
+                }];
+                locators.extend(self.chunk_states(*round_height));
+                locators
+            }
+            _ => vec![*locator],
+        };
+
         // Acquire the manifest file read lock.
         let manifest = self.manifest.read().unwrap();
 
         // Fetch the file size.
-        let size = manifest.size(locator)?;
+        let size = locators
+            .iter()
+            .map(|locator| manifest.size(locator))
+            .sum::<Result<u64, CoordinatorError>>()?;
 
         trace!("Fetched size of {}", self.to_path(&locator)?);
         Ok(size)
@@ -423,6 +480,583 @@ impl Storage for Disk {
             StorageAction::Update(update_action) => self.update(&update_action.locator, update_action.object),
         }
     }
+
+    /// Reloads the manifest and the opened locators from disk.
+    #[inline]
+    fn refresh(&mut self) -> Result<(), CoordinatorError> {
+        trace!("Refreshing disk storage");
+
+        // Reload the manifest, which another coordinator may have updated.
+        *self.manifest.write().unwrap() =
+            DiskManifest::load(self.environment.local_base_directory(), self.environment.is_read_only())?;
+
+        // Remap the opened locators, as their files may have been replaced or resized.
+        self.open.clear();
+        self.open_read_only.clear();
+        self.reopen()?;
+
+        // The cached round states may no longer match the files on disk.
+        self.round_headers.clear();
+        self.chunk_states.clear();
+
+        trace!("Refreshed disk storage");
+        Ok(())
+    }
+}
+
+impl Disk {
+    /// Returns an error if the storage is read-only, as the coordinator is not the primary.
+    #[inline]
+    fn check_writable(&self) -> Result<(), CoordinatorError> {
+        match self.environment.coordinator_mode() {
+            CoordinatorMode::Primary => Ok(()),
+            CoordinatorMode::ReadOnly { primary_url } => {
+                error!("Storage is read-only, mutations are handled by {}", primary_url);
+                Err(CoordinatorError::CoordinatorReadOnly {
+                    primary_url: primary_url.clone(),
+                })
+            }
+        }
+    }
+
+    /// Maps the opened locators in the manifest into memory.
+    ///
+    /// A read-only storage opens and maps the files read-only, as they belong to the primary coordinator.
+    #[inline]
+    fn reopen(&mut self) -> Result<(), CoordinatorError> {
+        // Acquire the manifest file read lock.
+        let manifest = self.manifest.read().unwrap();
+
+        // Open the previously opened locators in the manifest.
+        for locator in &manifest.open {
+            // Fetch the locator path.
+            let path = self.to_path(&locator)?;
+
+            trace!("Loading {}", path);
+
+            if self.environment.is_read_only() {
+                // Fetch the locator file, and load it into memory.
+                let file = manifest.reopen_file_read_only(locator)?;
+                let memory = unsafe { MmapOptions::new().map(&file)? };
+
+                // Add the object to the set of opened locators.
+                self.open_read_only.insert(locator.clone(), Arc::new(memory));
+                continue;
+            }
+
+            // Fetch the locator file.
+            let file = manifest.reopen_file(locator)?;
+
+            // Load the file into memory.
+            let memory = unsafe { MmapOptions::new().map_mut(&file)? };
+
+            // Add the object to the set of opened locators.
+            self.open.insert(locator.clone(), Arc::new(RwLock::new(memory)));
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Returns the number of bytes written to storage since it was loaded.
+    ///
+    #[inline]
+    pub fn bytes_written(&self) -> u64 {
+        self.bytes_written
+    }
+
+    ///
+    /// Re-encrypts the coordinator metadata in storage with the given key, or
+    /// decrypts it if no key is given.
+    ///
+    /// This must only be run by an operator while the coordinator is stopped, with storage
+    /// loaded using the current key, as the `rotate_storage_key` example does. The coordinator
+    /// must then be restarted with the new key. Every file is rewritten as a single change to
+    /// storage, so if this fails, storage is left encrypted with the current key.
+    ///
+    #[inline]
+    pub fn rotate_encryption_key(&mut self, key: Option<StorageKey>) -> Result<(), CoordinatorError> {
+        // Decrypt every locator that holds coordinator metadata with the current key.
+        let locators: Vec<Locator> = self
+            .manifest
+            .read()
+            .unwrap()
+            .locators
+            .iter()
+            .filter(|locator| Self::is_metadata(locator))
+            .cloned()
+            .collect();
+        let plaintexts = locators
+            .into_iter()
+            .map(|locator| Ok((locator, self.read_plaintext(&locator)?)))
+            .collect::<Result<Vec<(Locator, Vec<u8>)>, CoordinatorError>>()?;
+
+        // Write them back with the new key, and keep the current key if they fail to be written.
+        debug!("Rotating the encryption key of {} files", plaintexts.len());
+        let key = std::mem::replace(&mut self.key, key);
+        if let Err(error) = self.replace_all(plaintexts) {
+            self.key = key;
+            return Err(error);
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Returns the locator of the file that backs the given locator.
+    ///
+    /// A round state is backed by its round header once it is stored as chunk states,
+    /// and by a single file before it has been migrated.
+    ///
+    #[inline]
+    fn resolve(&self, locator: &Locator) -> Locator {
+        match locator {
+            Locator::RoundState { round_height } if self.is_round_state_split(*round_height) => Locator::RoundHeader {
+                round_height: *round_height,
+            },
+            _ => *locator,
+        }
+    }
+
+    ///
+    /// Returns `true` if the round state for the given round height is stored
+    /// as a round header and chunk states. Otherwise, returns `false`.
+    ///
+    #[inline]
+    fn is_round_state_split(&self, round_height: u64) -> bool {
+        self.manifest
+            .read()
+            .unwrap()
+            .contains(&Locator::RoundHeader { round_height })
+    }
+
+    ///
+    /// Returns the locators of all chunk states in storage for the given round height.
+    ///
+    #[inline]
+    fn chunk_states(&self, round_height: u64) -> Vec<Locator> {
+        self.manifest
+            .read()
+            .unwrap()
+            .locators
+            .iter()
+            .filter(|locator| match locator {
+                Locator::ChunkState {
+                    round_height: height, ..
+                } => *height == round_height,
+                _ => false,
+            })
+            .cloned()
+            .collect()
+    }
+
+    ///
+    /// Returns the round assembled from the round header and chunk states for the given round height.
+    ///
+    #[inline]
+    fn get_round_state(&self, round_height: u64) -> Result<Round, CoordinatorError> {
+        let header = match self.get(&Locator::RoundHeader { round_height })? {
+            Object::RoundHeader(header) => header,
+            _ => return Err(CoordinatorError::StorageFailed),
+        };
+
+        let chunks = (0..header.number_of_chunks())
+            .map(
+                |chunk_id| match self.get(&Locator::ChunkState { round_height, chunk_id })? {
+                    Object::ChunkState(chunk) => Ok(chunk),
+                    _ => Err(CoordinatorError::StorageFailed),
+                },
+            )
+            .collect::<Result<Vec<Chunk>, CoordinatorError>>()?;
+
+        Round::from_header(header, chunks)
+    }
+
+    ///
+    /// Stores the given round as a round header and one chunk state per chunk.
+    ///
+    /// Only the round header and chunk states that changed since they were last written
+    /// are serialized and written, so an update to a single chunk no longer rewrites the
+    /// state of the whole round. The changed files are replaced as a single change to storage,
+    /// so a crash never leaves the chunk states of one update next to the round header of
+    /// another. Any round state file from before the migration is removed last.
+    ///
+    #[inline]
+    fn update_round_state(&mut self, round: &Round) -> Result<(), CoordinatorError> {
+        let round_height = round.round_height();
+        let mut objects = vec![];
+
+        // Serialize the chunk states that changed since they were last written.
+        let mut chunks = vec![];
+        for chunk in round.chunks() {
+            let locator = Locator::ChunkState {
+                round_height,
+                chunk_id: chunk.chunk_id(),
+            };
+            if self.chunk_states.get(&locator) != Some(chunk) {
+                if let Some(bytes) = self.changed_bytes(&locator, Object::ChunkState(chunk.clone()))? {
+                    objects.push((locator, bytes));
+                }
+                chunks.push((locator, chunk.clone()));
+            }
+        }
+
+        // Serialize the round header, if it changed since it was last written.
+        let header_locator = Locator::RoundHeader { round_height };
+        let header = round.header();
+        let is_header_changed = self.round_headers.get(&header_locator) != Some(&header);
+        if is_header_changed {
+            if let Some(bytes) = self.changed_bytes(&header_locator, Object::RoundHeader(header.clone()))? {
+                objects.push((header_locator, bytes));
+            }
+        }
+
+        // Replace the changed files together.
+        if !objects.is_empty() {
+            self.replace_all(objects)?;
+        }
+        self.chunk_states.extend(chunks);
+        if is_header_changed {
+            self.round_headers.insert(header_locator, header);
+        }
+
+        // Remove the round state file, if the round was stored before it was split.
+        let locator = Locator::RoundState { round_height };
+        let is_in_manifest = self.manifest.read().unwrap().contains(&locator);
+        if is_in_manifest {
+            self.remove_file(&locator)?;
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Returns the serialized bytes of the given object, or `None` if the locator already holds
+    /// the same bytes.
+    ///
+    /// The bytes are also returned if the locator is not encrypted as the current key requires.
+    ///
+    #[inline]
+    fn changed_bytes(&self, locator: &Locator, object: Object) -> Result<Option<Vec<u8>>, CoordinatorError> {
+        let bytes = object.to_bytes()?;
+
+        if self.exists(locator) {
+            let is_encrypted = self.manifest.read().unwrap().is_encrypted(locator);
+            if is_encrypted == self.encrypts(locator) && self.read_plaintext(locator)? == bytes {
+                return Ok(None);
+            }
+        }
+
+        Ok(Some(bytes))
+    }
+
+    ///
+    /// Atomically replaces the file for the given locator with the given bytes,
+    /// encrypting them if the locator holds coordinator metadata and a key is set.
+    ///
+    #[inline]
+    fn replace(&mut self, locator: &Locator, bytes: &[u8]) -> Result<(), CoordinatorError> {
+        self.replace_all(vec![(*locator, bytes.to_vec())])
+    }
+
+    ///
+    /// Replaces the files for the given locators with the given bytes as a single change to storage,
+    /// encrypting them if the locator holds coordinator metadata and a key is set.
+    ///
+    /// Locators that do not exist yet are created. If the change fails, the existing files are
+    /// mapped again as they are on disk.
+    ///
+    #[inline]
+    fn replace_all(&mut self, objects: Vec<(Locator, Vec<u8>)>) -> Result<(), CoordinatorError> {
+        let files = objects
+            .iter()
+            .map(|(locator, bytes)| Ok((*locator, self.encode(locator, bytes)?, self.encrypts(locator))))
+            .collect::<Result<Vec<(Locator, Cow<[u8]>, bool)>, CoordinatorError>>()?;
+        let size: u64 = files.iter().map(|(_, bytes, _)| bytes.len() as u64).sum();
+
+        {
+            // Acquire the file write locks of the existing locators.
+            let memories: Vec<(Locator, Arc<RwLock<MmapMut>>)> = files
+                .iter()
+                .filter_map(|(locator, _, _)| self.open.get(locator).map(|memory| (*locator, memory.clone())))
+                .collect();
+            let mut writers: Vec<_> = memories
+                .iter()
+                .map(|(locator, memory)| (locator, memory.write().unwrap()))
+                .collect();
+
+            // Acquire the manifest file write lock.
+            let mut manifest = self.manifest.write().unwrap();
+
+            // Unmap the files before they are replaced, as replacing a file with an active memory map fails on Windows.
+            for (_, writer) in writers.iter_mut() {
+                **writer = MmapOptions::new().len(1).map_anon()?;
+            }
+
+            // Replace the files, and map the existing files again, whether or not they were replaced.
+            let replaced = manifest.replace_files(&files);
+            for (locator, writer) in writers.iter_mut() {
+                **writer = unsafe { MmapOptions::new().map_mut(&manifest.reopen_file(locator)?)? };
+            }
+            replaced?;
+
+            // Map the files of the new locators.
+            for (locator, _, _) in &files {
+                if !self.open.contains_key(locator) {
+                    let file = manifest.reopen_file(locator)?;
+                    self.open.insert(
+                        *locator,
+                        Arc::new(RwLock::new(unsafe { MmapOptions::new().map_mut(&file)? })),
+                    );
+                }
+            }
+        }
+
+        self.bytes_written += size;
+        Ok(())
+    }
+
+    ///
+    /// Writes the given bytes to the file for the given locator, resizing the file to fit.
+    ///
+    #[inline]
+    fn write(&mut self, locator: &Locator, bytes: &[u8]) -> Result<(), CoordinatorError> {
+        let is_encrypted = self.encrypts(locator);
+        let bytes = self.encode(locator, bytes)?;
+
+        {
+            // Acquire the file write lock.
+            let mut writer = self
+                .open
+                .get(locator)
+                .ok_or(CoordinatorError::StorageLockFailed)?
+                .write()
+                .unwrap();
+
+            // Acquire the manifest file write lock.
+            let mut manifest = self.manifest.write().unwrap();
+
+            // Resize the file to the given object size.
+            let file = manifest.resize_file(&locator, bytes.len() as u64)?;
+
+            // Update the writer.
+            *writer = unsafe { MmapOptions::new().map_mut(&file)? };
+
+            // Write the new object to the file.
+            (*writer).as_mut().write_all(&bytes)?;
+
+            // Sync all in-memory data to disk.
+            writer.flush()?;
+
+            // Record whether the file is encrypted.
+            manifest.set_encrypted(locator, is_encrypted)?;
+        }
+
+        self.bytes_written += bytes.len() as u64;
+        Ok(())
+    }
+
+    ///
+    /// Writes the contents of the given stream to the file for the given locator,
+    /// reading them in batches rather than all at once.
+    ///
+    /// The contents are written to a replacement of the file, which only takes the place of
+    /// the file once the stream was read in full, so a stream which fails or ends early
+    /// leaves the file as it was.
+    ///
+    #[inline]
+    fn write_stream(&mut self, locator: &Locator, stream: &ObjectStream) -> Result<(), CoordinatorError> {
+        let size = stream.size();
+
+        {
+            // Acquire the file write lock.
+            let mut writer = self
+                .open
+                .get(locator)
+                .ok_or(CoordinatorError::StorageLockFailed)?
+                .write()
+                .unwrap();
+
+            // Acquire the manifest file write lock.
+            let mut manifest = self.manifest.write().unwrap();
+
+            // Unmap the file before it is replaced, as replacing a file with an active memory map fails on Windows.
+            *writer = MmapOptions::new().len(1).map_anon()?;
+
+            // Write the contents to the replacement of the file, one batch at a time.
+            let file = match manifest.replace_file_with(locator, |file| {
+                stream.read_batches(STREAM_BATCH_SIZE, |batch| {
+                    file.write_all(batch)?;
+                    Ok(())
+                })
+            }) {
+                Ok(file) => file,
+                Err(error) => {
+                    // Map the file again, as it was left in place.
+                    *writer = unsafe { MmapOptions::new().map_mut(&manifest.reopen_file(locator)?)? };
+                    return Err(error);
+                }
+            };
+
+            // Update the writer.
+            *writer = unsafe { MmapOptions::new().map_mut(&file)? };
+
+            // Record that the file is not encrypted.
+            manifest.set_encrypted(locator, false)?;
+        }
+
+        self.bytes_written += size;
+        Ok(())
+    }
+
+    ///
+    /// Unmaps and deletes the file for the given locator.
+    ///
+    /// Readers of the locator may be held by other threads after the storage lock is released,
+    /// so the write lock of the memory map is acquired first, which waits for them to be dropped.
+    /// If the manifest fails to delete the file, the locator is mapped again and an error is returned.
+    ///
+    #[inline]
+    fn remove_file(&mut self, locator: &Locator) -> Result<(), CoordinatorError> {
+        // Remove the locator from the open locators, so no new readers are created for it.
+        let memory = self.open.remove(locator).ok_or(CoordinatorError::StorageLockFailed)?;
+
+        // Acquire the file write lock, which waits for the readers of the locator to be dropped.
+        let mut writer = memory.write().unwrap();
+
+        // Acquire the manifest file write lock.
+        let mut manifest = self.manifest.write().unwrap();
+
+        // Unmap the file before it is deleted, as deleting a file with an active memory map fails on Windows.
+        *writer = MmapOptions::new().len(1).map_anon()?;
+        drop(writer);
+        drop(memory);
+
+        // Remove the locator from the manifest.
+        if let Err(error) = manifest.remove_file(locator) {
+            // Map the file again if it was not deleted, so the locator remains usable.
+            if manifest.contains(locator) {
+                let file = manifest.reopen_file(locator)?;
+                let memory = unsafe { MmapOptions::new().map_mut(&file)? };
+                self.open.insert(*locator, Arc::new(RwLock::new(memory)));
+            }
+            return Err(error);
+        }
+
+        // Forget the last written state of the locator, if it is a round header or chunk state.
+        self.round_headers.remove(locator);
+        self.chunk_states.remove(locator);
+
+        Ok(())
+    }
+
+    ///
+    /// Returns `true` if the given locator holds coordinator metadata. Otherwise, returns `false`.
+    ///
+    /// The coordinator state, round states, round headers and chunk states hold the IDs and
+    /// IP addresses of participants. The remaining locators are public, and contribution
+    /// file signatures must have a fixed size, so they are never encrypted.
+    ///
+    #[inline]
+    fn is_metadata(locator: &Locator) -> bool {
+        matches!(
+            locator,
+            Locator::CoordinatorState
+                | Locator::RoundState { .. }
+                | Locator::RoundHeader { .. }
+                | Locator::ChunkState { .. }
+        )
+    }
+
+    ///
+    /// Returns `true` if the given locator is encrypted when it is written. Otherwise, returns `false`.
+    ///
+    #[inline]
+    fn encrypts(&self, locator: &Locator) -> bool {
+        self.key.is_some() && Self::is_metadata(locator)
+    }
+
+    ///
+    /// Returns the bytes to store for the given locator, encrypted if the locator is encrypted.
+    ///
+    #[inline]
+    fn encode<'a>(&self, locator: &Locator, bytes: &'a [u8]) -> Result<Cow<'a, [u8]>, CoordinatorError> {
+        match &self.key {
+            Some(key) if self.encrypts(locator) => Ok(Cow::Owned(key.encrypt(bytes)?)),
+            _ => Ok(Cow::Borrowed(bytes)),
+        }
+    }
+
+    ///
+    /// Returns a decrypted copy of the file for the given locator.
+    ///
+    /// The manifest records which files are encrypted, so files written before
+    /// encryption was enabled are read as they are.
+    ///
+    #[inline]
+    fn read_plaintext(&self, locator: &Locator) -> Result<Vec<u8>, CoordinatorError> {
+        let reader = self.read(locator)?;
+
+        match self.manifest.read().unwrap().is_encrypted(locator) {
+            true => match &self.key {
+                Some(key) => key.decrypt(&reader),
+                None => {
+                    error!(
+                        "{} is encrypted, but no storage encryption key is set",
+                        self.to_path(locator)?
+                    );
+                    Err(CoordinatorError::StorageEncryptionKeyMissing)
+                }
+            },
+            false => Ok(reader.to_vec()),
+        }
+    }
+
+    ///
+    /// Checks that the memory map for the given locator has the same length as the file on disk.
+    ///
+    /// Accessing a page of a memory map that is no longer backed by the file raises a SIGBUS,
+    /// which would take down the coordinator. This check must pass while the read or write lock
+    /// of the memory map is held, before a reader or writer is handed out, see [Disk::read].
+    ///
+    #[inline]
+    fn check_mapped_size(&self, locator: &Locator, mapped_size: usize) -> Result<(), CoordinatorError> {
+        let found = self.size(locator)?;
+        if found != mapped_size as u64 {
+            error!(
+                "{} is mapped with size {} but has size {} on disk",
+                self.to_path(locator)?,
+                mapped_size,
+                found
+            );
+            return Err(CoordinatorError::StorageMappedSizeMismatch);
+        }
+        Ok(())
+    }
+
+    ///
+    /// Returns a reader of the memory map of the opened file for the given locator.
+    ///
+    /// The reader holds the read lock of the memory map, and the memory map is checked to
+    /// span the file on disk, so the reader never accesses a page that is not backed by the
+    /// file. A read-only storage never writes to its files, so its readers hold no lock.
+    ///
+    #[inline]
+    fn read(&self, locator: &Locator) -> Result<ObjectReader, CoordinatorError> {
+        let reader = match self.open_read_only.get(locator) {
+            Some(memory) => ObjectReader::read_only(memory.clone()),
+            None => ObjectReader::new(
+                self.open
+                    .get(locator)
+                    .ok_or(CoordinatorError::StorageLockFailed)?
+                    .clone(),
+            ),
+        };
+
+        // Check that the mapped memory still spans the file on disk.
+        self.check_mapped_size(locator, reader.len())?;
+        Ok(reader)
+    }
 }
 
 impl StorageLocator for Disk {
@@ -448,24 +1082,32 @@ impl StorageObject for Disk {
             return Err(CoordinatorError::StorageLocatorMissing);
         }
 
+        // Check that the locator is not a round state stored as chunk states.
+        if let Locator::RoundState { round_height } = locator {
+            if self.is_round_state_split(*round_height) {
+                error!(
+                    "Round {} state is stored as chunk states and has no reader",
+                    round_height
+                );
+                return Err(CoordinatorError::StorageReaderFailed);
+            }
+        }
+
         // Check that the given locator is opened in storage.
         if !self.is_open(locator) {
             error!("Locator in call to reader() is not opened in storage.");
             return Err(CoordinatorError::StorageLocatorNotOpen);
         }
 
-        // Acquire the file read lock.
-        let reader = self
-            .open
-            .get(locator)
-            .ok_or(CoordinatorError::StorageLockFailed)?
-            .read()
-            .unwrap();
+        // Acquire the file read lock, and check that the mapped memory still spans the file on disk.
+        let reader = self.read(locator)?;
 
         match locator {
             Locator::CoordinatorState => Ok(reader),
             Locator::RoundHeight => Ok(reader),
             Locator::RoundState { round_height: _ } => Ok(reader),
+            Locator::RoundHeader { round_height: _ } => Ok(reader),
+            Locator::ChunkState { .. } => Ok(reader),
             Locator::RoundFile { round_height } => {
                 // Check that the round size is correct.
                 let expected = Object::round_file_size(&self.environment);
@@ -498,12 +1140,17 @@ impl StorageObject for Disk {
                 Ok(reader)
             }
             Locator::ContributionFileSignature(_) => Ok(reader),
+            Locator::PracticeChallenge => Ok(reader),
+            Locator::Health => Ok(reader),
+            Locator::QuarantinedContributionFile { .. } => Ok(reader),
         }
     }
 
     /// Returns an object writer for the given locator.
     #[inline]
     fn writer(&self, locator: &Locator) -> Result<ObjectWriter, CoordinatorError> {
+        self.check_writable()?;
+
         // Check that the locator exists in storage.
         if !self.exists(&locator) {
             let locator = self.to_path(&locator)?;
@@ -511,6 +1158,17 @@ impl StorageObject for Disk {
             return Err(CoordinatorError::StorageLocatorMissing);
         }
 
+        // Check that the locator is not a round state stored as chunk states.
+        if let Locator::RoundState { round_height } = locator {
+            if self.is_round_state_split(*round_height) {
+                error!(
+                    "Round {} state is stored as chunk states and has no writer",
+                    round_height
+                );
+                return Err(CoordinatorError::StorageUpdateFailed);
+            }
+        }
+
         // Check that the given locator is opened in storage.
         if !self.is_open(locator) {
             error!("Locator in call to writer() is not opened in storage.");
@@ -525,10 +1183,15 @@ impl StorageObject for Disk {
             .write()
             .unwrap();
 
+        // Check that the mapped memory still spans the file on disk.
+        self.check_mapped_size(locator, writer.len())?;
+
         match locator {
             Locator::CoordinatorState => Ok(writer),
             Locator::RoundHeight => Ok(writer),
             Locator::RoundState { round_height: _ } => Ok(writer),
+            Locator::RoundHeader { round_height: _ } => Ok(writer),
+            Locator::ChunkState { .. } => Ok(writer),
             Locator::RoundFile { round_height: _ } => {
                 // Check that the round size is correct.
                 let expected = Object::round_file_size(&self.environment);
@@ -556,6 +1219,9 @@ impl StorageObject for Disk {
                 Ok(writer)
             }
             Locator::ContributionFileSignature(_) => Ok(writer),
+            Locator::PracticeChallenge => Ok(writer),
+            Locator::Health => Ok(writer),
+            Locator::QuarantinedContributionFile { .. } => Ok(writer),
         }
     }
 }
@@ -564,27 +1230,54 @@ impl StorageObject for Disk {
 struct SerializedDiskManifest {
     open: BTreeSet<LocatorPath>,
     locators: BTreeSet<LocatorPath>,
+    #[serde(default)]
+    encrypted: BTreeSet<LocatorPath>,
+}
+
+/// A change to storage that replaces several files at once, recorded so it can be completed after a crash.
+#[derive(Debug, Serialize, Deserialize)]
+struct SerializedDiskJournal {
+    /// The manifest after the change.
+    manifest: SerializedDiskManifest,
+    /// The files to replace with the replacement files next to them.
+    replacements: BTreeSet<LocatorPath>,
 }
 
 #[derive(Debug)]
 struct DiskManifest {
     open: HashSet<Locator>,
     locators: HashSet<Locator>,
+    encrypted: HashSet<Locator>,
     resolver: DiskResolver,
 }
 
 impl DiskManifest {
     /// Load the manifest for storage from disk.
+    ///
+    /// A read-only storage loads the manifest of the primary coordinator, so it never creates
+    /// the base directory or the manifest.
     #[inline]
-    fn load(base_directory: &str) -> Result<Self, CoordinatorError> {
+    fn load(base_directory: &str, read_only: bool) -> Result<Self, CoordinatorError> {
+        // Create the resolver.
+        let resolver = DiskResolver::new(base_directory);
+
+        // Check that the manifest of the primary coordinator exists, if the storage is read-only.
+        if read_only && !Path::new(&resolver.manifest()).exists() {
+            error!("Read-only storage is missing the manifest at {}", resolver.manifest());
+            return Err(CoordinatorError::StorageInitializationFailed);
+        }
+
         // Check the base directory exists.
         if !Path::new(base_directory).exists() {
             // Create the base directory if it does not exist.
             fs::create_dir_all(base_directory).expect("unable to create the base directory");
         }
 
-        // Create the resolver.
-        let resolver = DiskResolver::new(base_directory);
+        // Complete the change recorded in the journal, if storage stopped partway through it.
+        // A read-only storage leaves this to the primary coordinator, and loads the manifest from before the change.
+        if !read_only {
+            Self::complete_pending_journal(&resolver)?;
+        }
 
         // Load the manifest.
         match Path::new(&resolver.manifest()).exists() {
@@ -604,6 +1297,14 @@ impl DiskManifest {
                         }
                     }
 
+                    // Check that all `encrypted` locators exist in the set of all `locators`.
+                    for encrypted in &manifest.encrypted {
+                        if !manifest.locators.contains(encrypted) {
+                            error!("{} is encrypted but missing in the manifest locators", encrypted);
+                            return Err(CoordinatorError::LocatorFileMissing);
+                        }
+                    }
+
                     // Check that all `locators` exist on disk.
                     for locator in &manifest.locators {
                         if !locator.as_path().is_file() {
@@ -627,9 +1328,17 @@ impl DiskManifest {
                     .map(|path| resolver.to_locator(&path).unwrap())
                     .collect();
 
+                // Fetch the encrypted locators from the manifest.
+                let encrypted: HashSet<Locator> = manifest
+                    .encrypted
+                    .par_iter()
+                    .map(|path| resolver.to_locator(&path).unwrap())
+                    .collect();
+
                 Ok(Self {
                     open,
                     locators,
+                    encrypted,
                     resolver,
                 })
             }
@@ -644,6 +1353,7 @@ impl DiskManifest {
                 Ok(Self {
                     open: HashSet::default(),
                     locators: HashSet::default(),
+                    encrypted: HashSet::default(),
                     resolver,
                 })
             }
@@ -662,11 +1372,8 @@ impl DiskManifest {
             return Err(CoordinatorError::LocatorFileAlreadyExistsAndOpen);
         }
 
-        // If the locator is a contribution file, initialize its directory.
-        if let Locator::ContributionFile(contribution_locator) = locator {
-            self.resolver
-                .chunk_directory_init(contribution_locator.round_height(), contribution_locator.chunk_id());
-        }
+        // Initialize the directory of the file.
+        self.initialize_directory(locator);
 
         // Load the file path.
         let path = self.resolver.to_path(&locator)?;
@@ -689,6 +1396,26 @@ impl DiskManifest {
         Ok(file)
     }
 
+    /// Initializes the directory of the file for the given locator, if it has its own directory.
+    #[inline]
+    fn initialize_directory(&self, locator: &Locator) {
+        // If the locator is a contribution file, initialize its directory.
+        if let Locator::ContributionFile(contribution_locator) = locator {
+            self.resolver
+                .chunk_directory_init(contribution_locator.round_height(), contribution_locator.chunk_id());
+        }
+
+        // If the locator is the practice challenge, initialize its directory.
+        if let Locator::PracticeChallenge = locator {
+            self.resolver.practice_directory_init();
+        }
+
+        // If the locator is a round header or chunk state, initialize its directory.
+        if let Locator::RoundHeader { round_height } | Locator::ChunkState { round_height, .. } = locator {
+            self.resolver.round_state_directory_init(*round_height);
+        }
+    }
+
     #[allow(dead_code)]
     #[inline]
     fn open_file(&mut self, locator: &Locator) -> Result<File, CoordinatorError> {
@@ -740,11 +1467,40 @@ impl DiskManifest {
         Ok(file)
     }
 
+    #[inline]
+    fn reopen_file_read_only(&self, locator: &Locator) -> Result<File, CoordinatorError> {
+        // Check that the file exists.
+        if !self.locators.contains(locator) {
+            error!("Locator missing in call to reopen_file_read_only() in storage.");
+            return Err(CoordinatorError::LocatorFileMissing);
+        }
+
+        // Check that the file is open.
+        if !self.open.contains(locator) {
+            return Err(CoordinatorError::LocatorFileNotOpen);
+        }
+
+        // Load the file path.
+        let path = self.resolver.to_path(&locator)?;
+
+        // Open the file.
+        let file = OpenOptions::new().read(true).open(&path)?;
+
+        Ok(file)
+    }
+
+    ///
+    /// Replaces the file for the given locator with an empty file of the given size.
+    ///
+    /// The file is never truncated in place, as pages of an existing memory map of the file
+    /// that are no longer backed by it raise a SIGBUS when they are accessed. Memory maps of
+    /// the replaced file remain valid until they are dropped.
+    ///
     #[inline]
     fn resize_file(&mut self, locator: &Locator, size: u64) -> Result<File, CoordinatorError> {
         // Check that the file exists.
         if !self.locators.contains(locator) {
-            error!("Locator missing in call to close_file() in storage.");
+            error!("Locator missing in call to resize_file() in storage.");
             return Err(CoordinatorError::LocatorFileMissing);
         }
 
@@ -753,14 +1509,213 @@ impl DiskManifest {
             return Err(CoordinatorError::LocatorFileShouldBeOpen);
         }
 
-        // Load the file path.
+        // Load the file path, and the path of its replacement.
         let path = self.resolver.to_path(&locator)?;
+        let replacement = format!("{}.tmp", path);
+
+        // Create the replacement with the given size, and move it into the place of the file.
+        {
+            let file = OpenOptions::new()
+                .read(true)
+                .write(true)
+                .create(true)
+                .truncate(true)
+                .open(&replacement)?;
+            file.set_len(size)?;
+        }
+        fs::rename(&replacement, &path)?;
 
         // Open the file.
         let file = OpenOptions::new().read(true).write(true).open(&path)?;
 
-        // Resize the file.
-        file.set_len(size)?;
+        Ok(file)
+    }
+
+    ///
+    /// Replaces the files for the given locators with the given bytes as a single change to storage,
+    /// and records whether each file is encrypted. Locators that do not exist yet are created.
+    ///
+    /// Each replacement is written next to its file and synced to disk. The manifest after the change,
+    /// and the files the replacements take the place of, are then recorded in a journal. If storage
+    /// stops before the journal is written, the change is discarded, and if it stops after, the change
+    /// is completed the next time the manifest is saved or loaded.
+    ///
+    #[inline]
+    fn replace_files(&mut self, files: &[(Locator, Cow<[u8]>, bool)]) -> Result<(), CoordinatorError> {
+        // Check that the existing files are open.
+        for (locator, _, _) in files {
+            if self.locators.contains(locator) && !self.open.contains(locator) {
+                return Err(CoordinatorError::LocatorFileShouldBeOpen);
+            }
+        }
+
+        // Write the replacements, and remove the ones already written if one fails.
+        let mut paths = Vec::with_capacity(files.len());
+        for (locator, bytes, _) in files {
+            self.initialize_directory(locator);
+            let path = self.resolver.to_path(locator)?;
+            if let Err(error) = Self::write_replacement(&format!("{}.tmp", path), |file| {
+                file.write_all(bytes)?;
+                Ok(())
+            }) {
+                for path in &paths {
+                    let _ = fs::remove_file(format!("{}.tmp", path));
+                }
+                return Err(error);
+            }
+            paths.push(path);
+        }
+
+        // Apply the change to the manifest, and record it in the journal.
+        let (open, locators, encrypted) = (self.open.clone(), self.locators.clone(), self.encrypted.clone());
+        for (locator, _, is_encrypted) in files {
+            self.locators.insert(*locator);
+            self.open.insert(*locator);
+            match *is_encrypted {
+                true => self.encrypted.insert(*locator),
+                false => self.encrypted.remove(locator),
+            };
+        }
+        let journal = self.serialize().and_then(|manifest| {
+            let journal = SerializedDiskJournal {
+                manifest,
+                replacements: paths.iter().cloned().collect(),
+            };
+            Self::write_atomically(&self.resolver.journal(), &serde_json::to_vec_pretty(&journal)?)?;
+            Ok(journal)
+        });
+        let journal = match journal {
+            Ok(journal) => journal,
+            Err(error) => {
+                self.open = open;
+                self.locators = locators;
+                self.encrypted = encrypted;
+                for path in &paths {
+                    let _ = fs::remove_file(format!("{}.tmp", path));
+                }
+                return Err(error);
+            }
+        };
+
+        // Move the replacements into place.
+        Self::complete_journal(&self.resolver, journal)
+    }
+
+    ///
+    /// Moves the replacements recorded in the given journal into place,
+    /// saves the manifest recorded in the journal, and removes the journal.
+    ///
+    /// Replacements already moved into place are skipped, so a journal
+    /// may be completed again after a failure.
+    ///
+    #[inline]
+    fn complete_journal(resolver: &DiskResolver, journal: SerializedDiskJournal) -> Result<(), CoordinatorError> {
+        for path in &journal.replacements {
+            let replacement = format!("{}.tmp", path);
+            match Path::new(&replacement).exists() {
+                true => fs::rename(&replacement, path)?,
+                false if !path.as_path().is_file() => {
+                    error!("{} is in the storage journal but missing on disk", path);
+                    return Err(CoordinatorError::LocatorFileMissing);
+                }
+                false => {}
+            }
+        }
+
+        Self::write_atomically(&resolver.manifest(), &serde_json::to_vec_pretty(&journal.manifest)?)?;
+        fs::remove_file(resolver.journal())?;
+
+        Ok(())
+    }
+
+    ///
+    /// Completes the change recorded in the storage journal, if one exists.
+    ///
+    #[inline]
+    fn complete_pending_journal(resolver: &DiskResolver) -> Result<(), CoordinatorError> {
+        if !Path::new(&resolver.journal()).exists() {
+            return Ok(());
+        }
+
+        debug!("Completing the change recorded in {}", resolver.journal());
+        let journal: SerializedDiskJournal = serde_json::from_slice(&fs::read(resolver.journal())?)?;
+        Self::complete_journal(resolver, journal)
+    }
+
+    ///
+    /// Writes the file at the given path with the given function, and syncs it to disk.
+    /// If the function fails, the file is removed.
+    ///
+    #[inline]
+    fn write_replacement(
+        path: &str,
+        write: impl FnOnce(&mut File) -> Result<(), CoordinatorError>,
+    ) -> Result<(), CoordinatorError> {
+        let written = OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(path)
+            .map_err(CoordinatorError::from)
+            .and_then(|mut file| {
+                write(&mut file)?;
+                file.sync_all()?;
+                Ok(())
+            });
+        if written.is_err() {
+            let _ = fs::remove_file(path);
+        }
+        written
+    }
+
+    ///
+    /// Replaces the file at the given path with the given bytes, through a replacement file.
+    ///
+    #[inline]
+    fn write_atomically(path: &str, bytes: &[u8]) -> Result<(), CoordinatorError> {
+        let replacement = format!("{}.tmp", path);
+        Self::write_replacement(&replacement, |file| {
+            file.write_all(bytes)?;
+            Ok(())
+        })?;
+        fs::rename(&replacement, path)?;
+        Ok(())
+    }
+
+    ///
+    /// Replaces the file for the given locator with a file written by the given function.
+    ///
+    /// The replacement is written next to the file, and only takes the place of the file
+    /// once it was written in full and synced to disk. If the function fails, the replacement
+    /// is removed, and the file is left as it was.
+    ///
+    #[inline]
+    fn replace_file_with(
+        &mut self,
+        locator: &Locator,
+        write: impl FnOnce(&mut File) -> Result<(), CoordinatorError>,
+    ) -> Result<File, CoordinatorError> {
+        // Check that the file exists.
+        if !self.locators.contains(locator) {
+            error!("Locator missing in call to replace_file_with() in storage.");
+            return Err(CoordinatorError::LocatorFileMissing);
+        }
+
+        // Check that the file is open.
+        if !self.open.contains(locator) {
+            return Err(CoordinatorError::LocatorFileShouldBeOpen);
+        }
+
+        // Load the file path, and the path of its replacement.
+        let path = self.resolver.to_path(&locator)?;
+        let replacement = format!("{}.tmp", path);
+
+        // Write the replacement, and sync it to disk before it takes the place of the file.
+        Self::write_replacement(&replacement, write)?;
+        fs::rename(&replacement, &path)?;
+
+        // Open the file.
+        let file = OpenOptions::new().read(true).write(true).open(&path)?;
 
         Ok(file)
     }
@@ -809,14 +1764,57 @@ impl DiskManifest {
         // Remove the file from the set of open files.
         self.open.remove(locator);
 
+        // Remove the file from the set of encrypted files.
+        self.encrypted.remove(locator);
+
         // Save the updated state.
         self.save()?;
 
         Ok(())
     }
 
+    #[inline]
+    fn set_encrypted(&mut self, locator: &Locator, is_encrypted: bool) -> Result<(), CoordinatorError> {
+        // Check that the file exists.
+        if !self.locators.contains(locator) {
+            error!("Locator missing in call to set_encrypted() in storage.");
+            return Err(CoordinatorError::LocatorFileMissing);
+        }
+
+        // Update the set of encrypted files, and save the updated state if it changed.
+        let is_changed = match is_encrypted {
+            true => self.encrypted.insert(*locator),
+            false => self.encrypted.remove(locator),
+        };
+        if is_changed {
+            self.save()?;
+        }
+
+        Ok(())
+    }
+
+    #[inline]
+    fn is_encrypted(&self, locator: &Locator) -> bool {
+        self.encrypted.contains(locator)
+    }
+
+    ///
+    /// Saves the manifest to disk, after completing any change recorded in the storage journal.
+    ///
     #[inline]
     fn save(&mut self) -> Result<(), CoordinatorError> {
+        // Complete the pending change first, as its journal would otherwise replace this manifest when it is loaded.
+        Self::complete_pending_journal(&self.resolver)?;
+
+        // Write the serialized manifest to disk.
+        let serialized = serde_json::to_vec_pretty(&self.serialize()?)?;
+        Self::write_atomically(&self.resolver.manifest(), &serialized)?;
+
+        Ok(())
+    }
+
+    #[inline]
+    fn serialize(&self) -> Result<SerializedDiskManifest, CoordinatorError> {
         // Serialize the open locators.
         let open: BTreeSet<LocatorPath> = self
             .open
@@ -831,13 +1829,18 @@ impl DiskManifest {
             .map(|locator| self.resolver.to_path(&locator))
             .collect::<Result<BTreeSet<LocatorPath>, CoordinatorError>>()?;
 
-        // Serialize the manifest.
-        let serialized = serde_json::to_string_pretty(&SerializedDiskManifest { open, locators })?;
-
-        // Write the serialized manifest to disk.
-        fs::write(Path::new(&self.resolver.manifest()), serialized)?;
+        // Serialize the encrypted locators.
+        let encrypted: BTreeSet<LocatorPath> = self
+            .encrypted
+            .par_iter()
+            .map(|locator| self.resolver.to_path(&locator))
+            .collect::<Result<BTreeSet<LocatorPath>, CoordinatorError>>()?;
 
-        Ok(())
+        Ok(SerializedDiskManifest {
+            open,
+            locators,
+            encrypted,
+        })
     }
 
     #[inline]
@@ -851,8 +1854,8 @@ impl DiskManifest {
         // Load the file path.
         let path = self.resolver.to_path(&locator)?;
 
-        // Open the file.
-        let file = OpenOptions::new().read(true).write(true).open(&path)?;
+        // Open the file read-only, as the files of a read-only storage belong to the primary coordinator.
+        let file = OpenOptions::new().read(true).open(&path)?;
 
         Ok(file.metadata()?.len())
     }
@@ -863,14 +1866,19 @@ impl DiskManifest {
     }
 }
 
+///
+/// Resolves locators to paths in the given base directory, and parses paths back into locators.
+///
+/// Paths read from the manifest are untrusted input, so parsing them must never panic.
+///
 #[derive(Debug)]
-struct DiskResolver {
+pub struct DiskResolver {
     base: String,
 }
 
 impl DiskResolver {
     #[inline]
-    fn new(base: &str) -> Self {
+    pub fn new(base: &str) -> Self {
         Self { base: base.to_string() }
     }
 }
@@ -882,6 +1890,12 @@ impl StorageLocator for DiskResolver {
             Locator::CoordinatorState => format!("{}/coordinator.json", self.base),
             Locator::RoundHeight => format!("{}/round_height", self.base),
             Locator::RoundState { round_height } => format!("{}/state.json", self.round_directory(*round_height)),
+            Locator::RoundHeader { round_height } => {
+                format!("{}/header.json", self.round_state_directory(*round_height))
+            }
+            Locator::ChunkState { round_height, chunk_id } => {
+                format!("{}/chunk_{}.json", self.round_state_directory(*round_height), chunk_id)
+            }
             Locator::RoundFile { round_height } => {
                 let round_directory = self.round_directory(*round_height);
                 format!("{}/round_{}.verified", round_directory, *round_height)
@@ -925,6 +1939,22 @@ impl StorageLocator for DiskResolver {
                     ),
                 }
             }
+            Locator::PracticeChallenge => format!("{}/challenge", self.practice_directory()),
+            Locator::Health => format!("{}/health.json", self.base),
+            Locator::QuarantinedContributionFile {
+                round_height,
+                chunk_id,
+                contribution_id,
+                attempt,
+            } => {
+                // Set the contribution locator as `{chunk_directory}/contribution_{contribution_id}.unverified.failed_{attempt}`.
+                format!(
+                    "{}/contribution_{}.unverified.failed_{}",
+                    self.chunk_directory(*round_height, *chunk_id),
+                    contribution_id,
+                    attempt
+                )
+            }
         };
         // Sanitize the path.
         LocatorPath::try_from(Path::new(&path))
@@ -970,6 +2000,16 @@ impl StorageLocator for DiskResolver {
             return Ok(Locator::RoundHeight);
         }
 
+        // Check if it matches the practice challenge.
+        if key == "practice/challenge" {
+            return Ok(Locator::PracticeChallenge);
+        }
+
+        // Check if it matches the health file.
+        if key == "health.json" {
+            return Ok(Locator::Health);
+        }
+
         // Parse the key into its components.
         if let Some((round, remainder)) = key.splitn(2, "/").collect_tuple() {
             // Check if it resembles the round directory.
@@ -990,6 +2030,22 @@ impl StorageLocator for DiskResolver {
                         return Ok(Locator::RoundState { round_height });
                     }
 
+                    // Check if it matches the round header or a chunk state.
+                    if let Some(state) = remainder.strip_prefix("state/") {
+                        if state == "header.json" {
+                            return Ok(Locator::RoundHeader { round_height });
+                        }
+
+                        if let Some(chunk_id) = state.strip_prefix("chunk_").and_then(|s| s.strip_suffix(".json")) {
+                            let chunk_id = u64::from_str(chunk_id)?;
+
+                            // Check that the chunk ID is formatted canonically.
+                            if state == format!("chunk_{}.json", chunk_id) {
+                                return Ok(Locator::ChunkState { round_height, chunk_id });
+                            }
+                        }
+                    }
+
                     // Check if it matches the round file.
                     if remainder == format!("round_{}.verified", round_height) {
                         return Ok(Locator::RoundFile { round_height });
@@ -1044,6 +2100,21 @@ impl StorageLocator for DiskResolver {
                                         ));
                                     }
 
+                                    // Check if it matches a quarantined contribution file.
+                                    if let Some(attempt) = extension.strip_prefix("unverified.failed_") {
+                                        let attempt = u64::from_str(attempt)?;
+
+                                        // Check that the attempt is formatted canonically.
+                                        if extension == format!("unverified.failed_{}", attempt) {
+                                            return Ok(Locator::QuarantinedContributionFile {
+                                                round_height,
+                                                chunk_id,
+                                                contribution_id,
+                                                attempt,
+                                            });
+                                        }
+                                    }
+
                                     // Check if it matches a unverified contribution file.
                                     if extension == "unverified" {
                                         return Ok(Locator::ContributionFile(ContributionLocator::new(
@@ -1082,12 +2153,48 @@ impl DiskResolver {
         format!("{}/manifest.json", self.base)
     }
 
+    /// Returns the storage journal file path.
+    #[inline]
+    fn journal(&self) -> String {
+        format!("{}/manifest.journal", self.base)
+    }
+
     /// Returns the round directory for a given round height from the coordinator.
     #[inline]
     fn round_directory(&self, round_height: u64) -> String {
         format!("{}/round_{}", self.base, round_height)
     }
 
+    /// Returns the round state directory for a given round height from the coordinator.
+    #[inline]
+    fn round_state_directory(&self, round_height: u64) -> String {
+        format!("{}/state", self.round_directory(round_height))
+    }
+
+    /// Initializes the round state directory for a given round height.
+    #[inline]
+    fn round_state_directory_init(&self, round_height: u64) {
+        let path = self.round_state_directory(round_height);
+        if !Path::new(&path).exists() {
+            std::fs::create_dir_all(&path).expect("unable to create the round state directory");
+        }
+    }
+
+    /// Returns the practice directory from the coordinator.
+    #[inline]
+    fn practice_directory(&self) -> String {
+        format!("{}/practice", self.base)
+    }
+
+    /// Initializes the practice directory.
+    #[inline]
+    fn practice_directory_init(&self) {
+        let path = self.practice_directory();
+        if !Path::new(&path).exists() {
+            std::fs::create_dir_all(&path).expect("unable to create the practice directory");
+        }
+    }
+
     /// Returns the chunk directory for a given round height and chunk ID from the coordinator.
     #[inline]
     fn chunk_directory(&self, round_height: u64, chunk_id: u64) -> String {
@@ -1118,7 +2225,11 @@ impl DiskResolver {
 #[cfg(test)]
 mod tests {
     use super::*;
-    // use crate::testing::prelude::*;
+    use crate::{
+        environment::{Parameters, Testing},
+        storage::StorageKeySource,
+        testing::prelude::*,
+    };
 
     #[test]
     fn test_to_path_coordinator_state() {
@@ -1162,6 +2273,49 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_to_path_practice_challenge() {
+        let locator = DiskResolver::new("./transcript/test");
+
+        assert_eq!(
+            LocatorPath::from("./transcript/test/practice/challenge"),
+            locator.to_path(&Locator::PracticeChallenge).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_to_locator_practice_challenge() {
+        let locator = DiskResolver::new("./transcript/test");
+
+        assert_eq!(
+            Locator::PracticeChallenge,
+            locator
+                .to_locator(&"./transcript/test/practice/challenge".into())
+                .unwrap(),
+        );
+    }
+
+    #[test]
+    fn test_to_path_health() {
+        let locator = DiskResolver::new("./transcript/test");
+
+        assert_eq!(
+            LocatorPath::from("./transcript/test/health.json"),
+            locator.to_path(&Locator::Health).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_to_locator_health() {
+        let locator = DiskResolver::new("./transcript/test");
+
+        assert_eq!(
+            Locator::Health,
+            locator.to_locator(&"./transcript/test/health.json".into()).unwrap(),
+        );
+        assert!(locator.to_locator(&"./transcript/test/health".into()).is_err());
+    }
+
     #[test]
     fn test_to_path_round_state() {
         let locator = DiskResolver::new("./transcript/test");
@@ -1572,4 +2726,703 @@ mod tests {
             Locator::ContributionFileSignature(ContributionSignatureLocator::new(1, 1, 1, true))
         );
     }
+
+    #[test]
+    fn test_to_locator_quarantined_contribution_file() {
+        let locator = DiskResolver::new("./transcript/test");
+        let quarantined = Locator::QuarantinedContributionFile {
+            round_height: 1,
+            chunk_id: 2,
+            contribution_id: 3,
+            attempt: 4,
+        };
+
+        assert_eq!(
+            LocatorPath::from("./transcript/test/round_1/chunk_2/contribution_3.unverified.failed_4"),
+            locator.to_path(&quarantined).unwrap()
+        );
+        assert_eq!(
+            quarantined,
+            locator
+                .to_locator(&"./transcript/test/round_1/chunk_2/contribution_3.unverified.failed_4".into())
+                .unwrap()
+        );
+        assert!(locator
+            .to_locator(&"./transcript/test/round_1/chunk_2/contribution_3.unverified.failed_04".into())
+            .is_err());
+    }
+
+    #[test]
+    fn test_to_path_round_header_and_chunk_state() {
+        let locator = DiskResolver::new("./transcript/test");
+
+        assert_eq!(
+            LocatorPath::from("./transcript/test/round_1/state/header.json"),
+            locator.to_path(&Locator::RoundHeader { round_height: 1 }).unwrap()
+        );
+        assert_eq!(
+            LocatorPath::from("./transcript/test/round_1/state/chunk_0.json"),
+            locator
+                .to_path(&Locator::ChunkState {
+                    round_height: 1,
+                    chunk_id: 0
+                })
+                .unwrap()
+        );
+        assert_eq!(
+            LocatorPath::from("./transcript/test/round_2/state/chunk_15.json"),
+            locator
+                .to_path(&Locator::ChunkState {
+                    round_height: 2,
+                    chunk_id: 15
+                })
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_to_locator_round_header_and_chunk_state() {
+        let locator = DiskResolver::new("./transcript/test");
+
+        assert_eq!(
+            Locator::RoundHeader { round_height: 1 },
+            locator
+                .to_locator(&"./transcript/test/round_1/state/header.json".into())
+                .unwrap(),
+        );
+        assert_eq!(
+            Locator::ChunkState {
+                round_height: 1,
+                chunk_id: 0
+            },
+            locator
+                .to_locator(&"./transcript/test/round_1/state/chunk_0.json".into())
+                .unwrap(),
+        );
+        assert_eq!(
+            Locator::ChunkState {
+                round_height: 2,
+                chunk_id: 15
+            },
+            locator
+                .to_locator(&"./transcript/test/round_2/state/chunk_15.json".into())
+                .unwrap(),
+        );
+        assert!(locator
+            .to_locator(&"./transcript/test/round_1/state/chunk_01.json".into())
+            .is_err());
+    }
+
+    #[test]
+    fn test_to_locator_malformed_paths() {
+        let locator = DiskResolver::new("./transcript/test");
+
+        // Paths outside the base directory, truncated paths, and paths with numbers that
+        // are empty, signed, padded or overflow, must be rejected rather than panic.
+        for path in &[
+            "",
+            "../transcript/test/round_1/state.json",
+            "./transcript/other/round_1/state.json",
+            "./transcript/test",
+            "./transcript/test/round_",
+            "./transcript/test/round_/state.json",
+            "./transcript/test/round_+1/state.json",
+            "./transcript/test/round_01/state.json",
+            "./transcript/test/round_18446744073709551616/state.json",
+            "./transcript/test/round_1/state/chunk_.json",
+            "./transcript/test/round_1/state/chunk_1.json.json",
+            "./transcript/test/round_1/chunk_1/contribution_",
+            "./transcript/test/round_1/chunk_1/contribution_1",
+            "./transcript/test/round_1/chunk_1/contribution_.verified",
+            "./transcript/test/round_1/chunk_1/contribution_1.unverified.extra",
+            "./transcript/test/round_1/chunk_1/contribution_1.verified/extra",
+            "./transcript/test/round_1/chunk_18446744073709551616/contribution_1.verified",
+        ] {
+            assert!(
+                locator.to_locator(&(*path).into()).is_err(),
+                "{:?} should not parse as a locator",
+                path
+            );
+        }
+    }
+
+    #[test]
+    #[serial]
+    fn test_round_state_stored_as_chunk_states() {
+        let environment = initialize_test_environment(&TEST_ENVIRONMENT);
+        let mut storage = Disk::load(&environment).unwrap();
+
+        let mut round = test_round_1_partial_json().unwrap();
+        let round_height = round.round_height();
+        let locator = Locator::RoundState { round_height };
+        storage.insert(locator, Object::RoundState(round.clone())).unwrap();
+
+        // The round is stored as a round header and a state per chunk, and assembled on read.
+        assert!(storage.exists(&locator));
+        assert!(storage.exists(&Locator::RoundHeader { round_height }));
+        assert!(!storage.manifest.read().unwrap().contains(&locator));
+        for chunk in round.chunks() {
+            assert!(storage.exists(&Locator::ChunkState {
+                round_height,
+                chunk_id: chunk.chunk_id()
+            }));
+        }
+        match storage.get(&locator).unwrap() {
+            Object::RoundState(candidate) => assert_eq!(round, candidate),
+            _ => panic!("Expected a round state"),
+        }
+
+        // Lock and release every chunk in turn, as over the course of a round.
+        let bytes_written = storage.bytes_written();
+        let mut round_state_bytes = 0;
+        for chunk_id in 0..round.chunks().len() as u64 {
+            for lock_holder in vec![Some(TEST_CONTRIBUTOR_ID_2.clone()), None] {
+                round.chunk_mut(chunk_id).unwrap().set_lock_holder_unsafe(lock_holder);
+                let object = Object::RoundState(round.clone());
+                round_state_bytes += object.size();
+                storage.update(&locator, object).unwrap();
+            }
+        }
+        let bytes_written = storage.bytes_written() - bytes_written;
+
+        // Only the changed chunk states were written, rather than the round state on every update.
+        assert!(
+            bytes_written * 32 < round_state_bytes,
+            "wrote {} bytes of chunk states, versus {} bytes of round states",
+            bytes_written,
+            round_state_bytes
+        );
+        match storage.get(&locator).unwrap() {
+            Object::RoundState(candidate) => assert_eq!(round, candidate),
+            _ => panic!("Expected a round state"),
+        }
+
+        // The chunk states were replaced by renaming their replacements into place.
+        for chunk_state in storage.chunk_states(round_height) {
+            let path = storage.to_path(&chunk_state).unwrap();
+            assert!(!Path::new(&format!("{}.tmp", path)).exists());
+        }
+
+        // Removing the round state removes the round header and every chunk state.
+        storage.remove(&locator).unwrap();
+        assert!(!storage.exists(&locator));
+        assert!(!storage.exists(&Locator::RoundHeader { round_height }));
+        assert!(storage.chunk_states(round_height).is_empty());
+    }
+
+    #[test]
+    #[serial]
+    fn test_round_state_migration() {
+        let environment = initialize_test_environment(&TEST_ENVIRONMENT);
+
+        let round = test_round_1_partial_json().unwrap();
+        let round_height = round.round_height();
+        let locator = Locator::RoundState { round_height };
+
+        // Store the round state as a single file, as it was before round states were split.
+        {
+            let mut storage = Disk::load(&environment).unwrap();
+            let bytes = Object::RoundState(round.clone()).to_bytes().unwrap();
+            fs::create_dir_all(storage.resolver.round_directory(round_height)).unwrap();
+            storage.initialize(locator, bytes.len() as u64).unwrap();
+            storage.write(&locator, &bytes).unwrap();
+            assert!(storage.exists(&locator));
+            assert!(!storage.exists(&Locator::RoundHeader { round_height }));
+        }
+
+        // Loading storage splits the round state into a round header and chunk states.
+        let storage = Disk::load(&environment).unwrap();
+        assert!(storage.exists(&locator));
+        assert!(storage.exists(&Locator::RoundHeader { round_height }));
+        assert!(!storage.manifest.read().unwrap().contains(&locator));
+        assert!(!storage.to_path(&locator).unwrap().as_path().exists());
+        match storage.get(&locator).unwrap() {
+            Object::RoundState(candidate) => assert_eq!(round, candidate),
+            _ => panic!("Expected a round state"),
+        }
+    }
+
+    #[test]
+    #[serial]
+    fn test_round_state_update_failure_and_recovery() {
+        let environment = initialize_test_environment(&TEST_ENVIRONMENT);
+
+        let mut round = test_round_1_partial_json().unwrap();
+        let round_height = round.round_height();
+        let locator = Locator::RoundState { round_height };
+        let chunk_locator = Locator::ChunkState {
+            round_height,
+            chunk_id: 0,
+        };
+        let chunk_path = {
+            let mut storage = Disk::load(&environment).unwrap();
+            storage.insert(locator, Object::RoundState(round.clone())).unwrap();
+            let chunk_path = storage.to_path(&chunk_locator).unwrap();
+            let failing_path = storage
+                .to_path(&Locator::ChunkState {
+                    round_height,
+                    chunk_id: 1,
+                })
+                .unwrap();
+
+            // Fail to write the replacement of the second chunk state, by taking its place with a directory.
+            fs::create_dir(format!("{}.tmp", failing_path)).unwrap();
+            let mut updated = round.clone();
+            updated
+                .chunk_mut(0)
+                .unwrap()
+                .set_lock_holder_unsafe(Some(TEST_CONTRIBUTOR_ID_2.clone()));
+            updated
+                .chunk_mut(1)
+                .unwrap()
+                .set_lock_holder_unsafe(Some(TEST_CONTRIBUTOR_ID_2.clone()));
+            assert!(storage.update(&locator, Object::RoundState(updated)).is_err());
+            fs::remove_dir(format!("{}.tmp", failing_path)).unwrap();
+
+            // Check that no chunk state was replaced, the replacement of the first was removed,
+            // and the chunk states remain mapped.
+            assert!(!Path::new(&format!("{}.tmp", chunk_path)).exists());
+            match storage.get(&locator).unwrap() {
+                Object::RoundState(candidate) => assert_eq!(round, candidate),
+                _ => panic!("Expected a round state"),
+            }
+            assert!(!Path::new(&storage.resolver.journal()).exists());
+            chunk_path
+        };
+
+        // Stop storage after the journal of a change was written, but before the change was completed.
+        round
+            .chunk_mut(0)
+            .unwrap()
+            .set_lock_holder_unsafe(Some(TEST_CONTRIBUTOR_ID_2.clone()));
+        {
+            let storage = Disk::load(&environment).unwrap();
+            let bytes = Object::ChunkState(round.chunk(0).unwrap().clone()).to_bytes().unwrap();
+            fs::write(format!("{}.tmp", chunk_path), &bytes).unwrap();
+            let journal = SerializedDiskJournal {
+                manifest: storage.manifest.read().unwrap().serialize().unwrap(),
+                replacements: vec![chunk_path.clone()].into_iter().collect(),
+            };
+            fs::write(storage.resolver.journal(), serde_json::to_vec(&journal).unwrap()).unwrap();
+        }
+
+        // Check that loading storage completes the change.
+        let storage = Disk::load(&environment).unwrap();
+        assert!(!Path::new(&storage.resolver.journal()).exists());
+        assert!(!Path::new(&format!("{}.tmp", chunk_path)).exists());
+        match storage.get(&locator).unwrap() {
+            Object::RoundState(candidate) => assert_eq!(round, candidate),
+            _ => panic!("Expected a round state"),
+        }
+    }
+
+    #[test]
+    #[serial]
+    fn test_reader_rejects_truncated_file() {
+        let environment = initialize_test_environment(&TEST_ENVIRONMENT);
+        let mut storage = Disk::load(&environment).unwrap();
+
+        let locator = Locator::PracticeChallenge;
+        storage
+            .insert(locator, Object::PracticeChallenge(vec![7u8; 1 << 16]))
+            .unwrap();
+        let path = storage.to_path(&locator).unwrap();
+
+        // Check that readers share the memory map of the object, rather than copying it.
+        {
+            let reader = storage.reader(&locator).unwrap();
+            let other = storage.reader(&locator).unwrap();
+            assert_eq!(&[7u8; 1 << 16][..], &reader[..]);
+            assert_eq!(reader.as_ptr(), other.as_ptr());
+        }
+
+        // Check that the object is updated once the readers are released.
+        storage
+            .update(&locator, Object::PracticeChallenge(vec![8u8; 100]))
+            .unwrap();
+        assert_eq!(&[8u8; 100][..], &storage.reader(&locator).unwrap()[..]);
+
+        // Truncate the file outside of storage.
+        OpenOptions::new()
+            .write(true)
+            .open(path.as_path())
+            .unwrap()
+            .set_len(10)
+            .unwrap();
+
+        // Check that the truncated file is rejected, rather than read through its memory map.
+        assert!(matches!(
+            storage.reader(&locator).err(),
+            Some(CoordinatorError::StorageMappedSizeMismatch)
+        ));
+        assert!(matches!(
+            storage.get(&locator).err(),
+            Some(CoordinatorError::StorageMappedSizeMismatch)
+        ));
+    }
+
+    #[test]
+    #[serial]
+    fn test_read_only_storage() {
+        let environment = initialize_test_environment(&TEST_ENVIRONMENT);
+        let read_only: Environment = Testing::from(Parameters::Test8Chunks)
+            .coordinator_mode(CoordinatorMode::ReadOnly {
+                primary_url: "http://localhost:9000".to_string(),
+            })
+            .into();
+
+        // Check that read-only storage does not create the storage of the primary coordinator.
+        assert!(matches!(
+            Disk::load(&read_only).err(),
+            Some(CoordinatorError::StorageInitializationFailed)
+        ));
+        assert!(!Path::new(environment.local_base_directory()).exists());
+
+        let locator = Locator::PracticeChallenge;
+        let mut primary = Disk::load(&environment).unwrap();
+        primary
+            .insert(locator, Object::PracticeChallenge(vec![7u8; 1 << 16]))
+            .unwrap();
+
+        // Check that read-only storage reads, but rejects mutations.
+        let mut replica = Disk::load(&read_only).unwrap();
+        let reader = replica.reader(&locator).unwrap();
+        assert_eq!(&[7u8; 1 << 16][..], &*reader);
+        assert!(matches!(
+            replica
+                .update(&locator, Object::PracticeChallenge(vec![8u8; 100]))
+                .err(),
+            Some(CoordinatorError::CoordinatorReadOnly { .. })
+        ));
+
+        // Shrink the object in the primary storage, while the replica holds a reader.
+        primary
+            .update(&locator, Object::PracticeChallenge(vec![8u8; 100]))
+            .unwrap();
+        assert_eq!(&[7u8; 1 << 16][..], &*reader);
+
+        // Check that the replica reads the updated object once it is refreshed.
+        replica.refresh().unwrap();
+        assert_eq!(&[8u8; 100][..], &*replica.reader(&locator).unwrap());
+    }
+
+    #[test]
+    #[serial]
+    fn test_insert_update_health() {
+        let environment = initialize_test_environment(&TEST_ENVIRONMENT_3);
+        let storage = test_storage(&environment);
+        let mut storage = storage.write().unwrap();
+
+        let inserted_at = Utc::now();
+        storage.insert(Locator::Health, Object::Health(inserted_at)).unwrap();
+        match storage.get(&Locator::Health).unwrap() {
+            Object::Health(stored) => assert_eq!(inserted_at, stored),
+            _ => panic!("Expected a health object"),
+        }
+
+        let updated_at = inserted_at + chrono::Duration::seconds(30);
+        storage.update(&Locator::Health, Object::Health(updated_at)).unwrap();
+        match storage.get(&Locator::Health).unwrap() {
+            Object::Health(stored) => assert_eq!(updated_at, stored),
+            _ => panic!("Expected a health object"),
+        }
+
+        // Check that the health file is stored in plaintext, so monitors can read it.
+        let path = storage.to_path(&Locator::Health).unwrap();
+        let contents = fs::read(path.as_path()).unwrap();
+        assert_eq!(updated_at, serde_json::from_slice::<DateTime<Utc>>(&contents).unwrap());
+    }
+
+    #[test]
+    #[serial]
+    fn test_remove_waits_for_readers() {
+        let environment = initialize_test_environment(&TEST_ENVIRONMENT_3);
+        let storage = test_storage(&environment);
+        let locator = Locator::RoundHeight;
+
+        let path = {
+            let mut storage = storage.write().unwrap();
+            storage.insert(locator, Object::RoundHeight(7)).unwrap();
+            storage.to_path(&locator).unwrap()
+        };
+
+        // Hold a reader on the locator, after the storage lock is released.
+        let reader = storage.read().unwrap().reader(&locator).unwrap();
+
+        // Remove the locator in another thread while the reader is held.
+        let remover_storage = storage.clone();
+        let handle = std::thread::spawn(move || remover_storage.write().unwrap().remove(&locator));
+
+        // Wait until the other thread holds the storage write lock.
+        while storage.try_read().is_ok() {
+            std::thread::yield_now();
+        }
+
+        // Check that the file is not deleted while the reader is held.
+        assert!(path.as_path().exists());
+        let round_height: u64 = serde_json::from_slice(&*reader).unwrap();
+        assert_eq!(7, round_height);
+        drop(reader);
+
+        // Check that the removal completed once the reader was dropped, and the file is gone.
+        handle.join().unwrap().unwrap();
+        assert!(!storage.read().unwrap().exists(&locator));
+        assert!(!path.as_path().exists());
+    }
+
+    #[test]
+    #[serial]
+    fn test_update_waits_for_readers() {
+        let environment = initialize_test_environment(&TEST_ENVIRONMENT_3);
+        let storage = test_storage(&environment);
+        let locator = Locator::PracticeChallenge;
+
+        storage
+            .write()
+            .unwrap()
+            .insert(locator, Object::PracticeChallenge(vec![7u8; 1 << 16]))
+            .unwrap();
+
+        // Hold a reader on the locator, after the storage lock is released.
+        let reader = storage.read().unwrap().reader(&locator).unwrap();
+
+        // Shrink the object in another thread while the reader is held.
+        let updater_storage = storage.clone();
+        let handle = std::thread::spawn(move || {
+            updater_storage
+                .write()
+                .unwrap()
+                .update(&locator, Object::PracticeChallenge(vec![8u8; 100]))
+        });
+
+        // Wait until the other thread holds the storage write lock.
+        while storage.try_read().is_ok() {
+            std::thread::yield_now();
+        }
+
+        // Check that the reader still maps the whole object while it is held.
+        assert_eq!(&[7u8; 1 << 16][..], &reader[..]);
+        drop(reader);
+
+        // Check that the update completed once the reader was dropped.
+        handle.join().unwrap().unwrap();
+        assert_eq!(&[8u8; 100][..], &storage.read().unwrap().reader(&locator).unwrap()[..]);
+    }
+
+    #[test]
+    #[serial]
+    fn test_insert_from_reader() {
+        let environment = initialize_test_environment(&TEST_ENVIRONMENT);
+        let mut storage = Disk::load(&environment).unwrap();
+
+        // Insert an object spanning several batches, read from a reader.
+        let locator = Locator::PracticeChallenge;
+        let bytes: Vec<u8> = (0..(3 * STREAM_BATCH_SIZE + 123)).map(|i| (i % 251) as u8).collect();
+        let object = Object::from_reader(std::io::Cursor::new(bytes.clone()), bytes.len() as u64);
+        assert_eq!(bytes.len() as u64, object.size());
+        storage.insert(locator, object).unwrap();
+
+        // Check that the stored bytes match.
+        match storage.get(&locator).unwrap() {
+            Object::PracticeChallenge(stored) => assert!(stored == bytes),
+            _ => panic!("Expected the practice challenge"),
+        }
+
+        // Check that a reader which ends before the given size is rejected, and leaves no locator.
+        storage.remove(&locator).unwrap();
+        let object = Object::from_reader(std::io::Cursor::new(vec![7u8; 100]), 200);
+        assert!(matches!(
+            storage.insert(locator, object),
+            Err(CoordinatorError::StorageObjectStreamTooShort)
+        ));
+        assert!(!storage.exists(&locator));
+
+        // Check that an update from a reader which ends early leaves the file as it was.
+        storage
+            .insert(locator, Object::PracticeChallenge(bytes.clone()))
+            .unwrap();
+        let object = Object::from_reader(
+            std::io::Cursor::new(vec![7u8; 2 * STREAM_BATCH_SIZE]),
+            bytes.len() as u64,
+        );
+        assert!(matches!(
+            storage.update(&locator, object),
+            Err(CoordinatorError::StorageObjectStreamTooShort)
+        ));
+        match storage.get(&locator).unwrap() {
+            Object::PracticeChallenge(stored) => assert!(stored == bytes),
+            _ => panic!("Expected the practice challenge"),
+        }
+        let path = storage.to_path(&locator).unwrap();
+        assert!(!Path::new(&format!("{}.tmp", path)).exists());
+
+        // Check that a stream is only read once, even through a clone.
+        let object = Object::from_reader(std::io::Cursor::new(vec![9u8; 100]), 100);
+        let clone = object.clone();
+        storage.update(&locator, object).unwrap();
+        assert!(matches!(
+            storage.update(&locator, clone.clone()),
+            Err(CoordinatorError::StorageObjectStreamConsumed)
+        ));
+        assert!(matches!(
+            clone.to_bytes(),
+            Err(CoordinatorError::StorageObjectStreamConsumed)
+        ));
+        match storage.get(&locator).unwrap() {
+            Object::PracticeChallenge(stored) => assert_eq!(vec![9u8; 100], stored),
+            _ => panic!("Expected the practice challenge"),
+        }
+    }
+
+    /// Returns the test environment with the storage encryption key read from the given environment variable.
+    fn encrypted_test_environment(variable: &str) -> Environment {
+        Testing::from(Parameters::Test8Chunks)
+            .storage_encryption_key(Some(StorageKeySource::EnvironmentVariable(variable.to_string())))
+            .into()
+    }
+
+    #[test]
+    #[serial]
+    fn test_encryption_reads_unencrypted_state() {
+        let environment = initialize_test_environment(&TEST_ENVIRONMENT);
+
+        let round = test_round_1_partial_json().unwrap();
+        let round_height = round.round_height();
+        let locator = Locator::RoundState { round_height };
+
+        // Store the round state before encryption is enabled.
+        {
+            let mut storage = Disk::load(&environment).unwrap();
+            storage.insert(locator, Object::RoundState(round.clone())).unwrap();
+            assert!(storage.manifest.read().unwrap().encrypted.is_empty());
+        }
+
+        // Enable encryption, and check that the unencrypted round state is still readable.
+        std::env::set_var("TEST_DISK_ENCRYPTION_KEY", StorageKey::generate().to_string());
+        let environment = encrypted_test_environment("TEST_DISK_ENCRYPTION_KEY");
+        let mut storage = Disk::load(&environment).unwrap();
+        match storage.get(&locator).unwrap() {
+            Object::RoundState(candidate) => assert_eq!(round, candidate),
+            _ => panic!("Expected a round state"),
+        }
+
+        // Check that the round state is encrypted once it is written again.
+        let mut round = round;
+        round
+            .chunk_mut(0)
+            .unwrap()
+            .set_lock_holder_unsafe(Some(TEST_CONTRIBUTOR_ID_2.clone()));
+        storage.update(&locator, Object::RoundState(round.clone())).unwrap();
+        for chunk in round.chunks() {
+            let chunk_state = Locator::ChunkState {
+                round_height,
+                chunk_id: chunk.chunk_id(),
+            };
+            assert!(storage.manifest.read().unwrap().is_encrypted(&chunk_state));
+            let path = storage.to_path(&chunk_state).unwrap();
+            assert!(serde_json::from_slice::<Chunk>(&fs::read(path.as_path()).unwrap()).is_err());
+        }
+        match storage.get(&locator).unwrap() {
+            Object::RoundState(candidate) => assert_eq!(round, candidate),
+            _ => panic!("Expected a round state"),
+        }
+
+        // Check that public locators are never encrypted.
+        storage.insert(Locator::RoundHeight, Object::RoundHeight(1)).unwrap();
+        assert!(!storage.manifest.read().unwrap().is_encrypted(&Locator::RoundHeight));
+    }
+
+    #[test]
+    #[serial]
+    fn test_encryption_with_wrong_key() {
+        initialize_test_environment(&TEST_ENVIRONMENT);
+
+        std::env::set_var("TEST_DISK_ENCRYPTION_KEY", StorageKey::generate().to_string());
+        let environment = encrypted_test_environment("TEST_DISK_ENCRYPTION_KEY");
+        {
+            let storage = Disk::load(&environment).unwrap();
+            assert!(storage
+                .manifest
+                .read()
+                .unwrap()
+                .is_encrypted(&Locator::CoordinatorState));
+        }
+
+        // Check that the coordinator state cannot be read with a different key.
+        std::env::set_var("TEST_DISK_ENCRYPTION_KEY", StorageKey::generate().to_string());
+        let storage = Disk::load(&environment).unwrap();
+        assert!(matches!(
+            storage.get(&Locator::CoordinatorState).err(),
+            Some(CoordinatorError::StorageDecryptionFailed)
+        ));
+
+        // Check that the coordinator state cannot be read without a key.
+        let storage = Disk::load(&TEST_ENVIRONMENT).unwrap();
+        assert!(matches!(
+            storage.get(&Locator::CoordinatorState).err(),
+            Some(CoordinatorError::StorageEncryptionKeyMissing)
+        ));
+    }
+
+    #[test]
+    #[serial]
+    fn test_encryption_key_rotation() {
+        initialize_test_environment(&TEST_ENVIRONMENT);
+
+        let round = test_round_1_partial_json().unwrap();
+        let locator = Locator::RoundState {
+            round_height: round.round_height(),
+        };
+
+        std::env::set_var("TEST_DISK_ENCRYPTION_KEY", StorageKey::generate().to_string());
+        let environment = encrypted_test_environment("TEST_DISK_ENCRYPTION_KEY");
+        let new_key = StorageKey::generate();
+        {
+            let mut storage = Disk::load(&environment).unwrap();
+            storage.insert(locator, Object::RoundState(round.clone())).unwrap();
+
+            // Fail to rotate the key, by taking the place of a replacement with a directory.
+            let path = storage.to_path(&Locator::CoordinatorState).unwrap();
+            fs::create_dir(format!("{}.tmp", path)).unwrap();
+            assert!(storage.rotate_encryption_key(Some(new_key.clone())).is_err());
+            fs::remove_dir(format!("{}.tmp", path)).unwrap();
+
+            // Check that storage is still readable with the current key.
+            match storage.get(&locator).unwrap() {
+                Object::RoundState(candidate) => assert_eq!(round, candidate),
+                _ => panic!("Expected a round state"),
+            }
+            drop(storage);
+            let mut storage = Disk::load(&environment).unwrap();
+            match storage.get(&locator).unwrap() {
+                Object::RoundState(candidate) => assert_eq!(round, candidate),
+                _ => panic!("Expected a round state"),
+            }
+
+            storage.rotate_encryption_key(Some(new_key.clone())).unwrap();
+        }
+
+        // Check that storage is readable with the new key, and no longer with the old one.
+        let storage = Disk::load(&environment).unwrap();
+        assert!(matches!(
+            storage.get(&locator).err(),
+            Some(CoordinatorError::StorageDecryptionFailed)
+        ));
+        drop(storage);
+        std::env::set_var("TEST_DISK_ENCRYPTION_KEY", new_key.to_string());
+        let mut storage = Disk::load(&environment).unwrap();
+        match storage.get(&locator).unwrap() {
+            Object::RoundState(candidate) => assert_eq!(round, candidate),
+            _ => panic!("Expected a round state"),
+        }
+
+        // Check that rotating to no key decrypts storage.
+        storage.rotate_encryption_key(None).unwrap();
+        assert!(storage.manifest.read().unwrap().encrypted.is_empty());
+        let storage = Disk::load(&TEST_ENVIRONMENT).unwrap();
+        match storage.get(&locator).unwrap() {
+            Object::RoundState(candidate) => assert_eq!(round, candidate),
+            _ => panic!("Expected a round state"),
+        }
+    }
 }
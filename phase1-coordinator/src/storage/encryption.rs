@@ -0,0 +1,182 @@
+use crate::CoordinatorError;
+
+use age::x25519::Identity;
+use secrecy::ExposeSecret;
+use serde::{Deserialize, Serialize};
+use std::{
+    env,
+    fmt,
+    fs,
+    io::{Read, Write},
+    iter,
+    str::FromStr,
+};
+use tracing::error;
+
+/// The source of the key used to encrypt the coordinator metadata in storage.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StorageKeySource {
+    /// The key is read from the file at the given path.
+    File(String),
+    /// The key is read from the environment variable with the given name.
+    EnvironmentVariable(String),
+}
+
+///
+/// The key used to encrypt the coordinator metadata in storage.
+///
+/// The coordinator state, round headers and chunk states hold the IDs and IP addresses
+/// of participants, so they are encrypted at rest once a key is configured. Contribution
+/// files and their signatures are public, and are stored unencrypted.
+///
+/// The key is an age X25519 identity, as generated by `age-keygen`.
+///
+#[derive(Clone)]
+pub struct StorageKey {
+    identity: Identity,
+}
+
+impl StorageKey {
+    ///
+    /// Returns a new randomly generated key.
+    ///
+    #[inline]
+    pub fn generate() -> Self {
+        Self {
+            identity: Identity::generate(),
+        }
+    }
+
+    ///
+    /// Returns the key from the given source.
+    ///
+    #[inline]
+    pub fn load(source: &StorageKeySource) -> Result<Self, CoordinatorError> {
+        let key = match source {
+            StorageKeySource::File(path) => fs::read_to_string(path).map_err(|error| {
+                error!("Unable to read the storage encryption key from {} ({})", path, error);
+                CoordinatorError::StorageEncryptionKeyMissing
+            })?,
+            StorageKeySource::EnvironmentVariable(name) => env::var(name).map_err(|error| {
+                error!("Unable to read the storage encryption key from ${} ({})", name, error);
+                CoordinatorError::StorageEncryptionKeyMissing
+            })?,
+        };
+
+        // Skip the comments that `age-keygen` writes before the key.
+        key.lines()
+            .map(str::trim)
+            .find(|line| !line.is_empty() && !line.starts_with('#'))
+            .ok_or(CoordinatorError::StorageEncryptionKeyInvalid)?
+            .parse()
+    }
+
+    ///
+    /// Returns the given bytes encrypted with this key.
+    ///
+    #[inline]
+    pub fn encrypt(&self, plaintext: &[u8]) -> Result<Vec<u8>, CoordinatorError> {
+        let encryptor = age::Encryptor::with_recipients(vec![Box::new(self.identity.to_public())]);
+
+        let mut ciphertext = Vec::with_capacity(plaintext.len());
+        let mut writer = encryptor
+            .wrap_output(&mut ciphertext)
+            .map_err(|_| CoordinatorError::StorageEncryptionFailed)?;
+        writer
+            .write_all(plaintext)
+            .map_err(|_| CoordinatorError::StorageEncryptionFailed)?;
+        writer.finish().map_err(|_| CoordinatorError::StorageEncryptionFailed)?;
+
+        Ok(ciphertext)
+    }
+
+    ///
+    /// Returns the given bytes decrypted with this key.
+    ///
+    #[inline]
+    pub fn decrypt(&self, ciphertext: &[u8]) -> Result<Vec<u8>, CoordinatorError> {
+        let decryptor = match age::Decryptor::new(ciphertext) {
+            Ok(age::Decryptor::Recipients(decryptor)) => decryptor,
+            _ => return Err(CoordinatorError::StorageDecryptionFailed),
+        };
+
+        let identity = Box::new(self.identity.clone()) as Box<dyn age::Identity>;
+        let mut reader = decryptor
+            .decrypt(iter::once(identity))
+            .map_err(|_| CoordinatorError::StorageDecryptionFailed)?;
+
+        let mut plaintext = Vec::with_capacity(ciphertext.len());
+        reader
+            .read_to_end(&mut plaintext)
+            .map_err(|_| CoordinatorError::StorageDecryptionFailed)?;
+
+        Ok(plaintext)
+    }
+}
+
+impl FromStr for StorageKey {
+    type Err = CoordinatorError;
+
+    #[inline]
+    fn from_str(key: &str) -> Result<Self, Self::Err> {
+        let identity = Identity::from_str(key.trim()).map_err(|error| {
+            error!("Invalid storage encryption key ({})", error);
+            CoordinatorError::StorageEncryptionKeyInvalid
+        })?;
+        Ok(Self { identity })
+    }
+}
+
+impl fmt::Display for StorageKey {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.identity.to_string().expose_secret())
+    }
+}
+
+impl fmt::Debug for StorageKey {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "StorageKey({})", self.identity.to_public())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encrypt_decrypt() {
+        let key = StorageKey::generate();
+        let plaintext = b"{\"participant\":\"127.0.0.1\"}".to_vec();
+
+        let ciphertext = key.encrypt(&plaintext).unwrap();
+        assert_ne!(plaintext, ciphertext);
+        assert_eq!(plaintext, key.decrypt(&ciphertext).unwrap());
+    }
+
+    #[test]
+    fn test_decrypt_with_wrong_key() {
+        let ciphertext = StorageKey::generate().encrypt(b"round state").unwrap();
+        assert!(matches!(
+            StorageKey::generate().decrypt(&ciphertext),
+            Err(CoordinatorError::StorageDecryptionFailed)
+        ));
+    }
+
+    #[test]
+    fn test_load_key_from_environment_variable() {
+        let key = StorageKey::generate();
+        env::set_var("TEST_STORAGE_ENCRYPTION_KEY", format!("# created: today\n{}\n", key));
+
+        let source = StorageKeySource::EnvironmentVariable("TEST_STORAGE_ENCRYPTION_KEY".to_string());
+        let loaded = StorageKey::load(&source).unwrap();
+        assert_eq!(key.to_string(), loaded.to_string());
+
+        env::set_var("TEST_STORAGE_ENCRYPTION_KEY", "not a key");
+        assert!(matches!(
+            StorageKey::load(&source),
+            Err(CoordinatorError::StorageEncryptionKeyInvalid)
+        ));
+    }
+}
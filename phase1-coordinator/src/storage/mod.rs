@@ -1,5 +1,8 @@
 pub mod disk;
 pub use disk::*;
 
+pub mod encryption;
+pub use encryption::*;
+
 pub mod storage;
 pub use storage::*;
@@ -1,18 +1,21 @@
 use crate::{
     environment::Environment,
-    objects::{ContributionFileSignature, Round},
+    objects::{Chunk, ContributionFileSignature, Round, RoundHeader},
     CoordinatorError,
     CoordinatorState,
 };
 use phase1::helpers::CurveKind;
 
-use memmap::MmapMut;
+use chrono::{DateTime, Utc};
+use memmap::{Mmap, MmapMut};
 use serde::{Deserialize, Serialize};
 use std::{
     convert::TryFrom,
+    fmt,
+    io::{self, Read},
     ops::{Deref, DerefMut},
     path::Path,
-    sync::{RwLockReadGuard, RwLockWriteGuard},
+    sync::{Arc, Mutex, RwLock, RwLockReadGuard, RwLockWriteGuard},
 };
 use zexe_algebra::{Bls12_377, BW6_761};
 
@@ -91,10 +94,29 @@ impl ContributionSignatureLocator {
 pub enum Locator {
     CoordinatorState,
     RoundHeight,
-    RoundState { round_height: u64 },
-    RoundFile { round_height: u64 },
+    RoundState {
+        round_height: u64,
+    },
+    RoundHeader {
+        round_height: u64,
+    },
+    ChunkState {
+        round_height: u64,
+        chunk_id: u64,
+    },
+    RoundFile {
+        round_height: u64,
+    },
     ContributionFile(ContributionLocator),
     ContributionFileSignature(ContributionSignatureLocator),
+    PracticeChallenge,
+    Health,
+    QuarantinedContributionFile {
+        round_height: u64,
+        chunk_id: u64,
+        contribution_id: u64,
+        attempt: u64,
+    },
 }
 
 impl From<ContributionLocator> for Locator {
@@ -115,36 +137,64 @@ pub enum Object {
     CoordinatorState(CoordinatorState),
     RoundHeight(u64),
     RoundState(Round),
+    RoundHeader(RoundHeader),
+    ChunkState(Chunk),
     RoundFile(Vec<u8>),
     ContributionFile(Vec<u8>),
     ContributionFileSignature(ContributionFileSignature),
+    PracticeChallenge(Vec<u8>),
+    Health(DateTime<Utc>),
+    Stream(ObjectStream),
 }
 
 impl Object {
-    pub fn to_bytes(&self) -> Vec<u8> {
-        match self {
-            Object::CoordinatorState(state) => {
-                serde_json::to_vec_pretty(state).expect("coordinator state to bytes failed")
-            }
-            Object::RoundHeight(height) => serde_json::to_vec(height).expect("round height to bytes failed"),
-            Object::RoundState(round) => serde_json::to_vec_pretty(round).expect("round state to bytes failed"),
+    ///
+    /// Returns an object with the contents read from the given reader, which yields `size` bytes.
+    ///
+    /// Storage writes the contents to the file of the object in batches as they are read,
+    /// so a large round or contribution file does not need to be held in memory to be stored.
+    ///
+    pub fn from_reader<R: Read + Send + 'static>(reader: R, size: u64) -> Self {
+        Object::Stream(ObjectStream {
+            state: Arc::new(Mutex::new(StreamState {
+                reader: Box::new(reader),
+                is_consumed: false,
+            })),
+            size,
+        })
+    }
+
+    ///
+    /// Returns the contents of the object.
+    ///
+    /// The contents of a stream are read into memory, which fails if the stream was
+    /// already read, or if its reader ends before the size of the stream.
+    ///
+    pub fn to_bytes(&self) -> Result<Vec<u8>, CoordinatorError> {
+        Ok(match self {
+            Object::CoordinatorState(state) => serde_json::to_vec_pretty(state)?,
+            Object::RoundHeight(height) => serde_json::to_vec(height)?,
+            Object::RoundState(round) => serde_json::to_vec_pretty(round)?,
+            Object::RoundHeader(header) => serde_json::to_vec_pretty(header)?,
+            Object::ChunkState(chunk) => serde_json::to_vec_pretty(chunk)?,
             Object::RoundFile(round) => round.to_vec(),
             Object::ContributionFile(contribution) => contribution.to_vec(),
-            Object::ContributionFileSignature(signature) => {
-                serde_json::to_vec_pretty(signature).expect("contribution file signature to bytes failed")
-            }
-        }
+            Object::ContributionFileSignature(signature) => serde_json::to_vec_pretty(signature)?,
+            Object::PracticeChallenge(challenge) => challenge.to_vec(),
+            Object::Health(updated_at) => serde_json::to_vec(updated_at)?,
+            Object::Stream(stream) => stream.to_bytes()?,
+        })
     }
 
     /// Returns the size in bytes of the object.
     pub fn size(&self) -> u64 {
         match self {
-            Object::CoordinatorState(_) => self.to_bytes().len() as u64,
-            Object::RoundHeight(_) => self.to_bytes().len() as u64,
-            Object::RoundState(_) => self.to_bytes().len() as u64,
             Object::RoundFile(round) => round.len() as u64,
             Object::ContributionFile(contribution) => contribution.len() as u64,
-            Object::ContributionFileSignature(_) => self.to_bytes().len() as u64,
+            Object::PracticeChallenge(challenge) => challenge.len() as u64,
+            Object::Stream(stream) => stream.size(),
+            // The remaining objects are serialized, which does not read a stream.
+            _ => self.to_bytes().expect("object to bytes failed").len() as u64,
         }
     }
 
@@ -189,6 +239,79 @@ impl Object {
     }
 }
 
+/// The reader of an object stream, and whether it was read.
+struct StreamState {
+    reader: Box<dyn Read + Send>,
+    is_consumed: bool,
+}
+
+/// The contents of an object, read from a reader of a known size.
+///
+/// The reader is consumed as it is read, so the contents can only be read once.
+/// Clones of a stream share its reader, so once any of them is read, reading
+/// another one fails with `CoordinatorError::StorageObjectStreamConsumed`.
+#[derive(Clone)]
+pub struct ObjectStream {
+    state: Arc<Mutex<StreamState>>,
+    size: u64,
+}
+
+impl ObjectStream {
+    /// Returns the size in bytes of the contents.
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    ///
+    /// Reads the contents in batches of at most `batch_size` bytes, passing each batch to `action`.
+    ///
+    /// Returns `CoordinatorError::StorageObjectStreamConsumed` if the stream was already read,
+    /// and `CoordinatorError::StorageObjectStreamTooShort` if its reader ends before its size.
+    ///
+    pub(crate) fn read_batches(
+        &self,
+        batch_size: usize,
+        mut action: impl FnMut(&[u8]) -> Result<(), CoordinatorError>,
+    ) -> Result<(), CoordinatorError> {
+        let mut state = self.state.lock().unwrap();
+        if state.is_consumed {
+            return Err(CoordinatorError::StorageObjectStreamConsumed);
+        }
+        state.is_consumed = true;
+
+        let mut buffer = vec![0; std::cmp::min(batch_size as u64, self.size) as usize];
+        let mut remaining = self.size;
+        while remaining > 0 {
+            let batch = &mut buffer[..std::cmp::min(remaining, batch_size as u64) as usize];
+            if let Err(error) = state.reader.read_exact(batch) {
+                return Err(match error.kind() {
+                    io::ErrorKind::UnexpectedEof => CoordinatorError::StorageObjectStreamTooShort,
+                    _ => error.into(),
+                });
+            }
+            action(batch)?;
+            remaining -= batch.len() as u64;
+        }
+        Ok(())
+    }
+
+    /// Reads the contents into memory.
+    fn to_bytes(&self) -> Result<Vec<u8>, CoordinatorError> {
+        let mut bytes = Vec::with_capacity(self.size as usize);
+        self.read_batches(self.size as usize, |batch| {
+            bytes.extend_from_slice(batch);
+            Ok(())
+        })?;
+        Ok(bytes)
+    }
+}
+
+impl fmt::Debug for ObjectStream {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ObjectStream {{ size: {} }}", self.size)
+    }
+}
+
 pub(crate) enum Lock<'a, T> {
     Read(RwLockReadGuard<'a, T>),
     Write(RwLockWriteGuard<'a, T>),
@@ -220,9 +343,75 @@ pub(crate) type StorageLock<'a> = Lock<'a, Box<dyn Storage>>;
 
 // TODO (howardwu): Genericize this if necessary for remote objects.
 //  Alternatively, usage of temporary memory-backed local files can also work.
-pub type ObjectReader<'a> = RwLockReadGuard<'a, MmapMut>;
+//
+// Writers borrow the storage they were obtained from, so a locator cannot be
+// removed while a writer is alive.
 pub type ObjectWriter<'a> = RwLockWriteGuard<'a, MmapMut>;
 
+/// A reader of an object in storage, which holds the read lock of the memory map of the object.
+///
+/// The memory map is checked to span the file of the object when the reader is created. Storage
+/// never truncates a file in place, and writes and removals of the locator acquire the write lock
+/// of its memory map, so the pages of a reader remain backed by the file until it is dropped.
+///
+/// A reader does not borrow the storage, so it may be held after the storage lock is released,
+/// but it must be dropped before the storage lock is acquired again, as writes and removals of
+/// the locator wait for it while they hold the storage write lock.
+pub struct ObjectReader {
+    memory: ObjectMemory,
+}
+
+enum ObjectMemory {
+    /// A memory map of an opened locator, and the read lock held on it.
+    ///
+    /// The guard is declared first, so it is dropped before the memory map it borrows.
+    Locked {
+        guard: RwLockReadGuard<'static, MmapMut>,
+        _memory: Arc<RwLock<MmapMut>>,
+    },
+    /// A read-only memory map of an opened locator, which storage never writes to.
+    ReadOnly(Arc<Mmap>),
+}
+
+impl ObjectReader {
+    /// Returns a reader of the given memory map, which holds its read lock until it is dropped.
+    pub(crate) fn new(memory: Arc<RwLock<MmapMut>>) -> Self {
+        // Safety: the lock is owned by the `Arc` held by the reader, so it does not move
+        // or get dropped while the guard borrows it, and the guard is dropped first.
+        let lock: &'static RwLock<MmapMut> = unsafe { &*Arc::as_ptr(&memory) };
+        Self {
+            memory: ObjectMemory::Locked {
+                guard: lock.read().unwrap(),
+                _memory: memory,
+            },
+        }
+    }
+
+    /// Returns a reader of the given read-only memory map.
+    pub(crate) fn read_only(memory: Arc<Mmap>) -> Self {
+        Self {
+            memory: ObjectMemory::ReadOnly(memory),
+        }
+    }
+}
+
+impl Deref for ObjectReader {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        match &self.memory {
+            ObjectMemory::Locked { guard, .. } => &guard[..],
+            ObjectMemory::ReadOnly(memory) => &memory[..],
+        }
+    }
+}
+
+impl AsRef<[u8]> for ObjectReader {
+    fn as_ref(&self) -> &[u8] {
+        self.deref()
+    }
+}
+
 /// A standard model for storage.
 pub trait Storage: Send + Sync + StorageLocator + StorageObject {
     /// Loads a new instance of `Storage`.
@@ -259,6 +448,10 @@ pub trait Storage: Send + Sync + StorageLocator + StorageObject {
 
     /// Process a [StorageAction] which mutates the storage.
     fn process(&mut self, action: StorageAction) -> Result<(), CoordinatorError>;
+
+    /// Reloads the view of the storage from its backing store,
+    /// to observe the changes made by another coordinator.
+    fn refresh(&mut self) -> Result<(), CoordinatorError>;
 }
 
 /// The path to a resource defined by a [Locator].
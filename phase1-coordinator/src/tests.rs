@@ -1,23 +1,46 @@
 use crate::{
     authentication::Dummy,
     commands::{Seed, SigningKey, SEED_LENGTH},
-    environment::{Environment, Parameters, Settings, Testing},
-    objects::Task,
-    storage::Storage,
+    environment::{CoordinatorMode, Environment, Parameters, Settings, Testing},
+    objects::{
+        AdmissionCredentials,
+        AdmissionPolicy,
+        ApiError,
+        ChunkingReport,
+        ClientVersion,
+        ClientVersionPolicy,
+        ContributionFileSignature,
+        ContributionRetryPolicy,
+        ContributionState,
+        CoordinatorEvent,
+        HardwareDeclaration,
+        HardwarePolicy,
+        InvitationPolicy,
+        ProofOfWorkChallenge,
+        ProofOfWorkPolicy,
+        RoundHistory,
+        RoundVerificationMetadata,
+        Task,
+        Version,
+    },
+    storage::{ContributionLocator, Locator, Object, Storage},
     testing::prelude::*,
     Coordinator,
     CoordinatorError,
     MockTimeSource,
+    MultiCoordinator,
     Participant,
     Round,
 };
 use chrono::Utc;
 use phase1::{helpers::CurveKind, ContributionMode, ProvingSystem};
+use setup_utils::calculate_hash;
 
 use rand::RngCore;
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 use std::{
     collections::{HashSet, LinkedList},
+    io::Read,
     iter::FromIterator,
     sync::Arc,
 };
@@ -2173,6 +2196,170 @@ fn participant_lock_timeout_drop_test() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Test that when a verifier is dropped for holding a lock for longer than
+/// [Environment::participant_lock_timeout], another verifier can take over
+/// the chunk, and a late upload of the dropped verifier is rejected.
+#[test]
+#[serial]
+fn verifier_lock_takeover_test() -> anyhow::Result<()> {
+    let time = Arc::new(MockTimeSource::new(Utc::now()));
+
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+
+    let testing_deployment: Testing = Testing::from(parameters)
+        .contributor_seen_timeout(chrono::Duration::minutes(20))
+        .participant_lock_timeout(chrono::Duration::minutes(10));
+
+    let environment = initialize_test_environment(&Environment::from(testing_deployment));
+
+    // Instantiate a coordinator.
+    let coordinator = Coordinator::new_with_time(environment, Box::new(Dummy), time.clone())?;
+
+    // Initialize the ceremony to round 0.
+    coordinator.initialize()?;
+
+    let contributor = create_contributor_test_details("1");
+    let verifier1 = create_verifier_test_details("1");
+    let verifier2 = create_verifier_test_details("2");
+    coordinator.add_to_queue(contributor.participant.clone(), 10)?;
+    coordinator.add_to_queue(verifier1.participant.clone(), 10)?;
+    coordinator.add_to_queue(verifier2.participant.clone(), 10)?;
+
+    // Update the ceremony to round 1.
+    coordinator.update()?;
+
+    contributor.contribute_to(&coordinator)?;
+
+    // The verifier assigned the verification locks the chunk, which initializes its next challenge file.
+    let (dropped, successor, (chunk_id, stale_locators)) = match coordinator.try_lock(&verifier1.participant) {
+        Ok(lock) => (verifier1, verifier2, lock),
+        Err(_) => {
+            let lock = coordinator.try_lock(&verifier2.participant)?;
+            (verifier2, verifier1, lock)
+        }
+    };
+    assert!(!stale_locators.lock_token().is_empty());
+    let next_challenge = Locator::ContributionFile(stale_locators.next_contribution());
+    assert!(coordinator.storage().read().unwrap().exists(&next_challenge));
+
+    let (_, mut events) = coordinator.subscribe()?;
+
+    // Push the time past the timeout, to drop the verifier holding the lock.
+    time.update(|prev| prev + chrono::Duration::minutes(11));
+    coordinator.update()?;
+    assert!(!coordinator.is_current_verifier(&dropped.participant));
+
+    // Check that the partial next challenge file was removed, and the takeover was recorded.
+    assert!(!coordinator.storage().read().unwrap().exists(&next_challenge));
+    let mut revoked = false;
+    while let Ok(event) = events.try_recv() {
+        revoked |= event
+            == CoordinatorEvent::VerifierLockRevoked {
+                round_height: 1,
+                chunk_id,
+                participant: dropped.participant.clone(),
+            };
+    }
+    assert!(revoked);
+
+    // The remaining verifier takes over the chunk, and is issued a new lock token.
+    let (takeover_chunk_id, locators) = coordinator.try_lock(&successor.participant)?;
+    assert_eq!(chunk_id, takeover_chunk_id);
+    assert_ne!(stale_locators.lock_token(), locators.lock_token());
+
+    // The late upload of the dropped verifier, and an upload with its stale token, are rejected.
+    let signature = ContributionFileSignature::new(
+        hex::encode(vec![0u8; 64]),
+        ContributionState::new(vec![0u8; 64], vec![0u8; 64], Some(vec![0u8; 64]))?,
+    )?;
+    for verifier in &[&dropped.participant, &successor.participant] {
+        let result = coordinator.upload_next_challenge(
+            verifier,
+            chunk_id,
+            stale_locators.lock_token(),
+            vec![1u8; 64],
+            signature.clone(),
+        );
+        assert!(matches!(result, Err(CoordinatorError::ChunkLockTokenMismatch)));
+    }
+
+    // The remaining verifier completes the verification of the chunk.
+    let contribution_id = locators.current_contribution().contribution_id();
+    coordinator.run_verification(
+        1,
+        chunk_id,
+        contribution_id,
+        &successor.participant,
+        &successor.signing_key,
+    )?;
+    coordinator.try_verify(&successor.participant, chunk_id)?;
+    let chunk = coordinator.get_chunk(chunk_id)?;
+    assert!(chunk.get_contribution(contribution_id)?.is_verified());
+
+    Ok(())
+}
+
+/// Test that the finish time of a round completed by a verification
+/// is taken from the time source of the coordinator.
+#[test]
+#[serial]
+fn round_finished_at_uses_time_source_test() -> anyhow::Result<()> {
+    // Start from a time which cannot be mistaken for the system time.
+    let start = Utc::now() - chrono::Duration::days(1);
+    let time = Arc::new(MockTimeSource::new(start));
+
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+    let environment = initialize_test_environment(&Testing::from(parameters).into());
+    let number_of_chunks = environment.number_of_chunks() as usize;
+
+    // Instantiate a coordinator.
+    let coordinator = Coordinator::new_with_time(environment, Box::new(Dummy), time.clone())?;
+
+    // Initialize the ceremony to round 0.
+    coordinator.initialize()?;
+
+    let (contributor1, contributor_signing_key1, seed1) = create_contributor("1");
+    let (verifier, verifier_signing_key) = create_verifier("1");
+    coordinator.add_to_queue(contributor1.clone(), 10)?;
+    coordinator.add_to_queue(verifier.clone(), 10)?;
+
+    // Update the ceremony to round 1.
+    coordinator.update()?;
+    assert_eq!(1, coordinator.current_round_height()?);
+
+    // Contribute and verify all chunks, advancing the time before the final verification.
+    for _ in 0..(number_of_chunks - 1) {
+        coordinator.contribute(&contributor1, &contributor_signing_key1, &seed1)?;
+        coordinator.verify(&verifier, &verifier_signing_key)?;
+    }
+    coordinator.contribute(&contributor1, &contributor_signing_key1, &seed1)?;
+    assert_eq!(None, coordinator.current_round()?.finished_at());
+
+    time.update(|prev| prev + chrono::Duration::minutes(5));
+    coordinator.verify(&verifier, &verifier_signing_key)?;
+
+    // Check that the round finished at the mocked time.
+    let round = coordinator.current_round()?;
+    assert!(round.is_complete());
+    assert_eq!(Some(start + chrono::Duration::minutes(5)), round.finished_at());
+
+    Ok(())
+}
+
 #[test]
 #[serial]
 fn round_on_groth16_bls12_377() {
@@ -2190,3 +2377,3446 @@ fn round_on_groth16_bw6_761() {
 fn round_on_marlin_bls12_377() {
     execute_round(ProvingSystem::Marlin, CurveKind::Bls12_377).unwrap();
 }
+
+/// Runs a full round with a single contributor and verifier, and checks the size of the aggregated round file.
+fn execute_marlin_round(environment: &Environment) -> anyhow::Result<()> {
+    let number_of_chunks = environment.number_of_chunks() as usize;
+
+    // Instantiate a coordinator.
+    let coordinator = Coordinator::new(environment.clone(), Box::new(Dummy))?;
+
+    // Initialize the ceremony to round 0.
+    coordinator.initialize()?;
+    assert_eq!(0, coordinator.current_round_height()?);
+
+    // Add a contributor and verifier to the queue, and advance to round 1.
+    let contributor = create_contributor_test_details("1");
+    let verifier = create_verifier_test_details("1");
+    coordinator.add_to_queue(contributor.participant.clone(), 10)?;
+    coordinator.add_to_queue(verifier.participant.clone(), 10)?;
+    coordinator.update()?;
+    assert_eq!(1, coordinator.current_round_height()?);
+
+    // Run contribution and verification on every chunk in round 1.
+    for _ in 0..number_of_chunks {
+        contributor.contribute_to(&coordinator)?;
+        verifier.verify(&coordinator)?;
+    }
+
+    // Aggregate round 1 and advance to round 2.
+    coordinator.add_to_queue(contributor.participant.clone(), 10)?;
+    coordinator.add_to_queue(verifier.participant.clone(), 10)?;
+    coordinator.update()?;
+    assert_eq!(2, coordinator.current_round_height()?);
+
+    // Check that the aggregated round file matches the expected Marlin layout.
+    let round = coordinator.get_round(1)?;
+    let storage_lock = coordinator.storage();
+    let storage = storage_lock.read().unwrap();
+    assert_eq!(
+        Object::round_file_size(environment),
+        storage.size(&Locator::RoundFile { round_height: 1 })?
+    );
+
+    // Check that the round records the hash of the aggregated round file.
+    let round_file = storage.reader(&Locator::RoundFile { round_height: 1 })?;
+    let round_file_hash = hex::encode(calculate_hash(round_file.as_ref()));
+    assert_eq!(Some(round_file_hash.as_str()), round.round_file_hash());
+
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn round_on_marlin_test_3_chunks() -> anyhow::Result<()> {
+    let environment = initialize_test_environment(&Testing::from(Parameters::TestMarlin3Chunks).into());
+    assert_eq!(3, environment.number_of_chunks());
+
+    execute_marlin_round(&environment)
+}
+
+#[test]
+#[serial]
+fn round_on_aleo_test_universal() -> anyhow::Result<()> {
+    let environment = initialize_test_environment(
+        &Testing::from(Parameters::AleoTestUniversal {
+            power: 10,
+            chunk_size: 256,
+            batch_size: 64,
+        })
+        .into(),
+    );
+    assert_eq!(4, environment.number_of_chunks());
+
+    execute_marlin_round(&environment)
+}
+
+#[test]
+#[serial]
+fn practice_round_trip_test() -> anyhow::Result<()> {
+    let time = Arc::new(MockTimeSource::new(Utc::now()));
+
+    let testing_deployment: Testing = Testing::from(Parameters::Test3Chunks)
+        .participant_lock_timeout(chrono::Duration::minutes(20))
+        .practice_power(Some(6));
+    let environment = initialize_test_environment(&Environment::from(testing_deployment));
+
+    // Instantiate a coordinator.
+    let coordinator = Coordinator::new_with_time(environment.clone(), Box::new(Dummy), time.clone())?;
+
+    // Initialize the ceremony to round 0, which generates the practice challenge.
+    coordinator.initialize()?;
+    let round = coordinator.current_round()?;
+
+    let (contributor, _, _) = create_contributor("1");
+
+    // Check that a practice response is rejected before the challenge is fetched.
+    match coordinator.practice_response(&contributor, &[]) {
+        Err(CoordinatorError::PracticeChallengeNotRequested) => {}
+        result => panic!("Expected the practice challenge to be requested, found {:?}", result),
+    }
+
+    // Compute a practice contribution, taking one minute.
+    let challenge = coordinator.practice_challenge(&contributor)?;
+    let response = crate::commands::practice::tests::contribute_practice(&environment, &challenge);
+    time.update(|now| now + chrono::Duration::minutes(1));
+
+    // Test 3 chunks has 2^7 - 1 powers on 172 powers per chunk.
+    let feedback = coordinator.practice_response(&contributor, &response)?;
+    assert_eq!(chrono::Duration::minutes(1), feedback.elapsed());
+    assert_eq!(
+        chrono::Duration::milliseconds(60_000 * 172 / 127),
+        feedback.estimated_contribution_time()
+    );
+    assert!(feedback.is_fast_enough());
+
+    // Check that a slow machine is told so.
+    coordinator.practice_challenge(&contributor)?;
+    time.update(|now| now + chrono::Duration::minutes(30));
+    let feedback = coordinator.practice_response(&contributor, &response)?;
+    assert!(!feedback.is_fast_enough());
+
+    // Check that an invalid practice response is rejected.
+    let mut invalid = response.clone();
+    let length = invalid.len();
+    invalid[length - 1] ^= 1;
+    coordinator.practice_challenge(&contributor)?;
+    assert!(coordinator.practice_response(&contributor, &invalid).is_err());
+
+    // Check that a session expires after twice the participant lock timeout.
+    coordinator.practice_challenge(&contributor)?;
+    time.update(|now| now + chrono::Duration::minutes(41));
+    match coordinator.practice_response(&contributor, &response) {
+        Err(CoordinatorError::PracticeChallengeExpired) => {}
+        result => panic!("Expected the practice session to expire, found {:?}", result),
+    }
+
+    // Check that the round and the queue were not touched.
+    assert_eq!(round, coordinator.current_round()?);
+    assert_eq!(0, coordinator.number_of_queue_contributors());
+
+    Ok(())
+}
+
+#[test]
+#[serial]
+/// Records the hardware each contributor declared when joining the queue in the round state.
+fn coordinator_contributor_hardware_test() -> anyhow::Result<()> {
+    let testing_deployment = Testing::from(Parameters::Test3Chunks).hardware_policy(Some(HardwarePolicy::aleo_inner()));
+    let environment = initialize_test_environment(&Environment::from(testing_deployment));
+
+    // Instantiate a coordinator.
+    let coordinator = Coordinator::new(environment, Box::new(Dummy))?;
+
+    // Initialize the ceremony to round 0.
+    coordinator.initialize()?;
+    assert_eq!(0, coordinator.current_round_height()?);
+
+    // Add a contributor with their declared hardware, and a verifier to the queue.
+    let (contributor1, _, _) = create_contributor("1");
+    let (verifier, _) = create_verifier("1");
+    let hardware = HardwareDeclaration::new(8, 16, 100);
+    coordinator.add_to_queue_with_hardware(contributor1.clone(), 10, Some(hardware))?;
+    coordinator.add_to_queue(verifier.clone(), 10)?;
+
+    // Update the ceremony to round 1.
+    coordinator.update()?;
+    assert_eq!(1, coordinator.current_round_height()?);
+
+    // Check that the declared hardware is recorded in the stored round state.
+    let round = coordinator.get_round(1)?;
+    assert_eq!(1, round.contributor_hardware().len());
+    assert_eq!(Some(&hardware), round.contributor_hardware().get(&contributor1));
+
+    // Check that round 0 records no declared hardware.
+    assert!(coordinator.get_round(0)?.contributor_hardware().is_empty());
+
+    Ok(())
+}
+
+#[test]
+#[serial]
+/// Completes a round with three contributors, where each chunk expects two contributions.
+fn coordinator_contributions_per_chunk_test() -> anyhow::Result<()> {
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+    let testing_deployment = Testing::from(parameters).contributions_per_chunk(Some(2));
+    let environment = initialize_test_environment(&Environment::from(testing_deployment));
+    let number_of_chunks = environment.number_of_chunks() as usize;
+
+    // Instantiate a coordinator.
+    let coordinator = Coordinator::new(environment, Box::new(Dummy))?;
+
+    // Initialize the ceremony to round 0.
+    coordinator.initialize()?;
+    assert_eq!(0, coordinator.current_round_height()?);
+
+    // Add three contributors and a verifier to the queue.
+    let (contributor1, contributor_signing_key1, seed1) = create_contributor("1");
+    let (contributor2, contributor_signing_key2, seed2) = create_contributor("2");
+    let (contributor3, _, _) = create_contributor("3");
+    let (verifier, verifier_signing_key) = create_verifier("1");
+    coordinator.add_to_queue(contributor1.clone(), 10)?;
+    coordinator.add_to_queue(contributor2.clone(), 9)?;
+    coordinator.add_to_queue(contributor3.clone(), 8)?;
+    coordinator.add_to_queue(verifier.clone(), 10)?;
+    assert_eq!(3, coordinator.number_of_queue_contributors());
+
+    // Update the ceremony to round 1.
+    coordinator.update()?;
+    assert_eq!(1, coordinator.current_round_height()?);
+    {
+        let round = coordinator.current_round()?;
+        assert_eq!(3, round.number_of_contributors());
+        assert_eq!(Some(2), round.contributions_per_chunk());
+        assert_eq!(3, round.expected_number_of_contributions());
+    }
+
+    // Check that the least reliable contributor is a spare, and is not assigned a chunk.
+    assert!(coordinator.try_lock(&contributor3).is_err());
+
+    // Contribute and verify all chunks with the two most reliable contributors.
+    for _ in 0..number_of_chunks {
+        coordinator.contribute(&contributor1, &contributor_signing_key1, &seed1)?;
+        coordinator.contribute(&contributor2, &contributor_signing_key2, &seed2)?;
+        coordinator.verify(&verifier, &verifier_signing_key)?;
+        coordinator.verify(&verifier, &verifier_signing_key)?;
+    }
+
+    // Check that the round is complete with two contributions in each chunk.
+    let round = coordinator.current_round()?;
+    assert!(round.is_complete());
+    for chunk in round.chunks() {
+        assert_eq!(3, chunk.get_contributions().len());
+    }
+
+    // Check that the spare contributor is not required to finish the round.
+    coordinator.update()?;
+    assert!(coordinator.is_finished_contributor(&contributor1));
+    assert!(coordinator.is_finished_contributor(&contributor2));
+    assert!(coordinator.is_finished_contributor(&contributor3));
+
+    Ok(())
+}
+
+#[test]
+#[serial]
+/// Drops a spare contributor, and checks the round continues without a reset.
+fn coordinator_drop_spare_contributor_test() -> anyhow::Result<()> {
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+    let testing_deployment = Testing::from(parameters).contributions_per_chunk(Some(2));
+    let environment = initialize_test_environment(&Environment::from(testing_deployment));
+    let number_of_chunks = environment.number_of_chunks() as usize;
+
+    // Instantiate a coordinator.
+    let coordinator = Coordinator::new(environment, Box::new(Dummy))?;
+
+    // Initialize the ceremony to round 0.
+    coordinator.initialize()?;
+    assert_eq!(0, coordinator.current_round_height()?);
+
+    // Add three contributors and a verifier to the queue.
+    let (contributor1, contributor_signing_key1, seed1) = create_contributor("1");
+    let (contributor2, contributor_signing_key2, seed2) = create_contributor("2");
+    let (contributor3, _, _) = create_contributor("3");
+    let (verifier, verifier_signing_key) = create_verifier("1");
+    coordinator.add_to_queue(contributor1.clone(), 10)?;
+    coordinator.add_to_queue(contributor2.clone(), 9)?;
+    coordinator.add_to_queue(contributor3.clone(), 8)?;
+    coordinator.add_to_queue(verifier.clone(), 10)?;
+
+    // Update the ceremony to round 1.
+    coordinator.update()?;
+    assert_eq!(1, coordinator.current_round_height()?);
+
+    // Contribute and verify the first chunk with the two most reliable contributors.
+    coordinator.contribute(&contributor1, &contributor_signing_key1, &seed1)?;
+    coordinator.contribute(&contributor2, &contributor_signing_key2, &seed2)?;
+    coordinator.verify(&verifier, &verifier_signing_key)?;
+    coordinator.verify(&verifier, &verifier_signing_key)?;
+
+    // Check that an update keeps the spare contributor in the current round.
+    coordinator.update()?;
+    assert!(coordinator.is_current_contributor(&contributor3));
+
+    // Drop the spare contributor from the current round.
+    coordinator.drop_participant(&contributor3)?;
+    assert!(!coordinator.is_current_contributor(&contributor3));
+    assert_eq!(1, coordinator.dropped_participants().len());
+
+    // Check that no other contributor was affected by the drop.
+    assert_eq!(1, coordinator.current_round_height()?);
+    for (_, contributor_info) in coordinator.current_contributors() {
+        assert_eq!(number_of_chunks - 1, contributor_info.assigned_tasks().len());
+        assert_eq!(1, contributor_info.completed_tasks().len());
+        assert_eq!(0, contributor_info.disposed_tasks().len());
+    }
+    {
+        let round = coordinator.current_round()?;
+        assert_eq!(2, round.number_of_contributors());
+        assert!(!round.is_contributor(&contributor3));
+    }
+
+    // Contribute and verify the remaining chunks.
+    for _ in 1..number_of_chunks {
+        coordinator.contribute(&contributor1, &contributor_signing_key1, &seed1)?;
+        coordinator.contribute(&contributor2, &contributor_signing_key2, &seed2)?;
+        coordinator.verify(&verifier, &verifier_signing_key)?;
+        coordinator.verify(&verifier, &verifier_signing_key)?;
+    }
+
+    // Check that the round is complete with two contributions in each chunk.
+    let round = coordinator.current_round()?;
+    assert!(round.is_complete());
+    for chunk in round.chunks() {
+        assert_eq!(3, chunk.get_contributions().len());
+    }
+
+    Ok(())
+}
+
+#[test]
+#[serial]
+/// Drops a contributor with tasks, and checks a spare contributor is promoted into its bucket.
+fn coordinator_drop_contributor_promotes_spare_test() -> anyhow::Result<()> {
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+    let testing_deployment = Testing::from(parameters).contributions_per_chunk(Some(2));
+    let environment = initialize_test_environment(&Environment::from(testing_deployment));
+    let number_of_chunks = environment.number_of_chunks() as usize;
+
+    // Instantiate a coordinator.
+    let coordinator = Coordinator::new(environment, Box::new(Dummy))?;
+
+    // Initialize the ceremony to round 0.
+    coordinator.initialize()?;
+    assert_eq!(0, coordinator.current_round_height()?);
+
+    // Add three contributors and a verifier to the queue.
+    let (contributor1, _, _) = create_contributor("1");
+    let (contributor2, contributor_signing_key2, seed2) = create_contributor("2");
+    let (contributor3, contributor_signing_key3, seed3) = create_contributor("3");
+    let (verifier, verifier_signing_key) = create_verifier("1");
+    coordinator.add_to_queue(contributor1.clone(), 10)?;
+    coordinator.add_to_queue(contributor2.clone(), 9)?;
+    coordinator.add_to_queue(contributor3.clone(), 8)?;
+    coordinator.add_to_queue(verifier.clone(), 10)?;
+
+    // Update the ceremony to round 1.
+    coordinator.update()?;
+    assert_eq!(1, coordinator.current_round_height()?);
+    assert!(coordinator.try_lock(&contributor3).is_err());
+
+    // Check that an update keeps the spare contributor in the current round.
+    coordinator.update()?;
+    assert!(coordinator.is_current_contributor(&contributor3));
+
+    // Drop the contributor in the first bucket from the current round.
+    coordinator.drop_participant(&contributor1)?;
+    assert!(!coordinator.is_current_contributor(&contributor1));
+
+    // Check that the spare contributor was promoted, instead of a coordinator contributor.
+    assert_eq!(1, coordinator.current_round_height()?);
+    let contributors = coordinator.current_contributors();
+    assert_eq!(2, contributors.len());
+    for (contributor, contributor_info) in contributors {
+        assert!(contributor == contributor2 || contributor == contributor3);
+        assert_eq!(number_of_chunks, contributor_info.assigned_tasks().len());
+    }
+    {
+        let round = coordinator.current_round()?;
+        assert_eq!(2, round.number_of_contributors());
+        assert!(!round.is_contributor(&contributor1));
+        assert!(round.is_contributor(&contributor3));
+    }
+
+    // Contribute and verify all chunks with the promoted spare and the remaining contributor.
+    for _ in 0..number_of_chunks {
+        coordinator.contribute(&contributor3, &contributor_signing_key3, &seed3)?;
+        coordinator.contribute(&contributor2, &contributor_signing_key2, &seed2)?;
+        coordinator.verify(&verifier, &verifier_signing_key)?;
+        coordinator.verify(&verifier, &verifier_signing_key)?;
+    }
+
+    // Check that the round is complete with two contributions in each chunk.
+    let round = coordinator.current_round()?;
+    assert!(round.is_complete());
+    for chunk in round.chunks() {
+        assert_eq!(3, chunk.get_contributions().len());
+    }
+
+    Ok(())
+}
+
+#[test]
+#[serial]
+/// Checks that a spare contributor finishes with the round, once all contributors with tasks finished.
+fn coordinator_spare_contributor_finishes_with_round_test() -> anyhow::Result<()> {
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+    let testing_deployment = Testing::from(parameters).contributions_per_chunk(Some(2));
+    let environment = initialize_test_environment(&Environment::from(testing_deployment));
+    let number_of_chunks = environment.number_of_chunks() as usize;
+
+    // Instantiate a coordinator.
+    let coordinator = Coordinator::new(environment, Box::new(Dummy))?;
+
+    // Initialize the ceremony to round 0.
+    coordinator.initialize()?;
+    assert_eq!(0, coordinator.current_round_height()?);
+
+    // Add three contributors and a verifier to the queue.
+    let (contributor1, contributor_signing_key1, seed1) = create_contributor("1");
+    let (contributor2, contributor_signing_key2, seed2) = create_contributor("2");
+    let (contributor3, _, _) = create_contributor("3");
+    let (verifier, verifier_signing_key) = create_verifier("1");
+    coordinator.add_to_queue(contributor1.clone(), 10)?;
+    coordinator.add_to_queue(contributor2.clone(), 9)?;
+    coordinator.add_to_queue(contributor3.clone(), 8)?;
+    coordinator.add_to_queue(verifier.clone(), 10)?;
+
+    // Update the ceremony to round 1.
+    coordinator.update()?;
+    assert_eq!(1, coordinator.current_round_height()?);
+
+    // Contribute and verify all chunks, updating the ceremony in between.
+    for _ in 0..number_of_chunks {
+        coordinator.contribute(&contributor1, &contributor_signing_key1, &seed1)?;
+        coordinator.contribute(&contributor2, &contributor_signing_key2, &seed2)?;
+        coordinator.verify(&verifier, &verifier_signing_key)?;
+        coordinator.verify(&verifier, &verifier_signing_key)?;
+
+        // Check that the spare contributor stays in the round until the last contribution.
+        assert!(coordinator.is_current_contributor(&contributor3));
+        coordinator.update()?;
+    }
+
+    // Check that the spare contributor finished with the other contributors.
+    assert!(!coordinator.is_current_contributor(&contributor3));
+    assert!(coordinator.current_contributors().is_empty());
+    assert!(coordinator.current_round()?.is_complete());
+
+    Ok(())
+}
+
+/// Checks that a coordinator refuses to start on the transcript in storage.
+fn assert_transcript_fork_detected(environment: &Environment) {
+    match Coordinator::new(environment.clone(), Box::new(Dummy)) {
+        Err(CoordinatorError::TranscriptForkDetected) => {}
+        Err(error) => panic!("Unexpected error: {}", error),
+        Ok(_) => panic!("Expected a transcript fork to be detected"),
+    }
+}
+
+/// Runs round 1 with a single contributor and verifier, and starts round 2.
+fn run_to_round_two(coordinator: &Coordinator, number_of_chunks: usize) -> anyhow::Result<()> {
+    let (contributor, _, _) = create_contributor("1");
+    let (verifier, _) = create_verifier("1");
+    coordinator.add_to_queue(contributor, 10)?;
+    coordinator.add_to_queue(verifier, 10)?;
+
+    // Update the ceremony to round 1.
+    coordinator.update()?;
+    assert_eq!(1, coordinator.current_round_height()?);
+
+    run_next_round(coordinator, number_of_chunks)
+}
+
+/// Runs the current round with the contributor and verifier of `run_to_round_two`, and starts the next round.
+fn run_next_round(coordinator: &Coordinator, number_of_chunks: usize) -> anyhow::Result<()> {
+    let (contributor, contributor_signing_key, seed) = create_contributor("1");
+    let (verifier, verifier_signing_key) = create_verifier("1");
+    let round_height = coordinator.current_round_height()?;
+
+    // Run contribution and verification for the current round.
+    for _ in 0..number_of_chunks {
+        coordinator.contribute(&contributor, &contributor_signing_key, &seed)?;
+        coordinator.verify(&verifier, &verifier_signing_key)?;
+    }
+
+    // Update the ceremony to the next round.
+    coordinator.add_to_queue(contributor, 10)?;
+    coordinator.add_to_queue(verifier, 10)?;
+    coordinator.update()?;
+    assert_eq!(round_height + 1, coordinator.current_round_height()?);
+
+    Ok(())
+}
+
+#[test]
+#[serial]
+/// Starts a coordinator on storage recording a different ceremony ID.
+fn coordinator_ceremony_id_mismatch_test() -> anyhow::Result<()> {
+    let testing = Testing::from(Parameters::Test3Chunks).ceremony_id(Some("ceremony-a".to_string()));
+    let environment = initialize_test_environment(&Environment::from(testing.clone()));
+
+    // Initialize the ceremony, which records the ceremony ID.
+    {
+        let coordinator = Coordinator::new(environment.clone(), Box::new(Dummy))?;
+        coordinator.initialize()?;
+        assert_eq!(Some("ceremony-a"), coordinator.state().ceremony_id());
+    }
+
+    // Check that the same ceremony ID, or none at all, is accepted.
+    Coordinator::new(environment, Box::new(Dummy))?;
+    Coordinator::new(testing.ceremony_id(None).into(), Box::new(Dummy))?;
+
+    // Check that a different ceremony ID is rejected.
+    assert_transcript_fork_detected(&testing.ceremony_id(Some("ceremony-b".to_string())).into());
+
+    Ok(())
+}
+
+#[test]
+#[serial]
+/// Initializes a ceremony without a ceremony ID, and checks a random one is recorded.
+fn coordinator_ceremony_id_generated_test() -> anyhow::Result<()> {
+    let environment = initialize_test_environment(&TEST_ENVIRONMENT);
+
+    let coordinator = Coordinator::new(environment, Box::new(Dummy))?;
+    assert_eq!(None, coordinator.state().ceremony_id());
+    coordinator.initialize()?;
+
+    // Check that the ceremony ID is a version 4 UUID.
+    let state = coordinator.state();
+    let ceremony_id = state.ceremony_id().unwrap();
+    assert_eq!(36, ceremony_id.len());
+    assert_eq!(Some('4'), ceremony_id.chars().nth(14));
+
+    Ok(())
+}
+
+#[test]
+#[serial]
+/// Starts a coordinator on storage with a round state beyond the stored round height.
+fn coordinator_round_beyond_round_height_test() -> anyhow::Result<()> {
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+    let environment = initialize_test_environment(&Testing::from(parameters).into());
+
+    {
+        // Instantiate a coordinator, and update the ceremony to round 1.
+        let coordinator = Coordinator::new(environment.clone(), Box::new(Dummy))?;
+        coordinator.initialize()?;
+        let (contributor, _, _) = create_contributor("1");
+        let (verifier, _) = create_verifier("1");
+        coordinator.add_to_queue(contributor, 10)?;
+        coordinator.add_to_queue(verifier, 10)?;
+        coordinator.update()?;
+        assert_eq!(1, coordinator.current_round_height()?);
+
+        // Restore the round height key from an earlier backup.
+        let storage = coordinator.storage();
+        let mut storage = storage.write().unwrap();
+        storage.update(&Locator::RoundHeight, Object::RoundHeight(0))?;
+    }
+
+    assert_transcript_fork_detected(&environment);
+
+    Ok(())
+}
+
+#[test]
+#[serial]
+/// Starts a coordinator on storage with a round that did not start from the previous round.
+fn coordinator_round_not_chained_to_previous_round_test() -> anyhow::Result<()> {
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+    let environment = initialize_test_environment(&Testing::from(parameters).into());
+    let number_of_chunks = environment.number_of_chunks() as usize;
+
+    {
+        // Instantiate a coordinator, and update the ceremony to round 2.
+        let coordinator = Coordinator::new(environment.clone(), Box::new(Dummy))?;
+        coordinator.initialize()?;
+        run_to_round_two(&coordinator, number_of_chunks)?;
+    }
+
+    // Check that the untouched transcript is accepted.
+    let coordinator = Coordinator::new(environment.clone(), Box::new(Dummy))?;
+
+    // Replace the initial contribution of a chunk with one from a different run.
+    {
+        let locator = Locator::ContributionFile(ContributionLocator::new(2, 1, 0, true));
+        let storage = coordinator.storage();
+        let storage = storage.write().unwrap();
+        storage.writer(&locator)?.as_mut()[0] ^= 1;
+    }
+    drop(coordinator);
+
+    assert_transcript_fork_detected(&environment);
+
+    Ok(())
+}
+
+#[test]
+#[serial]
+/// Starts a coordinator on storage with an earlier round that did not start from the round before it,
+/// or whose round before it has no signature of a final response.
+fn coordinator_earlier_round_not_chained_test() -> anyhow::Result<()> {
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+    let environment = initialize_test_environment(&Testing::from(parameters).into());
+    let number_of_chunks = environment.number_of_chunks() as usize;
+
+    {
+        // Instantiate a coordinator, and update the ceremony to round 3.
+        let coordinator = Coordinator::new(environment.clone(), Box::new(Dummy))?;
+        coordinator.initialize()?;
+        run_to_round_two(&coordinator, number_of_chunks)?;
+        run_next_round(&coordinator, number_of_chunks)?;
+        assert_eq!(3, coordinator.current_round_height()?);
+    }
+
+    // Check that the untouched transcript is accepted.
+    let coordinator = Coordinator::new(environment.clone(), Box::new(Dummy))?;
+
+    // Replace the initial contribution of a chunk in round 2 with one from a different run.
+    let locator = Locator::ContributionFile(ContributionLocator::new(2, 1, 0, true));
+    {
+        let storage = coordinator.storage();
+        let storage = storage.write().unwrap();
+        storage.writer(&locator)?.as_mut()[0] ^= 1;
+    }
+    assert_transcript_fork_detected(&environment);
+
+    // Restore the contribution, and remove the signature of the final response to a chunk in round 1.
+    let final_contribution_id = coordinator.get_round(1)?.expected_number_of_contributions() - 1;
+    {
+        let storage = coordinator.storage();
+        let mut storage = storage.write().unwrap();
+        storage.writer(&locator)?.as_mut()[0] ^= 1;
+        storage.remove(&Locator::ContributionFileSignature(ContributionSignatureLocator::new(
+            1,
+            1,
+            final_contribution_id,
+            false,
+        )))?;
+    }
+    drop(coordinator);
+
+    assert_transcript_fork_detected(&environment);
+
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn coordinator_round_height_inconsistent_test() -> anyhow::Result<()> {
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+    let environment = initialize_test_environment(&Testing::from(parameters).into());
+
+    // Instantiate a coordinator, and update the ceremony to round 1.
+    let coordinator = Coordinator::new(environment, Box::new(Dummy))?;
+    coordinator.initialize()?;
+    let (contributor, _, _) = create_contributor("1");
+    let (verifier, _) = create_verifier("1");
+    coordinator.add_to_queue(contributor, 10)?;
+    coordinator.add_to_queue(verifier, 10)?;
+    coordinator.update()?;
+    assert_eq!(1, coordinator.current_round_height()?);
+
+    // Write the round height of the next round, as if its round state was never written.
+    {
+        let storage = coordinator.storage();
+        let mut storage = storage.write().unwrap();
+        storage.update(&Locator::RoundHeight, Object::RoundHeight(2))?;
+    }
+
+    let result = coordinator.current_round_height();
+    assert!(matches!(result, Err(CoordinatorError::RoundHeightInconsistent)));
+
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn coordinator_chunk_and_contribution_id_boundaries_test() -> anyhow::Result<()> {
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+    let environment = initialize_test_environment(&Testing::from(parameters).into());
+
+    // Instantiate a coordinator, and update the ceremony to round 1.
+    let coordinator = Coordinator::new(environment, Box::new(Dummy))?;
+    coordinator.initialize()?;
+    let (contributor, contributor_signing_key, seed) = create_contributor("1");
+    let (verifier, _) = create_verifier("1");
+    coordinator.add_to_queue(contributor.clone(), 10)?;
+    coordinator.add_to_queue(verifier.clone(), 10)?;
+    coordinator.update()?;
+    assert_eq!(1, coordinator.current_round_height()?);
+
+    let is_chunk_id_out_of_range =
+        |error: &CoordinatorError| matches!(error, CoordinatorError::ChunkIdOutOfRange { .. });
+
+    // The first and last chunk IDs are accepted.
+    let max_chunk_id = coordinator.environment().number_of_chunks() - 1;
+    for chunk_id in &[0, max_chunk_id] {
+        assert_eq!(*chunk_id, coordinator.get_chunk(*chunk_id)?.chunk_id());
+        assert_eq!(None, coordinator.force_unlock_chunk(*chunk_id)?);
+        for result in vec![
+            coordinator.try_contribute(&contributor, *chunk_id).map(|_| ()),
+            coordinator.try_verify(&verifier, *chunk_id),
+        ] {
+            assert!(!is_chunk_id_out_of_range(&result.unwrap_err()));
+        }
+    }
+
+    // Chunk IDs past the last chunk are rejected at every entry point.
+    for chunk_id in &[max_chunk_id + 1, u64::MAX] {
+        for error in vec![
+            coordinator.get_chunk(*chunk_id).unwrap_err(),
+            coordinator.force_unlock_chunk(*chunk_id).unwrap_err(),
+            coordinator.try_contribute(&contributor, *chunk_id).unwrap_err(),
+            coordinator.try_verify(&verifier, *chunk_id).unwrap_err(),
+            coordinator
+                .run_computation(1, *chunk_id, 1, &contributor, &contributor_signing_key, &seed)
+                .unwrap_err(),
+        ] {
+            assert!(is_chunk_id_out_of_range(&error), "{:?}", error);
+        }
+    }
+
+    // Contribution IDs past the expected number of contributions are rejected.
+    let max_contribution_id = coordinator.current_round()?.expected_number_of_contributions() - 1;
+    let result = coordinator.run_computation(1, 0, max_contribution_id, &contributor, &contributor_signing_key, &seed);
+    assert!(matches!(
+        result,
+        Err(CoordinatorError::ChunkNotLockedOrByWrongParticipant)
+    ));
+    for contribution_id in &[max_contribution_id + 1, u64::MAX] {
+        let result = coordinator.run_computation(1, 0, *contribution_id, &contributor, &contributor_signing_key, &seed);
+        assert!(matches!(result, Err(CoordinatorError::ContributionIdOutOfRange { .. })));
+    }
+
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn coordinator_force_unlock_chunk_test() -> anyhow::Result<()> {
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+    let environment = initialize_test_environment(&Testing::from(parameters).into());
+
+    // Instantiate a coordinator.
+    let coordinator = Coordinator::new(environment, Box::new(Dummy))?;
+
+    // Initialize the ceremony to round 0.
+    coordinator.initialize()?;
+
+    let (contributor1, contributor_signing_key1, seed1) = create_contributor("1");
+    let (verifier, verifier_signing_key) = create_verifier("1");
+    coordinator.add_to_queue(contributor1.clone(), 10)?;
+    coordinator.add_to_queue(verifier.clone(), 10)?;
+
+    // Update the ceremony to round 1.
+    coordinator.update()?;
+    assert_eq!(1, coordinator.current_round_height()?);
+
+    // Lock a chunk as the contributor.
+    let (chunk_id, _) = coordinator.try_lock(&contributor1)?;
+    assert!(coordinator
+        .current_round()?
+        .chunk(chunk_id)?
+        .is_locked_by(&contributor1));
+
+    // Force unlock the chunk, and check that the prior lock holder is returned.
+    assert_eq!(Some(contributor1.clone()), coordinator.force_unlock_chunk(chunk_id)?);
+    assert!(coordinator.current_round()?.chunk(chunk_id)?.is_unlocked());
+    let (_, contributor_info) = coordinator.current_contributors().into_iter().next().unwrap();
+    assert!(contributor_info.locked_chunks().is_empty());
+
+    // Force unlocking an unlocked chunk returns no lock holder.
+    assert_eq!(None, coordinator.force_unlock_chunk(chunk_id)?);
+
+    // Check that the contributor can lock the same chunk again and contribute to it.
+    coordinator.contribute(&contributor1, &contributor_signing_key1, &seed1)?;
+    coordinator.verify(&verifier, &verifier_signing_key)?;
+    let (_, contributor_info) = coordinator.current_contributors().into_iter().next().unwrap();
+    assert_eq!(1, contributor_info.completed_tasks().len());
+    assert!(contributor_info
+        .completed_tasks()
+        .iter()
+        .any(|task| task.chunk_id() == chunk_id));
+
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn coordinator_get_chunk_test() -> anyhow::Result<()> {
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+    let environment = initialize_test_environment(&Testing::from(parameters).into());
+    let number_of_chunks = environment.number_of_chunks();
+
+    // Instantiate a coordinator.
+    let coordinator = Coordinator::new(environment, Box::new(Dummy))?;
+
+    // Initialize the ceremony to round 0.
+    coordinator.initialize()?;
+
+    let (contributor1, _, _) = create_contributor("1");
+    let (verifier, _) = create_verifier("1");
+    coordinator.add_to_queue(contributor1.clone(), 10)?;
+    coordinator.add_to_queue(verifier.clone(), 10)?;
+
+    // Update the ceremony to round 1.
+    coordinator.update()?;
+    assert_eq!(1, coordinator.current_round_height()?);
+
+    // Check that every chunk in the current round is returned.
+    for chunk_id in 0..number_of_chunks {
+        let chunk = coordinator.get_chunk(chunk_id)?;
+        assert_eq!(chunk_id, chunk.chunk_id());
+        assert_eq!(coordinator.current_round()?.chunk(chunk_id)?, &chunk);
+    }
+
+    // Check that a chunk ID past the last chunk is rejected.
+    match coordinator.get_chunk(number_of_chunks) {
+        Err(CoordinatorError::ChunkIdOutOfRange { chunk_id, num_chunks }) => {
+            assert_eq!(number_of_chunks, chunk_id);
+            assert_eq!(number_of_chunks, num_chunks);
+        }
+        result => panic!("Expected ChunkIdOutOfRange, found {:?}", result),
+    }
+
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn coordinator_contributors_by_chunk_test() -> anyhow::Result<()> {
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+    let environment = initialize_test_environment(&Testing::from(parameters).into());
+    let number_of_chunks = environment.number_of_chunks();
+
+    // Instantiate a coordinator.
+    let coordinator = Coordinator::new(environment, Box::new(Dummy))?;
+
+    // Initialize the ceremony to round 0.
+    coordinator.initialize()?;
+
+    let contributor1 = create_contributor_test_details("1");
+    let contributor2 = create_contributor_test_details("2");
+    let verifier = create_verifier_test_details("1");
+    coordinator.add_to_queue(contributor1.participant.clone(), 10)?;
+    coordinator.add_to_queue(contributor2.participant.clone(), 9)?;
+    coordinator.add_to_queue(verifier.participant.clone(), 10)?;
+
+    // Update the ceremony to round 1.
+    coordinator.update()?;
+
+    // Check that no chunk has contributors before the first contribution.
+    let contributors_by_chunk = coordinator.contributors_by_chunk()?;
+    assert_eq!(number_of_chunks as usize, contributors_by_chunk.len());
+    assert!(contributors_by_chunk.values().all(|contributors| contributors.is_empty()));
+
+    // Each contributor starts on a different chunk.
+    contributor1.contribute_to(&coordinator)?;
+    contributor2.contribute_to(&coordinator)?;
+
+    let contributors_by_chunk = coordinator.contributors_by_chunk()?;
+    let chunks_of = |participant: &Participant| -> Vec<u64> {
+        contributors_by_chunk
+            .iter()
+            .filter(|(_, contributors)| contributors[..] == [participant.clone()])
+            .map(|(chunk_id, _)| *chunk_id)
+            .collect()
+    };
+    let chunks1 = chunks_of(&contributor1.participant);
+    let chunks2 = chunks_of(&contributor2.participant);
+    assert_eq!(1, chunks1.len());
+    assert_eq!(1, chunks2.len());
+    assert_ne!(chunks1, chunks2);
+
+    // The remaining chunks have no contributors.
+    let contributions: usize = contributors_by_chunk.values().map(Vec::len).sum();
+    assert_eq!(2, contributions);
+
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn coordinator_remove_verifier_from_round_test() -> anyhow::Result<()> {
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+    let environment = initialize_test_environment(&Testing::from(parameters).into());
+
+    // Instantiate a coordinator.
+    let coordinator = Coordinator::new(environment, Box::new(Dummy))?;
+
+    // Initialize the ceremony to round 0.
+    coordinator.initialize()?;
+
+    let contributor1 = create_contributor_test_details("1");
+    let contributor2 = create_contributor_test_details("2");
+    let verifier1 = create_verifier_test_details("1");
+    let verifier2 = create_verifier_test_details("2");
+    coordinator.add_to_queue(contributor1.participant.clone(), 10)?;
+    coordinator.add_to_queue(contributor2.participant.clone(), 9)?;
+    coordinator.add_to_queue(verifier1.participant.clone(), 10)?;
+    coordinator.add_to_queue(verifier2.participant.clone(), 10)?;
+
+    // Update the ceremony to round 1.
+    coordinator.update()?;
+    assert_eq!(2, coordinator.current_verifiers().len());
+
+    // Each verifier is assigned the verification of one contribution.
+    contributor1.contribute_to(&coordinator)?;
+    contributor2.contribute_to(&coordinator)?;
+
+    // Only verifiers can be removed from the round.
+    let result = coordinator.remove_from_round(&contributor1.participant);
+    assert!(result.is_err());
+
+    // Removing a verifier returns the chunk of its verification task.
+    let affected_chunks = coordinator.remove_from_round(&verifier1.participant)?;
+    assert_eq!(1, affected_chunks.len());
+    assert!(!coordinator.is_current_verifier(&verifier1.participant));
+    assert!(coordinator.is_current_verifier(&verifier2.participant));
+    assert_eq!(1, coordinator.dropped_participants().len());
+
+    // The remaining verifier is reassigned the task, and verifies both contributions.
+    verifier2.verify(&coordinator)?;
+    verifier2.verify(&coordinator)?;
+
+    // The sole verifier cannot be removed from the round.
+    let result = coordinator.remove_from_round(&verifier2.participant);
+    assert!(matches!(result, Err(CoordinatorError::VerifierIsSoleVerifier)));
+    assert!(coordinator.is_current_verifier(&verifier2.participant));
+
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn coordinator_event_stream_test() -> anyhow::Result<()> {
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+    let environment = initialize_test_environment(&Testing::from(parameters).into());
+
+    // Instantiate a coordinator.
+    let coordinator = Coordinator::new(environment, Box::new(Dummy))?;
+
+    // Initialize the ceremony to round 0.
+    coordinator.initialize()?;
+
+    let contributor = create_contributor_test_details("1");
+    let verifier = create_verifier_test_details("1");
+    coordinator.add_to_queue(contributor.participant.clone(), 10)?;
+    coordinator.add_to_queue(verifier.participant.clone(), 10)?;
+
+    // Update the ceremony to round 1.
+    coordinator.update()?;
+
+    // Check that the snapshot holds the current round, without any locks.
+    let (snapshot, mut events) = coordinator.subscribe()?;
+    assert_eq!(
+        CoordinatorEvent::Snapshot {
+            round_height: 1,
+            locked_chunks: vec![],
+        },
+        snapshot
+    );
+
+    contributor.contribute_to(&coordinator)?;
+    verifier.verify(&coordinator)?;
+
+    // Check that the events of the contribution and its verification arrive in order.
+    let chunk_id = match events.try_recv()? {
+        CoordinatorEvent::ChunkLocked {
+            round_height: 1,
+            chunk_id,
+            participant,
+        } if participant == contributor.participant => chunk_id,
+        event => panic!("Expected the contributor to lock a chunk, got {:?}", event),
+    };
+    assert_eq!(
+        CoordinatorEvent::ContributionAdded {
+            round_height: 1,
+            chunk_id,
+            contribution_id: 1,
+            participant: contributor.participant.clone(),
+        },
+        events.try_recv()?
+    );
+    assert_eq!(
+        CoordinatorEvent::ChunkLocked {
+            round_height: 1,
+            chunk_id,
+            participant: verifier.participant.clone(),
+        },
+        events.try_recv()?
+    );
+    assert_eq!(
+        CoordinatorEvent::ContributionVerified {
+            round_height: 1,
+            chunk_id,
+            contribution_id: 1,
+            participant: verifier.participant.clone(),
+        },
+        events.try_recv()?
+    );
+    assert!(events.try_recv().is_err());
+
+    // Check that dropping a participant publishes the drop.
+    coordinator.drop_participant(&contributor.participant)?;
+    assert_eq!(
+        CoordinatorEvent::ParticipantDropped {
+            round_height: 1,
+            participant: contributor.participant.clone(),
+        },
+        events.try_recv()?
+    );
+    assert!(events.try_recv().is_err());
+
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn coordinator_round_file_test() -> anyhow::Result<()> {
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+    let environment = initialize_test_environment(&Testing::from(parameters).into());
+    let number_of_chunks = environment.number_of_chunks();
+
+    // Instantiate a coordinator.
+    let coordinator = Coordinator::new(environment.clone(), Box::new(Dummy))?;
+
+    // Initialize the ceremony to round 0.
+    coordinator.initialize()?;
+
+    // Add a contributor and verifier to the queue, and advance to round 1.
+    let contributor = create_contributor_test_details("1");
+    let verifier = create_verifier_test_details("1");
+    coordinator.add_to_queue(contributor.participant.clone(), 10)?;
+    coordinator.add_to_queue(verifier.participant.clone(), 10)?;
+    coordinator.update()?;
+    assert_eq!(1, coordinator.current_round_height()?);
+
+    // Check that the round file is missing before round 1 is aggregated.
+    assert!(matches!(
+        coordinator.round_file_locator(1),
+        Err(CoordinatorError::RoundFileMissing)
+    ));
+    assert!(matches!(
+        coordinator.round_file_size(1),
+        Err(CoordinatorError::RoundFileMissing)
+    ));
+
+    // Run contribution and verification on every chunk in round 1.
+    for _ in 0..number_of_chunks {
+        contributor.contribute_to(&coordinator)?;
+        verifier.verify(&coordinator)?;
+    }
+
+    // Aggregate round 1 and advance to round 2.
+    coordinator.add_to_queue(contributor.participant.clone(), 10)?;
+    coordinator.add_to_queue(verifier.participant.clone(), 10)?;
+    coordinator.update()?;
+    assert_eq!(2, coordinator.current_round_height()?);
+
+    // Check that the round file of round 1 is returned with the expected size.
+    let round_file = Locator::RoundFile { round_height: 1 };
+    assert_eq!(
+        coordinator.locator_to_path(round_file)?.to_string(),
+        coordinator.round_file_locator(1)?
+    );
+    assert_eq!(Object::round_file_size(&environment), coordinator.round_file_size(1)?);
+
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn coordinator_export_verifying_metadata_test() -> anyhow::Result<()> {
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+    let environment = initialize_test_environment(&Testing::from(parameters).into());
+    let number_of_chunks = environment.number_of_chunks();
+
+    // Instantiate a coordinator, and advance to round 1.
+    let coordinator = Coordinator::new(environment.clone(), Box::new(Dummy))?;
+    coordinator.initialize()?;
+    let contributor = create_contributor_test_details("1");
+    let verifier = create_verifier_test_details("1");
+    coordinator.add_to_queue(contributor.participant.clone(), 10)?;
+    coordinator.add_to_queue(verifier.participant.clone(), 10)?;
+    coordinator.update()?;
+    assert_eq!(1, coordinator.current_round_height()?);
+
+    // The metadata is only exported once the round is aggregated.
+    assert!(matches!(
+        coordinator.export_verifying_metadata(1),
+        Err(CoordinatorError::RoundFileMissing)
+    ));
+
+    // Run contribution and verification on every chunk in round 1.
+    for _ in 0..number_of_chunks {
+        contributor.contribute_to(&coordinator)?;
+        verifier.verify(&coordinator)?;
+    }
+
+    // Aggregate round 1 and advance to round 2.
+    coordinator.add_to_queue(contributor.participant.clone(), 10)?;
+    coordinator.add_to_queue(verifier.participant.clone(), 10)?;
+    coordinator.update()?;
+    assert_eq!(2, coordinator.current_round_height()?);
+
+    // Check that the metadata of round 1 is populated.
+    let metadata = coordinator.export_verifying_metadata(1)?;
+    assert_eq!(1, metadata.round_height);
+    assert_eq!(environment.parameters().fingerprint(), metadata.parameters_fingerprint);
+    assert_eq!(number_of_chunks as usize, metadata.chunks.len());
+    for (chunk_id, chunk) in metadata.chunks.iter().enumerate() {
+        assert_eq!(chunk_id as u64, chunk.chunk_id);
+        assert_eq!(128, chunk.initial_hash.len());
+        assert_eq!(vec![1], chunk.contribution_ids);
+    }
+    assert_eq!(128, metadata.output_hash.len());
+
+    // Check that the metadata is stable, and survives serialization.
+    assert_eq!(metadata, coordinator.export_verifying_metadata(1)?);
+    let json = serde_json::to_string(&metadata)?;
+    assert_eq!(metadata, serde_json::from_str::<RoundVerificationMetadata>(&json)?);
+
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn coordinator_reserve_next_contribution_locator_test() -> anyhow::Result<()> {
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+    let testing_deployment: Testing =
+        Testing::from(parameters).participant_lock_timeout(chrono::Duration::minutes(10));
+    let environment = initialize_test_environment(&Environment::from(testing_deployment));
+
+    // Instantiate a coordinator, and advance to round 1.
+    let time = Arc::new(MockTimeSource::new(Utc::now()));
+    let coordinator = Coordinator::new_with_time(environment, Box::new(Dummy), time.clone())?;
+    coordinator.initialize()?;
+    let contributor1 = create_contributor_test_details("1");
+    let contributor2 = create_contributor_test_details("2");
+    let verifier = create_verifier_test_details("1");
+    coordinator.add_to_queue(contributor1.participant.clone(), 10)?;
+    coordinator.add_to_queue(contributor2.participant.clone(), 9)?;
+    coordinator.add_to_queue(verifier.participant.clone(), 10)?;
+    coordinator.update()?;
+
+    // Fetch the chunk of the next task of the first contributor.
+    let task = coordinator
+        .current_contributors()
+        .into_iter()
+        .find(|(participant, _)| *participant == contributor1.participant)
+        .and_then(|(_, info)| info.assigned_tasks().front().copied())
+        .unwrap();
+    let chunk_id = task.chunk_id();
+    let locator = Locator::ContributionFile(ContributionLocator::new(1, chunk_id, task.contribution_id(), false));
+
+    // Check that the second contributor may not reserve a contribution assigned to the first.
+    assert!(matches!(
+        coordinator.reserve_next_contribution_locator(chunk_id, &contributor2.participant),
+        Err(CoordinatorError::ParticipantUnauthorizedForChunkId { .. })
+    ));
+
+    // Reserve the next contribution to the chunk from two threads at once.
+    let barrier = Arc::new(std::sync::Barrier::new(2));
+    let handles: Vec<_> = (0..2)
+        .map(|_| {
+            let coordinator = coordinator.clone();
+            let participant = contributor1.participant.clone();
+            let barrier = barrier.clone();
+            std::thread::spawn(move || {
+                barrier.wait();
+                coordinator.reserve_next_contribution_locator(chunk_id, &participant)
+            })
+        })
+        .collect();
+    let results = handles
+        .into_iter()
+        .map(|handle| handle.join().unwrap())
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Check that both reservations returned the same response file, and it exists in storage.
+    let reserved = coordinator.locator_to_path(locator)?.to_string();
+    assert_eq!(vec![reserved.clone(), reserved.clone()], results);
+    assert!(coordinator.storage().read().unwrap().exists(&locator));
+    let reservation = coordinator.contribution_reservation(chunk_id).unwrap();
+    assert_eq!(contributor1.participant, reservation.participant);
+
+    // Check that the reservation expires after the participant lock timeout.
+    time.update(|prev| prev + chrono::Duration::minutes(11));
+    assert!(coordinator.contribution_reservation(chunk_id).is_none());
+    assert_eq!(
+        reserved,
+        coordinator.reserve_next_contribution_locator(chunk_id, &contributor1.participant)?
+    );
+    assert!(coordinator.contribution_reservation(chunk_id).is_some());
+
+    // Check that the contributor may lock the reserved chunk, and contribute to it.
+    contributor1.contribute_to(&coordinator)?;
+    assert!(coordinator.contribution_reservation(chunk_id).is_none());
+    assert!(coordinator.storage().read().unwrap().exists(&locator));
+    let round = coordinator.current_round()?;
+    assert_eq!(task.contribution_id(), round.chunk(chunk_id)?.current_contribution_id());
+
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn coordinator_concurrent_verification_test() -> anyhow::Result<()> {
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+    let environment = initialize_test_environment(&Testing::from(parameters).into());
+
+    // Instantiate a coordinator, and advance to round 1.
+    let coordinator = Coordinator::new(environment, Box::new(Dummy))?;
+    coordinator.initialize()?;
+    let contributor = create_contributor_test_details("1");
+    let verifier1 = create_verifier_test_details("1");
+    let verifier2 = create_verifier_test_details("2");
+    coordinator.add_to_queue(contributor.participant.clone(), 10)?;
+    coordinator.add_to_queue(verifier1.participant.clone(), 10)?;
+    coordinator.add_to_queue(verifier2.participant.clone(), 10)?;
+    coordinator.update()?;
+
+    // Contribute to two chunks, which assigns one verification to each verifier.
+    contributor.contribute_to(&coordinator)?;
+    contributor.contribute_to(&coordinator)?;
+
+    // Check that the contributor cannot run a verification.
+    assert!(matches!(
+        coordinator.run_verification(1, 0, 1, &contributor.participant, &contributor.signing_key),
+        Err(CoordinatorError::ExpectedVerifier)
+    ));
+
+    // Verify the two chunks from two threads at once.
+    let barrier = Arc::new(std::sync::Barrier::new(2));
+    let handles: Vec<_> = vec![verifier1, verifier2]
+        .into_iter()
+        .map(|verifier| {
+            let coordinator = coordinator.clone();
+            let barrier = barrier.clone();
+            std::thread::spawn(move || {
+                barrier.wait();
+                verifier.verify(&coordinator)
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap()?;
+    }
+
+    // Check that both contributions were verified, and no chunk is left locked.
+    let round = coordinator.current_round()?;
+    let verified = round
+        .chunks()
+        .iter()
+        .filter(|chunk| chunk.get_contribution(1).map(|c| c.is_verified()).unwrap_or(false))
+        .count();
+    assert_eq!(2, verified);
+    assert!(round.chunks().iter().all(|chunk| !chunk.is_locked()));
+
+    Ok(())
+}
+
+/// Locks and verifies the next contribution for the given verifier, corrupting its
+/// response before the verification is added, and returns its chunk and contribution ID.
+fn fail_verification(coordinator: &Coordinator, verifier: &VerifierTestDetails) -> anyhow::Result<(u64, u64)> {
+    let (chunk_id, locked_locators) = coordinator.try_lock(&verifier.participant)?;
+    let response_locator = locked_locators.current_contribution();
+    let round_height = response_locator.round_height();
+    let contribution_id = response_locator.contribution_id();
+    coordinator.run_verification(
+        round_height,
+        chunk_id,
+        contribution_id,
+        &verifier.participant,
+        &verifier.signing_key,
+    )?;
+
+    // Corrupt the challenge hash in the response.
+    {
+        let locator =
+            Locator::ContributionFile(ContributionLocator::new(round_height, chunk_id, contribution_id, false));
+        let storage = coordinator.storage();
+        let storage = storage.write().unwrap();
+        storage.writer(&locator)?.as_mut()[0] ^= 1;
+    }
+
+    assert!(matches!(
+        coordinator.try_verify(&verifier.participant, chunk_id),
+        Err(CoordinatorError::ContributionHashMismatch)
+    ));
+
+    Ok((chunk_id, contribution_id))
+}
+
+#[test]
+#[serial]
+fn coordinator_contribution_retry_test() -> anyhow::Result<()> {
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+    let retry_policy = ContributionRetryPolicy::new(1, chrono::Duration::minutes(10));
+    let testing = Testing::from(parameters).contribution_retry_policy(Some(retry_policy));
+    let environment = initialize_test_environment(&testing.into());
+
+    // Instantiate a coordinator, and advance to round 1.
+    let coordinator = Coordinator::new(environment, Box::new(Dummy))?;
+    coordinator.initialize()?;
+    let contributor = create_contributor_test_details("1");
+    let verifier = create_verifier_test_details("1");
+    coordinator.add_to_queue(contributor.participant.clone(), 10)?;
+    coordinator.add_to_queue(verifier.participant.clone(), 10)?;
+    coordinator.update()?;
+    let (_, mut events) = coordinator.subscribe()?;
+
+    // Contribute, and fail the verification of the contribution.
+    contributor.contribute_to(&coordinator)?;
+    let (chunk_id, contribution_id) = fail_verification(&coordinator, &verifier)?;
+
+    // Check that the response is quarantined, and removed from the chunk.
+    {
+        let storage = coordinator.storage();
+        let storage = storage.read().unwrap();
+        assert!(storage.exists(&Locator::QuarantinedContributionFile {
+            round_height: 1,
+            chunk_id,
+            contribution_id,
+            attempt: 1,
+        }));
+        assert!(!storage.exists(&Locator::ContributionFile(ContributionLocator::new(
+            1,
+            chunk_id,
+            contribution_id,
+            false
+        ))));
+    }
+    let chunk = coordinator.get_chunk(chunk_id)?;
+    assert_eq!(contribution_id - 1, chunk.current_contribution_id());
+    assert!(!chunk.is_locked());
+
+    // Check that the task is returned to the front of the assigned tasks of the contributor.
+    let state = coordinator.state();
+    assert_eq!(1, state.failed_contributions().len());
+    assert_eq!(contributor.participant, state.failed_contributions()[0].contributor);
+    let contributor_info = state.current_participant_info(&contributor.participant).unwrap();
+    assert_eq!(
+        Some(&Task::new(chunk_id, contribution_id)),
+        contributor_info.assigned_tasks().front()
+    );
+    assert!(contributor_info.completed_tasks().is_empty());
+
+    // Re-upload the contribution to the same chunk and contribution ID, and verify it.
+    contributor.contribute_to(&coordinator)?;
+    verifier.verify(&coordinator)?;
+    let chunk = coordinator.get_chunk(chunk_id)?;
+    assert_eq!(contribution_id, chunk.current_contribution_id());
+    assert!(chunk.get_contribution(contribution_id)?.is_verified());
+    assert!(coordinator.is_current_contributor(&contributor.participant));
+
+    // Check that the events record both attempts.
+    let events: Vec<CoordinatorEvent> = std::iter::from_fn(|| events.try_recv().ok()).collect();
+    let attempts = events
+        .iter()
+        .filter(|event| {
+            matches!(event, CoordinatorEvent::ContributionAdded { chunk_id: id, contribution_id: c, .. }
+                if *id == chunk_id && *c == contribution_id)
+        })
+        .count();
+    assert_eq!(2, attempts);
+    assert!(events.contains(&CoordinatorEvent::ContributionFailed {
+        round_height: 1,
+        chunk_id,
+        contribution_id,
+        participant: contributor.participant.clone(),
+        attempt: 1,
+    }));
+
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn coordinator_contribution_retry_exhausted_test() -> anyhow::Result<()> {
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+    let retry_policy = ContributionRetryPolicy::new(0, chrono::Duration::minutes(10));
+    let testing = Testing::from(parameters).contribution_retry_policy(Some(retry_policy));
+    let environment = initialize_test_environment(&testing.into());
+    let number_of_chunks = environment.number_of_chunks() as usize;
+
+    // Instantiate a coordinator, and advance to round 1.
+    let coordinator = Coordinator::new(environment, Box::new(Dummy))?;
+    coordinator.initialize()?;
+    let contributor1 = create_contributor_test_details("1");
+    let contributor2 = create_contributor_test_details("2");
+    let verifier = create_verifier_test_details("1");
+    coordinator.add_to_queue(contributor1.participant.clone(), 10)?;
+    coordinator.add_to_queue(contributor2.participant.clone(), 9)?;
+    coordinator.add_to_queue(verifier.participant.clone(), 10)?;
+    coordinator.update()?;
+
+    // Contribute, and fail the verification of the contribution without any retries.
+    contributor1.contribute_to(&coordinator)?;
+    let (chunk_id, contribution_id) = fail_verification(&coordinator, &verifier)?;
+
+    // Check that the contributor is dropped.
+    assert!(!coordinator.is_current_contributor(&contributor1.participant));
+    assert!(coordinator.is_current_contributor(&contributor2.participant));
+    assert!(coordinator
+        .dropped_participants()
+        .iter()
+        .any(|info| *info.id() == contributor1.participant));
+
+    // Check that the failed task is reassigned to the replacement contributor.
+    let contributors = coordinator.current_contributors();
+    assert_eq!(2, contributors.len());
+    let (_, replacement_info) = contributors
+        .iter()
+        .find(|(participant, _)| *participant != contributor2.participant)
+        .unwrap();
+    assert_eq!(number_of_chunks, replacement_info.assigned_tasks().len());
+    assert!(replacement_info
+        .assigned_tasks()
+        .contains(&Task::new(chunk_id, contribution_id)));
+
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn coordinator_pause_resume_test() -> anyhow::Result<()> {
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+    let environment = initialize_test_environment(&Testing::from(parameters).into());
+
+    let contributor = create_contributor_test_details("1");
+    let verifier = create_verifier_test_details("1");
+    {
+        // Instantiate a coordinator, and advance to round 1.
+        let coordinator = Coordinator::new(environment.clone(), Box::new(Dummy))?;
+        coordinator.initialize()?;
+        coordinator.add_to_queue(contributor.participant.clone(), 10)?;
+        coordinator.add_to_queue(verifier.participant.clone(), 10)?;
+        coordinator.update()?;
+
+        // Contribute, and pause the ceremony before the contribution is verified.
+        contributor.contribute_to(&coordinator)?;
+        assert!(!coordinator.is_paused());
+        coordinator.pause()?;
+        assert!(coordinator.is_paused());
+    }
+
+    // Check that the pause persists across a restart of the coordinator.
+    let coordinator = Coordinator::new(environment, Box::new(Dummy))?;
+    assert!(coordinator.is_paused());
+
+    // Check that the contributor may not lock a chunk while paused.
+    assert!(matches!(
+        coordinator.try_lock(&contributor.participant),
+        Err(CoordinatorError::CeremonyPaused)
+    ));
+
+    // Check that the in-flight verification may finish while paused.
+    verifier.verify(&coordinator)?;
+    assert!(coordinator
+        .current_round()?
+        .chunks()
+        .iter()
+        .any(|chunk| chunk.get_contribution(1).map(|c| c.is_verified()).unwrap_or(false)));
+
+    // Check that the contributor may lock a chunk again after resuming.
+    coordinator.resume()?;
+    assert!(!coordinator.is_paused());
+    contributor.contribute_to(&coordinator)?;
+
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn coordinator_pause_suspends_timeouts_test() -> anyhow::Result<()> {
+    let time = Arc::new(MockTimeSource::new(Utc::now()));
+
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+    let testing_deployment: Testing = Testing::from(parameters)
+        .contributor_seen_timeout(chrono::Duration::minutes(5))
+        .participant_lock_timeout(chrono::Duration::minutes(10));
+    let environment = initialize_test_environment(&Environment::from(testing_deployment));
+
+    // Instantiate a coordinator, and advance to round 1.
+    let coordinator = Coordinator::new_with_time(environment, Box::new(Dummy), time.clone())?;
+    coordinator.initialize()?;
+    let contributor1 = create_contributor_test_details("1");
+    let contributor2 = create_contributor_test_details("2");
+    let verifier = create_verifier_test_details("1");
+    coordinator.add_to_queue(contributor1.participant.clone(), 10)?;
+    coordinator.add_to_queue(contributor2.participant.clone(), 9)?;
+    coordinator.add_to_queue(verifier.participant.clone(), 10)?;
+    coordinator.update()?;
+
+    // Lock a chunk, and pause the ceremony for longer than both timeouts.
+    time.update(|prev| prev + chrono::Duration::minutes(2));
+    coordinator.try_lock(&contributor1.participant)?;
+    coordinator.heartbeat(&contributor2.participant)?;
+    coordinator.pause()?;
+    time.update(|prev| prev + chrono::Duration::minutes(30));
+
+    // Check that no participant is dropped while paused.
+    coordinator.update()?;
+    assert!(coordinator.is_current_contributor(&contributor1.participant));
+    assert!(coordinator.is_current_contributor(&contributor2.participant));
+    assert!(coordinator.dropped_participants().is_empty());
+
+    // Check that no participant is dropped on resuming, as the timeouts resume where they were paused.
+    coordinator.resume()?;
+    coordinator.update()?;
+    assert!(coordinator.is_current_contributor(&contributor1.participant));
+    assert!(coordinator.is_current_contributor(&contributor2.participant));
+
+    // Check that the contributors are dropped once the timeouts elapse after resuming.
+    time.update(|prev| prev + chrono::Duration::minutes(9));
+    coordinator.update()?;
+    assert!(!coordinator.is_current_contributor(&contributor1.participant));
+    assert!(!coordinator.is_current_contributor(&contributor2.participant));
+
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn coordinator_get_round_metrics_test() -> anyhow::Result<()> {
+    let time = Arc::new(MockTimeSource::new(Utc::now()));
+
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+    let environment = initialize_test_environment(&Testing::from(parameters).into());
+    let number_of_chunks = environment.number_of_chunks();
+
+    // Instantiate a coordinator, and advance to round 1.
+    let coordinator = Coordinator::new_with_time(environment, Box::new(Dummy), time.clone())?;
+    coordinator.initialize()?;
+
+    let contributor1 = create_contributor_test_details("1");
+    let contributor2 = create_contributor_test_details("2");
+    let verifier = create_verifier_test_details("1");
+    coordinator.add_to_queue(contributor1.participant.clone(), 10)?;
+    coordinator.add_to_queue(contributor2.participant.clone(), 10)?;
+    coordinator.add_to_queue(verifier.participant.clone(), 10)?;
+    coordinator.update()?;
+
+    // Check the metrics of round 1 before any contributions.
+    let metrics = coordinator.get_round_metrics(1)?;
+    assert_eq!(1, metrics.round_height());
+    assert_eq!(0, metrics.number_of_contributions());
+    assert_eq!(0, metrics.number_of_verifications());
+    assert_eq!(0, metrics.number_of_participants());
+    assert!(metrics.finished_at().is_none());
+
+    // Complete round 1, one minute per chunk.
+    for _ in 0..number_of_chunks {
+        time.update(|prev| prev + chrono::Duration::minutes(1));
+        contributor1.contribute_to(&coordinator)?;
+        contributor2.contribute_to(&coordinator)?;
+        verifier.verify(&coordinator)?;
+        verifier.verify(&coordinator)?;
+    }
+    assert!(coordinator.current_round()?.is_complete());
+
+    // Check the metrics of the completed round.
+    let metrics = coordinator.get_round_metrics(1)?;
+    assert_eq!(2 * number_of_chunks, metrics.number_of_contributions());
+    assert_eq!(2 * number_of_chunks, metrics.number_of_verifications());
+    assert_eq!(3, metrics.number_of_participants());
+    assert!(metrics.finished_at().is_some());
+    assert!(metrics.elapsed_seconds().unwrap() > 0);
+
+    // Check that the elapsed time stops at the finish of the round.
+    time.update(|prev| prev + chrono::Duration::minutes(10));
+    assert_eq!(metrics, coordinator.get_round_metrics(1)?);
+
+    // Check that a round which does not exist has no metrics.
+    assert!(coordinator.get_round_metrics(2).is_err());
+
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn coordinator_estimate_time_remaining_test() -> anyhow::Result<()> {
+    let time = Arc::new(MockTimeSource::new(Utc::now()));
+
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+    let environment = initialize_test_environment(&Testing::from(parameters).into());
+    let number_of_chunks = environment.number_of_chunks();
+
+    // Instantiate a coordinator, and advance to round 1.
+    let coordinator = Coordinator::new_with_time(environment, Box::new(Dummy), time.clone())?;
+    coordinator.initialize()?;
+    let contributor = create_contributor_test_details("1");
+    let verifier = create_verifier_test_details("1");
+    coordinator.add_to_queue(contributor.participant.clone(), 10)?;
+    coordinator.add_to_queue(verifier.participant.clone(), 10)?;
+    coordinator.update()?;
+
+    // Check that there is no estimate before a contribution is timed.
+    assert_eq!(number_of_chunks, coordinator.current_round()?.contributions_remaining());
+    assert_eq!(None, coordinator.estimate_time_remaining()?);
+
+    // Contribute to a chunk in one minute.
+    let (chunk_id, locked_locators) = coordinator.try_lock(&contributor.participant)?;
+    let response_locator = locked_locators.next_contribution();
+    time.update(|prev| prev + chrono::Duration::minutes(1));
+    coordinator.run_computation(
+        response_locator.round_height(),
+        chunk_id,
+        response_locator.contribution_id(),
+        &contributor.participant,
+        &contributor.signing_key,
+        &contributor.seed,
+    )?;
+    coordinator.try_contribute(&contributor.participant, chunk_id)?;
+    verifier.verify(&coordinator)?;
+    coordinator.update()?;
+
+    // Check that each remaining contribution is estimated at one minute, without any other factor.
+    assert_eq!(
+        number_of_chunks - 1,
+        coordinator.current_round()?.contributions_remaining()
+    );
+    assert_eq!(
+        Some(chrono::Duration::minutes((number_of_chunks - 1) as i64)),
+        coordinator.estimate_time_remaining()?
+    );
+
+    // Check that no time remains once all contributions are uploaded.
+    for _ in 1..number_of_chunks {
+        contributor.contribute_to(&coordinator)?;
+        verifier.verify(&coordinator)?;
+    }
+    assert_eq!(0, coordinator.current_round()?.contributions_remaining());
+    assert_eq!(Some(chrono::Duration::zero()), coordinator.estimate_time_remaining()?);
+
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn multi_coordinator_isolation_test() -> anyhow::Result<()> {
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+    let testing = Testing::from(parameters);
+    let inner = initialize_test_environment(&testing.local_base_directory("./transcript/testing-inner").into());
+    let outer = initialize_test_environment(&testing.local_base_directory("./transcript/testing-outer").into());
+
+    // Add a coordinator for each setup, and check that the setups are isolated.
+    let mut coordinators = MultiCoordinator::new();
+    coordinators.insert("inner", inner.clone(), Box::new(Dummy))?;
+    coordinators.insert("outer", outer.clone(), Box::new(Dummy))?;
+    assert_eq!(vec!["inner", "outer"], coordinators.setups());
+    assert_eq!("/v1/inner", MultiCoordinator::mount_point("inner"));
+    assert!(matches!(
+        coordinators.insert("inner", outer, Box::new(Dummy)),
+        Err(CoordinatorError::SetupAlreadyAdded)
+    ));
+    assert!(matches!(
+        coordinators.insert("universal", inner.clone(), Box::new(Dummy)),
+        Err(CoordinatorError::SetupStorageShared)
+    ));
+    let inner_respelled = testing.local_base_directory("./transcript/../transcript/testing-inner/");
+    assert!(matches!(
+        coordinators.insert("universal", inner_respelled.into(), Box::new(Dummy)),
+        Err(CoordinatorError::SetupStorageShared)
+    ));
+    assert!(matches!(
+        coordinators.insert("inner/v2", inner, Box::new(Dummy)),
+        Err(CoordinatorError::SetupInvalid)
+    ));
+    coordinators.initialize()?;
+
+    // Run rounds 1 and 2 of each setup concurrently, with different participants,
+    // while the services of the setups update the coordinators.
+    let runtime = tokio::runtime::Runtime::new()?;
+    let services = {
+        let _guard = runtime.enter();
+        coordinators.spawn_services(std::time::Duration::from_millis(10))
+    };
+    let handles: Vec<_> = coordinators
+        .setups()
+        .into_iter()
+        .map(|setup| {
+            let setup = setup.to_string();
+            let coordinator = coordinators.get(&setup).unwrap().clone();
+            std::thread::spawn(move || -> anyhow::Result<()> {
+                let contributor = create_contributor_test_details(&setup);
+                let verifier = create_verifier_test_details(&setup);
+                for round_height in 1..=2 {
+                    coordinator.add_to_queue(contributor.participant.clone(), 10)?;
+                    coordinator.add_to_queue(verifier.participant.clone(), 10)?;
+
+                    // Wait for the service of the setup to advance to the round.
+                    let started = std::time::Instant::now();
+                    while coordinator.current_round_height()? < round_height {
+                        anyhow::ensure!(
+                            started.elapsed() < std::time::Duration::from_secs(60),
+                            "The {} setup did not advance to round {}",
+                            setup,
+                            round_height
+                        );
+                        std::thread::sleep(std::time::Duration::from_millis(10));
+                    }
+
+                    for _ in 0..coordinator.environment().number_of_chunks() {
+                        contributor.contribute_to(&coordinator)?;
+                        verifier.verify(&coordinator)?;
+                    }
+                }
+                Ok(())
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap()?;
+    }
+    for service in services {
+        service.abort();
+    }
+    runtime.shutdown_timeout(std::time::Duration::from_secs(10));
+
+    // Check that each round only holds the participants and files of its own setup.
+    for setup in coordinators.setups() {
+        let coordinator = coordinators.get(setup).unwrap();
+        let round = coordinator.current_round()?;
+        assert_eq!(2, round.round_height());
+        assert!(round.is_complete());
+        assert_eq!(
+            &vec![create_contributor_test_details(setup).participant],
+            round.contributors()
+        );
+        for other in coordinators.setups().into_iter().filter(|other| *other != setup) {
+            assert!(!coordinator.is_current_contributor(&create_contributor_test_details(other).participant));
+        }
+
+        let round_state = coordinator.locator_to_path(Locator::RoundState { round_height: 1 })?;
+        assert!(round_state
+            .to_string()
+            .starts_with(&format!("./transcript/testing-{}/", setup)));
+    }
+
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn coordinator_transcript_manifest_test() -> anyhow::Result<()> {
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+    let environment = initialize_test_environment(&Testing::from(parameters).into());
+
+    // Instantiate a coordinator, and advance to round 1.
+    let coordinator = Coordinator::new(environment, Box::new(Dummy))?;
+    coordinator.initialize()?;
+
+    let contributor = create_contributor_test_details("1");
+    let verifier = create_verifier_test_details("1");
+    coordinator.add_to_queue(contributor.participant.clone(), 10)?;
+    coordinator.add_to_queue(verifier.participant.clone(), 10)?;
+    coordinator.update()?;
+
+    let before = coordinator.transcript_manifest(None, usize::MAX)?;
+    assert!(before.next_after.is_none());
+
+    // Contribute and verify a chunk.
+    contributor.contribute_to(&coordinator)?;
+    verifier.verify(&coordinator)?;
+
+    // Check that the manifest lists the newly verified contribution.
+    let round = coordinator.current_round()?;
+    let contribution = round
+        .chunks()
+        .iter()
+        .filter_map(|chunk| chunk.get_contribution(1).ok())
+        .find(|contribution| contribution.is_verified())
+        .unwrap();
+    let path = contribution.get_verified_location().clone().unwrap();
+
+    let after = coordinator.transcript_manifest(None, usize::MAX)?;
+    assert!(after.artifacts.len() > before.artifacts.len());
+    let artifact = after
+        .artifacts
+        .iter()
+        .find(|artifact| artifact.path == path.to_string())
+        .unwrap();
+    let bytes = std::fs::read(path.as_path())?;
+    assert_eq!(bytes.len() as u64, artifact.size);
+    assert_eq!(hex::encode(calculate_hash(&bytes)), artifact.hash);
+    assert!(artifact.modified_at.is_some());
+
+    // Check that the artifacts are sorted by path.
+    let paths: Vec<_> = after.artifacts.iter().map(|artifact| artifact.path.clone()).collect();
+    let mut sorted = paths.clone();
+    sorted.sort();
+    assert_eq!(sorted, paths);
+
+    // Check that paging through the manifest lists the same artifacts.
+    let mut paged = Vec::new();
+    let mut next_after = None;
+    loop {
+        let page = coordinator.transcript_manifest(next_after.as_deref(), 2)?;
+        assert!(page.artifacts.len() <= 2);
+        paged.extend(page.artifacts);
+        next_after = page.next_after;
+        if next_after.is_none() {
+            break;
+        }
+    }
+    assert_eq!(after.artifacts, paged);
+
+    // Check that a page with a limit of zero still advances.
+    let page = coordinator.transcript_manifest(None, 0)?;
+    assert_eq!(after.artifacts[..1], page.artifacts[..]);
+    assert_eq!(Some(after.artifacts[0].path.clone()), page.next_after);
+
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn coordinator_reinitialize_chunk_test() -> anyhow::Result<()> {
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+    let environment = initialize_test_environment(&Testing::from(parameters).into());
+
+    // Instantiate a coordinator, and advance to round 1.
+    let coordinator = Coordinator::new(environment, Box::new(Dummy))?;
+    coordinator.initialize()?;
+
+    let contributor = create_contributor_test_details("1");
+    let verifier = create_verifier_test_details("1");
+    coordinator.add_to_queue(contributor.participant.clone(), 10)?;
+    coordinator.add_to_queue(verifier.participant.clone(), 10)?;
+    coordinator.update()?;
+    assert_eq!(1, coordinator.current_round_height()?);
+
+    // Delete the initial contribution file of chunk 1, keeping a copy of it.
+    let locator = Locator::ContributionFile(ContributionLocator::new(1, 1, 0, true));
+    let original = {
+        let storage = coordinator.storage();
+        let mut storage = storage.write().unwrap();
+        let original = storage.reader(&locator)?.as_ref().to_vec();
+        storage.remove(&locator)?;
+        original
+    };
+    assert!(!coordinator.storage().read().unwrap().exists(&locator));
+
+    // Reinitialize chunk 1, and check that the file is restored as it was.
+    coordinator.reinitialize_chunk(1)?;
+    {
+        let storage = coordinator.storage();
+        let storage = storage.read().unwrap();
+        assert_eq!(original, storage.reader(&locator)?.as_ref().to_vec());
+        let initial_locator = Locator::ContributionFile(ContributionLocator::new(0, 1, 0, true));
+        assert_eq!(original, storage.reader(&initial_locator)?.as_ref().to_vec());
+    }
+
+    // Check that a chunk which was contributed to cannot be reinitialized.
+    contributor.contribute_to(&coordinator)?;
+    let chunk_id = coordinator
+        .get_round(1)?
+        .chunks()
+        .iter()
+        .find(|chunk| chunk.current_contribution_id() > 0)
+        .map(|chunk| chunk.chunk_id())
+        .unwrap();
+    assert!(matches!(
+        coordinator.reinitialize_chunk(chunk_id),
+        Err(CoordinatorError::ChunkAlreadyContributed)
+    ));
+
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn coordinator_storage_health_test() -> anyhow::Result<()> {
+    let time = Arc::new(MockTimeSource::new(Utc::now()));
+
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+    let environment = initialize_test_environment(&Testing::from(parameters).into());
+
+    // Instantiate a coordinator, which has not been updated yet.
+    let coordinator = Coordinator::new_with_time(environment, Box::new(Dummy), time.clone())?;
+    coordinator.initialize()?;
+    assert_eq!(None, coordinator.storage_health()?);
+
+    // Check that an update writes the health file with the current time.
+    coordinator.update()?;
+    assert_eq!(Some(time.utc_now()), coordinator.storage_health()?);
+    let path = coordinator.storage().read().unwrap().to_path(&Locator::Health)?;
+    assert!(path.as_path().exists());
+
+    // Check that each update rewrites the health file.
+    time.update(|prev| prev + chrono::Duration::minutes(1));
+    coordinator.update()?;
+    assert_eq!(Some(time.utc_now()), coordinator.storage_health()?);
+
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn coordinator_read_only_test() -> anyhow::Result<()> {
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+    let testing = Testing::from(parameters);
+    let environment = initialize_test_environment(&testing.clone().into());
+
+    // Instantiate a primary coordinator, and advance to round 1.
+    let primary = Coordinator::new(environment, Box::new(Dummy))?;
+    primary.initialize()?;
+
+    let contributor = create_contributor_test_details("1");
+    let verifier = create_verifier_test_details("1");
+    primary.add_to_queue(contributor.participant.clone(), 10)?;
+    primary.add_to_queue(verifier.participant.clone(), 10)?;
+    primary.update()?;
+    assert_eq!(1, primary.current_round_height()?);
+
+    // Instantiate a read-only coordinator on the storage of the primary coordinator.
+    let primary_url = "http://localhost:9000".to_string();
+    let read_only: Environment = testing
+        .coordinator_mode(CoordinatorMode::ReadOnly {
+            primary_url: primary_url.clone(),
+        })
+        .into();
+    let replica = Coordinator::new(read_only, Box::new(Dummy))?;
+    replica.initialize()?;
+    assert_eq!(1, replica.current_round_height()?);
+
+    // Check that the read-only coordinator sees a newly verified contribution after a refresh.
+    contributor.contribute_to(&primary)?;
+    verifier.verify(&primary)?;
+    replica.update()?;
+    let verified = |coordinator: &Coordinator| -> anyhow::Result<usize> {
+        Ok(coordinator
+            .get_round(1)?
+            .chunks()
+            .iter()
+            .filter(|chunk| {
+                chunk
+                    .current_contribution()
+                    .map_or(false, |contribution| contribution.is_verified())
+            })
+            .count())
+    };
+    assert_eq!(1, verified(&primary)?);
+    assert_eq!(verified(&primary)?, verified(&replica)?);
+    assert_eq!(
+        primary.state().current_round_height(),
+        replica.state().current_round_height()
+    );
+
+    // Check that the read-only coordinator rejects mutations, with a pointer to the primary.
+    let error = replica
+        .add_to_queue(create_contributor_test_details("2").participant, 10)
+        .unwrap_err();
+    assert!(matches!(
+        error,
+        CoordinatorError::CoordinatorReadOnly { primary_url: ref url } if *url == primary_url
+    ));
+    assert_eq!(405, error.status());
+
+    Ok(())
+}
+
+#[test]
+#[serial]
+/// Rejects every mutation on a read-only coordinator, without changing its state or storage.
+fn coordinator_read_only_rejects_every_mutator_test() -> anyhow::Result<()> {
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+    let testing = Testing::from(parameters);
+    let environment = initialize_test_environment(&testing.clone().into());
+
+    // Instantiate a primary coordinator, and advance to round 1.
+    let primary = Coordinator::new(environment, Box::new(Dummy))?;
+    primary.initialize()?;
+
+    let contributor = create_contributor_test_details("1");
+    let verifier = create_verifier_test_details("1");
+    primary.add_to_queue(contributor.participant.clone(), 10)?;
+    primary.add_to_queue(verifier.participant.clone(), 10)?;
+    primary.update()?;
+    assert_eq!(1, primary.current_round_height()?);
+
+    // Contribute to a chunk, and lock it for its verification, so each mutator gets past its own checks.
+    contributor.contribute_to(&primary)?;
+    let (chunk_id, locators) = primary.try_lock(&verifier.participant)?;
+    let contribution_id = locators.current_contribution().contribution_id();
+
+    // Instantiate a read-only coordinator on the storage of the primary coordinator.
+    let read_only: Environment = testing
+        .coordinator_mode(CoordinatorMode::ReadOnly {
+            primary_url: "http://localhost:9000".to_string(),
+        })
+        .into();
+    let mut replica = Coordinator::new(read_only, Box::new(Dummy))?;
+    replica.initialize()?;
+    assert_eq!(
+        Some(locators.lock_token()),
+        replica.state().lock_token(&verifier.participant, chunk_id)
+    );
+
+    let state = serde_json::to_value(&replica.state())?;
+    let round = primary.current_round()?;
+
+    // Call every public mutator of the coordinator on the read-only coordinator.
+    let queued = create_contributor_test_details("2");
+    let signature = ContributionFileSignature::new(
+        hex::encode(vec![0u8; 64]),
+        ContributionState::new(vec![0u8; 64], vec![0u8; 64], Some(vec![0u8; 64]))?,
+    )?;
+    let results: Vec<(&str, Result<(), CoordinatorError>)> = vec![
+        ("add_to_queue", replica.add_to_queue(queued.participant.clone(), 10)),
+        (
+            "add_to_queue_with_hardware",
+            replica.add_to_queue_with_hardware(queued.participant.clone(), 10, None),
+        ),
+        (
+            "add_to_queue_with_admission",
+            replica.add_to_queue_with_admission(queued.participant.clone(), 10, None, &AdmissionCredentials::default()),
+        ),
+        ("issue_invitation", replica.issue_invitation().map(|_| ())),
+        (
+            "issue_admission_challenge",
+            replica.issue_admission_challenge(&queued.participant).map(|_| ()),
+        ),
+        (
+            "check_client",
+            replica
+                .check_client(&contributor.participant, Some("setup1-contributor/0.2.1"))
+                .map(|_| ()),
+        ),
+        ("remove_from_queue", replica.remove_from_queue(&queued.participant)),
+        ("drop_participant", replica.drop_participant(&contributor.participant)),
+        (
+            "remove_from_round",
+            replica.remove_from_round(&verifier.participant).map(|_| ()),
+        ),
+        ("ban_participant", replica.ban_participant(&contributor.participant)),
+        ("unban_participant", replica.unban_participant(&contributor.participant)),
+        ("enable_manual_lock", replica.enable_manual_lock()),
+        ("disable_manual_lock", replica.disable_manual_lock()),
+        ("pause", replica.pause()),
+        ("resume", replica.resume()),
+        ("heartbeat", replica.heartbeat(&contributor.participant)),
+        ("try_lock", replica.try_lock(&contributor.participant).map(|_| ())),
+        (
+            "reserve_next_contribution_locator",
+            replica
+                .reserve_next_contribution_locator(chunk_id, &contributor.participant)
+                .map(|_| ()),
+        ),
+        ("force_unlock_chunk", replica.force_unlock_chunk(chunk_id).map(|_| ())),
+        ("reinitialize_chunk", replica.reinitialize_chunk(chunk_id)),
+        (
+            "upload_next_challenge",
+            replica.upload_next_challenge(
+                &verifier.participant,
+                chunk_id,
+                locators.lock_token(),
+                vec![1u8; 64],
+                signature,
+            ),
+        ),
+        (
+            "try_contribute",
+            replica.try_contribute(&contributor.participant, chunk_id).map(|_| ()),
+        ),
+        ("try_verify", replica.try_verify(&verifier.participant, chunk_id)),
+        ("try_aggregate", replica.try_aggregate()),
+        ("try_advance", replica.try_advance(Utc::now()).map(|_| ())),
+        ("reset_round", replica.reset_round()),
+        (
+            "contribute",
+            replica.contribute(&contributor.participant, &contributor.signing_key, &contributor.seed),
+        ),
+        (
+            "verify",
+            replica
+                .verify(&verifier.participant, &verifier.signing_key)
+                .map_err(|error| error.downcast::<CoordinatorError>().unwrap()),
+        ),
+        (
+            "run_computation",
+            replica.run_computation(
+                1,
+                chunk_id,
+                contribution_id,
+                &contributor.participant,
+                &contributor.signing_key,
+                &contributor.seed,
+            ),
+        ),
+        (
+            "run_verification",
+            replica
+                .run_verification(
+                    1,
+                    chunk_id,
+                    contribution_id,
+                    &verifier.participant,
+                    &verifier.signing_key,
+                )
+                .map(|_| ()),
+        ),
+        (
+            "record_verification_timing",
+            replica.record_verification_timing(
+                &verifier.participant,
+                chunk_id,
+                chrono::Duration::seconds(1),
+                chrono::Duration::seconds(1),
+                None,
+            ),
+        ),
+    ];
+
+    // Check that each mutation was rejected, and that neither the replica nor storage changed.
+    for (mutator, result) in results {
+        assert!(
+            matches!(result, Err(CoordinatorError::CoordinatorReadOnly { .. })),
+            "{} was not rejected by the read-only coordinator: {:?}",
+            mutator,
+            result
+        );
+    }
+    assert_eq!(state, serde_json::to_value(&replica.state())?);
+    assert_eq!(round, primary.current_round()?);
+
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn coordinator_list_rounds_test() -> anyhow::Result<()> {
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+    let environment = initialize_test_environment(&Testing::from(parameters).into());
+    let number_of_chunks = environment.number_of_chunks();
+
+    // Instantiate a coordinator, which has no rounds before it is initialized.
+    let coordinator = Coordinator::new(environment, Box::new(Dummy))?;
+    assert!(coordinator.list_rounds(0, 10)?.is_empty());
+    coordinator.initialize()?;
+    assert_eq!(vec![0], coordinator.list_rounds(0, 10)?);
+
+    // Run three rounds, and advance to round 4.
+    let contributor = create_contributor_test_details("1");
+    let verifier = create_verifier_test_details("1");
+    for round_height in 1..=3 {
+        coordinator.add_to_queue(contributor.participant.clone(), 10)?;
+        coordinator.add_to_queue(verifier.participant.clone(), 10)?;
+        coordinator.update()?;
+        assert_eq!(round_height, coordinator.current_round_height()?);
+
+        for _ in 0..number_of_chunks {
+            contributor.contribute_to(&coordinator)?;
+            verifier.verify(&coordinator)?;
+        }
+    }
+    coordinator.add_to_queue(contributor.participant.clone(), 10)?;
+    coordinator.add_to_queue(verifier.participant.clone(), 10)?;
+    coordinator.update()?;
+    assert_eq!(4, coordinator.current_round_height()?);
+
+    // Check that the round heights are listed in ascending order, a page at a time.
+    assert_eq!(vec![0, 1, 2, 3, 4], coordinator.list_rounds(0, 10)?);
+    assert_eq!(vec![0, 1], coordinator.list_rounds(0, 2)?);
+    assert_eq!(vec![2, 3], coordinator.list_rounds(2, 2)?);
+    assert_eq!(vec![4], coordinator.list_rounds(4, 2)?);
+    assert!(coordinator.list_rounds(5, 2)?.is_empty());
+    assert!(coordinator.list_rounds(0, 0)?.is_empty());
+
+    Ok(())
+}
+
+#[test]
+#[serial]
+/// Rejects participants whose client is outside of the supported range of versions,
+/// and records the client of each participant on the tasks they complete.
+fn coordinator_client_version_test() -> anyhow::Result<()> {
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+    let policy = ClientVersionPolicy::new(Version::new(0, 2, 0), Some(Version::new(0, 3, 0)));
+    let testing_deployment = Testing::from(parameters).client_version_policy(Some(policy));
+    let environment = initialize_test_environment(&Environment::from(testing_deployment));
+    let number_of_chunks = environment.number_of_chunks();
+
+    // Instantiate a coordinator.
+    let coordinator = Coordinator::new(environment.clone(), Box::new(Dummy))?;
+
+    // Initialize the ceremony to round 0.
+    coordinator.initialize()?;
+    assert_eq!(0, coordinator.current_round_height()?);
+
+    let contributor = create_contributor_test_details("1");
+    let verifier = create_verifier_test_details("1");
+
+    // Check that a missing or malformed header is rejected.
+    for header in &[None, Some("setup1-contributor"), Some("setup1-contributor/latest")] {
+        let error = coordinator.check_client(&contributor.participant, *header).unwrap_err();
+        assert!(matches!(error, CoordinatorError::ClientVersionInvalid(_)));
+        assert_eq!(400, error.status());
+    }
+
+    // Check that an old client is rejected with the minimum version to upgrade to.
+    let error = coordinator
+        .check_client(&contributor.participant, Some("setup1-contributor/0.1.9"))
+        .unwrap_err();
+    assert_eq!("client_upgrade_required", error.code());
+    assert_eq!(426, error.status());
+    let api_error = ApiError::from(&error);
+    assert_eq!("0.2.0", api_error.details["minVersion"]);
+    assert_eq!("0.3.0", api_error.details["maxVersion"]);
+
+    // Check that a client newer than the maximum is rejected too.
+    assert!(coordinator
+        .check_client(&contributor.participant, Some("setup1-contributor/0.4.0"))
+        .is_err());
+    assert_eq!(None, coordinator.state().participant_client(&contributor.participant));
+
+    // Check that supported clients are accepted and recorded.
+    let contributor_client = coordinator.check_client(&contributor.participant, Some("setup1-contributor/0.2.1"))?;
+    let verifier_client = coordinator.check_client(&verifier.participant, Some("setup1-verifier/0.3.0"))?;
+    assert_eq!(
+        ClientVersion::new("setup1-verifier", Version::new(0, 3, 0)),
+        verifier_client
+    );
+
+    // Check that the clients are persisted across a restart of the coordinator.
+    let coordinator = Coordinator::new(environment, Box::new(Dummy))?;
+    assert_eq!(
+        Some(&contributor_client),
+        coordinator.state().participant_client(&contributor.participant)
+    );
+
+    // Run a round with both participants.
+    coordinator.add_to_queue(contributor.participant.clone(), 10)?;
+    coordinator.add_to_queue(verifier.participant.clone(), 10)?;
+    coordinator.update()?;
+    assert_eq!(1, coordinator.current_round_height()?);
+
+    for _ in 0..number_of_chunks {
+        contributor.contribute_to(&coordinator)?;
+        verifier.verify(&coordinator)?;
+    }
+
+    // Check that the clients are recorded on each contribution and verification in the round state.
+    let round = coordinator.get_round(1)?;
+    for chunk in round.chunks() {
+        let contribution = chunk.get_contribution(1)?;
+        assert_eq!(&Some(contributor_client.clone()), contribution.get_contributor_client());
+
+        let verifications = contribution.get_verifications();
+        assert_eq!(1, verifications.len());
+        assert_eq!(Some(&verifier_client), verifications[0].client());
+    }
+
+    Ok(())
+}
+
+#[test]
+#[serial]
+/// Retrying a completed verification succeeds for the same verifier, and fails for another verifier.
+fn coordinator_verify_retry_test() -> anyhow::Result<()> {
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+    let environment = initialize_test_environment(&Testing::from(parameters).into());
+
+    // Instantiate a coordinator, and advance to round 1.
+    let coordinator = Coordinator::new(environment, Box::new(Dummy))?;
+    coordinator.initialize()?;
+    let contributor = create_contributor_test_details("1");
+    let verifiers = vec![create_verifier_test_details("1"), create_verifier_test_details("2")];
+    coordinator.add_to_queue(contributor.participant.clone(), 10)?;
+    coordinator.add_to_queue(verifiers[0].participant.clone(), 10)?;
+    coordinator.add_to_queue(verifiers[1].participant.clone(), 10)?;
+    coordinator.update()?;
+
+    // Contribute, and verify the contribution.
+    contributor.contribute_to(&coordinator)?;
+    let verifier = assigned_verifier(&coordinator, &verifiers);
+    verifier.verify(&coordinator)?;
+    let round = coordinator.current_round()?;
+    let chunk_id = round
+        .chunks()
+        .iter()
+        .find(|chunk| chunk.current_contribution_id() == 1)
+        .map(|chunk| chunk.chunk_id())
+        .unwrap();
+    assert!(round.chunk(chunk_id)?.get_contribution(1)?.is_verified());
+
+    // Check that the same verifier retrying the verification is a no-op.
+    coordinator.try_verify(&verifier.participant, chunk_id)?;
+    assert_eq!(round, coordinator.current_round()?);
+
+    // Check that another verifier is told the contribution is already verified.
+    let other = verifiers
+        .iter()
+        .find(|other| other.participant != verifier.participant)
+        .unwrap();
+    assert!(matches!(
+        coordinator.try_verify(&other.participant, chunk_id),
+        Err(CoordinatorError::ContributionAlreadyVerified)
+    ));
+    assert_eq!(round, coordinator.current_round()?);
+
+    Ok(())
+}
+
+#[test]
+#[serial]
+/// Planning the next round reports the error the transition would produce, without writing anything.
+fn coordinator_plan_next_round_test() -> anyhow::Result<()> {
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+    let environment = initialize_test_environment(&Testing::from(parameters).into());
+
+    // Instantiate a coordinator.
+    let coordinator = Coordinator::new(environment, Box::new(Dummy))?;
+    coordinator.initialize()?;
+
+    // Check that planning with an empty queue lists the error of the transition.
+    let plan = coordinator.plan_next_round()?;
+    assert!(!plan.is_ready());
+    assert_eq!(1, plan.next_round_height());
+    assert!(matches!(plan.blockers()[0], CoordinatorError::QueueIsEmpty));
+    let error = coordinator.try_advance(Utc::now()).unwrap_err();
+    assert_eq!(format!("{:?}", error), format!("{:?}", plan.blockers()[0]));
+
+    // Check that planning with a full queue selects the participants, and writes nothing.
+    let contributor = create_contributor_test_details("1");
+    let verifier = create_verifier_test_details("1");
+    coordinator.add_to_queue(contributor.participant.clone(), 10)?;
+    coordinator.add_to_queue(verifier.participant.clone(), 10)?;
+    let plan = coordinator.plan_next_round()?;
+    assert!(plan.is_ready(), "{:?}", plan.blockers());
+    assert_eq!(&[contributor.participant.clone()], plan.contributors());
+    assert_eq!(&[verifier.participant.clone()], plan.verifiers());
+    assert!(plan.estimated_storage_bytes() > 0);
+    assert_eq!(None, plan.estimated_aggregation_seconds());
+    assert_eq!(0, coordinator.current_round_height()?);
+    assert_eq!(0, coordinator.state().current_round_height());
+    assert!(coordinator.state().is_queue_contributor(&contributor.participant));
+    assert!(coordinator.state().is_queue_verifier(&verifier.participant));
+
+    // Check that the transition selects the planned participants.
+    coordinator.update()?;
+    let round = coordinator.current_round()?;
+    assert_eq!(1, round.round_height());
+    assert_eq!(plan.contributors(), &round.contributors()[..]);
+    assert_eq!(plan.verifiers(), &round.verifiers()[..]);
+
+    // Check that planning during an unfinished round lists the error of the transition.
+    let plan = coordinator.plan_next_round()?;
+    assert_eq!(2, plan.next_round_height());
+    assert!(matches!(plan.blockers(), [CoordinatorError::CurrentRoundNotFinished]));
+    let error = coordinator.try_advance(Utc::now()).unwrap_err();
+    assert_eq!(format!("{:?}", error), format!("{:?}", plan.blockers()[0]));
+    assert_eq!(round, coordinator.current_round()?);
+
+    // Check that planning after an aggregated round estimates the aggregation time.
+    for _ in 0..coordinator.environment().number_of_chunks() {
+        contributor.contribute_to(&coordinator)?;
+        verifier.verify(&coordinator)?;
+    }
+    coordinator.update()?;
+    let plan = coordinator.plan_next_round()?;
+    assert!(plan.estimated_aggregation_seconds().is_some());
+
+    Ok(())
+}
+
+#[cfg(feature = "webhook")]
+#[test]
+#[serial]
+/// The webhook notifier posts each round transition, retrying a failed request.
+fn coordinator_webhook_notifier_test() -> anyhow::Result<()> {
+    use crate::webhook::{RoundTransition, WebhookNotifier};
+    use std::{
+        io::{Read, Write},
+        net::{TcpListener, TcpStream},
+        sync::mpsc,
+        time::Duration,
+    };
+
+    /// Reads a request from the stream, and returns its body.
+    fn read_body(stream: &mut TcpStream) -> Vec<u8> {
+        let mut request = vec![];
+        let mut buffer = [0u8; 1024];
+        loop {
+            let read = stream.read(&mut buffer).unwrap();
+            assert!(read > 0, "The connection closed before the request was complete");
+            request.extend_from_slice(&buffer[..read]);
+
+            let text = String::from_utf8_lossy(&request).to_string();
+            if let Some(end) = text.find("\r\n\r\n") {
+                let length = text[..end]
+                    .lines()
+                    .filter_map(|line| line.find(':').map(|index| line.split_at(index)))
+                    .find(|(name, _)| name.eq_ignore_ascii_case("content-length"))
+                    .map(|(_, value)| value[1..].trim().parse::<usize>().unwrap())
+                    .unwrap_or(0);
+                if request.len() >= end + 4 + length {
+                    return request[end + 4..end + 4 + length].to_vec();
+                }
+            }
+        }
+    }
+
+    // Start a webhook server which fails the first request, and records the bodies of the others.
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+    let url = format!("http://{}/round", listener.local_addr()?);
+    let (sender, bodies) = mpsc::channel();
+    std::thread::spawn(move || {
+        for (attempt, stream) in listener.incoming().enumerate() {
+            let mut stream = stream.unwrap();
+            let body = read_body(&mut stream);
+            let status = match attempt {
+                0 => "503 Service Unavailable",
+                _ => "200 OK",
+            };
+            write!(
+                stream,
+                "HTTP/1.1 {}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+                status
+            )
+            .unwrap();
+            if attempt > 0 {
+                sender.send(body).unwrap();
+            }
+        }
+    });
+
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+    let environment = initialize_test_environment(&Testing::from(parameters).into());
+    let number_of_chunks = environment.number_of_chunks();
+
+    // Instantiate a coordinator, and advance to round 1.
+    let coordinator = Coordinator::new(environment, Box::new(Dummy))?;
+    coordinator.initialize()?;
+    let contributor = create_contributor_test_details("1");
+    let verifier = create_verifier_test_details("1");
+    coordinator.add_to_queue(contributor.participant.clone(), 10)?;
+    coordinator.add_to_queue(verifier.participant.clone(), 10)?;
+    coordinator.update()?;
+
+    // Start the webhook notifier on the events of the coordinator.
+    let runtime = tokio::runtime::Runtime::new()?;
+    let (_, events) = coordinator.subscribe()?;
+    let notifier = WebhookNotifier::new(&url).retry_delay(Duration::from_millis(10));
+    runtime.spawn(notifier.run(coordinator.clone(), events));
+
+    // Run contribution and verification on every chunk in round 1, and advance to round 2.
+    for _ in 0..number_of_chunks {
+        contributor.contribute_to(&coordinator)?;
+        verifier.verify(&coordinator)?;
+    }
+    coordinator.add_to_queue(contributor.participant.clone(), 10)?;
+    coordinator.add_to_queue(verifier.participant.clone(), 10)?;
+    coordinator.update()?;
+    assert_eq!(2, coordinator.current_round_height()?);
+
+    // Check that the retried request holds the transition from round 1 to round 2.
+    let body = bodies.recv_timeout(Duration::from_secs(30))?;
+    let payload: serde_json::Value = serde_json::from_slice(&body)?;
+    let round_file_hash = coordinator.get_round(1)?.round_file_hash().unwrap().to_string();
+    assert_eq!(1, payload["old_round_height"]);
+    assert_eq!(2, payload["new_round_height"]);
+    assert_eq!(round_file_hash, payload["round_file_hash"]);
+    let transition: RoundTransition = serde_json::from_value(payload)?;
+    assert_eq!(coordinator.get_round(2)?.started_at(), Some(transition.timestamp));
+
+    Ok(())
+}
+
+#[test]
+#[serial]
+/// Invitation tokens admit a single contributor to the queue, and cannot be replayed.
+fn coordinator_admission_invitation_test() -> anyhow::Result<()> {
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+    let policy = AdmissionPolicy::new(Some(InvitationPolicy::new(b"operator secret")), None);
+    let testing_deployment = Testing::from(parameters).admission_policy(Some(policy));
+    let environment = initialize_test_environment(&Environment::from(testing_deployment.clone()));
+
+    // Instantiate a coordinator.
+    let coordinator = Coordinator::new(environment.clone(), Box::new(Dummy))?;
+    coordinator.initialize()?;
+
+    let contributor = create_contributor_test_details("1");
+    let other = create_contributor_test_details("2");
+    let join = |coordinator: &Coordinator, participant: &Participant, invitation: Option<String>| {
+        let credentials = AdmissionCredentials {
+            invitation,
+            challenge: None,
+            nonce: None,
+        };
+        coordinator.add_to_queue_with_admission(participant.clone(), 10, None, &credentials)
+    };
+
+    // Check that a contributor without a valid invitation is rejected.
+    let error = coordinator
+        .add_to_queue(contributor.participant.clone(), 10)
+        .unwrap_err();
+    assert!(matches!(error, CoordinatorError::InvitationMissing));
+    assert_eq!("admission_required", error.code());
+    assert_eq!(403, error.status());
+    let forged = InvitationPolicy::new(b"forged secret").issue()?;
+    assert!(matches!(
+        join(&coordinator, &contributor.participant, Some(forged)),
+        Err(CoordinatorError::InvitationInvalid)
+    ));
+
+    // Check that an invitation issued by the operator admits the contributor.
+    let invitation = coordinator.issue_invitation()?;
+    join(&coordinator, &contributor.participant, Some(invitation.clone()))?;
+    assert!(coordinator.is_queue_contributor(&contributor.participant));
+
+    // Check that the invitation cannot be replayed, by the same or another contributor.
+    coordinator.remove_from_queue(&contributor.participant)?;
+    assert!(matches!(
+        join(&coordinator, &contributor.participant, Some(invitation.clone())),
+        Err(CoordinatorError::InvitationConsumed)
+    ));
+    assert!(matches!(
+        join(&coordinator, &other.participant, Some(invitation.clone())),
+        Err(CoordinatorError::InvitationConsumed)
+    ));
+
+    // Check that the key of the invitations is not written to the coordinator state.
+    let stored = serde_json::to_string(&coordinator.state())?;
+    assert!(!stored.contains(&hex::encode(b"operator secret")));
+
+    // Check that the consumed invitation is persisted across a restart of the coordinator,
+    // and that the key is restored from the environment the coordinator is started with.
+    drop(coordinator);
+    let coordinator = Coordinator::new(environment, Box::new(Dummy))?;
+    assert!(matches!(
+        join(&coordinator, &other.participant, Some(invitation)),
+        Err(CoordinatorError::InvitationConsumed)
+    ));
+    join(&coordinator, &other.participant, Some(coordinator.issue_invitation()?))?;
+
+    // Check that verifiers are not subject to the admission gates.
+    let verifier = create_verifier_test_details("1");
+    coordinator.add_to_queue(verifier.participant.clone(), 10)?;
+
+    // Check that a read-only coordinator does not issue invitations, as only the primary may.
+    let read_only: Environment = testing_deployment
+        .coordinator_mode(CoordinatorMode::ReadOnly {
+            primary_url: "http://localhost:9000".to_string(),
+        })
+        .into();
+    let replica = Coordinator::new(read_only, Box::new(Dummy))?;
+    replica.initialize()?;
+    assert!(matches!(
+        replica.issue_invitation(),
+        Err(CoordinatorError::CoordinatorReadOnly { .. })
+    ));
+
+    Ok(())
+}
+
+#[test]
+#[serial]
+/// Contributors must solve an unexpired proof-of-work challenge of sufficient difficulty to join the queue.
+fn coordinator_admission_proof_of_work_test() -> anyhow::Result<()> {
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+    let policy = AdmissionPolicy::new(None, Some(ProofOfWorkPolicy::new(8, chrono::Duration::minutes(5))));
+    let testing_deployment = Testing::from(parameters).admission_policy(Some(policy));
+    let environment = initialize_test_environment(&Environment::from(testing_deployment));
+
+    // Instantiate a coordinator with a mock time source.
+    let time = Arc::new(MockTimeSource::new(Utc::now()));
+    let coordinator = Coordinator::new_with_time(environment, Box::new(Dummy), time.clone())?;
+    coordinator.initialize()?;
+
+    let contributor = create_contributor_test_details("1");
+    let join = |challenge: Option<&ProofOfWorkChallenge>, nonce: Option<u64>| {
+        let credentials = AdmissionCredentials {
+            invitation: None,
+            challenge: challenge.cloned(),
+            nonce,
+        };
+        coordinator.add_to_queue_with_admission(contributor.participant.clone(), 10, None, &credentials)
+    };
+
+    // Check that a contributor must request a challenge, and solve it.
+    assert!(matches!(
+        coordinator.issue_invitation(),
+        Err(CoordinatorError::AdmissionPolicyMissing)
+    ));
+    assert!(matches!(join(None, None), Err(CoordinatorError::ProofOfWorkMissing)));
+    assert!(matches!(
+        join(None, Some(0)),
+        Err(CoordinatorError::ProofOfWorkChallengeMissing)
+    ));
+
+    // Check that a solution below the difficulty of the challenge is rejected.
+    let challenge = coordinator.issue_admission_challenge(&contributor.participant)?;
+    assert_eq!(8, challenge.difficulty);
+    let insufficient = (0..)
+        .find(|nonce| !challenge.is_solved_by(&contributor.participant, *nonce))
+        .unwrap();
+    let error = join(Some(&challenge), Some(insufficient)).unwrap_err();
+    assert!(matches!(error, CoordinatorError::ProofOfWorkInsufficient));
+    assert_eq!(403, error.status());
+
+    // Check that a challenge is bound to the contributor it was issued to.
+    let other = create_contributor_test_details("2");
+    let other_challenge = coordinator.issue_admission_challenge(&other.participant)?;
+    let nonce = other_challenge.solve(&contributor.participant);
+    assert!(matches!(
+        join(Some(&other_challenge), Some(nonce)),
+        Err(CoordinatorError::ProofOfWorkChallengeInvalid)
+    ));
+
+    // Check that a challenge with a lowered difficulty is rejected.
+    let easier = ProofOfWorkChallenge {
+        difficulty: 0,
+        ..challenge.clone()
+    };
+    assert!(matches!(
+        join(Some(&easier), Some(insufficient)),
+        Err(CoordinatorError::ProofOfWorkChallengeInvalid)
+    ));
+
+    // Check that a solution to an expired challenge is rejected.
+    let nonce = challenge.solve(&contributor.participant);
+    time.update(|now| now + chrono::Duration::minutes(6));
+    assert!(matches!(
+        join(Some(&challenge), Some(nonce)),
+        Err(CoordinatorError::ProofOfWorkChallengeExpired)
+    ));
+
+    // Check that a solution to a new challenge admits the contributor, and consumes the challenge.
+    let challenge = coordinator.issue_admission_challenge(&contributor.participant)?;
+    let nonce = challenge.solve(&contributor.participant);
+    join(Some(&challenge), Some(nonce))?;
+    assert!(coordinator.is_queue_contributor(&contributor.participant));
+    coordinator.remove_from_queue(&contributor.participant)?;
+    assert!(matches!(
+        join(Some(&challenge), Some(nonce)),
+        Err(CoordinatorError::ProofOfWorkChallengeConsumed)
+    ));
+
+    Ok(())
+}
+
+#[test]
+#[serial]
+/// A contributor requesting any number of proof-of-work challenges does not keep others from being issued one.
+fn coordinator_admission_challenges_are_not_exhausted_test() -> anyhow::Result<()> {
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+    let policy = AdmissionPolicy::new(None, Some(ProofOfWorkPolicy::new(8, chrono::Duration::minutes(5))));
+    let testing_deployment = Testing::from(parameters).admission_policy(Some(policy));
+    let environment = initialize_test_environment(&Environment::from(testing_deployment));
+
+    // Instantiate a coordinator.
+    let coordinator = Coordinator::new(environment, Box::new(Dummy))?;
+    coordinator.initialize()?;
+
+    // Flood the coordinator with challenge requests from a single contributor.
+    let flooder = create_contributor_test_details("1");
+    let state = serde_json::to_value(&coordinator.state())?;
+    for _ in 0..20_000 {
+        coordinator.issue_admission_challenge(&flooder.participant)?;
+    }
+
+    // Check that the challenges are not held by the coordinator.
+    assert_eq!(state, serde_json::to_value(&coordinator.state())?);
+
+    // Check that another contributor is still issued a challenge, and joins the queue with it.
+    let contributor = create_contributor_test_details("2");
+    let challenge = coordinator.issue_admission_challenge(&contributor.participant)?;
+    let credentials = AdmissionCredentials {
+        invitation: None,
+        nonce: Some(challenge.solve(&contributor.participant)),
+        challenge: Some(challenge),
+    };
+    coordinator.add_to_queue_with_admission(contributor.participant.clone(), 10, None, &credentials)?;
+    assert!(coordinator.is_queue_contributor(&contributor.participant));
+
+    Ok(())
+}
+
+#[test]
+#[serial]
+/// The queue position of each participant follows the order they were admitted to the queue.
+fn coordinator_queue_position_test() -> anyhow::Result<()> {
+    let environment = initialize_test_environment(&TEST_ENVIRONMENT);
+
+    // Instantiate a coordinator.
+    let coordinator = Coordinator::new(environment, Box::new(Dummy))?;
+    coordinator.initialize()?;
+
+    let contributor1 = create_contributor_test_details("1");
+    let contributor2 = create_contributor_test_details("2");
+    let contributor3 = create_contributor_test_details("3");
+    let contributor4 = create_contributor_test_details("4");
+
+    // Enqueue the contributors, with the highest reliability score last.
+    coordinator.add_to_queue(contributor1.participant.clone(), 1)?;
+    coordinator.add_to_queue(contributor2.participant.clone(), 5)?;
+    coordinator.add_to_queue(contributor3.participant.clone(), 10)?;
+
+    // Check that each contributor reports its position in admission order.
+    assert_eq!(Some(0), coordinator.queue_position(&contributor1.participant)?);
+    assert_eq!(Some(1), coordinator.queue_position(&contributor2.participant)?);
+    assert_eq!(Some(2), coordinator.queue_position(&contributor3.participant)?);
+    assert!(coordinator.participant_is_queued(&contributor3.participant));
+
+    // Check that a contributor who is not queued has no position.
+    assert_eq!(None, coordinator.queue_position(&contributor4.participant)?);
+    assert!(!coordinator.participant_is_queued(&contributor4.participant));
+
+    // Check that a contributor who leaves and rejoins the queue moves to the back.
+    coordinator.remove_from_queue(&contributor1.participant)?;
+    assert_eq!(None, coordinator.queue_position(&contributor1.participant)?);
+    assert_eq!(Some(0), coordinator.queue_position(&contributor2.participant)?);
+    coordinator.add_to_queue(contributor1.participant.clone(), 1)?;
+    assert_eq!(Some(1), coordinator.queue_position(&contributor3.participant)?);
+    assert_eq!(Some(2), coordinator.queue_position(&contributor1.participant)?);
+
+    Ok(())
+}
+
+#[test]
+#[serial]
+/// Verification timings are recorded, and recommend a chunk size for a target task duration.
+fn coordinator_chunking_report_test() -> anyhow::Result<()> {
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+    let environment = initialize_test_environment(&Testing::from(parameters).into());
+
+    // Instantiate a coordinator.
+    let coordinator = Coordinator::new(environment.clone(), Box::new(Dummy))?;
+    coordinator.initialize()?;
+
+    let (contributor, contributor_signing_key, seed) = create_contributor("1");
+    let (verifier, verifier_signing_key) = create_verifier("1");
+    coordinator.add_to_queue(contributor.clone(), 10)?;
+    coordinator.add_to_queue(verifier.clone(), 10)?;
+
+    // Update the ceremony to round 1.
+    coordinator.update()?;
+    assert_eq!(1, coordinator.current_round_height()?);
+
+    // Check that there is nothing to report before any verifications.
+    assert_eq!(None, coordinator.chunking_report(chrono::Duration::minutes(10)));
+
+    // Check that only verifiers may report their timings.
+    let one_second = chrono::Duration::seconds(1);
+    assert!(matches!(
+        coordinator.record_verification_timing(&contributor, 0, one_second, one_second, None),
+        Err(CoordinatorError::ExpectedVerifier)
+    ));
+
+    // Check that verifiers may only report the timings of chunks they verified.
+    assert!(matches!(
+        coordinator.record_verification_timing(&verifier, 0, one_second, one_second, None),
+        Err(CoordinatorError::UnauthorizedChunkVerifier)
+    ));
+
+    // Check that a verification run by the coordinator is recorded, without transfers.
+    coordinator.contribute(&contributor, &contributor_signing_key, &seed)?;
+    coordinator.verify(&verifier, &verifier_signing_key)?;
+    let timings = coordinator.state().verification_timings().to_vec();
+    assert_eq!(1, timings.len());
+    assert_eq!((1, 0), (timings[0].round_height, timings[0].chunk_id));
+    assert_eq!(chrono::Duration::zero(), timings[0].transfer_duration);
+    assert_eq!(None, timings[0].hardware);
+
+    // Report the verification of chunk 0, which holds 16 powers of tau in G1,
+    // and 16 elements of each of tau_g2, alpha_g1 and beta_g1, and beta_g2.
+    coordinator.record_verification_timing(
+        &verifier,
+        0,
+        chrono::Duration::seconds(52),
+        chrono::Duration::seconds(13),
+        Some("8 cores, 16 GB".to_string()),
+    )?;
+    let timings = coordinator.state().verification_timings().to_vec();
+    assert_eq!(2, timings.len());
+    assert_eq!((1, 0), (timings[1].round_height, timings[1].chunk_id));
+    assert_eq!((16, 65), (timings[1].number_of_powers, timings[1].number_of_elements));
+    assert_eq!(Some("8 cores, 16 GB".to_string()), timings[1].hardware);
+    assert_eq!(
+        2,
+        coordinator
+            .chunking_report(chrono::Duration::minutes(10))
+            .unwrap()
+            .number_of_timings
+    );
+
+    // Check that 65 seconds for 16 powers fits 147 powers in 10 minutes.
+    let report = ChunkingReport::new(&timings[1..], 16, chrono::Duration::minutes(10)).unwrap();
+    assert_eq!(1, report.number_of_timings);
+    assert_eq!(0.8, report.verification_seconds_per_element);
+    assert_eq!(0.2, report.transfer_seconds_per_element);
+    assert_eq!(4.0625, report.elements_per_power);
+    assert_eq!(16, report.current_chunk_size);
+    assert_eq!(147, report.recommended_chunk_size);
+
+    // Check that the reported verification of a chunk it did not verify is still rejected.
+    assert!(matches!(
+        coordinator.record_verification_timing(&verifier, 1, one_second, one_second, None),
+        Err(CoordinatorError::UnauthorizedChunkVerifier)
+    ));
+
+    // Check that the timings are persisted across a restart of the coordinator.
+    drop(coordinator);
+    let coordinator = Coordinator::new(environment, Box::new(Dummy))?;
+    let persisted = coordinator.state().verification_timings().to_vec();
+    assert_eq!(timings.len(), persisted.len());
+    assert_eq!(timings[0].chunk_id, persisted[0].chunk_id);
+    assert_eq!(timings[1], persisted[1]);
+
+    Ok(())
+}
+
+#[test]
+#[serial]
+/// Tests that the contribution file reader returns the stored bytes of a contribution.
+fn coordinator_contribution_file_reader_test() -> anyhow::Result<()> {
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+    let environment = initialize_test_environment(&Testing::from(parameters).into());
+
+    // Instantiate a coordinator.
+    let coordinator = Coordinator::new(environment.clone(), Box::new(Dummy))?;
+    coordinator.initialize()?;
+
+    let (contributor, contributor_signing_key, seed) = create_contributor("1");
+    let (verifier, verifier_signing_key) = create_verifier("1");
+    coordinator.add_to_queue(contributor.clone(), 10)?;
+    coordinator.add_to_queue(verifier.clone(), 10)?;
+
+    // Update the ceremony to round 1.
+    coordinator.update()?;
+    assert_eq!(1, coordinator.current_round_height()?);
+
+    // Reads the given contribution file directly from storage.
+    let read_from_storage = |chunk_id: u64, contribution_id: u64, verified: bool| -> anyhow::Result<Vec<u8>> {
+        let storage = coordinator.storage();
+        let storage = storage.read().unwrap();
+        let locator = ContributionLocator::new(1, chunk_id, contribution_id, verified);
+        Ok(storage.reader(&Locator::ContributionFile(locator))?.to_vec())
+    };
+
+    // Reads the given contribution file through the coordinator.
+    let read_from_coordinator = |chunk_id: u64, contribution_id: u64| -> anyhow::Result<Vec<u8>> {
+        let mut bytes = vec![];
+        coordinator
+            .contribution_file_reader(1, chunk_id, contribution_id)?
+            .read_to_end(&mut bytes)?;
+        Ok(bytes)
+    };
+
+    // Check that the initial challenge of a chunk is read back.
+    let bytes = read_from_coordinator(0, 0)?;
+    assert!(!bytes.is_empty());
+    assert_eq!(read_from_storage(0, 0, true)?, bytes);
+
+    // Check that an unverified contribution is read from the uploaded file.
+    coordinator.contribute(&contributor, &contributor_signing_key, &seed)?;
+    let round = coordinator.current_round()?;
+    let chunk_id = round
+        .chunks()
+        .iter()
+        .find(|chunk| chunk.get_contribution(1).is_ok())
+        .unwrap()
+        .chunk_id();
+    let bytes = read_from_coordinator(chunk_id, 1)?;
+    assert_eq!(read_from_storage(chunk_id, 1, false)?, bytes);
+
+    // Check that a verified contribution is read from the verified file.
+    coordinator.verify(&verifier, &verifier_signing_key)?;
+    let bytes = read_from_coordinator(chunk_id, 1)?;
+    assert_eq!(read_from_storage(chunk_id, 1, true)?, bytes);
+
+    // Check that missing contributions and invalid chunk IDs are rejected.
+    assert!(matches!(
+        coordinator.contribution_file_reader(1, chunk_id, 2),
+        Err(CoordinatorError::ContributionMissing)
+    ));
+    assert!(coordinator.contribution_file_reader(1, 8, 0).is_err());
+
+    Ok(())
+}
+
+/// Returns the verifier which is assigned the next verification task.
+fn assigned_verifier<'a>(coordinator: &Coordinator, verifiers: &'a [VerifierTestDetails]) -> &'a VerifierTestDetails {
+    let state = coordinator.state();
+    verifiers
+        .iter()
+        .find(|verifier| {
+            state
+                .current_participant_info(&verifier.participant)
+                .map(|info| !info.assigned_tasks().is_empty())
+                .unwrap_or(false)
+        })
+        .unwrap()
+}
+
+#[test]
+#[serial]
+fn coordinator_required_verifications_test() -> anyhow::Result<()> {
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+    let testing = Testing::from(parameters).required_verifications(2);
+    let environment = initialize_test_environment(&testing.into());
+
+    // Instantiate a coordinator, and advance to round 1.
+    let coordinator = Coordinator::new(environment, Box::new(Dummy))?;
+    coordinator.initialize()?;
+    let contributor = create_contributor_test_details("1");
+    let verifiers = vec![create_verifier_test_details("1"), create_verifier_test_details("2")];
+    coordinator.add_to_queue(contributor.participant.clone(), 10)?;
+    coordinator.add_to_queue(verifiers[0].participant.clone(), 10)?;
+    coordinator.add_to_queue(verifiers[1].participant.clone(), 10)?;
+    coordinator.update()?;
+    let (_, mut events) = coordinator.subscribe()?;
+
+    // Contribute, and verify the contribution by the first verifier.
+    contributor.contribute_to(&coordinator)?;
+    let first = assigned_verifier(&coordinator, &verifiers);
+    first.verify(&coordinator)?;
+
+    // Check that the contribution is not accepted yet, and is assigned to the other verifier.
+    let round = coordinator.current_round()?;
+    let (chunk_id, contribution_id) = round
+        .chunks()
+        .iter()
+        .find(|chunk| chunk.current_contribution_id() == 1)
+        .map(|chunk| (chunk.chunk_id(), 1))
+        .unwrap();
+    let contribution = round.chunk(chunk_id)?.get_contribution(contribution_id)?;
+    assert!(!contribution.is_verified());
+    assert!(contribution.is_verified_by(&first.participant));
+    let second = assigned_verifier(&coordinator, &verifiers);
+    assert_ne!(first.participant, second.participant);
+    assert!(matches!(
+        coordinator.try_lock(&first.participant),
+        Err(CoordinatorError::ParticipantHasNoRemainingTasks)
+    ));
+
+    // Verify the contribution by the second verifier, and check that it is accepted.
+    second.verify(&coordinator)?;
+    let chunk = coordinator.get_chunk(chunk_id)?;
+    let contribution = chunk.get_contribution(contribution_id)?;
+    assert!(contribution.is_verified());
+    let verified_by: Vec<_> = contribution
+        .get_verifications()
+        .iter()
+        .filter(|record| record.is_success())
+        .map(|record| record.verifier().clone())
+        .collect();
+    assert_eq!(vec![first.participant.clone(), second.participant.clone()], verified_by);
+
+    // Check that the contribution was only announced as verified once it was accepted.
+    let events: Vec<CoordinatorEvent> = std::iter::from_fn(|| events.try_recv().ok()).collect();
+    let verified_events: Vec<_> = events
+        .iter()
+        .filter(|event| matches!(event, CoordinatorEvent::ContributionVerified { .. }))
+        .collect();
+    assert_eq!(
+        vec![&CoordinatorEvent::ContributionVerified {
+            round_height: 1,
+            chunk_id,
+            contribution_id,
+            participant: second.participant.clone(),
+        }],
+        verified_events
+    );
+
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn coordinator_required_verifications_disagreement_test() -> anyhow::Result<()> {
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+    let retry_policy = ContributionRetryPolicy::new(1, chrono::Duration::minutes(10));
+    let testing = Testing::from(parameters)
+        .required_verifications(2)
+        .contribution_retry_policy(Some(retry_policy));
+    let environment = initialize_test_environment(&testing.into());
+
+    // Instantiate a coordinator, and advance to round 1.
+    let coordinator = Coordinator::new(environment, Box::new(Dummy))?;
+    coordinator.initialize()?;
+    let contributor = create_contributor_test_details("1");
+    let verifiers = vec![create_verifier_test_details("1"), create_verifier_test_details("2")];
+    coordinator.add_to_queue(contributor.participant.clone(), 10)?;
+    coordinator.add_to_queue(verifiers[0].participant.clone(), 10)?;
+    coordinator.add_to_queue(verifiers[1].participant.clone(), 10)?;
+    coordinator.update()?;
+    let (_, mut events) = coordinator.subscribe()?;
+
+    // Contribute, verify the contribution by the first verifier, and fail it by the second verifier.
+    contributor.contribute_to(&coordinator)?;
+    assigned_verifier(&coordinator, &verifiers).verify(&coordinator)?;
+    let (chunk_id, contribution_id) = fail_verification(&coordinator, assigned_verifier(&coordinator, &verifiers))?;
+
+    // Check that the disagreement rejects the contribution, and returns the task to the contributor.
+    let chunk = coordinator.get_chunk(chunk_id)?;
+    assert_eq!(contribution_id - 1, chunk.current_contribution_id());
+    assert!(!chunk.is_locked());
+    let events: Vec<CoordinatorEvent> = std::iter::from_fn(|| events.try_recv().ok()).collect();
+    assert!(events.contains(&CoordinatorEvent::ContributionFailed {
+        round_height: 1,
+        chunk_id,
+        contribution_id,
+        participant: contributor.participant.clone(),
+        attempt: 1,
+    }));
+    assert!(!events
+        .iter()
+        .any(|event| matches!(event, CoordinatorEvent::ContributionVerified { .. })));
+
+    // Re-upload the contribution, and check that both verifiers must verify it again.
+    contributor.contribute_to(&coordinator)?;
+    assigned_verifier(&coordinator, &verifiers).verify(&coordinator)?;
+    assert!(!coordinator
+        .get_chunk(chunk_id)?
+        .get_contribution(contribution_id)?
+        .is_verified());
+    assigned_verifier(&coordinator, &verifiers).verify(&coordinator)?;
+    let chunk = coordinator.get_chunk(chunk_id)?;
+    let contribution = chunk.get_contribution(contribution_id)?;
+    assert!(contribution.is_verified());
+    assert!(verifiers
+        .iter()
+        .all(|verifier| contribution.is_verified_by(&verifier.participant)));
+
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn coordinator_required_verifications_validation_test() -> anyhow::Result<()> {
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+
+    // Check that the coordinator rejects required verifications no contribution could reach.
+    for required_verifications in &[0, 6] {
+        let testing = Testing::from(parameters.clone()).required_verifications(*required_verifications);
+        let environment = initialize_test_environment(&testing.into());
+        assert_eq!(5, environment.maximum_verifiers_per_round());
+        assert!(matches!(
+            Coordinator::new(environment, Box::new(Dummy)),
+            Err(CoordinatorError::RequiredVerificationsInvalid { .. })
+        ));
+    }
+
+    // Instantiate a coordinator which requires two verifications, and advance to round 1 with one verifier.
+    let testing = Testing::from(parameters).required_verifications(2);
+    let environment = initialize_test_environment(&testing.into());
+    let coordinator = Coordinator::new(environment, Box::new(Dummy))?;
+    coordinator.initialize()?;
+    let contributor = create_contributor_test_details("1");
+    let verifier = create_verifier_test_details("1");
+    coordinator.add_to_queue(contributor.participant.clone(), 10)?;
+    coordinator.add_to_queue(verifier.participant.clone(), 10)?;
+    coordinator.update()?;
+
+    // Check that the verification fails, as there is no other verifier to assign it to.
+    contributor.contribute_to(&coordinator)?;
+    let error = verifier.verify(&coordinator).unwrap_err();
+    assert!(matches!(
+        error.downcast_ref::<CoordinatorError>(),
+        Some(CoordinatorError::VerifierMissing)
+    ));
+
+    // Check that the failed verification did not change the state, and fails again on a retry.
+    let round = coordinator.current_round()?;
+    let chunk = round.chunks().iter().find(|chunk| chunk.is_locked()).unwrap();
+    assert!(chunk.current_contribution()?.get_verifications().is_empty());
+    let (_, verifier_info) = coordinator.current_verifiers().into_iter().next().unwrap();
+    assert_eq!(1, verifier_info.pending_tasks().len());
+    assert!(verifier_info.completed_tasks().is_empty());
+    assert!(matches!(
+        coordinator.try_verify(&verifier.participant, chunk.chunk_id()),
+        Err(CoordinatorError::VerifierMissing)
+    ));
+
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn coordinator_ceremony_history_test() -> anyhow::Result<()> {
+    let time = Arc::new(MockTimeSource::new(Utc::now()));
+
+    let parameters = Parameters::Custom(Settings::new(
+        ContributionMode::Chunked,
+        ProvingSystem::Groth16,
+        CurveKind::Bls12_377,
+        6,  /* power */
+        16, /* batch_size */
+        16, /* chunk_size */
+    ));
+    let environment = initialize_test_environment(&Testing::from(parameters).history_rounds(Some(2)).into());
+    let number_of_chunks = environment.number_of_chunks();
+
+    // Instantiate a coordinator.
+    let coordinator = Coordinator::new_with_time(environment.clone(), Box::new(Dummy), time.clone())?;
+
+    // Initialize the ceremony to round 0.
+    coordinator.initialize()?;
+    assert_eq!(0, coordinator.history_aggregates().rounds_completed);
+
+    // Run three rounds, where round `n` takes `n` hours.
+    let contributor = create_contributor_test_details("1");
+    let verifier = create_verifier_test_details("1");
+    for round_height in 1..=3 {
+        coordinator.add_to_queue(contributor.participant.clone(), 10)?;
+        coordinator.add_to_queue(verifier.participant.clone(), 10)?;
+        coordinator.update()?;
+        assert_eq!(round_height, coordinator.current_round_height()?);
+
+        time.update(|prev| prev + chrono::Duration::hours(round_height as i64));
+        for _ in 0..number_of_chunks {
+            contributor.contribute_to(&coordinator)?;
+            verifier.verify(&coordinator)?;
+        }
+    }
+
+    // Aggregate round 3.
+    coordinator.add_to_queue(contributor.participant.clone(), 10)?;
+    coordinator.add_to_queue(verifier.participant.clone(), 10)?;
+    coordinator.update()?;
+    assert_eq!(4, coordinator.current_round_height()?);
+
+    let aggregates = coordinator.history_aggregates();
+    assert_eq!(3, aggregates.rounds_completed);
+    assert_eq!(Some(7200), aggregates.average_seconds_per_round);
+    assert_eq!(Some(number_of_chunks as f64 / 2.0), aggregates.contributions_per_hour);
+    assert!(aggregates.bytes_processed > 0);
+
+    // Check that only the last two rounds are kept individually.
+    let history = coordinator.ceremony_history();
+    let round_heights: Vec<u64> = history.rounds().map(RoundHistory::round_height).collect();
+    assert_eq!(vec![2, 3], round_heights);
+    for round in history.rounds() {
+        assert_eq!(1, round.number_of_contributors());
+        assert_eq!(1, round.number_of_verifiers());
+        assert_eq!(number_of_chunks, round.number_of_contributions());
+    }
+
+    // Check that the history survives a restart of the coordinator.
+    drop(coordinator);
+    let coordinator = Coordinator::new_with_time(environment, Box::new(Dummy), time.clone())?;
+    assert_eq!(history, coordinator.ceremony_history());
+    assert_eq!(aggregates, coordinator.history_aggregates());
+
+    Ok(())
+}
@@ -0,0 +1,174 @@
+use crate::{objects::CoordinatorEvent, Coordinator, CoordinatorError};
+
+use chrono::{DateTime, Utc};
+use reqwest::header::CONTENT_TYPE;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use thiserror::Error;
+use tokio::sync::broadcast::{error::RecvError, Receiver};
+use tracing::*;
+
+/// The transition of the ceremony to a new round, as posted to the webhook.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RoundTransition {
+    /// The height of the round the ceremony transitioned from.
+    pub old_round_height: u64,
+    /// The height of the round the ceremony transitioned to.
+    pub new_round_height: u64,
+    /// The hash of the aggregated round file of the old round, if it was aggregated.
+    pub round_file_hash: Option<String>,
+    /// The time the new round started.
+    pub timestamp: DateTime<Utc>,
+}
+
+impl RoundTransition {
+    ///
+    /// Loads the transition to the given round height from the coordinator.
+    ///
+    #[inline]
+    pub fn load(coordinator: &Coordinator, new_round_height: u64) -> Result<Self, CoordinatorError> {
+        let old_round_height = new_round_height
+            .checked_sub(1)
+            .ok_or(CoordinatorError::RoundHeightMismatch)?;
+        let old_round = coordinator.get_round(old_round_height)?;
+        let new_round = coordinator.get_round(new_round_height)?;
+
+        Ok(Self {
+            old_round_height,
+            new_round_height,
+            round_file_hash: old_round.round_file_hash().map(str::to_string),
+            timestamp: new_round.started_at().unwrap_or_else(Utc::now),
+        })
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum WebhookError {
+    #[error("Unable to load the round transition: {0}")]
+    Coordinator(CoordinatorError),
+    #[error("The task loading the round transition failed: {0}")]
+    Task(tokio::task::JoinError),
+    #[error("Unable to post the round transition: {0}")]
+    Request(#[from] reqwest::Error),
+}
+
+///
+/// Notifies an external system, such as a chat or paging service, of each
+/// round transition, by posting a `RoundTransition` as JSON to the webhook URL.
+///
+/// Failed requests are retried with an exponential backoff, and each request
+/// is abandoned after the timeout.
+///
+#[derive(Debug, Clone)]
+pub struct WebhookNotifier {
+    client: reqwest::Client,
+    url: String,
+    timeout: Duration,
+    attempts: u32,
+    retry_delay: Duration,
+}
+
+impl WebhookNotifier {
+    /// Creates a new instance of `WebhookNotifier`, posting to the given URL.
+    #[inline]
+    pub fn new(url: &str) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            url: url.to_string(),
+            timeout: Duration::from_secs(10),
+            attempts: 3,
+            retry_delay: Duration::from_secs(1),
+        }
+    }
+
+    /// Sets the duration after which a request to the webhook is abandoned.
+    #[inline]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Sets the number of times a notification is attempted, including the first attempt.
+    #[inline]
+    pub fn attempts(mut self, attempts: u32) -> Self {
+        self.attempts = attempts.max(1);
+        self
+    }
+
+    /// Sets the delay before the first retry, which doubles after each retry.
+    #[inline]
+    pub fn retry_delay(mut self, retry_delay: Duration) -> Self {
+        self.retry_delay = retry_delay;
+        self
+    }
+
+    ///
+    /// Posts the given round transition to the webhook, retrying failed attempts.
+    ///
+    /// On failure of the last attempt, returns its error.
+    ///
+    #[inline]
+    pub async fn notify(&self, transition: &RoundTransition) -> Result<(), WebhookError> {
+        let body = serde_json::to_vec(transition).expect("Unable to serialize the round transition");
+
+        let mut retry_delay = self.retry_delay;
+        for attempt in 1.. {
+            let result = self
+                .client
+                .post(&self.url)
+                .header(CONTENT_TYPE, "application/json")
+                .timeout(self.timeout)
+                .body(body.clone())
+                .send()
+                .await
+                .and_then(|response| response.error_for_status());
+
+            match result {
+                Ok(_) => break,
+                Err(error) if attempt < self.attempts => {
+                    warn!(
+                        "Webhook notification of round {} failed on attempt {}, retrying in {:?} ({})",
+                        transition.new_round_height, attempt, retry_delay, error
+                    );
+                    tokio::time::sleep(retry_delay).await;
+                    retry_delay *= 2;
+                }
+                Err(error) => return Err(error.into()),
+            }
+        }
+
+        debug!("Notified the webhook of round {}", transition.new_round_height);
+        Ok(())
+    }
+
+    ///
+    /// Notifies the webhook of every round transition published to the given
+    /// receiver of coordinator events, as the task of a webhook notifier.
+    ///
+    pub async fn run(self, coordinator: Coordinator, mut events: Receiver<CoordinatorEvent>) {
+        loop {
+            let round_height = match events.recv().await {
+                Ok(CoordinatorEvent::RoundAdvanced { round_height }) => round_height,
+                Ok(_) => continue,
+                Err(RecvError::Lagged(missed)) => {
+                    warn!("Webhook notifier fell behind, and missed {} coordinator events", missed);
+                    continue;
+                }
+                Err(RecvError::Closed) => return,
+            };
+
+            // Load the transition on the blocking thread pool, as it waits on the storage lock.
+            let loader = coordinator.clone();
+            let transition = tokio::task::spawn_blocking(move || RoundTransition::load(&loader, round_height)).await;
+
+            let result = match transition {
+                Ok(Ok(transition)) => self.notify(&transition).await,
+                Ok(Err(error)) => Err(WebhookError::Coordinator(error)),
+                Err(error) => Err(WebhookError::Task(error)),
+            };
+            if let Err(error) = result {
+                error!("Unable to notify the webhook of round {} ({})", round_height, error);
+            }
+        }
+    }
+}
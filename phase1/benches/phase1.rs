@@ -1,5 +1,6 @@
 use phase1::{
     helpers::testing::{generate_input, setup_verify},
+    ContributionMode,
     Phase1,
     Phase1Parameters,
     ProvingSystem,
@@ -92,6 +93,59 @@ fn benchmark_computation(c: &mut Criterion) {
     }
 }
 
+// Benchmark contributing to a large ceremony, where the element groups are
+// processed concurrently, for both full and chunked contributions
+fn benchmark_computation_power_18(c: &mut Criterion) {
+    let correctness = CheckForCorrectness::No;
+    let compressed_input = UseCompression::No;
+    let compressed_output = UseCompression::Yes;
+
+    let proving_system = &[ProvingSystem::Groth16, ProvingSystem::Marlin];
+    let contribution_mode = &[ContributionMode::Full, ContributionMode::Chunked];
+
+    let power = 18;
+    let batch = 1 << 16;
+    let chunk_size = 1 << 17;
+
+    let mut group = c.benchmark_group(format!("computation_power_{}", power));
+    group.sample_size(10);
+
+    for proof_system in proving_system {
+        for mode in contribution_mode {
+            let parameters = match mode {
+                ContributionMode::Full => Phase1Parameters::<Bls12_377>::new_full(*proof_system, power, batch),
+                ContributionMode::Chunked => {
+                    Phase1Parameters::<Bls12_377>::new_chunk(*mode, 0, chunk_size, *proof_system, power, batch)
+                }
+            };
+
+            let (input, _) = generate_input(&parameters, compressed_input, correctness);
+            let mut output = vec![0; parameters.get_length(compressed_output)];
+            let current_accumulator_hash = blank_hash();
+
+            // Generate the private key.
+            let mut rng = thread_rng();
+            let (_, private_key) = Phase1::key_generation(&mut rng, current_accumulator_hash.as_ref())
+                .expect("could not generate keypair");
+
+            group.bench_function(format!("{:?}_{:?}", proof_system, mode), |b| {
+                b.iter(|| {
+                    Phase1::computation(
+                        &input,
+                        &mut output,
+                        compressed_input,
+                        compressed_output,
+                        correctness,
+                        &private_key,
+                        &parameters,
+                    )
+                    .unwrap()
+                })
+            });
+        }
+    }
+}
+
 // Benchmark comparing contributing to the ceremony for various sizes and input/output
 // compressed situations. Parallel verification is consistently faster by 10-15% in all
 // modes of operation
@@ -147,10 +201,67 @@ fn benchmark_verification(c: &mut Criterion) {
     }
 }
 
+// Benchmark reading the phase 2 prefix of a transcript against reading the whole
+// transcript, from a single file and from the chunk files of a chunked transcript
+fn benchmark_deserialize_prefix(c: &mut Criterion) {
+    let correctness = CheckForCorrectness::Full;
+    let compression = UseCompression::Yes;
+
+    let batch = 256;
+    let mut group = c.benchmark_group("deserialize_prefix");
+    group.sample_size(10);
+
+    for power in 10..14 {
+        let parameters = Phase1Parameters::<Bls12_377>::new_full(ProvingSystem::Groth16, power, batch);
+        let (input, _) = generate_input(&parameters, compression, correctness);
+
+        // Read the powers used by a phase 2 circuit of a quarter of the size.
+        let phase2_size = 1 << (power - 2);
+        let (g1_length, other_length) = (2 * phase2_size - 1, phase2_size);
+
+        // Split the same transcript into chunks of the phase 2 size.
+        let chunk_size = phase2_size;
+        let chunks = (0..(parameters.powers_g1_length + chunk_size - 1) / chunk_size)
+            .map(|chunk_index| {
+                let chunk_parameters =
+                    parameters.into_chunk_parameters(ContributionMode::Chunked, chunk_index, chunk_size);
+                generate_input(&chunk_parameters, compression, correctness).0
+            })
+            .collect::<Vec<_>>();
+
+        group.throughput(Throughput::Elements(power as u64));
+        group.bench_with_input("full", &power, |b, _power| {
+            b.iter(|| Phase1::deserialize(&input, compression, correctness, &parameters).unwrap())
+        });
+        group.bench_with_input("prefix", &power, |b, _power| {
+            b.iter(|| {
+                Phase1::deserialize_prefix(&input, compression, correctness, g1_length, other_length, &parameters)
+                    .unwrap()
+            })
+        });
+        group.bench_with_input("chunked_prefix", &power, |b, _power| {
+            b.iter(|| {
+                Phase1::deserialize_chunked_prefix(
+                    &chunks,
+                    compression,
+                    correctness,
+                    g1_length,
+                    other_length,
+                    chunk_size,
+                    &parameters,
+                )
+                .unwrap()
+            })
+        });
+    }
+}
+
 criterion_group!(
     benches,
     benchmark_initialization,
     benchmark_computation,
-    benchmark_verification
+    benchmark_computation_power_18,
+    benchmark_verification,
+    benchmark_deserialize_prefix
 );
 criterion_main!(benches);
@@ -8,11 +8,14 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
     /// reads them as group elements, and attempts to write them to
     /// the output buffer.
     ///
+    /// Returns the BLAKE2b hash of the output buffer, computed while the buffer
+    /// is still in memory, so callers do not have to read the output again to hash it.
+    ///
     pub fn aggregation(
         inputs: &[(&[u8], UseCompression)],
         (output, compressed_output): (&mut [u8], UseCompression),
         parameters: &Phase1Parameters<E>,
-    ) -> Result<()> {
+    ) -> Result<GenericArray<u8, U64>> {
         let span = info_span!("phase1-aggregation");
         let _enter = span.enter();
 
@@ -26,9 +29,9 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
             let compressed_input = *compressed_input;
 
             let (in_tau_g1, in_tau_g2, in_alpha_g1, in_beta_g1, in_beta_g2) =
-                split(input, &chunk_parameters, compressed_input);
+                split(input, &chunk_parameters, compressed_input)?;
             let (tau_g1, tau_g2, alpha_g1, beta_g1, beta_g2) =
-                split_at_chunk_mut(output, &chunk_parameters, compressed_output);
+                split_at_chunk_mut(output, &chunk_parameters, compressed_output)?;
 
             let start = chunk_index * chunk_parameters.chunk_size;
             let end = (chunk_index + 1) * chunk_parameters.chunk_size;
@@ -174,9 +177,11 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
             debug!("chunk {} processing successful", chunk_index);
         }
 
+        let output_hash = calculate_hash(output);
+
         info!("phase1-aggregation complete");
 
-        Ok(())
+        Ok(output_hash)
     }
 }
 
@@ -369,7 +374,11 @@ mod tests {
                 powers,
                 batch,
             );
-            Phase1::aggregation(&full_contribution, (&mut output, compressed_output), &parameters).unwrap();
+            let output_hash =
+                Phase1::aggregation(&full_contribution, (&mut output, compressed_output), &parameters).unwrap();
+
+            // The returned hash is the hash of the aggregated output.
+            assert_eq!(calculate_hash(&output), output_hash);
 
             let parameters = Phase1Parameters::<E>::new_full(*proving_system, powers, batch);
             assert!(Phase1::aggregate_verification((&output, compressed_output, correctness), &parameters,).is_ok());
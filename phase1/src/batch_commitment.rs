@@ -0,0 +1,578 @@
+use super::*;
+
+use std::convert::TryInto;
+
+/// The size of the batch commitment trailer, which holds the number of batches
+/// as a little-endian `u64` followed by the 64-byte Merkle root.
+pub const BATCH_COMMITMENT_SIZE: usize = 8 + 64;
+
+/// A commitment to the batches of an accumulator, given by the Merkle root over
+/// the digests of each batch of serialized group elements.
+///
+/// The batches follow the order [TauG1, TauG2, AlphaG1, BetaG1, BetaG2], and each
+/// holds up to `batch_size` elements of a single element type, so that separate
+/// verifiers can check separate batches of the same contribution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatchCommitment {
+    pub number_of_batches: usize,
+    pub root: GenericArray<u8, U64>,
+}
+
+impl BatchCommitment {
+    /// Writes the commitment to the start of the given buffer.
+    pub fn write(&self, output: &mut [u8]) -> Result<()> {
+        if output.len() < BATCH_COMMITMENT_SIZE {
+            return Err(Error::InvalidLength {
+                expected: BATCH_COMMITMENT_SIZE,
+                got: output.len(),
+            });
+        }
+
+        output[0..8].copy_from_slice(&(self.number_of_batches as u64).to_le_bytes());
+        output[8..BATCH_COMMITMENT_SIZE].copy_from_slice(self.root.as_slice());
+        Ok(())
+    }
+
+    /// Reads the commitment from the start of the given buffer.
+    pub fn read(input: &[u8]) -> Result<Self> {
+        if input.len() < BATCH_COMMITMENT_SIZE {
+            return Err(Error::InvalidLength {
+                expected: BATCH_COMMITMENT_SIZE,
+                got: input.len(),
+            });
+        }
+
+        let number_of_batches = u64::from_le_bytes(input[0..8].try_into().expect("slice is 8 bytes long"));
+        Ok(Self {
+            number_of_batches: number_of_batches as usize,
+            root: GenericArray::clone_from_slice(&input[8..BATCH_COMMITMENT_SIZE]),
+        })
+    }
+}
+
+/// A Merkle path from the digest of a single batch to the root of a `BatchCommitment`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatchProof {
+    /// The index of the batch.
+    pub index: usize,
+    /// The sibling digests from the leaf up to the root. Levels at which the
+    /// node has no sibling are carried up unchanged, and have no entry.
+    pub path: Vec<GenericArray<u8, U64>>,
+}
+
+/// The Merkle tree over the batches of an accumulator, built once so that the
+/// proof of every batch can be extracted without hashing the accumulator again.
+#[derive(Debug, Clone)]
+pub struct BatchTree<'b> {
+    output: &'b [u8],
+    layout: Vec<(ElementType, usize, usize)>,
+    /// The levels of the tree, from the leaf digests up to the root.
+    levels: Vec<Vec<GenericArray<u8, U64>>>,
+}
+
+impl<'b> BatchTree<'b> {
+    /// Returns the commitment to the batches, given by the root of the tree.
+    pub fn commitment(&self) -> BatchCommitment {
+        let root = match self.levels.last() {
+            Some(level) if !level.is_empty() => level[0].clone(),
+            _ => blank_hash(),
+        };
+        BatchCommitment {
+            number_of_batches: self.layout.len(),
+            root,
+        }
+    }
+
+    /// Returns the bytes of the batch at the given index, and its proof against the batch commitment.
+    pub fn batch_with_proof(&self, index: usize) -> Result<(&'b [u8], BatchProof)> {
+        let (_, start, end) = *self.layout.get(index).ok_or(Error::PositionError(
+            ElementType::TauG1,
+            self.layout.len().saturating_sub(1),
+            index,
+        ))?;
+
+        let mut path = vec![];
+        let mut position = index;
+        for level in &self.levels[..self.levels.len().saturating_sub(1)] {
+            let sibling = position ^ 1;
+            if sibling < level.len() {
+                path.push(level[sibling].clone());
+            }
+            position /= 2;
+        }
+
+        Ok((&self.output[start..end], BatchProof { index, path }))
+    }
+}
+
+impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
+    ///
+    /// Phase 1 - Computation with a batch commitment
+    ///
+    /// Runs `Phase1::computation`, and then appends the batch commitment of the
+    /// output as a trailer after the public key. The output buffer must have
+    /// `BATCH_COMMITMENT_SIZE` bytes of room beyond the usual contribution.
+    ///
+    pub fn computation_with_batch_commitment(
+        input: &[u8],
+        output: &mut [u8],
+        compressed_input: UseCompression,
+        compressed_output: UseCompression,
+        check_input_for_correctness: CheckForCorrectness,
+        key: &PrivateKey<E>,
+        parameters: &'a Phase1Parameters<E>,
+    ) -> Result<BatchCommitment> {
+        Self::computation(
+            input,
+            output,
+            compressed_input,
+            compressed_output,
+            check_input_for_correctness,
+            key,
+            parameters,
+        )?;
+
+        let commitment = Self::batch_commitment(output, compressed_output, parameters)?;
+        let position = batch_commitment_position(parameters, compressed_output);
+        commitment.write(&mut output[position.min(output.len())..])?;
+        debug!("Wrote batch commitment over {} batches", commitment.number_of_batches);
+
+        Ok(commitment)
+    }
+
+    ///
+    /// Phase 1 - Verification with a batch commitment
+    ///
+    /// Runs `Phase1::verification`, and then checks that the batch commitment
+    /// trailer of the output matches its batches.
+    ///
+    #[cfg(not(feature = "wasm"))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn verification_with_batch_commitment(
+        input: &[u8],
+        output: &[u8],
+        key: &PublicKey<E>,
+        digest: &[u8],
+        compressed_input: UseCompression,
+        compressed_output: UseCompression,
+        check_input_for_correctness: CheckForCorrectness,
+        check_output_for_correctness: CheckForCorrectness,
+        parameters: &'a Phase1Parameters<E>,
+    ) -> Result<BatchCommitment> {
+        Self::verification(
+            input,
+            output,
+            key,
+            digest,
+            compressed_input,
+            compressed_output,
+            check_input_for_correctness,
+            check_output_for_correctness,
+            parameters,
+        )?;
+
+        Self::verify_batch_commitment(output, compressed_output, parameters)
+    }
+
+    /// Computes the batch commitment over the group elements of the given accumulator.
+    pub fn batch_commitment(
+        output: &[u8],
+        compressed_output: UseCompression,
+        parameters: &Phase1Parameters<E>,
+    ) -> Result<BatchCommitment> {
+        Ok(Self::batch_tree(output, compressed_output, parameters)?.commitment())
+    }
+
+    /// Reads the batch commitment trailer of the given accumulator, and checks it against the batches.
+    pub fn verify_batch_commitment(
+        output: &[u8],
+        compressed_output: UseCompression,
+        parameters: &Phase1Parameters<E>,
+    ) -> Result<BatchCommitment> {
+        let position = batch_commitment_position(parameters, compressed_output);
+        let trailer = BatchCommitment::read(output.get(position..).unwrap_or(&[]))?;
+
+        if trailer != Self::batch_commitment(output, compressed_output, parameters)? {
+            return Err(VerificationError::InvalidBatchCommitment.into());
+        }
+
+        Ok(trailer)
+    }
+
+    /// Builds the Merkle tree over the batches of the given accumulator, to extract batch proofs from.
+    pub fn batch_tree<'b>(
+        output: &'b [u8],
+        compressed_output: UseCompression,
+        parameters: &Phase1Parameters<E>,
+    ) -> Result<BatchTree<'b>> {
+        let layout = batch_layout(parameters, compressed_output);
+        let levels = merkle_levels(batch_leaves(output, &layout)?);
+
+        Ok(BatchTree { output, layout, levels })
+    }
+
+    ///
+    /// Verifies that a single batch of a contribution is committed to by the batch commitment.
+    ///
+    /// Checks that the Merkle path of the proof leads from the batch to the root
+    /// of the commitment, and that the batch holds valid group elements. This does
+    /// not check that the batch holds the correct powers, which is only checked by
+    /// `Phase1::verification` over the whole contribution.
+    ///
+    pub fn verify_batch_with_proof(
+        batch: &[u8],
+        proof: &BatchProof,
+        commitment: &BatchCommitment,
+        compressed_output: UseCompression,
+        check_output_for_correctness: CheckForCorrectness,
+        parameters: &Phase1Parameters<E>,
+    ) -> Result<()> {
+        let layout = batch_layout(parameters, compressed_output);
+        if layout.len() != commitment.number_of_batches {
+            return Err(VerificationError::InvalidBatchProof(proof.index).into());
+        }
+
+        // Check that the batch has the size expected at its index.
+        let (element_type, start, end) = *layout
+            .get(proof.index)
+            .ok_or(VerificationError::InvalidBatchProof(proof.index))?;
+        if batch.len() != end - start {
+            return Err(Error::InvalidLength {
+                expected: end - start,
+                got: batch.len(),
+            });
+        }
+
+        // Check that the batch is committed to.
+        let root = merkle_path_root(merkle_leaf_hash(batch), proof.index, layout.len(), &proof.path)
+            .ok_or(VerificationError::InvalidBatchProof(proof.index))?;
+        if root != commitment.root {
+            return Err(VerificationError::InvalidBatchProof(proof.index).into());
+        }
+
+        // Check that the batch holds valid group elements.
+        match element_type {
+            ElementType::TauG2 | ElementType::BetaG2 => {
+                batch.read_batch::<E::G2Affine>(compressed_output, check_output_for_correctness)?;
+            }
+            _ => {
+                batch.read_batch::<E::G1Affine>(compressed_output, check_output_for_correctness)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the position of the batch commitment trailer, after the accumulator and public key.
+fn batch_commitment_position<E: PairingEngine>(parameters: &Phase1Parameters<E>, compressed: UseCompression) -> usize {
+    match compressed {
+        UseCompression::Yes => parameters.contribution_size,
+        UseCompression::No => parameters.accumulator_size + parameters.public_key_size,
+    }
+}
+
+/// Returns the element type, start, and end of each batch in the accumulator,
+/// following the section sizes used by `split`.
+fn batch_layout<E: PairingEngine>(
+    parameters: &Phase1Parameters<E>,
+    compressed: UseCompression,
+) -> Vec<(ElementType, usize, usize)> {
+    let g1_size = buffer_size::<E::G1Affine>(compressed);
+    let g2_size = buffer_size::<E::G2Affine>(compressed);
+
+    let sections = match parameters.proving_system {
+        ProvingSystem::Groth16 => vec![
+            (ElementType::TauG1, g1_size, parameters.g1_chunk_size),
+            (ElementType::TauG2, g2_size, parameters.other_chunk_size),
+            (ElementType::AlphaG1, g1_size, parameters.other_chunk_size),
+            (ElementType::BetaG1, g1_size, parameters.other_chunk_size),
+            (ElementType::BetaG2, g2_size, 1),
+        ],
+        ProvingSystem::Marlin => {
+            let (g2_chunk_size, alpha_chunk_size) = if parameters.chunk_index == 0 {
+                (parameters.total_size_in_log2 + 2, 3 + 3 * parameters.total_size_in_log2)
+            } else {
+                (0, 0)
+            };
+            vec![
+                (ElementType::TauG1, g1_size, parameters.g1_chunk_size),
+                (ElementType::TauG2, g2_size, g2_chunk_size),
+                (ElementType::AlphaG1, g1_size, alpha_chunk_size),
+            ]
+        }
+    };
+
+    let mut layout = vec![];
+    let mut position = parameters.hash_size;
+    for (element_type, element_size, length) in sections {
+        let mut remaining = length;
+        while remaining > 0 {
+            let batch_length = std::cmp::min(remaining, parameters.batch_size);
+            layout.push((element_type, position, position + batch_length * element_size));
+            position += batch_length * element_size;
+            remaining -= batch_length;
+        }
+    }
+    layout
+}
+
+/// Returns the leaf digest of each batch in the given layout.
+fn batch_leaves(output: &[u8], layout: &[(ElementType, usize, usize)]) -> Result<Vec<GenericArray<u8, U64>>> {
+    layout
+        .iter()
+        .map(|(_, start, end)| match output.get(*start..*end) {
+            Some(batch) => Ok(merkle_leaf_hash(batch)),
+            None => Err(Error::InvalidLength {
+                expected: *end,
+                got: output.len(),
+            }),
+        })
+        .collect()
+}
+
+/// Returns the parent level of the given level of the Merkle tree.
+/// A trailing node without a sibling is carried up unchanged.
+fn merkle_parents(level: &[GenericArray<u8, U64>]) -> Vec<GenericArray<u8, U64>> {
+    level
+        .chunks(2)
+        .map(|pair| match pair {
+            [left, right] => merkle_node_hash(left, right),
+            [node] => node.clone(),
+            _ => unreachable!(),
+        })
+        .collect()
+}
+
+/// Returns the levels of the Merkle tree over the given leaves, from the leaves up to the root.
+fn merkle_levels(leaves: Vec<GenericArray<u8, U64>>) -> Vec<Vec<GenericArray<u8, U64>>> {
+    let mut levels = vec![leaves];
+    while levels[levels.len() - 1].len() > 1 {
+        let parents = merkle_parents(&levels[levels.len() - 1]);
+        levels.push(parents);
+    }
+    levels
+}
+
+/// Returns the Merkle root reached from the given leaf, index, and path,
+/// or `None` if the path does not fit a tree with the given number of leaves.
+fn merkle_path_root(
+    leaf: GenericArray<u8, U64>,
+    mut index: usize,
+    mut width: usize,
+    path: &[GenericArray<u8, U64>],
+) -> Option<GenericArray<u8, U64>> {
+    if index >= width {
+        return None;
+    }
+
+    let mut node = leaf;
+    let mut siblings = path.iter();
+    while width > 1 {
+        let sibling = index ^ 1;
+        if sibling < width {
+            let sibling = siblings.next()?;
+            node = if index % 2 == 0 {
+                merkle_node_hash(&node, sibling)
+            } else {
+                merkle_node_hash(sibling, &node)
+            };
+        }
+        index /= 2;
+        width = (width + 1) / 2;
+    }
+
+    // Check that the whole path was used.
+    match siblings.next() {
+        Some(_) => None,
+        None => Some(node),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::testing::generate_input;
+    use setup_utils::derive_rng_from_seed;
+
+    use zexe_algebra::{Bls12_377, BW6_761};
+
+    fn curve_batch_commitment_test<E: PairingEngine>(
+        powers: usize,
+        batch: usize,
+        compressed_input: UseCompression,
+        compressed_output: UseCompression,
+    ) {
+        for proving_system in &[ProvingSystem::Groth16, ProvingSystem::Marlin] {
+            let parameters = Phase1Parameters::<E>::new_full(*proving_system, powers, batch);
+            let (input, _) = generate_input(&parameters, compressed_input, CheckForCorrectness::No);
+
+            let current_accumulator_hash = blank_hash();
+            let mut rng = derive_rng_from_seed(b"curve_batch_commitment_test");
+            let (public_key, private_key) =
+                Phase1::key_generation(&mut rng, current_accumulator_hash.as_ref()).unwrap();
+
+            // Compute the contribution without a batch commitment.
+            let length = batch_commitment_position(&parameters, compressed_output);
+            let mut output = vec![0; length];
+            Phase1::computation(
+                &input,
+                &mut output,
+                compressed_input,
+                compressed_output,
+                CheckForCorrectness::Full,
+                &private_key,
+                &parameters,
+            )
+            .unwrap();
+            public_key.write(&mut output, compressed_output, &parameters).unwrap();
+
+            // Compute the contribution with a batch commitment.
+            let mut output_with_commitment = vec![0; length + BATCH_COMMITMENT_SIZE];
+            let commitment = Phase1::computation_with_batch_commitment(
+                &input,
+                &mut output_with_commitment,
+                compressed_input,
+                compressed_output,
+                CheckForCorrectness::Full,
+                &private_key,
+                &parameters,
+            )
+            .unwrap();
+            public_key
+                .write(&mut output_with_commitment, compressed_output, &parameters)
+                .unwrap();
+
+            // Check that the contribution is unaffected by the trailer.
+            assert_eq!(output[..], output_with_commitment[..length]);
+
+            // Check that the trailer is verified along with the contribution.
+            let verified = Phase1::verification_with_batch_commitment(
+                &input,
+                &output_with_commitment,
+                &public_key,
+                &current_accumulator_hash,
+                compressed_input,
+                compressed_output,
+                CheckForCorrectness::No,
+                CheckForCorrectness::Full,
+                &parameters,
+            )
+            .unwrap();
+            assert_eq!(commitment, verified);
+            assert!(commitment.number_of_batches > 1);
+
+            // Check that every batch verifies against the commitment.
+            let tree = Phase1::batch_tree(&output_with_commitment, compressed_output, &parameters).unwrap();
+            assert_eq!(commitment, tree.commitment());
+            for index in 0..commitment.number_of_batches {
+                let (batch, proof) = tree.batch_with_proof(index).unwrap();
+                Phase1::verify_batch_with_proof(
+                    batch,
+                    &proof,
+                    &commitment,
+                    compressed_output,
+                    CheckForCorrectness::Full,
+                    &parameters,
+                )
+                .unwrap();
+            }
+            assert!(tree.batch_with_proof(commitment.number_of_batches).is_err());
+
+            // Check that a tampered batch is rejected.
+            let index = commitment.number_of_batches / 2;
+            let (batch, proof) = tree.batch_with_proof(index).unwrap();
+            let mut tampered = batch.to_vec();
+            tampered[0] ^= 1;
+            assert!(Phase1::verify_batch_with_proof(
+                &tampered,
+                &proof,
+                &commitment,
+                compressed_output,
+                CheckForCorrectness::Full,
+                &parameters,
+            )
+            .is_err());
+
+            // Check that a batch is rejected at another index.
+            let wrong_index = BatchProof {
+                index: index + 1,
+                path: proof.path.clone(),
+            };
+            assert!(Phase1::verify_batch_with_proof(
+                batch,
+                &wrong_index,
+                &commitment,
+                compressed_output,
+                CheckForCorrectness::Full,
+                &parameters,
+            )
+            .is_err());
+
+            // Check that a tampered path is rejected.
+            let mut tampered_path = proof.clone();
+            tampered_path.path[0][0] ^= 1;
+            assert!(Phase1::verify_batch_with_proof(
+                batch,
+                &tampered_path,
+                &commitment,
+                compressed_output,
+                CheckForCorrectness::Full,
+                &parameters,
+            )
+            .is_err());
+
+            // Check that a tampered accumulator no longer matches its trailer.
+            let (_, start, _) = batch_layout(&parameters, compressed_output)[index];
+            output_with_commitment[start] ^= 1;
+            assert!(Phase1::verify_batch_commitment(&output_with_commitment, compressed_output, &parameters).is_err());
+        }
+    }
+
+    #[test]
+    fn test_batch_commitment_bls12_377() {
+        curve_batch_commitment_test::<Bls12_377>(4, 4, UseCompression::Yes, UseCompression::Yes);
+        curve_batch_commitment_test::<Bls12_377>(4, 4, UseCompression::No, UseCompression::No);
+        curve_batch_commitment_test::<Bls12_377>(4, 4, UseCompression::No, UseCompression::Yes);
+    }
+
+    #[test]
+    fn test_batch_commitment_bw6_761() {
+        curve_batch_commitment_test::<BW6_761>(3, 4, UseCompression::No, UseCompression::Yes);
+    }
+
+    #[test]
+    fn test_merkle_path_root() {
+        for width in 1..10 {
+            let leaves: Vec<_> = (0..width).map(|i: u8| merkle_leaf_hash(&[i])).collect();
+            let tree = BatchTree {
+                output: &[],
+                layout: vec![(ElementType::TauG1, 0, 0); width as usize],
+                levels: merkle_levels(leaves.clone()),
+            };
+            let root = tree.commitment().root;
+
+            for index in 0..width as usize {
+                let (_, proof) = tree.batch_with_proof(index).unwrap();
+                assert_eq!(
+                    Some(root.clone()),
+                    merkle_path_root(leaves[index].clone(), index, width as usize, &proof.path)
+                );
+
+                // A path with an extra sibling is rejected.
+                let mut long_path = proof.path.clone();
+                long_path.push(root.clone());
+                assert_eq!(
+                    None,
+                    merkle_path_root(leaves[index].clone(), index, width as usize, &long_path)
+                );
+            }
+
+            // An index outside of the tree is rejected.
+            assert_eq!(
+                None,
+                merkle_path_root(leaves[0].clone(), width as usize, width as usize, &[])
+            );
+        }
+    }
+}
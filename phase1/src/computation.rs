@@ -1,6 +1,28 @@
 use super::*;
 use zexe_algebra::{batch_inversion, Field};
 
+/// The running hash of the output of a computation, which absorbs the output
+/// in order, as its elements are written.
+#[derive(Default)]
+struct OutputHasher {
+    hasher: Blake2b,
+    /// The position in the output up to which it was absorbed.
+    position: usize,
+}
+
+impl OutputHasher {
+    /// Absorbs the output up to `end`, given the bytes of the output from `offset`.
+    /// The bytes before the position of the hasher were already absorbed, and are skipped.
+    fn absorb(&mut self, bytes: &[u8], offset: usize, end: usize) {
+        if end > self.position {
+            // The bytes must start at or before the position, so that no output is skipped.
+            debug_assert!(self.position >= offset);
+            self.hasher.update(&bytes[self.position - offset..end - offset]);
+            self.position = end;
+        }
+    }
+}
+
 impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
     ///
     /// Phase 1 - Computation: Steps 5, 6, and 7
@@ -10,6 +32,15 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
     /// Finally, each group element read from the input is multiplied by the corresponding power of tau depending
     /// on its index and maybe some extra coefficient, and is written to the output buffer.
     ///
+    /// The threat model is an attacker that measures the running time of the computation, for example
+    /// from another process on the same machine, to learn about the secret key. The group elements are
+    /// multiplied by the secrets with `mul_secret`, whose sequence of group operations does not depend on
+    /// the secrets, and the powers derived from the secrets are zeroized once they are applied. The field
+    /// arithmetic itself is not constant time, and neither are the powers of tau, which are computed with
+    /// public exponents from tau. Attackers with access to the memory or power draw of the machine are
+    /// out of scope. The cost of the ladder over the multiplication of Zexe, which skips the zero bits,
+    /// is measured by the `SecretExponentiation` benchmark of `setup-utils`.
+    ///
     pub fn computation(
         input: &[u8],
         output: &mut [u8],
@@ -18,19 +49,184 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
         check_input_for_correctness: CheckForCorrectness,
         key: &PrivateKey<E>,
         parameters: &'a Phase1Parameters<E>,
+    ) -> Result<()> {
+        Self::compute(
+            input,
+            output,
+            compressed_input,
+            compressed_output,
+            check_input_for_correctness,
+            key,
+            parameters,
+            None,
+            None,
+        )
+    }
+
+    ///
+    /// Phase 1 - Computation: Steps 5, 6, and 7
+    ///
+    /// Runs the computation of `Phase1::computation` until the given `cancellation` is cancelled.
+    ///
+    /// The flag is checked before each batch, so a cancelled computation stops at the next
+    /// batch boundary with `Error::Cancelled`, leaving the output partially written.
+    ///
+    #[allow(clippy::too_many_arguments)]
+    pub fn computation_with_cancellation(
+        input: &[u8],
+        output: &mut [u8],
+        compressed_input: UseCompression,
+        compressed_output: UseCompression,
+        check_input_for_correctness: CheckForCorrectness,
+        key: &PrivateKey<E>,
+        parameters: &'a Phase1Parameters<E>,
+        cancellation: &Cancellation,
+    ) -> Result<()> {
+        Self::compute(
+            input,
+            output,
+            compressed_input,
+            compressed_output,
+            check_input_for_correctness,
+            key,
+            parameters,
+            Some(cancellation),
+            None,
+        )
+    }
+
+    ///
+    /// Phase 1 - Computation: Steps 5, 6, and 7
+    ///
+    /// Runs the computation of `Phase1::computation`, writes the public key of the
+    /// contributor after the accumulator, and returns the hash of the output.
+    ///
+    /// The hash is computed as the output is written, so the output is not read a second
+    /// time by `calculate_hash`. The powers of tau in G1 are stored first, and each batch of
+    /// them is absorbed once it is computed. The remaining regions are computed concurrently
+    /// with them, and are absorbed at the end, together with beta in G2 and the public key.
+    ///
+    #[allow(clippy::too_many_arguments)]
+    pub fn computation_with_hash(
+        input: &[u8],
+        output: &mut [u8],
+        compressed_input: UseCompression,
+        compressed_output: UseCompression,
+        check_input_for_correctness: CheckForCorrectness,
+        key: &PrivateKey<E>,
+        public_key: &PublicKey<E>,
+        parameters: &'a Phase1Parameters<E>,
+    ) -> Result<GenericArray<u8, U64>> {
+        Self::compute_with_hash(
+            input,
+            output,
+            compressed_input,
+            compressed_output,
+            check_input_for_correctness,
+            key,
+            public_key,
+            parameters,
+            None,
+        )
+    }
+
+    ///
+    /// Phase 1 - Computation: Steps 5, 6, and 7
+    ///
+    /// Runs the computation of `Phase1::computation_with_hash` until the given `cancellation`
+    /// is cancelled, in which case it stops at the next batch boundary with `Error::Cancelled`.
+    ///
+    #[allow(clippy::too_many_arguments)]
+    pub fn computation_with_hash_and_cancellation(
+        input: &[u8],
+        output: &mut [u8],
+        compressed_input: UseCompression,
+        compressed_output: UseCompression,
+        check_input_for_correctness: CheckForCorrectness,
+        key: &PrivateKey<E>,
+        public_key: &PublicKey<E>,
+        parameters: &'a Phase1Parameters<E>,
+        cancellation: &Cancellation,
+    ) -> Result<GenericArray<u8, U64>> {
+        Self::compute_with_hash(
+            input,
+            output,
+            compressed_input,
+            compressed_output,
+            check_input_for_correctness,
+            key,
+            public_key,
+            parameters,
+            Some(cancellation),
+        )
+    }
+
+    /// Transforms the accumulator with the private key, writes the public key after it,
+    /// and returns the hash of the output.
+    #[allow(clippy::too_many_arguments)]
+    fn compute_with_hash(
+        input: &[u8],
+        output: &mut [u8],
+        compressed_input: UseCompression,
+        compressed_output: UseCompression,
+        check_input_for_correctness: CheckForCorrectness,
+        key: &PrivateKey<E>,
+        public_key: &PublicKey<E>,
+        parameters: &'a Phase1Parameters<E>,
+        cancellation: Option<&Cancellation>,
+    ) -> Result<GenericArray<u8, U64>> {
+        let mut hasher = OutputHasher::default();
+        Self::compute(
+            input,
+            output,
+            compressed_input,
+            compressed_output,
+            check_input_for_correctness,
+            key,
+            parameters,
+            cancellation,
+            Some(&mut hasher),
+        )?;
+
+        public_key.write(output, compressed_output, parameters)?;
+        hasher.absorb(output, 0, output.len());
+
+        Ok(hasher.hasher.finalize())
+    }
+
+    /// Transforms the accumulator with the private key, stopping at the
+    /// next batch boundary once `cancellation` is cancelled, and absorbing
+    /// the output written in order into `hasher`.
+    #[allow(clippy::too_many_arguments)]
+    fn compute(
+        input: &[u8],
+        output: &mut [u8],
+        compressed_input: UseCompression,
+        compressed_output: UseCompression,
+        check_input_for_correctness: CheckForCorrectness,
+        key: &PrivateKey<E>,
+        parameters: &'a Phase1Parameters<E>,
+        cancellation: Option<&Cancellation>,
+        mut hasher: Option<&mut OutputHasher>,
     ) -> Result<()> {
         let span = info_span!("phase1-computation");
         let _ = span.enter();
 
         info!("starting...");
 
+        // Absorb the hash of the previous contribution, which precedes the elements.
+        let layout = parameters.layout(compressed_output);
+        if let Some(hasher) = hasher.as_mut() {
+            hasher.absorb(output, 0, layout.tau_g1.start.min(output.len()));
+        }
+
         // Get immutable references of the input chunks.
         let (tau_g1_inputs, tau_g2_inputs, alpha_g1_inputs, beta_g1_inputs, mut beta_g2_inputs) =
-            split(&input, parameters, compressed_input);
+            split(&input, parameters, compressed_input)?;
 
         // Get mutable references of the outputs.
         let (tau_g1_outputs, tau_g2_outputs, alpha_g1_outputs, beta_g1_outputs, beta_g2_outputs) =
-            split_mut(output, parameters, compressed_output);
+            split_mut(output, parameters, compressed_output)?;
 
         match parameters.proving_system {
             ProvingSystem::Groth16 => {
@@ -40,14 +236,21 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
                     let mut beta_g2_el =
                         beta_g2_inputs.read_element::<E::G2Affine>(compressed_input, check_input_for_correctness)?;
                     // Multiply it by the key's beta element.
-                    beta_g2_el = beta_g2_el.mul(key.beta).into_affine();
+                    beta_g2_el = mul_secret(&beta_g2_el, &key.beta).into_affine();
                     // Write it back.
                     beta_g2_outputs.write_element(&beta_g2_el, compressed_output)?;
                 }
 
                 // load `batch_size` chunks on each iteration and perform the transformation
-                iter_chunk(&parameters, |start, end| {
-                    debug!("contributing to chunk from {} to {}", start, end);
+                iter_chunk(&parameters, cancellation, |batch| {
+                    let (start, end) = (batch.start, batch.end);
+                    debug!(
+                        "contributing to batch {} of {} from {} to {}",
+                        batch.index + 1,
+                        batch.total,
+                        start,
+                        end
+                    );
 
                     let span = info_span!("batch", start, end);
                     let _ = span.enter();
@@ -68,7 +271,7 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
                             let _ = span.enter();
 
                             // Generate powers from `start` to `end` (e.g. [0,4) then [4, 8) etc.)
-                            let powers = generate_powers_of_tau::<E>(&key.tau, start, end);
+                            let mut powers = generate_powers_of_tau::<E>(&key.tau, start, end);
 
                             trace!("generated powers of tau");
 
@@ -176,9 +379,17 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
                                     });
                                 }
                             });
+
+                            zeroize_secrets(&mut powers);
                         });
                     });
 
+                    // Absorb the powers of tau in G1 written by this batch.
+                    if let Some(hasher) = hasher.as_mut() {
+                        let end = layout.tau_g1.start + end_chunk * layout.tau_g1.element_size;
+                        hasher.absorb(tau_g1_outputs, layout.tau_g1.start, end);
+                    }
+
                     debug!("chunk contribution successful");
 
                     Ok(())
@@ -187,105 +398,139 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
             ProvingSystem::Marlin => {
                 // we assume batch_size > 3 + 3*total_size_in_log2, allowing all the smaller amounts
                 // of powers in tau G2 and alpha tau G1 to reside there
-                if parameters.chunk_index == 0 {
-                    let degree_bound_powers = (0..parameters.total_size_in_log2)
+                let mut degree_bound_powers = if parameters.chunk_index == 0 {
+                    (0..parameters.total_size_in_log2)
                         .map(|i| key.tau.pow([parameters.powers_length as u64 - 1 - (1 << i) + 2]))
-                        .collect::<Vec<_>>();
-
-                    let mut g2_inverse_powers = degree_bound_powers.clone();
-
-                    batch_inversion(&mut g2_inverse_powers);
-
-                    apply_powers::<E::G2Affine>(
-                        (tau_g2_outputs, compressed_output),
-                        (tau_g2_inputs, compressed_input, check_input_for_correctness),
-                        (2, parameters.total_size_in_log2 + 2),
-                        &g2_inverse_powers,
-                        None,
-                    )
-                    .expect("could not apply powers of tau to tau_g2 elements");
-
-                    let g1_degree_powers = degree_bound_powers
-                        .into_iter()
-                        .map(|f| vec![f, f * &key.tau, f * &key.tau.pow([2])])
-                        .flatten()
-                        .collect::<Vec<_>>();
-
-                    apply_powers::<E::G1Affine>(
-                        (alpha_g1_outputs, compressed_output),
-                        (alpha_g1_inputs, compressed_input, check_input_for_correctness),
-                        (3, 3 + 3 * parameters.total_size_in_log2),
-                        &g1_degree_powers,
-                        Some(&key.alpha),
-                    )
-                    .expect("could not apply powers of tau to tau_g2 elements");
-
-                    let num_alpha_powers = 3;
-                    let powers = generate_powers_of_tau::<E>(&key.tau, 0, num_alpha_powers);
-
-                    apply_powers::<E::G1Affine>(
-                        (alpha_g1_outputs, compressed_output),
-                        (alpha_g1_inputs, compressed_input, check_input_for_correctness),
-                        (0, num_alpha_powers),
-                        &powers,
-                        Some(&key.alpha),
-                    )
-                    .expect("could not apply powers of tau alpha to tau_g1 elements");
-
-                    let powers = generate_powers_of_tau::<E>(&key.tau, 0, 2);
-
-                    apply_powers::<E::G2Affine>(
-                        (tau_g2_outputs, compressed_output),
-                        (tau_g2_inputs, compressed_input, check_input_for_correctness),
-                        (0, 2),
-                        &powers,
-                        None,
-                    )
-                    .expect("could not apply powers of tau to initial tau_g2 elements");
-                }
+                        .collect::<Vec<_>>()
+                } else {
+                    vec![]
+                };
+
+                // The powers in tau G2 and alpha tau G1 are independent of the powers in tau G1,
+                // so they are processed concurrently with the batches of tau G1.
+                //
+                // Memory stays flat in the number of powers: `iter_chunk` materializes the powers
+                // of a single batch at a time, and the concurrent tasks only hold the
+                // `total_size_in_log2 + 2` powers in tau G2 and `3 + 3 * total_size_in_log2` powers
+                // in alpha tau G1, which fit in a single batch.
+                let mut result = Ok(());
+                rayon_cfg::scope(|s| {
+                    if parameters.chunk_index == 0 {
+                        s.spawn(|_| {
+                            let mut g2_inverse_powers = degree_bound_powers.clone();
+
+                            batch_inversion(&mut g2_inverse_powers);
+
+                            apply_powers::<E::G2Affine>(
+                                (tau_g2_outputs, compressed_output),
+                                (tau_g2_inputs, compressed_input, check_input_for_correctness),
+                                (2, parameters.total_size_in_log2 + 2),
+                                &g2_inverse_powers,
+                                None,
+                            )
+                            .expect("could not apply powers of tau to tau_g2 elements");
+                            zeroize_secrets(&mut g2_inverse_powers);
 
-                // load `batch_size` chunks on each iteration and perform the transformation
-                iter_chunk(&parameters, |start, end| {
-                    debug!("contributing to chunk from {} to {}", start, end);
+                            let mut powers = generate_powers_of_tau::<E>(&key.tau, 0, 2);
 
-                    let span = info_span!("batch", start, end);
-                    let _ = span.enter();
-
-                    // Determine the chunk start and end indices based on the contribution mode.
-                    let (start_chunk, end_chunk) = match parameters.contribution_mode {
-                        ContributionMode::Chunked => (
-                            start - parameters.chunk_index * parameters.chunk_size,
-                            end - parameters.chunk_index * parameters.chunk_size,
-                        ),
-                        ContributionMode::Full => (start, end),
-                    };
+                            apply_powers::<E::G2Affine>(
+                                (tau_g2_outputs, compressed_output),
+                                (tau_g2_inputs, compressed_input, check_input_for_correctness),
+                                (0, 2),
+                                &powers,
+                                None,
+                            )
+                            .expect("could not apply powers of tau to initial tau_g2 elements");
+                            zeroize_secrets(&mut powers);
 
-                    rayon_cfg::scope(|t| {
-                        let _ = span.enter();
+                            trace!("applied powers to tau_g2 elements");
+                        });
 
-                        t.spawn(|_| {
-                            let _ = span.enter();
+                        s.spawn(|_| {
+                            let mut g1_degree_powers = degree_bound_powers
+                                .iter()
+                                .map(|f| vec![*f, *f * &key.tau, *f * &key.tau.pow([2])])
+                                .flatten()
+                                .collect::<Vec<_>>();
 
-                            // Generate powers from `start` to `end` (e.g. [0,4) then [4, 8) etc.)
-                            let powers = generate_powers_of_tau::<E>(&key.tau, start, end);
+                            apply_powers::<E::G1Affine>(
+                                (alpha_g1_outputs, compressed_output),
+                                (alpha_g1_inputs, compressed_input, check_input_for_correctness),
+                                (3, 3 + 3 * parameters.total_size_in_log2),
+                                &g1_degree_powers,
+                                Some(&key.alpha),
+                            )
+                            .expect("could not apply powers of tau to alpha_g1 elements");
+                            zeroize_secrets(&mut g1_degree_powers);
 
-                            trace!("generated powers of tau");
+                            let num_alpha_powers = 3;
+                            let mut powers = generate_powers_of_tau::<E>(&key.tau, 0, num_alpha_powers);
 
                             apply_powers::<E::G1Affine>(
-                                (tau_g1_outputs, compressed_output),
-                                (tau_g1_inputs, compressed_input, check_input_for_correctness),
-                                (start_chunk, end_chunk),
+                                (alpha_g1_outputs, compressed_output),
+                                (alpha_g1_inputs, compressed_input, check_input_for_correctness),
+                                (0, num_alpha_powers),
                                 &powers,
-                                None,
+                                Some(&key.alpha),
                             )
-                            .expect("could not apply powers of tau to tau_g1 elements");
-                        });
-                    });
+                            .expect("could not apply powers of tau alpha to tau_g1 elements");
+                            zeroize_secrets(&mut powers);
 
-                    debug!("chunk contribution successful");
+                            trace!("applied powers to alpha_g1 elements");
+                        });
+                    }
+
+                    // load `batch_size` chunks on each iteration and perform the transformation
+                    result = iter_chunk(&parameters, cancellation, |batch| {
+                        let (start, end) = (batch.start, batch.end);
+                        debug!(
+                            "contributing to batch {} of {} from {} to {}",
+                            batch.index + 1,
+                            batch.total,
+                            start,
+                            end
+                        );
+
+                        let span = info_span!("batch", start, end);
+                        let _ = span.enter();
 
-                    Ok(())
-                })?;
+                        // Determine the chunk start and end indices based on the contribution mode.
+                        let (start_chunk, end_chunk) = match parameters.contribution_mode {
+                            ContributionMode::Chunked => (
+                                start - parameters.chunk_index * parameters.chunk_size,
+                                end - parameters.chunk_index * parameters.chunk_size,
+                            ),
+                            ContributionMode::Full => (start, end),
+                        };
+
+                        // Generate powers from `start` to `end` (e.g. [0,4) then [4, 8) etc.)
+                        let mut powers = generate_powers_of_tau::<E>(&key.tau, start, end);
+
+                        trace!("generated powers of tau");
+
+                        apply_powers::<E::G1Affine>(
+                            (tau_g1_outputs, compressed_output),
+                            (tau_g1_inputs, compressed_input, check_input_for_correctness),
+                            (start_chunk, end_chunk),
+                            &powers,
+                            None,
+                        )
+                        .expect("could not apply powers of tau to tau_g1 elements");
+                        zeroize_secrets(&mut powers);
+
+                        // Absorb the powers of tau in G1 written by this batch.
+                        if let Some(hasher) = hasher.as_mut() {
+                            let end = layout.tau_g1.start + end_chunk * layout.tau_g1.element_size;
+                            hasher.absorb(tau_g1_outputs, layout.tau_g1.start, end);
+                        }
+
+                        debug!("chunk contribution successful");
+
+                        Ok(())
+                    });
+                });
+                zeroize_secrets(&mut degree_bound_powers);
+                result?;
             }
         }
 
@@ -299,7 +544,7 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
 mod tests {
     use super::*;
     use crate::helpers::testing::generate_input;
-    use setup_utils::{batch_exp, derive_rng_from_seed, generate_powers_of_tau};
+    use setup_utils::{batch_exp, calculate_hash, derive_rng_from_seed, generate_powers_of_tau};
 
     use zexe_algebra::{Bls12_377, ProjectiveCurve, BW6_761};
 
@@ -413,6 +658,347 @@ mod tests {
         }
     }
 
+    /// Applies a contribution for Marlin with each group of elements processed in sequence,
+    /// as the computation did before the groups were processed concurrently.
+    fn marlin_computation_sequential<E: PairingEngine>(
+        input: &[u8],
+        output: &mut [u8],
+        compressed_input: UseCompression,
+        compressed_output: UseCompression,
+        key: &PrivateKey<E>,
+        parameters: &Phase1Parameters<E>,
+    ) {
+        let check_input_for_correctness = CheckForCorrectness::Full;
+        let (tau_g1_inputs, tau_g2_inputs, alpha_g1_inputs, _, _) =
+            split(&input, parameters, compressed_input).unwrap();
+        let (tau_g1_outputs, tau_g2_outputs, alpha_g1_outputs, _, _) =
+            split_mut(output, parameters, compressed_output).unwrap();
+
+        if parameters.chunk_index == 0 {
+            let degree_bound_powers = (0..parameters.total_size_in_log2)
+                .map(|i| key.tau.pow([parameters.powers_length as u64 - 1 - (1 << i) + 2]))
+                .collect::<Vec<_>>();
+
+            let mut g2_inverse_powers = degree_bound_powers.clone();
+            batch_inversion(&mut g2_inverse_powers);
+            apply_powers::<E::G2Affine>(
+                (tau_g2_outputs, compressed_output),
+                (tau_g2_inputs, compressed_input, check_input_for_correctness),
+                (2, parameters.total_size_in_log2 + 2),
+                &g2_inverse_powers,
+                None,
+            )
+            .unwrap();
+
+            let g1_degree_powers = degree_bound_powers
+                .into_iter()
+                .map(|f| vec![f, f * &key.tau, f * &key.tau.pow([2])])
+                .flatten()
+                .collect::<Vec<_>>();
+            apply_powers::<E::G1Affine>(
+                (alpha_g1_outputs, compressed_output),
+                (alpha_g1_inputs, compressed_input, check_input_for_correctness),
+                (3, 3 + 3 * parameters.total_size_in_log2),
+                &g1_degree_powers,
+                Some(&key.alpha),
+            )
+            .unwrap();
+
+            let powers = generate_powers_of_tau::<E>(&key.tau, 0, 3);
+            apply_powers::<E::G1Affine>(
+                (alpha_g1_outputs, compressed_output),
+                (alpha_g1_inputs, compressed_input, check_input_for_correctness),
+                (0, 3),
+                &powers,
+                Some(&key.alpha),
+            )
+            .unwrap();
+
+            let powers = generate_powers_of_tau::<E>(&key.tau, 0, 2);
+            apply_powers::<E::G2Affine>(
+                (tau_g2_outputs, compressed_output),
+                (tau_g2_inputs, compressed_input, check_input_for_correctness),
+                (0, 2),
+                &powers,
+                None,
+            )
+            .unwrap();
+        }
+
+        iter_chunk(&parameters, None, |batch| {
+            let (start, end) = (batch.start, batch.end);
+            let (start_chunk, end_chunk) = match parameters.contribution_mode {
+                ContributionMode::Chunked => (
+                    start - parameters.chunk_index * parameters.chunk_size,
+                    end - parameters.chunk_index * parameters.chunk_size,
+                ),
+                ContributionMode::Full => (start, end),
+            };
+            let powers = generate_powers_of_tau::<E>(&key.tau, start, end);
+            apply_powers::<E::G1Affine>(
+                (tau_g1_outputs, compressed_output),
+                (tau_g1_inputs, compressed_input, check_input_for_correctness),
+                (start_chunk, end_chunk),
+                &powers,
+                None,
+            )
+            .unwrap();
+            Ok(())
+        })
+        .unwrap();
+    }
+
+    fn curve_marlin_computation_matches_sequential_test<E: PairingEngine>(
+        parameters: &Phase1Parameters<E>,
+        compressed_input: UseCompression,
+        compressed_output: UseCompression,
+    ) {
+        let (input, _) = generate_input(parameters, compressed_input, CheckForCorrectness::No);
+
+        let current_accumulator_hash = blank_hash();
+        let mut rng = derive_rng_from_seed(b"curve_marlin_computation_matches_sequential_test");
+        let (_, privkey) =
+            Phase1::key_generation(&mut rng, current_accumulator_hash.as_ref()).expect("could not generate keypair");
+
+        let mut output = vec![0; parameters.get_length(compressed_output)];
+        Phase1::computation(
+            &input,
+            &mut output,
+            compressed_input,
+            compressed_output,
+            CheckForCorrectness::Full,
+            &privkey,
+            parameters,
+        )
+        .unwrap();
+
+        let mut expected = vec![0; parameters.get_length(compressed_output)];
+        marlin_computation_sequential(
+            &input,
+            &mut expected,
+            compressed_input,
+            compressed_output,
+            &privkey,
+            parameters,
+        );
+
+        assert_eq!(expected, output);
+    }
+
+    #[test]
+    fn test_marlin_computation_matches_sequential() {
+        for (compressed_input, compressed_output) in &[
+            (UseCompression::No, UseCompression::Yes),
+            (UseCompression::Yes, UseCompression::No),
+        ] {
+            curve_marlin_computation_matches_sequential_test(
+                &Phase1Parameters::<Bls12_377>::new_full(ProvingSystem::Marlin, 4, 16),
+                *compressed_input,
+                *compressed_output,
+            );
+
+            for chunk_index in 0..2 {
+                curve_marlin_computation_matches_sequential_test(
+                    &Phase1Parameters::<Bls12_377>::new_chunk(
+                        ContributionMode::Chunked,
+                        chunk_index,
+                        8,
+                        ProvingSystem::Marlin,
+                        4,
+                        16,
+                    ),
+                    *compressed_input,
+                    *compressed_output,
+                );
+            }
+        }
+    }
+
+    fn curve_computation_with_hash_test<E: PairingEngine>(
+        parameters: &Phase1Parameters<E>,
+        compressed_input: UseCompression,
+        compressed_output: UseCompression,
+    ) {
+        let (input, _) = generate_input(parameters, compressed_input, CheckForCorrectness::No);
+
+        let current_accumulator_hash = blank_hash();
+        let mut rng = derive_rng_from_seed(b"curve_computation_with_hash_test");
+        let (pubkey, privkey) =
+            Phase1::key_generation(&mut rng, current_accumulator_hash.as_ref()).expect("could not generate keypair");
+
+        // Write the hash of the challenge to the output, as contributors do.
+        let mut output = vec![0; parameters.get_length(compressed_output) + parameters.public_key_size];
+        output[parameters.layout(compressed_output).hash.range()].copy_from_slice(current_accumulator_hash.as_slice());
+        let mut expected = output.clone();
+
+        let digest = Phase1::computation_with_hash(
+            &input,
+            &mut output,
+            compressed_input,
+            compressed_output,
+            CheckForCorrectness::Full,
+            &privkey,
+            &pubkey,
+            parameters,
+        )
+        .unwrap();
+
+        Phase1::computation(
+            &input,
+            &mut expected,
+            compressed_input,
+            compressed_output,
+            CheckForCorrectness::Full,
+            &privkey,
+            parameters,
+        )
+        .unwrap();
+        pubkey.write(&mut expected, compressed_output, parameters).unwrap();
+
+        assert_eq!(expected, output);
+        assert_eq!(calculate_hash(&output), digest);
+
+        // The hash is the same when the computation may be cancelled.
+        let mut cancellable = expected.clone();
+        let cancellation = Cancellation::new();
+        let cancellable_digest = Phase1::computation_with_hash_and_cancellation(
+            &input,
+            &mut cancellable,
+            compressed_input,
+            compressed_output,
+            CheckForCorrectness::Full,
+            &privkey,
+            &pubkey,
+            parameters,
+            &cancellation,
+        )
+        .unwrap();
+        assert_eq!(expected, cancellable);
+        assert_eq!(digest, cancellable_digest);
+
+        // A cancelled computation stops without a hash.
+        cancellation.cancel();
+        let result = Phase1::computation_with_hash_and_cancellation(
+            &input,
+            &mut cancellable,
+            compressed_input,
+            compressed_output,
+            CheckForCorrectness::Full,
+            &privkey,
+            &pubkey,
+            parameters,
+            &cancellation,
+        );
+        assert!(matches!(result, Err(Error::Cancelled)));
+    }
+
+    #[test]
+    fn test_computation_with_hash() {
+        for proving_system in &[ProvingSystem::Groth16, ProvingSystem::Marlin] {
+            for (compressed_input, compressed_output) in &[
+                (UseCompression::No, UseCompression::No),
+                (UseCompression::No, UseCompression::Yes),
+            ] {
+                // The batches overlap by one element, which must be absorbed once.
+                curve_computation_with_hash_test(
+                    &Phase1Parameters::<Bls12_377>::new_full(*proving_system, 4, 16),
+                    *compressed_input,
+                    *compressed_output,
+                );
+
+                for chunk_index in 0..2 {
+                    curve_computation_with_hash_test(
+                        &Phase1Parameters::<Bls12_377>::new_chunk(
+                            ContributionMode::Chunked,
+                            chunk_index,
+                            8,
+                            *proving_system,
+                            4,
+                            16,
+                        ),
+                        *compressed_input,
+                        *compressed_output,
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_groth16_computation_with_hash_matches_computation() {
+        // Several batches per chunk, and chunks past the powers in G2, alpha in G1 and beta in G1.
+        for chunk_index in 0..4 {
+            curve_computation_with_hash_test(
+                &Phase1Parameters::<Bls12_377>::new_chunk(
+                    ContributionMode::Chunked,
+                    chunk_index,
+                    8,
+                    ProvingSystem::Groth16,
+                    4,
+                    4,
+                ),
+                UseCompression::No,
+                UseCompression::Yes,
+            );
+        }
+    }
+
+    #[cfg(feature = "parallel")]
+    fn curve_computation_deterministic_test<E: PairingEngine>(parameters: &Phase1Parameters<E>) {
+        let compressed_input = UseCompression::No;
+        let compressed_output = UseCompression::Yes;
+
+        let (input, _) = generate_input(parameters, compressed_input, CheckForCorrectness::No);
+
+        let current_accumulator_hash = blank_hash();
+        let mut rng = derive_rng_from_seed(b"curve_computation_deterministic_test");
+        let (_, privkey) =
+            Phase1::key_generation(&mut rng, current_accumulator_hash.as_ref()).expect("could not generate keypair");
+
+        let compute = || {
+            let mut output = vec![0; parameters.get_length(compressed_output)];
+            Phase1::computation(
+                &input,
+                &mut output,
+                compressed_input,
+                compressed_output,
+                CheckForCorrectness::Full,
+                &privkey,
+                parameters,
+            )
+            .unwrap();
+            output
+        };
+
+        // The groups are processed concurrently, so the output must not depend on the thread count.
+        let sequential = rayon::ThreadPoolBuilder::new()
+            .num_threads(1)
+            .build()
+            .unwrap()
+            .install(compute);
+        let parallel = compute();
+        assert_eq!(sequential, parallel);
+    }
+
+    #[test]
+    #[cfg(feature = "parallel")]
+    fn test_computation_is_deterministic() {
+        for proving_system in &[ProvingSystem::Groth16, ProvingSystem::Marlin] {
+            curve_computation_deterministic_test(&Phase1Parameters::<Bls12_377>::new_full(*proving_system, 6, 16));
+
+            for chunk_index in 0..2 {
+                curve_computation_deterministic_test(&Phase1Parameters::<Bls12_377>::new_chunk(
+                    ContributionMode::Chunked,
+                    chunk_index,
+                    32,
+                    *proving_system,
+                    6,
+                    16,
+                ));
+            }
+        }
+    }
+
     #[test]
     fn test_computation_bls12_377_compressed() {
         // Receives a compressed/uncompressed input, contributes to it, and produces a compressed/uncompressed output
@@ -20,6 +20,13 @@ type AccumulatorElements<E: PairingEngine> = (
     E::G2Affine,
 );
 
+#[allow(type_alias_bounds)]
+type BoundaryElementsTuple<E: PairingEngine> = (Vec<E::G1Affine>, Vec<E::G2Affine>, Vec<E::G1Affine>);
+
+/// The number of leading elements of TauG1, TauG2 and AlphaG1 which are read
+/// to check the initial conditions of an accumulator or chunk.
+pub const BOUNDARY_ELEMENTS_LENGTH: usize = 2;
+
 #[allow(type_alias_bounds)]
 #[allow(unused)]
 type AccumulatorElementsRef<'a, E: PairingEngine> = (
@@ -41,11 +48,14 @@ cfg_if! {
 
         /// Given a public key and the accumulator's digest, it hashes each G1 element
         /// along with the digest, and then hashes it to G2.
-        pub(crate) fn compute_g2_s_key<E: PairingEngine>(key: &PublicKey<E>, digest: &[u8]) -> Result<[E::G2Affine; 3]> {
+        pub(crate) fn compute_g2_s_key<E: PairingEngine, H: TranscriptHasher>(
+            key: &PublicKey<E>,
+            digest: &[u8],
+        ) -> Result<[E::G2Affine; 3]> {
             Ok([
-                compute_g2_s::<E>(&digest, &key.tau_g1.0, &key.tau_g1.1, 0)?,
-                compute_g2_s::<E>(&digest, &key.alpha_g1.0, &key.alpha_g1.1, 1)?,
-                compute_g2_s::<E>(&digest, &key.beta_g1.0, &key.beta_g1.1, 2)?,
+                compute_g2_s_with_hasher::<E, H>(&digest, &key.tau_g1.0, &key.tau_g1.1, 0)?,
+                compute_g2_s_with_hasher::<E, H>(&digest, &key.alpha_g1.0, &key.alpha_g1.1, 1)?,
+                compute_g2_s_with_hasher::<E, H>(&digest, &key.beta_g1.0, &key.beta_g1.1, 2)?,
             ])
         }
 
@@ -89,50 +99,48 @@ cfg_if! {
 
         /// Reads a list of group elements from the buffer to the provided `elements` slice
         /// and then checks that the elements are nonzero and in the prime order subgroup.
+        /// On failure, the returned error names the given `element_type`.
         pub(crate) fn check_elements_are_nonzero_and_in_prime_order_subgroup<C: AffineCurve>(
             (buffer, compression): (&[u8], UseCompression),
             (start, end): (usize, usize),
             elements: &mut [C],
+            element_type: ElementType,
         ) -> Result<()> {
             let size = buffer_size::<C>(compression);
-            buffer[start * size..end * size].read_batch_preallocated(
-                &mut elements[0..end - start],
-                compression,
-                CheckForCorrectness::Full,
-            )?;
+            buffer[start * size..end * size]
+                .read_batch_preallocated(&mut elements[0..end - start], compression, CheckForCorrectness::Full)
+                .map_err(|error| Error::InvalidElements(element_type, Box::new(error)))?;
             // TODO(kobi): replace with batch subgroup check
             let all_in_prime_order_subgroup = cfg_iter!(elements).all(|p| {
                 p.mul(<<C::ScalarField as PrimeField>::Params as FpParameters>::MODULUS)
                     .is_zero()
             });
             if !all_in_prime_order_subgroup {
-                return Err(Error::IncorrectSubgroup);
+                return Err(Error::IncorrectSubgroup(element_type));
             }
             Ok(())
         }
 
-        /// Reads a chunk of 2 elements from the buffer
-        pub(crate) fn read_initial_elements<C: AffineCurve>(
-            buffer: &[u8],
-            compressed: UseCompression,
-            check_input_for_correctness: CheckForCorrectness,
-        ) -> Result<Vec<C>> {
-            read_initial_elements_with_amount(buffer, 2, compressed, check_input_for_correctness)
-        }
-
-        /// Reads a chunk of {amount} elements from the buffer
-        pub(crate) fn read_initial_elements_with_amount<C: AffineCurve>(
+        /// Reads the first `n` elements from the buffer.
+        /// If the buffer holds fewer than `n` elements, returns `Error::InsufficientElements`.
+        pub(crate) fn read_initial_elements_n<C: AffineCurve>(
             buffer: &[u8],
-            amount: usize,
             compressed: UseCompression,
             check_input_for_correctness: CheckForCorrectness,
+            n: usize,
         ) -> Result<Vec<C>> {
-            let batch = amount;
             let size = buffer_size::<C>(compressed);
-            let result = buffer[0..batch * size].read_batch(compressed, check_input_for_correctness)?;
-            if result.len() != batch {
+            let available = buffer.len() / size;
+            if available < n {
+                return Err(Error::InsufficientElements {
+                    expected: n,
+                    got: available,
+                });
+            }
+            let result = buffer[0..n * size].read_batch(compressed, check_input_for_correctness)?;
+            if result.len() != n {
                 return Err(Error::InvalidLength {
-                    expected: batch,
+                    expected: n,
                     got: result.len(),
                 });
             }
@@ -170,9 +178,9 @@ cfg_if! {
             match parameters.proving_system {
                 ProvingSystem::Groth16 => {
                     // Get an immutable reference to the compressed input chunks
-                    let (in_tau_g1, in_tau_g2, in_alpha_g1, in_beta_g1, mut in_beta_g2) = split(&input, parameters, compressed_input);
+                    let (in_tau_g1, in_tau_g2, in_alpha_g1, in_beta_g1, mut in_beta_g2) = split(&input, parameters, compressed_input)?;
                     // Get mutable refs to the decompressed outputs
-                    let (tau_g1, tau_g2, alpha_g1, beta_g1, beta_g2) = split_mut(output, parameters, compressed_output);
+                    let (tau_g1, tau_g2, alpha_g1, beta_g1, beta_g2) = split_mut(output, parameters, compressed_output)?;
 
                     // Decompress beta_g2
                     {
@@ -229,9 +237,9 @@ cfg_if! {
                 }
                 ProvingSystem::Marlin => {
                     // Get an immutable reference to the compressed input chunks
-                    let (in_tau_g1, in_tau_g2, in_alpha_g1, _, _) = split(&input, parameters, compressed_input);
+                    let (in_tau_g1, in_tau_g2, in_alpha_g1, _, _) = split(&input, parameters, compressed_input)?;
                     // Get mutable refs to the decompressed outputs
-                    let (tau_g1, tau_g2, alpha_g1, _, _) = split_mut(output, parameters, compressed_output);
+                    let (tau_g1, tau_g2, alpha_g1, _, _) = split_mut(output, parameters, compressed_output)?;
 
                     if parameters.chunk_index == 0 || parameters.contribution_mode == ContributionMode::Full {
                         // Load `batch_size` chunks on each iteration and decompress them
@@ -272,7 +280,7 @@ pub fn serialize<E: PairingEngine>(
     parameters: &Phase1Parameters<E>,
 ) -> Result<()> {
     let (in_tau_g1, in_tau_g2, in_alpha_g1, in_beta_g1, in_beta_g2) = elements;
-    let (tau_g1, tau_g2, alpha_g1, beta_g1, beta_g2) = split_mut(output, parameters, compressed);
+    let (tau_g1, tau_g2, alpha_g1, beta_g1, beta_g2) = split_mut(output, parameters, compressed)?;
 
     tau_g1.write_batch(&in_tau_g1, compressed)?;
     tau_g2.write_batch(&in_tau_g2, compressed)?;
@@ -295,7 +303,41 @@ pub fn deserialize<E: PairingEngine>(
     parameters: &Phase1Parameters<E>,
 ) -> Result<AccumulatorElements<E>> {
     // Get an immutable reference to the input chunks
-    let (in_tau_g1, in_tau_g2, in_alpha_g1, in_beta_g1, in_beta_g2) = split(&input, parameters, compressed);
+    let (in_tau_g1, in_tau_g2, in_alpha_g1, in_beta_g1, in_beta_g2) = split(&input, parameters, compressed)?;
+
+    // Deserialize each part of the buffer separately
+    let tau_g1 = in_tau_g1.read_batch(compressed, check_input_for_correctness)?;
+    let tau_g2 = in_tau_g2.read_batch(compressed, check_input_for_correctness)?;
+    let alpha_g1 = in_alpha_g1.read_batch(compressed, check_input_for_correctness)?;
+    let beta_g1 = in_beta_g1.read_batch(compressed, check_input_for_correctness)?;
+    let beta_g2 = match parameters.proving_system {
+        ProvingSystem::Groth16 => (&*in_beta_g2).read_element(compressed, check_input_for_correctness)?,
+        ProvingSystem::Marlin => E::G2Affine::prime_subgroup_generator(),
+    };
+
+    Ok((tau_g1, tau_g2, alpha_g1, beta_g1, beta_g2))
+}
+
+/// Deserializes at most the first `g1_length` elements of TauG1 and the first
+/// `other_length` elements of TauG2, AlphaG1 and BetaG1. The rest of the input
+/// is never read, so only the requested prefix is held in memory.
+pub fn deserialize_prefix<E: PairingEngine>(
+    input: &[u8],
+    compressed: UseCompression,
+    check_input_for_correctness: CheckForCorrectness,
+    g1_length: usize,
+    other_length: usize,
+    parameters: &Phase1Parameters<E>,
+) -> Result<AccumulatorElements<E>> {
+    let g1_size = buffer_size::<E::G1Affine>(compressed);
+    let g2_size = buffer_size::<E::G2Affine>(compressed);
+
+    // Get an immutable reference to the prefix of each input chunk
+    let (in_tau_g1, in_tau_g2, in_alpha_g1, in_beta_g1, in_beta_g2) = split(&input, parameters, compressed)?;
+    let in_tau_g1 = prefix(in_tau_g1, g1_length, g1_size);
+    let in_tau_g2 = prefix(in_tau_g2, other_length, g2_size);
+    let in_alpha_g1 = prefix(in_alpha_g1, other_length, g1_size);
+    let in_beta_g1 = prefix(in_beta_g1, other_length, g1_size);
 
     // Deserialize each part of the buffer separately
     let tau_g1 = in_tau_g1.read_batch(compressed, check_input_for_correctness)?;
@@ -310,6 +352,37 @@ pub fn deserialize<E: PairingEngine>(
     Ok((tau_g1, tau_g2, alpha_g1, beta_g1, beta_g2))
 }
 
+/// Deserializes the first `BOUNDARY_ELEMENTS_LENGTH` elements of TauG1, TauG2 and AlphaG1,
+/// which `read_initial_elements_n` reads to check the initial conditions. A chunk which
+/// holds fewer elements of a vector returns all of them, and the rest of the input is never read.
+pub fn read_boundary_elements<E: PairingEngine>(
+    input: &[u8],
+    compressed: UseCompression,
+    check_input_for_correctness: CheckForCorrectness,
+    parameters: &Phase1Parameters<E>,
+) -> Result<BoundaryElementsTuple<E>> {
+    let g1_size = buffer_size::<E::G1Affine>(compressed);
+    let g2_size = buffer_size::<E::G2Affine>(compressed);
+
+    // Get an immutable reference to the boundary of each input chunk
+    let (in_tau_g1, in_tau_g2, in_alpha_g1, _, _) = split(&input, parameters, compressed)?;
+    let in_tau_g1 = prefix(in_tau_g1, BOUNDARY_ELEMENTS_LENGTH, g1_size);
+    let in_tau_g2 = prefix(in_tau_g2, BOUNDARY_ELEMENTS_LENGTH, g2_size);
+    let in_alpha_g1 = prefix(in_alpha_g1, BOUNDARY_ELEMENTS_LENGTH, g1_size);
+
+    // Deserialize each part of the buffer separately
+    let tau_g1 = in_tau_g1.read_batch(compressed, check_input_for_correctness)?;
+    let tau_g2 = in_tau_g2.read_batch(compressed, check_input_for_correctness)?;
+    let alpha_g1 = in_alpha_g1.read_batch(compressed, check_input_for_correctness)?;
+
+    Ok((tau_g1, tau_g2, alpha_g1))
+}
+
+/// Returns the first `length` elements of the given size in the buffer, or the whole buffer if it is shorter.
+fn prefix(buffer: &[u8], length: usize, element_size: usize) -> &[u8] {
+    &buffer[0..std::cmp::min(buffer.len(), length * element_size)]
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -346,4 +419,33 @@ mod tests {
         decompress_buffer_curve_test::<<Bls12_377 as PairingEngine>::G1Affine>();
         decompress_buffer_curve_test::<<Bls12_377 as PairingEngine>::G2Affine>();
     }
+
+    fn read_initial_elements_n_curve_test<C: AffineCurve>(compressed: UseCompression) {
+        // Write a few random points.
+        let mut rng = thread_rng();
+        let num_els = 3;
+        let elements: Vec<C> = random_point_vec(num_els, &mut rng);
+        let mut buffer = vec![0; num_els * buffer_size::<C>(compressed)];
+        buffer.write_batch(&elements, compressed).unwrap();
+
+        // Any number of elements up to the length of the buffer may be read.
+        for n in 0..=num_els {
+            let read = read_initial_elements_n::<C>(&buffer, compressed, CheckForCorrectness::Full, n).unwrap();
+            assert_eq!(read, elements[..n].to_vec());
+        }
+
+        // Reading past the end of the buffer is an error, and does not panic.
+        match read_initial_elements_n::<C>(&buffer, compressed, CheckForCorrectness::Full, num_els + 1) {
+            Err(Error::InsufficientElements { expected: 4, got: 3 }) => {}
+            result => panic!("expected an InsufficientElements error, found {:?}", result.map(|_| ())),
+        }
+    }
+
+    #[test]
+    fn test_read_initial_elements_n() {
+        for compressed in &[UseCompression::Yes, UseCompression::No] {
+            read_initial_elements_n_curve_test::<<Bls12_377 as PairingEngine>::G1Affine>(*compressed);
+            read_initial_elements_n_curve_test::<<Bls12_377 as PairingEngine>::G2Affine>(*compressed);
+        }
+    }
 }
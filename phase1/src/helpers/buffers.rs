@@ -1,9 +1,13 @@
-use crate::{ContributionMode, Phase1Parameters, ProvingSystem};
+use crate::{AccumulatorLayout, ContributionMode, Phase1Parameters, ProvingSystem};
 use setup_utils::{BatchDeserializer, BatchSerializer, *};
 
 use zexe_algebra::{AffineCurve, PairingEngine};
 
 use itertools::{Itertools, MinMaxResult};
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
 
 /// Buffer, compression
 type Input<'a> = (&'a [u8], UseCompression, CheckForCorrectness);
@@ -17,11 +21,50 @@ type SplitBufMut<'a> = (&'a mut [u8], &'a mut [u8], &'a mut [u8], &'a mut [u8],
 /// Immutable slices with format [TauG1, TauG2, AlphaG1, BetaG1, BetaG2]
 type SplitBuf<'a> = (&'a [u8], &'a [u8], &'a [u8], &'a [u8], &'a [u8]);
 
+/// The position of a batch in the iteration over the accumulator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BatchContext {
+    /// The index of the batch, starting at 0.
+    pub index: usize,
+    /// The number of batches in the iteration.
+    pub total: usize,
+    /// The index of the first element of the batch.
+    pub start: usize,
+    /// The index after the last element of the batch.
+    pub end: usize,
+}
+
+/// A flag to cancel an operation over the accumulator, which is shared between
+/// its clones. The operation stops with `Error::Cancelled` at the next batch boundary.
+#[derive(Debug, Clone, Default)]
+pub struct Cancellation(Arc<AtomicBool>);
+
+impl Cancellation {
+    /// Creates a new flag, which is not cancelled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancels the operations which check this flag.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+
+    /// Returns `true` if the flag was cancelled. Otherwise, returns `false`.
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
 /// Helper function to iterate over the accumulator in chunks.
 /// `action` will perform an action on the chunk
+///
+/// If a `cancellation` is given, it is checked before each batch,
+/// and the iteration stops with `Error::Cancelled` once it is cancelled.
 pub(crate) fn iter_chunk(
     parameters: &Phase1Parameters<impl PairingEngine>,
-    mut action: impl FnMut(usize, usize) -> Result<()>,
+    cancellation: Option<&Cancellation>,
+    mut action: impl FnMut(BatchContext) -> Result<()>,
 ) -> Result<()> {
     // Determine the range to iterate over.
     let (min, max) = {
@@ -44,17 +87,30 @@ pub(crate) fn iter_chunk(
         (min, max)
     };
 
+    // Determine the number of batches, which overlap by one element.
+    let batch_size = parameters.batch_size - 1;
+    let total = (max.saturating_sub(min) + batch_size - 1) / batch_size;
+
     // Iterate over the range, processing each element with the given input.
     (min..max)
-        .chunks(parameters.batch_size - 1)
+        .chunks(batch_size)
         .into_iter()
-        .map(|chunk| {
+        .enumerate()
+        .map(|(index, chunk)| {
+            if cancellation.map_or(false, Cancellation::is_cancelled) {
+                return Err(Error::Cancelled);
+            }
             let (start, end) = match chunk.minmax() {
                 MinMaxResult::MinMax(start, end) => (start, if end >= max - 1 { end + 1 } else { end + 2 }), // ensure there's overlap between chunks
                 MinMaxResult::OneElement(start) => (start, if start >= max - 1 { start + 1 } else { start + 2 }),
                 _ => return Err(Error::InvalidChunk),
             };
-            action(start, end)
+            action(BatchContext {
+                index,
+                total,
+                start,
+                end,
+            })
         })
         .collect::<Result<_>>()
 }
@@ -74,8 +130,8 @@ pub(crate) fn apply_powers<C: AffineCurve>(
     // Read the input
     let mut elements =
         &mut input[start * in_size..end * in_size].read_batch::<C>(input_compressed, check_input_for_correctness)?;
-    // calculate the powers
-    batch_exp(&mut elements, &powers[..end - start], coeff)?;
+    // calculate the powers, without leaking the secret powers through timing
+    batch_exp_secret(&mut elements, &powers[..end - start], coeff)?;
     // write back
     output[start * out_size..end * out_size].write_batch(&elements, output_compressed)?;
 
@@ -86,36 +142,45 @@ pub(crate) fn apply_powers<C: AffineCurve>(
 /// Splits the full buffer in 5 non overlapping mutable slice for a given chunk and batch size.
 /// Each slice corresponds to the group elements in the following order
 /// [TauG1, TauG2, AlphaG1, BetaG1, BetaG2]
+///
+/// Returns `Error::InvalidLength` if the buffer is too short for the parameters,
+/// and `Error::InvalidChunk` if the chunk is outside of the elements of the buffer.
 pub(crate) fn split_at_chunk_mut<'a, E: PairingEngine>(
     buffer: &'a mut [u8],
     parameters: &'a Phase1Parameters<E>,
     compressed: UseCompression,
-) -> SplitBufMut<'a> {
+) -> Result<SplitBufMut<'a>> {
     let g1_size = buffer_size::<E::G1Affine>(compressed);
     let g2_size = buffer_size::<E::G2Affine>(compressed);
 
-    let buffer_to_chunk = |buffer: &'a mut [u8], element_size: usize, is_other: bool| -> &'a mut [u8] {
+    let buffer_to_chunk = |buffer: &'a mut [u8], element_size: usize, is_other: bool| -> Result<&'a mut [u8]> {
         // Determine whether to return an empty chunk based on the size of 'other'.
         if is_other && parameters.other_chunk_size == 0 {
-            return &mut [];
+            return Ok(&mut []);
         }
 
         // Determine the chunk size based on the proof system.
         let chunk_size = match (parameters.proving_system, is_other) {
             (ProvingSystem::Groth16, true) => parameters.other_chunk_size,
             (ProvingSystem::Groth16, false) => parameters.g1_chunk_size,
-            (ProvingSystem::Marlin, true) => return &mut [],
+            (ProvingSystem::Marlin, true) => return Ok(&mut []),
             (ProvingSystem::Marlin, false) => parameters.g1_chunk_size,
         };
 
         let start = parameters.chunk_index * parameters.chunk_size * element_size;
         let end = start + chunk_size * element_size;
 
-        &mut buffer[start..end]
+        buffer.get_mut(start..end).ok_or(Error::InvalidChunk)
     };
 
     match parameters.proving_system {
         ProvingSystem::Groth16 => {
+            let expected = parameters.hash_size
+                + g1_size * parameters.powers_g1_length
+                + (g2_size + 2 * g1_size) * parameters.powers_length
+                + g2_size;
+            check_length(buffer, expected)?;
+
             // leave the first 64 bytes for the hash
             let (_, others) = buffer.split_at_mut(parameters.hash_size);
             let (tau_g1, others) = others.split_at_mut(g1_size * parameters.powers_g1_length);
@@ -125,13 +190,13 @@ pub(crate) fn split_at_chunk_mut<'a, E: PairingEngine>(
 
             // We take up to g2_size for beta_g2, since there might be other
             // elements after it at the end of the buffer.
-            (
-                buffer_to_chunk(tau_g1, g1_size, false),
-                buffer_to_chunk(tau_g2, g2_size, true),
-                buffer_to_chunk(alpha_g1, g1_size, true),
-                buffer_to_chunk(beta_g1, g1_size, true),
+            Ok((
+                buffer_to_chunk(tau_g1, g1_size, false)?,
+                buffer_to_chunk(tau_g2, g2_size, true)?,
+                buffer_to_chunk(alpha_g1, g1_size, true)?,
+                buffer_to_chunk(beta_g1, g1_size, true)?,
                 &mut beta_g2[0..g2_size],
-            )
+            ))
         }
         ProvingSystem::Marlin => {
             let (g2_chunk_size, alpha_chunk_size) = if parameters.chunk_index == 0 {
@@ -140,19 +205,25 @@ pub(crate) fn split_at_chunk_mut<'a, E: PairingEngine>(
                 (0, 0)
             };
 
+            let expected = parameters.hash_size
+                + g1_size * parameters.powers_length
+                + g2_size * g2_chunk_size
+                + g1_size * alpha_chunk_size;
+            check_length(buffer, expected)?;
+
             // leave the first 64 bytes for the hash
             let (_, others) = buffer.split_at_mut(parameters.hash_size);
             let (tau_g1, others) = others.split_at_mut(g1_size * parameters.powers_length);
             let (tau_g2, others) = others.split_at_mut(g2_size * g2_chunk_size);
             let (alpha_g1, _) = others.split_at_mut(g1_size * alpha_chunk_size);
 
-            (
-                buffer_to_chunk(tau_g1, g1_size, false),
+            Ok((
+                buffer_to_chunk(tau_g1, g1_size, false)?,
                 tau_g2,
                 alpha_g1,
                 &mut [],
                 &mut [],
-            )
+            ))
         }
     }
 }
@@ -160,99 +231,153 @@ pub(crate) fn split_at_chunk_mut<'a, E: PairingEngine>(
 /// Splits the full buffer in 5 non overlapping mutable slice.
 /// Each slice corresponds to the group elements in the following order
 /// [TauG1, TauG2, AlphaG1, BetaG1, BetaG2]
+///
+/// Returns `Error::InvalidLength` if the buffer is too short for the parameters.
 pub(crate) fn split_mut<'a, E: PairingEngine>(
     buffer: &'a mut [u8],
     parameters: &'a Phase1Parameters<E>,
     compressed: UseCompression,
-) -> SplitBufMut<'a> {
-    match parameters.proving_system {
-        ProvingSystem::Groth16 => {
-            let g1_size = buffer_size::<E::G1Affine>(compressed);
-            let g2_size = buffer_size::<E::G2Affine>(compressed);
-
-            let g1_chunk_size = parameters.g1_chunk_size;
-            let other_chunk_size = parameters.other_chunk_size;
-
-            let (_, others) = buffer.split_at_mut(parameters.hash_size);
-            let (tau_g1, others) = others.split_at_mut(g1_size * g1_chunk_size);
-            let (tau_g2, others) = others.split_at_mut(g2_size * other_chunk_size);
-            let (alpha_g1, others) = others.split_at_mut(g1_size * other_chunk_size);
-            let (beta_g1, beta_g2) = others.split_at_mut(g1_size * other_chunk_size);
+) -> Result<SplitBufMut<'a>> {
+    let layout = parameters.layout(compressed);
+    check_split_length(buffer, &layout)?;
 
-            // We take up to g2_size for beta_g2, since there might be other
-            // elements after it at the end of the buffer.
-            (tau_g1, tau_g2, alpha_g1, beta_g1, &mut beta_g2[0..g2_size])
-        }
-        ProvingSystem::Marlin => {
-            let g1_size = buffer_size::<E::G1Affine>(compressed);
-            let g2_size = buffer_size::<E::G2Affine>(compressed);
+    let (_, others) = buffer.split_at_mut(layout.tau_g1.start);
+    let (tau_g1, others) = others.split_at_mut(layout.tau_g1.len());
+    let (tau_g2, others) = others.split_at_mut(layout.tau_g2.len());
+    let (alpha_g1, others) = others.split_at_mut(layout.alpha_g1.len());
+    let (beta_g1, others) = others.split_at_mut(layout.beta_g1.len());
 
-            let g1_chunk_size = parameters.g1_chunk_size;
-            let (g2_chunk_size, alpha_chunk_size) = if parameters.chunk_index == 0 {
-                (parameters.total_size_in_log2 + 2, 3 + 3 * parameters.total_size_in_log2)
-            } else {
-                (0, 0)
-            };
+    // We take up to the end of beta_g2, since there might be other
+    // elements after it at the end of the buffer.
+    let (beta_g2, _) = others.split_at_mut(layout.beta_g2.len());
 
-            let (_, others) = buffer.split_at_mut(parameters.hash_size);
-            let (tau_g1, others) = others.split_at_mut(g1_size * g1_chunk_size);
-            let (tau_g2, others) = others.split_at_mut(g2_size * g2_chunk_size);
-            let (alpha_g1, _) = others.split_at_mut(g1_size * alpha_chunk_size);
-
-            (tau_g1, tau_g2, alpha_g1, &mut [], &mut [])
-        }
-    }
+    Ok((tau_g1, tau_g2, alpha_g1, beta_g1, beta_g2))
 }
 
 /// Splits the full buffer in 5 non overlapping immutable slice.
 /// Each slice corresponds to the group elements in the following order
 /// [TauG1, TauG2, AlphaG1, BetaG1, BetaG2]
+///
+/// Returns `Error::InvalidLength` if the buffer is too short for the parameters.
 pub(crate) fn split<'a, E: PairingEngine>(
     buffer: &'a [u8],
     parameters: &Phase1Parameters<E>,
     compressed: UseCompression,
-) -> SplitBuf<'a> {
-    match parameters.proving_system {
-        ProvingSystem::Groth16 => {
-            let g1_size = buffer_size::<E::G1Affine>(compressed);
-            let g2_size = buffer_size::<E::G2Affine>(compressed);
+) -> Result<SplitBuf<'a>> {
+    let layout = parameters.layout(compressed);
+    check_split_length(buffer, &layout)?;
 
-            let g1_chunk_size = parameters.g1_chunk_size;
-            let other_chunk_size = parameters.other_chunk_size;
+    // Check that tau_g1 is not empty.
+    if layout.tau_g1.is_empty() {
+        return Err(Error::InvalidChunk);
+    }
 
-            let (_, others) = buffer.split_at(parameters.hash_size);
-            let (tau_g1, others) = others.split_at(g1_size * g1_chunk_size);
-            let (tau_g2, others) = others.split_at(g2_size * other_chunk_size);
-            let (alpha_g1, others) = others.split_at(g1_size * other_chunk_size);
-            let (beta_g1, beta_g2) = others.split_at(g1_size * other_chunk_size);
+    // We take up to the end of beta_g2, since there might be other
+    // elements after it at the end of the buffer.
+    Ok((
+        &buffer[layout.tau_g1.range()],
+        &buffer[layout.tau_g2.range()],
+        &buffer[layout.alpha_g1.range()],
+        &buffer[layout.beta_g1.range()],
+        &buffer[layout.beta_g2.range()],
+    ))
+}
 
-            // Check that tau_g1 is not empty.
-            assert!(tau_g1.len() > 0);
+/// Checks that the buffer holds every element up to the end of beta_g2 in the layout.
+fn check_split_length(buffer: &[u8], layout: &AccumulatorLayout) -> Result<()> {
+    check_length(buffer, layout.beta_g2.end)
+}
 
-            // We take up to g2_size for beta_g2, since there might be other
-            // elements after it at the end of the buffer.
-            (tau_g1, tau_g2, alpha_g1, beta_g1, &beta_g2[0..g2_size])
-        }
-        ProvingSystem::Marlin => {
-            let g1_size = buffer_size::<E::G1Affine>(compressed);
-            let g2_size = buffer_size::<E::G2Affine>(compressed);
+/// Checks that the buffer holds at least the expected number of bytes.
+fn check_length(buffer: &[u8], expected: usize) -> Result<()> {
+    if buffer.len() < expected {
+        return Err(Error::InvalidLength {
+            expected,
+            got: buffer.len(),
+        });
+    }
+    Ok(())
+}
 
-            let g1_chunk_size = parameters.g1_chunk_size;
-            let (g2_chunk_size, alpha_chunk_size) = if parameters.chunk_index == 0 {
-                (parameters.total_size_in_log2 + 2, 3 + 3 * parameters.total_size_in_log2)
-            } else {
-                (0, 0)
-            };
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-            let (_, others) = buffer.split_at(parameters.hash_size);
-            let (tau_g1, others) = others.split_at(g1_size * g1_chunk_size);
-            let (tau_g2, others) = others.split_at(g2_size * g2_chunk_size);
-            let (alpha_g1, _) = others.split_at(g1_size * alpha_chunk_size);
+    use zexe_algebra::Bls12_377;
 
-            // Check that tau_g1 is not empty.
-            assert!(tau_g1.len() > 0);
+    #[test]
+    fn test_iter_chunk_batch_context() {
+        let parameters = Phase1Parameters::<Bls12_377>::new_full(ProvingSystem::Groth16, 4, 4);
 
-            (tau_g1, tau_g2, alpha_g1, &[], &[])
+        // Each batch knows its position, and the batches overlap by one element.
+        let mut batches = vec![];
+        iter_chunk(&parameters, None, |batch| {
+            batches.push(batch);
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(parameters.powers_g1_length / 3 + 1, batches.len());
+        for (index, batch) in batches.iter().enumerate() {
+            assert_eq!(index, batch.index);
+            assert_eq!(batches.len(), batch.total);
+            assert_eq!(index * 3, batch.start);
         }
+        assert_eq!(parameters.powers_g1_length, batches.last().unwrap().end);
+    }
+
+    #[test]
+    fn test_iter_chunk_cancellation() {
+        let parameters = Phase1Parameters::<Bls12_377>::new_full(ProvingSystem::Groth16, 4, 4);
+
+        // Cancelling during a batch stops the iteration before the next batch.
+        let cancellation = Cancellation::new();
+        let mut indices = vec![];
+        let result = iter_chunk(&parameters, Some(&cancellation), |batch| {
+            indices.push(batch.index);
+            if batch.index == 1 {
+                cancellation.cancel();
+            }
+            Ok(())
+        });
+        assert!(matches!(result, Err(Error::Cancelled)));
+        assert_eq!(vec![0, 1], indices);
+
+        // The clones of a cancellation share the flag.
+        assert!(cancellation.clone().is_cancelled());
+        assert!(!Cancellation::new().is_cancelled());
+    }
+
+    #[test]
+    fn test_split_short_buffer() {
+        let parameters = Phase1Parameters::<Bls12_377>::new_full(ProvingSystem::Groth16, 3, 4);
+        let compressed = UseCompression::No;
+        let expected = parameters.layout(compressed).beta_g2.end;
+
+        // A buffer holding every element up to beta_g2 is split.
+        let mut buffer = vec![0u8; expected];
+        assert!(split(&buffer, &parameters, compressed).is_ok());
+        assert!(split_mut(&mut buffer, &parameters, compressed).is_ok());
+        assert!(split_at_chunk_mut(&mut buffer, &parameters, compressed).is_ok());
+
+        // A buffer missing the last byte of beta_g2 is rejected instead of panicking.
+        let mut buffer = vec![0u8; expected - 1];
+        assert!(matches!(
+            split(&buffer, &parameters, compressed),
+            Err(Error::InvalidLength { expected: e, got }) if e == expected && got == expected - 1
+        ));
+        assert!(matches!(
+            split_mut(&mut buffer, &parameters, compressed),
+            Err(Error::InvalidLength { .. })
+        ));
+        assert!(matches!(
+            split_at_chunk_mut(&mut buffer, &parameters, compressed),
+            Err(Error::InvalidLength { expected: e, got }) if e == expected && got == expected - 1
+        ));
+
+        // The error is propagated by the callers of the split.
+        assert!(matches!(
+            crate::Phase1::initialization(&mut buffer, compressed, &parameters),
+            Err(Error::InvalidLength { .. })
+        ));
     }
 }
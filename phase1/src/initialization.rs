@@ -17,7 +17,7 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
         let span = info_span!("phase1-initialization");
         let _ = span.enter();
 
-        let (tau_g1, tau_g2, alpha_g1, beta_g1, beta_g2) = split_mut(output, parameters, compressed_output);
+        let (tau_g1, tau_g2, alpha_g1, beta_g1, beta_g2) = split_mut(output, parameters, compressed_output)?;
 
         let one_g1 = &E::G1Affine::prime_subgroup_generator();
         let one_g2 = &E::G2Affine::prime_subgroup_generator();
@@ -60,7 +60,9 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
 mod tests {
     use super::*;
 
-    use zexe_algebra::{AffineCurve, Bls12_377, BW6_761};
+    use zexe_algebra::{AffineCurve, Bls12_377, CanonicalSerialize, BW6_761};
+
+    use rand::thread_rng;
 
     fn curve_initialization_test<E: PairingEngine>(powers: usize, batch: usize, compression: UseCompression) {
         for proving_system in &[ProvingSystem::Groth16, ProvingSystem::Marlin] {
@@ -106,6 +108,103 @@ mod tests {
         }
     }
 
+    fn curve_initialization_size_test<E: PairingEngine>(powers: usize, batch: usize, compression: UseCompression) {
+        // Pick a chunk size which leaves the last chunk partially filled.
+        let chunk_size = 7;
+
+        for proving_system in &[ProvingSystem::Groth16, ProvingSystem::Marlin] {
+            let full_parameters = Phase1Parameters::<E>::new_full(*proving_system, powers, batch);
+            let upper_bound = match proving_system {
+                ProvingSystem::Groth16 => full_parameters.powers_g1_length,
+                ProvingSystem::Marlin => full_parameters.powers_length,
+            };
+            let number_of_chunks = (upper_bound + chunk_size - 1) / chunk_size;
+
+            let chunk_parameters = (0..number_of_chunks).map(|chunk_index| {
+                Phase1Parameters::<E>::new_chunk(
+                    ContributionMode::Chunked,
+                    chunk_index,
+                    chunk_size,
+                    *proving_system,
+                    powers,
+                    batch,
+                )
+            });
+
+            for parameters in std::iter::once(full_parameters).chain(chunk_parameters) {
+                let expected_challenge_length = match compression {
+                    UseCompression::Yes => parameters.contribution_size - parameters.public_key_size,
+                    UseCompression::No => parameters.accumulator_size,
+                };
+
+                // Leave room past the expected length, to check that nothing is written there.
+                let mut output = vec![0; expected_challenge_length + 1024];
+                Phase1::initialization(&mut output, compression, &parameters).unwrap();
+                assert!(output[expected_challenge_length..].iter().all(|byte| *byte == 0));
+
+                // Serialize the initial elements one by one, without the precomputed sizes.
+                let deserialized = Phase1::deserialize(
+                    &output[..expected_challenge_length],
+                    compression,
+                    CheckForCorrectness::Full,
+                    &parameters,
+                )
+                .unwrap();
+                let mut serialized = blank_hash().to_vec();
+                serialized
+                    .write_elements_exact(&deserialized.tau_powers_g1, compression)
+                    .unwrap();
+                serialized
+                    .write_elements_exact(&deserialized.tau_powers_g2, compression)
+                    .unwrap();
+                serialized
+                    .write_elements_exact(&deserialized.alpha_tau_powers_g1, compression)
+                    .unwrap();
+                serialized
+                    .write_elements_exact(&deserialized.beta_tau_powers_g1, compression)
+                    .unwrap();
+                if parameters.proving_system == ProvingSystem::Groth16 {
+                    serialized.write_element(&deserialized.beta_g2, compression).unwrap();
+                }
+
+                // The initial accumulator has no public key, so its length excludes it.
+                assert_eq!(parameters.hash_size, blank_hash().len());
+                assert_eq!(expected_challenge_length, serialized.len());
+                assert_eq!(
+                    &serialized[parameters.hash_size..],
+                    &output[parameters.hash_size..expected_challenge_length]
+                );
+
+                // The public key of a contribution is always compressed.
+                let mut rng = thread_rng();
+                let (public_key, _) = Phase1::<E>::key_generation(&mut rng, blank_hash().as_ref()).unwrap();
+                let mut serialized_public_key = vec![];
+                public_key.serialize(&mut serialized_public_key).unwrap();
+                assert_eq!(parameters.public_key_size, serialized_public_key.len());
+            }
+        }
+    }
+
+    #[test]
+    fn test_initialization_size_bls12_377_compressed() {
+        curve_initialization_size_test::<Bls12_377>(4, 4, UseCompression::Yes);
+    }
+
+    #[test]
+    fn test_initialization_size_bls12_377_uncompressed() {
+        curve_initialization_size_test::<Bls12_377>(4, 4, UseCompression::No);
+    }
+
+    #[test]
+    fn test_initialization_size_bw6_761_compressed() {
+        curve_initialization_size_test::<BW6_761>(4, 4, UseCompression::Yes);
+    }
+
+    #[test]
+    fn test_initialization_size_bw6_761_uncompressed() {
+        curve_initialization_size_test::<BW6_761>(4, 4, UseCompression::No);
+    }
+
     #[test]
     fn test_initialization_bls12_377_compressed() {
         curve_initialization_test::<Bls12_377>(4, 4, UseCompression::Yes);
@@ -3,6 +3,15 @@ use super::*;
 impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
     /// Constructs a keypair given an RNG and a 64-byte transcript `digest`.
     pub fn key_generation<R: Rng>(rng: &mut R, digest: &[u8]) -> Result<(PublicKey<E>, PrivateKey<E>)> {
+        Self::key_generation_with_hasher::<Blake2b, R>(rng, digest)
+    }
+
+    /// Constructs a keypair given an RNG and a 64-byte transcript `digest`,
+    /// computed with the given transcript hasher.
+    pub fn key_generation_with_hasher<H: TranscriptHasher, R: Rng>(
+        rng: &mut R,
+        digest: &[u8],
+    ) -> Result<(PublicKey<E>, PrivateKey<E>)> {
         if digest.len() != 64 {
             return Err(Error::InvalidLength {
                 expected: 64,
@@ -23,7 +32,7 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
             // Compute g^{s*x}
             let g1_s_x = g1_s.mul(x).into_affine();
             // Hash into G2 as g^{s'}
-            let g2_s: E::G2Affine = compute_g2_s::<E>(&digest, &g1_s, &g1_s_x, personalization)?;
+            let g2_s: E::G2Affine = compute_g2_s_with_hasher::<E, H>(&digest, &g1_s, &g1_s_x, personalization)?;
             // Compute g^{s'*x}
             let g2_s_x = g2_s.mul(x).into_affine();
 
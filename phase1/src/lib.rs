@@ -1,32 +1,55 @@
+// Without the `std` feature, only the verification core is built, with `core` and `alloc`.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub mod verification_core;
+
+#[cfg(feature = "std")]
 pub mod helpers;
 
+#[cfg(feature = "std")]
 pub mod objects;
+#[cfg(feature = "std")]
 pub use objects::*;
 
-#[cfg(not(feature = "wasm"))]
+#[cfg(all(feature = "std", not(feature = "wasm")))]
 mod aggregation;
+#[cfg(feature = "std")]
+mod batch_commitment;
+#[cfg(feature = "std")]
+pub use batch_commitment::{BatchCommitment, BatchProof, BatchTree, BATCH_COMMITMENT_SIZE};
+#[cfg(feature = "std")]
 mod computation;
+#[cfg(feature = "std")]
 mod initialization;
+#[cfg(feature = "std")]
 mod key_generation;
+#[cfg(feature = "std")]
 mod serialization;
-#[cfg(not(feature = "wasm"))]
+#[cfg(all(feature = "std", not(feature = "wasm")))]
 mod verification;
 
+#[cfg(feature = "std")]
 use crate::helpers::{
     accumulator::{self},
     buffers::*,
 };
+#[cfg(feature = "std")]
 use setup_utils::*;
 
-#[cfg(not(feature = "wasm"))]
+#[cfg(all(feature = "std", not(feature = "wasm")))]
 use crate::helpers::accumulator::*;
 
-#[cfg(not(feature = "wasm"))]
+#[cfg(all(feature = "std", not(feature = "wasm")))]
 use zexe_algebra::Zero;
 
+#[cfg(feature = "std")]
 use zexe_algebra::{AffineCurve, PairingEngine, ProjectiveCurve, UniformRand};
 
+#[cfg(feature = "std")]
 use rand::Rng;
+#[cfg(feature = "std")]
 use tracing::{debug, info, info_span, trace};
 
 /// `Phase1` is an object that participants of the ceremony contribute
@@ -36,6 +59,7 @@ use tracing::{debug, info, info_span, trace};
 ///
 /// * (τ, τ<sup>2</sup>, ..., τ<sup>2<sup>22</sup> - 2</sup>, α, ατ, ατ<sup>2</sup>, ..., ατ<sup>2<sup>21</sup> - 1</sup>, β, βτ, βτ<sup>2</sup>, ..., βτ<sup>2<sup>21</sup> - 1</sup>)<sub>1</sub>
 /// * (β, τ, τ<sup>2</sup>, ..., τ<sup>2<sup>21</sup> - 1</sup>)<sub>2</sub>
+#[cfg(feature = "std")]
 #[derive(Debug)]
 pub struct Phase1<'a, E: PairingEngine> {
     /// Groth16: tau^0, tau^1, tau^2, ..., tau^{TAU_POWERS_G1_LENGTH - 1}
@@ -61,6 +85,7 @@ pub struct Phase1<'a, E: PairingEngine> {
     pub parameters: &'a Phase1Parameters<E>,
 }
 
+#[cfg(feature = "std")]
 impl<'a, E: PairingEngine> PartialEq for Phase1<'a, E> {
     fn eq(&self, other: &Self) -> bool {
         self.tau_powers_g1 == other.tau_powers_g1
@@ -71,3 +96,23 @@ impl<'a, E: PairingEngine> PartialEq for Phase1<'a, E> {
             && self.hash == other.hash
     }
 }
+
+/// The leading elements of an accumulator or chunk, which verification reads to check
+/// the initial conditions and the continuity of the powers across chunks.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct BoundaryElements<E: PairingEngine> {
+    /// The first powers of tau in G1.
+    pub tau_g1: Vec<E::G1Affine>,
+    /// The first powers of tau in G2.
+    pub tau_g2: Vec<E::G2Affine>,
+    /// The first powers of tau in G1 multiplied by alpha.
+    pub alpha_g1: Vec<E::G1Affine>,
+}
+
+#[cfg(feature = "std")]
+impl<E: PairingEngine> PartialEq for BoundaryElements<E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.tau_g1 == other.tau_g1 && self.tau_g2 == other.tau_g2 && self.alpha_g1 == other.alpha_g1
+    }
+}
@@ -0,0 +1,78 @@
+use serde::{Deserialize, Serialize};
+use std::ops::Range;
+
+/// A contiguous region of elements of the same size in a serialized accumulator
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct ElementRegion {
+    /// The number of elements in the region
+    pub count: usize,
+    /// The size in bytes of each element in the region
+    pub element_size: usize,
+    /// The byte offset of the first element of the region
+    pub start: usize,
+    /// The byte offset past the last element of the region
+    pub end: usize,
+}
+
+impl ElementRegion {
+    /// Creates a region of `count` elements of `element_size` bytes, starting at `start`
+    pub fn new(count: usize, element_size: usize, start: usize) -> Self {
+        Self {
+            count,
+            element_size,
+            start,
+            end: start + count * element_size,
+        }
+    }
+
+    /// Returns the size in bytes of the region
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    /// Returns `true` if the region has no elements
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Returns the byte range of the region
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.end
+    }
+}
+
+/// The regions of a serialized accumulator, in the order they are stored.
+///
+/// The offsets are relative to the start of the file of the accumulator, which is the
+/// file of a single chunk in chunked contribution mode. For Marlin, the regions of
+/// beta are empty, as are the regions of tau in G2 and alpha in G1 after the first chunk.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct AccumulatorLayout {
+    /// The hash of the previous contribution
+    pub hash: ElementRegion,
+    /// The powers of tau in G1
+    pub tau_g1: ElementRegion,
+    /// The powers of tau in G2
+    pub tau_g2: ElementRegion,
+    /// The powers of tau multiplied by alpha in G1
+    pub alpha_g1: ElementRegion,
+    /// The powers of tau multiplied by beta in G1
+    pub beta_g1: ElementRegion,
+    /// Beta in G2
+    pub beta_g2: ElementRegion,
+    /// The public key of the contributor, which follows the accumulator in a contribution.
+    /// The public key is always compressed.
+    pub public_key: ElementRegion,
+}
+
+impl AccumulatorLayout {
+    /// Returns the size in bytes of the accumulator, excluding the public key
+    pub fn accumulator_size(&self) -> usize {
+        self.beta_g2.end
+    }
+
+    /// Returns the size in bytes of a contribution, including the public key
+    pub fn contribution_size(&self) -> usize {
+        self.public_key.end
+    }
+}
@@ -1,3 +1,6 @@
+pub mod layout;
+pub use layout::*;
+
 pub mod parameters;
 pub use parameters::*;
 
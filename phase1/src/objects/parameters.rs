@@ -1,9 +1,10 @@
+use crate::{AccumulatorLayout, ElementRegion};
 use setup_utils::UseCompression;
 
 use zexe_algebra::{ConstantSerializedSize, PairingEngine};
 
 use serde::{Deserialize, Serialize};
-use std::marker::PhantomData;
+use std::{fmt, marker::PhantomData};
 
 #[derive(Clone, PartialEq, Eq, Debug, Copy, Serialize, Deserialize)]
 pub enum ContributionMode {
@@ -254,6 +255,42 @@ impl<E: PairingEngine> Phase1Parameters<E> {
         }
     }
 
+    /// Returns the layout of the serialized accumulator depending on if it's compressed or not,
+    /// with the element count, element size and byte offsets of each region
+    pub fn layout(&self, compressed: UseCompression) -> AccumulatorLayout {
+        let (g1_size, g2_size) = match compressed {
+            UseCompression::Yes => (self.curve.g1_compressed_size, self.curve.g2_compressed_size),
+            UseCompression::No => (self.curve.g1_size, self.curve.g2_size),
+        };
+
+        // Determine the number of elements in each region based on the proof system.
+        let (tau_g2_count, alpha_g1_count, beta_g1_count, beta_g2_count) = match self.proving_system {
+            ProvingSystem::Groth16 => (self.other_chunk_size, self.other_chunk_size, self.other_chunk_size, 1),
+            ProvingSystem::Marlin => match self.chunk_index == 0 {
+                true => (self.total_size_in_log2 + 2, 3 + 3 * self.total_size_in_log2, 0, 0),
+                false => (0, 0, 0, 0),
+            },
+        };
+
+        let hash = ElementRegion::new(1, self.hash_size, 0);
+        let tau_g1 = ElementRegion::new(self.g1_chunk_size, g1_size, hash.end);
+        let tau_g2 = ElementRegion::new(tau_g2_count, g2_size, tau_g1.end);
+        let alpha_g1 = ElementRegion::new(alpha_g1_count, g1_size, tau_g2.end);
+        let beta_g1 = ElementRegion::new(beta_g1_count, g1_size, alpha_g1.end);
+        let beta_g2 = ElementRegion::new(beta_g2_count, g2_size, beta_g1.end);
+        let public_key = ElementRegion::new(1, self.public_key_size, beta_g2.end);
+
+        AccumulatorLayout {
+            hash,
+            tau_g1,
+            tau_g2,
+            alpha_g1,
+            beta_g1,
+            beta_g2,
+            public_key,
+        }
+    }
+
     fn chunk_sizes(
         contribution_mode: ContributionMode,
         chunk_index: usize,
@@ -303,6 +340,20 @@ impl<E: PairingEngine> Phase1Parameters<E> {
     }
 }
 
+impl<E: PairingEngine> fmt::Display for Phase1Parameters<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "power: {}, batch size: {}, proving system: {:?}, contribution mode: {:?}",
+            self.total_size_in_log2, self.batch_size, self.proving_system, self.contribution_mode
+        )?;
+        if self.contribution_mode == ContributionMode::Chunked {
+            write!(f, ", chunk size: {}, chunk index: {}", self.chunk_size, self.chunk_index)?;
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -322,4 +373,121 @@ mod tests {
         curve_parameters_test::<Bls12_381>(96, 192, 48, 96);
         curve_parameters_test::<BW6_761>(192, 192, 96, 96);
     }
+
+    fn region(count: usize, element_size: usize, start: usize, end: usize) -> ElementRegion {
+        ElementRegion {
+            count,
+            element_size,
+            start,
+            end,
+        }
+    }
+
+    fn layout_test<E: PairingEngine>(parameters: &Phase1Parameters<E>, compressed: UseCompression) {
+        let layout = parameters.layout(compressed);
+        assert_eq!(layout.accumulator_size(), parameters.get_length(compressed));
+        if compressed == UseCompression::Yes {
+            assert_eq!(layout.contribution_size(), parameters.contribution_size);
+        }
+    }
+
+    #[test]
+    fn test_layout_groth16_full() {
+        let parameters = Phase1Parameters::<Bls12_377>::new_full(ProvingSystem::Groth16, 3, 4);
+        layout_test(&parameters, UseCompression::No);
+        layout_test(&parameters, UseCompression::Yes);
+        assert_eq!(
+            AccumulatorLayout {
+                hash: region(1, 64, 0, 64),
+                tau_g1: region(15, 96, 64, 1504),
+                tau_g2: region(8, 192, 1504, 3040),
+                alpha_g1: region(8, 96, 3040, 3808),
+                beta_g1: region(8, 96, 3808, 4576),
+                beta_g2: region(1, 192, 4576, 4768),
+                public_key: region(1, 576, 4768, 5344),
+            },
+            parameters.layout(UseCompression::No)
+        );
+    }
+
+    #[test]
+    fn test_layout_groth16_chunked() {
+        let parameters =
+            Phase1Parameters::<Bls12_377>::new_chunk(ContributionMode::Chunked, 1, 4, ProvingSystem::Groth16, 3, 4);
+        layout_test(&parameters, UseCompression::No);
+        layout_test(&parameters, UseCompression::Yes);
+        assert_eq!(
+            AccumulatorLayout {
+                hash: region(1, 64, 0, 64),
+                tau_g1: region(4, 48, 64, 256),
+                tau_g2: region(4, 96, 256, 640),
+                alpha_g1: region(4, 48, 640, 832),
+                beta_g1: region(4, 48, 832, 1024),
+                beta_g2: region(1, 96, 1024, 1120),
+                public_key: region(1, 576, 1120, 1696),
+            },
+            parameters.layout(UseCompression::Yes)
+        );
+
+        // The last chunk only has powers of tau in G1.
+        let parameters = parameters.into_chunk_parameters(ContributionMode::Chunked, 3, 4);
+        layout_test(&parameters, UseCompression::No);
+        layout_test(&parameters, UseCompression::Yes);
+        assert_eq!(
+            AccumulatorLayout {
+                hash: region(1, 64, 0, 64),
+                tau_g1: region(3, 48, 64, 208),
+                tau_g2: region(0, 96, 208, 208),
+                alpha_g1: region(0, 48, 208, 208),
+                beta_g1: region(0, 48, 208, 208),
+                beta_g2: region(1, 96, 208, 304),
+                public_key: region(1, 576, 304, 880),
+            },
+            parameters.layout(UseCompression::Yes)
+        );
+    }
+
+    #[test]
+    fn test_layout_marlin_chunked() {
+        let parameters =
+            Phase1Parameters::<Bls12_377>::new_chunk(ContributionMode::Chunked, 0, 4, ProvingSystem::Marlin, 3, 12);
+        layout_test(&parameters, UseCompression::No);
+        layout_test(&parameters, UseCompression::Yes);
+        assert_eq!(
+            AccumulatorLayout {
+                hash: region(1, 64, 0, 64),
+                tau_g1: region(4, 48, 64, 256),
+                tau_g2: region(5, 96, 256, 736),
+                alpha_g1: region(12, 48, 736, 1312),
+                beta_g1: region(0, 48, 1312, 1312),
+                beta_g2: region(0, 96, 1312, 1312),
+                public_key: region(1, 576, 1312, 1888),
+            },
+            parameters.layout(UseCompression::Yes)
+        );
+
+        // Only the first chunk has powers of tau in G2 and alpha in G1.
+        let parameters = parameters.into_chunk_parameters(ContributionMode::Chunked, 1, 4);
+        layout_test(&parameters, UseCompression::No);
+        layout_test(&parameters, UseCompression::Yes);
+        let layout = parameters.layout(UseCompression::Yes);
+        assert_eq!(region(4, 48, 64, 256), layout.tau_g1);
+        assert!(layout.tau_g2.is_empty() && layout.alpha_g1.is_empty());
+        assert_eq!(region(1, 576, 256, 832), layout.public_key);
+    }
+
+    #[test]
+    fn test_parameters_display() {
+        let full = Phase1Parameters::<Bls12_377>::new_full(ProvingSystem::Groth16, 10, 256);
+        assert_eq!(
+            "power: 10, batch size: 256, proving system: Groth16, contribution mode: Full",
+            full.to_string()
+        );
+
+        let chunk = full.into_chunk_parameters(ContributionMode::Chunked, 2, 512);
+        assert_eq!(
+            "power: 10, batch size: 256, proving system: Groth16, contribution mode: Chunked, chunk size: 512, chunk index: 2",
+            chunk.to_string()
+        );
+    }
 }
@@ -1,9 +1,47 @@
+use setup_utils::zeroize_secret;
 use zexe_algebra::PairingEngine;
 
 /// Contains the secrets τ, α and β that the participant of the ceremony must destroy.
+///
+/// The secrets are overwritten with zeros when the private key is dropped. Copies of the
+/// secrets made while the contribution is computed are not covered by this.
 #[derive(PartialEq, Debug)]
 pub struct PrivateKey<E: PairingEngine> {
     pub tau: E::Fr,
     pub alpha: E::Fr,
     pub beta: E::Fr,
 }
+
+impl<E: PairingEngine> Drop for PrivateKey<E> {
+    fn drop(&mut self) {
+        zeroize_secret(&mut self.tau);
+        zeroize_secret(&mut self.alpha);
+        zeroize_secret(&mut self.beta);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Phase1;
+
+    use rand::thread_rng;
+    use std::mem::ManuallyDrop;
+    use zexe_algebra::{Bls12_377, Zero};
+
+    #[test]
+    fn test_private_key_zeroized_on_drop() {
+        let (_, privkey) = Phase1::<Bls12_377>::key_generation(&mut thread_rng(), &[0; 64]).unwrap();
+        assert!(!privkey.tau.is_zero());
+        assert!(!privkey.alpha.is_zero());
+        assert!(!privkey.beta.is_zero());
+
+        // Run the destructor in place, so the memory that held the secrets can be inspected.
+        let mut privkey = ManuallyDrop::new(privkey);
+        unsafe { ManuallyDrop::drop(&mut privkey) };
+
+        assert!(privkey.tau.is_zero());
+        assert!(privkey.alpha.is_zero());
+        assert!(privkey.beta.is_zero());
+    }
+}
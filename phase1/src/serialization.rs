@@ -39,6 +39,121 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
         })
     }
 
+    /// Deserializes the first `g1_length` powers of tau in G1 and the first `other_length`
+    /// elements of the other vectors, leaving the rest of the input unread.
+    pub fn deserialize_prefix(
+        input: &[u8],
+        compression: UseCompression,
+        check_input_for_correctness: CheckForCorrectness,
+        g1_length: usize,
+        other_length: usize,
+        parameters: &'a Phase1Parameters<E>,
+    ) -> Result<Phase1<'a, E>> {
+        let (tau_powers_g1, tau_powers_g2, alpha_tau_powers_g1, beta_tau_powers_g1, beta_g2) =
+            accumulator::deserialize_prefix(
+                input,
+                compression,
+                check_input_for_correctness,
+                g1_length,
+                other_length,
+                parameters,
+            )?;
+        Ok(Phase1 {
+            tau_powers_g1,
+            tau_powers_g2,
+            alpha_tau_powers_g1,
+            beta_tau_powers_g1,
+            beta_g2,
+            hash: blank_hash(),
+            parameters,
+        })
+    }
+
+    /// Deserializes the same prefix as `deserialize_prefix` from the chunks of a chunked
+    /// transcript of `chunk_size` powers each, given in chunk order. Each chunk is read
+    /// only up to the part of the prefix it holds, and the chunks after the prefix are
+    /// never read. Returns an error if the chunks end before the prefix is complete.
+    pub fn deserialize_chunked_prefix<I>(
+        chunks: I,
+        compression: UseCompression,
+        check_input_for_correctness: CheckForCorrectness,
+        g1_length: usize,
+        other_length: usize,
+        chunk_size: usize,
+        parameters: &'a Phase1Parameters<E>,
+    ) -> Result<Phase1<'a, E>>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<[u8]>,
+    {
+        let mut accumulator = Phase1 {
+            tau_powers_g1: Vec::with_capacity(g1_length),
+            tau_powers_g2: Vec::with_capacity(other_length),
+            alpha_tau_powers_g1: Vec::with_capacity(other_length),
+            beta_tau_powers_g1: Vec::with_capacity(other_length),
+            beta_g2: E::G2Affine::prime_subgroup_generator(),
+            hash: blank_hash(),
+            parameters,
+        };
+
+        for (chunk_index, chunk) in chunks.into_iter().enumerate() {
+            let remaining_g1 = g1_length.saturating_sub(accumulator.tau_powers_g1.len());
+            let remaining_other = other_length.saturating_sub(accumulator.tau_powers_g2.len());
+            if remaining_g1 == 0 && remaining_other == 0 {
+                break;
+            }
+
+            let chunk_parameters = parameters.into_chunk_parameters(ContributionMode::Chunked, chunk_index, chunk_size);
+            let (tau_g1, tau_g2, alpha_g1, beta_g1, beta_g2) = accumulator::deserialize_prefix(
+                chunk.as_ref(),
+                compression,
+                check_input_for_correctness,
+                remaining_g1,
+                remaining_other,
+                &chunk_parameters,
+            )?;
+
+            accumulator.tau_powers_g1.extend(tau_g1);
+            accumulator.tau_powers_g2.extend(tau_g2);
+            accumulator.alpha_tau_powers_g1.extend(alpha_g1);
+            accumulator.beta_tau_powers_g1.extend(beta_g1);
+            accumulator.beta_g2 = beta_g2;
+        }
+
+        // The chunks must hold the whole prefix.
+        if accumulator.tau_powers_g1.len() < g1_length {
+            return Err(Error::InsufficientElements {
+                expected: g1_length,
+                got: accumulator.tau_powers_g1.len(),
+            });
+        }
+        if accumulator.tau_powers_g2.len() < other_length {
+            return Err(Error::InsufficientElements {
+                expected: other_length,
+                got: accumulator.tau_powers_g2.len(),
+            });
+        }
+
+        Ok(accumulator)
+    }
+
+    /// Deserializes the leading elements of TauG1, TauG2 and AlphaG1, which are read to
+    /// check the initial conditions, without deserializing the rest of the input.
+    pub fn read_boundary_elements(
+        input: &[u8],
+        compression: UseCompression,
+        check_input_for_correctness: CheckForCorrectness,
+        parameters: &Phase1Parameters<E>,
+    ) -> Result<BoundaryElements<E>> {
+        let (tau_g1, tau_g2, alpha_g1) =
+            accumulator::read_boundary_elements(input, compression, check_input_for_correctness, parameters)?;
+        Ok(BoundaryElements {
+            tau_g1,
+            tau_g2,
+            alpha_g1,
+        })
+    }
+
     #[cfg(not(feature = "wasm"))]
     pub fn decompress(
         input: &[u8],
@@ -88,6 +203,160 @@ mod tests {
         }
     }
 
+    fn deserialize_prefix_curve_test<E: PairingEngine + Sync>(compress: UseCompression) {
+        // Create a small accumulator with some random state.
+        let parameters = Phase1Parameters::<E>::new_full(ProvingSystem::Groth16, 3, 2);
+        let (buffer, accumulator) = generate_random_accumulator(&parameters, compress);
+
+        // Read the prefix needed by a phase 2 circuit of 4 constraints.
+        let (g1_length, other_length) = (7, 4);
+        let prefix = Phase1::deserialize_prefix(
+            &buffer,
+            compress,
+            CheckForCorrectness::Full,
+            g1_length,
+            other_length,
+            &parameters,
+        )
+        .unwrap();
+        assert_eq!(prefix.tau_powers_g1[..], accumulator.tau_powers_g1[..g1_length]);
+        assert_eq!(prefix.tau_powers_g2[..], accumulator.tau_powers_g2[..other_length]);
+        assert_eq!(
+            prefix.alpha_tau_powers_g1[..],
+            accumulator.alpha_tau_powers_g1[..other_length]
+        );
+        assert_eq!(
+            prefix.beta_tau_powers_g1[..],
+            accumulator.beta_tau_powers_g1[..other_length]
+        );
+        assert_eq!(prefix.beta_g2, accumulator.beta_g2);
+
+        // Split the same accumulator into a chunked transcript.
+        let chunk_size = 2;
+        let chunks = (0..(parameters.powers_g1_length + chunk_size - 1) / chunk_size)
+            .map(|chunk_index| {
+                let chunk_parameters =
+                    parameters.into_chunk_parameters(ContributionMode::Chunked, chunk_index, chunk_size);
+                let start = chunk_index * chunk_size;
+                let mut chunk = generate_output(&chunk_parameters, compress);
+                accumulator::serialize(
+                    (
+                        &accumulator.tau_powers_g1[start..start + chunk_parameters.g1_chunk_size],
+                        &accumulator.tau_powers_g2[start..start + chunk_parameters.other_chunk_size],
+                        &accumulator.alpha_tau_powers_g1[start..start + chunk_parameters.other_chunk_size],
+                        &accumulator.beta_tau_powers_g1[start..start + chunk_parameters.other_chunk_size],
+                        &accumulator.beta_g2,
+                    ),
+                    &mut chunk,
+                    compress,
+                    &chunk_parameters,
+                )
+                .unwrap();
+                chunk
+            })
+            .collect::<Vec<_>>();
+
+        // Reading the prefix from the chunks must match reading it from the full transcript.
+        let chunked_prefix = Phase1::deserialize_chunked_prefix(
+            &chunks,
+            compress,
+            CheckForCorrectness::Full,
+            g1_length,
+            other_length,
+            chunk_size,
+            &parameters,
+        )
+        .unwrap();
+        assert_eq!(chunked_prefix, prefix);
+
+        // The whole transcript can be read back from its chunks as well.
+        let chunked_full = Phase1::deserialize_chunked_prefix(
+            &chunks,
+            compress,
+            CheckForCorrectness::Full,
+            parameters.powers_g1_length,
+            parameters.powers_length,
+            chunk_size,
+            &parameters,
+        )
+        .unwrap();
+        assert_eq!(chunked_full, accumulator);
+
+        // Missing chunk files must not yield a truncated prefix.
+        let result = Phase1::deserialize_chunked_prefix(
+            &chunks[..chunks.len() - 1],
+            compress,
+            CheckForCorrectness::Full,
+            parameters.powers_g1_length,
+            parameters.powers_length,
+            chunk_size,
+            &parameters,
+        );
+        assert!(matches!(result, Err(Error::InsufficientElements { .. })));
+    }
+
+    fn read_boundary_elements_curve_test<E: PairingEngine + Sync>(compress: UseCompression) {
+        for proving_system in &[ProvingSystem::Groth16, ProvingSystem::Marlin] {
+            // Create a small accumulator with some random state.
+            let parameters = Phase1Parameters::<E>::new_full(*proving_system, 3, 2);
+            let (buffer, accumulator) = generate_random_accumulator(&parameters, compress);
+
+            // The boundary must match the leading elements of a full deserialization.
+            let boundary =
+                Phase1::read_boundary_elements(&buffer, compress, CheckForCorrectness::Full, &parameters).unwrap();
+            let deserialized = Phase1::deserialize(&buffer, compress, CheckForCorrectness::Full, &parameters).unwrap();
+            assert_eq!(boundary.tau_g1[..], deserialized.tau_powers_g1[..2]);
+            assert_eq!(boundary.tau_g2[..], deserialized.tau_powers_g2[..2]);
+            assert_eq!(boundary.alpha_g1[..], deserialized.alpha_tau_powers_g1[..2]);
+            assert_eq!(deserialized, accumulator);
+        }
+
+        // A chunk holding a single power of tau in G2 returns only that power.
+        let parameters = Phase1Parameters::<E>::new_full(ProvingSystem::Groth16, 3, 2);
+        let (_, accumulator) = generate_random_accumulator(&parameters, compress);
+        let (chunk_index, chunk_size) = (1, 7);
+        let chunk_parameters = parameters.into_chunk_parameters(ContributionMode::Chunked, chunk_index, chunk_size);
+        assert_eq!(1, chunk_parameters.other_chunk_size);
+        let start = chunk_index * chunk_size;
+        let mut chunk = generate_output(&chunk_parameters, compress);
+        accumulator::serialize(
+            (
+                &accumulator.tau_powers_g1[start..start + chunk_parameters.g1_chunk_size],
+                &accumulator.tau_powers_g2[start..start + chunk_parameters.other_chunk_size],
+                &accumulator.alpha_tau_powers_g1[start..start + chunk_parameters.other_chunk_size],
+                &accumulator.beta_tau_powers_g1[start..start + chunk_parameters.other_chunk_size],
+                &accumulator.beta_g2,
+            ),
+            &mut chunk,
+            compress,
+            &chunk_parameters,
+        )
+        .unwrap();
+        let boundary =
+            Phase1::read_boundary_elements(&chunk, compress, CheckForCorrectness::Full, &chunk_parameters).unwrap();
+        assert_eq!(boundary.tau_g1[..], accumulator.tau_powers_g1[start..start + 2]);
+        assert_eq!(boundary.tau_g2[..], accumulator.tau_powers_g2[start..start + 1]);
+        assert_eq!(boundary.alpha_g1[..], accumulator.alpha_tau_powers_g1[start..start + 1]);
+    }
+
+    #[test]
+    fn test_read_boundary_elements_bls12_377() {
+        read_boundary_elements_curve_test::<Bls12_377>(UseCompression::Yes);
+        read_boundary_elements_curve_test::<Bls12_377>(UseCompression::No);
+    }
+
+    #[test]
+    fn test_read_boundary_elements_bw6_761() {
+        read_boundary_elements_curve_test::<BW6_761>(UseCompression::Yes);
+        read_boundary_elements_curve_test::<BW6_761>(UseCompression::No);
+    }
+
+    #[test]
+    fn test_deserialize_prefix_bls12_377() {
+        deserialize_prefix_curve_test::<Bls12_377>(UseCompression::Yes);
+        deserialize_prefix_curve_test::<Bls12_377>(UseCompression::No);
+    }
+
     #[test]
     fn test_serialization_bls12_377() {
         serialize_curve_test::<Bls12_377>(UseCompression::Yes, 2, 2);
@@ -20,7 +20,7 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
     /// that they're in the prime order subgroup. In the first chunk, it also checks
     /// the proofs of knowledge and that the elements were correctly multiplied.
     ///
-    #[allow(clippy::too_many_arguments, clippy::cognitive_complexity)]
+    #[allow(clippy::too_many_arguments)]
     pub fn verification(
         input: &[u8],
         output: &[u8],
@@ -31,6 +31,69 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
         check_input_for_correctness: CheckForCorrectness,
         check_output_for_correctness: CheckForCorrectness,
         parameters: &'a Phase1Parameters<E>,
+    ) -> Result<()> {
+        Self::verification_with_hasher::<Blake2b>(
+            input,
+            output,
+            key,
+            digest,
+            compressed_input,
+            compressed_output,
+            check_input_for_correctness,
+            check_output_for_correctness,
+            parameters,
+        )
+    }
+
+    ///
+    /// Phase 1 - Verification
+    ///
+    /// Verifies a transformation of the `Accumulator` with the `PublicKey`,
+    /// given a 64-byte transcript `digest` computed with the given transcript hasher.
+    ///
+    /// The proofs of knowledge are checked against the hashes into G2 computed with
+    /// the same hasher, so the contributor must have used it in `key_generation_with_hasher`.
+    ///
+    #[allow(clippy::too_many_arguments)]
+    pub fn verification_with_hasher<H: TranscriptHasher>(
+        input: &[u8],
+        output: &[u8],
+        key: &PublicKey<E>,
+        digest: &[u8],
+        compressed_input: UseCompression,
+        compressed_output: UseCompression,
+        check_input_for_correctness: CheckForCorrectness,
+        check_output_for_correctness: CheckForCorrectness,
+        parameters: &'a Phase1Parameters<E>,
+    ) -> Result<()> {
+        Self::verify::<H>(
+            input,
+            output,
+            key,
+            digest,
+            compressed_input,
+            compressed_output,
+            check_input_for_correctness,
+            check_output_for_correctness,
+            parameters,
+            None,
+        )
+    }
+
+    /// Verifies a transformation of the `Accumulator` with the `PublicKey` and the given
+    /// transcript hasher, stopping at the next batch boundary once `cancellation` is cancelled.
+    #[allow(clippy::too_many_arguments, clippy::cognitive_complexity)]
+    fn verify<H: TranscriptHasher>(
+        input: &[u8],
+        output: &[u8],
+        key: &PublicKey<E>,
+        digest: &[u8],
+        compressed_input: UseCompression,
+        compressed_output: UseCompression,
+        check_input_for_correctness: CheckForCorrectness,
+        check_output_for_correctness: CheckForCorrectness,
+        parameters: &'a Phase1Parameters<E>,
+        cancellation: Option<&Cancellation>,
     ) -> Result<()> {
         let span = info_span!("phase1-verification");
         let _ = span.enter();
@@ -38,15 +101,15 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
         info!("starting...");
 
         // Split the output buffer into its components.
-        let (tau_g1, tau_g2, alpha_g1, beta_g1, beta_g2) = split(output, parameters, compressed_output);
+        let (tau_g1, tau_g2, alpha_g1, beta_g1, beta_g2) = split(output, parameters, compressed_output)?;
 
         if parameters.contribution_mode == ContributionMode::Full || parameters.chunk_index == 0 {
             // Run proof of knowledge checks if contribution mode is on full, or this is the first chunk index.
             // Split the input buffer into its components.
             let (in_tau_g1, in_tau_g2, in_alpha_g1, in_beta_g1, in_beta_g2) =
-                split(input, parameters, compressed_input);
+                split(input, parameters, compressed_input)?;
 
-            let [tau_g2_s, alpha_g2_s, beta_g2_s] = compute_g2_s_key(&key, &digest)?;
+            let [tau_g2_s, alpha_g2_s, beta_g2_s] = compute_g2_s_key::<E, H>(&key, &digest)?;
 
             // Compose into tuple form for convenience.
             let tau_single_g1_check = &(key.tau_g1.0, key.tau_g1.1);
@@ -59,38 +122,30 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
             // Ensure the key ratios are correctly produced.
             {
                 // Check the proofs of knowledge for tau, alpha, and beta.
-                let check_ratios = &[
-                    (&(key.tau_g1.0, key.tau_g1.1), &(tau_g2_s, key.tau_g2), "Tau G1<>G2"),
-                    (
-                        &(key.alpha_g1.0, key.alpha_g1.1),
-                        &(alpha_g2_s, key.alpha_g2),
-                        "Alpha G1<>G2",
-                    ),
-                    (
-                        &(key.beta_g1.0, key.beta_g1.1),
-                        &(beta_g2_s, key.beta_g2),
-                        "Beta G1<>G2",
-                    ),
-                ];
-
-                for (a, b, err) in check_ratios {
-                    check_same_ratio::<E>(a, b, err)?;
-                }
+                verification_core::check_key_ratios::<E>(
+                    [key.tau_g1, key.alpha_g1, key.beta_g1],
+                    [tau_g2_s, alpha_g2_s, beta_g2_s],
+                    [key.tau_g2, key.alpha_g2, key.beta_g2],
+                )?;
                 debug!("key ratios were correctly produced");
             }
 
             // Ensure that the initial conditions are correctly formed (first 2 elements).
-            // We allocate a G1 vector of length 2 and re-use it for our G1 elements.
-            // We keep the values of the tau_g1 / tau_g2 elements for later use.
+            // Each check reads only the elements it needs, and a chunk which holds
+            // fewer elements fails with `Error::InsufficientElements`.
 
             // Check that tau^i was computed correctly in G1.
-            let (mut before_g1, mut after_g1) = {
-                // Previous iteration of tau_g1[0].
-                let before_g1 =
-                    read_initial_elements::<E::G1Affine>(in_tau_g1, compressed_input, check_input_for_correctness)?;
-                // Current iteration of tau_g1[0].
+            {
+                // Previous iteration of tau_g1[0] and tau_g1[1].
+                let before_g1 = read_initial_elements_n::<E::G1Affine>(
+                    in_tau_g1,
+                    compressed_input,
+                    check_input_for_correctness,
+                    2,
+                )?;
+                // Current iteration of tau_g1[0] and tau_g1[1].
                 let after_g1 =
-                    read_initial_elements::<E::G1Affine>(tau_g1, compressed_output, check_output_for_correctness)?;
+                    read_initial_elements_n::<E::G1Affine>(tau_g1, compressed_output, check_output_for_correctness, 2)?;
 
                 // Check tau_g1[0] is the prime subgroup generator.
                 if after_g1[0] != E::G1Affine::prime_subgroup_generator() {
@@ -103,18 +158,20 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
                     tau_single_g2_check,
                     "Before-After: tau_g1",
                 )?;
-
-                (before_g1, after_g1)
-            };
+            }
 
             // Check that tau^i was computed correctly in G2.
             {
-                // Previous iteration of tau_g2[0].
-                let before_g2 =
-                    read_initial_elements::<E::G2Affine>(in_tau_g2, compressed_input, check_input_for_correctness)?;
-                // Current iteration of tau_g2[0].
+                // Previous iteration of tau_g2[0] and tau_g2[1].
+                let before_g2 = read_initial_elements_n::<E::G2Affine>(
+                    in_tau_g2,
+                    compressed_input,
+                    check_input_for_correctness,
+                    2,
+                )?;
+                // Current iteration of tau_g2[0] and tau_g2[1].
                 let after_g2 =
-                    read_initial_elements::<E::G2Affine>(tau_g2, compressed_output, check_output_for_correctness)?;
+                    read_initial_elements_n::<E::G2Affine>(tau_g2, compressed_output, check_output_for_correctness, 2)?;
 
                 // Check tau_g2[0] is the prime subgroup generator.
                 if after_g2[0] != E::G2Affine::prime_subgroup_generator() {
@@ -142,8 +199,18 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
 
                 // Check that alpha_g1[0] and beta_g1[0] was multiplied correctly.
                 for (before, after, check) in &checks {
-                    before.read_batch_preallocated(&mut before_g1, compressed_input, check_input_for_correctness)?;
-                    after.read_batch_preallocated(&mut after_g1, compressed_output, check_output_for_correctness)?;
+                    let before_g1 = read_initial_elements_n::<E::G1Affine>(
+                        before,
+                        compressed_input,
+                        check_input_for_correctness,
+                        1,
+                    )?;
+                    let after_g1 = read_initial_elements_n::<E::G1Affine>(
+                        after,
+                        compressed_output,
+                        check_output_for_correctness,
+                        1,
+                    )?;
                     check_same_ratio::<E>(
                         &(before_g1[0], after_g1[0]),
                         check,
@@ -173,12 +240,19 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
 
         debug!("initial elements were computed correctly");
 
-        iter_chunk(&parameters, |start, end| {
+        iter_chunk(&parameters, cancellation, |batch| {
             // Preallocate 2 vectors per batch.
             // Ensure that the pairs are created correctly (we do this in chunks!).
             // Load `batch_size` chunks on each iteration and perform the transformation.
-
-            debug!("verifying chunk from {} to {}", start, end);
+            let (start, end) = (batch.start, batch.end);
+
+            debug!(
+                "verifying batch {} of {} from {} to {}",
+                batch.index + 1,
+                batch.total,
+                start,
+                end
+            );
 
             let span = info_span!("batch", start, end);
             let _enter = span.enter();
@@ -192,6 +266,13 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
                 ContributionMode::Full => (start, end),
             };
 
+            // Collect the result of each subgroup check, so that failures are
+            // propagated instead of panicking inside the rayon scope.
+            let mut tau_g1_result = Ok(());
+            let mut tau_g2_result = Ok(());
+            let mut alpha_g1_result = Ok(());
+            let mut beta_g1_result = Ok(());
+
             match parameters.proving_system {
                 ProvingSystem::Groth16 => {
                     rayon::scope(|t| {
@@ -203,14 +284,14 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
 
                             let mut g1 = vec![E::G1Affine::zero(); parameters.batch_size];
 
-                            check_elements_are_nonzero_and_in_prime_order_subgroup::<E::G1Affine>(
+                            tau_g1_result = check_elements_are_nonzero_and_in_prime_order_subgroup::<E::G1Affine>(
                                 (tau_g1, compressed_output),
                                 (start_chunk, end_chunk),
                                 &mut g1,
-                            )
-                            .expect("could not check element are non zero and in prime order subgroup");
+                                ElementType::TauG1,
+                            );
 
-                            trace!("tau_g1 verification was successful");
+                            trace!("tau_g1 verification was completed");
                         });
 
                         if start < parameters.powers_length {
@@ -244,14 +325,15 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
 
                                     let mut g2 = vec![E::G2Affine::zero(); parameters.batch_size];
 
-                                    check_elements_are_nonzero_and_in_prime_order_subgroup::<E::G2Affine>(
-                                        (tau_g2, compressed_output),
-                                        (start_chunk, end_chunk),
-                                        &mut g2,
-                                    )
-                                    .expect("could not check element are non zero and in prime order subgroup");
+                                    tau_g2_result =
+                                        check_elements_are_nonzero_and_in_prime_order_subgroup::<E::G2Affine>(
+                                            (tau_g2, compressed_output),
+                                            (start_chunk, end_chunk),
+                                            &mut g2,
+                                            ElementType::TauG2,
+                                        );
 
-                                    trace!("tau_g2 verification was successful");
+                                    trace!("tau_g2 verification was completed");
                                 });
 
                                 // Process alpha_g1 elements.
@@ -260,14 +342,15 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
 
                                     let mut g1 = vec![E::G1Affine::zero(); parameters.batch_size];
 
-                                    check_elements_are_nonzero_and_in_prime_order_subgroup::<E::G1Affine>(
-                                        (alpha_g1, compressed_output),
-                                        (start_chunk, end_chunk),
-                                        &mut g1,
-                                    )
-                                    .expect("could not check element are non zero and in prime order subgroup");
+                                    alpha_g1_result =
+                                        check_elements_are_nonzero_and_in_prime_order_subgroup::<E::G1Affine>(
+                                            (alpha_g1, compressed_output),
+                                            (start_chunk, end_chunk),
+                                            &mut g1,
+                                            ElementType::AlphaG1,
+                                        );
 
-                                    trace!("alpha_g1 verification was successful");
+                                    trace!("alpha_g1 verification was completed");
                                 });
 
                                 // Process beta_g1 elements.
@@ -276,14 +359,15 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
 
                                     let mut g1 = vec![E::G1Affine::zero(); parameters.batch_size];
 
-                                    check_elements_are_nonzero_and_in_prime_order_subgroup::<E::G1Affine>(
-                                        (beta_g1, compressed_output),
-                                        (start_chunk, end_chunk),
-                                        &mut g1,
-                                    )
-                                    .expect("could not check element are non zero and in prime order subgroup");
+                                    beta_g1_result =
+                                        check_elements_are_nonzero_and_in_prime_order_subgroup::<E::G1Affine>(
+                                            (beta_g1, compressed_output),
+                                            (start_chunk, end_chunk),
+                                            &mut g1,
+                                            ElementType::BetaG1,
+                                        );
 
-                                    trace!("beta_g1 verification was successful");
+                                    trace!("beta_g1 verification was completed");
                                 });
                             });
                         }
@@ -299,55 +383,61 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
 
                             let mut g1 = vec![E::G1Affine::zero(); parameters.batch_size];
 
-                            check_elements_are_nonzero_and_in_prime_order_subgroup::<E::G1Affine>(
+                            tau_g1_result = check_elements_are_nonzero_and_in_prime_order_subgroup::<E::G1Affine>(
                                 (tau_g1, compressed_output),
                                 (start_chunk, end_chunk),
                                 &mut g1,
-                            )
-                            .expect("could not check ratios for tau_g1 elements");
+                                ElementType::TauG1,
+                            );
 
-                            trace!("tau_g1 verification was successful");
+                            trace!("tau_g1 verification was completed");
                         });
 
                         if start == 0 {
                             t.spawn(|_| {
                                 let _ = span.enter();
 
-                                let mut g1 = vec![E::G1Affine::zero(); parameters.batch_size];
-
                                 let num_alpha_powers = 3;
 
                                 let start_chunk = 0;
                                 let end_chunk = num_alpha_powers + 3 * parameters.total_size_in_log2;
 
-                                check_elements_are_nonzero_and_in_prime_order_subgroup::<E::G1Affine>(
+                                // The powers in alpha_g1 are checked at once, so may exceed the batch size.
+                                let mut g1 = vec![E::G1Affine::zero(); end_chunk];
+
+                                alpha_g1_result = check_elements_are_nonzero_and_in_prime_order_subgroup::<E::G1Affine>(
                                     (alpha_g1, compressed_output),
                                     (start_chunk, end_chunk),
                                     &mut g1,
-                                )
-                                .expect("could not check ratios for tau_g1 elements");
+                                    ElementType::AlphaG1,
+                                );
 
-                                trace!("alpha_g1 verification was successful");
+                                trace!("alpha_g1 verification was completed");
 
                                 let start_chunk = 0;
                                 let end_chunk = parameters.total_size_in_log2 + 2;
 
-                                let mut g2 = vec![E::G2Affine::zero(); parameters.batch_size];
+                                let mut g2 = vec![E::G2Affine::zero(); end_chunk];
 
-                                check_elements_are_nonzero_and_in_prime_order_subgroup::<E::G2Affine>(
+                                tau_g2_result = check_elements_are_nonzero_and_in_prime_order_subgroup::<E::G2Affine>(
                                     (tau_g2, compressed_output),
                                     (start_chunk, end_chunk),
                                     &mut g2,
-                                )
-                                .expect("could not check element are non zero and in prime order subgroup");
+                                    ElementType::TauG2,
+                                );
 
-                                trace!("tau_g2 verification was successful");
+                                trace!("tau_g2 verification was completed");
                             });
                         }
                     });
                 }
             }
 
+            tau_g1_result?;
+            tau_g2_result?;
+            alpha_g1_result?;
+            beta_g1_result?;
+
             debug!("batch verification successful");
 
             Ok(())
@@ -358,6 +448,130 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
         Ok(())
     }
 
+    ///
+    /// Phase 1 - Verification
+    ///
+    /// Verifies a transformation of the `Accumulator` with the `PublicKey`,
+    /// given a 64-byte transcript `digest`, using at most `thread_budget` threads.
+    ///
+    /// The chunks and the element checks spawned within each chunk all run on
+    /// a dedicated thread pool of `thread_budget` threads, so the verification
+    /// never runs more tasks concurrently than the given budget.
+    ///
+    #[allow(clippy::too_many_arguments)]
+    pub fn verification_with_thread_budget(
+        input: &[u8],
+        output: &[u8],
+        key: &PublicKey<E>,
+        digest: &[u8],
+        compressed_input: UseCompression,
+        compressed_output: UseCompression,
+        check_input_for_correctness: CheckForCorrectness,
+        check_output_for_correctness: CheckForCorrectness,
+        parameters: &'a Phase1Parameters<E>,
+        thread_budget: usize,
+    ) -> Result<()> {
+        if thread_budget == 0 {
+            return Err(Error::InvalidThreadBudget(thread_budget));
+        }
+
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(thread_budget)
+            .build()
+            .map_err(|_| Error::InvalidThreadBudget(thread_budget))?;
+
+        pool.install(|| {
+            Self::verification(
+                input,
+                output,
+                key,
+                digest,
+                compressed_input,
+                compressed_output,
+                check_input_for_correctness,
+                check_output_for_correctness,
+                parameters,
+            )
+        })
+    }
+
+    ///
+    /// Phase 1 - Verification
+    ///
+    /// Verifies a transformation of the `Accumulator` with the `PublicKey`,
+    /// given a 64-byte transcript `digest`, and the expected hash of the output, if known.
+    ///
+    /// The hash of the output is checked before the pairing checks, so an output which
+    /// was corrupted in transport is rejected cheaply with `OutputHashMismatch`.
+    ///
+    #[allow(clippy::too_many_arguments)]
+    pub fn verification_with_expected_hash(
+        input: &[u8],
+        output: &[u8],
+        key: &PublicKey<E>,
+        digest: &[u8],
+        compressed_input: UseCompression,
+        compressed_output: UseCompression,
+        check_input_for_correctness: CheckForCorrectness,
+        check_output_for_correctness: CheckForCorrectness,
+        parameters: &'a Phase1Parameters<E>,
+        expected_output_hash: Option<&[u8]>,
+    ) -> Result<()> {
+        if let Some(expected_output_hash) = expected_output_hash {
+            if calculate_hash(output).as_slice() != expected_output_hash {
+                return Err(VerificationError::OutputHashMismatch.into());
+            }
+        }
+
+        Self::verification(
+            input,
+            output,
+            key,
+            digest,
+            compressed_input,
+            compressed_output,
+            check_input_for_correctness,
+            check_output_for_correctness,
+            parameters,
+        )
+    }
+
+    ///
+    /// Phase 1 - Verification
+    ///
+    /// Verifies a transformation of the `Accumulator` with the `PublicKey`,
+    /// given a 64-byte transcript `digest`, until the given `cancellation` is cancelled.
+    ///
+    /// The flag is checked before each batch, so a cancelled verification stops
+    /// at the next batch boundary with `Error::Cancelled`.
+    ///
+    #[allow(clippy::too_many_arguments)]
+    pub fn verification_with_cancellation(
+        input: &[u8],
+        output: &[u8],
+        key: &PublicKey<E>,
+        digest: &[u8],
+        compressed_input: UseCompression,
+        compressed_output: UseCompression,
+        check_input_for_correctness: CheckForCorrectness,
+        check_output_for_correctness: CheckForCorrectness,
+        parameters: &'a Phase1Parameters<E>,
+        cancellation: &Cancellation,
+    ) -> Result<()> {
+        Self::verify::<Blake2b>(
+            input,
+            output,
+            key,
+            digest,
+            compressed_input,
+            compressed_output,
+            check_input_for_correctness,
+            check_output_for_correctness,
+            parameters,
+            Some(cancellation),
+        )
+    }
+
     /// Verifies that the accumulator was transformed correctly
     /// given the `PublicKey` and the so-far hash of the accumulator.
     /// This verifies the ratios in a given accumulator.
@@ -370,28 +584,27 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
 
         info!("starting...");
 
-        let (tau_g1, tau_g2, alpha_g1, beta_g1, _) = split(output, parameters, compressed_output);
+        let (tau_g1, tau_g2, alpha_g1, beta_g1, _) = split(output, parameters, compressed_output)?;
 
         let (g1_check, g2_check, g1_alpha_check) = {
             // Ensure that the initial conditions are correctly formed (first 2 elements)
-            // We allocate a G1 vector of length 2 and re-use it for our G1 elements.
             // We keep the values of the tau_g1 / tau_g2 elements for later use.
 
-            // Current iteration of tau_g1[0].
+            // Current iteration of tau_g1[0] and tau_g1[1].
             let after_g1 =
-                read_initial_elements::<E::G1Affine>(tau_g1, compressed_output, check_output_for_correctness)?;
+                read_initial_elements_n::<E::G1Affine>(tau_g1, compressed_output, check_output_for_correctness, 2)?;
 
-            // Current iteration of tau_g2[0].
+            // Current iteration of tau_g2[0] and tau_g2[1].
             let after_g2 =
-                read_initial_elements::<E::G2Affine>(tau_g2, compressed_output, check_output_for_correctness)?;
+                read_initial_elements_n::<E::G2Affine>(tau_g2, compressed_output, check_output_for_correctness, 2)?;
 
             // Fetch the iteration of alpha_g1[0].
             let after_alpha_g1 =
-                read_initial_elements::<E::G1Affine>(alpha_g1, compressed_output, check_output_for_correctness)?;
+                read_initial_elements_n::<E::G1Affine>(alpha_g1, compressed_output, check_output_for_correctness, 1)?;
 
             let g1_check = (after_g1[0], after_g1[1]);
             let g2_check = (after_g2[0], after_g2[1]);
-            let g1_alpha_check = (after_alpha_g1[0], after_alpha_g1[1]);
+            let g1_alpha_check = after_alpha_g1[0];
 
             (g1_check, g2_check, g1_alpha_check)
         };
@@ -403,16 +616,21 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
             // Ensure that the pairs are created correctly (we do this in chunks!)
             // load `batch_size` chunks on each iteration and perform the transformation
             ProvingSystem::Groth16 => {
-                iter_chunk(&parameters, |start, end| {
-                    debug!("verifying batch from {} to {}", start, end);
+                iter_chunk(&parameters, None, |batch| {
+                    let (start, end) = (batch.start, batch.end);
+                    debug!(
+                        "verifying batch {} of {} from {} to {}",
+                        batch.index + 1,
+                        batch.total,
+                        start,
+                        end
+                    );
 
                     let span = info_span!("batch", start, end);
                     let _enter = span.enter();
 
-                    rayon::scope(|t| {
-                        let _enter = span.enter();
-
-                        t.spawn(|_| {
+                    let (tau_g1_checked, powers_checked) = rayon::join(
+                        || -> Result<()> {
                             let _enter = span.enter();
 
                             let mut g1 = vec![E::G1Affine::zero(); parameters.batch_size];
@@ -422,13 +640,16 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
                                 (start, end),
                                 &mut g1,
                                 &g2_check,
-                            )
-                            .expect("could not check ratios for tau_g1 elements");
+                            )?;
 
                             trace!("tau_g1 verification successful");
-                        });
+                            Ok(())
+                        },
+                        || -> Result<()> {
+                            if start >= parameters.powers_length {
+                                return Ok(());
+                            }
 
-                        if start < parameters.powers_length {
                             // if the `end` would be out of bounds, then just process until
                             // the end (this is necessary in case the last batch would try to
                             // process more elements than available)
@@ -438,10 +659,8 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
                                 end
                             };
 
-                            rayon::scope(|t| {
-                                let _enter = span.enter();
-
-                                t.spawn(|_| {
+                            let (tau_g2_checked, (alpha_g1_checked, beta_g1_checked)) = rayon::join(
+                                || -> Result<()> {
                                     let _enter = span.enter();
 
                                     let mut g2 = vec![E::G2Affine::zero(); parameters.batch_size];
@@ -451,46 +670,53 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
                                         (start, end),
                                         &mut g2,
                                         &g1_check,
-                                    )
-                                    .expect("could not check ratios for tau_g2 elements");
+                                    )?;
 
                                     trace!("tau_g2 verification successful");
-                                });
-
-                                t.spawn(|_| {
-                                    let _enter = span.enter();
-
-                                    let mut g1 = vec![E::G1Affine::zero(); parameters.batch_size];
-
-                                    check_power_ratios::<E>(
-                                        (alpha_g1, compressed_output, check_output_for_correctness),
-                                        (start, end),
-                                        &mut g1,
-                                        &g2_check,
-                                    )
-                                    .expect("could not check ratios for alpha_g1 elements");
-
-                                    trace!("alpha_g1 verification successful");
-                                });
-
-                                t.spawn(|_| {
-                                    let _enter = span.enter();
-
-                                    let mut g1 = vec![E::G1Affine::zero(); parameters.batch_size];
-
-                                    check_power_ratios::<E>(
-                                        (beta_g1, compressed_output, check_output_for_correctness),
-                                        (start, end),
-                                        &mut g1,
-                                        &g2_check,
+                                    Ok(())
+                                },
+                                || {
+                                    rayon::join(
+                                        || -> Result<()> {
+                                            let _enter = span.enter();
+
+                                            let mut g1 = vec![E::G1Affine::zero(); parameters.batch_size];
+
+                                            check_power_ratios::<E>(
+                                                (alpha_g1, compressed_output, check_output_for_correctness),
+                                                (start, end),
+                                                &mut g1,
+                                                &g2_check,
+                                            )?;
+
+                                            trace!("alpha_g1 verification successful");
+                                            Ok(())
+                                        },
+                                        || -> Result<()> {
+                                            let _enter = span.enter();
+
+                                            let mut g1 = vec![E::G1Affine::zero(); parameters.batch_size];
+
+                                            check_power_ratios::<E>(
+                                                (beta_g1, compressed_output, check_output_for_correctness),
+                                                (start, end),
+                                                &mut g1,
+                                                &g2_check,
+                                            )?;
+
+                                            trace!("beta_g1 verification successful");
+                                            Ok(())
+                                        },
                                     )
-                                    .expect("could not check ratios for beta_g1 elements");
-
-                                    trace!("beta_g1 verification successful");
-                                });
-                            });
-                        }
-                    });
+                                },
+                            );
+                            tau_g2_checked?;
+                            alpha_g1_checked?;
+                            beta_g1_checked
+                        },
+                    );
+                    tau_g1_checked?;
+                    powers_checked?;
 
                     debug!("chunk verification successful");
 
@@ -498,16 +724,21 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
                 })?;
             }
             ProvingSystem::Marlin => {
-                iter_chunk(&parameters, |start, end| {
-                    debug!("verifying batch from {} to {}", start, end);
+                iter_chunk(&parameters, None, |batch| {
+                    let (start, end) = (batch.start, batch.end);
+                    debug!(
+                        "verifying batch {} of {} from {} to {}",
+                        batch.index + 1,
+                        batch.total,
+                        start,
+                        end
+                    );
 
                     let span = info_span!("batch", start, end);
                     let _enter = span.enter();
 
-                    rayon::scope(|t| {
-                        let _enter = span.enter();
-
-                        t.spawn(|_| {
+                    let (tau_g1_checked, powers_of_two_checked) = rayon::join(
+                        || -> Result<()> {
                             let _enter = span.enter();
 
                             let mut g1 = vec![E::G1Affine::zero(); parameters.batch_size];
@@ -517,13 +748,14 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
                                 (start, end),
                                 &mut g1,
                                 &g2_check,
-                            )
-                            .expect("could not check ratios for tau_g1 elements");
+                            )?;
 
                             trace!("tau_g1 verification successful");
-                        });
+                            Ok(())
+                        },
+                        || -> Result<()> {
+                            let _enter = span.enter();
 
-                        {
                             let powers_of_two_in_range = (0..parameters.total_size_in_log2)
                                 .map(|i| (i, parameters.powers_length as u64 - 1 - (1 << i) + 2))
                                 .map(|(i, p)| (i, p as usize))
@@ -534,61 +766,65 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
                                 let g1_size = buffer_size::<E::G1Affine>(compressed_output);
                                 let g2_size = buffer_size::<E::G2Affine>(compressed_output);
 
-                                let g1 = (&tau_g1[p * g1_size..(p + 1) * g1_size])
-                                    .read_element(compressed_output, check_output_for_correctness)
-                                    .expect("should have read g1 element");
-                                let g2 = (&tau_g2[(2 + i) * g2_size..(2 + i + 1) * g2_size])
-                                    .read_element(compressed_output, check_output_for_correctness)
-                                    .expect("should have read g2 element");
+                                let g1 = read_initial_elements_n::<E::G1Affine>(
+                                    tau_g1.get(p * g1_size..).unwrap_or_default(),
+                                    compressed_output,
+                                    check_output_for_correctness,
+                                    1,
+                                )?[0];
+                                let g2 = read_initial_elements_n::<E::G2Affine>(
+                                    tau_g2.get((2 + i) * g2_size..).unwrap_or_default(),
+                                    compressed_output,
+                                    check_output_for_correctness,
+                                    1,
+                                )?[0];
                                 check_same_ratio::<E>(
                                     &(g1, E::G1Affine::prime_subgroup_generator()),
                                     &(E::G2Affine::prime_subgroup_generator(), g2),
                                     "G1<>G2",
-                                )
-                                .expect("should have checked same ratio");
-
-                                let mut alpha_g1_elements = vec![E::G1Affine::zero(); 3];
-                                (&alpha_g1[(3 + 3 * i) * g1_size..(3 + 3 * i + 3) * g1_size])
-                                    .read_batch_preallocated(
-                                        &mut alpha_g1_elements,
-                                        compressed_output,
-                                        check_output_for_correctness,
-                                    )
-                                    .expect("should have read alpha g1 elements");
+                                )?;
+
+                                let alpha_g1_elements = read_initial_elements_n::<E::G1Affine>(
+                                    alpha_g1.get((3 + 3 * i) * g1_size..).unwrap_or_default(),
+                                    compressed_output,
+                                    check_output_for_correctness,
+                                    3,
+                                )?;
                                 check_same_ratio::<E>(
                                     &(alpha_g1_elements[0], alpha_g1_elements[1]),
                                     &g2_check,
                                     "alpha_g1 ratio 1",
-                                )
-                                .expect("should have checked same ratio");
+                                )?;
                                 check_same_ratio::<E>(
                                     &(alpha_g1_elements[1], alpha_g1_elements[2]),
                                     &g2_check,
                                     "alpha_g1 ratio 2",
-                                )
-                                .expect("should have checked same ratio");
+                                )?;
                                 check_same_ratio::<E>(
-                                    &(alpha_g1_elements[0], g1_alpha_check.0),
+                                    &(alpha_g1_elements[0], g1_alpha_check),
                                     &(E::G2Affine::prime_subgroup_generator(), g2),
                                     "alpha consistent",
-                                )
-                                .expect("should have checked same ratio");
+                                )?;
                             }
-                        }
-                    });
 
-                    // This is the first batch, check alpha_g1. batch size is guaranteed to be of size >= 3
+                            Ok(())
+                        },
+                    );
+                    tau_g1_checked?;
+                    powers_of_two_checked?;
+
+                    // This is the first batch, check alpha_g1. The powers of alpha are read
+                    // independently of the batch, so the batch size may be smaller than them.
                     if start == 0 {
                         let num_alpha_powers = 3;
-                        let mut g1 = vec![E::G1Affine::zero(); num_alpha_powers];
+                        let g1 = read_initial_elements_n::<E::G1Affine>(
+                            alpha_g1,
+                            compressed_output,
+                            check_output_for_correctness,
+                            num_alpha_powers,
+                        )?;
 
-                        check_power_ratios::<E>(
-                            (alpha_g1, compressed_output, check_output_for_correctness),
-                            (0, num_alpha_powers),
-                            &mut g1,
-                            &g2_check,
-                        )
-                        .expect("could not check ratios for alpha_g1");
+                        check_same_ratio::<E>(&power_pairs(&g1), &g2_check, "Power pairs")?;
 
                         trace!("alpha_g1 verification was successful");
 
@@ -599,8 +835,7 @@ impl<'a, E: PairingEngine + Sync> Phase1<'a, E> {
                             (0, 2),
                             &mut g2,
                             &g1_check,
-                        )
-                        .expect("could not check ratios for tau_g2");
+                        )?;
 
                         trace!("tau_g2 verification was successful");
                     }
@@ -710,6 +945,19 @@ mod tests {
                 Phase1::aggregate_verification((&output_2, compressed_output, CheckForCorrectness::Full), &parameters);
             assert!(res.is_ok());
 
+            // aggregate verification returns an error, rather than panicking, if an alpha element is corrupted
+            let mut corrupted = output_2.clone();
+            {
+                let g1_size = buffer_size::<E::G1Affine>(compressed_output);
+                let (_, _, alpha_g1, _, _) = split_mut(&mut corrupted, &parameters, compressed_output).unwrap();
+                for byte in &mut alpha_g1[4 * g1_size..5 * g1_size] {
+                    *byte = !*byte;
+                }
+            }
+            let res =
+                Phase1::aggregate_verification((&corrupted, compressed_output, CheckForCorrectness::Full), &parameters);
+            assert!(res.is_err());
+
             // verification will fail if the old hash is used
             let res = Phase1::verification(
                 &output,
@@ -908,6 +1156,408 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_chunk_verification_names_corrupted_element_type() {
+        let compressed = UseCompression::No;
+        let correctness = CheckForCorrectness::Full;
+        let batch = 3 + 3 * 4;
+        let parameters = Phase1Parameters::<Bls12_377>::new_chunk(
+            ContributionMode::Chunked,
+            0,
+            batch,
+            ProvingSystem::Groth16,
+            4,
+            batch,
+        );
+
+        // Start with an empty hash as this is the first time.
+        let digest = blank_hash();
+
+        // Construct the contributor's keypair.
+        let (public_key, private_key) = {
+            let mut rng = derive_rng_from_seed(b"test_verify_transformation 1");
+            Phase1::<Bls12_377>::key_generation(&mut rng, digest.as_ref()).expect("could not generate keypair")
+        };
+
+        // Compute a chunked contribution.
+        let (input, _) = generate_input(&parameters, compressed, correctness);
+        let mut output = generate_output(&parameters, compressed);
+        Phase1::computation(
+            &input,
+            &mut output,
+            compressed,
+            compressed,
+            correctness,
+            &private_key,
+            &parameters,
+        )
+        .unwrap();
+        drop(private_key);
+
+        // Corrupt a beta_g1 element past the initial elements checked by the proofs of knowledge.
+        {
+            let g1_size = buffer_size::<<Bls12_377 as PairingEngine>::G1Affine>(compressed);
+            let (_, _, _, beta_g1, _) = split_mut(&mut output, &parameters, compressed).unwrap();
+            for byte in &mut beta_g1[2 * g1_size..3 * g1_size] {
+                *byte = 0xff;
+            }
+        }
+
+        let error = Phase1::verification(
+            &input,
+            &output,
+            &public_key,
+            &digest,
+            compressed,
+            compressed,
+            correctness,
+            correctness,
+            &parameters,
+        )
+        .unwrap_err();
+        match &error {
+            Error::InvalidElements(ElementType::BetaG1, _) | Error::IncorrectSubgroup(ElementType::BetaG1) => {}
+            error => panic!("expected a BetaG1 error, found {}", error),
+        }
+        assert!(format!("{}", error).contains("BetaG1"));
+    }
+
+    /// BLAKE2b over a domain separator, standing in for an alternative transcript hash function.
+    struct DomainSeparatedHasher(Blake2b);
+
+    impl Default for DomainSeparatedHasher {
+        fn default() -> Self {
+            let mut hasher = Blake2b::default();
+            hasher.update(b"alternative transcript hasher");
+            Self(hasher)
+        }
+    }
+
+    impl TranscriptHasher for DomainSeparatedHasher {
+        fn update(&mut self, data: &[u8]) {
+            self.0.update(data);
+        }
+
+        fn finalize(self) -> GenericArray<u8, U64> {
+            self.0.finalize()
+        }
+    }
+
+    #[test]
+    fn test_verification_with_cancellation() {
+        let compressed = UseCompression::No;
+        let correctness = CheckForCorrectness::Full;
+        let parameters = Phase1Parameters::<Bls12_377>::new_full(ProvingSystem::Groth16, 4, 4);
+        let mut rng = derive_rng_from_seed(b"test_verification_with_cancellation");
+
+        let digest = blank_hash();
+        let (input, _) = generate_input(&parameters, compressed, correctness);
+        let (public_key, private_key) =
+            Phase1::key_generation(&mut rng, digest.as_ref()).expect("could not generate keypair");
+
+        // A computation which was cancelled stops with the cancelled error.
+        let cancelled = Cancellation::new();
+        cancelled.cancel();
+        let mut output = generate_output(&parameters, compressed);
+        let result = Phase1::computation_with_cancellation(
+            &input,
+            &mut output,
+            compressed,
+            compressed,
+            correctness,
+            &private_key,
+            &parameters,
+            &cancelled,
+        );
+        assert!(matches!(result, Err(Error::Cancelled)));
+
+        // A computation which was not cancelled runs to completion.
+        let cancellation = Cancellation::new();
+        Phase1::computation_with_cancellation(
+            &input,
+            &mut output,
+            compressed,
+            compressed,
+            correctness,
+            &private_key,
+            &parameters,
+            &cancellation,
+        )
+        .unwrap();
+        drop(private_key);
+
+        // A verification which was cancelled stops with the cancelled error.
+        let result = Phase1::verification_with_cancellation(
+            &input,
+            &output,
+            &public_key,
+            &digest,
+            compressed,
+            compressed,
+            correctness,
+            correctness,
+            &parameters,
+            &cancelled,
+        );
+        assert!(matches!(result, Err(Error::Cancelled)));
+
+        // The cancelled verification left the accumulator intact, so it verifies once not cancelled.
+        Phase1::verification_with_cancellation(
+            &input,
+            &output,
+            &public_key,
+            &digest,
+            compressed,
+            compressed,
+            correctness,
+            correctness,
+            &parameters,
+            &cancellation,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_verification_with_alternative_hasher() {
+        type H = DomainSeparatedHasher;
+
+        let compressed = UseCompression::No;
+        let correctness = CheckForCorrectness::Full;
+        let parameters = Phase1Parameters::<Bls12_377>::new_full(ProvingSystem::Groth16, 2, 2);
+        let mut rng = derive_rng_from_seed(b"test_verification_with_alternative_hasher");
+
+        // Contribute twice, chaining the running hash of the accumulator.
+        let (mut input, _) = generate_input(&parameters, compressed, correctness);
+        let mut digest = H::blank_hash();
+        assert_ne!(blank_hash(), digest);
+        for _ in 0..2 {
+            let (public_key, private_key) = Phase1::key_generation_with_hasher::<H, _>(&mut rng, digest.as_ref())
+                .expect("could not generate keypair");
+            let mut output = generate_output(&parameters, compressed);
+            Phase1::computation(
+                &input,
+                &mut output,
+                compressed,
+                compressed,
+                correctness,
+                &private_key,
+                &parameters,
+            )
+            .unwrap();
+            drop(private_key);
+
+            // The contribution verifies with the hasher it was made with.
+            Phase1::verification_with_hasher::<H>(
+                &input,
+                &output,
+                &public_key,
+                &digest,
+                compressed,
+                compressed,
+                correctness,
+                correctness,
+                &parameters,
+            )
+            .unwrap();
+
+            // The proofs of knowledge do not verify with the default hasher.
+            assert!(Phase1::verification(
+                &input,
+                &output,
+                &public_key,
+                &digest,
+                compressed,
+                compressed,
+                correctness,
+                correctness,
+                &parameters,
+            )
+            .is_err());
+
+            digest = H::calculate_hash(&output);
+            input = output;
+        }
+    }
+
+    fn thread_budget_verification_test<E: PairingEngine>(parameters: &Phase1Parameters<E>) {
+        let compressed = UseCompression::No;
+        let correctness = CheckForCorrectness::Full;
+
+        // Start with an empty hash as this is the first time.
+        let digest = blank_hash();
+
+        // Construct the contributor's keypair.
+        let (public_key, private_key) = {
+            let mut rng = derive_rng_from_seed(b"test_verification_thread_budget");
+            Phase1::<E>::key_generation(&mut rng, digest.as_ref()).expect("could not generate keypair")
+        };
+
+        // Compute a contribution.
+        let (input, _) = generate_input(&parameters, compressed, correctness);
+        let mut output = generate_output(&parameters, compressed);
+        Phase1::computation(
+            &input,
+            &mut output,
+            compressed,
+            compressed,
+            correctness,
+            &private_key,
+            &parameters,
+        )
+        .unwrap();
+        drop(private_key);
+
+        // Corrupt a tau_g1 element past the initial elements checked by the proofs of knowledge.
+        let mut corrupted = output.clone();
+        {
+            let g1_size = buffer_size::<E::G1Affine>(compressed);
+            let (tau_g1, _, _, _, _) = split_mut(&mut corrupted, &parameters, compressed).unwrap();
+            for byte in &mut tau_g1[2 * g1_size..3 * g1_size] {
+                *byte = 0xff;
+            }
+        }
+
+        let verify = |output: &[u8], thread_budget: Option<usize>| match thread_budget {
+            Some(thread_budget) => Phase1::verification_with_thread_budget(
+                &input,
+                output,
+                &public_key,
+                &digest,
+                compressed,
+                compressed,
+                correctness,
+                correctness,
+                &parameters,
+                thread_budget,
+            ),
+            None => Phase1::verification(
+                &input,
+                output,
+                &public_key,
+                &digest,
+                compressed,
+                compressed,
+                correctness,
+                correctness,
+                &parameters,
+            ),
+        };
+
+        // The result must not depend on the thread budget.
+        let expected = verify(&corrupted, None).unwrap_err().to_string();
+        verify(&output, None).unwrap();
+        for thread_budget in &[1, 4] {
+            verify(&output, Some(*thread_budget)).unwrap();
+            assert_eq!(
+                expected,
+                verify(&corrupted, Some(*thread_budget)).unwrap_err().to_string()
+            );
+        }
+    }
+
+    #[test]
+    fn test_verification_with_thread_budget() {
+        let batch = 3 + 3 * 4;
+        for proving_system in &[ProvingSystem::Groth16, ProvingSystem::Marlin] {
+            thread_budget_verification_test(&Phase1Parameters::<Bls12_377>::new_full(*proving_system, 4, batch));
+            thread_budget_verification_test(&Phase1Parameters::<Bls12_377>::new_chunk(
+                ContributionMode::Chunked,
+                0,
+                batch,
+                *proving_system,
+                4,
+                batch,
+            ));
+        }
+
+        // A budget of zero threads is rejected.
+        let parameters = Phase1Parameters::<Bls12_377>::new_full(ProvingSystem::Groth16, 4, batch);
+        let (input, _) = generate_input(&parameters, UseCompression::No, CheckForCorrectness::No);
+        let digest = blank_hash();
+        let mut rng = derive_rng_from_seed(b"test_verification_thread_budget");
+        let (public_key, _) = Phase1::<Bls12_377>::key_generation(&mut rng, digest.as_ref()).unwrap();
+        match Phase1::verification_with_thread_budget(
+            &input,
+            &input,
+            &public_key,
+            &digest,
+            UseCompression::No,
+            UseCompression::No,
+            CheckForCorrectness::No,
+            CheckForCorrectness::No,
+            &parameters,
+            0,
+        ) {
+            Err(Error::InvalidThreadBudget(0)) => {}
+            result => panic!("expected an invalid thread budget, found {:?}", result),
+        }
+    }
+
+    #[test]
+    fn test_verification_with_expected_hash() {
+        let parameters = Phase1Parameters::<Bls12_377>::new_full(ProvingSystem::Groth16, 4, 3 + 3 * 4);
+        let compressed = UseCompression::No;
+        let correctness = CheckForCorrectness::Full;
+
+        // Start with an empty hash as this is the first time.
+        let digest = blank_hash();
+
+        // Construct the contributor's keypair.
+        let (public_key, private_key) = {
+            let mut rng = derive_rng_from_seed(b"test_verification_with_expected_hash");
+            Phase1::<Bls12_377>::key_generation(&mut rng, digest.as_ref()).expect("could not generate keypair")
+        };
+
+        // Compute a contribution.
+        let (input, _) = generate_input(&parameters, compressed, correctness);
+        let mut output = generate_output(&parameters, compressed);
+        Phase1::computation(
+            &input,
+            &mut output,
+            compressed,
+            compressed,
+            correctness,
+            &private_key,
+            &parameters,
+        )
+        .unwrap();
+        drop(private_key);
+
+        let verify = |output: &[u8], expected_output_hash: Option<&[u8]>| {
+            Phase1::verification_with_expected_hash(
+                &input,
+                output,
+                &public_key,
+                &digest,
+                compressed,
+                compressed,
+                correctness,
+                correctness,
+                &parameters,
+                expected_output_hash,
+            )
+        };
+
+        // The output is verified with its hash, or without an expected hash.
+        let output_hash = calculate_hash(&output);
+        verify(&output, Some(output_hash.as_slice())).unwrap();
+        verify(&output, None).unwrap();
+
+        // A valid output is rejected before the pairing checks if the expected hash differs.
+        match verify(&output, Some(blank_hash().as_slice())) {
+            Err(Error::VerificationError(VerificationError::OutputHashMismatch)) => {}
+            result => panic!("expected an output hash mismatch, found {:?}", result),
+        }
+
+        // A corrupted output is rejected with its expected hash.
+        let mut corrupted = output.clone();
+        *corrupted.last_mut().unwrap() ^= 1;
+        match verify(&corrupted, Some(output_hash.as_slice())) {
+            Err(Error::VerificationError(VerificationError::OutputHashMismatch)) => {}
+            result => panic!("expected an output hash mismatch, found {:?}", result),
+        }
+    }
+
     #[test]
     fn test_verification_bls12_377() {
         full_verification_test::<Bls12_377>(4, 3 + 3 * 4, UseCompression::Yes, UseCompression::Yes);
@@ -930,4 +1580,22 @@ mod tests {
         chunk_verification_test::<Bls12_377>(4, 3 + 3 * 4, UseCompression::No, UseCompression::No);
         chunk_verification_test::<Bls12_377>(4, 3 + 3 * 4, UseCompression::Yes, UseCompression::No);
     }
+
+    #[test]
+    fn test_verification_batch_smaller_than_initial_elements() {
+        // The batch sizes are smaller than the powers of alpha checked with Marlin.
+        for batch in &[2, 3] {
+            full_verification_test::<Bls12_377>(4, *batch, UseCompression::Yes, UseCompression::No);
+            full_verification_test::<BW6_761>(4, *batch, UseCompression::No, UseCompression::Yes);
+        }
+    }
+
+    #[test]
+    fn test_chunk_verification_batch_smaller_than_initial_elements() {
+        // The batch sizes are smaller than the powers of alpha checked with Marlin.
+        for batch in &[2, 3] {
+            chunk_verification_test::<Bls12_377>(4, *batch, UseCompression::Yes, UseCompression::No);
+            chunk_verification_test::<BW6_761>(4, *batch, UseCompression::No, UseCompression::Yes);
+        }
+    }
 }
@@ -0,0 +1,317 @@
+//! The verification math of Phase 1, which builds with `no_std` and `alloc`.
+//!
+//! This module reads elements from byte slices, checks that pairs of elements have the
+//! same ratio, and checks the proofs of knowledge in a public key, so a published transcript
+//! can be spot-checked where there is no filesystem or threads, such as in a TEE enclave.
+//! Every function takes the bytes it checks, and elements are checked as thoroughly as
+//! `CheckForCorrectness::Full` checks them in the verification of `Phase1`.
+//!
+//! Without the `std` feature, this is the only module the crate builds,
+//! as `cargo build -p phase1 --no-default-features` checks.
+
+use alloc::{vec, vec::Vec};
+use core::fmt;
+
+use blake2::{Blake2b, Digest};
+use rand::{RngCore, SeedableRng};
+use rand_chacha::ChaChaRng;
+use zexe_algebra::{
+    AffineCurve,
+    CanonicalDeserialize,
+    CanonicalSerialize,
+    ConstantSerializedSize,
+    PairingEngine,
+    ProjectiveCurve,
+    SerializationError,
+    Zero,
+};
+
+/// An error found by the verification core.
+#[derive(Debug)]
+pub enum CoreError {
+    /// The bytes hold fewer elements than the check reads.
+    InsufficientElements { expected: usize, got: usize },
+    /// A compressed element is not the canonical encoding of a point.
+    NonCanonicalEncoding,
+    /// An element is the point at infinity.
+    PointAtInfinity,
+    /// The pairs of elements in the named check do not have the same ratio.
+    InvalidRatio(&'static str),
+    /// An element could not be serialized or deserialized.
+    Serialization(SerializationError),
+}
+
+impl fmt::Display for CoreError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CoreError::InsufficientElements { expected, got } => write!(
+                f,
+                "Expected at least {} elements, but the buffer holds {}",
+                expected, got
+            ),
+            CoreError::NonCanonicalEncoding => write!(f, "Compressed element is not canonically encoded"),
+            CoreError::PointAtInfinity => write!(f, "Got point at infinity"),
+            CoreError::InvalidRatio(check) => write!(f, "Invalid ratio in {}", check),
+            CoreError::Serialization(error) => write!(f, "Serialization error in Zexe: {}", error),
+        }
+    }
+}
+
+impl From<SerializationError> for CoreError {
+    fn from(error: SerializationError) -> Self {
+        CoreError::Serialization(error)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CoreError {}
+
+#[cfg(feature = "std")]
+impl From<CoreError> for setup_utils::Error {
+    fn from(error: CoreError) -> Self {
+        match error {
+            CoreError::InsufficientElements { expected, got } => {
+                setup_utils::Error::InsufficientElements { expected, got }
+            }
+            CoreError::NonCanonicalEncoding => setup_utils::VerificationError::NonCanonicalEncoding.into(),
+            CoreError::PointAtInfinity => setup_utils::Error::PointAtInfinity,
+            CoreError::InvalidRatio(check) => setup_utils::VerificationError::InvalidRatio(check).into(),
+            CoreError::Serialization(error) => setup_utils::Error::ZexeSerializationError(error),
+        }
+    }
+}
+
+pub type CoreResult<T> = core::result::Result<T, CoreError>;
+
+/// The pairs in G2 that a public key proves knowledge of tau, alpha and beta with,
+/// of the form (H(s<sup>x</sup>)<sub>2</sub>, H(s<sup>x</sup>)<sub>2</sub><sup>x</sup>).
+/// The elements of a contribution are checked against these pairs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeyRatios<E: PairingEngine> {
+    pub tau: (E::G2Affine, E::G2Affine),
+    pub alpha: (E::G2Affine, E::G2Affine),
+    pub beta: (E::G2Affine, E::G2Affine),
+}
+
+/// Returns the size in bytes of a compressed or uncompressed element.
+pub fn element_size<G: AffineCurve>(compressed: bool) -> usize {
+    match compressed {
+        true => G::SERIALIZED_SIZE,
+        false => G::UNCOMPRESSED_SIZE,
+    }
+}
+
+/// Reads the first `n` elements from the given bytes, and checks that each is a point
+/// in the prime order subgroup other than the point at infinity. Compressed elements
+/// must also be the canonical encoding of their point.
+///
+/// If the bytes hold fewer than `n` elements, returns `CoreError::InsufficientElements`.
+pub fn read_elements<G: AffineCurve>(bytes: &[u8], n: usize, compressed: bool) -> CoreResult<Vec<G>> {
+    let size = element_size::<G>(compressed);
+    let available = bytes.len() / size;
+    if available < n {
+        return Err(CoreError::InsufficientElements {
+            expected: n,
+            got: available,
+        });
+    }
+
+    bytes
+        .chunks_exact(size)
+        .take(n)
+        .map(|encoded| read_element(encoded, compressed))
+        .collect()
+}
+
+/// Reads and checks one element, which takes up all of the given bytes.
+fn read_element<G: AffineCurve>(encoded: &[u8], compressed: bool) -> CoreResult<G> {
+    let point = match compressed {
+        true => {
+            // Bytes which do not decode to a point are not a canonical encoding either.
+            let point = G::deserialize(&mut &encoded[..]).map_err(|_| CoreError::NonCanonicalEncoding)?;
+
+            let mut reencoded = vec![0u8; encoded.len()];
+            point.serialize(&mut &mut reencoded[..])?;
+            if reencoded[..] != encoded[..] {
+                return Err(CoreError::NonCanonicalEncoding);
+            }
+            point
+        }
+        false => G::deserialize_uncompressed(&mut &encoded[..])?,
+    };
+
+    if point.is_zero() {
+        return Err(CoreError::PointAtInfinity);
+    }
+    Ok(point)
+}
+
+/// Checks that the pair in G1 has the same ratio as the pair in G2,
+/// naming the check in the error if it does not.
+pub fn check_same_ratio<E: PairingEngine>(
+    g1: &(E::G1Affine, E::G1Affine),
+    g2: &(E::G2Affine, E::G2Affine),
+    check: &'static str,
+) -> CoreResult<()> {
+    if E::pairing(g1.0, g2.1) != E::pairing(g1.1, g2.0) {
+        return Err(CoreError::InvalidRatio(check));
+    }
+    Ok(())
+}
+
+/// Hashes the given 64-byte digest to a point in G2, seeding a ChaCha RNG with its first 32 bytes.
+fn hash_to_g2<E: PairingEngine>(digest: &[u8]) -> E::G2Projective {
+    let mut seed = [0u8; 32];
+    seed.copy_from_slice(&digest[..32]);
+    let mut rng = ChaChaRng::from_seed(seed);
+    loop {
+        // Each byte is drawn from its own 32-bit output, as `Rng::gen::<u8>` draws it.
+        let bytes: Vec<u8> = (0..E::G2Affine::SERIALIZED_SIZE)
+            .map(|_| rng.next_u32() as u8)
+            .collect();
+        if let Some(p) = E::G2Affine::from_random_bytes(&bytes) {
+            let scaled = p.mul_by_cofactor_to_projective();
+            if !scaled.is_zero() {
+                return scaled;
+            }
+        }
+    }
+}
+
+/// Computes BLAKE2b(personalization | digest | g<sup>s</sup> | g<sup>s*x</sup>), and hashes it to G2.
+pub fn compute_g2_s<E: PairingEngine>(
+    digest: &[u8],
+    g1_s: &E::G1Affine,
+    g1_s_x: &E::G1Affine,
+    personalization: u8,
+) -> CoreResult<E::G2Affine> {
+    let size = E::G1Affine::SERIALIZED_SIZE;
+    let mut data = vec![0u8; 2 * size];
+    g1_s.serialize(&mut &mut data[..size])?;
+    g1_s_x.serialize(&mut &mut data[size..])?;
+
+    let mut hasher = Blake2b::new();
+    hasher.input(&[personalization]);
+    hasher.input(digest);
+    hasher.input(&data);
+    Ok(hash_to_g2::<E>(hasher.result().as_ref()).into_affine())
+}
+
+/// Checks the proofs of knowledge of tau, alpha and beta, given the pairs (s, s<sup>x</sup>) in G1,
+/// the points H(s<sup>x</sup>)<sub>2</sub>, and the points H(s<sup>x</sup>)<sub>2</sub><sup>x</sup> of the public key.
+pub fn check_key_ratios<E: PairingEngine>(
+    [tau_g1, alpha_g1, beta_g1]: [(E::G1Affine, E::G1Affine); 3],
+    [tau_g2_s, alpha_g2_s, beta_g2_s]: [E::G2Affine; 3],
+    [tau_g2, alpha_g2, beta_g2]: [E::G2Affine; 3],
+) -> CoreResult<KeyRatios<E>> {
+    let ratios = KeyRatios {
+        tau: (tau_g2_s, tau_g2),
+        alpha: (alpha_g2_s, alpha_g2),
+        beta: (beta_g2_s, beta_g2),
+    };
+
+    check_same_ratio::<E>(&tau_g1, &ratios.tau, "Tau G1<>G2")?;
+    check_same_ratio::<E>(&alpha_g1, &ratios.alpha, "Alpha G1<>G2")?;
+    check_same_ratio::<E>(&beta_g1, &ratios.beta, "Beta G1<>G2")?;
+
+    Ok(ratios)
+}
+
+/// Checks the proofs of knowledge of the public key serialized in the given bytes,
+/// for a contribution to the transcript with the given 64-byte digest.
+///
+/// The key is serialized as it is in a contribution file, as the pairs in G1 for tau,
+/// alpha and beta, followed by the points in G2 for tau, alpha and beta, all compressed.
+pub fn check_public_key<E: PairingEngine>(key: &[u8], digest: &[u8]) -> CoreResult<KeyRatios<E>> {
+    let g1_size = element_size::<E::G1Affine>(true);
+    let g1 = read_elements::<E::G1Affine>(key, 6, true)?;
+    let g2 = read_elements::<E::G2Affine>(key.get(6 * g1_size..).unwrap_or_default(), 3, true)?;
+
+    let g1_pairs = [(g1[0], g1[1]), (g1[2], g1[3]), (g1[4], g1[5])];
+    let g2_s = [
+        compute_g2_s::<E>(digest, &g1[0], &g1[1], 0)?,
+        compute_g2_s::<E>(digest, &g1[2], &g1[3], 1)?,
+        compute_g2_s::<E>(digest, &g1[4], &g1[5], 2)?,
+    ];
+    check_key_ratios::<E>(g1_pairs, g2_s, [g2[0], g2[1], g2[2]])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{helpers::testing::random_point_vec, Phase1};
+    use setup_utils::{blank_hash, calculate_hash, derive_rng_from_seed, BatchSerializer, UseCompression};
+
+    use rand::thread_rng;
+    use zexe_algebra::{Bls12_377, BW6_761};
+
+    fn read_elements_curve_test<G: AffineCurve>() {
+        let mut rng = thread_rng();
+        let elements: Vec<G> = random_point_vec(3, &mut rng);
+
+        for (compressed, compression) in &[(true, UseCompression::Yes), (false, UseCompression::No)] {
+            let size = element_size::<G>(*compressed);
+            let mut buffer = vec![0; 3 * size];
+            buffer.write_batch(&elements, *compression).unwrap();
+
+            // The elements are read as they were written.
+            assert_eq!(elements, read_elements::<G>(&buffer, 3, *compressed).unwrap());
+
+            // Reading past the end of the buffer is an error.
+            assert!(matches!(
+                read_elements::<G>(&buffer, 4, *compressed),
+                Err(CoreError::InsufficientElements { expected: 4, got: 3 })
+            ));
+
+            // The point at infinity is rejected.
+            let mut zero = vec![0; size];
+            zero.write_batch(&[G::zero()], *compression).unwrap();
+            assert!(matches!(
+                read_elements::<G>(&zero, 1, *compressed),
+                Err(CoreError::PointAtInfinity)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_read_elements() {
+        read_elements_curve_test::<<Bls12_377 as PairingEngine>::G1Affine>();
+        read_elements_curve_test::<<Bls12_377 as PairingEngine>::G2Affine>();
+        read_elements_curve_test::<<BW6_761 as PairingEngine>::G1Affine>();
+        read_elements_curve_test::<<BW6_761 as PairingEngine>::G2Affine>();
+    }
+
+    fn check_public_key_curve_test<E: PairingEngine>() {
+        let digest = blank_hash();
+        let mut rng = derive_rng_from_seed(b"test_check_public_key");
+        let (public_key, _) = Phase1::<E>::key_generation(&mut rng, digest.as_ref()).unwrap();
+        let mut key = vec![];
+        public_key.serialize(&mut key).unwrap();
+
+        // The points hashed to G2 match those of the verification of `Phase1`.
+        let g2_s = compute_g2_s::<E>(digest.as_ref(), &public_key.tau_g1.0, &public_key.tau_g1.1, 0).unwrap();
+        let expected =
+            setup_utils::compute_g2_s::<E>(digest.as_ref(), &public_key.tau_g1.0, &public_key.tau_g1.1, 0).unwrap();
+        assert_eq!(expected, g2_s);
+
+        // The key proves knowledge for the digest it was generated for, and for no other.
+        let ratios = check_public_key::<E>(&key, digest.as_ref()).unwrap();
+        assert_eq!((g2_s, public_key.tau_g2), ratios.tau);
+        assert!(matches!(
+            check_public_key::<E>(&key, calculate_hash(b"another transcript").as_ref()),
+            Err(CoreError::InvalidRatio("Tau G1<>G2"))
+        ));
+
+        // A truncated key is an error, rather than a panic.
+        assert!(matches!(
+            check_public_key::<E>(&key[..key.len() - 1], digest.as_ref()),
+            Err(CoreError::InsufficientElements { expected: 3, got: 2 })
+        ));
+    }
+
+    #[test]
+    fn test_check_public_key() {
+        check_public_key_curve_test::<Bls12_377>();
+        check_public_key_curve_test::<BW6_761>();
+    }
+}
@@ -1,6 +1,6 @@
 use snarkos_errors::gadgets::SynthesisError;
 use snarkos_models::{
-    curves::{Field, PairingEngine},
+    curves::{Field, One, PairingEngine},
     gadgets::r1cs::{ConstraintSynthesizer, ConstraintSystem},
 };
 
@@ -40,6 +40,40 @@ impl<E: PairingEngine> ConstraintSynthesizer<E::Fr> for TestCircuit<E> {
     }
 }
 
+// circuit proving knowledge of a square root, which can be extended
+// with an extra private variable constrained on its own, so that the
+// circuit without the variable is a specialization of the extended circuit
+#[derive(Clone, Debug)]
+pub struct SpecializableCircuit<E: PairingEngine> {
+    pub square_root: Option<E::Fr>,
+    pub extended: bool,
+}
+impl<E: PairingEngine> ConstraintSynthesizer<E::Fr> for SpecializableCircuit<E> {
+    fn generate_constraints<CS: ConstraintSystem<E::Fr>>(self, cs: &mut CS) -> std::result::Result<(), SynthesisError> {
+        let x = cs.alloc(|| "x", || self.square_root.ok_or(SynthesisError::AssignmentMissing))?;
+        let out = cs.alloc_input(
+            || "square",
+            || {
+                self.square_root
+                    .map(|x| x.square())
+                    .ok_or(SynthesisError::AssignmentMissing)
+            },
+        )?;
+        // x * x = x^2
+        for _ in 0..4 {
+            cs.enforce(|| "x * x = x^2", |lc| lc + x, |lc| lc + x, |lc| lc + out);
+        }
+
+        // the extra variable is only involved in the last constraint,
+        // so the constraints of `x` are the same in both circuits
+        if self.extended {
+            let one = cs.alloc(|| "one", || Ok(E::Fr::one()))?;
+            cs.enforce(|| "one * one = one", |lc| lc + one, |lc| lc + one, |lc| lc + one);
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -6,7 +6,7 @@ pub mod keypair;
 
 pub mod parameters;
 #[cfg(not(feature = "wasm"))]
-mod polynomial;
+pub mod polynomial;
 
 pub mod chunked_groth16;
 
@@ -2,7 +2,8 @@ use cfg_if::cfg_if;
 
 cfg_if! {
     if #[cfg(not(feature = "wasm"))] {
-        use super::polynomial::eval;
+        use super::polynomial::{eval_with_scratch, EvalScratch};
+        use std::collections::HashMap;
         use zexe_algebra::{ Zero };
         use zexe_groth16::{VerifyingKey};
         use zexe_r1cs_core::SynthesisError;
@@ -57,6 +58,71 @@ impl<E: PairingEngine + PartialEq> PartialEq for MPCParameters<E> {
     }
 }
 
+/// Stripped parameters are `MPCParameters` without their transcript of contributions,
+/// for provers who do not need to verify the ceremony. Instead of the transcript,
+/// they contain the hash of the contributions they were stripped of, so that the
+/// detached contributions can be reattached to restore the verifiable parameters.
+#[derive(Clone)]
+pub struct StrippedParameters<E: PairingEngine> {
+    pub params: Parameters<E>,
+    pub cs_hash: [u8; 64],
+    pub contributions_hash: [u8; 64],
+}
+
+impl<E: PairingEngine> fmt::Debug for StrippedParameters<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "StrippedParameters {{ params: {:?}, cs_hash: {:?}, contributions_hash: {:?}}}",
+            self.params,
+            &self.cs_hash[..],
+            &self.contributions_hash[..]
+        )
+    }
+}
+
+impl<E: PairingEngine + PartialEq> PartialEq for StrippedParameters<E> {
+    fn eq(&self, other: &StrippedParameters<E>) -> bool {
+        self.params == other.params
+            && &self.cs_hash[..] == other.cs_hash.as_ref()
+            && &self.contributions_hash[..] == other.contributions_hash.as_ref()
+    }
+}
+
+impl<E: PairingEngine> StrippedParameters<E> {
+    /// Get the underlying Groth16 `Parameters`
+    pub fn get_params(&self) -> &Parameters<E> {
+        &self.params
+    }
+
+    /// Serialize these parameters. As with `MPCParameters`, the serialized
+    /// parameters can be read by Zexe's Groth16 `Parameters`.
+    pub fn write<W: Write>(&self, writer: &mut W) -> Result<()> {
+        self.params.serialize(writer)?;
+        writer.write_all(&self.cs_hash)?;
+        writer.write_all(&self.contributions_hash)?;
+
+        Ok(())
+    }
+
+    /// Deserialize these parameters.
+    pub fn read<R: Read>(mut reader: R) -> Result<StrippedParameters<E>> {
+        let params = Parameters::deserialize(&mut reader)?;
+
+        let mut cs_hash = [0u8; 64];
+        reader.read_exact(&mut cs_hash)?;
+
+        let mut contributions_hash = [0u8; 64];
+        reader.read_exact(&mut contributions_hash)?;
+
+        Ok(StrippedParameters {
+            params,
+            cs_hash,
+            contributions_hash,
+        })
+    }
+}
+
 impl<E: PairingEngine> MPCParameters<E> {
     #[cfg(not(feature = "wasm"))]
     pub fn new_from_buffer<Aleo, C>(
@@ -87,8 +153,27 @@ impl<E: PairingEngine> MPCParameters<E> {
     /// are unsafe to use until there are contributions (see `contribute()`).
     #[cfg(not(feature = "wasm"))]
     pub fn new(assembly: zexe_groth16::KeypairAssembly<E>, params: Groth16Params<E>) -> Result<MPCParameters<E>> {
+        Self::new_with_scratch(assembly, params, &mut EvalScratch::default())
+    }
+
+    /// Create new Groth16 parameters as `new`, evaluating the QAP in the given scratch
+    /// buffers, so that creating the parameters of several circuits allocates them once.
+    #[cfg(not(feature = "wasm"))]
+    pub fn new_with_scratch(
+        assembly: zexe_groth16::KeypairAssembly<E>,
+        params: Groth16Params<E>,
+        scratch: &mut EvalScratch<E>,
+    ) -> Result<MPCParameters<E>> {
+        // Check that the circuit fits in the coefficients created from phase 1.
+        if assembly.num_constraints > params.coeffs_g1.len() {
+            return Err(Error::PolynomialDegreeTooLarge {
+                required: assembly.num_constraints,
+                supported: params.coeffs_g1.len(),
+            });
+        }
+
         // Evaluate the QAP against the coefficients created from phase 1
-        let (a_g1, b_g1, b_g2, gamma_abc_g1, l) = eval::<E>(
+        let (a_g1, b_g1, b_g2, gamma_abc_g1, l) = eval_with_scratch::<E>(
             // Lagrange coeffs for Tau, read in from Phase 1
             &params.coeffs_g1,
             &params.coeffs_g2,
@@ -100,6 +185,7 @@ impl<E: PairingEngine> MPCParameters<E> {
             &assembly.ct,
             // Helper
             assembly.num_inputs,
+            scratch,
         );
 
         // Reject unconstrained elements, so that
@@ -138,6 +224,89 @@ impl<E: PairingEngine> MPCParameters<E> {
         })
     }
 
+    /// Specialize these parameters to a smaller circuit, without running phase 2 again.
+    ///
+    /// The `initial` parameters are the parameters these were contributed to, before
+    /// any contribution, and `params` are the phase 1 coefficients both were created from.
+    /// The A, B and IC queries do not depend on delta, so they are evaluated anew for the
+    /// circuit. The H query and the element of the L query of each auxiliary variable
+    /// are divided by the accumulated delta, so they can only be kept as they are: the
+    /// circuit must use the same domain and number of inputs, and each of its auxiliary
+    /// variables must be constrained exactly as an auxiliary variable of the original
+    /// circuit. This holds when the circuit drops auxiliary variables along with the
+    /// constraints which only involve them.
+    ///
+    /// The specialized parameters keep the transcript of contributions, so specializing
+    /// the `initial` parameters as well allows verifying them with `verify_chain`.
+    #[cfg(not(feature = "wasm"))]
+    pub fn specialize(
+        &self,
+        initial: &Self,
+        assembly: zexe_groth16::KeypairAssembly<E>,
+        params: Groth16Params<E>,
+    ) -> Result<MPCParameters<E>> {
+        // The parameters must have been contributed to from the initial parameters.
+        ensure_unchanged(&initial.cs_hash[..], &self.cs_hash[..], InvariantKind::CsHash)?;
+        ensure_same_length(&initial.params.l_query, &self.params.l_query)?;
+
+        // Evaluate the QAP of the specialized circuit before any contribution.
+        let specialized = Self::new(assembly, params)?;
+
+        // The inputs are not divided by delta, but changing them changes the statement.
+        let expected = initial.params.vk.gamma_abc_g1.len();
+        let found = specialized.params.vk.gamma_abc_g1.len();
+        if expected != found {
+            return Err(Phase2Error::SpecializationInputsMismatch { expected, found }.into());
+        }
+
+        // The H query only depends on the domain.
+        if specialized.params.h_query != initial.params.h_query {
+            return Err(Phase2Error::SpecializationDomainMismatch.into());
+        }
+
+        // Map each element of the initial L query to its position, to find
+        // the contributed element of each auxiliary variable of the circuit.
+        let mut positions = HashMap::with_capacity(initial.params.l_query.len());
+        for (i, element) in initial.params.l_query.iter().enumerate() {
+            let mut key = vec![];
+            element.serialize(&mut key)?;
+            positions.insert(key, i);
+        }
+        let mut l_query = Vec::with_capacity(specialized.params.l_query.len());
+        for (i, element) in specialized.params.l_query.iter().enumerate() {
+            let mut key = vec![];
+            element.serialize(&mut key)?;
+            match positions.get(&key) {
+                Some(position) => l_query.push(self.params.l_query[*position]),
+                None => return Err(Phase2Error::SpecializationVariableMismatch(i).into()),
+            }
+        }
+
+        let vk = VerifyingKey {
+            alpha_g1: self.params.vk.alpha_g1,
+            beta_g2: self.params.vk.beta_g2,
+            gamma_g2: self.params.vk.gamma_g2,
+            delta_g2: self.params.vk.delta_g2,
+            gamma_abc_g1: specialized.params.vk.gamma_abc_g1,
+        };
+        let params = Parameters {
+            vk,
+            beta_g1: self.params.beta_g1,
+            delta_g1: self.params.delta_g1,
+            a_query: specialized.params.a_query,
+            b_g1_query: specialized.params.b_g1_query,
+            b_g2_query: specialized.params.b_g2_query,
+            h_query: self.params.h_query.clone(),
+            l_query,
+        };
+
+        Ok(MPCParameters {
+            params,
+            cs_hash: self.cs_hash,
+            contributions: self.contributions.clone(),
+        })
+    }
+
     /// Get the underlying Groth16 `Parameters`
     pub fn get_params(&self) -> &Parameters<E> {
         &self.params
@@ -181,6 +350,15 @@ impl<E: PairingEngine> MPCParameters<E> {
     /// `MPCParameters::contribute`, for ensuring that contributions
     /// exist in the final parameters.
     pub fn verify(&self, after: &Self) -> Result<Vec<[u8; 64]>> {
+        self.verify_parameters(after)?;
+
+        // generate the transcript from the current contributions and the previous cs_hash
+        verify_transcript(self.cs_hash, &after.contributions)
+    }
+
+    /// Verify that `after` was derived from these parameters by applying
+    /// the delta of its last contribution. This does not walk the transcript.
+    fn verify_parameters(&self, after: &Self) -> Result<()> {
         let before = self;
 
         let pubkey = if let Some(pubkey) = after.contributions.last() {
@@ -271,8 +449,33 @@ impl<E: PairingEngine> MPCParameters<E> {
             "L_query ratio check failed",
         )?;
 
-        // generate the transcript from the current contributions and the previous cs_hash
-        verify_transcript(before.cs_hash, &after.contributions)
+        Ok(())
+    }
+
+    /// Verify the full chain of contributions from the `initial` parameters
+    /// to the `last` parameters. Each recorded contribution is checked in order,
+    /// and the first invalid one is reported by its index in the transcript.
+    /// On success, this returns the hashes of every contribution in order.
+    pub fn verify_chain(initial: &Self, last: &Self) -> Result<Vec<[u8; 64]>> {
+        if last.contributions.is_empty() {
+            return Err(Phase2Error::NoContributions.into());
+        }
+
+        // Walk each step of the transcript, so a failure can be attributed to a contribution
+        let mut hashes = Vec::with_capacity(last.contributions.len());
+        let mut old_delta = E::G1Affine::prime_subgroup_generator();
+        for (i, pubkey) in last.contributions.iter().enumerate() {
+            verify_transcript_step(initial.cs_hash, &last.contributions[0..i], pubkey, old_delta)
+                .map_err(|e| Error::InvalidContribution(i, Box::new(e)))?;
+            old_delta = pubkey.delta_after;
+
+            hashes.push(pubkey.hash());
+        }
+
+        // The accumulated delta must have been applied to the final parameters
+        initial.verify_parameters(last)?;
+
+        Ok(hashes)
     }
 
     /// Serialize these parameters. The serialized parameters
@@ -300,6 +503,58 @@ impl<E: PairingEngine> MPCParameters<E> {
             contributions,
         })
     }
+
+    /// Strips the transcript of contributions from these parameters, for provers
+    /// who only need the proving parameters. Returns the stripped parameters, which
+    /// commit to the contributions by their hash, and the detached contributions,
+    /// which can be serialized with `PublicKey::write_batch`.
+    pub fn strip_contributions(&self) -> (StrippedParameters<E>, Vec<PublicKey<E>>) {
+        let stripped = StrippedParameters {
+            params: self.params.clone(),
+            cs_hash: self.cs_hash,
+            contributions_hash: hash_contributions(self.cs_hash, &self.contributions),
+        };
+        (stripped, self.contributions.clone())
+    }
+
+    /// Reattaches the detached contributions to the stripped parameters, to restore
+    /// the verifiable parameters they were stripped from. The contributions must
+    /// match the hash the stripped parameters commit to.
+    pub fn reattach(stripped: StrippedParameters<E>, contributions: Vec<PublicKey<E>>) -> Result<MPCParameters<E>> {
+        let contributions_hash = hash_contributions(stripped.cs_hash, &contributions);
+        if contributions_hash[..] != stripped.contributions_hash[..] {
+            return Err(Phase2Error::ContributionsHashMismatch.into());
+        }
+
+        Ok(MPCParameters {
+            params: stripped.params,
+            cs_hash: stripped.cs_hash,
+            contributions,
+        })
+    }
+}
+
+/// Returns the hash of the transcript of contributions to the parameters of a circuit.
+///
+/// Internally calculates: `H(cs_hash | <contributions>)`
+pub fn hash_contributions<E: PairingEngine>(cs_hash: [u8; 64], contributions: &[PublicKey<E>]) -> [u8; 64] {
+    let h = {
+        let sink = io::sink();
+        let mut sink = HashWriter::new(sink);
+
+        sink.write_all(&cs_hash[..]).unwrap();
+        PublicKey::write_batch(&mut sink, contributions).unwrap();
+        sink.into_hash()
+    };
+    let mut contributions_hash = [0; 64];
+    contributions_hash.copy_from_slice(h.as_ref());
+    contributions_hash
+}
+
+/// Deserializes the Groth16 `Parameters` from either serialized `MPCParameters`
+/// or serialized `StrippedParameters`, which both start with them.
+pub fn read_groth16_parameters<E: PairingEngine, R: Read>(mut reader: R) -> Result<Parameters<E>> {
+    Ok(Parameters::deserialize(&mut reader)?)
 }
 
 /// This is a cheap helper utility that exists purely
@@ -345,25 +600,7 @@ pub fn verify_transcript<E: PairingEngine>(cs_hash: [u8; 64], contributions: &[P
     let mut result = vec![];
     let mut old_delta = E::G1Affine::prime_subgroup_generator();
     for (i, pubkey) in contributions.iter().enumerate() {
-        let hash = hash_cs_pubkeys(cs_hash, &contributions[0..i], pubkey.s, pubkey.s_delta);
-        ensure_unchanged(&pubkey.transcript[..], &hash.as_ref()[..], InvariantKind::Transcript)?;
-
-        // generate the G2 point from the hash
-        let r = hash_to_g2::<E>(hash.as_ref()).into_affine();
-
-        // Check the signature of knowledge
-        check_same_ratio::<E>(
-            &(pubkey.s, pubkey.s_delta),
-            &(r, pubkey.r_delta),
-            "Incorrect signature of knowledge",
-        )?;
-
-        // Check the change with the previous G1 Delta is consistent
-        check_same_ratio::<E>(
-            &(old_delta, pubkey.delta_after),
-            &(r, pubkey.r_delta),
-            "Inconsistent G1 Delta",
-        )?;
+        verify_transcript_step(cs_hash, &contributions[0..i], pubkey, old_delta)?;
         old_delta = pubkey.delta_after;
 
         result.push(pubkey.hash());
@@ -372,6 +609,37 @@ pub fn verify_transcript<E: PairingEngine>(cs_hash: [u8; 64], contributions: &[P
     Ok(result)
 }
 
+/// Verifies a single contribution in the transcript, given the contributions
+/// which precede it and the G1 delta before it was applied.
+fn verify_transcript_step<E: PairingEngine>(
+    cs_hash: [u8; 64],
+    previous: &[PublicKey<E>],
+    pubkey: &PublicKey<E>,
+    old_delta: E::G1Affine,
+) -> Result<()> {
+    let hash = hash_cs_pubkeys(cs_hash, previous, pubkey.s, pubkey.s_delta);
+    ensure_unchanged(&pubkey.transcript[..], &hash.as_ref()[..], InvariantKind::Transcript)?;
+
+    // generate the G2 point from the hash
+    let r = hash_to_g2::<E>(hash.as_ref()).into_affine();
+
+    // Check the signature of knowledge
+    check_same_ratio::<E>(
+        &(pubkey.s, pubkey.s_delta),
+        &(r, pubkey.r_delta),
+        "Incorrect signature of knowledge",
+    )?;
+
+    // Check the change with the previous G1 Delta is consistent
+    check_same_ratio::<E>(
+        &(old_delta, pubkey.delta_after),
+        &(r, pubkey.r_delta),
+        "Inconsistent G1 Delta",
+    )?;
+
+    Ok(())
+}
+
 #[allow(unused)]
 fn hash_params<E: PairingEngine>(params: &Parameters<E>) -> Result<[u8; 64]> {
     let sink = io::sink();
@@ -420,7 +688,7 @@ pub fn circuit_to_qap<E: AleoPairingEngine, Zexe: PairingEngine, C: AleoR1CS<E::
     // (we do uncompressed because it is faster)
     // (This could alternatively be done with unsafe memory swapping, but we
     // prefer to err on the side of caution)
-    let mut serialized = Vec::new();
+    let mut serialized = Vec::with_capacity(assembly.serialized_size());
     assembly
         .serialize(&mut serialized)
         .expect("serializing the KeypairAssembly should not fail");
@@ -434,7 +702,7 @@ mod tests {
     use super::*;
     use crate::{
         chunked_groth16::{contribute, verify},
-        helpers::testing::TestCircuit,
+        helpers::testing::{SpecializableCircuit, TestCircuit},
     };
     use phase1::{helpers::testing::setup_verify, Phase1, Phase1Parameters, ProvingSystem};
     use setup_utils::{Groth16Params, UseCompression};
@@ -547,14 +815,186 @@ mod tests {
         contribution2.verify(&contribution3).unwrap();
     }
 
+    #[test]
+    fn verify_contribution_chain() {
+        verify_chain_curve::<AleoBls12_377, Bls12_377>()
+    }
+
+    // a chain of contributions is verified step by step from the initial parameters
+    fn verify_chain_curve<Aleo: AleoPairingEngine, E: PairingEngine>() {
+        let rng = &mut thread_rng();
+        let initial = generate_ceremony::<Aleo, E>();
+
+        let mut last = initial.clone();
+        let hashes = (0..3).map(|_| last.contribute(rng).unwrap()).collect::<Vec<_>>();
+
+        let verified = MPCParameters::verify_chain(&initial, &last).unwrap();
+        assert_eq!(3, verified.len());
+        for (expected, hash) in hashes.iter().zip(&verified) {
+            assert_eq!(&expected[..], &hash[..]);
+        }
+
+        // tampering with the second contribution is reported at its index
+        let mut tampered = last.clone();
+        tampered.contributions[1].s_delta = tampered.contributions[0].s_delta;
+        match MPCParameters::verify_chain(&initial, &tampered) {
+            Err(Error::InvalidContribution(index, _)) => assert_eq!(1, index),
+            Err(e) => panic!("Expected an invalid contribution, got {}", e),
+            Ok(_) => panic!("Verifying a tampered chain must fail"),
+        }
+
+        // an empty chain has no contributions to verify
+        assert!(MPCParameters::verify_chain(&initial, &initial).is_err());
+    }
+
+    #[test]
+    fn strip_and_reattach_contributions() {
+        strip_and_reattach_curve::<AleoBls12_377, Bls12_377>()
+    }
+
+    // stripped parameters and detached contributions round trip through
+    // serialization, and reattach to parameters with a verifiable chain
+    fn strip_and_reattach_curve<Aleo: AleoPairingEngine, E: PairingEngine + PartialEq>() {
+        let rng = &mut thread_rng();
+        let initial = generate_ceremony::<Aleo, E>();
+        let mut last = initial.clone();
+        let hashes = (0..2).map(|_| last.contribute(rng).unwrap()).collect::<Vec<_>>();
+
+        let (stripped, contributions) = last.strip_contributions();
+        assert_eq!(last.params, stripped.params);
+        assert_eq!(last.contributions, contributions);
+
+        // the stripped parameters are smaller by the size of the transcript
+        let mut full_serialized = vec![];
+        last.write(&mut full_serialized).unwrap();
+        let mut stripped_serialized = vec![];
+        stripped.write(&mut stripped_serialized).unwrap();
+        let mut contributions_serialized = vec![];
+        PublicKey::write_batch(&mut contributions_serialized, &contributions).unwrap();
+        assert_eq!(
+            full_serialized.len() + 64,
+            stripped_serialized.len() + contributions_serialized.len()
+        );
+
+        // both forms contain the Groth16 parameters
+        let full_params = read_groth16_parameters::<E, _>(&full_serialized[..]).unwrap();
+        let stripped_params = read_groth16_parameters::<E, _>(&stripped_serialized[..]).unwrap();
+        assert_eq!(last.params, full_params);
+        assert_eq!(last.params, stripped_params);
+
+        // the deserialized stripped parameters and contributions reattach to the original parameters
+        let stripped = StrippedParameters::<E>::read(&stripped_serialized[..]).unwrap();
+        let contributions = PublicKey::<E>::read_batch(&mut &contributions_serialized[..]).unwrap();
+        let reattached = MPCParameters::reattach(stripped.clone(), contributions.clone()).unwrap();
+        assert_eq!(last, reattached);
+
+        // the chain of contributions is verifiable again
+        let verified = MPCParameters::verify_chain(&initial, &reattached).unwrap();
+        assert_eq!(2, verified.len());
+        for (expected, hash) in hashes.iter().zip(&verified) {
+            assert_eq!(&expected[..], &hash[..]);
+        }
+
+        // the stripped parameters only reattach to the contributions they were stripped of
+        let reordered = contributions.iter().rev().cloned().collect::<Vec<_>>();
+        for contributions in vec![contributions[..1].to_vec(), reordered, vec![]] {
+            match MPCParameters::reattach(stripped.clone(), contributions) {
+                Err(Error::Phase2Error(Phase2Error::ContributionsHashMismatch)) => {}
+                Err(e) => panic!("Expected a contributions hash mismatch, got {}", e),
+                Ok(_) => panic!("Reattaching other contributions must fail"),
+            }
+        }
+    }
+
+    #[test]
+    fn circuit_too_large() {
+        let groth_params = generate_groth_params::<Bls12_377>(4);
+
+        // this circuit requires 7 constraints, so a ceremony with size 4 is too small
+        let c = TestCircuit::<AleoBls12_377>(None);
+        let assembly = circuit_to_qap::<AleoBls12_377, Bls12_377, _>(c).unwrap();
+
+        match MPCParameters::new(assembly, groth_params) {
+            Err(Error::PolynomialDegreeTooLarge { required, supported }) => {
+                assert!(required > supported);
+                assert_eq!(4, supported);
+            }
+            Err(e) => panic!("Expected the polynomial degree to be too large, got {}", e),
+            Ok(_) => panic!("A circuit larger than the ceremony must be rejected"),
+        }
+    }
+
+    #[test]
+    fn specialize_contributed_parameters() {
+        specialize_curve::<AleoBls12_377, Bls12_377>()
+    }
+
+    // contributed parameters are specialized to a circuit without one of
+    // its auxiliary variables, and keep a valid chain of contributions
+    fn specialize_curve<Aleo: AleoPairingEngine, E: PairingEngine>() {
+        let rng = &mut thread_rng();
+        let qap = |extended| {
+            let c = SpecializableCircuit::<Aleo> {
+                square_root: None,
+                extended,
+            };
+            circuit_to_qap::<Aleo, E, _>(c).unwrap()
+        };
+
+        let initial = MPCParameters::new(qap(true), generate_groth_params::<E>(8)).unwrap();
+        let mut last = initial.clone();
+        let hashes = (0..2).map(|_| last.contribute(rng).unwrap()).collect::<Vec<_>>();
+
+        // the initial parameters are specialized too, to verify the chain against them
+        let specialized_initial = initial
+            .specialize(&initial, qap(false), generate_groth_params::<E>(8))
+            .unwrap();
+        let specialized = last
+            .specialize(&initial, qap(false), generate_groth_params::<E>(8))
+            .unwrap();
+        assert_eq!(initial.params.l_query.len() - 1, specialized.params.l_query.len());
+        assert_eq!(last.params.vk.delta_g2, specialized.params.vk.delta_g2);
+
+        let verified = MPCParameters::verify_chain(&specialized_initial, &specialized).unwrap();
+        assert_eq!(2, verified.len());
+        for (expected, hash) in hashes.iter().zip(&verified) {
+            assert_eq!(&expected[..], &hash[..]);
+        }
+
+        // the circuit must use the same domain
+        match last.specialize(&initial, qap(false), generate_groth_params::<E>(16)) {
+            Err(Error::Phase2Error(Phase2Error::SpecializationDomainMismatch)) => {}
+            Err(e) => panic!("Expected a domain mismatch, got {}", e),
+            Ok(_) => panic!("A circuit over another domain must be rejected"),
+        }
+
+        // the reduced circuit is not a specialization of the extended circuit
+        let reduced = MPCParameters::new(qap(false), generate_groth_params::<E>(8)).unwrap();
+        match reduced.specialize(&reduced, qap(true), generate_groth_params::<E>(8)) {
+            Err(Error::Phase2Error(Phase2Error::SpecializationVariableMismatch(index))) => assert_eq!(1, index),
+            Err(e) => panic!("Expected a variable mismatch, got {}", e),
+            Ok(_) => panic!("A circuit with more constraints must be rejected"),
+        }
+    }
+
     // helper which generates the initial phase 2 params
     // for the TestCircuit
     fn generate_ceremony<Aleo: AleoPairingEngine, E: PairingEngine>() -> MPCParameters<E> {
+        let groth_params = generate_groth_params::<E>(7);
+
+        // this circuit requires 7 constraints, so a ceremony with size 8 is sufficient
+        let c = TestCircuit::<Aleo>(None);
+        let assembly = circuit_to_qap::<Aleo, E, _>(c).unwrap();
+
+        MPCParameters::new(assembly, groth_params).unwrap()
+    }
+
+    // helper which generates the phase 2 coefficients of the given size
+    fn generate_groth_params<E: PairingEngine>(phase2_size: usize) -> Groth16Params<E> {
         // the phase2 params are generated correctly,
         // even though the powers of tau are >> the circuit size
         let powers = 5;
         let batch = 16;
-        let phase2_size = 7;
         let params = Phase1Parameters::<E>::new_full(ProvingSystem::Groth16, powers, batch);
         let accumulator = {
             let compressed = UseCompression::No;
@@ -570,12 +1010,6 @@ mod tests {
             accumulator.beta_tau_powers_g1,
             accumulator.beta_g2,
         )
-        .unwrap();
-
-        // this circuit requires 7 constraints, so a ceremony with size 8 is sufficient
-        let c = TestCircuit::<Aleo>(None);
-        let assembly = circuit_to_qap::<Aleo, E, _>(c).unwrap();
-
-        MPCParameters::new(assembly, groth_params).unwrap()
+        .unwrap()
     }
 }
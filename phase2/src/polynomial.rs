@@ -3,6 +3,27 @@ use zexe_r1cs_core::Index;
 
 use rayon::prelude::*;
 
+/// The buffers of the projective points of the evaluated queries, which are reused
+/// across evaluations so that evaluating the QAP of several circuits allocates them once.
+#[derive(Debug)]
+pub struct EvalScratch<E: PairingEngine> {
+    a_g1: Vec<E::G1Projective>,
+    b_g1: Vec<E::G1Projective>,
+    b_g2: Vec<E::G2Projective>,
+    ext: Vec<E::G1Projective>,
+}
+
+impl<E: PairingEngine> Default for EvalScratch<E> {
+    fn default() -> Self {
+        Self {
+            a_g1: vec![],
+            b_g1: vec![],
+            b_g2: vec![],
+            ext: vec![],
+        }
+    }
+}
+
 /// Evaluates and returns the provided QAP Polynomial vectors at the provided coefficients.
 /// Format: [a_g1, b_g1, b_g2, gamma_abc_g1, l_g1]
 /// The returned points are _affine_
@@ -27,21 +48,79 @@ pub fn eval<E: PairingEngine>(
     Vec<E::G1Affine>,
     Vec<E::G1Affine>,
 ) {
-    // calculate the evaluated polynomials
-    let a_g1 = dot_product_vec(at, coeffs_g1, num_inputs);
-    let b_g1 = dot_product_vec(bt, coeffs_g1, num_inputs);
-    let b_g2 = dot_product_vec(bt, coeffs_g2, num_inputs);
-    let ext = dot_product_ext::<E>((at, beta_coeffs_g1), (bt, alpha_coeffs_g1), (ct, coeffs_g1), num_inputs);
+    eval_with_scratch::<E>(
+        coeffs_g1,
+        coeffs_g2,
+        alpha_coeffs_g1,
+        beta_coeffs_g1,
+        at,
+        bt,
+        ct,
+        num_inputs,
+        &mut EvalScratch::default(),
+    )
+}
+
+/// Evaluates the provided QAP Polynomial vectors at the provided coefficients, as `eval`,
+/// computing the projective points in the given scratch buffers.
+#[allow(clippy::too_many_arguments)]
+#[allow(clippy::type_complexity)]
+pub fn eval_with_scratch<E: PairingEngine>(
+    // Lagrange coefficients for tau
+    coeffs_g1: &[E::G1Affine],
+    coeffs_g2: &[E::G2Affine],
+    alpha_coeffs_g1: &[E::G1Affine],
+    beta_coeffs_g1: &[E::G1Affine],
+    // QAP polynomials
+    at: &[Vec<(E::Fr, Index)>],
+    bt: &[Vec<(E::Fr, Index)>],
+    ct: &[Vec<(E::Fr, Index)>],
+    // The number of inputs
+    num_inputs: usize,
+    // The buffers of the projective points
+    scratch: &mut EvalScratch<E>,
+) -> (
+    Vec<E::G1Affine>,
+    Vec<E::G1Affine>,
+    Vec<E::G2Affine>,
+    Vec<E::G1Affine>,
+    Vec<E::G1Affine>,
+) {
+    let EvalScratch { a_g1, b_g1, b_g2, ext } = scratch;
+
+    // calculate the evaluated polynomials, each query concurrently with the others
+    rayon::join(
+        || {
+            rayon::join(
+                || dot_product_vec(at, coeffs_g1, num_inputs, a_g1),
+                || dot_product_vec(bt, coeffs_g1, num_inputs, b_g1),
+            )
+        },
+        || {
+            rayon::join(
+                || dot_product_vec(bt, coeffs_g2, num_inputs, b_g2),
+                || {
+                    dot_product_ext::<E>(
+                        (at, beta_coeffs_g1),
+                        (bt, alpha_coeffs_g1),
+                        (ct, coeffs_g1),
+                        num_inputs,
+                        ext,
+                    )
+                },
+            )
+        },
+    );
 
     // break to `gamma_abc_g1` and `l` coeffs
     let (gamma_abc_g1, l) = ext.split_at(num_inputs);
 
     // back to affine and return
-    let a_g1 = a_g1.iter().map(|p| p.into_affine()).collect();
-    let b_g1 = b_g1.iter().map(|p| p.into_affine()).collect();
-    let b_g2 = b_g2.iter().map(|p| p.into_affine()).collect();
-    let gamma_abc_g1 = gamma_abc_g1.iter().map(|p| p.into_affine()).collect();
-    let l = l.iter().map(|p| p.into_affine()).collect();
+    let a_g1 = a_g1.par_iter().map(|p| p.into_affine()).collect();
+    let b_g1 = b_g1.par_iter().map(|p| p.into_affine()).collect();
+    let b_g2 = b_g2.par_iter().map(|p| p.into_affine()).collect();
+    let gamma_abc_g1 = gamma_abc_g1.par_iter().map(|p| p.into_affine()).collect();
+    let l = l.par_iter().map(|p| p.into_affine()).collect();
 
     (a_g1, b_g1, b_g2, gamma_abc_g1, l)
 }
@@ -53,35 +132,30 @@ fn dot_product_ext<E: PairingEngine>(
     (bt, alpha_coeffs_g1): (&[Vec<(E::Fr, Index)>], &[E::G1Affine]),
     (ct, coeffs_g1): (&[Vec<(E::Fr, Index)>], &[E::G1Affine]),
     num_inputs: usize,
-) -> Vec<E::G1Projective> {
-    let mut ret = at
-        .par_iter()
-        .zip(bt.par_iter().zip(ct))
-        .map(|(at, (bt, ct))| {
-            dot_product(&at, &beta_coeffs_g1, num_inputs)
-                + &dot_product(&bt, &alpha_coeffs_g1, num_inputs)
-                + &dot_product(&ct, &coeffs_g1, num_inputs)
-        })
-        .collect::<Vec<_>>();
-    E::G1Projective::batch_normalization(&mut ret);
-    ret
+    ret: &mut Vec<E::G1Projective>,
+) {
+    ret.clear();
+    ret.par_extend(at.par_iter().zip(bt.par_iter().zip(ct)).map(|(at, (bt, ct))| {
+        dot_product(&at, &beta_coeffs_g1, num_inputs)
+            + &dot_product(&bt, &alpha_coeffs_g1, num_inputs)
+            + &dot_product(&ct, &coeffs_g1, num_inputs)
+    }));
+    E::G1Projective::batch_normalization(ret);
 }
 
-/// Returns a batch normalized projective vector where the coefficients
-/// have been applied to the input
+/// Writes into `ret` a batch normalized projective vector where the coefficients
+/// have been applied to the input, reusing the allocation of `ret`
 /// This is a NxN * Nx1 -> Nx1 matrix multiplication basically
 fn dot_product_vec<C: AffineCurve>(
     input: &[Vec<(C::ScalarField, Index)>],
     coeffs: &[C],
     num_inputs: usize,
-) -> Vec<C::Projective> {
-    let mut ret = input
-        .par_iter()
-        .map(|row| dot_product(row, coeffs, num_inputs))
-        .collect::<Vec<_>>();
+    ret: &mut Vec<C::Projective>,
+) {
+    ret.clear();
+    ret.par_extend(input.par_iter().map(|row| dot_product(row, coeffs, num_inputs)));
     // Batch normalize
-    C::Projective::batch_normalization(&mut ret);
-    ret
+    C::Projective::batch_normalization(ret);
 }
 
 /// Executes a dot product between two vectors (1xN * Nx1)
@@ -111,7 +185,7 @@ mod tests {
     use phase1::helpers::testing::random_point_vec;
     use rand::{thread_rng, Rng};
     use zexe_algebra::{
-        bls12_377::{Bls12_377, Fr, G1Affine, G1Projective},
+        bls12_377::{Bls12_377, Fr, G1Affine, G1Projective, G2Affine},
         UniformRand,
     };
 
@@ -157,7 +231,8 @@ mod tests {
         // it also applies the coefficients vector to each row
         // in the inputs vector
         let input_vec = vec![input; 10];
-        let got = dot_product_vec(&input_vec, &elements, num_inputs);
+        let mut got = vec![];
+        dot_product_vec(&input_vec, &elements, num_inputs, &mut got);
         assert_eq!(got, vec![expected; 10])
     }
 
@@ -174,11 +249,13 @@ mod tests {
         let alpha_coeffs_g1: Vec<G1Affine> = random_point_vec(6, &mut rng);
         let coeffs_g1: Vec<G1Affine> = random_point_vec(6, &mut rng);
 
-        let got = dot_product_ext::<Bls12_377>(
+        let mut got = vec![];
+        dot_product_ext::<Bls12_377>(
             (&at, &beta_coeffs_g1),
             (&bt, &alpha_coeffs_g1),
             (&ct, &coeffs_g1),
             num_inputs,
+            &mut got,
         );
 
         // it should be the sum of the dot products
@@ -195,4 +272,74 @@ mod tests {
         }
         assert_eq!(got, expected);
     }
+
+    #[test]
+    fn test_eval() {
+        let mut rng = thread_rng();
+        let num_inputs = 3;
+        // generate the QAP polynomials
+        let at = (0..10).map(|_| gen_input(&mut rng)).collect::<Vec<_>>();
+        let bt = (0..10).map(|_| gen_input(&mut rng)).collect::<Vec<_>>();
+        let ct = (0..10).map(|_| gen_input(&mut rng)).collect::<Vec<_>>();
+        // generate the coeffs vectors
+        let coeffs_g1: Vec<G1Affine> = random_point_vec(6, &mut rng);
+        let coeffs_g2: Vec<G2Affine> = random_point_vec(6, &mut rng);
+        let alpha_coeffs_g1: Vec<G1Affine> = random_point_vec(6, &mut rng);
+        let beta_coeffs_g1: Vec<G1Affine> = random_point_vec(6, &mut rng);
+
+        let (a_g1, b_g1, b_g2, gamma_abc_g1, l) = eval::<Bls12_377>(
+            &coeffs_g1,
+            &coeffs_g2,
+            &alpha_coeffs_g1,
+            &beta_coeffs_g1,
+            &at,
+            &bt,
+            &ct,
+            num_inputs,
+        );
+
+        // each query should be exactly the serially evaluated one
+        let scalars = |t: &[Vec<(Fr, Index)>], i: usize| t[i].iter().map(|e| e.0).collect::<Vec<_>>();
+        let mut ext = Vec::new();
+        for i in 0..at.len() {
+            assert_eq!(a_g1[i], get_expected(&coeffs_g1, &scalars(&at, i)).into_affine());
+            assert_eq!(b_g1[i], get_expected(&coeffs_g1, &scalars(&bt, i)).into_affine());
+            let expected_b_g2 = coeffs_g2[0].mul(bt[i][0].0)
+                + coeffs_g2[1].mul(bt[i][1].0)
+                + coeffs_g2[2].mul(bt[i][5].0)
+                + coeffs_g2[num_inputs].mul(bt[i][2].0)
+                + coeffs_g2[num_inputs + 2].mul(bt[i][3].0)
+                + coeffs_g2[num_inputs + 1].mul(bt[i][4].0);
+            assert_eq!(b_g2[i], expected_b_g2.into_affine());
+            ext.push(
+                (get_expected(&beta_coeffs_g1, &scalars(&at, i))
+                    + get_expected(&alpha_coeffs_g1, &scalars(&bt, i))
+                    + get_expected(&coeffs_g1, &scalars(&ct, i)))
+                .into_affine(),
+            );
+        }
+        assert_eq!(gamma_abc_g1, ext[..num_inputs].to_vec());
+        assert_eq!(l, ext[num_inputs..].to_vec());
+
+        // reusing the scratch buffers for a smaller QAP gives exactly the same queries
+        let mut scratch = EvalScratch::default();
+        for &len in &[at.len(), at.len() / 2] {
+            let got = eval_with_scratch::<Bls12_377>(
+                &coeffs_g1,
+                &coeffs_g2,
+                &alpha_coeffs_g1,
+                &beta_coeffs_g1,
+                &at[..len],
+                &bt[..len],
+                &ct[..len],
+                num_inputs,
+                &mut scratch,
+            );
+            assert_eq!(got.0, a_g1[..len].to_vec());
+            assert_eq!(got.1, b_g1[..len].to_vec());
+            assert_eq!(got.2, b_g2[..len].to_vec());
+            assert_eq!(got.3, gamma_abc_g1);
+            assert_eq!(got.4, l[..len - num_inputs].to_vec());
+        }
+    }
 }
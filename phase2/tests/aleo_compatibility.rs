@@ -1,5 +1,8 @@
 use phase1::{helpers::testing::setup_verify, Phase1, Phase1Parameters, ProvingSystem};
-use phase2::{helpers::testing::TestCircuit, parameters::MPCParameters};
+use phase2::{
+    helpers::testing::{SpecializableCircuit, TestCircuit},
+    parameters::{circuit_to_qap, MPCParameters},
+};
 use setup_utils::{CheckForCorrectness, Groth16Params, UseCompression};
 
 use zexe_algebra::{serialize::CanonicalSerialize, Bls12_377, PairingEngine as ZexePairingEngine, BW6_761};
@@ -28,27 +31,8 @@ fn generate_mpc_parameters<Aleo: AleoPairingEngine, Zexe: ZexePairingEngine, C,
 where
     C: Clone + ConstraintSynthesizer<Aleo::Fr>,
 {
-    let powers = 6; // Powers of tau
-    let batch = 4;
-    let params = Phase1Parameters::<Zexe>::new_full(proving_system, powers, batch);
     let compressed = UseCompression::Yes;
-    // Make 1 power of tau contribution (assume powers of tau gets calculated properly).
-    let (_, output, _, _) = setup_verify(compressed, CheckForCorrectness::Full, compressed, &params);
-    let accumulator = Phase1::deserialize(&output, compressed, CheckForCorrectness::Full, &params).unwrap();
-
-    // Prepare only the first 32 powers (for whatever reason).
-    let groth_params = Groth16Params::<Zexe>::new(
-        32,
-        accumulator.tau_powers_g1,
-        accumulator.tau_powers_g2,
-        accumulator.alpha_tau_powers_g1,
-        accumulator.beta_tau_powers_g1,
-        accumulator.beta_g2,
-    )
-    .unwrap();
-    // Write the transcript to a file.
-    let mut writer = vec![];
-    groth_params.write(&mut writer, compressed).unwrap();
+    let mut writer = generate_groth16_transcript::<Zexe>(proving_system);
 
     // perform the MPC on only the amount of constraints required for the circuit
     let mut counter = ConstraintCounter::new();
@@ -74,6 +58,33 @@ where
     mpc
 }
 
+// Returns the transcript of the Groth16 coefficients for the first 32 powers of tau.
+fn generate_groth16_transcript<Zexe: ZexePairingEngine>(proving_system: ProvingSystem) -> Vec<u8> {
+    let powers = 6; // Powers of tau
+    let batch = 4;
+    let params = Phase1Parameters::<Zexe>::new_full(proving_system, powers, batch);
+    let compressed = UseCompression::Yes;
+    // Make 1 power of tau contribution (assume powers of tau gets calculated properly).
+    let (_, output, _, _) = setup_verify(compressed, CheckForCorrectness::Full, compressed, &params);
+    let accumulator = Phase1::deserialize(&output, compressed, CheckForCorrectness::Full, &params).unwrap();
+
+    // Prepare only the first 32 powers (for whatever reason).
+    let groth_params = Groth16Params::<Zexe>::new(
+        32,
+        accumulator.tau_powers_g1,
+        accumulator.tau_powers_g2,
+        accumulator.alpha_tau_powers_g1,
+        accumulator.beta_tau_powers_g1,
+        accumulator.beta_g2,
+    )
+    .unwrap();
+    // Write the transcript to a file.
+    let mut writer = vec![];
+    groth_params.write(&mut writer, compressed).unwrap();
+
+    writer
+}
+
 fn test_groth16_curve<Aleo: AleoPairingEngine, Zexe: ZexePairingEngine>() {
     let rng = &mut thread_rng();
     // Generate the parameters.
@@ -101,6 +112,55 @@ fn test_groth16_curve<Aleo: AleoPairingEngine, Zexe: ZexePairingEngine>() {
     assert!(res.is_ok());
 }
 
+fn test_specialized_groth16_curve<Aleo: AleoPairingEngine, Zexe: ZexePairingEngine>() {
+    let rng = &mut thread_rng();
+    let compressed = UseCompression::Yes;
+    let mut transcript = generate_groth16_transcript::<Zexe>(ProvingSystem::Groth16);
+
+    // Both the extended and the reduced circuit fit in a domain of size 8.
+    let mut groth_params =
+        || Groth16Params::<Zexe>::read(transcript.as_mut(), compressed, CheckForCorrectness::Full, 32, 8).unwrap();
+    let qap = |extended| {
+        let c = SpecializableCircuit::<Aleo> {
+            square_root: None,
+            extended,
+        };
+        circuit_to_qap::<Aleo, Zexe, _>(c).unwrap()
+    };
+
+    // Run phase 2 for the extended circuit.
+    let initial = MPCParameters::<Zexe>::new(qap(true), groth_params()).unwrap();
+    let mut last = initial.clone();
+    last.contribute(rng).unwrap();
+    last.contribute(rng).unwrap();
+
+    // Specialize the contributed parameters to the reduced circuit.
+    let specialized_initial = initial.specialize(&initial, qap(false), groth_params()).unwrap();
+    let specialized = last.specialize(&initial, qap(false), groth_params()).unwrap();
+    let hashes = MPCParameters::verify_chain(&specialized_initial, &specialized).unwrap();
+    assert_eq!(2, hashes.len());
+
+    // Convert the Zexe parameters to Aleo parameters.
+    let mut v = Vec::new();
+    specialized.get_params().serialize(&mut v).unwrap();
+    let params = AleoGroth16Params::<Aleo>::deserialize(&mut &v[..]).unwrap();
+
+    // Prepare the verification key (for proof verification)
+    let pvk = prepare_verifying_key(&params.vk);
+
+    // Create a proof of the reduced circuit with the specialized parameters.
+    let proof = {
+        let c = SpecializableCircuit::<Aleo> {
+            square_root: Some(Aleo::Fr::from(5)),
+            extended: false,
+        };
+        create_random_proof(c, &params, rng).unwrap()
+    };
+
+    let res = verify_proof(&pvk, &proof, &[Aleo::Fr::from(25u8)]);
+    assert!(res.unwrap());
+}
+
 #[test]
 fn test_groth16_bls12_377() {
     test_groth16_curve::<AleoBls12_377, Bls12_377>()
@@ -110,3 +170,8 @@ fn test_groth16_bls12_377() {
 fn test_groth16_bw6() {
     test_groth16_curve::<AleoBW6, BW6_761>()
 }
+
+#[test]
+fn test_specialized_groth16_bls12_377() {
+    test_specialized_groth16_curve::<AleoBls12_377, Bls12_377>()
+}
@@ -1,8 +1,8 @@
 use phase1::helpers::testing::random_point_vec;
-use setup_utils::{batch_exp, dense_multiexp, generate_powers_of_tau};
+use setup_utils::{batch_exp, batch_exp_secret, dense_multiexp, generate_powers_of_tau};
 
 use zexe_algebra::{
-    bls12_377::{Bls12_377, G1Affine},
+    bls12_377::{Bls12_377, G1Affine, G2Affine},
     AffineCurve,
     Field,
     PairingEngine,
@@ -11,7 +11,7 @@ use zexe_algebra::{
     Zero,
 };
 
-use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
 use rand::Rng;
 use std::ops::MulAssign;
 
@@ -75,6 +75,39 @@ fn benchmark_batchexp(c: &mut Criterion) {
     }
 }
 
+// Benchmark comparing batch_exp with batch_exp_secret, which Phase1::computation applies the
+// powers of tau with. The ladder of batch_exp_secret does an addition for every bit of the
+// exponent rather than for the set bits only, and this measures what that costs per element
+fn benchmark_batchexp_secret(c: &mut Criterion) {
+    let mut group = c.benchmark_group("SecretExponentiation");
+    group.sample_size(10);
+    let mut rng = rand::thread_rng();
+    let tau = <Bls12_377 as PairingEngine>::Fr::rand(&mut rng);
+    let coeff = <Bls12_377 as PairingEngine>::Fr::rand(&mut rng);
+
+    for len in (5..12).map(|i| 2u32.pow(i)) {
+        group.throughput(Throughput::Elements(len as u64));
+        let powers = generate_powers_of_tau::<Bls12_377>(&tau, 0, len as usize);
+
+        // Both groups are multiplied in a contribution, and the G2 operations are the more expensive.
+        let mut g1: Vec<G1Affine> = random_point_vec(len as usize, &mut rng);
+        let mut g2: Vec<G2Affine> = random_point_vec(len as usize, &mut rng);
+        group.bench_with_input(BenchmarkId::new("g1_batch_exp", len), &len, |b, _len| {
+            b.iter(|| batch_exp(&mut g1, &powers, Some(&coeff)).unwrap())
+        });
+        group.bench_with_input(BenchmarkId::new("g1_batch_exp_secret", len), &len, |b, _len| {
+            b.iter(|| batch_exp_secret(&mut g1, &powers, Some(&coeff)).unwrap())
+        });
+        group.bench_with_input(BenchmarkId::new("g2_batch_exp", len), &len, |b, _len| {
+            b.iter(|| batch_exp(&mut g2, &powers, Some(&coeff)).unwrap())
+        });
+        group.bench_with_input(BenchmarkId::new("g2_batch_exp_secret", len), &len, |b, _len| {
+            b.iter(|| batch_exp_secret(&mut g2, &powers, Some(&coeff)).unwrap())
+        });
+    }
+    group.finish();
+}
+
 // Benchmark for finding the optimal batch size for power_pairs
 fn benchmark_multiexp(c: &mut Criterion) {
     let mut group = c.benchmark_group("Multiexp");
@@ -86,12 +119,27 @@ fn benchmark_multiexp(c: &mut Criterion) {
         let randomness = randomness(&v1, &mut rng);
 
         group.bench_with_input("dense", &len, |b, _len| b.iter(|| dense_multiexp(&v1, &randomness)));
+        group.bench_with_input("serial", &len, |b, _len| b.iter(|| serial_multiexp(&v1, &randomness)));
     }
 }
 
+// The naive multiexp with a scalar multiplication per term, as the baseline for the bucketed multiexp
+fn serial_multiexp<G: AffineCurve>(bases: &[G], exponents: &[<G::ScalarField as PrimeField>::BigInt]) -> G::Projective {
+    bases
+        .iter()
+        .zip(exponents)
+        .fold(G::Projective::zero(), |acc, (base, exp)| acc + &base.mul(*exp))
+}
+
 fn randomness<G: AffineCurve>(v: &[G], rng: &mut impl Rng) -> Vec<<G::ScalarField as PrimeField>::BigInt> {
     (0..v.len()).map(|_| G::ScalarField::rand(rng).into_repr()).collect()
 }
 
-criterion_group!(benches, benchmark_phase1, benchmark_batchexp, benchmark_multiexp);
+criterion_group!(
+    benches,
+    benchmark_phase1,
+    benchmark_batchexp,
+    benchmark_batchexp_secret,
+    benchmark_multiexp
+);
 criterion_main!(benches);
@@ -23,6 +23,8 @@ pub enum CheckForCorrectness {
     Full,
     OnlyNonZero,
     OnlyInGroup,
+    /// Only checks that compressed points use the canonical encoding.
+    CanonicalOnly,
     No,
 }
 
@@ -32,6 +34,7 @@ impl fmt::Display for CheckForCorrectness {
             CheckForCorrectness::Full => write!(f, "Full"),
             CheckForCorrectness::OnlyNonZero => write!(f, "OnlyNonZero"),
             CheckForCorrectness::OnlyInGroup => write!(f, "OnlyInGroup"),
+            CheckForCorrectness::CanonicalOnly => write!(f, "CanonicalOnly"),
             CheckForCorrectness::No => write!(f, "No"),
         }
     }
@@ -21,18 +21,30 @@ pub enum Error {
     VerificationError(#[from] VerificationError),
     #[error("Invalid variable length: expected {expected}, got {got}")]
     InvalidLength { expected: usize, got: usize },
+    #[error("Expected at least {expected} elements, but the buffer holds {got}")]
+    InsufficientElements { expected: usize, got: usize },
     #[error("Chunk does not have a min and max")]
     InvalidChunk,
     #[error("R1CS Error: {0}")]
     SynthesisError(#[from] SynthesisError),
     #[error("Phase 2 Error: {0}")]
     Phase2Error(#[from] Phase2Error),
+    #[error("Invalid contribution {0}: {1}")]
+    InvalidContribution(usize, Box<Error>),
     #[error("Crossbeam error during while joining the thread")]
     CrossBeamError,
-    #[error("Got point not in the prime order subgroup")]
-    IncorrectSubgroup,
+    #[error("Got {0} point not in the prime order subgroup")]
+    IncorrectSubgroup(ElementType),
+    #[error("Invalid {0} elements: {1}")]
+    InvalidElements(ElementType, Box<Error>),
     #[error("Got invalid decompression parameters")]
     InvalidDecompressionParametersError,
+    #[error("Invalid thread budget of {0} threads")]
+    InvalidThreadBudget(usize),
+    #[error("Polynomial degree too large: requires a domain of size {required}, but at most {supported} is supported")]
+    PolynomialDegreeTooLarge { required: usize, supported: usize },
+    #[error("Operation was cancelled")]
+    Cancelled,
 }
 
 impl From<Box<dyn std::any::Any + Send>> for Error {
@@ -49,8 +61,16 @@ pub enum Phase2Error {
     InvalidLength,
     #[error("There were no contributions found")]
     NoContributions,
+    #[error("The contributions do not match the hash the stripped parameters commit to")]
+    ContributionsHashMismatch,
     #[error("The Transcript was not consistent")]
     InvalidTranscript,
+    #[error("The specialized circuit has {found} inputs, but the parameters have {expected}")]
+    SpecializationInputsMismatch { expected: usize, found: usize },
+    #[error("The specialized circuit does not use the same domain as the parameters")]
+    SpecializationDomainMismatch,
+    #[error("Auxiliary variable {0} of the specialized circuit is not constrained as in the parameters")]
+    SpecializationVariableMismatch(usize),
 }
 
 #[derive(PartialEq, Debug, Clone)]
@@ -99,4 +119,16 @@ pub enum VerificationError {
     #[error("Invalid generator for {0} powers")]
     /// The first power of Tau was not the generator of that group
     InvalidGenerator(ElementType),
+    #[error("Non-canonical encoding of a compressed point")]
+    /// The bytes are not the canonical encoding of a compressed point
+    NonCanonicalEncoding,
+    #[error("Invalid batch commitment")]
+    /// The batch commitment trailer does not match the batches of the accumulator
+    InvalidBatchCommitment,
+    #[error("Invalid proof for batch {0}")]
+    /// The Merkle path of the batch does not lead to the batch commitment root
+    InvalidBatchProof(usize),
+    #[error("The hash of the output does not match the expected hash")]
+    /// The output was corrupted or replaced before it was received
+    OutputHashMismatch,
 }
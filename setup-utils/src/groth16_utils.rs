@@ -1,8 +1,8 @@
 /// Utilities to read/write and convert the Powers of Tau from Phase 1
 /// to Phase 2-compatible Lagrange Coefficients.
-use crate::{buffer_size, CheckForCorrectness, Deserializer, Result, Serializer, UseCompression};
+use crate::{buffer_size, CheckForCorrectness, Deserializer, Error, Result, Serializer, UseCompression};
 
-use zexe_algebra::{AffineCurve, PairingEngine, PrimeField, ProjectiveCurve};
+use zexe_algebra::{AffineCurve, FpParameters, PairingEngine, PrimeField, ProjectiveCurve};
 use zexe_fft::{
     cfg_into_iter,
     cfg_iter,
@@ -39,6 +39,25 @@ impl<E: PairingEngine> PartialEq for Groth16Params<E> {
     }
 }
 
+/// Returns the largest evaluation domain supported by the scalar field of the given curve,
+/// which bounds the number of constraints of a circuit in phase 2.
+pub fn max_supported_domain_size<E: PairingEngine>() -> usize {
+    let two_adicity = <<E::Fr as PrimeField>::Params as FpParameters>::TWO_ADICITY;
+    1usize.checked_shl(two_adicity).unwrap_or(usize::MAX)
+}
+
+/// Checks that an evaluation domain of the given size is supported by the scalar field of the given curve.
+fn check_domain_size<E: PairingEngine>(size: usize) -> Result<()> {
+    let supported = max_supported_domain_size::<E>();
+    if size > supported {
+        return Err(Error::PolynomialDegreeTooLarge {
+            required: size,
+            supported,
+        });
+    }
+    Ok(())
+}
+
 /// Performs an IFFT over the provided evaluation domain to the provided
 /// vector of affine points. It then normalizes and returns them back into
 /// affine form
@@ -71,6 +90,10 @@ impl<E: PairingEngine> Groth16Params<E> {
     /// # Panics
     ///
     /// If `phase2_size` > length of any of the provided vectors
+    ///
+    /// # Errors
+    ///
+    /// If `phase2_size` > `max_supported_domain_size`
     pub fn new(
         phase2_size: usize,
         tau_powers_g1: Vec<E::G1Affine>,
@@ -83,7 +106,12 @@ impl<E: PairingEngine> Groth16Params<E> {
         let _enter = span.enter();
 
         // Create the evaluation domain
-        let domain = Radix2EvaluationDomain::<E::Fr>::new(phase2_size).expect("could not create domain");
+        check_domain_size::<E>(phase2_size)?;
+        let domain =
+            Radix2EvaluationDomain::<E::Fr>::new(phase2_size).ok_or_else(|| Error::PolynomialDegreeTooLarge {
+                required: phase2_size,
+                supported: max_supported_domain_size::<E>(),
+            })?;
 
         info!("converting powers of tau to lagrange coefficients");
 
@@ -172,6 +200,8 @@ impl<E: PairingEngine> Groth16Params<E> {
         let span = info_span!("Groth16Utils_read");
         let _enter = span.enter();
 
+        check_domain_size::<E>(num_constraints)?;
+
         let mut reader = std::io::Cursor::new(reader);
         let alpha_g1 = reader.read_element(compressed, check_input_for_correctness)?;
         let beta_g1 = reader.read_element(compressed, check_input_for_correctness)?;
@@ -290,6 +320,7 @@ mod tests {
                 CheckForCorrectness::Full => CheckForCorrectnessPhase1::Full,
                 CheckForCorrectness::OnlyNonZero => CheckForCorrectnessPhase1::OnlyNonZero,
                 CheckForCorrectness::OnlyInGroup => CheckForCorrectnessPhase1::OnlyInGroup,
+                CheckForCorrectness::CanonicalOnly => CheckForCorrectnessPhase1::No,
                 CheckForCorrectness::No => CheckForCorrectnessPhase1::No,
             }
         }
@@ -320,6 +351,27 @@ mod tests {
         )
         .unwrap();
 
+        // Only the prefix of the accumulator used by phase 2 is needed for the same parameters.
+        let prefix = Phase1::deserialize_prefix(
+            &output,
+            compat(compressed),
+            compat_correctness(CheckForCorrectness::Full),
+            2 * prepared_phase1_size - 1,
+            prepared_phase1_size,
+            &params,
+        )
+        .unwrap();
+        let prefix_params = Groth16Params::<E>::new(
+            prepared_phase1_size,
+            prefix.tau_powers_g1,
+            prefix.tau_powers_g2,
+            prefix.alpha_tau_powers_g1,
+            prefix.beta_tau_powers_g1,
+            prefix.beta_g2,
+        )
+        .unwrap();
+        assert_eq!(prefix_params, groth_params);
+
         let mut writer = vec![];
         groth_params.write(&mut writer, compressed).unwrap();
         let mut reader = std::io::Cursor::new(writer);
@@ -384,4 +436,24 @@ mod tests {
     fn large_phase2_uncompressed_fails() {
         read_write_curve::<Bls12_377>(3, 9, UseCompression::No);
     }
+
+    #[test]
+    fn phase2_larger_than_field_fails() {
+        // Bls12_377 supports evaluation domains of up to 2^47 elements.
+        let supported = max_supported_domain_size::<Bls12_377>();
+        assert_eq!(1 << 47, supported);
+
+        // Check that the size is rejected before the powers are read.
+        let beta_g2 = <Bls12_377 as PairingEngine>::G2Affine::prime_subgroup_generator();
+        match Groth16Params::<Bls12_377>::new(supported + 1, vec![], vec![], vec![], vec![], beta_g2) {
+            Err(Error::PolynomialDegreeTooLarge { required, .. }) => assert_eq!(supported + 1, required),
+            result => panic!("Expected the polynomial degree to be too large, got {:?}", result),
+        }
+        let result =
+            Groth16Params::<Bls12_377>::read(&mut [], UseCompression::Yes, CheckForCorrectness::No, 0, supported + 1);
+        match result {
+            Err(Error::PolynomialDegreeTooLarge { required, .. }) => assert_eq!(supported + 1, required),
+            result => panic!("Expected the polynomial degree to be too large, got {:?}", result),
+        }
+    }
 }
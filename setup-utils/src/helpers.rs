@@ -23,9 +23,16 @@ use rand_chacha::ChaChaRng;
 use std::{
     convert::TryInto,
     io::{self, Write},
+    mem,
     ops::{AddAssign, Mul},
-    sync::Arc,
+    ptr,
+    slice,
+    sync::{
+        atomic::{self, Ordering},
+        Arc,
+    },
 };
+use subtle::{Choice, ConditionallySelectable};
 use typenum::consts::U64;
 
 #[cfg(not(feature = "wasm"))]
@@ -105,6 +112,104 @@ pub fn batch_exp<C: AffineCurve>(
     Ok(())
 }
 
+/// Raises each base to the corresponding secret exponent, multiplied by the secret coefficient
+/// if one is given. Unlike `batch_exp`, each multiplication is done with `mul_secret`, and the
+/// products of the exponents with the coefficient are zeroized once they are applied.
+pub fn batch_exp_secret<C: AffineCurve>(
+    bases: &mut [C],
+    exps: &[C::ScalarField],
+    coeff: Option<&C::ScalarField>,
+) -> Result<()> {
+    if bases.len() != exps.len() {
+        return Err(Error::InvalidLength {
+            expected: bases.len(),
+            got: exps.len(),
+        });
+    }
+    let mut points: Vec<_> = cfg_iter_mut!(bases)
+        .zip(exps)
+        .map(|(base, exp)| {
+            let mut exp = if let Some(coeff) = coeff { exp.mul(coeff) } else { *exp };
+            let point = mul_secret(base, &exp);
+            zeroize_secret(&mut exp);
+            point
+        })
+        .collect();
+    C::Projective::batch_normalization(&mut points);
+    cfg_iter_mut!(bases)
+        .zip(points)
+        .for_each(|(base, proj)| *base = proj.into_affine());
+
+    Ok(())
+}
+
+/// Multiplies the base by a secret scalar with a Montgomery ladder.
+///
+/// The multiplication in Zexe skips the additions for the zero bits of the scalar, so its
+/// running time depends on the scalar. The ladder does one addition and one doubling for every
+/// bit of the scalar representation, whatever its value. Both accumulators start offset by the
+/// base, so neither is the identity, which the group law handles separately, and the offset is
+/// subtracted at the end. The accumulator to double is selected with a conditional swap, so
+/// neither the branches taken nor the memory accessed depend on the bit.
+///
+/// This only covers the sequence of group operations: the field arithmetic of Zexe is not
+/// constant time, and the group law still has a special case for equal points, which
+/// is only reached with negligible probability for a random scalar.
+pub fn mul_secret<C: AffineCurve>(base: &C, scalar: &C::ScalarField) -> C::Projective {
+    let base = base.into_projective();
+
+    // The accumulators always differ by the base.
+    let (mut low, mut high) = (base, base.double());
+    let mut offset = base;
+
+    // The accumulators are swapped while the last bit is set, so the one to double is always `low`.
+    let mut swapped = Choice::from(0);
+    let mut repr = scalar.into_repr();
+    for limb in repr.as_ref().iter().rev() {
+        for i in (0..64).rev() {
+            let bit = Choice::from(((limb >> i) & 1) as u8);
+            conditional_swap(&mut low, &mut high, swapped ^ bit);
+            swapped = bit;
+            high += &low;
+            low.double_in_place();
+            offset.double_in_place();
+        }
+    }
+    conditional_swap(&mut low, &mut high, swapped);
+    zeroize_secret(&mut repr);
+
+    low - &offset
+}
+
+/// Swaps the given points if the choice is set, without branching on it or indexing memory with it.
+fn conditional_swap<P: ProjectiveCurve>(a: &mut P, b: &mut P, choice: Choice) {
+    // The points of Zexe are made of field elements, whose limbs are 64-bit words.
+    assert!(mem::size_of::<P>() % mem::size_of::<u64>() == 0 && mem::align_of::<P>() >= mem::align_of::<u64>());
+    let words = mem::size_of::<P>() / mem::size_of::<u64>();
+
+    // The words of both points are swapped, or none of them, so both remain valid points.
+    let a = unsafe { slice::from_raw_parts_mut(a as *mut P as *mut u64, words) };
+    let b = unsafe { slice::from_raw_parts_mut(b as *mut P as *mut u64, words) };
+    for (a, b) in a.iter_mut().zip(b.iter_mut()) {
+        u64::conditional_swap(a, b, choice);
+    }
+}
+
+/// Overwrites the given secret with its default value, which is zero for field
+/// elements and integers, in a way the compiler does not optimize away.
+pub fn zeroize_secret<T: Default>(secret: &mut T) {
+    // The write is volatile, as a write to memory that is never read again may be elided.
+    unsafe { ptr::write_volatile(secret, T::default()) };
+    atomic::compiler_fence(Ordering::SeqCst);
+}
+
+/// Overwrites each of the given secrets with its default value. See `zeroize_secret`.
+pub fn zeroize_secrets<T: Default>(secrets: &mut [T]) {
+    for secret in secrets.iter_mut() {
+        zeroize_secret(secret);
+    }
+}
+
 // Create an RNG based on a mixture of system randomness and user provided randomness
 pub fn user_system_randomness() -> Vec<u8> {
     let mut system_rng = OsRng;
@@ -229,12 +334,43 @@ impl<W: Write> Write for HashWriter<W> {
 /// implementation now writes without a particular order, so plain recalculation at the end
 /// of the procedure is more efficient
 pub fn calculate_hash(input_map: &[u8]) -> GenericArray<u8, U64> {
-    let chunk_size = 1 << 30; // read by 1GB from map
-    let mut hasher = Blake2b::default();
-    for chunk in input_map.chunks(chunk_size) {
-        hasher.input(&chunk);
+    <Blake2b as TranscriptHasher>::calculate_hash(input_map)
+}
+
+/// The hash function of the transcript, which computes the running hash of the accumulator
+/// and the hashes into G2 of the proofs of knowledge. It defaults to BLAKE2b, which is
+/// implemented by `Blake2b`, and other hash functions may implement it to experiment with.
+pub trait TranscriptHasher: Default {
+    /// Absorbs the given data into the hash.
+    fn update(&mut self, data: &[u8]);
+
+    /// Returns the 64-byte hash of the absorbed data.
+    fn finalize(self) -> GenericArray<u8, U64>;
+
+    /// Returns the hash of no data.
+    fn blank_hash() -> GenericArray<u8, U64> {
+        Self::default().finalize()
+    }
+
+    /// Returns the hash of the given accumulator.
+    fn calculate_hash(input_map: &[u8]) -> GenericArray<u8, U64> {
+        let chunk_size = 1 << 30; // read by 1GB from map
+        let mut hasher = Self::default();
+        for chunk in input_map.chunks(chunk_size) {
+            hasher.update(&chunk);
+        }
+        hasher.finalize()
+    }
+}
+
+impl TranscriptHasher for Blake2b {
+    fn update(&mut self, data: &[u8]) {
+        self.input(data);
+    }
+
+    fn finalize(self) -> GenericArray<u8, U64> {
+        self.result()
     }
-    hasher.result()
 }
 
 /// Hashes to G2 using the first 32 bytes of `digest`. Panics if `digest` is less
@@ -265,7 +401,7 @@ mod tests {
     use super::*;
     use zexe_algebra::{
         bls12_377::Bls12_377,
-        bls12_381::{Bls12_381, Fr, G1Affine, G2Affine},
+        bls12_381::{Bls12_381, Fr, G1Affine, G1Projective, G2Affine},
     };
 
     #[test]
@@ -310,6 +446,80 @@ mod tests {
         assert!(!same_ratio::<Bls12_381>(&(g1_s, g1), &(g2, g2_s)));
     }
 
+    #[test]
+    fn test_dense_multiexp() {
+        let rng = &mut thread_rng();
+
+        let len = 1000;
+        let bases: Vec<G1Affine> = (0..len)
+            .map(|_| G1Affine::prime_subgroup_generator().mul(Fr::rand(rng)).into_affine())
+            .collect();
+        let exponents: Vec<_> = (0..len).map(|_| Fr::rand(rng).into_repr()).collect();
+
+        // Compare against a serial multiexp with each term added separately.
+        let expected = bases
+            .iter()
+            .zip(&exponents)
+            .fold(G1Projective::zero(), |acc, (base, exp)| acc + &base.mul(*exp));
+
+        assert_eq!(expected, dense_multiexp(&bases, &exponents));
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_dense_multiexp_thread_pool() {
+        let rng = &mut thread_rng();
+
+        let len = 1000;
+        let bases: Vec<G1Affine> = (0..len)
+            .map(|_| G1Affine::prime_subgroup_generator().mul(Fr::rand(rng)).into_affine())
+            .collect();
+        let exponents: Vec<_> = (0..len).map(|_| Fr::rand(rng).into_repr()).collect();
+        let expected = dense_multiexp(&bases, &exponents);
+
+        // The multiexp runs on the threads of the pool it is called from.
+        for num_threads in &[1, 2] {
+            let pool = rayon::ThreadPoolBuilder::new()
+                .num_threads(*num_threads)
+                .build()
+                .unwrap();
+            let candidate = pool.install(|| {
+                assert_eq!(*num_threads, crate::rayon_cfg::current_num_threads());
+                dense_multiexp(&bases, &exponents)
+            });
+            assert_eq!(expected, candidate);
+        }
+    }
+
+    #[test]
+    fn test_dense_multiexp_sparse() {
+        let rng = &mut thread_rng();
+
+        let len = 1000;
+        let bases: Vec<G1Affine> = (0..len)
+            .map(|_| G1Affine::prime_subgroup_generator().mul(Fr::rand(rng)).into_affine())
+            .collect();
+        // Only every tenth exponent is non-zero.
+        let exponents: Vec<_> = (0..len)
+            .map(|i| match i % 10 {
+                0 => Fr::rand(rng).into_repr(),
+                _ => Fr::zero().into_repr(),
+            })
+            .collect();
+
+        let expected = bases
+            .iter()
+            .zip(&exponents)
+            .fold(G1Projective::zero(), |acc, (base, exp)| acc + &base.mul(*exp));
+
+        assert_eq!(expected, dense_multiexp_unfiltered(&bases, &exponents));
+        assert_eq!(expected, dense_multiexp(&bases, &exponents));
+
+        // All of the terms are dropped when every exponent is zero.
+        let exponents = vec![Fr::zero().into_repr(); len];
+        assert_eq!(G1Projective::zero(), dense_multiexp(&bases, &exponents));
+    }
+
     #[test]
     fn test_power_pairs() {
         use std::ops::MulAssign;
@@ -337,6 +547,83 @@ mod tests {
             &(G2Affine::prime_subgroup_generator(), gx)
         ));
     }
+
+    #[test]
+    fn test_batch_exp_secret() {
+        let rng = &mut thread_rng();
+
+        let len = 100;
+        let bases: Vec<G1Affine> = (0..len)
+            .map(|_| G1Affine::prime_subgroup_generator().mul(Fr::rand(rng)).into_affine())
+            .collect();
+        let mut exps: Vec<Fr> = (0..len).map(|_| Fr::rand(rng)).collect();
+        // The edge cases of the ladder.
+        exps[0] = Fr::zero();
+        exps[1] = Fr::one();
+        exps[2] = -Fr::one();
+        let coeff = Fr::rand(rng);
+
+        for coeff in [None, Some(&coeff)].iter() {
+            let mut expected = bases.clone();
+            batch_exp(&mut expected, &exps, *coeff).unwrap();
+            let mut actual = bases.clone();
+            batch_exp_secret(&mut actual, &exps, *coeff).unwrap();
+            assert_eq!(expected, actual);
+        }
+    }
+
+    // Timings are too noisy in debug builds and on loaded machines to run by default. Run it with
+    // `cargo test --release -p setup-utils test_mul_secret_timing -- --ignored` on an idle machine.
+    #[test]
+    #[ignore]
+    fn test_mul_secret_timing_is_independent_of_weight() {
+        use std::time::Instant;
+
+        let base = G1Affine::prime_subgroup_generator();
+
+        // The scalars range from a weight of 1 to the full weight of the field.
+        let weights: Vec<u32> = (0..8).map(|i| 1 + i * 36).collect();
+        let timings: Vec<f64> = weights
+            .iter()
+            .map(|weight| {
+                let scalar = Fr::from_repr(low_bits(*weight)).unwrap();
+                // The minimum of the runs discards the noise from the scheduler.
+                (0..20)
+                    .map(|_| {
+                        let start = Instant::now();
+                        let _ = mul_secret(&base, &scalar);
+                        start.elapsed().as_nanos() as f64
+                    })
+                    .fold(f64::INFINITY, f64::min)
+            })
+            .collect();
+
+        let weights: Vec<f64> = weights.iter().map(|weight| *weight as f64).collect();
+        let correlation = correlation(&weights, &timings);
+        assert!(correlation.abs() < 0.9, "correlation was {}", correlation);
+    }
+
+    /// Returns the integer with the given number of low bits set.
+    fn low_bits(weight: u32) -> <Fr as PrimeField>::BigInt {
+        let mut repr = <Fr as PrimeField>::BigInt::default();
+        for (i, limb) in repr.as_mut().iter_mut().enumerate() {
+            let bits = weight.saturating_sub(64 * i as u32).min(64);
+            *limb = match bits {
+                64 => u64::max_value(),
+                _ => (1 << bits) - 1,
+            };
+        }
+        repr
+    }
+
+    /// Returns the Pearson correlation coefficient of the samples.
+    fn correlation(xs: &[f64], ys: &[f64]) -> f64 {
+        let mean = |v: &[f64]| v.iter().sum::<f64>() / v.len() as f64;
+        let (mean_x, mean_y) = (mean(xs), mean(ys));
+        let covariance: f64 = xs.iter().zip(ys).map(|(x, y)| (x - mean_x) * (y - mean_y)).sum();
+        let deviation = |v: &[f64], mean: f64| v.iter().map(|x| (x - mean).powi(2)).sum::<f64>().sqrt();
+        covariance / (deviation(xs, mean_x) * deviation(ys, mean_y))
+    }
 }
 
 pub fn merge_pairs<G: AffineCurve>(v1: &[G], v2: &[G]) -> (G, G) {
@@ -360,7 +647,7 @@ pub fn power_pairs<G: AffineCurve>(v: &[G]) -> (G, G) {
 
 /// Compute BLAKE2b("")
 pub fn blank_hash() -> GenericArray<u8, U64> {
-    Blake2b::new().result()
+    <Blake2b as TranscriptHasher>::blank_hash()
 }
 
 pub fn reduced_hash(old_power: u8, new_power: u8) -> GenericArray<u8, U64> {
@@ -369,6 +656,24 @@ pub fn reduced_hash(old_power: u8, new_power: u8) -> GenericArray<u8, U64> {
     hasher.result()
 }
 
+/// Compute BLAKE2b(0x00 | data), the hash of a Merkle tree leaf.
+pub fn merkle_leaf_hash(data: &[u8]) -> GenericArray<u8, U64> {
+    let mut hasher = Blake2b::new();
+    hasher.input(&[0u8]);
+    hasher.input(data);
+    hasher.result()
+}
+
+/// Compute BLAKE2b(0x01 | left | right), the hash of a Merkle tree node.
+/// The distinct prefixes keep a node from being passed off as a leaf.
+pub fn merkle_node_hash(left: &[u8], right: &[u8]) -> GenericArray<u8, U64> {
+    let mut hasher = Blake2b::new();
+    hasher.input(&[1u8]);
+    hasher.input(left);
+    hasher.input(right);
+    hasher.result()
+}
+
 /// Checks if pairs have the same ratio.
 /// Under the hood uses pairing to check
 /// x1/x2 = y1/y2 => x1*y2 = x2*y1
@@ -395,17 +700,33 @@ pub fn compute_g2_s<E: PairingEngine>(
     g1_s_x: &E::G1Affine,
     personalization: u8,
 ) -> Result<E::G2Affine> {
-    let mut h = Blake2b::default();
-    h.input(&[personalization]);
-    h.input(digest);
+    compute_g2_s_with_hasher::<E, Blake2b>(digest, g1_s, g1_s_x, personalization)
+}
+
+/// Compute H(personalization | transcript | g^s | g^{s*x}) with the given transcript hasher
+/// and then hash it to G2
+pub fn compute_g2_s_with_hasher<E: PairingEngine, H: TranscriptHasher>(
+    digest: &[u8],
+    g1_s: &E::G1Affine,
+    g1_s_x: &E::G1Affine,
+    personalization: u8,
+) -> Result<E::G2Affine> {
+    let mut h = H::default();
+    h.update(&[personalization]);
+    h.update(digest);
     let size = E::G1Affine::SERIALIZED_SIZE;
     let mut data = vec![0; 2 * size];
     g1_s.serialize(&mut &mut data[..size])?;
     g1_s_x.serialize(&mut &mut data[size..])?;
-    h.input(&data);
-    Ok(hash_to_g2::<E>(h.result().as_ref()).into_affine())
+    h.update(&data);
+    Ok(hash_to_g2::<E>(h.finalize().as_ref()).into_affine())
 }
 
+/// The fraction of zero exponents at which `dense_multiexp` drops the zero terms
+/// before bucketing. Every region rescans all of the exponents, so filtering
+/// them out once up front pays for itself well before this point.
+const DENSE_MULTIEXP_ZERO_THRESHOLD: f64 = 0.25;
+
 /// Perform multi-exponentiation. The caller is responsible for ensuring that
 /// the number of bases is the same as the number of exponents.
 #[allow(dead_code)]
@@ -416,6 +737,26 @@ pub fn dense_multiexp<G: AffineCurve>(
     if exponents.len() != bases.len() {
         panic!("invalid length")
     }
+
+    let zero = G::ScalarField::zero().into_repr();
+    let zeros = exponents.iter().filter(|exp| **exp == zero).count();
+    if zeros > 0 && zeros as f64 >= exponents.len() as f64 * DENSE_MULTIEXP_ZERO_THRESHOLD {
+        let (bases, exponents): (Vec<_>, Vec<_>) = bases
+            .iter()
+            .zip(exponents)
+            .filter(|(_, exp)| **exp != zero)
+            .map(|(base, exp)| (*base, *exp))
+            .unzip();
+        return dense_multiexp_unfiltered(&bases, &exponents);
+    }
+
+    dense_multiexp_unfiltered(bases, exponents)
+}
+
+fn dense_multiexp_unfiltered<G: AffineCurve>(
+    bases: &[G],
+    exponents: &[<G::ScalarField as PrimeField>::BigInt],
+) -> G::Projective {
     let c = if exponents.len() < 32 {
         3u32
     } else {
@@ -435,12 +776,14 @@ fn dense_multiexp_inner<G: AffineCurve>(
     use std::sync::Mutex;
     // Perform this region of the multiexp. We use a different strategy - go over region in parallel,
     // then over another region, etc. No Arc required
-    let chunk = (bases.len() / num_cpus::get()) + 1;
+    // The region is split across the threads of the current pool, so a caller
+    // running within a smaller pool bounds the number of threads used.
+    let chunk = (bases.len() / crate::rayon_cfg::current_num_threads()) + 1;
     let this = {
         // let mask = (1u64 << c) - 1u64;
         let this_region = Mutex::new(G::Projective::zero());
         let arc = Arc::new(this_region);
-        crossbeam::scope(|scope| {
+        crate::rayon_cfg::scope(|scope| {
             for (base, exp) in bases.chunks(chunk).zip(exponents.chunks(chunk)) {
                 let this_region_rwlock = arc.clone();
                 // let handle =
@@ -488,8 +831,7 @@ fn dense_multiexp_inner<G: AffineCurve>(
                     (*guard).add_assign(&acc);
                 });
             }
-        })
-        .expect("dense_multiexp failed");
+        });
 
         let this_region = Arc::try_unwrap(arc).unwrap();
 
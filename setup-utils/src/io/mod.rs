@@ -21,9 +21,15 @@ mod tests {
     use super::*;
     use phase1::helpers::testing::random_point_vec;
 
-    use zexe_algebra::bls12_377::{G1Affine, G2Affine};
+    use zexe_algebra::{
+        bls12_377::{FqParameters, G1Affine, G2Affine},
+        BigInteger,
+        FpParameters,
+        PrimeField,
+        ToBytes,
+    };
 
-    use crate::CheckForCorrectness;
+    use crate::{CheckForCorrectness, Error, VerificationError};
     use rand::thread_rng;
 
     #[test]
@@ -58,6 +64,53 @@ mod tests {
         read_write_batch_element_preallocated::<G2Affine>(UseCompression::Yes);
     }
 
+    #[test]
+    fn read_canonical_compressed() {
+        let el = G1Affine::prime_subgroup_generator();
+        let mut buf = vec![];
+        buf.write_element(&el, UseCompression::Yes).unwrap();
+
+        for check in &[CheckForCorrectness::Full, CheckForCorrectness::CanonicalOnly] {
+            let deserialized: G1Affine = buf.read_element(UseCompression::Yes, *check).unwrap();
+            assert_eq!(el, deserialized);
+        }
+    }
+
+    #[test]
+    fn read_non_canonical_compressed() {
+        let el = G1Affine::prime_subgroup_generator();
+        let mut buf = vec![];
+        buf.write_element(&el, UseCompression::Yes).unwrap();
+        let flags = buf[buf.len() - 1] & 0b1100_0000;
+
+        // Encode x + p in place of x, keeping the original flags.
+        let mut x = el.x.into_repr();
+        x.add_nocarry(&FqParameters::MODULUS);
+        let mut non_canonical = vec![];
+        x.write(&mut non_canonical).unwrap();
+        assert_eq!(buf.len(), non_canonical.len());
+        assert_eq!(0, non_canonical[non_canonical.len() - 1] & 0b1100_0000);
+        *non_canonical.last_mut().unwrap() |= flags;
+
+        for check in &[CheckForCorrectness::Full, CheckForCorrectness::CanonicalOnly] {
+            match non_canonical.read_element::<G1Affine>(UseCompression::Yes, *check) {
+                Err(Error::VerificationError(VerificationError::NonCanonicalEncoding)) => {}
+                result => panic!("Expected a non-canonical encoding error, found {:?}", result),
+            }
+        }
+
+        // Set the infinity flag on top of a non-zero x-coordinate.
+        let mut non_canonical = buf.clone();
+        *non_canonical.last_mut().unwrap() |= 0b0100_0000;
+
+        for check in &[CheckForCorrectness::Full, CheckForCorrectness::CanonicalOnly] {
+            match non_canonical.read_element::<G1Affine>(UseCompression::Yes, *check) {
+                Err(Error::VerificationError(VerificationError::NonCanonicalEncoding)) => {}
+                result => panic!("Expected a non-canonical encoding error, found {:?}", result),
+            }
+        }
+    }
+
     fn read_write_single_element<E: AffineCurve>(compression: UseCompression) {
         // uncompressed buffers are twice the size
         let el = E::prime_subgroup_generator();
@@ -1,4 +1,4 @@
-use crate::{buffer_size, CheckForCorrectness, Error, Result, UseCompression};
+use crate::{buffer_size, CheckForCorrectness, Error, Result, UseCompression, VerificationError};
 
 use zexe_algebra::AffineCurve;
 use zexe_fft::cfg_chunks;
@@ -61,9 +61,18 @@ impl<R: Read> Deserializer for R {
         check_for_correctness: CheckForCorrectness,
     ) -> Result<G> {
         let point = match compression {
-            UseCompression::Yes => G::deserialize(self)?,
+            UseCompression::Yes => {
+                if check_for_correctness == CheckForCorrectness::Full
+                    || check_for_correctness == CheckForCorrectness::CanonicalOnly
+                {
+                    read_canonical_compressed(self)?
+                } else {
+                    G::deserialize(self)?
+                }
+            }
             UseCompression::No => {
                 if check_for_correctness == CheckForCorrectness::OnlyNonZero
+                    || check_for_correctness == CheckForCorrectness::CanonicalOnly
                     || check_for_correctness == CheckForCorrectness::No
                 {
                     G::deserialize_unchecked(self)?
@@ -94,6 +103,42 @@ impl<R: Read> Deserializer for R {
     }
 }
 
+/// The largest compressed element read by [read_canonical_compressed], which is a G2 element of BW6-761.
+const MAX_COMPRESSED_SIZE: usize = 192;
+
+/// Reads 1 compressed element and checks that it is the canonical encoding of a point.
+/// The underlying deserializer may accept encodings with a non-reduced x-coordinate
+/// or stray bits alongside the infinity flag, which would otherwise let the same point
+/// be submitted under several encodings. Bytes which do not decode to a point at all
+/// are not a canonical encoding either, so every failure is reported as
+/// [VerificationError::NonCanonicalEncoding].
+///
+/// The element is read into and re-serialized from buffers on the stack, so reading
+/// a batch of elements does not allocate for every element.
+fn read_canonical_compressed<G: AffineCurve, R: Read>(reader: &mut R) -> Result<G> {
+    let size = buffer_size::<G>(UseCompression::Yes);
+    if size > MAX_COMPRESSED_SIZE {
+        return Err(Error::InvalidLength {
+            expected: MAX_COMPRESSED_SIZE,
+            got: size,
+        });
+    }
+
+    let mut encoded = [0u8; MAX_COMPRESSED_SIZE];
+    let encoded = &mut encoded[..size];
+    reader.read_exact(encoded)?;
+
+    let point = G::deserialize(&mut &encoded[..]).map_err(|_| VerificationError::NonCanonicalEncoding)?;
+
+    let mut reencoded = [0u8; MAX_COMPRESSED_SIZE];
+    point.serialize(&mut &mut reencoded[..size])?;
+    if reencoded[..size] != encoded[..] {
+        return Err(VerificationError::NonCanonicalEncoding.into());
+    }
+
+    Ok(point)
+}
+
 // We implement this for slices so that the consumer does not need to write the `&mut slice.as_ref()`
 // boilerplate in each call. This should have no performance overhead
 impl Deserializer for [u8] {
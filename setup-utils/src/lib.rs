@@ -9,7 +9,7 @@ pub use errors::{Error, InvariantKind, Phase2Error, VerificationError};
 pub type Result<T> = std::result::Result<T, Error>;
 
 mod groth16_utils;
-pub use groth16_utils::Groth16Params;
+pub use groth16_utils::{max_supported_domain_size, Groth16Params};
 
 mod elements;
 pub use elements::{CheckForCorrectness, ElementType, UseCompression};
@@ -26,7 +26,7 @@ mod seed;
 pub use seed::derive_rng_from_seed;
 
 // Re-exports for handling hashes
-pub use blake2::digest::generic_array::GenericArray;
+pub use blake2::{digest::generic_array::GenericArray, Blake2b};
 pub use typenum::U64;
 
 pub use zexe_fft::{cfg_chunks, cfg_into_iter, cfg_iter_mut};
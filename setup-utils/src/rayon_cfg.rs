@@ -15,6 +15,10 @@ cfg_if::cfg_if! {
             let scope = ScopeShim {};
             op(&scope)
         }
+
+        pub fn current_num_threads() -> usize {
+            1
+        }
     } else {
         pub fn scope<'scope, OP, R>(op: OP) -> R
         where
@@ -22,5 +26,9 @@ cfg_if::cfg_if! {
             R: Send {
             rayon::scope(op)
         }
+
+        pub fn current_num_threads() -> usize {
+            rayon::current_num_threads()
+        }
     }
 }
@@ -1,5 +1,7 @@
 use crate::utils::UploadMode;
+use phase1_coordinator::objects::HardwareDeclaration;
 
+use anyhow::{anyhow, Result};
 use clap::AppSettings;
 use secrecy::SecretString;
 use structopt::StructOpt;
@@ -36,4 +38,31 @@ pub struct ContributeOptions {
     /// Read seed and private key at the given path.
     #[structopt(long, help = "Path to a file containing seed and private key")]
     pub keys_path: PathBuf,
+
+    /// The number of CPU cores available for contributing, declared when joining the queue.
+    #[structopt(long, help = "Number of CPU cores to declare to the coordinator")]
+    pub cores: Option<u32>,
+
+    /// The memory available for contributing, declared when joining the queue.
+    #[structopt(long, help = "Memory in gigabytes to declare to the coordinator")]
+    pub memory_gb: Option<u32>,
+
+    /// The network bandwidth available for contributing, declared when joining the queue.
+    #[structopt(long, help = "Bandwidth in megabits per second to declare to the coordinator")]
+    pub bandwidth_mbps: Option<u32>,
+}
+
+impl ContributeOptions {
+    /// Returns the hardware to declare to the coordinator when joining the queue, if any.
+    pub fn hardware(&self) -> Result<Option<HardwareDeclaration>> {
+        match (self.cores, self.memory_gb, self.bandwidth_mbps) {
+            (Some(cores), Some(memory_gb), Some(bandwidth_mbps)) => {
+                Ok(Some(HardwareDeclaration::new(cores, memory_gb, bandwidth_mbps)))
+            }
+            (None, None, None) => Ok(None),
+            _ => Err(anyhow!(
+                "--cores, --memory-gb and --bandwidth-mbps must be declared together"
+            )),
+        }
+    }
 }
@@ -21,7 +21,7 @@ use phase1::helpers::converters::CurveKind;
 use phase1_cli::contribute;
 use phase1_coordinator::{
     environment::Environment,
-    objects::{Chunk, Participant, Round},
+    objects::{Chunk, HardwareDeclaration, Participant, Round},
 };
 use setup1_shared::structures::PublicSettings;
 use setup_utils::calculate_hash;
@@ -92,6 +92,8 @@ pub struct Contribute {
     seed: Arc<SecretVec<u8>>,
     pub upload_mode: UploadMode,
     pub environment: Environment,
+    /// The hardware declared to the coordinator when joining the queue, if any.
+    pub hardware: Option<HardwareDeclaration>,
 
     pub challenge_filename: String,
     pub challenge_hash_filename: String,
@@ -124,6 +126,7 @@ impl Contribute {
             seed,
             upload_mode: opts.upload_mode.clone(),
             environment: environment.clone(),
+            hardware: opts.hardware()?,
 
             challenge_filename: CHALLENGE_FILENAME.to_string(),
             challenge_hash_filename: CHALLENGE_HASH_FILENAME.to_string(),
@@ -721,13 +724,18 @@ impl Contribute {
         let join_queue_path_url = self.server_url.join(&join_queue_path)?;
         let client = reqwest::Client::new();
         let authorization = get_authorization_value(&self.private_key, "POST", &join_queue_path, auth_rng)?;
-        let response = client
+        let request = client
             .post(join_queue_path_url.as_str())
-            .header(http::header::AUTHORIZATION, authorization)
-            .header(http::header::CONTENT_LENGTH, 0)
-            .send()
-            .await?
-            .error_for_status()?;
+            .header(http::header::AUTHORIZATION, authorization);
+
+        // Declare the hardware of this contributor, if it was given.
+        let request = match &self.hardware {
+            Some(hardware) => request
+                .header(http::header::CONTENT_TYPE, "application/json")
+                .body(serde_json::to_vec(hardware)?),
+            None => request.header(http::header::CONTENT_LENGTH, 0),
+        };
+        let response = request.send().await?.error_for_status()?;
 
         let data = response.bytes().await?;
         let joined = serde_json::from_slice::<bool>(&*data)?;
@@ -901,15 +909,23 @@ fn read_keys<P: AsRef<Path>>(keys_path: P, passphrase: Option<SecretString>) ->
 async fn request_coordinator_public_settings(coordinator_url: &Url) -> anyhow::Result<PublicSettings> {
     let settings_endpoint_url = coordinator_url.join("/v1/coordinator/settings")?;
     let client = reqwest::Client::new();
-    let bytes = client
+    let mut response = client
         .post(settings_endpoint_url)
         .header(http::header::CONTENT_LENGTH, 0)
         .send()
-        .await?
-        .bytes()
         .await?;
-    PublicSettings::decode(&bytes.to_vec())
-        .map_err(|e| anyhow::anyhow!("Error decoding coordinator PublicSettings: {}", e))
+
+    // Check the announced length, and read no more than the size limit of the body.
+    if let Some(content_length) = response.content_length() {
+        PublicSettings::check_size(content_length.min(usize::MAX as u64) as usize)?;
+    }
+    let mut bytes = Vec::new();
+    while let Some(chunk) = response.chunk().await? {
+        PublicSettings::check_size(bytes.len() + chunk.len())?;
+        bytes.extend_from_slice(&chunk);
+    }
+
+    PublicSettings::decode(&bytes).map_err(|e| anyhow::anyhow!("Error decoding coordinator PublicSettings: {}", e))
 }
 
 pub async fn contribute_subcommand(opts: &ContributeOptions) -> anyhow::Result<()> {
@@ -7,7 +7,7 @@ use phase1_coordinator::{
     environment::{Development, Environment, Parameters, Production},
     objects::{ContributionFileSignature, ContributionState},
 };
-use setup1_shared::structures::SetupKind;
+use setup1_shared::structures::{SetupKind, TestUniversalParameters};
 use snarkos_toolkit::account::{Address, PrivateKey, ViewKey};
 use zexe_algebra::PairingEngine;
 
@@ -198,6 +198,21 @@ fn universal_environment() -> Environment {
     Production::from(Parameters::AleoUniversal).into()
 }
 
+#[inline]
+fn test_universal_environment() -> Environment {
+    let TestUniversalParameters {
+        power,
+        chunk_size,
+        batch_size,
+    } = SetupKind::TEST_UNIVERSAL_PARAMETERS;
+    Production::from(Parameters::AleoTestUniversal {
+        power,
+        chunk_size,
+        batch_size,
+    })
+    .into()
+}
+
 /// Returns the [Environment] settings based on a setup kind
 pub fn environment_by_setup_kind(kind: &SetupKind) -> Environment {
     match kind {
@@ -205,6 +220,7 @@ pub fn environment_by_setup_kind(kind: &SetupKind) -> Environment {
         SetupKind::Inner => inner_environment(),
         SetupKind::Outer => outer_environment(),
         SetupKind::Universal => universal_environment(),
+        SetupKind::TestUniversal => test_universal_environment(),
     }
 }
 
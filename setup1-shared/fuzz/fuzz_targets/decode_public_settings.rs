@@ -0,0 +1,11 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use setup1_shared::structures::PublicSettings;
+
+fuzz_target!(|data: &[u8]| {
+    // Decoding must never panic, and decoded settings must survive a round trip.
+    if let Ok(settings) = PublicSettings::decode(data) {
+        let encoded = settings.encode().expect("decoded settings must encode");
+        assert_eq!(settings, PublicSettings::decode(&encoded).expect("encoded settings must decode"));
+    }
+});
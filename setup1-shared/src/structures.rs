@@ -1,35 +1,265 @@
 //! The data structures shared between coordinator, contributor and verifier
 
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// The version of the encoding of [PublicSettings] written by [PublicSettings::encode]
+pub const PUBLIC_SETTINGS_VERSION: u8 = 1;
+
+/// The limit in bytes of an encoded [PublicSettings], which is far
+/// above the size of any settings, to bound the memory spent decoding
+pub const MAXIMUM_PUBLIC_SETTINGS_SIZE: usize = 64 * 1024;
+
+/// The size in bytes of the version and length header of an encoded [PublicSettings]
+const PUBLIC_SETTINGS_HEADER_SIZE: usize = 1 + 4;
+
+/// The errors of encoding and decoding [PublicSettings]
+#[derive(Debug, Error)]
+pub enum PublicSettingsError {
+    #[error("Public settings are empty")]
+    Empty,
+    #[error("Public settings exceed the size limit: {size} vs {maximum}")]
+    TooLarge { size: usize, maximum: usize },
+    #[error("Public settings are truncated: expected {expected} bytes, got {got}")]
+    Truncated { expected: usize, got: usize },
+    #[error("Public settings have {0} trailing bytes")]
+    TrailingBytes(usize),
+    #[error("Public settings version {0} is not supported")]
+    UnsupportedVersion(u8),
+    #[error("Public settings are malformed: {0}")]
+    Json(#[from] serde_json::Error),
+}
 
 /// The kind of a setup
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(rename_all = "lowercase")]
 pub enum SetupKind {
     Development,
     Inner,
     Outer,
     Universal,
+    /// A universal setup at a reduced power, for rehearsing the ceremony
+    /// with contributors on commodity hardware.
+    TestUniversal,
+}
+
+/// The parameters of a test universal setup
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TestUniversalParameters {
+    pub power: usize,
+    pub chunk_size: usize,
+    pub batch_size: usize,
+}
+
+impl SetupKind {
+    /// The parameters of [SetupKind::TestUniversal], which contributors
+    /// and verifiers must agree on to produce matching files
+    pub const TEST_UNIVERSAL_PARAMETERS: TestUniversalParameters = TestUniversalParameters {
+        power: 18,
+        chunk_size: 16384,
+        batch_size: 1024,
+    };
 }
 
 /// The public settings of a setup to let the contributors know
 /// what kind of a setup is running at the moment and some
 /// other details
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PublicSettings {
     pub setup: SetupKind,
     pub check_reliability: bool,
 }
 
+/// Encoding format:
+/// 1 byte version | 4 bytes JSON length | JSON
 impl PublicSettings {
-    /// Encodes self as a JSON message to a vector of bytes
-    pub fn encode(&self) -> Result<Vec<u8>, serde_json::Error> {
-        serde_json::to_vec(self)
+    /// Checks that a message of the given size is within [MAXIMUM_PUBLIC_SETTINGS_SIZE],
+    /// so clients can reject a response by its announced length before reading it
+    pub fn check_size(size: usize) -> Result<(), PublicSettingsError> {
+        match size > MAXIMUM_PUBLIC_SETTINGS_SIZE {
+            true => Err(PublicSettingsError::TooLarge {
+                size,
+                maximum: MAXIMUM_PUBLIC_SETTINGS_SIZE,
+            }),
+            false => Ok(()),
+        }
+    }
+
+    /// Encodes self as a versioned, length-prefixed JSON message to a vector of bytes
+    pub fn encode(&self) -> Result<Vec<u8>, PublicSettingsError> {
+        let json = serde_json::to_vec(self)?;
+        Self::check_size(PUBLIC_SETTINGS_HEADER_SIZE + json.len())?;
+
+        let json_length = (json.len() as u32).to_be_bytes();
+        Ok([&[PUBLIC_SETTINGS_VERSION][..], &json_length, &json].concat())
+    }
+
+    /// Decodes a versioned, length-prefixed JSON message from a slice of bytes into Self.
+    ///
+    /// A bare JSON message, as encoded before the settings were versioned,
+    /// is decoded as well, until every coordinator encodes the new format.
+    pub fn decode(bytes: &[u8]) -> Result<Self, PublicSettingsError> {
+        Self::check_size(bytes.len())?;
+
+        match bytes.first() {
+            None => Err(PublicSettingsError::Empty),
+            // A JSON object, which no version byte starts with.
+            Some(b'{') => Ok(serde_json::from_slice(bytes)?),
+            Some(&PUBLIC_SETTINGS_VERSION) => {
+                if bytes.len() < PUBLIC_SETTINGS_HEADER_SIZE {
+                    return Err(PublicSettingsError::Truncated {
+                        expected: PUBLIC_SETTINGS_HEADER_SIZE,
+                        got: bytes.len(),
+                    });
+                }
+
+                let mut json_length_buffer = [0u8; 4];
+                json_length_buffer.copy_from_slice(&bytes[1..PUBLIC_SETTINGS_HEADER_SIZE]);
+                let json_length = u32::from_be_bytes(json_length_buffer) as usize;
+
+                // Check the length against the message, which is within the size limit.
+                let json = &bytes[PUBLIC_SETTINGS_HEADER_SIZE..];
+                if json.len() < json_length {
+                    return Err(PublicSettingsError::Truncated {
+                        expected: PUBLIC_SETTINGS_HEADER_SIZE + json_length,
+                        got: bytes.len(),
+                    });
+                }
+                if json.len() > json_length {
+                    return Err(PublicSettingsError::TrailingBytes(json.len() - json_length));
+                }
+
+                Ok(serde_json::from_slice(json)?)
+            }
+            Some(&version) => Err(PublicSettingsError::UnsupportedVersion(version)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn all_settings() -> Vec<PublicSettings> {
+        let kinds = vec![
+            SetupKind::Development,
+            SetupKind::Inner,
+            SetupKind::Outer,
+            SetupKind::Universal,
+            SetupKind::TestUniversal,
+        ];
+        kinds
+            .into_iter()
+            .flat_map(|setup| {
+                vec![true, false]
+                    .into_iter()
+                    .map(move |check_reliability| PublicSettings {
+                        setup: setup.clone(),
+                        check_reliability,
+                    })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn public_settings_round_trip() {
+        for settings in all_settings() {
+            let encoded = settings.encode().unwrap();
+            assert_eq!(PUBLIC_SETTINGS_VERSION, encoded[0]);
+            assert_eq!(settings, PublicSettings::decode(&encoded).unwrap());
+        }
+    }
+
+    #[test]
+    fn public_settings_decode_legacy() {
+        for settings in all_settings() {
+            let legacy = serde_json::to_vec(&settings).unwrap();
+            assert_eq!(settings, PublicSettings::decode(&legacy).unwrap());
+        }
+    }
+
+    #[test]
+    fn public_settings_decode_truncated() {
+        for settings in all_settings() {
+            let encoded = settings.encode().unwrap();
+            for length in 0..encoded.len() {
+                assert!(PublicSettings::decode(&encoded[..length]).is_err());
+            }
+
+            let mut extended = encoded.clone();
+            extended.push(0);
+            assert!(matches!(
+                PublicSettings::decode(&extended),
+                Err(PublicSettingsError::TrailingBytes(1))
+            ));
+        }
     }
 
-    /// Decodes a JSON message from a slice of bytes into Self
-    pub fn decode(bytes: &[u8]) -> Result<Self, serde_json::Error> {
-        serde_json::from_slice(bytes)
+    #[test]
+    fn public_settings_decode_garbled() {
+        // Decoding every single byte substitution must return, whether or not it succeeds.
+        let encoded = all_settings()[0].encode().unwrap();
+        for position in 0..encoded.len() {
+            for byte in 0..=u8::MAX {
+                let mut garbled = encoded.clone();
+                garbled[position] = byte;
+                let _ = PublicSettings::decode(&garbled);
+            }
+        }
+
+        // A length prefix beyond the message does not allocate it.
+        let mut garbled = encoded.clone();
+        garbled[1..PUBLIC_SETTINGS_HEADER_SIZE].copy_from_slice(&u32::MAX.to_be_bytes());
+        assert!(matches!(
+            PublicSettings::decode(&garbled),
+            Err(PublicSettingsError::Truncated { .. })
+        ));
+
+        assert!(matches!(
+            PublicSettings::decode(&[2, 0, 0, 0, 0]),
+            Err(PublicSettingsError::UnsupportedVersion(2))
+        ));
+        assert!(matches!(
+            PublicSettings::decode(&vec![b'{'; MAXIMUM_PUBLIC_SETTINGS_SIZE + 1]),
+            Err(PublicSettingsError::TooLarge { .. })
+        ));
+    }
+
+    fn public_settings() -> impl Strategy<Value = PublicSettings> {
+        (0..all_settings().len()).prop_map(|index| all_settings()[index].clone())
+    }
+
+    proptest! {
+        #[test]
+        fn public_settings_round_trip_prop(settings in public_settings()) {
+            let encoded = settings.encode().unwrap();
+            prop_assert_eq!(settings, PublicSettings::decode(&encoded).unwrap());
+        }
+
+        #[test]
+        fn public_settings_decode_truncated_prop(settings in public_settings(), length in any::<usize>()) {
+            let encoded = settings.encode().unwrap();
+            prop_assert!(PublicSettings::decode(&encoded[..length % encoded.len()]).is_err());
+        }
+
+        #[test]
+        fn public_settings_decode_arbitrary_prop(bytes in proptest::collection::vec(any::<u8>(), 0..256)) {
+            // Decoding arbitrary bytes must return, whether or not it succeeds.
+            let _ = PublicSettings::decode(&bytes);
+        }
+
+        #[test]
+        fn public_settings_decode_versioned_prop(
+            json_length in any::<u32>(),
+            json in proptest::collection::vec(any::<u8>(), 0..256),
+        ) {
+            // A versioned message with an arbitrary length prefix must return, whether or not it succeeds.
+            let bytes = [&[PUBLIC_SETTINGS_VERSION][..], &json_length.to_be_bytes(), &json].concat();
+            if PublicSettings::decode(&bytes).is_ok() {
+                prop_assert_eq!(json_length as usize, json.len());
+            }
+        }
     }
 }
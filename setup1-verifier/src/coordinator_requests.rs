@@ -1,46 +1,78 @@
-use crate::{errors::VerifierError, objects::LockResponse, utils::AleoAuthentication, verifier::Verifier};
-use snarkos_toolkit::account::Address;
+use crate::{
+    errors::VerifierError,
+    objects::{LockResponse, QueueStatus},
+    utils::authentication::AleoAuthentication,
+    verifier::Verifier,
+};
+use phase1_coordinator::objects::{ApiError, CLIENT_VERSION_HEADER};
+use snarkos_toolkit::account::{Address, ViewKey};
 
 use reqwest::Client;
 use tracing::{debug, error, info};
 
+/// The header of the lock token sent with the upload of a next challenge file.
+const LOCK_TOKEN_HEADER: &str = "X-Lock-Token";
+
+/// The client this verifier identifies itself with, from its Cargo manifest.
+const CLIENT_VERSION: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+
 impl Verifier {
     ///
-    /// Attempts to join the coordinator queue
+    /// Attempts to join the coordinator queue with the given view key
     ///
-    /// On success, this function returns `true`.
+    /// On success, this function returns the `QueueStatus` of the verifier.
+    /// A verifier which is already in the queue is treated as a success.
     ///
     /// On failure, this function returns a `VerifierError`.
     ///
-    pub(crate) async fn join_queue(&self) -> Result<bool, VerifierError> {
+    pub(crate) async fn join_queue(&self, view_key: &ViewKey) -> Result<QueueStatus, VerifierError> {
         let coordinator_api_url = &self.coordinator_api_url;
 
-        let aleo_address = Address::from_view_key(&self.view_key)?.to_string();
+        let aleo_address = Address::from_view_key(view_key)?.to_string();
 
         let method = "post";
         let path = "/v1/queue/verifier/join";
 
-        let authentication = AleoAuthentication::authenticate(&self.view_key, &method, &path)?;
+        let authentication = AleoAuthentication::authenticate(view_key, &method, &path)?;
 
         info!("Attempting to join as verifier join the queue as {}", aleo_address);
 
+        self.metrics.record_coordinator_request();
         match Client::new()
             .post(coordinator_api_url.join(path).expect("Should create a path"))
             .header(http::header::AUTHORIZATION, authentication.to_string())
+            .header(CLIENT_VERSION_HEADER, CLIENT_VERSION)
             .header(http::header::CONTENT_LENGTH, 0)
             .send()
             .await
         {
             Ok(response) => {
                 if !response.status().is_success() {
-                    error!("Verifier failed to join the queue");
+                    match ApiError::from_response(&*response.bytes().await?) {
+                        Some(api_error) if api_error.code == "client_upgrade_required" => {
+                            error!(
+                                "Verifier {} is not supported ({})",
+                                CLIENT_VERSION, api_error.details["upgrade"]
+                            )
+                        }
+                        _ => error!("Verifier failed to join the queue"),
+                    }
                     return Err(VerifierError::FailedToJoinQueue);
                 }
 
-                // Parse the lock response
-                let queue_response = serde_json::from_slice::<bool>(&*response.bytes().await?)?;
-                info!("{} joined the queue with status {}", aleo_address, queue_response);
-                Ok(queue_response)
+                // Parse the queue status
+                let queue_status = QueueStatus::from_response(&*response.bytes().await?)?;
+                if !queue_status.is_queued() {
+                    error!("Verifier was not added to the queue");
+                    return Err(VerifierError::FailedToJoinQueue);
+                }
+
+                match queue_status.already_in_queue {
+                    true => info!("{} is already in the queue", aleo_address),
+                    false => info!("{} joined the queue", aleo_address),
+                }
+                debug!("Decoded verifier queue status: {:?}", queue_status);
+                Ok(queue_status)
             }
             Err(_) => {
                 error!("Request ({}) to join the queue failed", path);
@@ -64,21 +96,37 @@ impl Verifier {
         let method = "post";
         let path = "/v1/verifier/try_lock";
 
-        let authentication = AleoAuthentication::authenticate(&self.view_key, &method, &path)?;
+        let authentication = self.authenticate(&method, &path)?;
 
         info!("Verifier attempting to lock a chunk");
 
+        self.metrics.record_coordinator_request();
         match Client::new()
             .post(coordinator_api_url.join(path).expect("Should create a path"))
             .header(http::header::AUTHORIZATION, authentication.to_string())
+            .header(CLIENT_VERSION_HEADER, CLIENT_VERSION)
             .header(http::header::CONTENT_LENGTH, 0)
             .send()
             .await
         {
             Ok(response) => {
                 if !response.status().is_success() {
-                    error!("Verifier failed to acquire a lock on a chunk");
-                    return Err(VerifierError::FailedLock);
+                    let body = response.bytes().await?;
+                    return Err(match ApiError::from_response(&body) {
+                        Some(api_error) => {
+                            match api_error.code.as_str() {
+                                "no_remaining_tasks" | "round_not_ready" => {
+                                    info!("Verifier has no chunk to lock ({})", api_error.code)
+                                }
+                                _ => error!("Verifier failed to acquire a lock on a chunk ({:?})", api_error),
+                            }
+                            VerifierError::FailedLockWithCode(api_error.code)
+                        }
+                        None => {
+                            error!("Verifier failed to acquire a lock on a chunk");
+                            VerifierError::FailedLock
+                        }
+                    });
                 }
 
                 // Parse the lock response
@@ -99,6 +147,51 @@ impl Verifier {
         }
     }
 
+    ///
+    /// Attempts to fetch the task that the verifier would lock next, without locking it.
+    ///
+    /// On success, this function returns the next task, if there is one.
+    ///
+    /// On failure, this function returns a `VerifierError`.
+    ///
+    pub(crate) async fn peek_task(&self) -> Result<Option<LockResponse>, VerifierError> {
+        let coordinator_api_url = &self.coordinator_api_url;
+        let method = "get";
+        let path = "/v1/verifier/peek_task";
+
+        let authentication = self.authenticate(&method, &path)?;
+
+        self.metrics.record_coordinator_request();
+        match Client::new()
+            .get(coordinator_api_url.join(path).expect("Should create a path"))
+            .header(http::header::AUTHORIZATION, authentication.to_string())
+            .header(CLIENT_VERSION_HEADER, CLIENT_VERSION)
+            .send()
+            .await
+        {
+            Ok(response) => {
+                if !response.status().is_success() {
+                    debug!("Verifier failed to peek at the next task");
+                    return Ok(None);
+                }
+
+                // Parse the next task
+                let json_response = response.bytes().await?;
+                let task = serde_json::from_slice::<Option<LockResponse>>(&*json_response)?;
+                debug!("Decoded verifier peek response: {:#?}", task);
+
+                Ok(task)
+            }
+            Err(_) => {
+                error!("Request ({}) to peek at the next task failed", path);
+                return Err(VerifierError::FailedRequest(
+                    path.to_string(),
+                    coordinator_api_url.to_string(),
+                ));
+            }
+        }
+    }
+
     ///
     /// Attempts to run verification in the current round for a given `chunk_id`
     ///
@@ -117,10 +210,12 @@ impl Verifier {
         info!("Verifier running verification of a contribution at chunk {}", chunk_id);
 
         let signature_path = format!("{}", path.replace("./", ""));
-        let authentication = AleoAuthentication::authenticate(&self.view_key, &method, &signature_path)?;
+        let authentication = self.authenticate(&method, &signature_path)?;
+        self.metrics.record_coordinator_request();
         match Client::new()
             .post(coordinator_api_url.join(&path).expect("Should create a path"))
             .header(http::header::AUTHORIZATION, authentication.to_string())
+            .header(CLIENT_VERSION_HEADER, CLIENT_VERSION)
             .header(http::header::CONTENT_LENGTH, 0)
             .send()
             .await
@@ -128,10 +223,12 @@ impl Verifier {
             Ok(response) => {
                 if !response.status().is_success() {
                     error!("Failed to verify the challenge at chunk {}", chunk_id);
+                    self.metrics.record_failed_verification();
                     return Err(VerifierError::FailedVerification(chunk_id));
                 }
 
                 info!("Verifier successfully verified a contribution on chunk {}", chunk_id);
+                self.metrics.record_chunk_verified();
 
                 Ok(response.text().await?)
             }
@@ -164,10 +261,12 @@ impl Verifier {
 
         info!("Verifier downloading a response file at {} ", path);
 
-        let authentication = AleoAuthentication::authenticate(&self.view_key, &method, &path)?;
+        let authentication = self.authenticate(&method, &path)?;
+        self.metrics.record_coordinator_request();
         match Client::new()
             .get(coordinator_api_url.join(&path).expect("Should create a path"))
             .header("Authorization", authentication.to_string())
+            .header(CLIENT_VERSION_HEADER, CLIENT_VERSION)
             .send()
             .await
         {
@@ -177,9 +276,12 @@ impl Verifier {
                     return Err(VerifierError::FailedResponseDownload(path));
                 }
 
+                let response_file = response.bytes().await?.to_vec();
+                self.metrics.record_download(response_file.len());
+
                 info!("Verifier downloaded the response file {} ", path);
 
-                Ok(response.bytes().await?.to_vec())
+                Ok(response_file)
             }
             Err(_) => {
                 error!("Request ({}) to download a response file failed.", path);
@@ -210,10 +312,12 @@ impl Verifier {
 
         info!("Verifier downloading a challenge file at {} ", path);
 
-        let authentication = AleoAuthentication::authenticate(&self.view_key, &method, &path)?;
+        let authentication = self.authenticate(&method, &path)?;
+        self.metrics.record_coordinator_request();
         match Client::new()
             .get(coordinator_api_url.join(&path).expect("Should create a path"))
             .header("Authorization", authentication.to_string())
+            .header(CLIENT_VERSION_HEADER, CLIENT_VERSION)
             .send()
             .await
         {
@@ -223,9 +327,12 @@ impl Verifier {
                     return Err(VerifierError::FailedChallengeDownload(path));
                 }
 
+                let challenge_file = response.bytes().await?.to_vec();
+                self.metrics.record_download(challenge_file.len());
+
                 info!("Verifier downloaded the challenge file {} ", path);
 
-                Ok(response.bytes().await?.to_vec())
+                Ok(challenge_file)
             }
             Err(_) => {
                 error!("Request ({}) to download a challenge file failed.", path);
@@ -241,6 +348,9 @@ impl Verifier {
     /// Attempts to upload the next challenge file to the coordinator
     /// at a given `next_challenge_locator`
     ///
+    /// The token of the lock is sent with the upload, if the coordinator issued one,
+    /// so that the coordinator rejects the upload if the lock was taken over.
+    ///
     /// On success, this function returns an `ok` status
     ///
     /// On failure, this function returns a `VerifierError`.
@@ -249,32 +359,33 @@ impl Verifier {
         &self,
         chunk_id: u64,
         contribution_id: u64,
+        lock_token: Option<&str>,
         signature_and_next_challenge_file_bytes: Vec<u8>,
     ) -> Result<String, VerifierError> {
         let coordinator_api_url = &self.coordinator_api_url;
         let method = "post";
         let path = format!("/v1/upload/challenge/{}/{}", chunk_id, contribution_id);
 
-        let authentication = AleoAuthentication::authenticate(&self.view_key, &method, &path)?;
+        let authentication = self.authenticate(&method, &path)?;
 
-        info!(
-            "Verifier uploading a response with size {} to {} ",
-            signature_and_next_challenge_file_bytes.len(),
-            path
-        );
+        let upload_size = signature_and_next_challenge_file_bytes.len();
+        info!("Verifier uploading a response with size {} to {} ", upload_size, path);
 
-        match Client::new()
+        self.metrics.record_coordinator_request();
+        let mut request = Client::new()
             .post(coordinator_api_url.join(&path).expect("Should create a path"))
             .header(http::header::AUTHORIZATION, authentication.to_string())
+            .header(CLIENT_VERSION_HEADER, CLIENT_VERSION)
             .header(http::header::CONTENT_TYPE, "application/octet-stream")
             .header(
                 http::header::CONTENT_LENGTH,
                 signature_and_next_challenge_file_bytes.len(),
-            )
-            .body(signature_and_next_challenge_file_bytes)
-            .send()
-            .await
-        {
+            );
+        if let Some(lock_token) = lock_token {
+            request = request.header(LOCK_TOKEN_HEADER, lock_token);
+        }
+
+        match request.body(signature_and_next_challenge_file_bytes).send().await {
             Ok(response) => {
                 if !response.status().is_success() {
                     error!("Failed to upload the new challenge file {}", path);
@@ -282,6 +393,7 @@ impl Verifier {
                 }
 
                 info!("Verifier uploaded the next challenge file {} ", path);
+                self.metrics.record_upload(upload_size);
 
                 Ok(response.text().await?)
             }
@@ -14,6 +14,9 @@ pub enum VerifierError {
     #[error("Failed to lock a chunk")]
     FailedLock,
 
+    #[error("The coordinator rejected the lock on a chunk ({})", _0)]
+    FailedLockWithCode(String),
+
     #[error("Request {} sent to {} errored", _0, _1)]
     FailedRequest(String, String),
 
@@ -26,14 +29,26 @@ pub enum VerifierError {
     #[error("The coordinator failed to verify the uploaded challenge file at chunk {}", _0)]
     FailedVerification(u64),
 
+    #[error("Transcript chunk {} contribution {} failed verification: {}", _0, _1, _2)]
+    FailedTranscriptVerification(u64, u64, String),
+
     #[error("Failed to join the queue")]
     FailedToJoinQueue,
 
+    #[error("Transcript chunk {} does not start from the expected initial hash", _0)]
+    MismatchedInitialHash(u64),
+
     #[error("Mismatched response hashes")]
     MismatchedResponseHashes,
 
     #[error("Next challenge file missing stored response hash")]
     MissingStoredResponseHash,
+
+    #[error("Transcript is missing the state of its round")]
+    MissingTranscriptRoundState,
+
+    #[error("The verifier has no view key")]
+    MissingViewKey,
 }
 
 impl From<anyhow::Error> for VerifierError {
@@ -9,7 +9,11 @@ extern crate lazy_static;
 
 pub mod coordinator_requests;
 pub mod errors;
+pub mod metrics;
 pub mod objects;
+pub mod prefetch;
 pub mod tasks;
+pub mod transcript;
 pub mod utils;
 pub mod verifier;
+pub mod working_directory;
@@ -1,13 +1,13 @@
-use setup1_verifier::{utils::init_logger, verifier::Verifier};
+use setup1_verifier::{transcript::verify_transcript, utils::init_logger, verifier::Verifier};
 
 use phase1_coordinator::environment::{Development, Environment, Parameters, Production};
-use setup1_shared::structures::{PublicSettings, SetupKind};
-use snarkos_toolkit::account::{Address, ViewKey};
+use setup1_shared::structures::{PublicSettings, SetupKind, TestUniversalParameters};
+use snarkos_toolkit::account::ViewKey;
 use structopt::StructOpt;
 use url::Url;
 
 use std::{path::PathBuf, str::FromStr};
-use tracing::info;
+use tracing::{error, info};
 
 fn development() -> Environment {
     Development::from(Parameters::TestCustom {
@@ -30,27 +30,96 @@ fn universal() -> Environment {
     Production::from(Parameters::AleoUniversal).into()
 }
 
+fn test_universal() -> Environment {
+    let TestUniversalParameters {
+        power,
+        chunk_size,
+        batch_size,
+    } = SetupKind::TEST_UNIVERSAL_PARAMETERS;
+    Production::from(Parameters::AleoTestUniversal {
+        power,
+        chunk_size,
+        batch_size,
+    })
+    .into()
+}
+
+fn setup_environment(setup: &SetupKind) -> Environment {
+    match setup {
+        SetupKind::Development => development(),
+        SetupKind::Inner => inner(),
+        SetupKind::Outer => outer(),
+        SetupKind::Universal => universal(),
+        SetupKind::TestUniversal => test_universal(),
+    }
+}
+
+fn parse_setup_kind(setup: &str) -> Result<SetupKind, serde_json::Error> {
+    serde_json::from_value(serde_json::Value::String(setup.to_lowercase()))
+}
+
 #[derive(Debug, StructOpt)]
-#[structopt(name = "Aleo setup verifier")]
+#[structopt(name = "Aleo setup verifier", setting = structopt::clap::AppSettings::SubcommandsNegateReqs)]
 struct Options {
-    #[structopt(long, help = "Path to a file containing verifier view key")]
-    view_key: PathBuf,
-    #[structopt(long, help = "Coordinator api url, for example http://localhost:9000")]
-    api_url: Url,
+    #[structopt(
+        long,
+        required = true,
+        number_of_values = 1,
+        help = "Path to a file containing a verifier view key, repeat to rotate between several keys"
+    )]
+    view_key: Vec<PathBuf>,
+    #[structopt(
+        long,
+        help = "Maximum number of bytes of task files on disk, new downloads pause beyond it"
+    )]
+    disk_budget: Option<u64>,
+    #[structopt(
+        long,
+        required = true,
+        help = "Coordinator api url, for example http://localhost:9000"
+    )]
+    api_url: Option<Url>,
+    #[structopt(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Debug, StructOpt)]
+enum Command {
+    /// Verifies an exported round transcript offline, without a running coordinator
+    VerifyTranscript {
+        #[structopt(long, help = "Path to the exported round directory")]
+        transcript: PathBuf,
+        #[structopt(long, help = "Hex-encoded hash recorded by the initial challenge of every chunk")]
+        initial_hash: String,
+        #[structopt(
+            long,
+            parse(try_from_str = parse_setup_kind),
+            help = "Setup kind of the transcript, for example universal"
+        )]
+        setup: SetupKind,
+    },
 }
 
 async fn request_coordinator_public_settings(coordinator_url: &Url) -> anyhow::Result<PublicSettings> {
     let settings_endpoint_url = coordinator_url.join("/v1/coordinator/settings")?;
     let client = reqwest::Client::new();
-    let bytes = client
+    let mut response = client
         .post(settings_endpoint_url)
         .header(http::header::CONTENT_LENGTH, 0)
         .send()
-        .await?
-        .bytes()
         .await?;
-    PublicSettings::decode(&bytes.to_vec())
-        .map_err(|e| anyhow::anyhow!("Error decoding coordinator PublicSettings: {}", e))
+
+    // Check the announced length, and read no more than the size limit of the body.
+    if let Some(content_length) = response.content_length() {
+        PublicSettings::check_size(content_length.min(usize::MAX as u64) as usize)?;
+    }
+    let mut bytes = Vec::new();
+    while let Some(chunk) = response.chunk().await? {
+        PublicSettings::check_size(bytes.len() + chunk.len())?;
+        bytes.extend_from_slice(&chunk);
+    }
+
+    PublicSettings::decode(&bytes).map_err(|e| anyhow::anyhow!("Error decoding coordinator PublicSettings: {}", e))
 }
 
 #[tokio::main]
@@ -59,34 +128,57 @@ async fn main() {
 
     init_logger();
 
-    let public_settings = request_coordinator_public_settings(&options.api_url)
+    if let Some(Command::VerifyTranscript {
+        transcript,
+        initial_hash,
+        setup,
+    }) = options.command
+    {
+        let initial_hash = hex::decode(initial_hash).expect("Invalid initial hash");
+        match verify_transcript(&setup_environment(&setup), &transcript, &initial_hash) {
+            Ok(number_of_contributions) => info!(
+                "Transcript verified successfully ({} contributions)",
+                number_of_contributions
+            ),
+            Err(error) => {
+                error!("Transcript verification failed: {}", error);
+                std::process::exit(1);
+            }
+        }
+        return;
+    }
+
+    let api_url = options.api_url.expect("Coordinator api url is required");
+
+    let public_settings = request_coordinator_public_settings(&api_url)
         .await
         .expect("Failed to fetch the coordinator public settings");
 
-    let environment = match public_settings.setup {
-        SetupKind::Development => development(),
-        SetupKind::Inner => inner(),
-        SetupKind::Outer => outer(),
-        SetupKind::Universal => universal(),
-    };
+    let environment = setup_environment(&public_settings.setup);
 
     let storage_prefix = format!("{:?}", public_settings.setup).to_lowercase();
     let tasks_storage_path = format!("{}_verifier.tasks", storage_prefix);
 
-    let raw_view_key = std::fs::read_to_string(options.view_key).expect("View key not found");
-    let view_key = ViewKey::from_str(&raw_view_key).expect("Invalid view key");
-    let address = Address::from_view_key(&view_key).expect("Address not derived correctly");
+    let view_keys = options
+        .view_key
+        .iter()
+        .map(|view_key_path| {
+            let raw_view_key = std::fs::read_to_string(view_key_path).expect("View key not found");
+            ViewKey::from_str(&raw_view_key).expect("Invalid view key")
+        })
+        .collect();
 
     // Initialize the verifier
     info!("Initializing verifier...");
-    let verifier = Verifier::new(
-        options.api_url.clone(),
-        view_key,
-        address,
-        environment,
-        tasks_storage_path,
-    )
-    .expect("Failed to initialize verifier");
+    let mut verifier = Verifier::new(api_url.clone(), view_keys, environment, tasks_storage_path)
+        .expect("Failed to initialize verifier");
+    if let Some(disk_budget) = options.disk_budget {
+        verifier = verifier.with_disk_budget(disk_budget);
+    }
+    info!(
+        "Verifier address is {}",
+        verifier.address().expect("Address not derived correctly")
+    );
 
     verifier.start_verifier().await;
 }
@@ -0,0 +1,266 @@
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+
+///
+/// The counters of the verifier operations, shared between the clones of a verifier.
+///
+#[derive(Debug, Default)]
+pub struct VerifierMetrics {
+    /// The number of requests sent to the coordinator.
+    coordinator_requests: AtomicU64,
+    /// The number of chunks which the coordinator accepted a verification for.
+    chunks_verified: AtomicU64,
+    /// The number of verifications which the coordinator rejected.
+    failed_verifications: AtomicU64,
+    /// The number of bytes of challenge and response files downloaded from the coordinator.
+    bytes_downloaded: AtomicU64,
+    /// The number of bytes of next challenge files uploaded to the coordinator.
+    bytes_uploaded: AtomicU64,
+    /// The number of verifications which were run locally.
+    verifications_run: AtomicU64,
+    /// The total time (in milliseconds) spent running verifications locally.
+    verification_time_millis: AtomicU64,
+    /// The number of bytes of the files of the tasks of the verifier on disk.
+    disk_usage_bytes: AtomicU64,
+    /// Indicator if new downloads are paused, as the disk budget is exceeded.
+    downloads_paused: AtomicBool,
+}
+
+impl VerifierMetrics {
+    ///
+    /// Records a request sent to the coordinator.
+    ///
+    pub fn record_coordinator_request(&self) {
+        self.coordinator_requests.fetch_add(1, Ordering::Relaxed);
+    }
+
+    ///
+    /// Records a verification which was accepted by the coordinator.
+    ///
+    pub fn record_chunk_verified(&self) {
+        self.chunks_verified.fetch_add(1, Ordering::Relaxed);
+    }
+
+    ///
+    /// Records a verification which was rejected by the coordinator.
+    ///
+    pub fn record_failed_verification(&self) {
+        self.failed_verifications.fetch_add(1, Ordering::Relaxed);
+    }
+
+    ///
+    /// Records the size of a file downloaded from the coordinator.
+    ///
+    pub fn record_download(&self, bytes: usize) {
+        self.bytes_downloaded.fetch_add(bytes as u64, Ordering::Relaxed);
+    }
+
+    ///
+    /// Records the size of a file uploaded to the coordinator.
+    ///
+    pub fn record_upload(&self, bytes: usize) {
+        self.bytes_uploaded.fetch_add(bytes as u64, Ordering::Relaxed);
+    }
+
+    ///
+    /// Records the duration (in milliseconds) of a verification which was run locally.
+    ///
+    pub fn record_verification_time(&self, duration_millis: i64) {
+        self.verifications_run.fetch_add(1, Ordering::Relaxed);
+        self.verification_time_millis
+            .fetch_add(duration_millis.max(0) as u64, Ordering::Relaxed);
+    }
+
+    ///
+    /// Records the disk usage of the verifier, and if new downloads are paused.
+    ///
+    pub fn record_disk_usage(&self, bytes: u64, paused: bool) {
+        self.disk_usage_bytes.store(bytes, Ordering::Relaxed);
+        self.downloads_paused.store(paused, Ordering::Relaxed);
+    }
+
+    ///
+    /// Returns a copy of the current counters.
+    ///
+    pub fn snapshot(&self) -> VerifierMetricsSnapshot {
+        VerifierMetricsSnapshot {
+            coordinator_requests: self.coordinator_requests.load(Ordering::Relaxed),
+            chunks_verified: self.chunks_verified.load(Ordering::Relaxed),
+            failed_verifications: self.failed_verifications.load(Ordering::Relaxed),
+            bytes_downloaded: self.bytes_downloaded.load(Ordering::Relaxed),
+            bytes_uploaded: self.bytes_uploaded.load(Ordering::Relaxed),
+            verifications_run: self.verifications_run.load(Ordering::Relaxed),
+            verification_time_millis: self.verification_time_millis.load(Ordering::Relaxed),
+            disk_usage_bytes: self.disk_usage_bytes.load(Ordering::Relaxed),
+            downloads_paused: self.downloads_paused.load(Ordering::Relaxed),
+        }
+    }
+}
+
+///
+/// The counters of the verifier operations at a point in time.
+///
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct VerifierMetricsSnapshot {
+    /// The number of requests sent to the coordinator.
+    pub coordinator_requests: u64,
+    /// The number of chunks which the coordinator accepted a verification for.
+    pub chunks_verified: u64,
+    /// The number of verifications which the coordinator rejected.
+    pub failed_verifications: u64,
+    /// The number of bytes downloaded from the coordinator.
+    pub bytes_downloaded: u64,
+    /// The number of bytes uploaded to the coordinator.
+    pub bytes_uploaded: u64,
+    /// The number of verifications which were run locally.
+    pub verifications_run: u64,
+    /// The total time (in milliseconds) spent running verifications locally.
+    pub verification_time_millis: u64,
+    /// The number of bytes of the files of the tasks of the verifier on disk.
+    pub disk_usage_bytes: u64,
+    /// Indicator if new downloads are paused, as the disk budget is exceeded.
+    pub downloads_paused: bool,
+}
+
+impl VerifierMetricsSnapshot {
+    ///
+    /// Returns the average time (in milliseconds) of a verification run locally,
+    /// or `None` if no verification was run yet.
+    ///
+    pub fn average_verification_time_millis(&self) -> Option<u64> {
+        match self.verifications_run {
+            0 => None,
+            verifications_run => Some(self.verification_time_millis / verifications_run),
+        }
+    }
+
+    ///
+    /// Returns the counters in the Prometheus text exposition format, for scraping.
+    ///
+    pub fn to_prometheus(&self) -> String {
+        let counters = [
+            (
+                "coordinator_requests_total",
+                "Requests sent to the coordinator.",
+                self.coordinator_requests,
+            ),
+            (
+                "chunks_verified_total",
+                "Chunks verified by the coordinator.",
+                self.chunks_verified,
+            ),
+            (
+                "failed_verifications_total",
+                "Verifications rejected by the coordinator.",
+                self.failed_verifications,
+            ),
+            (
+                "bytes_downloaded_total",
+                "Bytes downloaded from the coordinator.",
+                self.bytes_downloaded,
+            ),
+            (
+                "bytes_uploaded_total",
+                "Bytes uploaded to the coordinator.",
+                self.bytes_uploaded,
+            ),
+            (
+                "verifications_run_total",
+                "Verifications run locally.",
+                self.verifications_run,
+            ),
+            (
+                "verification_time_milliseconds_total",
+                "Time spent running verifications locally.",
+                self.verification_time_millis,
+            ),
+        ];
+
+        let mut output = String::new();
+        for (name, help, value) in counters.iter() {
+            output += &format!("# HELP setup1_verifier_{} {}\n", name, help);
+            output += &format!("# TYPE setup1_verifier_{} counter\n", name);
+            output += &format!("setup1_verifier_{} {}\n", name, value);
+        }
+
+        let gauges = [
+            (
+                "disk_usage_bytes",
+                "Bytes of task files on disk.",
+                self.disk_usage_bytes,
+            ),
+            (
+                "downloads_paused",
+                "Whether new downloads are paused by the disk budget.",
+                self.downloads_paused as u64,
+            ),
+        ];
+        for (name, help, value) in gauges.iter() {
+            output += &format!("# HELP setup1_verifier_{} {}\n", name, help);
+            output += &format!("# TYPE setup1_verifier_{} gauge\n", name);
+            output += &format!("setup1_verifier_{} {}\n", name, value);
+        }
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verification_cycle_metrics() {
+        let metrics = VerifierMetrics::default();
+        assert_eq!(VerifierMetricsSnapshot::default(), metrics.snapshot());
+        assert_eq!(None, metrics.snapshot().average_verification_time_millis());
+
+        // Run a verification cycle which the coordinator accepts, with a request for
+        // each download, the upload, and the verification.
+        for _ in 0..4 {
+            metrics.record_coordinator_request();
+        }
+        metrics.record_download(128);
+        metrics.record_download(256);
+        metrics.record_verification_time(1000);
+        metrics.record_upload(64);
+        metrics.record_chunk_verified();
+
+        // Run a verification cycle which the coordinator rejects.
+        for _ in 0..4 {
+            metrics.record_coordinator_request();
+        }
+        metrics.record_download(128);
+        metrics.record_download(256);
+        metrics.record_verification_time(3000);
+        metrics.record_upload(64);
+        metrics.record_failed_verification();
+
+        let snapshot = metrics.snapshot();
+        assert_eq!(8, snapshot.coordinator_requests);
+        assert_eq!(1, snapshot.chunks_verified);
+        assert_eq!(1, snapshot.failed_verifications);
+        assert_eq!(768, snapshot.bytes_downloaded);
+        assert_eq!(128, snapshot.bytes_uploaded);
+        assert_eq!(2, snapshot.verifications_run);
+        assert_eq!(Some(2000), snapshot.average_verification_time_millis());
+    }
+
+    #[test]
+    fn test_to_prometheus() {
+        let metrics = VerifierMetrics::default();
+        metrics.record_coordinator_request();
+        metrics.record_chunk_verified();
+        metrics.record_download(42);
+        metrics.record_disk_usage(2048, true);
+
+        let output = metrics.snapshot().to_prometheus();
+        assert!(output.contains("# TYPE setup1_verifier_coordinator_requests_total counter\n"));
+        assert!(output.contains("setup1_verifier_coordinator_requests_total 1\n"));
+        assert!(output.contains("# TYPE setup1_verifier_chunks_verified_total counter\n"));
+        assert!(output.contains("setup1_verifier_chunks_verified_total 1\n"));
+        assert!(output.contains("setup1_verifier_bytes_downloaded_total 42\n"));
+        assert!(output.contains("setup1_verifier_bytes_uploaded_total 0\n"));
+        assert!(output.contains("# TYPE setup1_verifier_disk_usage_bytes gauge\n"));
+        assert!(output.contains("setup1_verifier_disk_usage_bytes 2048\n"));
+        assert!(output.contains("setup1_verifier_downloads_paused 1\n"));
+    }
+}
@@ -39,4 +39,118 @@ pub struct LockResponse {
 
     #[serde(alias = "nextChallengeContributionId")]
     pub next_challenge_contribution_id: u64,
+
+    /// The token of the lock, which is sent with the upload of the next challenge
+    #[serde(alias = "lockToken", default)]
+    pub lock_token: Option<String>,
+
+    /// The hex encoded hash of the challenge file, if the coordinator reports it
+    #[serde(alias = "challengeHash", default)]
+    pub challenge_hash: Option<String>,
+
+    /// The hex encoded hash of the response file, if the coordinator reports it
+    #[serde(alias = "responseHash", default)]
+    pub response_hash: Option<String>,
+}
+
+///
+/// The status of the verifier in the coordinator queue,
+/// returned when the verifier attempts to join the queue.
+///
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct QueueStatus {
+    /// Indicator if the verifier joined the queue with this request
+    pub joined: bool,
+
+    /// The position of the verifier in the queue, if known
+    #[serde(default)]
+    pub position: Option<u64>,
+
+    /// Indicator if the verifier was already in the queue
+    #[serde(alias = "alreadyInQueue", default)]
+    pub already_in_queue: bool,
+}
+
+impl QueueStatus {
+    ///
+    /// Parses the response of the coordinator to a join queue request.
+    ///
+    /// Coordinators which predate the queue status respond with a bare `bool`,
+    /// which is parsed as whether the verifier joined the queue.
+    ///
+    pub fn from_response(response: &[u8]) -> Result<Self, serde_json::Error> {
+        match serde_json::from_slice::<bool>(response) {
+            Ok(joined) => Ok(Self {
+                joined,
+                position: None,
+                already_in_queue: false,
+            }),
+            Err(_) => serde_json::from_slice::<Self>(response),
+        }
+    }
+
+    ///
+    /// Returns `true` if the verifier is in the queue, either because
+    /// it joined with this request or because it had already joined.
+    ///
+    pub fn is_queued(&self) -> bool {
+        self.joined || self.already_in_queue
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_queue_status_repeated_join() {
+        // The first join adds the verifier to the queue.
+        let first = QueueStatus::from_response(br#"{"joined":true,"position":3,"alreadyInQueue":false}"#).unwrap();
+        assert!(first.joined);
+        assert_eq!(Some(3), first.position);
+        assert!(first.is_queued());
+
+        // A repeated join, e.g. after a reconnect, finds the verifier already in the queue.
+        let second = QueueStatus::from_response(br#"{"joined":false,"position":3,"alreadyInQueue":true}"#).unwrap();
+        assert!(!second.joined);
+        assert!(second.already_in_queue);
+        assert!(second.is_queued());
+    }
+
+    #[test]
+    pub fn test_queue_status_legacy_response() {
+        let joined = QueueStatus::from_response(b"true").unwrap();
+        assert!(joined.is_queued());
+        assert_eq!(None, joined.position);
+
+        let rejected = QueueStatus::from_response(b"false").unwrap();
+        assert!(!rejected.is_queued());
+
+        assert!(QueueStatus::from_response(b"{}").is_err());
+    }
+
+    #[test]
+    pub fn test_lock_response_lock_token() {
+        let response = r#"{
+            "chunkId": 1,
+            "contributionId": 1,
+            "locked": true,
+            "participantID": "test_participant",
+            "challengeLocator": "challenge",
+            "challengeChunkId": 1,
+            "challengeContributionId": 0,
+            "responseLocator": "response",
+            "nextChallengeLocator": "next_challenge",
+            "nextChallengeChunkId": 1,
+            "nextChallengeContributionId": 1
+        }"#;
+
+        // Coordinators which predate lock tokens do not issue one.
+        let lock_response = serde_json::from_str::<LockResponse>(response).unwrap();
+        assert_eq!(None, lock_response.lock_token);
+
+        let response = response.replace("\"locked\": true", "\"locked\": true, \"lockToken\": \"abc\"");
+        let lock_response = serde_json::from_str::<LockResponse>(&response).unwrap();
+        assert_eq!(Some("abc".to_string()), lock_response.lock_token);
+    }
 }
@@ -0,0 +1,207 @@
+use crate::objects::LockResponse;
+
+use setup_utils::calculate_hash;
+
+///
+/// Returns `true` if the given tasks verify the same contribution.
+/// Otherwise, returns `false`.
+///
+/// The lock state and the next challenge locators of the tasks are not compared,
+/// as they are only set once a task is locked.
+///
+pub(crate) fn is_same_task(task: &LockResponse, other: &LockResponse) -> bool {
+    task.chunk_id == other.chunk_id
+        && task.contribution_id == other.contribution_id
+        && task.challenge_locator == other.challenge_locator
+        && task.challenge_chunk_id == other.challenge_chunk_id
+        && task.challenge_contribution_id == other.challenge_contribution_id
+        && task.response_locator == other.response_locator
+}
+
+///
+/// The challenge and response files of a task, downloaded before the task was locked.
+///
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Prefetch {
+    /// The task that the files were downloaded for.
+    pub(crate) task: LockResponse,
+    /// The downloaded challenge file.
+    pub(crate) challenge_file: Vec<u8>,
+    /// The downloaded response file.
+    pub(crate) response_file: Vec<u8>,
+    /// The hex encoded hash of the downloaded challenge file.
+    challenge_hash: String,
+    /// The hex encoded hash of the downloaded response file.
+    response_hash: String,
+}
+
+impl Prefetch {
+    ///
+    /// Creates the prefetch of the given task from its downloaded files.
+    ///
+    pub fn new(task: LockResponse, challenge_file: Vec<u8>, response_file: Vec<u8>) -> Self {
+        let challenge_hash = hex::encode(calculate_hash(&challenge_file));
+        let response_hash = hex::encode(calculate_hash(&response_file));
+        Self {
+            task,
+            challenge_file,
+            response_file,
+            challenge_hash,
+            response_hash,
+        }
+    }
+
+    ///
+    /// Returns `true` if the files were downloaded for the given task, and their
+    /// hashes match the hashes of the files that the coordinator reports for it.
+    /// Otherwise, returns `false`.
+    ///
+    /// The files of a task may be replaced in the coordinator under the same locators,
+    /// for example when a contribution is uploaded again, so the prefetched files are
+    /// only used if the coordinator reports the hashes of the files of the task.
+    ///
+    pub fn is_for(&self, task: &LockResponse) -> bool {
+        let matches = |expected: &Option<String>, hash: &str| {
+            expected
+                .as_ref()
+                .map_or(false, |expected| expected.eq_ignore_ascii_case(hash))
+        };
+
+        is_same_task(&self.task, task)
+            && matches(&task.challenge_hash, &self.challenge_hash)
+            && matches(&task.response_hash, &self.response_hash)
+    }
+}
+
+///
+/// The files of the task that the verifier expects to lock next,
+/// downloaded while the current task is being verified.
+///
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PrefetchCache {
+    prefetch: Option<Prefetch>,
+}
+
+impl PrefetchCache {
+    ///
+    /// Stores the given prefetched files, replacing any previously prefetched files.
+    ///
+    pub fn insert(&mut self, prefetch: Prefetch) {
+        self.prefetch = Some(prefetch);
+    }
+
+    ///
+    /// Returns `true` if the files of the given task were prefetched.
+    /// Otherwise, returns `false`.
+    ///
+    pub fn contains(&self, task: &LockResponse) -> bool {
+        self.prefetch.as_ref().map_or(false, |prefetch| prefetch.is_for(task))
+    }
+
+    ///
+    /// Takes the prefetched files of the given locked task, if any.
+    ///
+    /// The cache is emptied either way, as files prefetched for any other task
+    /// are stale once a task is locked.
+    ///
+    pub fn take(&mut self, task: &LockResponse) -> Option<Prefetch> {
+        self.prefetch.take().filter(|prefetch| prefetch.is_for(task))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_task(chunk_id: u64, locked: bool) -> LockResponse {
+        test_task_with_files(chunk_id, locked, &[1, 2, 3], &[4, 5, 6])
+    }
+
+    fn test_task_with_files(chunk_id: u64, locked: bool, challenge_file: &[u8], response_file: &[u8]) -> LockResponse {
+        LockResponse {
+            chunk_id,
+            contribution_id: 1,
+            locked,
+            participant_id: "test_participant".to_string(),
+            challenge_locator: format!("test_challenge_locator_{}", chunk_id),
+            challenge_chunk_id: chunk_id,
+            challenge_contribution_id: 0,
+            response_locator: format!("test_response_locator_{}", chunk_id),
+            next_challenge_locator: match locked {
+                true => format!("test_next_challenge_locator_{}", chunk_id),
+                false => String::new(),
+            },
+            next_challenge_chunk_id: chunk_id,
+            next_challenge_contribution_id: 1,
+            lock_token: None,
+            challenge_hash: Some(hex::encode(calculate_hash(challenge_file))),
+            response_hash: Some(hex::encode(calculate_hash(response_file))),
+        }
+    }
+
+    fn test_prefetch(chunk_id: u64) -> Prefetch {
+        Prefetch::new(test_task(chunk_id, false), vec![1, 2, 3], vec![4, 5, 6])
+    }
+
+    #[test]
+    pub fn test_take_prefetch_of_locked_task() {
+        let mut cache = PrefetchCache::default();
+        cache.insert(test_prefetch(1));
+
+        // The peeked task matches the task once it is locked.
+        let locked_task = test_task(1, true);
+        assert!(cache.contains(&locked_task));
+        assert_eq!(Some(test_prefetch(1)), cache.take(&locked_task));
+
+        // The prefetched files are only used once.
+        assert!(!cache.contains(&locked_task));
+        assert_eq!(None, cache.take(&locked_task));
+    }
+
+    #[test]
+    pub fn test_discard_stale_prefetch() {
+        let mut cache = PrefetchCache::default();
+        cache.insert(test_prefetch(1));
+
+        // Locking another task discards the prefetched files.
+        assert_eq!(None, cache.take(&test_task(2, true)));
+        assert_eq!(None, cache.take(&test_task(1, true)));
+
+        // A newer prefetch replaces an older one.
+        cache.insert(test_prefetch(1));
+        cache.insert(test_prefetch(2));
+        assert!(!cache.contains(&test_task(1, true)));
+        assert!(cache.contains(&test_task(2, true)));
+    }
+
+    #[test]
+    pub fn test_discard_prefetch_of_replaced_files() {
+        let mut cache = PrefetchCache::default();
+
+        // A response uploaded again under the same locator discards the prefetched files.
+        cache.insert(test_prefetch(1));
+        let reuploaded_task = test_task_with_files(1, true, &[1, 2, 3], &[7, 8, 9]);
+        assert!(!cache.contains(&reuploaded_task));
+        assert_eq!(None, cache.take(&reuploaded_task));
+
+        // A task without the hashes of its files does not use the prefetched files.
+        cache.insert(test_prefetch(1));
+        let task_without_hashes = LockResponse {
+            challenge_hash: None,
+            response_hash: None,
+            ..test_task(1, true)
+        };
+        assert!(!cache.contains(&task_without_hashes));
+        assert_eq!(None, cache.take(&task_without_hashes));
+
+        // The hashes are compared regardless of the case of their encoding.
+        cache.insert(test_prefetch(1));
+        let task = test_task(1, true);
+        let uppercase_task = LockResponse {
+            challenge_hash: task.challenge_hash.as_ref().map(|hash| hash.to_uppercase()),
+            response_hash: task.response_hash.as_ref().map(|hash| hash.to_uppercase()),
+            ..task
+        };
+        assert_eq!(Some(test_prefetch(1)), cache.take(&uppercase_task));
+    }
+}
@@ -120,6 +120,9 @@ mod tests {
             next_challenge_locator: "test_next_challenge_locator_1".to_string(),
             next_challenge_chunk_id: 2,
             next_challenge_contribution_id: 0,
+            lock_token: None,
+            challenge_hash: None,
+            response_hash: None,
         };
         pub static ref TASK_2: LockResponse = LockResponse {
             chunk_id: 2,
@@ -133,6 +136,9 @@ mod tests {
             next_challenge_locator: "test_next_challenge_locator_2".to_string(),
             next_challenge_chunk_id: 3,
             next_challenge_contribution_id: 0,
+            lock_token: None,
+            challenge_hash: None,
+            response_hash: None,
         };
         pub static ref TASK_3: LockResponse = LockResponse {
             chunk_id: 3,
@@ -146,6 +152,9 @@ mod tests {
             next_challenge_locator: "test_next_challenge_locator_3".to_string(),
             next_challenge_chunk_id: 4,
             next_challenge_contribution_id: 0,
+            lock_token: None,
+            challenge_hash: None,
+            response_hash: None,
         };
     }
 
@@ -0,0 +1,321 @@
+use crate::errors::VerifierError;
+
+use phase1::{helpers::CurveKind, Phase1, Phase1Parameters, PublicKey};
+use phase1_coordinator::{environment::Environment, objects::RoundHeader, phase1_chunked_parameters, Round};
+use setup_utils::{calculate_hash, CheckForCorrectness};
+use zexe_algebra::{Bls12_377, PairingEngine as Engine, BW6_761};
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+use tracing::{debug, error, info};
+
+///
+/// Verifies every contribution of an exported round transcript, without a running coordinator.
+///
+/// The transcript is a copy of the round directory of the coordinator storage, which holds
+/// the response of each contribution at `chunk_{chunk_id}/contribution_{contribution_id}.unverified`
+/// and the challenge it was computed on at `chunk_{chunk_id}/contribution_{contribution_id - 1}.verified`.
+/// The initial challenge of each chunk must record the given expected initial hash, and each chunk
+/// must hold the number of contributions expected by the round state in the transcript.
+///
+/// Returns the number of contributions verified, or the first failure in chunk and contribution order.
+///
+pub fn verify_transcript(
+    environment: &Environment,
+    transcript: &Path,
+    expected_initial_hash: &[u8],
+) -> Result<u64, VerifierError> {
+    info!("Verifying the transcript at {}", transcript.display());
+
+    let expected_number_of_contributions = expected_number_of_contributions(transcript)?;
+
+    let mut number_of_contributions = 0;
+    for chunk_id in 0..environment.number_of_chunks() {
+        number_of_contributions += verify_chunk(
+            environment,
+            transcript,
+            chunk_id,
+            expected_initial_hash,
+            expected_number_of_contributions,
+        )?;
+    }
+
+    info!(
+        "Verified {} contributions in the transcript at {}",
+        number_of_contributions,
+        transcript.display()
+    );
+    Ok(number_of_contributions)
+}
+
+/// Returns the expected number of contributions in each chunk, including the initialization
+/// contribution, from the round state in the transcript.
+fn expected_number_of_contributions(transcript: &Path) -> Result<u64, VerifierError> {
+    // The round state is stored as a round header and chunk states, or as a single file before it was split.
+    let header_path = transcript.join("state").join("header.json");
+    let round_path = transcript.join("state.json");
+    if header_path.exists() {
+        let header: RoundHeader = serde_json::from_slice(&fs::read(header_path)?)?;
+        Ok(header.expected_number_of_contributions())
+    } else if round_path.exists() {
+        let round: Round = serde_json::from_slice(&fs::read(round_path)?)?;
+        Ok(round.expected_number_of_contributions())
+    } else {
+        error!("Transcript at {} has no round state", transcript.display());
+        Err(VerifierError::MissingTranscriptRoundState)
+    }
+}
+
+/// Verifies every contribution of the given chunk, and returns the number of contributions verified.
+fn verify_chunk(
+    environment: &Environment,
+    transcript: &Path,
+    chunk_id: u64,
+    expected_initial_hash: &[u8],
+    expected_number_of_contributions: u64,
+) -> Result<u64, VerifierError> {
+    let chunk_directory = transcript.join(format!("chunk_{}", chunk_id));
+    let failure = |contribution_id: u64, reason: String| {
+        error!(
+            "Chunk {} contribution {} failed verification: {}",
+            chunk_id, contribution_id, reason
+        );
+        VerifierError::FailedTranscriptVerification(chunk_id, contribution_id, reason)
+    };
+
+    // Check that the initial challenge records the expected initial hash.
+    let initial_challenge_path = contribution_path(&chunk_directory, 0, true);
+    let mut challenge = match fs::read(&initial_challenge_path) {
+        Ok(challenge) => Some(challenge),
+        Err(_) => return Err(failure(0, "missing initial challenge".to_string())),
+    };
+    if challenge.as_ref().and_then(|challenge| challenge.get(0..64)) != Some(expected_initial_hash) {
+        error!(
+            "Chunk {} initial challenge does not record the expected initial hash",
+            chunk_id
+        );
+        return Err(VerifierError::MismatchedInitialHash(chunk_id));
+    }
+
+    for contribution_id in 1..expected_number_of_contributions {
+        let response_path = contribution_path(&chunk_directory, contribution_id, false);
+        if !response_path.exists() {
+            return Err(failure(contribution_id, "missing response".to_string()));
+        }
+
+        // The challenge of the final contribution is stored in the next round, so a
+        // missing challenge is only allowed after the final contribution.
+        let current_challenge = match challenge.take() {
+            Some(challenge) => challenge,
+            None => return Err(failure(contribution_id, "missing challenge".to_string())),
+        };
+        let response = fs::read(&response_path)?;
+
+        debug!("Verifying chunk {} contribution {}", chunk_id, contribution_id);
+        verify_contribution(environment, chunk_id, &current_challenge, &response)
+            .map_err(|reason| failure(contribution_id, reason))?;
+
+        // Check that the next challenge, if it is in this round, records the response hash.
+        let next_challenge_path = contribution_path(&chunk_directory, contribution_id, true);
+        if next_challenge_path.exists() {
+            let next_challenge = fs::read(&next_challenge_path)?;
+            if next_challenge.get(0..64) != Some(calculate_hash(&response).as_slice()) {
+                return Err(failure(
+                    contribution_id,
+                    "next challenge does not record the response hash".to_string(),
+                ));
+            }
+            challenge = Some(next_challenge);
+        }
+    }
+
+    // Check that the chunk has no contributions beyond the expected number.
+    let contribution_id = expected_number_of_contributions.max(1);
+    if contribution_path(&chunk_directory, contribution_id, false).exists() {
+        return Err(failure(contribution_id, "unexpected response".to_string()));
+    }
+
+    Ok(contribution_id - 1)
+}
+
+/// Returns the path of the given contribution file in the chunk directory.
+fn contribution_path(chunk_directory: &Path, contribution_id: u64, verified: bool) -> PathBuf {
+    let extension = match verified {
+        true => "verified",
+        false => "unverified",
+    };
+    chunk_directory.join(format!("contribution_{}.{}", contribution_id, extension))
+}
+
+/// Verifies the given response against the given challenge, and returns the reason for any failure.
+fn verify_contribution(
+    environment: &Environment,
+    chunk_id: u64,
+    challenge: &[u8],
+    response: &[u8],
+) -> Result<(), String> {
+    let settings = environment.parameters();
+    match settings.curve() {
+        CurveKind::Bls12_377 => verification(
+            environment,
+            challenge,
+            response,
+            &phase1_chunked_parameters!(Bls12_377, settings, chunk_id),
+        ),
+        CurveKind::BW6 => verification(
+            environment,
+            challenge,
+            response,
+            &phase1_chunked_parameters!(BW6_761, settings, chunk_id),
+        ),
+    }
+}
+
+fn verification<T: Engine + Sync>(
+    environment: &Environment,
+    challenge: &[u8],
+    response: &[u8],
+    parameters: &Phase1Parameters<T>,
+) -> Result<(), String> {
+    // Check that the response was computed on the challenge.
+    let challenge_hash = calculate_hash(challenge);
+    if response.get(0..64) != Some(challenge_hash.as_slice()) {
+        return Err("response does not record the challenge hash".to_string());
+    }
+
+    // Fetch the compression settings.
+    let compressed_challenge = environment.compressed_inputs();
+    let compressed_response = environment.compressed_outputs();
+
+    // Fetch the public key of the contributor.
+    let public_key = PublicKey::read(response, compressed_response, &parameters).map_err(|e| e.to_string())?;
+
+    Phase1::verification(
+        challenge,
+        response,
+        &public_key,
+        &challenge_hash,
+        compressed_challenge,
+        compressed_response,
+        CheckForCorrectness::No,
+        CheckForCorrectness::Full,
+        &parameters,
+    )
+    .map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use phase1::{ContributionMode, ProvingSystem};
+    use phase1_coordinator::{
+        authentication::Dummy,
+        environment::{Parameters, Settings, Testing},
+        testing::prelude::*,
+        Coordinator,
+        Participant,
+    };
+    use setup_utils::blank_hash;
+
+    use serial_test::serial;
+
+    /// Copies the given directory, as an operator would when exporting a transcript.
+    fn copy_directory(from: &Path, to: &Path) {
+        fs::create_dir_all(to).unwrap();
+        for entry in fs::read_dir(from).unwrap() {
+            let entry = entry.unwrap();
+            let destination = to.join(entry.file_name());
+            match entry.file_type().unwrap().is_dir() {
+                true => copy_directory(&entry.path(), &destination),
+                false => {
+                    fs::copy(entry.path(), destination).unwrap();
+                }
+            }
+        }
+    }
+
+    /// Runs a round with two contributors, and exports its transcript to the returned path.
+    fn export_test_round(environment: &Environment) -> PathBuf {
+        let coordinator = Coordinator::new(environment.clone(), Box::new(Dummy)).unwrap();
+        coordinator.initialize().unwrap();
+
+        let contributor1 = Participant::Contributor("test-contributor-1".to_string());
+        let contributor2 = Participant::Contributor("test-contributor-2".to_string());
+        let verifier = Participant::Verifier("test-verifier-1".to_string());
+        let signing_key = "secret_key".to_string();
+        coordinator.add_to_queue(contributor1.clone(), 10).unwrap();
+        coordinator.add_to_queue(contributor2.clone(), 9).unwrap();
+        coordinator.add_to_queue(verifier.clone(), 10).unwrap();
+
+        // Complete round 1.
+        coordinator.update().unwrap();
+        assert_eq!(1, coordinator.current_round_height().unwrap());
+        for _ in 0..environment.number_of_chunks() {
+            coordinator.contribute(&contributor1, &signing_key, &[1; 32]).unwrap();
+            coordinator.contribute(&contributor2, &signing_key, &[2; 32]).unwrap();
+            coordinator.verify(&verifier, &signing_key).unwrap();
+            coordinator.verify(&verifier, &signing_key).unwrap();
+        }
+        assert!(coordinator.current_round().unwrap().is_complete());
+
+        // Export the round directory.
+        let base = Path::new(environment.local_base_directory());
+        let transcript = base.join("exported_round_1");
+        copy_directory(&base.join("round_1"), &transcript);
+        transcript
+    }
+
+    #[test]
+    #[serial]
+    fn test_verify_transcript() {
+        let parameters = Parameters::Custom(Settings::new(
+            ContributionMode::Chunked,
+            ProvingSystem::Groth16,
+            CurveKind::Bls12_377,
+            6,  /* power */
+            16, /* batch_size */
+            16, /* chunk_size */
+        ));
+        let environment = initialize_test_environment(&Testing::from(parameters).into());
+        let transcript = export_test_round(&environment);
+
+        // Check that the transcript verifies.
+        let number_of_contributions = verify_transcript(&environment, &transcript, &blank_hash()).unwrap();
+        assert_eq!(2 * environment.number_of_chunks(), number_of_contributions);
+
+        // Check that an unexpected initial hash is reported.
+        match verify_transcript(&environment, &transcript, &[0; 64]) {
+            Err(VerifierError::MismatchedInitialHash(0)) => {}
+            result => panic!("Expected a mismatched initial hash, found {:?}", result),
+        }
+
+        // Check that a tampered response is reported as the first failure.
+        let chunk_id = environment.number_of_chunks() - 1;
+        let response_path = contribution_path(&transcript.join(format!("chunk_{}", chunk_id)), 2, false);
+        let mut response = fs::read(&response_path).unwrap();
+        response[100] ^= 1;
+        fs::write(&response_path, response).unwrap();
+        match verify_transcript(&environment, &transcript, &blank_hash()) {
+            Err(VerifierError::FailedTranscriptVerification(failed_chunk_id, 2, _)) => {
+                assert_eq!(chunk_id, failed_chunk_id)
+            }
+            result => panic!("Expected a failed verification, found {:?}", result),
+        }
+
+        // Check that a missing last response is reported, rather than verifying fewer contributions.
+        fs::remove_file(contribution_path(&transcript.join("chunk_0"), 2, false)).unwrap();
+        match verify_transcript(&environment, &transcript, &blank_hash()) {
+            Err(VerifierError::FailedTranscriptVerification(0, 2, _)) => {}
+            result => panic!("Expected a failed verification, found {:?}", result),
+        }
+
+        // Check that a missing response is reported.
+        fs::remove_file(contribution_path(&transcript.join("chunk_0"), 1, false)).unwrap();
+        match verify_transcript(&environment, &transcript, &blank_hash()) {
+            Err(VerifierError::FailedTranscriptVerification(0, 1, _)) => {}
+            result => panic!("Expected a failed verification, found {:?}", result),
+        }
+    }
+}
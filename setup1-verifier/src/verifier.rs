@@ -1,8 +1,16 @@
 use crate::{
     errors::VerifierError,
+    metrics::{VerifierMetrics, VerifierMetricsSnapshot},
     objects::LockResponse,
+    prefetch::{is_same_task, Prefetch, PrefetchCache},
     tasks::Tasks,
-    utils::{authentication::AleoAuthentication, create_parent_directory, remove_file_if_exists, write_to_file},
+    utils::{
+        authentication::{AleoAuthentication, AuthenticationHeader},
+        create_parent_directory,
+        remove_file_if_exists,
+        write_to_file,
+    },
+    working_directory::WorkingDirectory,
 };
 
 use phase1::helpers::CurveKind;
@@ -11,14 +19,22 @@ use phase1_coordinator::{
     environment::Environment,
     objects::{ContributionFileSignature, ContributionState},
     phase1_chunked_parameters,
-    Participant,
+    storage::Object,
 };
 use setup_utils::calculate_hash;
 use snarkos_toolkit::account::{Address, ViewKey};
 use zexe_algebra::{Bls12_377, BW6_761};
 
 use chrono::Utc;
-use std::{fs, str::FromStr, sync::Arc, thread::sleep, time::Duration};
+use std::{
+    fs,
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc,
+    },
+    thread::sleep,
+    time::Duration,
+};
 use tokio::{signal, sync::Mutex};
 use tracing::{debug, error, info, trace, warn};
 use url::Url;
@@ -41,20 +57,38 @@ macro_rules! pretty_hash {
     }};
 }
 
+///
+/// The action of a verifier on the queue, after it failed to lock a chunk.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum QueueAction {
+    /// Join the queue with the active view key, which is not in the current round.
+    JoinActive,
+    /// Join the queue with the next view key, while the active view key is in the current round.
+    JoinNext,
+    /// Rotate to the next view key, which joined the queue for the round that started.
+    Rotate,
+    /// Wait before trying again, as the state of the verifier in the round is unknown.
+    Wait,
+}
+
 ///
 /// The verifier used to manage and dispatch/execute verifier operations
 /// to the remote coordinator.
 ///
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Verifier {
     /// The url of the coordinator that will be
     pub(crate) coordinator_api_url: Url,
 
-    /// The view key that will be used for server authentication
-    pub(crate) view_key: ViewKey,
+    /// The view keys that will be used for server authentication, in the order they are rotated
+    pub(crate) view_keys: Arc<Vec<ViewKey>>,
+
+    /// The index of the view key that is currently used, which is the identity of the verifier
+    pub(crate) active_key: Arc<AtomicUsize>,
 
-    /// The identity of the verifier
-    pub(crate) verifier: Participant,
+    /// Indicator if the view key after the active one has joined the queue for the next round
+    pub(crate) next_key_queued: Arc<AtomicBool>,
 
     /// The coordinator environment
     pub(crate) environment: Environment,
@@ -64,46 +98,137 @@ pub struct Verifier {
 
     /// The path where tasks will be stored.
     pub(crate) tasks_storage_path: String,
-}
 
-// Manual implementation, since ViewKey doesn't implement Clone
-impl Clone for Verifier {
-    fn clone(&self) -> Self {
-        let view_key = ViewKey::from_str(&self.view_key.to_string()).expect("Error cloning the verifier ViewKey");
-        Self {
-            coordinator_api_url: self.coordinator_api_url.clone(),
-            view_key,
-            verifier: self.verifier.clone(),
-            environment: self.environment.clone(),
-            tasks: self.tasks.clone(),
-            tasks_storage_path: self.tasks_storage_path.clone(),
-        }
-    }
+    /// The files of the next task, downloaded while the current task is verified.
+    pub(crate) prefetch: Arc<Mutex<PrefetchCache>>,
+
+    /// The counters of the verifier operations.
+    pub(crate) metrics: Arc<VerifierMetrics>,
+
+    /// The files of the tasks of the verifier, within its disk budget.
+    pub(crate) working_directory: Arc<Mutex<WorkingDirectory>>,
 }
 
 impl Verifier {
     ///
     /// Initialize a new verifier.
     ///
+    /// The verifier rotates between the given view keys, starting from the first one.
+    ///
     pub fn new(
         coordinator_api_url: Url,
-        view_key: ViewKey,
-        address: Address,
+        view_keys: Vec<ViewKey>,
         environment: Environment,
         tasks_storage_path: String,
     ) -> Result<Self, VerifierError> {
-        let verifier_id = address.to_string();
+        if view_keys.is_empty() {
+            return Err(VerifierError::MissingViewKey);
+        }
 
         Ok(Self {
             coordinator_api_url,
-            view_key,
-            verifier: Participant::Verifier(verifier_id),
+            view_keys: Arc::new(view_keys),
+            active_key: Arc::new(AtomicUsize::new(0)),
+            next_key_queued: Arc::new(AtomicBool::new(false)),
             environment,
             tasks: Arc::new(Mutex::new(Tasks::load(&tasks_storage_path))),
             tasks_storage_path,
+            prefetch: Arc::new(Mutex::new(PrefetchCache::default())),
+            metrics: Arc::new(VerifierMetrics::default()),
+            working_directory: Arc::new(Mutex::new(WorkingDirectory::default())),
         })
     }
 
+    ///
+    /// Limits the size (in bytes) of the files of the tasks of the verifier.
+    ///
+    /// Once the budget is exceeded, the verifier pauses new downloads until there is room again.
+    ///
+    pub fn with_disk_budget(mut self, budget: u64) -> Self {
+        self.working_directory = Arc::new(Mutex::new(WorkingDirectory::new(Some(budget))));
+        self
+    }
+
+    ///
+    /// Returns the view key that is currently used by the verifier.
+    ///
+    #[inline]
+    pub(crate) fn view_key(&self) -> &ViewKey {
+        &self.view_keys[self.active_key.load(Ordering::SeqCst) % self.view_keys.len()]
+    }
+
+    ///
+    /// Returns the view key that the verifier rotates to next.
+    ///
+    #[inline]
+    pub(crate) fn next_view_key(&self) -> &ViewKey {
+        &self.view_keys[(self.active_key.load(Ordering::SeqCst) + 1) % self.view_keys.len()]
+    }
+
+    ///
+    /// Returns the address of the view key that is currently used by the verifier.
+    ///
+    #[inline]
+    pub fn address(&self) -> Result<Address, VerifierError> {
+        Ok(Address::from_view_key(self.view_key())?)
+    }
+
+    ///
+    /// Returns the authentication header of a request to the coordinator,
+    /// signed with the view key that is currently used by the verifier.
+    ///
+    #[inline]
+    pub(crate) fn authenticate(&self, method: &str, path: &str) -> Result<AuthenticationHeader, VerifierError> {
+        AleoAuthentication::authenticate(self.view_key(), method, path)
+    }
+
+    ///
+    /// Rotates to the next view key of the verifier, if it has more than one.
+    ///
+    /// The coordinator identifies the verifiers of a round by their address, so the key
+    /// is only rotated at round boundaries, see [Verifier::queue_action].
+    ///
+    pub fn rotate_key(&self) -> Result<(), VerifierError> {
+        if self.view_keys.len() > 1 {
+            self.active_key.fetch_add(1, Ordering::SeqCst);
+            info!("Verifier rotated to the view key of {}", self.address()?);
+        }
+        Ok(())
+    }
+
+    ///
+    /// Returns the current counters of the verifier operations.
+    ///
+    #[inline]
+    pub fn metrics_snapshot(&self) -> VerifierMetricsSnapshot {
+        self.metrics.snapshot()
+    }
+
+    ///
+    /// Returns `true` if the files of a new task fit within the disk budget of the verifier
+    /// and the free space of the disk, after deleting the files of released tasks as needed.
+    /// Otherwise, returns `false`.
+    ///
+    /// Records the disk usage of the verifier in its metrics.
+    ///
+    pub async fn check_disk_budget(&self) -> bool {
+        let mut working_directory = self.working_directory.lock().await;
+        let has_room = working_directory.has_room(self.task_size());
+        self.metrics
+            .record_disk_usage(working_directory.usage(), working_directory.is_paused());
+        has_room
+    }
+
+    ///
+    /// Returns the expected size in bytes of the files of a task, which are the challenge file,
+    /// the response file, and the next challenge file. The first chunk is the largest.
+    ///
+    fn task_size(&self) -> u64 {
+        let challenge_size = Object::contribution_file_size(&self.environment, 0, true);
+        let response_size = Object::contribution_file_size(&self.environment, 0, false);
+        2 * challenge_size + response_size
+    }
+
     ///
     /// Initializes a listener to handle the shutdown signal.
     ///
@@ -166,6 +291,9 @@ impl Verifier {
         // Remove the given task from `tasks`.
         tasks.remove_task(task);
 
+        // Release the files of the task, so they may be deleted to stay within the disk budget.
+        self.working_directory.lock().await.release(task);
+
         if tasks.is_empty() {
             // If there are no tasks, delete the stored tasks file.
             remove_file_if_exists(&self.tasks_storage_path);
@@ -178,7 +306,69 @@ impl Verifier {
     }
 
     ///
-    /// Downloads the challenge file from the coordinator and stores it to the verifier filesystem.
+    /// Downloads the challenge file of the task that the verifier expects to lock next,
+    /// along with its response file, and stores them in the prefetch cache.
+    ///
+    /// Prefetching is best effort, so failures are logged and otherwise ignored.
+    /// The files are only prefetched if the coordinator reports their hashes, as
+    /// they are only used once their hashes match the hashes of the locked task.
+    ///
+    pub async fn prefetch_next_task(&self, current_task: &LockResponse) {
+        // Skip prefetching while new downloads are paused.
+        if self.working_directory.lock().await.is_paused() {
+            return;
+        }
+
+        // Peek at the task that the verifier would lock next.
+        let task = match self.peek_task().await {
+            Ok(Some(task)) => task,
+            Ok(None) => return,
+            Err(error) => {
+                debug!("Verifier failed to peek at the next task {}", error);
+                return;
+            }
+        };
+
+        // Skip the current task, and a task which was already prefetched.
+        if is_same_task(&task, current_task) || self.prefetch.lock().await.contains(&task) {
+            return;
+        }
+
+        // Skip a task whose prefetched files could not be checked against the locked task.
+        if task.challenge_hash.is_none() || task.response_hash.is_none() {
+            debug!(
+                "Coordinator does not report the hashes of the files of chunk {}",
+                task.chunk_id
+            );
+            return;
+        }
+
+        info!("Prefetching the files of chunk {}", task.chunk_id);
+
+        let challenge_file = self
+            .download_challenge_file(task.challenge_chunk_id, task.challenge_contribution_id)
+            .await;
+        let response_file = self.download_response_file(task.chunk_id, task.contribution_id).await;
+
+        match (challenge_file, response_file) {
+            (Ok(challenge_file), Ok(response_file)) => {
+                // Discard the files if they were replaced since the task was peeked.
+                let prefetch = Prefetch::new(task.clone(), challenge_file, response_file);
+                match prefetch.is_for(&task) {
+                    true => self.prefetch.lock().await.insert(prefetch),
+                    false => warn!(
+                        "Verifier prefetched files of chunk {} which do not match their hashes",
+                        task.chunk_id
+                    ),
+                }
+            }
+            (Err(error), _) | (_, Err(error)) => warn!("Verifier failed to prefetch the next task {}", error),
+        }
+    }
+
+    ///
+    /// Downloads the challenge file from the coordinator, unless it was prefetched,
+    /// and stores it to the verifier filesystem.
     /// Returns the hash of the downloaded response file. Otherwise, returns a `VerifierError`
     ///
     pub async fn process_challenge_file(
@@ -186,9 +376,13 @@ impl Verifier {
         chunk_id: u64,
         contribution_id: u64,
         challenge_locator: &str,
+        prefetched: Option<Vec<u8>>,
     ) -> Result<Vec<u8>, VerifierError> {
-        // Download the challenge file from the coordinator.
-        let challenge_file = self.download_challenge_file(chunk_id, contribution_id).await?;
+        // Download the challenge file from the coordinator, if it was not prefetched.
+        let challenge_file = match prefetched {
+            Some(challenge_file) => challenge_file,
+            None => self.download_challenge_file(chunk_id, contribution_id).await?,
+        };
 
         // Compute the challenge hash using the challenge file.
         let challenge_hash = calculate_hash(&challenge_file);
@@ -208,7 +402,8 @@ impl Verifier {
     }
 
     ///
-    /// Downloads the response file from the coordinator and stores it to the verifier filesystem.
+    /// Downloads the response file from the coordinator, unless it was prefetched,
+    /// and stores it to the verifier filesystem.
     /// Returns the hash of the downloaded response file. Otherwise, returns a `VerifierError`
     ///
     pub async fn process_response_file(
@@ -216,9 +411,13 @@ impl Verifier {
         chunk_id: u64,
         contribution_id: u64,
         response_locator: &str,
+        prefetched: Option<Vec<u8>>,
     ) -> Result<Vec<u8>, VerifierError> {
-        // Download the response file from the coordinator.
-        let response_file = self.download_response_file(chunk_id, contribution_id).await?;
+        // Download the response file from the coordinator, if it was not prefetched.
+        let response_file = match prefetched {
+            Some(response_file) => response_file,
+            None => self.download_response_file(chunk_id, contribution_id).await?,
+        };
 
         // Compute the response hash using the response file.
         let response_hash = calculate_hash(&response_file);
@@ -304,6 +503,7 @@ impl Verifier {
         let stop = Utc::now();
 
         let contribution_duration = stop.timestamp_millis() - start.timestamp_millis();
+        self.metrics.record_verification_time(contribution_duration);
 
         info!(
             "Verification on chunk {} completed in {} seconds",
@@ -322,7 +522,7 @@ impl Verifier {
         info!("Check that the response hash matches the next challenge hash");
 
         // Fetch the saved response hash in the next challenge file.
-        let saved_response_hash = match next_challenge_file.chunks(64).next() {
+        let saved_response_hash = match next_challenge_file.get(0..64) {
             Some(hash) => hash.to_vec(),
             None => return Err(VerifierError::MissingStoredResponseHash),
         };
@@ -356,7 +556,7 @@ impl Verifier {
         )?;
         let message = contribution_state.signature_message()?;
 
-        let signature = AleoAuthentication::sign(&self.view_key, message)?;
+        let signature = AleoAuthentication::sign(self.view_key(), message)?;
 
         let contribution_file_signature = ContributionFileSignature::new(signature, contribution_state)?;
 
@@ -399,6 +599,30 @@ impl Verifier {
         Ok(signature_and_next_challenge_bytes)
     }
 
+    ///
+    /// Returns the action of the verifier on the queue, after it failed to lock a chunk
+    /// with the given error.
+    ///
+    /// The coordinator only lets the verifiers of the current round lock chunks, so
+    /// the view key is rotated at round boundaries. While the active view key is in the
+    /// current round, the next view key joins the queue for the next round. Once the
+    /// coordinator reports that the active view key is not in the current round, the
+    /// verifier rotates to the next view key if it joined the queue, and otherwise
+    /// joins the queue with the active view key.
+    ///
+    pub(crate) fn queue_action(&self, lock_error: &VerifierError) -> QueueAction {
+        match lock_error {
+            VerifierError::FailedLockWithCode(code) if code == "unauthorized_contributor" => {
+                match self.next_key_queued.swap(false, Ordering::SeqCst) && self.view_keys.len() > 1 {
+                    true => QueueAction::Rotate,
+                    false => QueueAction::JoinActive,
+                }
+            }
+            VerifierError::FailedLockWithCode(_) => QueueAction::JoinNext,
+            _ => QueueAction::Wait,
+        }
+    }
+
     ///
     /// Start the verifier loop. Polls the coordinator to lock and verify chunks.
     ///
@@ -429,6 +653,8 @@ impl Verifier {
                 }
             }
 
+            debug!("Verifier metrics {:?}", self.metrics_snapshot());
+
             // Sleep for 5 seconds in between iterations.
             sleep(Duration::from_secs(5));
         }
@@ -448,13 +674,34 @@ impl Verifier {
     /// 9. Attempts to apply the verification in the ceremony
     ///     - Request to the coordinator to run `try_verify`
     ///
+    /// If the files of a task do not fit within the disk budget or on the disk, no chunk is locked.
+    ///
     pub async fn try_verify(&self) -> Result<(), VerifierError> {
+        // Skip new downloads while the files of a task do not fit within the disk budget or on the disk.
+        if !self.check_disk_budget().await {
+            return Ok(());
+        }
+
         // Attempt to fetch a task from the queue or lock a chunk from the coordinator.
         let lock_response = match self.get_task().await {
             Ok(lock_response) => lock_response,
+            // If the verifier already holds its maximum of locks, it is still in the round.
+            Err(VerifierError::FailedLockWithCode(code)) if code == "chunk_lock_held" || code == "chunk_lock_limit" => {
+                return Err(VerifierError::FailedLockWithCode(code));
+            }
             Err(err) => {
                 // If there are no tasks, attempt to join the queue for the next round.
-                self.join_queue().await?;
+                match self.queue_action(&err) {
+                    QueueAction::JoinActive => {
+                        self.join_queue(self.view_key()).await?;
+                    }
+                    QueueAction::JoinNext => {
+                        self.join_queue(self.next_view_key()).await?;
+                        self.next_key_queued.store(true, Ordering::SeqCst);
+                    }
+                    QueueAction::Rotate => self.rotate_key()?,
+                    QueueAction::Wait => {}
+                }
 
                 return Err(err);
             }
@@ -462,6 +709,9 @@ impl Verifier {
 
         info!("Attempting to verify chunk {}", lock_response.chunk_id);
 
+        // Track the files of the task in the working directory.
+        self.working_directory.lock().await.start(&lock_response);
+
         // Deserialize the lock response.
         let LockResponse {
             chunk_id,
@@ -475,18 +725,42 @@ impl Verifier {
             next_challenge_locator,
             next_challenge_chunk_id,
             next_challenge_contribution_id,
+            lock_token,
+            challenge_hash: _,
+            response_hash: _,
         } = &lock_response;
 
+        // Take the files of the task, if they were prefetched during the previous verification.
+        let (prefetched_challenge, prefetched_response) = match self.prefetch.lock().await.take(&lock_response) {
+            Some(prefetch) => {
+                info!("Using the prefetched files of chunk {}", chunk_id);
+                (Some(prefetch.challenge_file), Some(prefetch.response_file))
+            }
+            None => (None, None),
+        };
+
         // Download and process the challenge file.
         let challenge_hash = self
-            .process_challenge_file(*challenge_chunk_id, *challenge_contribution_id, &challenge_locator)
+            .process_challenge_file(
+                *challenge_chunk_id,
+                *challenge_contribution_id,
+                &challenge_locator,
+                prefetched_challenge,
+            )
             .await?;
 
         // Download and process the response file.
         let response_hash = self
-            .process_response_file(*chunk_id, *contribution_id, &response_locator)
+            .process_response_file(*chunk_id, *contribution_id, &response_locator, prefetched_response)
             .await?;
 
+        // Prefetch the files of the next task while this task is verified.
+        let verifier = self.clone();
+        let current_task = lock_response.clone();
+        tokio::task::spawn(async move {
+            verifier.prefetch_next_task(&current_task).await;
+        });
+
         // Run verification on a chunk with the given locators.
         let _duration = self.run_verification(
             *chunk_id,
@@ -513,12 +787,16 @@ impl Verifier {
         self.upload_next_challenge_locator_file(
             *next_challenge_chunk_id,
             *next_challenge_contribution_id,
+            lock_token.as_deref(),
             signature_and_next_challenge_bytes,
         )
         .await?;
         // Attempt to perform the verification with the uploaded challenge file at `next_challenge_locator`.
         self.verify_contribution(*chunk_id).await?;
 
+        // Delete the files of the task, as the coordinator confirmed its verification.
+        self.working_directory.lock().await.complete(&lock_response);
+
         // Clear the task from the cache.
         self.clear_task(&lock_response).await?;
 
@@ -529,10 +807,19 @@ impl Verifier {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use phase1_coordinator::environment::{Parameters, Testing};
+    use phase1::{ContributionMode, ProvingSystem};
+    use phase1_coordinator::{
+        authentication::Dummy,
+        environment::{Parameters, Settings, Testing},
+        testing::prelude::*,
+        Coordinator,
+        Participant,
+    };
+    use snarkos_toolkit::account::PrivateKey;
 
     use rand::{Rng, SeedableRng};
     use rand_xorshift::XorShiftRng;
+    use serial_test::serial;
     use std::str::FromStr;
 
     const TEST_VIEW_KEY: &str = "AViewKey1cWNDyYMjc9p78PnCderRx37b9pJr4myQqmmPeCfeiLf3";
@@ -545,18 +832,152 @@ mod tests {
         });
 
         let view_key = ViewKey::from_str(TEST_VIEW_KEY).expect("Invalid view key");
-        let address = Address::from_view_key(&view_key).expect("Address not derived correctly");
 
         Verifier::new(
             Url::from_str("http://test_coordinator_url").unwrap(),
-            view_key,
-            address,
+            vec![view_key],
             environment.into(),
             "TEST_VERIFIER.tasks".to_string(),
         )
         .unwrap()
     }
 
+    #[test]
+    pub fn test_rotating_view_keys() {
+        let environment: Testing = Testing::from(Parameters::TestCustom {
+            number_of_chunks: 64,
+            power: 16,
+            batch_size: 512,
+        });
+
+        let view_keys = vec![
+            ViewKey::from_str(TEST_VIEW_KEY).unwrap(),
+            ViewKey::from(&PrivateKey::new(&mut rand::thread_rng()).unwrap()).unwrap(),
+        ];
+        let addresses: Vec<String> = view_keys
+            .iter()
+            .map(|view_key| Address::from_view_key(view_key).unwrap().to_string())
+            .collect();
+
+        let verifier = Verifier::new(
+            Url::from_str("http://test_coordinator_url").unwrap(),
+            view_keys,
+            environment.into(),
+            "TEST_ROTATING_VERIFIER.tasks".to_string(),
+        )
+        .unwrap();
+
+        // Requests are signed with the active key, which rotates in order, and is shared by clones.
+        let clone = verifier.clone();
+        for expected in [0, 1, 0].iter() {
+            let header = clone.authenticate("post", "/v1/verifier/try_lock").unwrap();
+            assert_eq!(addresses[*expected], header.address);
+            assert!(
+                AleoAuthentication::verify_auth(&header, "post".to_string(), "/v1/verifier/try_lock".to_string())
+                    .unwrap()
+            );
+            verifier.rotate_key().unwrap();
+        }
+
+        // A verifier requires at least one view key.
+        let result = Verifier::new(
+            Url::from_str("http://test_coordinator_url").unwrap(),
+            vec![],
+            verifier.environment.clone(),
+            "TEST_ROTATING_VERIFIER.tasks".to_string(),
+        );
+        assert!(matches!(result, Err(VerifierError::MissingViewKey)));
+    }
+
+    #[test]
+    #[serial]
+    pub fn test_rotating_view_keys_at_round_boundaries() {
+        let parameters = Parameters::Custom(Settings::new(
+            ContributionMode::Chunked,
+            ProvingSystem::Groth16,
+            CurveKind::Bls12_377,
+            6,  /* power */
+            16, /* batch_size */
+            16, /* chunk_size */
+        ));
+        let environment = initialize_test_environment(&Testing::from(parameters).into());
+        let coordinator = Coordinator::new(environment.clone(), Box::new(Dummy)).unwrap();
+        coordinator.initialize().unwrap();
+
+        let view_keys = vec![
+            ViewKey::from_str(TEST_VIEW_KEY).unwrap(),
+            ViewKey::from(&PrivateKey::new(&mut rand::thread_rng()).unwrap()).unwrap(),
+        ];
+        let verifiers: Vec<Participant> = view_keys
+            .iter()
+            .map(|view_key| Participant::Verifier(Address::from_view_key(view_key).unwrap().to_string()))
+            .collect();
+        let verifier = Verifier::new(
+            Url::from_str("http://test_coordinator_url").unwrap(),
+            view_keys,
+            environment.clone(),
+            "TEST_ROUND_ROTATING_VERIFIER.tasks".to_string(),
+        )
+        .unwrap();
+        let active = || Participant::Verifier(verifier.address().unwrap().to_string());
+
+        // Fails to lock a chunk with the active view key, and applies the queue action to the coordinator.
+        let fail_lock = || {
+            let error = coordinator.try_lock(&active()).unwrap_err();
+            let action = verifier.queue_action(&VerifierError::FailedLockWithCode(error.code().to_string()));
+            match action {
+                QueueAction::JoinActive => coordinator.add_to_queue(active(), 10).unwrap(),
+                QueueAction::JoinNext => {
+                    let next =
+                        Participant::Verifier(Address::from_view_key(verifier.next_view_key()).unwrap().to_string());
+                    coordinator.add_to_queue(next, 10).unwrap();
+                    verifier.next_key_queued.store(true, Ordering::SeqCst);
+                }
+                QueueAction::Rotate => verifier.rotate_key().unwrap(),
+                QueueAction::Wait => {}
+            }
+            action
+        };
+
+        let contributor1 = Participant::Contributor("test-contributor-1".to_string());
+        let contributor2 = Participant::Contributor("test-contributor-2".to_string());
+        let signing_key = "secret_key".to_string();
+
+        // The first view key is not in a round, so it joins the queue.
+        assert_eq!(QueueAction::JoinActive, fail_lock());
+        coordinator.add_to_queue(contributor1.clone(), 10).unwrap();
+        coordinator.update().unwrap();
+        assert_eq!(1, coordinator.current_round_height().unwrap());
+        assert!(coordinator.is_current_verifier(&verifiers[0]));
+
+        // While the first view key is in round 1, the second view key joins the queue.
+        assert_eq!(QueueAction::JoinNext, fail_lock());
+        assert_eq!(verifiers[0], active());
+
+        // Complete round 1 with the first view key, and start round 2.
+        for _ in 0..environment.number_of_chunks() {
+            coordinator.contribute(&contributor1, &signing_key, &[1; 32]).unwrap();
+            coordinator.verify(&verifiers[0], &signing_key).unwrap();
+        }
+        coordinator.add_to_queue(contributor2.clone(), 10).unwrap();
+        coordinator.update().unwrap();
+        assert_eq!(2, coordinator.current_round_height().unwrap());
+        assert!(!coordinator.is_current_verifier(&verifiers[0]));
+        assert!(coordinator.is_current_verifier(&verifiers[1]));
+
+        // The first view key is rejected in round 2, so the verifier rotates to the second view key.
+        assert_eq!(QueueAction::Rotate, fail_lock());
+        assert_eq!(verifiers[1], active());
+
+        // The second view key is in round 2, so the first view key joins the queue for round 3.
+        assert_eq!(QueueAction::JoinNext, fail_lock());
+        for _ in 0..environment.number_of_chunks() {
+            coordinator.contribute(&contributor2, &signing_key, &[2; 32]).unwrap();
+            coordinator.verify(&verifiers[1], &signing_key).unwrap();
+        }
+        assert!(coordinator.current_round().unwrap().is_complete());
+    }
+
     #[test]
     pub fn test_verify_response_hash() {
         let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
@@ -581,6 +1002,206 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    pub async fn test_process_prefetched_files() {
+        let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+        let verifier = test_verifier();
+
+        // Generate dummy contributions.
+        let dummy_response: [u8; 32] = rng.gen();
+        let dummy_challenge: [u8; 32] = rng.gen();
+
+        // The test coordinator cannot be reached, so the prefetched files are processed without downloads.
+        let challenge_locator = "./prefetch_test/challenge";
+        let response_locator = "./prefetch_test/response";
+        let challenge_hash = verifier
+            .process_challenge_file(0, 0, challenge_locator, Some(dummy_challenge.to_vec()))
+            .await
+            .unwrap();
+        let response_hash = verifier
+            .process_response_file(0, 1, response_locator, Some(dummy_response.to_vec()))
+            .await
+            .unwrap();
+
+        assert_eq!(calculate_hash(&dummy_challenge).to_vec(), challenge_hash);
+        assert_eq!(calculate_hash(&dummy_response).to_vec(), response_hash);
+        assert_eq!(dummy_challenge.to_vec(), fs::read(challenge_locator).unwrap());
+        assert_eq!(dummy_response.to_vec(), fs::read(response_locator).unwrap());
+
+        // Prefetched files are not counted as downloads, and send no requests.
+        assert_eq!(0, verifier.metrics_snapshot().bytes_downloaded);
+        assert_eq!(0, verifier.metrics_snapshot().coordinator_requests);
+
+        // Without prefetched files, the download from the test coordinator fails.
+        let result = verifier.process_response_file(0, 1, response_locator, None).await;
+        assert!(result.is_err());
+        assert_eq!(1, verifier.metrics_snapshot().coordinator_requests);
+
+        fs::remove_dir_all("./prefetch_test").unwrap();
+    }
+
+    /// Starts a mock coordinator which serves the given next task and files, and returns
+    /// its URL and the number of files it served.
+    async fn mock_coordinator(
+        next_task: LockResponse,
+        challenge_file: Vec<u8>,
+        response_file: Vec<u8>,
+    ) -> (Url, Arc<AtomicUsize>) {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = Url::from_str(&format!("http://{}", listener.local_addr().unwrap())).unwrap();
+        let downloads = Arc::new(AtomicUsize::new(0));
+
+        let served = downloads.clone();
+        tokio::spawn(async move {
+            loop {
+                let (mut stream, _) = listener.accept().await.unwrap();
+
+                // Read the request headers, as the requests of the verifier have no body.
+                let mut request = vec![];
+                let mut buffer = [0u8; 1024];
+                while !request.windows(4).any(|window| window == b"\r\n\r\n") {
+                    let read = stream.read(&mut buffer).await.unwrap();
+                    if read == 0 {
+                        break;
+                    }
+                    request.extend_from_slice(&buffer[..read]);
+                }
+                let request = String::from_utf8_lossy(&request);
+                let path = request.split_whitespace().nth(1).unwrap_or_default();
+
+                let (status, body) = if path == "/v1/verifier/peek_task" {
+                    ("200 OK", serde_json::to_vec(&Some(&next_task)).unwrap())
+                } else if path.starts_with("/v1/download/challenge/") {
+                    served.fetch_add(1, Ordering::SeqCst);
+                    ("200 OK", challenge_file.clone())
+                } else if path.starts_with("/v1/download/response/") {
+                    served.fetch_add(1, Ordering::SeqCst);
+                    ("200 OK", response_file.clone())
+                } else {
+                    ("404 Not Found", vec![])
+                };
+
+                let header = format!(
+                    "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                    status,
+                    body.len()
+                );
+                stream.write_all(header.as_bytes()).await.unwrap();
+                stream.write_all(&body).await.unwrap();
+                stream.shutdown().await.unwrap();
+            }
+        });
+
+        (url, downloads)
+    }
+
+    fn mock_verifier(coordinator_api_url: Url) -> Verifier {
+        let environment: Testing = Testing::from(Parameters::TestCustom {
+            number_of_chunks: 64,
+            power: 16,
+            batch_size: 512,
+        });
+
+        let view_key = ViewKey::from_str(TEST_VIEW_KEY).expect("Invalid view key");
+
+        Verifier::new(
+            coordinator_api_url,
+            vec![view_key],
+            environment.into(),
+            "TEST_MOCK_VERIFIER.tasks".to_string(),
+        )
+        .unwrap()
+    }
+
+    fn mock_task(chunk_id: u64, challenge_file: &[u8], response_file: &[u8]) -> LockResponse {
+        LockResponse {
+            chunk_id,
+            contribution_id: 1,
+            locked: false,
+            participant_id: "test_participant".to_string(),
+            challenge_locator: format!("test_challenge_locator_{}", chunk_id),
+            challenge_chunk_id: chunk_id,
+            challenge_contribution_id: 0,
+            response_locator: format!("test_response_locator_{}", chunk_id),
+            next_challenge_locator: String::new(),
+            next_challenge_chunk_id: chunk_id,
+            next_challenge_contribution_id: 1,
+            lock_token: None,
+            challenge_hash: Some(hex::encode(calculate_hash(challenge_file))),
+            response_hash: Some(hex::encode(calculate_hash(response_file))),
+        }
+    }
+
+    #[tokio::test]
+    pub async fn test_prefetch_from_mock_coordinator() {
+        let challenge_file = vec![1u8; 64];
+        let response_file = vec![2u8; 64];
+        let next_task = mock_task(1, &challenge_file, &response_file);
+        let current_task = mock_task(0, &challenge_file, &response_file);
+
+        let (url, downloads) = mock_coordinator(next_task.clone(), challenge_file.clone(), response_file.clone()).await;
+        let verifier = mock_verifier(url);
+
+        // The files of the next task are downloaded once.
+        verifier.prefetch_next_task(&current_task).await;
+        assert_eq!(2, downloads.load(Ordering::SeqCst));
+        verifier.prefetch_next_task(&current_task).await;
+        assert_eq!(2, downloads.load(Ordering::SeqCst));
+
+        // The locked task takes the prefetched files, as their hashes match.
+        let locked_task = LockResponse {
+            locked: true,
+            next_challenge_locator: "test_next_challenge_locator_1".to_string(),
+            lock_token: Some("test_lock_token".to_string()),
+            ..next_task.clone()
+        };
+        let prefetch = verifier.prefetch.lock().await.take(&locked_task).unwrap();
+        assert_eq!(challenge_file, prefetch.challenge_file);
+        assert_eq!(response_file, prefetch.response_file);
+
+        // The prefetched files are not used once the response was uploaded again.
+        verifier.prefetch_next_task(&current_task).await;
+        assert_eq!(4, downloads.load(Ordering::SeqCst));
+        let reuploaded_task = LockResponse {
+            response_hash: Some(hex::encode(calculate_hash(&[3u8; 64]))),
+            ..locked_task
+        };
+        assert_eq!(None, verifier.prefetch.lock().await.take(&reuploaded_task));
+    }
+
+    #[tokio::test]
+    pub async fn test_discard_prefetch_of_files_replaced_since_peek() {
+        let challenge_file = vec![1u8; 64];
+        let response_file = vec![2u8; 64];
+        let current_task = mock_task(0, &challenge_file, &response_file);
+
+        // The coordinator serves a response other than the one whose hash it reported.
+        let next_task = mock_task(1, &challenge_file, &response_file);
+        let (url, downloads) = mock_coordinator(next_task.clone(), challenge_file, vec![3u8; 64]).await;
+        let verifier = mock_verifier(url);
+
+        verifier.prefetch_next_task(&current_task).await;
+        assert_eq!(2, downloads.load(Ordering::SeqCst));
+        assert!(!verifier.prefetch.lock().await.contains(&next_task));
+
+        // A task without the hashes of its files is not prefetched.
+        let (url, downloads) = mock_coordinator(
+            LockResponse {
+                challenge_hash: None,
+                response_hash: None,
+                ..next_task
+            },
+            vec![1u8; 64],
+            vec![2u8; 64],
+        )
+        .await;
+        mock_verifier(url).prefetch_next_task(&current_task).await;
+        assert_eq!(0, downloads.load(Ordering::SeqCst));
+    }
+
     #[test]
     pub fn test_contribution_signatures() {
         let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
@@ -614,7 +1235,7 @@ mod tests {
         let message = contribution_state.signature_message().unwrap();
 
         // Derive the verifier address
-        let address = Address::from_view_key(&verifier.view_key).unwrap();
+        let address = verifier.address().unwrap();
 
         // Check that the signature verifies
         assert!(AleoAuthentication::verify(&address.to_string(), signature, message).unwrap())
@@ -0,0 +1,286 @@
+use crate::{objects::LockResponse, utils::remove_file_if_exists};
+
+use std::{fs, path::Path};
+use tracing::{debug, info, trace, warn};
+
+/// The state of a file written by the verifier for a task.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum FileState {
+    /// The task of the file is in progress, so the file must be kept.
+    InProgress,
+    /// The task of the file was cleared without a confirmed verification,
+    /// so the file may be deleted to stay within the budget.
+    Released,
+}
+
+///
+/// The files that the verifier writes for its tasks, with an optional budget on their total size.
+///
+/// The files of a task are deleted as soon as the coordinator confirms its verification.
+/// The files of a task which was cleared otherwise are kept until the budget is exceeded,
+/// and the files of the tasks in progress are never deleted. Before each task, the verifier
+/// checks that the files of the task fit within the budget and the free space of the disk,
+/// and pauses new downloads until there is room again.
+///
+#[derive(Debug, Default)]
+pub struct WorkingDirectory {
+    /// The maximum number of bytes of the files of the verifier, if any.
+    budget: Option<u64>,
+    /// The files of the verifier, in the order they were written, with their state.
+    files: Vec<(String, FileState)>,
+    /// Indicator if new downloads are paused, as the budget is exceeded.
+    paused: bool,
+}
+
+impl WorkingDirectory {
+    ///
+    /// Creates a new working directory with the given budget in bytes, if any.
+    ///
+    pub fn new(budget: Option<u64>) -> Self {
+        Self {
+            budget,
+            ..Default::default()
+        }
+    }
+
+    ///
+    /// Returns the budget in bytes of the files of the verifier, if any.
+    ///
+    pub fn budget(&self) -> Option<u64> {
+        self.budget
+    }
+
+    ///
+    /// Returns `true` if new downloads are paused, as the budget is exceeded.
+    /// Otherwise, returns `false`.
+    ///
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    ///
+    /// Returns the number of bytes of the files of the verifier,
+    /// measured from the sizes of the files on disk.
+    ///
+    pub fn usage(&self) -> u64 {
+        self.files.iter().map(|(path, _)| Self::file_size(path)).sum()
+    }
+
+    ///
+    /// Tracks the files of the given task as in progress.
+    ///
+    pub fn start(&mut self, task: &LockResponse) {
+        for path in Self::paths(task) {
+            self.files.retain(|(tracked, _)| tracked != path);
+            self.files.push((path.to_string(), FileState::InProgress));
+        }
+    }
+
+    ///
+    /// Deletes the files of the given task, once the coordinator confirmed its verification.
+    ///
+    pub fn complete(&mut self, task: &LockResponse) {
+        for path in Self::paths(task) {
+            self.files.retain(|(tracked, _)| tracked != path);
+            remove_file_if_exists(path);
+        }
+    }
+
+    ///
+    /// Releases the files of the given task, so they may be deleted to stay within the budget.
+    ///
+    pub fn release(&mut self, task: &LockResponse) {
+        let paths = Self::paths(task);
+        for (path, state) in self.files.iter_mut() {
+            if paths.contains(&path.as_str()) {
+                *state = FileState::Released;
+            }
+        }
+    }
+
+    ///
+    /// Returns `true` if the files of a new task, of the given expected size in bytes,
+    /// fit within the budget and the free space of the disk. Otherwise, returns `false`
+    /// and pauses new downloads.
+    ///
+    /// If the budget would be exceeded, the released files are deleted first, oldest first.
+    ///
+    pub fn has_room(&mut self, expected_size: u64) -> bool {
+        let within_budget = match self.budget {
+            Some(budget) => {
+                let mut usage = self.usage();
+                while usage + expected_size > budget {
+                    let index = match self.files.iter().position(|(_, state)| *state == FileState::Released) {
+                        Some(index) => index,
+                        None => break,
+                    };
+                    let (path, _) = self.files.remove(index);
+                    trace!("Deleting {} to stay within the disk budget", path);
+                    usage -= Self::file_size(&path);
+                    remove_file_if_exists(&path);
+                }
+
+                let within_budget = usage + expected_size <= budget;
+                if !within_budget && !self.paused {
+                    warn!(
+                        "The verifier files use {} bytes, and a task needs {} bytes, over the budget of {} bytes",
+                        usage, expected_size, budget
+                    );
+                }
+                within_budget
+            }
+            None => true,
+        };
+
+        // Check the free space of the disk, so a task does not fail to write its files.
+        let on_disk = match Self::available_space() {
+            Some(available) if available < expected_size => {
+                if !self.paused {
+                    warn!(
+                        "The disk has {} bytes free, and a task needs {} bytes",
+                        available, expected_size
+                    );
+                }
+                false
+            }
+            _ => true,
+        };
+
+        let has_room = within_budget && on_disk;
+        match (self.paused, has_room) {
+            (false, false) => warn!("Pausing new downloads until there is room for the files of a task"),
+            (true, true) => info!("There is room for the files of a task. Resuming downloads"),
+            _ => (),
+        }
+        self.paused = !has_room;
+
+        has_room
+    }
+
+    /// Returns the free space in bytes of the disk of the working directory, if it can be read.
+    fn available_space() -> Option<u64> {
+        match fs2::available_space(Path::new(".")) {
+            Ok(available) => Some(available),
+            Err(error) => {
+                debug!("Failed to read the free space of the disk {}", error);
+                None
+            }
+        }
+    }
+
+    /// Returns the paths of the files of the given task.
+    fn paths(task: &LockResponse) -> [&str; 3] {
+        [
+            &task.challenge_locator,
+            &task.response_locator,
+            &task.next_challenge_locator,
+        ]
+    }
+
+    /// Returns the size of the file at the given path, or 0 if it does not exist.
+    fn file_size(path: &str) -> u64 {
+        fs::metadata(path).map(|metadata| metadata.len()).unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::write_to_file;
+
+    use std::path::Path;
+
+    const TEST_DIRECTORY: &str = "./working_directory_test";
+
+    fn task(chunk_id: u64) -> LockResponse {
+        LockResponse {
+            chunk_id,
+            contribution_id: 1,
+            locked: true,
+            participant_id: "test_participant".to_string(),
+            challenge_locator: format!("{}/challenge_{}", TEST_DIRECTORY, chunk_id),
+            challenge_chunk_id: chunk_id,
+            challenge_contribution_id: 0,
+            response_locator: format!("{}/response_{}", TEST_DIRECTORY, chunk_id),
+            next_challenge_locator: format!("{}/next_challenge_{}", TEST_DIRECTORY, chunk_id),
+            next_challenge_chunk_id: chunk_id,
+            next_challenge_contribution_id: 1,
+            lock_token: None,
+            challenge_hash: None,
+            response_hash: None,
+        }
+    }
+
+    fn write_files(task: &LockResponse, size: usize) {
+        for path in WorkingDirectory::paths(task).iter() {
+            write_to_file(path, vec![0u8; size]);
+        }
+    }
+
+    #[test]
+    pub fn test_working_directory_budget() {
+        let mut working_directory = WorkingDirectory::new(Some(100));
+        assert_eq!(Some(100), working_directory.budget());
+
+        // The files of a task in progress are exempt, so downloads pause over the budget.
+        let task_1 = task(1);
+        working_directory.start(&task_1);
+        write_files(&task_1, 40);
+        assert_eq!(120, working_directory.usage());
+        assert!(!working_directory.has_room(0));
+        assert!(working_directory.is_paused());
+        assert!(Path::new(&task_1.response_locator).exists());
+
+        // The files of a confirmed task are deleted immediately, so downloads resume.
+        working_directory.complete(&task_1);
+        assert_eq!(0, working_directory.usage());
+        assert!(!Path::new(&task_1.response_locator).exists());
+        assert!(working_directory.has_room(0));
+        assert!(!working_directory.is_paused());
+
+        // A task whose files would exceed the budget is not started.
+        assert!(!working_directory.has_room(120));
+        assert!(working_directory.is_paused());
+        assert!(working_directory.has_room(100));
+        assert!(!working_directory.is_paused());
+
+        // The files of a released task are kept while within the budget.
+        let task_2 = task(2);
+        working_directory.start(&task_2);
+        write_files(&task_2, 20);
+        working_directory.release(&task_2);
+        assert!(working_directory.has_room(0));
+        assert!(Path::new(&task_2.response_locator).exists());
+
+        // The files of a released task are deleted, oldest first, once over the budget.
+        let task_3 = task(3);
+        working_directory.start(&task_3);
+        write_files(&task_3, 20);
+        assert_eq!(120, working_directory.usage());
+        assert!(working_directory.has_room(0));
+        assert!(!Path::new(&task_2.challenge_locator).exists());
+        assert!(Path::new(&task_2.next_challenge_locator).exists());
+        assert!(Path::new(&task_3.challenge_locator).exists());
+        assert_eq!(100, working_directory.usage());
+
+        // The released files are deleted to make room for the files of a new task.
+        working_directory.release(&task_3);
+        assert!(working_directory.has_room(60));
+        assert_eq!(40, working_directory.usage());
+        assert!(Path::new(&task_3.next_challenge_locator).exists());
+
+        fs::remove_dir_all(TEST_DIRECTORY).unwrap();
+    }
+
+    #[test]
+    pub fn test_working_directory_without_budget() {
+        let mut working_directory = WorkingDirectory::new(None);
+        working_directory.start(&task(1));
+        assert!(working_directory.has_room(0));
+        assert!(!working_directory.is_paused());
+
+        // A task which does not fit on the disk is not started, even without a budget.
+        assert!(!working_directory.has_room(u64::MAX));
+        assert!(working_directory.is_paused());
+    }
+}
@@ -35,7 +35,10 @@ use rand::SeedableRng;
 use rand_xorshift::XorShiftRng;
 use snarkos_dpc::base_dpc::{program::PrivateProgramInput, BaseDPCComponents, NoopCircuit};
 use snarkos_models::algorithms::SNARK;
-use std::fs::OpenOptions;
+use std::{
+    fs::OpenOptions,
+    time::{Duration, Instant},
+};
 
 type AleoInner = InnerPairing;
 type AleoOuter = OuterPairing;
@@ -78,6 +81,9 @@ pub struct NewOpts {
 
     #[options(help = "setup the inner or the outer circuit?")]
     pub is_inner: bool,
+
+    #[options(help = "print a table of the time taken by each step")]
+    pub timings: bool,
 }
 
 pub fn new(opt: &NewOpts) -> anyhow::Result<()> {
@@ -171,11 +177,18 @@ pub fn generate_params<Aleo: AleoPairingengine, Zexe: PairingEngine, C: Clone +
         .open(&opt.output)
         .expect("could not open file for writing the MPC parameters ");
 
+    let mut timings = vec![];
+    let now = Instant::now();
     let phase2_size = ceremony_size(&circuit);
+    timings.push(("count the constraints", now.elapsed()));
+
+    let now = Instant::now();
     let keypair = circuit_to_qap::<Aleo, Zexe, _>(circuit)?;
+    timings.push(("convert the circuit to a QAP", now.elapsed()));
 
     // Read `num_constraints` Lagrange coefficients from the Phase1 Powers of Tau which were
     // prepared for this step. This will fail if Phase 1 was too small.
+    let now = Instant::now();
     let phase1 = Groth16Params::<Zexe>::read(
         &mut phase1_transcript,
         COMPRESSION,
@@ -184,9 +197,34 @@ pub fn generate_params<Aleo: AleoPairingengine, Zexe: PairingEngine, C: Clone +
         phase2_size,
     )?;
 
+    timings.push(("read the phase 1 coefficients", now.elapsed()));
+
     // Generate the initial transcript
+    let now = Instant::now();
     let mpc = MPCParameters::new(keypair, phase1)?;
+    timings.push(("evaluate the QAP", now.elapsed()));
+
+    let now = Instant::now();
     mpc.write(&mut output)?;
+    timings.push(("write the parameters", now.elapsed()));
+
+    if opt.timings {
+        print_timings(&timings);
+    }
 
     Ok(())
 }
+
+/// Prints a table of the time taken by each step of generating the parameters.
+///
+/// Running the command with `RAYON_NUM_THREADS=1` gives the single threaded times,
+/// to compare against the times with all the threads.
+fn print_timings(timings: &[(&str, Duration)]) {
+    println!("| Step | Time (s) |");
+    println!("|------|----------|");
+    for (step, duration) in timings {
+        println!("| {} | {:.3} |", step, duration.as_secs_f64());
+    }
+    let total: Duration = timings.iter().map(|(_, duration)| *duration).sum();
+    println!("| total | {:.3} |", total.as_secs_f64());
+}